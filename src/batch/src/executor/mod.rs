@@ -37,7 +37,8 @@ use crate::executor2::executor_wrapper::ExecutorWrapper;
 use crate::executor2::{
     BoxedExecutor2, BoxedExecutor2Builder, DeleteExecutor2, ExchangeExecutor2, FilterExecutor2,
     HashAggExecutor2Builder, HashJoinExecutor2Builder, InsertExecutor2, LimitExecutor2,
-    ProjectExecutor2, TopNExecutor2, TraceExecutor2, ValuesExecutor2,
+    ProjectExecutor2, ProjectSetExecutor2, SessionWindowExecutor2, TableFunctionExecutor2,
+    TopNExecutor2, TraceExecutor2, ValuesExecutor2,
 };
 use crate::task::{BatchEnvironment, TaskId};
 
@@ -209,6 +210,9 @@ impl<'a> ExecutorBuilder<'a> {
             NodeBody::MergeSortExchange => MergeSortExchangeExecutor,
             NodeBody::GenerateInt32Series => GenerateSeriesI32Executor,
             NodeBody::HopWindow => NotImplementedBuilder,
+            NodeBody::SessionWindow => SessionWindowExecutor2,
+            NodeBody::TableFunction => TableFunctionExecutor2,
+            NodeBody::ProjectSet => ProjectSetExecutor2,
         }?;
         let input_desc = real_executor.identity().to_string();
         Ok(Box::new(TraceExecutor::new(real_executor, input_desc)))
@@ -239,6 +243,9 @@ impl<'a> ExecutorBuilder<'a> {
             NodeBody::MergeSortExchange => MergeSortExchangeExecutor,
             NodeBody::GenerateInt32Series => GenerateSeriesI32Executor,
             NodeBody::HopWindow => NotImplementedBuilder,
+            NodeBody::SessionWindow => SessionWindowExecutor2,
+            NodeBody::TableFunction => TableFunctionExecutor2,
+            NodeBody::ProjectSet => ProjectSetExecutor2,
         }?;
         let input_desc = real_executor.identity().to_string();
         Ok(Box::new(TraceExecutor2::new(real_executor, input_desc)))
@@ -212,7 +212,9 @@ impl Executor for OrderByExecutor {
                         Interval,
                         NaiveDate,
                         NaiveTime,
-                        NaiveDateTime
+                        NaiveDateTime,
+                        Jsonb,
+                        Bytea
                     ]
                 );
             }
@@ -22,6 +22,9 @@ mod insert;
 mod join;
 mod limit;
 mod project;
+mod project_set;
+mod session_window;
+mod table_function;
 mod top_n;
 mod trace;
 mod values;
@@ -35,9 +38,12 @@ pub use insert::*;
 pub use join::*;
 pub use limit::*;
 pub use project::*;
+pub use project_set::*;
 use risingwave_common::array::DataChunk;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::Result;
+pub use session_window::*;
+pub use table_function::*;
 pub use top_n::*;
 pub use trace::*;
 pub use values::*;
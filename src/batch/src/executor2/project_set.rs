@@ -0,0 +1,169 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_expr::table_function::{eval_project_set, ProjectSetSelectItem};
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+
+use crate::executor::ExecutorBuilder;
+use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2};
+
+/// `ProjectSetExecutor2` evaluates a select list containing table functions, which may return
+/// multiple rows for each input row. The first output column is the `projected_row_id`.
+pub struct ProjectSetExecutor2 {
+    select_list: Vec<ProjectSetSelectItem>,
+    child: BoxedExecutor2,
+    schema: Schema,
+    identity: String,
+}
+
+impl Executor2 for ProjectSetExecutor2 {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl ProjectSetExecutor2 {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        #[for_await]
+        for data_chunk in self.child.execute() {
+            let data_chunk = data_chunk?.compact()?;
+            let (columns, _) = eval_project_set(&self.select_list, &data_chunk)?;
+            let ret = DataChunk::builder().columns(columns).build();
+            if ret.cardinality() > 0 {
+                yield ret
+            }
+        }
+    }
+}
+
+impl BoxedExecutor2Builder for ProjectSetExecutor2 {
+    fn new_boxed_executor2(source: &ExecutorBuilder) -> Result<BoxedExecutor2> {
+        ensure!(source.plan_node().get_children().len() == 1);
+
+        let project_set_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::ProjectSet
+        )?;
+
+        let proto_child = source.plan_node.get_children().get(0).ok_or_else(|| {
+            RwError::from(ErrorCode::InternalError(String::from(
+                "Child interpreting error",
+            )))
+        })?;
+        let child_node = source.clone_for_plan(proto_child).build2()?;
+
+        let select_list: Vec<_> = project_set_node
+            .get_select_list()
+            .iter()
+            .map(ProjectSetSelectItem::from_prost)
+            .try_collect()?;
+
+        let fields = std::iter::once(Field::unnamed(DataType::Int64))
+            .chain(
+                select_list
+                    .iter()
+                    .map(|item| Field::unnamed(item.return_type())),
+            )
+            .collect();
+
+        Ok(Box::new(Self {
+            select_list,
+            child: child_node,
+            schema: Schema { fields },
+            identity: source.plan_node().get_identity().clone(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream::StreamExt;
+    use risingwave_common::array::DataChunkTestExt;
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::types::{DataType, ScalarImpl};
+    use risingwave_expr::expr::{Expression, InputRefExpression, LiteralExpression};
+    use risingwave_expr::table_function::new_generate_series;
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+
+    #[tokio::test]
+    async fn test_project_set_executor() {
+        let chunk = DataChunk::from_pretty(
+            "i
+             2
+             0
+             3",
+        );
+        let mut mock_executor =
+            MockExecutor::new(Schema::new(vec![Field::unnamed(DataType::Int32)]));
+        mock_executor.add(chunk);
+
+        let one = || LiteralExpression::new(DataType::Int32, Some(ScalarImpl::Int32(1))).boxed();
+        let series = new_generate_series(
+            vec![
+                one(),
+                InputRefExpression::new(DataType::Int32, 0).boxed(),
+                one(),
+            ],
+            DataType::Int32,
+        )
+        .unwrap();
+        let select_list = vec![
+            ProjectSetSelectItem::TableFunction(series),
+            ProjectSetSelectItem::Expr(InputRefExpression::new(DataType::Int32, 0).boxed()),
+        ];
+
+        let executor = Box::new(ProjectSetExecutor2 {
+            select_list,
+            child: Box::new(mock_executor),
+            schema: Schema::new(vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Int32),
+            ]),
+            identity: "ProjectSetExecutor2".to_string(),
+        });
+
+        let mut stream = executor.execute();
+        let chunk = stream.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk,
+            DataChunk::from_pretty(
+                "I i i
+                 0 1 2
+                 1 2 2
+                 0 1 3
+                 1 2 3
+                 2 3 3"
+            )
+        );
+        assert!(stream.next().await.is_none());
+    }
+}
@@ -0,0 +1,244 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::{DataChunk, Row};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::ErrorCode::{InternalError, InvalidInputSyntax};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_common::util::chunk_coalesce::DataChunkBuilder;
+use risingwave_common::util::session_window::SessionGap;
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+
+use crate::executor::ExecutorBuilder;
+use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2};
+
+/// [`SessionWindowExecutor2`] appends the `window_start` and `window_end` of the session to each
+/// row. All rows are buffered, since a session can only be decided after seeing all the rows of its
+/// partition. Rows whose time is null do not belong to any session and are discarded.
+pub struct SessionWindowExecutor2 {
+    child: BoxedExecutor2,
+    schema: Schema,
+    identity: String,
+    time_col_idx: usize,
+    gap: SessionGap,
+    partition_key_indices: Vec<usize>,
+}
+
+impl BoxedExecutor2Builder for SessionWindowExecutor2 {
+    fn new_boxed_executor2(source: &ExecutorBuilder) -> Result<BoxedExecutor2> {
+        ensure!(source.plan_node().get_children().len() == 1);
+
+        let session_window_node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::SessionWindow
+        )?;
+        let time_col_idx = session_window_node.get_time_col()?.column_idx as usize;
+        let gap = session_window_node.get_gap()?.into();
+        let gap = SessionGap::from_interval(gap)
+            .ok_or_else(|| InvalidInputSyntax(format!("invalid gap of session window: {}", gap)))?;
+        let partition_key_indices = session_window_node
+            .get_partition_keys()
+            .iter()
+            .map(|key| key.column_idx as usize)
+            .collect();
+
+        if let Some(child_plan) = source.plan_node().get_children().get(0) {
+            let child = source.clone_for_plan(child_plan).build2()?;
+            return Ok(Box::new(Self::new(
+                child,
+                time_col_idx,
+                gap,
+                partition_key_indices,
+                source.plan_node().get_identity().clone(),
+            )));
+        }
+        Err(InternalError("SessionWindow must have one child".to_string()).into())
+    }
+}
+
+impl SessionWindowExecutor2 {
+    fn new(
+        child: BoxedExecutor2,
+        time_col_idx: usize,
+        gap: SessionGap,
+        partition_key_indices: Vec<usize>,
+        identity: String,
+    ) -> Self {
+        let schema = child
+            .schema()
+            .clone()
+            .into_fields()
+            .into_iter()
+            .chain([
+                Field::with_name(DataType::Timestamp, "window_start"),
+                Field::with_name(DataType::Timestamp, "window_end"),
+            ])
+            .collect();
+        Self {
+            child,
+            schema,
+            identity,
+            time_col_idx,
+            gap,
+            partition_key_indices,
+        }
+    }
+}
+
+impl Executor2 for SessionWindowExecutor2 {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl SessionWindowExecutor2 {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        let mut partitions: HashMap<Row, Vec<Row>> = HashMap::new();
+
+        #[for_await]
+        for data_chunk in self.child.execute() {
+            let data_chunk = data_chunk?;
+            for row in data_chunk.rows() {
+                if row.value_at(self.time_col_idx).is_none() {
+                    continue;
+                }
+                let partition_key = Row(self
+                    .partition_key_indices
+                    .iter()
+                    .map(|idx| row.value_at(*idx).map(|v| v.into_scalar_impl()))
+                    .collect());
+                partitions
+                    .entry(partition_key)
+                    .or_default()
+                    .push(row.to_owned_row());
+            }
+        }
+
+        let time_of = |row: &Row| match &row[self.time_col_idx] {
+            Some(ScalarImpl::NaiveDateTime(time)) => Ok(*time),
+            _ => Err(RwError::from(InternalError(
+                "the time column of session window should be timestamp".to_string(),
+            ))),
+        };
+        let mut builder = DataChunkBuilder::with_default_size(self.schema.data_types());
+        for (_, mut rows) in partitions {
+            let times: Vec<_> = rows.iter().map(time_of).try_collect()?;
+            let order = (0..rows.len())
+                .sorted_by_key(|idx| times[*idx])
+                .collect_vec();
+            let sorted_times = order.iter().map(|idx| times[*idx]).collect_vec();
+            let windows = self.gap.assign(&sorted_times);
+
+            for (idx, (window_start, window_end)) in order.into_iter().zip_eq(windows) {
+                let row = &mut rows[idx];
+                row.0.push(Some(ScalarImpl::NaiveDateTime(window_start)));
+                row.0.push(Some(ScalarImpl::NaiveDateTime(window_end)));
+                if let Some(chunk) = builder.append_one_row_from_datums(row.values())? {
+                    yield chunk;
+                }
+            }
+        }
+        if let Some(chunk) = builder.consume_all()? {
+            yield chunk;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream::StreamExt;
+    use risingwave_common::array::DataChunkTestExt;
+    use risingwave_common::types::IntervalUnit;
+
+    use super::*;
+    use crate::executor::test_utils::MockExecutor;
+
+    #[tokio::test]
+    async fn test_session_window_executor() {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int32),
+                Field::unnamed(DataType::Timestamp),
+            ],
+        };
+        let mut mock_executor = MockExecutor::new(schema);
+        mock_executor.add(DataChunk::from_pretty(
+            "i TS
+             1 2022-01-01T10:00:00
+             1 2022-01-01T10:20:00
+             2 2022-01-01T10:05:00
+             1 2022-01-01T10:30:00
+             1 .",
+        ));
+        mock_executor.add(DataChunk::from_pretty(
+            "i TS
+             1 2022-01-01T10:10:00
+             2 2022-01-01T10:20:00",
+        ));
+
+        let executor = Box::new(SessionWindowExecutor2::new(
+            Box::new(mock_executor),
+            1,
+            SessionGap::from_interval(IntervalUnit::from_minutes(15)).unwrap(),
+            vec![0],
+            "SessionWindowExecutor2".to_string(),
+        ));
+        assert_eq!(
+            executor.schema().data_types(),
+            vec![
+                DataType::Int32,
+                DataType::Timestamp,
+                DataType::Timestamp,
+                DataType::Timestamp
+            ]
+        );
+
+        let mut stream = executor.execute();
+        let chunk = stream.next().await.unwrap().unwrap();
+        assert!(stream.next().await.is_none());
+
+        let rows = chunk
+            .rows()
+            .map(|row| row.to_owned_row())
+            .sorted()
+            .collect_vec();
+        let expected = DataChunk::from_pretty(
+            "i TS                  TS                  TS
+             1 2022-01-01T10:00:00 2022-01-01T10:00:00 2022-01-01T10:45:00
+             1 2022-01-01T10:10:00 2022-01-01T10:00:00 2022-01-01T10:45:00
+             1 2022-01-01T10:20:00 2022-01-01T10:00:00 2022-01-01T10:45:00
+             1 2022-01-01T10:30:00 2022-01-01T10:00:00 2022-01-01T10:45:00
+             2 2022-01-01T10:05:00 2022-01-01T10:05:00 2022-01-01T10:20:00
+             2 2022-01-01T10:20:00 2022-01-01T10:20:00 2022-01-01T10:35:00",
+        );
+        assert_eq!(
+            rows,
+            expected.rows().map(|row| row.to_owned_row()).collect_vec()
+        );
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use futures_async_stream::try_stream;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::DataChunk;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::util::chunk_coalesce::DEFAULT_CHUNK_BUFFER_SIZE;
+use risingwave_expr::table_function::{build_from_prost, BoxedTableFunction};
+use risingwave_pb::batch_plan::plan_node::NodeBody;
+
+use crate::executor::ExecutorBuilder;
+use crate::executor2::{BoxedDataChunkStream, BoxedExecutor2, BoxedExecutor2Builder, Executor2};
+
+/// `TableFunctionExecutor2` evaluates a table function with constant arguments, e.g.
+/// `SELECT * FROM generate_series(1, 10, 1)`.
+pub struct TableFunctionExecutor2 {
+    schema: Schema,
+    identity: String,
+    table_function: BoxedTableFunction,
+    chunk_size: usize,
+}
+
+impl Executor2 for TableFunctionExecutor2 {
+    fn schema(&self) -> &Schema {
+        &self.schema
+    }
+
+    fn identity(&self) -> &str {
+        &self.identity
+    }
+
+    fn execute(self: Box<Self>) -> BoxedDataChunkStream {
+        self.do_execute()
+    }
+}
+
+impl TableFunctionExecutor2 {
+    #[try_stream(boxed, ok = DataChunk, error = RwError)]
+    async fn do_execute(self: Box<Self>) {
+        let mut sets = self.table_function.eval(&DataChunk::new_dummy(1))?;
+        ensure!(sets.len() == 1);
+        let set = sets.pop().unwrap();
+
+        let return_type = self.table_function.return_type();
+        let mut start = 0;
+        while start < set.len() {
+            let end = (start + self.chunk_size).min(set.len());
+            let mut builder = return_type.create_array_builder(end - start)?;
+            for idx in start..end {
+                builder.append_array_element(&set, idx)?;
+            }
+            let column = Column::new(Arc::new(builder.finish()?));
+            yield DataChunk::builder().columns(vec![column]).build();
+            start = end;
+        }
+    }
+}
+
+impl BoxedExecutor2Builder for TableFunctionExecutor2 {
+    fn new_boxed_executor2(source: &ExecutorBuilder) -> Result<BoxedExecutor2> {
+        let node = try_match_expand!(
+            source.plan_node().get_node_body().unwrap(),
+            NodeBody::TableFunction
+        )?;
+
+        let table_function = build_from_prost(node.get_table_function()?)?;
+        let schema = Schema::new(vec![Field::unnamed(table_function.return_type())]);
+
+        Ok(Box::new(Self {
+            schema,
+            identity: source.plan_node().get_identity().clone(),
+            table_function,
+            chunk_size: DEFAULT_CHUNK_BUFFER_SIZE,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use risingwave_common::array::{Array, I64Array};
+    use risingwave_common::types::{DataType, ScalarImpl};
+    use risingwave_expr::expr::{Expression, LiteralExpression};
+    use risingwave_expr::table_function::new_generate_series;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_table_function_executor() {
+        let literal = |v: i64| LiteralExpression::new(DataType::Int64, Some(ScalarImpl::Int64(v)));
+        let table_function = new_generate_series(
+            vec![literal(1).boxed(), literal(10).boxed(), literal(1).boxed()],
+            DataType::Int64,
+        )
+        .unwrap();
+        let executor = Box::new(TableFunctionExecutor2 {
+            schema: Schema::new(vec![Field::unnamed(DataType::Int64)]),
+            identity: "TableFunctionExecutor2".to_string(),
+            table_function,
+            chunk_size: 4,
+        });
+
+        let chunks: Vec<_> = executor.execute().collect().await;
+        let values: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| chunk.unwrap())
+            .inspect(|chunk| assert!(chunk.cardinality() <= 4))
+            .flat_map(|chunk| {
+                let array: &I64Array = chunk.column_at(0).array_ref().into();
+                array.iter().collect::<Vec<_>>()
+            })
+            .collect();
+        assert_eq!(values, (1..=10).map(Some).collect::<Vec<_>>());
+    }
+}
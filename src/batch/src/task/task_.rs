@@ -18,6 +18,7 @@ use std::sync::Arc;
 use parking_lot::Mutex;
 use risingwave_common::array::DataChunk;
 use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_expr::expr::with_now_epoch;
 use risingwave_pb::batch_plan::{
     PlanFragment, TaskId as ProstTaskId, TaskOutputId as ProstOutputId,
 };
@@ -233,6 +234,7 @@ impl BatchTaskExecution {
             .extend(receivers.into_iter().map(Some));
         let failure = self.failure.clone();
         let task_id = self.task_id.clone();
+        let epoch = self.epoch;
         tokio::spawn(async move {
             trace!("Executing plan [{:?}]", task_id);
             let mut sender = sender;
@@ -242,7 +244,10 @@ impl BatchTaskExecution {
             let join_handle = tokio::spawn(async move {
                 // We should only pass a reference of sender to execution because we should only
                 // close it after task error has been set.
-                if let Err(e) = BatchTaskExecution::try_execute(exec, &mut sender)
+                // `now()` is evaluated to the time of the snapshot.
+                let execution =
+                    with_now_epoch(epoch, BatchTaskExecution::try_execute(exec, &mut sender));
+                if let Err(e) = execution
                     .instrument(tracing::trace_span!(
                         "batch_execute",
                         task_id = ?task_id.task_id,
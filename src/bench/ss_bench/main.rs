@@ -182,6 +182,7 @@ async fn main() {
                 sstable_store: hummock.inner().sstable_store(),
                 stats: state_store_stats.clone(),
                 is_share_buffer_compact: false,
+                bloom_key_extractor: hummock.inner().bloom_key_extractor().clone(),
             }),
             hummock.inner().local_version_manager().clone(),
         ));
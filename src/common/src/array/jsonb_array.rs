@@ -0,0 +1,197 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hash;
+
+use risingwave_pb::data::{Array as ProstArray, ArrayType};
+use serde_json::Value;
+
+use super::{ArrayMeta, NULL_VAL_FOR_HASH};
+use crate::array::value_reader::Utf8ValueReader;
+use crate::array::{
+    read_string_array, Array, ArrayBuilder, ArrayBuilderImpl, ArrayImpl, ArrayIterator,
+    Utf8ArrayBuilder,
+};
+use crate::buffer::{Bitmap, BitmapBuilder};
+use crate::error::ErrorCode::InternalError;
+use crate::error::{Result, RwError};
+use crate::types::{JsonbRef, JsonbVal, Scalar};
+
+/// `JsonbArray` stores parsed JSON values, so that the operators don't need to re-parse the text
+/// for every access.
+#[derive(Debug)]
+pub struct JsonbArray {
+    bitmap: Bitmap,
+    data: Vec<Value>,
+}
+
+#[derive(Debug)]
+pub struct JsonbArrayBuilder {
+    bitmap: BitmapBuilder,
+    data: Vec<Value>,
+}
+
+impl JsonbArray {
+    /// JSONB values are transferred as text, i.e. in the same layout as a `Utf8Array` but with
+    /// [`ArrayType::Jsonb`].
+    pub fn from_protobuf(array: &ProstArray, cardinality: usize) -> Result<ArrayImpl> {
+        let text_array =
+            read_string_array::<Utf8ArrayBuilder, Utf8ValueReader>(array, cardinality)?;
+        let mut builder = JsonbArrayBuilder::new(cardinality)?;
+        for text in text_array.as_utf8().iter() {
+            let value = text
+                .map(|s| {
+                    s.parse::<JsonbVal>().map_err(|e| {
+                        RwError::from(InternalError(format!(
+                            "failed to read jsonb from string: {}",
+                            e
+                        )))
+                    })
+                })
+                .transpose()?;
+            builder.append(value.as_ref().map(|v| v.as_scalar_ref()))?;
+        }
+        Ok(builder.finish()?.into())
+    }
+}
+
+impl Array for JsonbArray {
+    type Builder = JsonbArrayBuilder;
+    type Iter<'a> = ArrayIterator<'a, Self>;
+    type OwnedItem = JsonbVal;
+    type RefItem<'a> = JsonbRef<'a>;
+
+    fn value_at(&self, idx: usize) -> Option<Self::RefItem<'_>> {
+        if !self.is_null(idx) {
+            Some(JsonbRef::from_value(&self.data[idx]))
+        } else {
+            None
+        }
+    }
+
+    unsafe fn value_at_unchecked(&self, idx: usize) -> Option<Self::RefItem<'_>> {
+        if !self.is_null_unchecked(idx) {
+            Some(JsonbRef::from_value(self.data.get_unchecked(idx)))
+        } else {
+            None
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        ArrayIterator::new(self)
+    }
+
+    fn to_protobuf(&self) -> ProstArray {
+        let mut builder = Utf8ArrayBuilder::new(self.len()).unwrap();
+        for v in self.iter() {
+            builder.append(v.map(|v| v.to_string()).as_deref()).unwrap();
+        }
+        let mut array = builder.finish().unwrap().to_protobuf();
+        array.array_type = ArrayType::Jsonb as i32;
+        array
+    }
+
+    fn null_bitmap(&self) -> &Bitmap {
+        &self.bitmap
+    }
+
+    fn set_bitmap(&mut self, bitmap: Bitmap) {
+        self.bitmap = bitmap;
+    }
+
+    fn hash_at<H: std::hash::Hasher>(&self, idx: usize, state: &mut H) {
+        if !self.is_null(idx) {
+            JsonbRef::from_value(&self.data[idx]).hash(state);
+        } else {
+            NULL_VAL_FOR_HASH.hash(state);
+        }
+    }
+
+    fn create_builder(&self, capacity: usize) -> Result<ArrayBuilderImpl> {
+        let array_builder = JsonbArrayBuilder::new(capacity)?;
+        Ok(ArrayBuilderImpl::Jsonb(array_builder))
+    }
+}
+
+impl ArrayBuilder for JsonbArrayBuilder {
+    type ArrayType = JsonbArray;
+
+    fn with_meta(capacity: usize, _meta: ArrayMeta) -> Result<Self> {
+        Ok(Self {
+            bitmap: BitmapBuilder::with_capacity(capacity),
+            data: Vec::with_capacity(capacity),
+        })
+    }
+
+    fn append(&mut self, value: Option<JsonbRef<'_>>) -> Result<()> {
+        match value {
+            Some(x) => {
+                self.bitmap.append(true);
+                self.data.push(x.value().clone());
+            }
+            None => {
+                self.bitmap.append(false);
+                self.data.push(Value::Null);
+            }
+        };
+        Ok(())
+    }
+
+    fn append_array(&mut self, other: &JsonbArray) -> Result<()> {
+        for bit in other.bitmap.iter() {
+            self.bitmap.append(bit);
+        }
+        self.data.extend_from_slice(&other.data);
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<Self::ArrayType> {
+        Ok(JsonbArray {
+            bitmap: self.bitmap.finish(),
+            data: self.data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jsonb_array_protobuf() {
+        let values = [Some(r#"{"a": [1, "b"]}"#), None, Some("null"), Some("2")]
+            .map(|v| v.map(|s| s.parse::<JsonbVal>().unwrap()));
+        let mut builder = JsonbArrayBuilder::new(values.len()).unwrap();
+        for v in &values {
+            builder
+                .append(v.as_ref().map(|v| v.as_scalar_ref()))
+                .unwrap();
+        }
+        let array = builder.finish().unwrap();
+        assert_eq!(array.value_at(2).unwrap().type_name(), "null");
+        assert!(array.value_at(1).is_none());
+
+        let prost_array = array.to_protobuf();
+        assert_eq!(prost_array.array_type, ArrayType::Jsonb as i32);
+        let decoded = ArrayImpl::from_protobuf(&prost_array, values.len()).unwrap();
+        let decoded = decoded.as_jsonb();
+        for (idx, v) in values.iter().enumerate() {
+            assert_eq!(decoded.value_at(idx), v.as_ref().map(|v| v.as_scalar_ref()));
+        }
+    }
+}
@@ -267,8 +267,8 @@ pub struct ListValue {
 }
 
 impl fmt::Display for ListValue {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        Ok(())
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_scalar_ref())
     }
 }
 
@@ -288,6 +288,10 @@ impl ListValue {
     pub fn new(values: Vec<Datum>) -> Self {
         Self { values }
     }
+
+    pub fn values(&self) -> &[Datum] {
+        &self.values
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -305,6 +309,47 @@ impl<'a> ListRef<'a> {
             ListRef::ValueRef { val } => val.values.iter().map(to_datum_ref).collect(),
         }
     }
+
+    /// Formats the list in PostgreSQL array syntax, e.g. `{1,NULL,"a b"}`, where the elements are
+    /// formatted by `elem_fmt`. Nested lists are formatted recursively.
+    pub fn fmt_pg_array(
+        &self,
+        f: &mut impl fmt::Write,
+        elem_fmt: &impl Fn(ScalarRefImpl<'_>) -> String,
+    ) -> fmt::Result {
+        f.write_char('{')?;
+        for (i, value) in self.values_ref().into_iter().enumerate() {
+            if i > 0 {
+                f.write_char(',')?;
+            }
+            match value {
+                None => f.write_str("NULL")?,
+                Some(ScalarRefImpl::List(list)) => list.fmt_pg_array(f, elem_fmt)?,
+                Some(scalar) => write_pg_array_element(f, &elem_fmt(scalar))?,
+            }
+        }
+        f.write_char('}')
+    }
+}
+
+/// Writes an element in PostgreSQL array syntax. It's double-quoted if it's empty, looks like
+/// `NULL`, or contains delimiters, quotes, backslashes or whitespaces.
+fn write_pg_array_element(f: &mut impl fmt::Write, s: &str) -> fmt::Result {
+    let need_quote = s.is_empty()
+        || s.eq_ignore_ascii_case("null")
+        || s.chars()
+            .any(|c| matches!(c, '{' | '}' | ',' | '"' | '\\') || c.is_ascii_whitespace());
+    if !need_quote {
+        return f.write_str(s);
+    }
+    f.write_char('"')?;
+    for c in s.chars() {
+        if c == '"' || c == '\\' {
+            f.write_char('\\')?;
+        }
+        f.write_char(c)?;
+    }
+    f.write_char('"')
 }
 
 impl Hash for ListRef<'_> {
@@ -364,8 +409,8 @@ impl Debug for ListRef<'_> {
 }
 
 impl Display for ListRef<'_> {
-    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        todo!()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_pg_array(f, &|scalar| scalar.to_string())
     }
 }
 
@@ -628,4 +673,28 @@ mod tests {
             ListValue::new(vec![Some(1.into()), None]),
         );
     }
+
+    #[test]
+    fn test_list_display() {
+        use crate::types::ScalarImpl;
+
+        let value = ListValue::new(vec![
+            Some(ScalarImpl::Utf8("a".into())),
+            None,
+            Some(ScalarImpl::Utf8("".into())),
+            Some(ScalarImpl::Utf8("NULL".into())),
+            Some(ScalarImpl::Utf8("b c".into())),
+            Some(ScalarImpl::Utf8("{\"d\\}".into())),
+        ]);
+        assert_eq!(value.to_string(), r#"{a,NULL,"","NULL","b c","{\"d\\}"}"#);
+
+        let nested = ListValue::new(vec![
+            Some(ScalarImpl::List(ListValue::new(vec![
+                Some(1.into()),
+                Some(2.into()),
+            ]))),
+            Some(ScalarImpl::List(ListValue::new(vec![]))),
+        ]);
+        assert_eq!(nested.to_string(), "{{1,2},{}}");
+    }
 }
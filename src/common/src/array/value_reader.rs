@@ -18,7 +18,8 @@ use std::str::{from_utf8, FromStr};
 use byteorder::{BigEndian, ReadBytesExt};
 
 use crate::array::{
-    Array, ArrayBuilder, DecimalArrayBuilder, PrimitiveArrayItemType, Utf8ArrayBuilder,
+    Array, ArrayBuilder, BytesArrayBuilder, DecimalArrayBuilder, PrimitiveArrayItemType,
+    Utf8ArrayBuilder,
 };
 use crate::error::ErrorCode::InternalError;
 use crate::error::{ErrorCode, Result, RwError};
@@ -73,6 +74,14 @@ impl VarSizedValueReader<Utf8ArrayBuilder> for Utf8ValueReader {
     }
 }
 
+pub struct BytesValueReader {}
+
+impl VarSizedValueReader<BytesArrayBuilder> for BytesValueReader {
+    fn read(buf: &[u8]) -> Result<&[u8]> {
+        Ok(buf)
+    }
+}
+
 pub struct DecimalValueReader {}
 
 impl VarSizedValueReader<DecimalArrayBuilder> for DecimalValueReader {
@@ -27,7 +27,7 @@ use crate::array::{
 };
 use crate::error::Result;
 use crate::types::{
-    DataType, Datum, Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper,
+    DataType, Datum, Decimal, IntervalUnit, JsonbRef, NaiveDateTimeWrapper, NaiveDateWrapper,
     NaiveTimeWrapper, OrderedF32, OrderedF64, ScalarRef, ToOwnedDatum,
 };
 use crate::util::hash_util::CRC32FastBuilder;
@@ -360,6 +360,20 @@ impl<'a> HashKeySerDe<'a> for &'a str {
     }
 }
 
+impl<'a> HashKeySerDe<'a> for &'a [u8] {
+    type S = Vec<u8>;
+
+    /// This should never be called
+    fn serialize(self) -> Self::S {
+        panic!("Should not serialize bytes for hash!")
+    }
+
+    /// This should never be called
+    fn deserialize<R: Read>(_source: &mut R) -> Self {
+        panic!("Should not serialize bytes for hash!")
+    }
+}
+
 impl HashKeySerDe<'_> for NaiveDateWrapper {
     type S = [u8; 4];
 
@@ -443,6 +457,20 @@ impl<'a> HashKeySerDe<'a> for ListRef<'a> {
     }
 }
 
+impl<'a> HashKeySerDe<'a> for JsonbRef<'a> {
+    type S = Vec<u8>;
+
+    /// This should never be called
+    fn serialize(self) -> Self::S {
+        panic!("Should not serialize jsonb for hash!")
+    }
+
+    /// This should never be called
+    fn deserialize<R: Read>(_source: &mut R) -> Self {
+        panic!("Should not serialize jsonb for hash!")
+    }
+}
+
 pub struct FixedSizeKeySerializer<const N: usize> {
     buffer: [u8; N],
     null_bitmap: u8,
@@ -666,8 +694,9 @@ mod tests {
     use crate::array;
     use crate::array::column::Column;
     use crate::array::{
-        ArrayRef, BoolArray, DataChunk, DecimalArray, F32Array, F64Array, I16Array, I32Array,
-        I32ArrayBuilder, I64Array, NaiveDateArray, NaiveDateTimeArray, NaiveTimeArray, Utf8Array,
+        ArrayRef, BoolArray, BytesArray, DataChunk, DecimalArray, F32Array, F64Array, I16Array,
+        I32Array, I32ArrayBuilder, I64Array, NaiveDateArray, NaiveDateTimeArray, NaiveTimeArray,
+        Utf8Array,
     };
     use crate::hash::{
         HashKey, Key128, Key16, Key256, Key32, Key64, KeySerialized, PrecomputedBuildHasher,
@@ -696,6 +725,7 @@ mod tests {
                 capacity,
                 seed + 10,
             )),
+            Column::new(seed_rand_array_ref::<BytesArray>(capacity, seed + 11)),
         ];
 
         DataChunk::try_from(columns).expect("Failed to create data chunk")
@@ -832,6 +862,7 @@ mod tests {
     #[test]
     fn test_var_length_hash_key() {
         do_test::<KeySerialized, _>(vec![0, 7], generate_random_data_chunk);
+        do_test::<KeySerialized, _>(vec![11, 2], generate_random_data_chunk);
     }
 
     fn generate_decimal_test_data() -> DataChunk {
@@ -27,8 +27,8 @@ use rand::{Rng, SeedableRng};
 
 use crate::array::{Array, ArrayBuilder, ArrayRef, ListValue, StructValue};
 use crate::types::{
-    Decimal, IntervalUnit, NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper, NativeType,
-    Scalar,
+    Decimal, IntervalUnit, JsonbVal, NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper,
+    NativeType, Scalar,
 };
 
 pub trait RandValue {
@@ -53,6 +53,13 @@ impl RandValue for String {
     }
 }
 
+impl RandValue for Box<[u8]> {
+    fn rand_value<R: Rng>(rand: &mut R) -> Self {
+        let len = rand.gen_range(1..=10);
+        (0..len).map(|_| rand.gen::<u8>()).collect()
+    }
+}
+
 impl RandValue for Decimal {
     fn rand_value<R: Rng>(rand: &mut R) -> Self {
         Decimal::from_f64((rand.gen::<u32>() as f64) + 0.1f64).unwrap()
@@ -115,6 +122,12 @@ impl RandValue for ListValue {
     }
 }
 
+impl RandValue for JsonbVal {
+    fn rand_value<R: rand::Rng>(rand: &mut R) -> Self {
+        JsonbVal::from_value(rand.gen::<i64>().into())
+    }
+}
+
 pub fn rand_array<A, R>(rand: &mut R, size: usize) -> A
 where
     A: Array,
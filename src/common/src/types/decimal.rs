@@ -17,7 +17,7 @@ use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
 
 use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedSub};
 pub use rust_decimal::prelude::{FromPrimitive, FromStr, ToPrimitive};
-use rust_decimal::{Decimal as RustDecimal, Error, RoundingStrategy};
+use rust_decimal::{Decimal as RustDecimal, Error, MathematicalOps, RoundingStrategy};
 
 #[derive(Debug, Copy, Clone, PartialEq, Hash, Eq, Ord, PartialOrd)]
 pub enum Decimal {
@@ -421,6 +421,31 @@ impl Decimal {
         }
     }
 
+    #[must_use]
+    pub fn ceil(&self) -> Self {
+        match self {
+            Self::Normalized(d) => Self::Normalized(d.ceil()),
+            d => *d,
+        }
+    }
+
+    #[must_use]
+    pub fn floor(&self) -> Self {
+        match self {
+            Self::Normalized(d) => Self::Normalized(d.floor()),
+            d => *d,
+        }
+    }
+
+    /// Returns the square root, or `None` if the value is negative.
+    pub fn sqrt(&self) -> Option<Self> {
+        match self {
+            Self::Normalized(d) => d.sqrt().map(Self::Normalized),
+            Self::NaN | Self::PositiveINF => Some(*self),
+            Self::NegativeINF => None,
+        }
+    }
+
     pub fn from_i128_with_scale(num: i128, scale: u32) -> Self {
         Decimal::Normalized(RustDecimal::from_i128_with_scale(num, scale))
     }
@@ -0,0 +1,302 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+use bytes::{Buf, BufMut};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{Scalar, ScalarImpl, ScalarPartialOrd, ScalarRef};
+
+/// Owned JSONB value, the scalar type of [`DataType::Jsonb`](super::DataType::Jsonb).
+///
+/// Objects are backed by an ordered map, so the textual representation of a value is canonical:
+/// keys are sorted and duplicated keys are collapsed. Equality, ordering and hashing are all
+/// defined on top of this representation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonbVal(Box<Value>);
+
+/// Reference to a JSONB value, which may point into a [`JsonbArray`](crate::array::JsonbArray).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsonbRef<'a>(&'a Value);
+
+impl JsonbVal {
+    pub fn from_value(value: Value) -> Self {
+        Self(Box::new(value))
+    }
+
+    pub fn into_value(self) -> Value {
+        *self.0
+    }
+
+    /// The value of JSON `null`, which is different from SQL `NULL`.
+    pub fn null() -> Self {
+        Self::from_value(Value::Null)
+    }
+
+    /// Deserialize the value from memcomparable format.
+    pub fn memcmp_deserialize(
+        deserializer: &mut memcomparable::Deserializer<impl Buf>,
+    ) -> memcomparable::Result<Self> {
+        let text = String::deserialize(deserializer)?;
+        text.parse()
+            .map_err(|e: serde_json::Error| memcomparable::Error::Message(e.to_string()))
+    }
+}
+
+impl<'a> JsonbRef<'a> {
+    pub fn from_value(value: &'a Value) -> Self {
+        Self(value)
+    }
+
+    pub fn value(&self) -> &'a Value {
+        self.0
+    }
+
+    /// Serialize the value into memcomparable format. Values are encoded as their canonical
+    /// text, which keeps the encoding consistent with [`Ord`].
+    pub fn memcmp_serialize(
+        &self,
+        serializer: &mut memcomparable::Serializer<impl BufMut>,
+    ) -> memcomparable::Result<()> {
+        self.0.to_string().serialize(serializer)
+    }
+
+    /// Returns the name of the top-level JSON type, as `jsonb_typeof` in PostgreSQL.
+    pub fn type_name(&self) -> &'static str {
+        match self.0 {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(_) => "number",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(_) => "object",
+        }
+    }
+
+    /// Gets the field of an object by key. Returns `None` if the key is absent or `self` is not
+    /// an object.
+    pub fn access_object_field(&self, key: &str) -> Option<JsonbRef<'a>> {
+        self.0.as_object()?.get(key).map(JsonbRef)
+    }
+
+    /// Gets the element of an array by index. Negative indexes count from the end of the array.
+    /// Returns `None` if the index is out of range or `self` is not an array.
+    pub fn access_array_element(&self, idx: i64) -> Option<JsonbRef<'a>> {
+        let array = self.0.as_array()?;
+        let idx = if idx < 0 {
+            array.len().checked_sub(idx.unsigned_abs() as usize)?
+        } else {
+            idx as usize
+        };
+        array.get(idx).map(JsonbRef)
+    }
+
+    /// Follows a path of object keys or array indexes, as `#>` in PostgreSQL.
+    pub fn access_path<'b>(&self, path: impl IntoIterator<Item = &'b str>) -> Option<JsonbRef<'a>> {
+        let mut current = *self;
+        for key in path {
+            current = match current.0 {
+                Value::Object(_) => current.access_object_field(key)?,
+                Value::Array(_) => current.access_array_element(key.trim().parse().ok()?)?,
+                _ => return None,
+            };
+        }
+        Some(current)
+    }
+
+    /// Converts the value to text, as `->>` in PostgreSQL: strings are unquoted and JSON `null`
+    /// becomes SQL `NULL`.
+    pub fn as_text(&self) -> Option<String> {
+        match self.0 {
+            Value::Null => None,
+            Value::String(s) => Some(s.clone()),
+            other => Some(other.to_string()),
+        }
+    }
+
+    /// Returns the elements if `self` is an array.
+    pub fn array_elements(&self) -> Option<impl Iterator<Item = JsonbRef<'a>>> {
+        Some(self.0.as_array()?.iter().map(JsonbRef))
+    }
+
+    /// Whether `self` contains `other`, as `@>` in PostgreSQL.
+    ///
+    /// An object contains another one if it has all of its keys with contained values. An array
+    /// contains another one if every element of the latter is contained by some element of the
+    /// former. As a special case, an array also contains a scalar that equals one of its elements.
+    pub fn contains(&self, other: JsonbRef<'_>) -> bool {
+        match (self.0, other.0) {
+            (Value::Object(left), Value::Object(right)) => right.iter().all(|(k, r)| {
+                left.get(k)
+                    .map_or(false, |l| JsonbRef(l).contains(JsonbRef(r)))
+            }),
+            (Value::Array(left), Value::Array(right)) => right.iter().all(|r| {
+                left.iter().any(|l| match (l, r) {
+                    (Value::Array(_), Value::Array(_)) | (Value::Object(_), Value::Object(_)) => {
+                        JsonbRef(l).contains(JsonbRef(r))
+                    }
+                    _ => l == r,
+                })
+            }),
+            (Value::Array(left), right) if !right.is_object() => left.iter().any(|l| l == right),
+            (left, right) => left == right,
+        }
+    }
+}
+
+impl FromStr for JsonbVal {
+    type Err = serde_json::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        serde_json::from_str(s).map(Self::from_value)
+    }
+}
+
+impl Display for JsonbVal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Display for JsonbRef<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self.0, f)
+    }
+}
+
+impl PartialOrd for JsonbVal {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonbVal {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.as_scalar_ref().cmp(&other.as_scalar_ref())
+    }
+}
+
+impl PartialOrd for JsonbRef<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for JsonbRef<'_> {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        if self.0 == other.0 {
+            return std::cmp::Ordering::Equal;
+        }
+        self.0.to_string().cmp(&other.0.to_string())
+    }
+}
+
+impl Hash for JsonbVal {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_scalar_ref().hash(state)
+    }
+}
+
+impl Hash for JsonbRef<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.to_string().hash(state)
+    }
+}
+
+/// Implement `Scalar` for `JsonbVal`.
+impl Scalar for JsonbVal {
+    type ScalarRefType<'a> = JsonbRef<'a>;
+
+    fn as_scalar_ref(&self) -> JsonbRef<'_> {
+        JsonbRef(&self.0)
+    }
+
+    fn to_scalar_value(self) -> ScalarImpl {
+        ScalarImpl::Jsonb(self)
+    }
+}
+
+/// Implement `ScalarRef` for `JsonbRef`.
+impl<'a> ScalarRef<'a> for JsonbRef<'a> {
+    type ScalarType = JsonbVal;
+
+    fn to_owned_scalar(&self) -> JsonbVal {
+        JsonbVal::from_value(self.0.clone())
+    }
+}
+
+impl ScalarPartialOrd for JsonbVal {
+    fn scalar_cmp(&self, other: Self::ScalarRefType<'_>) -> Option<std::cmp::Ordering> {
+        self.as_scalar_ref().partial_cmp(&other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jsonb(s: &str) -> JsonbVal {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_jsonb_access() {
+        let v = jsonb(r#"{"a": [1, {"b": "foo"}, null], "c": 2.5}"#);
+        let v = v.as_scalar_ref();
+        assert_eq!(v.type_name(), "object");
+        assert_eq!(v.access_object_field("c").unwrap().to_string(), "2.5");
+        assert!(v.access_object_field("d").is_none());
+
+        let a = v.access_object_field("a").unwrap();
+        assert_eq!(a.type_name(), "array");
+        assert_eq!(a.access_array_element(0).unwrap().to_string(), "1");
+        assert_eq!(a.access_array_element(-1).unwrap().as_text(), None);
+        assert!(a.access_array_element(3).is_none());
+        assert!(a.access_array_element(-4).is_none());
+        assert_eq!(a.array_elements().unwrap().count(), 3);
+
+        let b = v.access_path(["a", "1", "b"]).unwrap();
+        assert_eq!(b.to_string(), r#""foo""#);
+        assert_eq!(b.as_text().unwrap(), "foo");
+        assert!(v.access_path(["a", "x"]).is_none());
+    }
+
+    #[test]
+    fn test_jsonb_contains() {
+        let check = |l: &str, r: &str| jsonb(l).as_scalar_ref().contains(jsonb(r).as_scalar_ref());
+        assert!(check(r#"{"a": 1, "b": [1, 2]}"#, r#"{"b": [2]}"#));
+        assert!(!check(r#"{"a": 1, "b": [1, 2]}"#, r#"{"b": 2}"#));
+        assert!(check(r#"[1, 2, [3, 4]]"#, r#"[[4], 1]"#));
+        assert!(!check(r#"[1, 2, [3, 4]]"#, r#"[3]"#));
+        assert!(check(r#"["foo", "bar"]"#, r#""foo""#));
+        assert!(!check(r#""foo""#, r#"["foo"]"#));
+        assert!(check("1", "1"));
+    }
+
+    #[test]
+    fn test_jsonb_memcmp_encoding() {
+        let v = jsonb(r#"{"b": [1, true], "a": null}"#);
+        assert_eq!(v.to_string(), r#"{"a":null,"b":[1,true]}"#);
+
+        let mut serializer = memcomparable::Serializer::new(vec![]);
+        v.as_scalar_ref().memcmp_serialize(&mut serializer).unwrap();
+        let encoded = serializer.into_inner();
+        let mut deserializer = memcomparable::Deserializer::new(&encoded[..]);
+        assert_eq!(JsonbVal::memcmp_deserialize(&mut deserializer).unwrap(), v);
+    }
+}
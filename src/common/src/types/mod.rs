@@ -32,12 +32,14 @@ pub use scalar_impl::*;
 mod chrono_wrapper;
 mod decimal;
 pub mod interval;
+mod jsonb;
 
 mod ordered_float;
 use chrono::{Datelike, Timelike};
 pub use chrono_wrapper::{NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper};
 pub use decimal::Decimal;
 pub use interval::*;
+pub use jsonb::{JsonbRef, JsonbVal};
 pub use ordered_float::IntoOrdered;
 use paste::paste;
 
@@ -65,6 +67,8 @@ pub enum DataType {
     Interval,
     Struct { fields: Arc<[DataType]> },
     List { datatype: Box<DataType> },
+    Jsonb,
+    Bytea,
 }
 
 const DECIMAL_DEFAULT_PRECISION: u32 = 20;
@@ -104,6 +108,8 @@ impl From<&ProstDataType> for DataType {
             TypeName::List => DataType::List {
                 datatype: Box::new(DataType::Int32),
             },
+            TypeName::Jsonb => DataType::Jsonb,
+            TypeName::Bytea => DataType::Bytea,
         }
     }
 }
@@ -135,6 +141,8 @@ impl DataType {
                 },
             )?
             .into(),
+            DataType::Jsonb => JsonbArrayBuilder::new(capacity)?.into(),
+            DataType::Bytea => BytesArrayBuilder::new(capacity)?.into(),
         })
     }
 
@@ -155,6 +163,8 @@ impl DataType {
             DataType::Interval => TypeName::Interval,
             DataType::Struct { .. } => TypeName::Struct,
             DataType::List { .. } => TypeName::List,
+            DataType::Jsonb => TypeName::Jsonb,
+            DataType::Bytea => TypeName::Bytea,
         }
     }
 
@@ -184,6 +194,20 @@ impl DataType {
             DataType::Interval => DataSize::Variable,
             DataType::Struct { .. } => DataSize::Variable,
             DataType::List { .. } => DataSize::Variable,
+            DataType::Jsonb => DataSize::Variable,
+            DataType::Bytea => DataSize::Variable,
+        }
+    }
+
+    /// Returns the size of the memcomparable encoding of a non-null value of this type, or `None`
+    /// if the size is variable.
+    pub fn memcmp_fixed_size(&self) -> Option<usize> {
+        match self {
+            DataType::Boolean => Some(1),
+            DataType::Int16 => Some(2),
+            DataType::Int32 | DataType::Float32 => Some(4),
+            DataType::Int64 | DataType::Float64 | DataType::Timestampz => Some(8),
+            _ => None,
         }
     }
 
@@ -274,7 +298,9 @@ macro_rules! for_all_scalar_variants {
             { NaiveDateTime, naivedatetime, NaiveDateTimeWrapper, NaiveDateTimeWrapper },
             { NaiveTime, naivetime, NaiveTimeWrapper, NaiveTimeWrapper },
             { Struct, struct, StructValue, StructRef<'scalar> },
-            { List, list, ListValue, ListRef<'scalar> }
+            { List, list, ListValue, ListRef<'scalar> },
+            { Jsonb, jsonb, JsonbVal, JsonbRef<'scalar> },
+            { Bytea, bytea, Box<[u8]>, &'scalar [u8] }
         }
     };
 }
@@ -544,6 +570,8 @@ impl std::hash::Hash for ScalarImpl {
                     Self::NaiveTime(naivetime) => naivetime.hash(state),
                     Self::Struct(v) => v.hash(state),
                     Self::List(v) => v.hash(state),
+                    Self::Jsonb(v) => v.hash(state),
+                    Self::Bytea(v) => v.hash(state),
                 }
             };
         }
@@ -551,13 +579,25 @@ impl std::hash::Hash for ScalarImpl {
     }
 }
 
+// Byte strings don't implement `Display`, so they are formatted in the hex format of PostgreSQL,
+// e.g. `\xdeadbeef`.
+macro_rules! fmt_scalar_variant {
+    ($f:ident,Bytea, $inner:ident) => {{
+        write!($f, "\\x")?;
+        $inner.iter().try_for_each(|b| write!($f, "{:02x}", b))
+    }};
+    ($f:ident, $variant_name:ident, $inner:ident) => {
+        Display::fmt($inner, $f)
+    };
+}
+
 impl Display for ScalarImpl {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         macro_rules! impl_display_fmt {
             ([], $( { $variant_name:ident, $suffix_name:ident, $scalar:ty, $scalar_ref:ty } ),*) => {
                 match self {
                     $( Self::$variant_name(ref inner) => {
-                        Display::fmt(inner, f)
+                        fmt_scalar_variant!(f, $variant_name, inner)
                     }, )*
                 }
             }
@@ -573,7 +613,7 @@ impl Display for ScalarRefImpl<'_> {
             ([], $( { $variant_name:ident, $suffix_name:ident, $scalar:ty, $scalar_ref:ty } ),*) => {
                 match self {
                     $( Self::$variant_name(inner) => {
-                        Display::fmt(inner, f)
+                        fmt_scalar_variant!(f, $variant_name, inner)
                     }, )*
                 }
             }
@@ -608,6 +648,8 @@ impl ScalarRefImpl<'_> {
             &Self::NaiveTime(v) => {
                 ser.serialize_naivetime(v.0.num_seconds_from_midnight(), v.0.nanosecond())?
             }
+            Self::Jsonb(v) => v.memcmp_serialize(ser)?,
+            Self::Bytea(v) => serde::Serializer::serialize_bytes(&mut *ser, v)?,
             _ => {
                 panic!("Type is unable to be serialized.")
             }
@@ -662,6 +704,8 @@ impl ScalarImpl {
                 let days = de.deserialize_naivedate()?;
                 NaiveDateWrapper::with_days(days)?
             }),
+            Ty::Jsonb => Self::Jsonb(JsonbVal::memcmp_deserialize(de)?),
+            Ty::Bytea => Self::Bytea(de.read_bytes()?.into()),
             _ => {
                 panic!("Type is unable to be deserialized.")
             }
@@ -749,4 +793,28 @@ mod tests {
         assert!(decoded_floats.is_sorted());
         assert_eq!(floats, decoded_floats);
     }
+
+    #[test]
+    fn test_bytea() {
+        let values = [&b""[..], b"\x00", b"\x00\xff", b"\x01"].map(|v| ScalarImpl::Bytea(v.into()));
+        assert_eq!(values[2].to_string(), "\\x00ff");
+        assert_eq!(values[0].as_scalar_ref_impl().to_string(), "\\x");
+
+        let memcomparables = values
+            .iter()
+            .map(|v| {
+                let mut serializer = memcomparable::Serializer::new(vec![]);
+                v.serialize(&mut serializer).unwrap();
+                serializer.into_inner()
+            })
+            .collect_vec();
+        assert!(memcomparables.is_sorted());
+        for (v, encoded) in values.iter().zip_eq(memcomparables) {
+            let mut deserializer = memcomparable::Deserializer::new(encoded.as_slice());
+            assert_eq!(
+                &ScalarImpl::deserialize(DataType::Bytea, &mut deserializer).unwrap(),
+                v
+            );
+        }
+    }
 }
@@ -43,6 +43,16 @@ impl Epoch {
     pub fn as_system_time(&self) -> SystemTime {
         *UNIX_SINGULARITY_DATE_EPOCH + Duration::from_millis(self.0)
     }
+
+    /// Returns the epoch of the given milliseconds since the UNIX epoch, or `None` if it's earlier
+    /// than the singularity date.
+    pub fn from_unix_millis(millis: u64) -> Option<Self> {
+        let singularity_millis = UNIX_SINGULARITY_DATE_EPOCH
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64;
+        millis.checked_sub(singularity_millis).map(Self)
+    }
 }
 
 impl From<u64> for Epoch {
@@ -70,4 +80,13 @@ mod tests {
         let singularity_st = SystemTime::from(singularity_dt);
         assert_eq!(singularity_st, *UNIX_SINGULARITY_DATE_EPOCH);
     }
+
+    #[test]
+    fn test_epoch_from_unix_millis() {
+        let utc = Utc.ymd(2021, 4, 1).and_hms_milli(0, 0, 1, 500);
+        let epoch = Epoch::from_unix_millis(utc.timestamp_millis() as u64).unwrap();
+        assert_eq!(epoch, Epoch(1500));
+        assert_eq!(epoch.as_system_time(), SystemTime::from(utc));
+        assert!(Epoch::from_unix_millis(0).is_none());
+    }
 }
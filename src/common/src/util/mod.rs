@@ -28,6 +28,7 @@ pub mod env_var;
 pub mod hash_util;
 pub mod ordered;
 pub mod prost;
+pub mod session_window;
 pub mod sort_util;
 #[macro_use]
 pub mod try_match;
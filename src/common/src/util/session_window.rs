@@ -0,0 +1,96 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Session windows shared by the batch and the streaming executors.
+
+use chrono::Duration;
+
+use crate::types::{IntervalUnit, NaiveDateTimeWrapper};
+
+/// The gap of session windows. Two adjacent times of a partition are in the same session iff they
+/// are less than the gap apart. A session spans from its first time to its last time plus the gap.
+#[derive(Clone, Copy, Debug)]
+pub struct SessionGap(Duration);
+
+impl SessionGap {
+    /// Returns `None` if `gap` is not positive or has months, whose length is not fixed.
+    pub fn from_interval(gap: IntervalUnit) -> Option<Self> {
+        if gap.get_months() != 0 {
+            return None;
+        }
+        let ms = (gap.get_days() as i64)
+            .checked_mul(24 * 60 * 60 * 1000)?
+            .checked_add(gap.get_ms())?;
+        (ms > 0).then(|| Self(Duration::milliseconds(ms)))
+    }
+
+    /// Returns whether the adjacent times `prev <= next` are in the same session.
+    pub fn connects(&self, prev: NaiveDateTimeWrapper, next: NaiveDateTimeWrapper) -> bool {
+        next.0 - prev.0 < self.0
+    }
+
+    /// Returns the `window_end` of the session whose last time is `last`.
+    pub fn window_end(&self, last: NaiveDateTimeWrapper) -> NaiveDateTimeWrapper {
+        NaiveDateTimeWrapper::new(last.0 + self.0)
+    }
+
+    /// Returns the `(window_start, window_end)` of each time in `times`, which must be sorted.
+    pub fn assign(
+        &self,
+        times: &[NaiveDateTimeWrapper],
+    ) -> Vec<(NaiveDateTimeWrapper, NaiveDateTimeWrapper)> {
+        let mut windows = Vec::with_capacity(times.len());
+        let mut session_begin = 0;
+        for idx in 1..=times.len() {
+            if idx == times.len() || !self.connects(times[idx - 1], times[idx]) {
+                let window = (times[session_begin], self.window_end(times[idx - 1]));
+                windows.extend(std::iter::repeat(window).take(idx - session_begin));
+                session_begin = idx;
+            }
+        }
+        windows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assign_sessions() {
+        let gap = SessionGap::from_interval(IntervalUnit::from_minutes(10)).unwrap();
+        let time = |minutes| {
+            NaiveDateTimeWrapper::new(
+                chrono::NaiveDate::from_ymd(2022, 1, 1).and_hms(0, 0, 0)
+                    + Duration::minutes(minutes),
+            )
+        };
+
+        assert!(gap.assign(&[]).is_empty());
+        assert_eq!(
+            gap.assign(&[time(0), time(5), time(14), time(24), time(40)]),
+            vec![
+                (time(0), time(24)),
+                (time(0), time(24)),
+                (time(0), time(24)),
+                (time(24), time(34)),
+                (time(40), time(50)),
+            ]
+        );
+
+        assert!(SessionGap::from_interval(IntervalUnit::from_month(1)).is_none());
+        assert!(SessionGap::from_interval(IntervalUnit::from_millis(0)).is_none());
+        assert!(SessionGap::from_interval(IntervalUnit::from_millis(-1)).is_none());
+    }
+}
@@ -189,7 +189,9 @@ pub fn compare_two_row(
                 Interval,
                 NaiveDate,
                 NaiveDateTime,
-                NaiveTime
+                NaiveTime,
+                Jsonb,
+                Bytea
             ]
         );
         if res != Ordering::Equal {
@@ -256,7 +256,7 @@ async fn test_table_v2_materialize() -> Result<()> {
             col_row_ids.push(col_row_id.value_at(0).unwrap());
             col_row_ids.push(col_row_id.value_at(1).unwrap());
         }
-        Message::Barrier(_) => panic!(),
+        Message::Barrier(_) | Message::Watermark(_) => panic!(),
     }
 
     // Send a barrier and poll again, should write changes to storage
@@ -324,7 +324,7 @@ async fn test_table_v2_materialize() -> Result<()> {
             let col_row_id = c.columns()[1].array_ref().as_int64();
             assert_eq!(col_row_id.value_at(0).unwrap(), col_row_ids[0]);
         }
-        Message::Barrier(_) => panic!(),
+        Message::Barrier(_) | Message::Watermark(_) => panic!(),
     }
 
     // Send a barrier and poll again, should write changes to storage
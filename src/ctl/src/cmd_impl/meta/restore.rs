@@ -0,0 +1,89 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+use etcd_client::{Client as EtcdClient, ConnectOptions};
+use risingwave_meta::hummock::{decode_meta_snapshot, restore_meta_snapshot};
+use risingwave_meta::storage::EtcdMetaStore;
+use risingwave_storage::hummock::backup::{
+    get_backup_meta_snapshot_path, get_backup_sst_data_path, get_backup_sst_meta_path,
+};
+use risingwave_storage::object::parse_object_store;
+
+use crate::common::HummockServiceOpts;
+
+/// Restore backup `backup_id` into a fresh meta store in etcd. SSTs of the backup are copied
+/// back to `data_directory` of the object store given by `RW_HUMMOCK_URL`.
+pub async fn restore(
+    backup_id: u64,
+    backup_prefix: &str,
+    data_directory: &str,
+    etcd_endpoints: &str,
+) -> anyhow::Result<()> {
+    let hummock_opts = HummockServiceOpts::from_env()?;
+    let object_store = parse_object_store(&hummock_opts.hummock_url).await;
+
+    let encoded = object_store
+        .read(
+            &get_backup_meta_snapshot_path(backup_prefix, backup_id),
+            None,
+        )
+        .await
+        .map_err(|e| anyhow!("backup {} is not found or not finished: {}", backup_id, e))?;
+    let meta_snapshot = decode_meta_snapshot(&encoded)?;
+    let hummock_version = meta_snapshot
+        .hummock_version
+        .as_ref()
+        .ok_or_else(|| anyhow!("hummock version is missing in backup {}", backup_id))?;
+
+    let sst_ids = hummock_version
+        .levels
+        .iter()
+        .flat_map(|level| level.table_infos.iter().map(|sst| sst.id));
+    for sst_id in sst_ids {
+        let meta = object_store
+            .read(
+                &get_backup_sst_meta_path(backup_prefix, backup_id, sst_id),
+                None,
+            )
+            .await?;
+        object_store
+            .upload(&format!("{}/{}.meta", data_directory, sst_id), meta)
+            .await?;
+        let data = object_store
+            .read(
+                &get_backup_sst_data_path(backup_prefix, backup_id, sst_id),
+                None,
+            )
+            .await?;
+        object_store
+            .upload(&format!("{}/{}.data", data_directory, sst_id), data)
+            .await?;
+    }
+
+    let client = EtcdClient::connect(
+        etcd_endpoints.split(',').collect::<Vec<_>>(),
+        Some(
+            ConnectOptions::default()
+                .with_keep_alive(Duration::from_secs(3), Duration::from_secs(5)),
+        ),
+    )
+    .await?;
+    let meta_store = EtcdMetaStore::new(client);
+    restore_meta_snapshot(&meta_store, meta_snapshot).await?;
+    println!("backup {} restored", backup_id);
+    Ok(())
+}
@@ -31,6 +31,9 @@ enum Commands {
     /// Commands for Hummock
     #[clap(subcommand)]
     Hummock(HummockCommands),
+    /// Commands for Meta
+    #[clap(subcommand)]
+    Meta(MetaCommands),
 }
 
 #[derive(Subcommand)]
@@ -41,11 +44,41 @@ enum HummockCommands {
     ListKv,
 }
 
+#[derive(Subcommand)]
+enum MetaCommands {
+    /// backup meta and SST data into the backup prefix of the object store
+    Backup,
+    /// restore a backup into a fresh meta store
+    Restore {
+        /// id of the backup to restore
+        #[clap(long)]
+        backup_id: u64,
+        /// backup prefix the meta node was configured with
+        #[clap(long, default_value = "backup")]
+        backup_prefix: String,
+        /// data directory of the restored cluster in the object store
+        #[clap(long, default_value = "hummock_001")]
+        data_directory: String,
+        /// comma separated etcd endpoints of the fresh meta store
+        #[clap(long)]
+        etcd_endpoints: String,
+    },
+}
+
 pub async fn start(opts: CliOpts) {
     match &opts.command {
         Commands::Hummock(HummockCommands::ListVersion) => {
             cmd_impl::hummock::list_version().await.unwrap()
         }
         Commands::Hummock(HummockCommands::ListKv) => cmd_impl::hummock::list_kv().await.unwrap(),
+        Commands::Meta(MetaCommands::Backup) => cmd_impl::meta::backup().await.unwrap(),
+        Commands::Meta(MetaCommands::Restore {
+            backup_id,
+            backup_prefix,
+            data_directory,
+            etcd_endpoints,
+        }) => cmd_impl::meta::restore(*backup_id, backup_prefix, data_directory, etcd_endpoints)
+            .await
+            .unwrap(),
     }
 }
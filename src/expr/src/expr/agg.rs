@@ -28,6 +28,18 @@ pub enum AggKind {
     Avg,
     StringAgg,
     SingleValue,
+    ApproxCountDistinct,
+    /// Merges the `HyperLogLog` sketches produced by the partial phase of `ApproxCountDistinct`.
+    ApproxCountDistinctMerge,
+    BoolAnd,
+    BoolOr,
+    ArrayAgg,
+    /// The statistical aggregations are rewritten into `Sum` and `Count` by the planner, like
+    /// `Avg`.
+    StddevPop,
+    StddevSamp,
+    VarPop,
+    VarSamp,
 }
 
 impl std::fmt::Display for AggKind {
@@ -41,6 +53,15 @@ impl std::fmt::Display for AggKind {
             AggKind::Avg => write!(f, "avg"),
             AggKind::StringAgg => write!(f, "string_agg"),
             AggKind::SingleValue => write!(f, "single_value"),
+            AggKind::ApproxCountDistinct => write!(f, "approx_count_distinct"),
+            AggKind::ApproxCountDistinctMerge => write!(f, "approx_count_distinct_merge"),
+            AggKind::BoolAnd => write!(f, "bool_and"),
+            AggKind::BoolOr => write!(f, "bool_or"),
+            AggKind::ArrayAgg => write!(f, "array_agg"),
+            AggKind::StddevPop => write!(f, "stddev_pop"),
+            AggKind::StddevSamp => write!(f, "stddev_samp"),
+            AggKind::VarPop => write!(f, "var_pop"),
+            AggKind::VarSamp => write!(f, "var_samp"),
         }
     }
 }
@@ -57,6 +78,11 @@ impl TryFrom<Type> for AggKind {
             Type::Count => Ok(AggKind::Count),
             Type::StringAgg => Ok(AggKind::StringAgg),
             Type::SingleValue => Ok(AggKind::SingleValue),
+            Type::ApproxCountDistinct => Ok(AggKind::ApproxCountDistinct),
+            Type::ApproxCountDistinctMerge => Ok(AggKind::ApproxCountDistinctMerge),
+            Type::BoolAnd => Ok(AggKind::BoolAnd),
+            Type::BoolOr => Ok(AggKind::BoolOr),
+            Type::ArrayAgg => Ok(AggKind::ArrayAgg),
             _ => Err(ErrorCode::InternalError("Unrecognized agg.".into()).into()),
         }
     }
@@ -72,6 +98,17 @@ impl AggKind {
             Self::Count => Type::Count,
             Self::StringAgg => Type::StringAgg,
             Self::SingleValue => Type::SingleValue,
+            Self::ApproxCountDistinct => Type::ApproxCountDistinct,
+            Self::ApproxCountDistinctMerge => Type::ApproxCountDistinctMerge,
+            Self::BoolAnd => Type::BoolAnd,
+            Self::BoolOr => Type::BoolOr,
+            Self::ArrayAgg => Type::ArrayAgg,
+            Self::StddevPop | Self::StddevSamp | Self::VarPop | Self::VarSamp => {
+                panic!(
+                    "cannot convert {} to prost, it should be rewritten by the planner",
+                    self
+                )
+            }
             Self::RowCount => {
                 panic!("cannot convert RowCount to prost, TODO: remove RowCount from AggKind")
             }
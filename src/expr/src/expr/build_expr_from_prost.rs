@@ -12,23 +12,36 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use risingwave_common::array::DataChunk;
+use risingwave_common::array::{BytesArray, DataChunk, I32Array, I64Array};
 use risingwave_common::ensure;
 use risingwave_common::error::{ErrorCode, Result, RwError};
-use risingwave_common::types::{DataType, ToOwnedDatum};
+use risingwave_common::types::{DataType, ScalarImpl, ToOwnedDatum};
 use risingwave_pb::expr::expr_node::RexNode;
 use risingwave_pb::expr::{expr_node, ExprNode};
 
-use crate::expr::expr_binary_bytes::new_substr_start;
+use crate::expr::expr_array::{ArrayExpression, ArrayKind};
+use crate::expr::expr_binary_bytes::{
+    new_concat_op, new_left_expr, new_repeat_expr, new_right_expr, new_substr_start,
+};
 use crate::expr::expr_binary_nonnull::{new_binary_expr, new_like_default};
 use crate::expr::expr_binary_nullable::new_nullable_binary_expr;
 use crate::expr::expr_case::{CaseExpression, WhenClause};
+use crate::expr::expr_concat_ws::ConcatWsExpression;
 use crate::expr::expr_in::InExpression;
-use crate::expr::expr_ternary_bytes::{new_replace_expr, new_substr_start_end, new_translate_expr};
+use crate::expr::expr_regexp::{RegexpExpression, RegexpKind, RegexpPattern};
+use crate::expr::expr_ternary_bytes::{
+    new_lpad_expr, new_replace_expr, new_rpad_expr, new_split_part_expr, new_substr_start_end,
+    new_translate_expr,
+};
+use crate::expr::expr_udf::{UdfExpression, WasmFunction};
 use crate::expr::expr_unary::{
     new_length_default, new_ltrim_expr, new_rtrim_expr, new_trim_expr, new_unary_expr,
 };
-use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression};
+use crate::expr::expr_variadic::{VariadicExpression, VariadicFunction};
+use crate::expr::template::{BinaryExpression, TernaryExpression, UnaryExpression};
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, LiteralExpression};
+use crate::vector_op::bytea::{bytea_length, bytea_substr_start, bytea_substr_start_for};
+use crate::vector_op::conditional::{coalesce, greatest, least, nullif};
 
 fn get_return_type_and_children(prost: &ExprNode) -> Result<(Vec<ExprNode>, DataType)> {
     let ret_type = DataType::from(prost.get_return_type()?);
@@ -78,12 +91,38 @@ pub fn build_substr_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_return_type_and_children(prost)?;
     let child = expr_build_from_prost(&children[0])?;
     ensure!(children.len() == 2 || children.len() == 3);
+    let is_bytea = child.return_type() == DataType::Bytea;
     if children.len() == 2 {
         let off = expr_build_from_prost(&children[1])?;
+        if is_bytea {
+            return Ok(Box::new(BinaryExpression::<
+                BytesArray,
+                I32Array,
+                BytesArray,
+                _,
+            >::new(
+                child, off, ret_type, bytea_substr_start
+            )));
+        }
         Ok(new_substr_start(child, off, ret_type))
     } else if children.len() == 3 {
         let off = expr_build_from_prost(&children[1])?;
         let len = expr_build_from_prost(&children[2])?;
+        if is_bytea {
+            return Ok(Box::new(TernaryExpression::<
+                BytesArray,
+                I32Array,
+                I32Array,
+                BytesArray,
+                _,
+            >::new(
+                child,
+                off,
+                len,
+                ret_type,
+                bytea_substr_start_for,
+            )));
+        }
         Ok(new_substr_start_end(child, off, len, ret_type))
     } else {
         unreachable!()
@@ -128,6 +167,13 @@ pub fn build_length_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     // TODO: add encoding length expr
     ensure!(children.len() == 1);
     let child = expr_build_from_prost(&children[0])?;
+    if child.return_type() == DataType::Bytea {
+        return Ok(Box::new(UnaryExpression::<BytesArray, I64Array, _>::new(
+            child,
+            ret_type,
+            bytea_length,
+        )));
+    }
     Ok(new_length_default(child, ret_type))
 }
 
@@ -139,6 +185,111 @@ pub fn build_like_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     Ok(new_like_default(expr_ia1, expr_ia2, ret_type))
 }
 
+/// Builds a [`RegexpExpression`]. A constant pattern is compiled here, so that it's not compiled
+/// again for every row.
+pub fn build_regexp_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    let kind = match prost.get_expr_type()? {
+        expr_node::Type::RegexpEq => RegexpKind::Eq,
+        expr_node::Type::SimilarTo => RegexpKind::SimilarTo,
+        expr_node::Type::RegexpMatch => RegexpKind::Match,
+        expr_node::Type::RegexpReplace => RegexpKind::Replace,
+        _ => unreachable!(),
+    };
+    let num_args = if kind == RegexpKind::Replace { 3 } else { 2 };
+    ensure!(children.len() == num_args || children.len() == num_args + 1);
+
+    let text = expr_build_from_prost(&children[0])?;
+    let replacement = match kind {
+        RegexpKind::Replace => Some(expr_build_from_prost(&children[2])?),
+        _ => None,
+    };
+    // The flags or the escape character.
+    let option = children
+        .get(num_args)
+        .map(eval_constant_string)
+        .transpose()?;
+    let pattern = match &option {
+        // A null option makes all the results null.
+        Some(None) => RegexpPattern::Constant(None),
+        _ if children[1].get_expr_type()? == expr_node::Type::ConstantValue => {
+            let option = option.clone().flatten();
+            let ctx = eval_constant_string(&children[1])?
+                .map(|pattern| kind.compile(&pattern, option.as_deref()))
+                .transpose()?;
+            RegexpPattern::Constant(ctx)
+        }
+        _ => RegexpPattern::Dynamic(expr_build_from_prost(&children[1])?),
+    };
+    Ok(Box::new(RegexpExpression::new(
+        kind,
+        text,
+        pattern,
+        option.flatten(),
+        replacement,
+        ret_type,
+    )))
+}
+
+/// Evaluates a constant varchar argument, such as the flags of regular expression functions.
+pub(crate) fn eval_constant_string(prost: &ExprNode) -> Result<Option<String>> {
+    ensure!(prost.get_expr_type()? == expr_node::Type::ConstantValue);
+    match LiteralExpression::try_from(prost)?.literal() {
+        Some(ScalarImpl::Utf8(s)) => Ok(Some(s)),
+        None => Ok(None),
+        Some(v) => {
+            Err(ErrorCode::InternalError(format!("expects a varchar constant: {:?}", v)).into())
+        }
+    }
+}
+
+pub fn build_array_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    let (kind, num_args) = match prost.get_expr_type()? {
+        expr_node::Type::Array => (ArrayKind::Construct, None),
+        expr_node::Type::ArrayAccess => (ArrayKind::Access, Some(2)),
+        expr_node::Type::ArrayRangeAccess => (ArrayKind::RangeAccess, Some(3)),
+        expr_node::Type::ArrayLength => (ArrayKind::Length, Some(2)),
+        expr_node::Type::ArrayAppend => (ArrayKind::Append, Some(2)),
+        expr_node::Type::ArrayCat => (ArrayKind::Cat, Some(2)),
+        expr_node::Type::ArrayContains => (ArrayKind::Contains, Some(2)),
+        expr_node::Type::ArrayAnyEqual => (ArrayKind::AnyEqual, Some(2)),
+        expr_node::Type::StringToArray => {
+            ensure!(children.len() == 2 || children.len() == 3);
+            (ArrayKind::StringToArray, None)
+        }
+        _ => unreachable!(),
+    };
+    if let Some(num_args) = num_args {
+        ensure!(children.len() == num_args);
+    }
+    let children = children
+        .iter()
+        .map(expr_build_from_prost)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(ArrayExpression::new(kind, children, ret_type)))
+}
+
+pub fn build_variadic_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    let func: VariadicFunction = match prost.get_expr_type()? {
+        expr_node::Type::Coalesce => coalesce,
+        expr_node::Type::Nullif => {
+            ensure!(children.len() == 2);
+            nullif
+        }
+        expr_node::Type::Greatest => greatest,
+        expr_node::Type::Least => least,
+        _ => unreachable!(),
+    };
+    ensure!(!children.is_empty());
+    let children = children
+        .iter()
+        .map(expr_build_from_prost)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(VariadicExpression::new(children, ret_type, func)))
+}
+
 pub fn build_in_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     let (children, ret_type) = get_return_type_and_children(prost)?;
     ensure!(ret_type == DataType::Boolean);
@@ -213,6 +364,82 @@ pub fn build_translate_expr(prost: &ExprNode) -> Result<BoxedExpression> {
     Ok(new_translate_expr(s, match_str, replace_str, ret_type))
 }
 
+pub fn build_concat_ws_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() >= 2);
+    let sep_expr = expr_build_from_prost(&children[0])?;
+    let string_exprs = children[1..]
+        .iter()
+        .map(expr_build_from_prost)
+        .collect::<Result<Vec<_>>>()?;
+    Ok(Box::new(ConcatWsExpression::new(
+        ret_type,
+        sep_expr,
+        string_exprs,
+    )))
+}
+
+/// Builds the string functions taking 2 arguments that are evaluated with a bytes writer.
+pub fn build_binary_bytes_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 2);
+    let left = expr_build_from_prost(&children[0])?;
+    let right = expr_build_from_prost(&children[1])?;
+    let new_expr = match prost.get_expr_type()? {
+        expr_node::Type::ConcatOp => new_concat_op,
+        expr_node::Type::Left => new_left_expr,
+        expr_node::Type::Right => new_right_expr,
+        expr_node::Type::Repeat => new_repeat_expr,
+        _ => unreachable!(),
+    };
+    Ok(new_expr(left, right, ret_type))
+}
+
+/// Builds the string functions taking 3 arguments that are evaluated with a bytes writer.
+pub fn build_ternary_bytes_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let (children, ret_type) = get_return_type_and_children(prost)?;
+    ensure!(children.len() == 3);
+    let first = expr_build_from_prost(&children[0])?;
+    let second = expr_build_from_prost(&children[1])?;
+    let third = expr_build_from_prost(&children[2])?;
+    let new_expr = match prost.get_expr_type()? {
+        expr_node::Type::SplitPart => new_split_part_expr,
+        expr_node::Type::Lpad => new_lpad_expr,
+        expr_node::Type::Rpad => new_rpad_expr,
+        _ => unreachable!(),
+    };
+    Ok(new_expr(first, second, third, ret_type))
+}
+
+pub fn build_udf_expr(prost: &ExprNode) -> Result<BoxedExpression> {
+    let ret_type = DataType::from(prost.get_return_type()?);
+    let udf = if let RexNode::Udf(udf) = prost.get_rex_node()? {
+        udf
+    } else {
+        return Err(ErrorCode::InternalError("expects a user-defined function".to_string()).into());
+    };
+    if !udf.get_language().eq_ignore_ascii_case("wasm") {
+        return Err(ErrorCode::InternalError(format!(
+            "unsupported language of user-defined function: {}",
+            udf.get_language()
+        ))
+        .into());
+    }
+    let arg_types = udf
+        .get_arg_types()
+        .iter()
+        .map(DataType::from)
+        .collect::<Vec<_>>();
+    ensure!(udf.get_children().len() == arg_types.len());
+    let children = udf
+        .get_children()
+        .iter()
+        .map(expr_build_from_prost)
+        .collect::<Result<Vec<_>>>()?;
+    let function = WasmFunction::new(udf.get_name(), arg_types, ret_type.clone(), udf.get_body())?;
+    Ok(Box::new(UdfExpression::new(children, ret_type, function)))
+}
+
 #[cfg(test)]
 mod tests {
     use std::vec;
@@ -273,6 +500,62 @@ mod tests {
         assert!(build_in_expr(&p).is_ok());
     }
 
+    #[test]
+    fn test_build_regexp_expr() {
+        let varchar = |expr_type: Type, rex_node: Option<RexNode>| ExprNode {
+            expr_type: expr_type as i32,
+            return_type: Some(ProstDataType {
+                type_name: TypeName::Varchar as i32,
+                ..Default::default()
+            }),
+            rex_node,
+        };
+        let constant = |s: &str| {
+            varchar(
+                Type::ConstantValue,
+                Some(RexNode::Constant(ConstantValue {
+                    body: s.as_bytes().to_vec(),
+                })),
+            )
+        };
+        let input_ref = varchar(
+            Type::InputRef,
+            Some(RexNode::InputRef(InputRefExpr { column_idx: 0 })),
+        );
+        let regexp_replace = |children| {
+            varchar(
+                Type::RegexpReplace,
+                Some(RexNode::FuncCall(FunctionCall { children })),
+            )
+        };
+
+        let p = regexp_replace(vec![
+            input_ref.clone(),
+            constant("a+"),
+            constant("b"),
+            constant("g"),
+        ]);
+        assert!(build_regexp_expr(&p).is_ok());
+        // Invalid patterns and flags are reported when building the expression.
+        let p = regexp_replace(vec![input_ref.clone(), constant("(a"), constant("b")]);
+        assert!(build_regexp_expr(&p).is_err());
+        let p = regexp_replace(vec![
+            input_ref.clone(),
+            constant("a+"),
+            constant("b"),
+            constant("z"),
+        ]);
+        assert!(build_regexp_expr(&p).is_err());
+        // The flags must be a constant.
+        let p = regexp_replace(vec![
+            input_ref.clone(),
+            constant("a+"),
+            constant("b"),
+            input_ref,
+        ]);
+        assert!(build_regexp_expr(&p).is_err());
+    }
+
     #[test]
     fn test_build_case_expr() {
         let call = FunctionCall {
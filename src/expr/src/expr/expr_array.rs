@@ -0,0 +1,391 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{ArrayRef, DataChunk, ListRef, ListValue};
+use risingwave_common::error::ErrorCode::{InternalError, NotImplemented};
+use risingwave_common::error::Result;
+use risingwave_common::types::{
+    DataType, Datum, DatumRef, ScalarImpl, ScalarRefImpl, ToOwnedDatum,
+};
+use risingwave_pb::expr::expr_node::Type as ProstType;
+
+use crate::expr::expr_unary::new_unary_expr;
+use crate::expr::{BoxedExpression, Expression, InputRefExpression};
+use crate::vector_op::array::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArrayKind {
+    /// `ARRAY[element, ...]`.
+    Construct,
+    /// `list[index]`.
+    Access,
+    /// `list[start:end]`.
+    RangeAccess,
+    /// `array_length(list, dimension)`.
+    Length,
+    /// `array_append(list, element)`.
+    Append,
+    /// `array_cat(list, list)`.
+    Cat,
+    /// `list @> list`.
+    Contains,
+    /// `element = ANY(list)`.
+    AnyEqual,
+    /// `string_to_array(string, delimiter [, null_string])`.
+    StringToArray,
+}
+
+fn as_list<'a>(datum: &DatumRef<'a>) -> Option<ListRef<'a>> {
+    match datum {
+        Some(ScalarRefImpl::List(list)) => Some(*list),
+        _ => None,
+    }
+}
+
+fn as_str<'a>(datum: Option<&DatumRef<'a>>) -> Option<&'a str> {
+    match datum {
+        Some(Some(ScalarRefImpl::Utf8(s))) => Some(*s),
+        _ => None,
+    }
+}
+
+/// `ArrayExpression` evaluates the functions and operators over lists row by row, as lists can't
+/// be built by the generic expression templates.
+#[derive(Debug)]
+pub struct ArrayExpression {
+    kind: ArrayKind,
+    children: Vec<BoxedExpression>,
+    return_type: DataType,
+}
+
+impl ArrayExpression {
+    pub fn new(kind: ArrayKind, children: Vec<BoxedExpression>, return_type: DataType) -> Self {
+        Self {
+            kind,
+            children,
+            return_type,
+        }
+    }
+
+    fn eval_row(&self, args: &[DatumRef<'_>]) -> Result<Datum> {
+        use ScalarRefImpl::{Int32, List, Utf8};
+
+        Ok(match (self.kind, args) {
+            (ArrayKind::Construct, _) => Some(ScalarImpl::List(ListValue::new(
+                args.iter().map(|v| v.to_owned_datum()).collect(),
+            ))),
+            (ArrayKind::Access, [Some(List(list)), Some(Int32(index))]) => {
+                array_access(*list, *index)
+            }
+            (ArrayKind::RangeAccess, [Some(List(list)), Some(Int32(start)), Some(Int32(end))]) => {
+                Some(ScalarImpl::List(array_range_access(*list, *start, *end)))
+            }
+            (ArrayKind::Length, [Some(List(list)), Some(Int32(dimension))]) => {
+                array_length(*list, *dimension).map(ScalarImpl::Int32)
+            }
+            (ArrayKind::Append, [list, element]) => {
+                Some(ScalarImpl::List(array_append(as_list(list), *element)))
+            }
+            (ArrayKind::Cat, [left, right]) => {
+                array_cat(as_list(left), as_list(right)).map(ScalarImpl::List)
+            }
+            (ArrayKind::Contains, [Some(List(left)), Some(List(right))]) => {
+                Some(ScalarImpl::Bool(array_contains(*left, *right)))
+            }
+            (ArrayKind::AnyEqual, [element, Some(List(list))]) => {
+                array_any_equal(*element, *list).map(ScalarImpl::Bool)
+            }
+            (ArrayKind::StringToArray, [Some(Utf8(s)), delimiter, null_string @ ..]) => {
+                Some(ScalarImpl::List(string_to_array(
+                    s,
+                    as_str(Some(delimiter)),
+                    as_str(null_string.first()),
+                )))
+            }
+            // Otherwise, a null argument results in null.
+            _ if args.iter().any(Option::is_none) => None,
+            _ => {
+                return Err(InternalError(format!(
+                    "unexpected arguments of {:?}: {:?}",
+                    self.kind, args
+                ))
+                .into())
+            }
+        })
+    }
+}
+
+impl Expression for ArrayExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let arrays: Vec<ArrayRef> = self
+            .children
+            .iter()
+            .map(|child| child.eval(input))
+            .try_collect()?;
+
+        let mut output_array = self.return_type.create_array_builder(input.capacity())?;
+        for idx in 0..input.capacity() {
+            if let Some(visibility) = input.get_visibility_ref() {
+                if !visibility.is_set(idx)? {
+                    continue;
+                }
+            }
+            let args = arrays.iter().map(|array| array.value_at(idx)).collect_vec();
+            let datum = self.eval_row(&args)?;
+            output_array.append_datum(&datum)?;
+        }
+        Ok(Arc::new(output_array.finish()?))
+    }
+}
+
+/// Casts a list from a string in PostgreSQL array syntax or from another list type, or casts a
+/// list to a string. The elements are cast by `element_cast`, which is evaluated over a column of
+/// the source elements.
+#[derive(Debug)]
+pub struct ListCastExpression {
+    child: BoxedExpression,
+    return_type: DataType,
+    source_element_type: DataType,
+    element_cast: Option<BoxedExpression>,
+}
+
+impl ListCastExpression {
+    pub fn new(child: BoxedExpression, return_type: DataType) -> Result<Self> {
+        let not_supported = || {
+            NotImplemented(
+                format!(
+                    "CAST({:?} AS {:?}) not supported yet!",
+                    child.return_type(),
+                    return_type
+                ),
+                None.into(),
+            )
+        };
+        // Strings are parsed into the texts of elements before casting.
+        let source_element_type = match child.return_type() {
+            DataType::List { datatype } => *datatype,
+            DataType::Varchar => DataType::Varchar,
+            _ => return Err(not_supported().into()),
+        };
+        let element_cast = match &return_type {
+            DataType::List { datatype } if **datatype != source_element_type => {
+                Some(new_unary_expr(
+                    ProstType::Cast,
+                    (**datatype).clone(),
+                    InputRefExpression::new(source_element_type.clone(), 0).boxed(),
+                )?)
+            }
+            DataType::List { .. } => None,
+            DataType::Varchar if matches!(child.return_type(), DataType::List { .. }) => None,
+            _ => return Err(not_supported().into()),
+        };
+        Ok(Self {
+            child,
+            return_type,
+            source_element_type,
+            element_cast,
+        })
+    }
+
+    fn eval_row(&self, value: ScalarRefImpl<'_>) -> Result<ScalarImpl> {
+        let elements: Vec<Datum> = match value {
+            ScalarRefImpl::List(list) if self.return_type == DataType::Varchar => {
+                return Ok(ScalarImpl::Utf8(list.to_string()));
+            }
+            ScalarRefImpl::List(list) => list
+                .values_ref()
+                .into_iter()
+                .map(|v| v.to_owned_datum())
+                .collect(),
+            ScalarRefImpl::Utf8(s) => parse_array_elements(s)?
+                .into_iter()
+                .map(|e| e.map(ScalarImpl::Utf8))
+                .collect(),
+            _ => unreachable!(),
+        };
+        let element_cast = match &self.element_cast {
+            Some(element_cast) => element_cast,
+            None => return Ok(ScalarImpl::List(ListValue::new(elements))),
+        };
+
+        let mut builder = self
+            .source_element_type
+            .create_array_builder(elements.len())?;
+        for element in &elements {
+            builder.append_datum(element)?;
+        }
+        let chunk = DataChunk::builder()
+            .columns(vec![Column::new(Arc::new(builder.finish()?))])
+            .build();
+        let cast = element_cast.eval(&chunk)?;
+        Ok(ScalarImpl::List(ListValue::new(
+            (0..cast.len()).map(|idx| cast.datum_at(idx)).collect(),
+        )))
+    }
+}
+
+impl Expression for ListCastExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let array = self.child.eval(input)?;
+        let mut output_array = self.return_type.create_array_builder(input.capacity())?;
+        for idx in 0..input.capacity() {
+            if let Some(visibility) = input.get_visibility_ref() {
+                if !visibility.is_set(idx)? {
+                    continue;
+                }
+            }
+            let datum = array.value_at(idx).map(|v| self.eval_row(v)).transpose()?;
+            output_array.append_datum(&datum)?;
+        }
+        Ok(Arc::new(output_array.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{I32Array, Utf8Array};
+    use risingwave_common::column;
+
+    use super::*;
+    use crate::expr::LiteralExpression;
+
+    fn int_list(values: &[Option<i32>]) -> Datum {
+        Some(ScalarImpl::List(ListValue::new(
+            values.iter().map(|v| v.map(ScalarImpl::Int32)).collect(),
+        )))
+    }
+
+    fn int_list_type() -> DataType {
+        DataType::List {
+            datatype: Box::new(DataType::Int32),
+        }
+    }
+
+    #[test]
+    fn test_array_construct_access() {
+        let construct = ArrayExpression::new(
+            ArrayKind::Construct,
+            vec![
+                InputRefExpression::new(DataType::Int32, 0).boxed(),
+                LiteralExpression::new(DataType::Int32, Some(ScalarImpl::Int32(7))).boxed(),
+            ],
+            int_list_type(),
+        );
+        let access = ArrayExpression::new(
+            ArrayKind::Access,
+            vec![
+                construct.boxed(),
+                InputRefExpression::new(DataType::Int32, 1).boxed(),
+            ],
+            DataType::Int32,
+        );
+        let values = column! { I32Array, [Some(1), None, Some(3)] };
+        let indexes = column! { I32Array, [Some(1), Some(1), None] };
+        let chunk = DataChunk::builder().columns(vec![values, indexes]).build();
+        let result = access.eval(&chunk).unwrap();
+        assert_eq!(result.datum_at(0), Some(ScalarImpl::Int32(1)));
+        assert_eq!(result.datum_at(1), None);
+        assert_eq!(result.datum_at(2), None);
+    }
+
+    #[test]
+    fn test_array_any_equal() {
+        let expr = ArrayExpression::new(
+            ArrayKind::AnyEqual,
+            vec![
+                InputRefExpression::new(DataType::Int32, 0).boxed(),
+                LiteralExpression::new(int_list_type(), int_list(&[Some(1), Some(2)])).boxed(),
+            ],
+            DataType::Boolean,
+        );
+        let values = column! { I32Array, [Some(1), Some(3), None] };
+        let chunk = DataChunk::builder().columns(vec![values]).build();
+        let result = expr.eval(&chunk).unwrap();
+        assert_eq!(result.datum_at(0), Some(ScalarImpl::Bool(true)));
+        assert_eq!(result.datum_at(1), Some(ScalarImpl::Bool(false)));
+        assert_eq!(result.datum_at(2), None);
+    }
+
+    #[test]
+    fn test_list_cast() {
+        let from_str = ListCastExpression::new(
+            InputRefExpression::new(DataType::Varchar, 0).boxed(),
+            int_list_type(),
+        )
+        .unwrap();
+        let strings = column! { Utf8Array, [Some("{1, NULL,3}"), Some("{}"), None] };
+        let chunk = DataChunk::builder().columns(vec![strings]).build();
+        let result = from_str.eval(&chunk).unwrap();
+        assert_eq!(result.datum_at(0), int_list(&[Some(1), None, Some(3)]));
+        assert_eq!(result.datum_at(1), int_list(&[]));
+        assert_eq!(result.datum_at(2), None);
+
+        let to_str = ListCastExpression::new(from_str.boxed(), DataType::Varchar).unwrap();
+        let result = to_str.eval(&chunk).unwrap();
+        assert_eq!(
+            result.datum_at(0),
+            Some(ScalarImpl::Utf8("{1,NULL,3}".to_string()))
+        );
+
+        let malformed = column! { Utf8Array, [Some("{1,a}")] };
+        let chunk = DataChunk::builder().columns(vec![malformed]).build();
+        let to_int_list = ListCastExpression::new(
+            InputRefExpression::new(DataType::Varchar, 0).boxed(),
+            int_list_type(),
+        )
+        .unwrap();
+        assert!(to_int_list.eval(&chunk).is_err());
+    }
+
+    #[test]
+    fn test_nested_list_cast() {
+        let nested_type = DataType::List {
+            datatype: Box::new(DataType::List {
+                datatype: Box::new(DataType::Int64),
+            }),
+        };
+        let expr = ListCastExpression::new(
+            InputRefExpression::new(DataType::Varchar, 0).boxed(),
+            nested_type,
+        )
+        .unwrap();
+        let strings = column! { Utf8Array, [Some("{{1,2},{3}}")] };
+        let chunk = DataChunk::builder().columns(vec![strings]).build();
+        let result = expr.eval(&chunk).unwrap();
+        let inner = |values: &[i64]| {
+            Some(ScalarImpl::List(ListValue::new(
+                values.iter().map(|v| Some(ScalarImpl::Int64(*v))).collect(),
+            )))
+        };
+        assert_eq!(
+            result.datum_at(0),
+            Some(ScalarImpl::List(ListValue::new(vec![
+                inner(&[1, 2]),
+                inner(&[3])
+            ])))
+        );
+    }
+}
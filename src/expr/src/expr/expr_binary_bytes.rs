@@ -19,6 +19,8 @@ use risingwave_common::types::DataType;
 
 use crate::expr::template::BinaryBytesExpression;
 use crate::expr::BoxedExpression;
+use crate::vector_op::concat_op::concat_op;
+use crate::vector_op::repeat::repeat;
 use crate::vector_op::substr::*;
 
 pub fn new_substr_start(
@@ -48,6 +50,58 @@ pub fn new_substr_for(
     ))
 }
 
+pub fn new_concat_op(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryBytesExpression::<Utf8Array, Utf8Array, _>::new(
+        expr_ia1,
+        expr_ia2,
+        return_type,
+        concat_op,
+    ))
+}
+
+pub fn new_left_expr(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryBytesExpression::<Utf8Array, I32Array, _>::new(
+        expr_ia1,
+        expr_ia2,
+        return_type,
+        left,
+    ))
+}
+
+pub fn new_right_expr(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryBytesExpression::<Utf8Array, I32Array, _>::new(
+        expr_ia1,
+        expr_ia2,
+        return_type,
+        right,
+    ))
+}
+
+pub fn new_repeat_expr(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(BinaryBytesExpression::<Utf8Array, I32Array, _>::new(
+        expr_ia1,
+        expr_ia2,
+        return_type,
+        repeat,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_common::array::DataChunk;
@@ -113,4 +167,24 @@ mod tests {
         let res = substr_for_str_none.eval(&DataChunk::new_dummy(1)).unwrap();
         assert_eq!(res.to_datum(), None);
     }
+
+    #[test]
+    fn test_left_right_repeat() {
+        for (f, expected) in [
+            (new_left_expr as fn(_, _, _) -> _, "ab"),
+            (new_right_expr, "bc"),
+            (new_repeat_expr, "abcabc"),
+        ] {
+            let expr = create_str_i32_binary_expr(
+                f,
+                Some(ScalarImpl::from(String::from("abc"))),
+                Some(ScalarImpl::Int32(2)),
+            );
+            let res = expr.eval(&DataChunk::new_dummy(1)).unwrap();
+            assert_eq!(
+                res.to_datum(),
+                Some(ScalarImpl::from(String::from(expected)))
+            );
+        }
+    }
 }
@@ -13,8 +13,8 @@
 // limitations under the License.
 
 use risingwave_common::array::{
-    Array, BoolArray, DecimalArray, I32Array, IntervalArray, NaiveDateArray, NaiveDateTimeArray,
-    Utf8Array,
+    Array, BoolArray, BytesArray, DecimalArray, F64Array, I32Array, I64Array, IntervalArray,
+    JsonbArray, NaiveDateArray, NaiveDateTimeArray, Utf8Array,
 };
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::Result;
@@ -24,11 +24,18 @@ use risingwave_pb::expr::expr_node::Type;
 use crate::expr::template::BinaryExpression;
 use crate::expr::BoxedExpression;
 use crate::vector_op::arithmetic_op::*;
+use crate::vector_op::bytea::{decode, encode};
 use crate::vector_op::cmp::*;
+use crate::vector_op::date_trunc::date_trunc_timestamp;
 use crate::vector_op::extract::{extract_from_date, extract_from_timestamp};
+use crate::vector_op::jsonb::jsonb_contains;
 use crate::vector_op::like::like_default;
+use crate::vector_op::math::power_f64;
 use crate::vector_op::position::position;
 use crate::vector_op::round::round_digits;
+use crate::vector_op::starts_with::starts_with;
+use crate::vector_op::timestampz::{timestamp_at_time_zone, timestampz_at_time_zone};
+use crate::vector_op::to_char::{to_char_timestamp, to_timestamp};
 use crate::vector_op::tumble::{tumble_start_date, tumble_start_date_time};
 
 /// A placeholder function that returns bool in [`gen_binary_expr_atm`]
@@ -346,6 +353,15 @@ pub fn new_binary_expr(
                 },
             }
         }
+        Type::StartsWith => Box::new(BinaryExpression::<Utf8Array, Utf8Array, BoolArray, _>::new(
+            l,
+            r,
+            ret,
+            starts_with,
+        )),
+        Type::Power => Box::new(BinaryExpression::<F64Array, F64Array, F64Array, _>::new(
+            l, r, ret, power_f64,
+        )),
         Type::Extract => build_extract_expr(ret, l, r),
         Type::RoundDigit => Box::new(
             BinaryExpression::<DecimalArray, I32Array, DecimalArray, _>::new(
@@ -359,6 +375,39 @@ pub fn new_binary_expr(
             l, r, ret, position,
         )),
         Type::TumbleStart => new_tumble_start(l, r, ret),
+        Type::JsonbContains => Box::new(
+            BinaryExpression::<JsonbArray, JsonbArray, BoolArray, _>::new(
+                l,
+                r,
+                ret,
+                jsonb_contains,
+            ),
+        ),
+        Type::Encode => Box::new(
+            BinaryExpression::<BytesArray, Utf8Array, Utf8Array, _>::new(l, r, ret, encode),
+        ),
+        Type::Decode => Box::new(
+            BinaryExpression::<Utf8Array, Utf8Array, BytesArray, _>::new(l, r, ret, decode),
+        ),
+        Type::DateTrunc => Box::new(BinaryExpression::<
+            Utf8Array,
+            NaiveDateTimeArray,
+            NaiveDateTimeArray,
+            _,
+        >::new(l, r, ret, date_trunc_timestamp)),
+        Type::ToChar => Box::new(BinaryExpression::<
+            NaiveDateTimeArray,
+            Utf8Array,
+            Utf8Array,
+            _,
+        >::new(l, r, ret, to_char_timestamp)),
+        Type::ToTimestamp => Box::new(BinaryExpression::<
+            Utf8Array,
+            Utf8Array,
+            NaiveDateTimeArray,
+            _,
+        >::new(l, r, ret, to_timestamp)),
+        Type::AtTimeZone => new_at_time_zone(l, r, ret),
         tp => {
             unimplemented!(
                 "The expression {:?} using vectorized expression framework is not supported yet!",
@@ -397,6 +446,37 @@ fn new_tumble_start(
     }
 }
 
+fn new_at_time_zone(
+    expr_ia1: BoxedExpression,
+    expr_ia2: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    match expr_ia1.return_type() {
+        DataType::Timestamp => Box::new(BinaryExpression::<
+            NaiveDateTimeArray,
+            Utf8Array,
+            I64Array,
+            _,
+        >::new(
+            expr_ia1, expr_ia2, return_type, timestamp_at_time_zone
+        )),
+        DataType::Timestampz => {
+            Box::new(
+                BinaryExpression::<I64Array, Utf8Array, NaiveDateTimeArray, _>::new(
+                    expr_ia1,
+                    expr_ia2,
+                    return_type,
+                    timestampz_at_time_zone,
+                ),
+            )
+        }
+        _ => unimplemented!(
+            "at time zone is not supported for {:?}",
+            expr_ia1.return_type()
+        ),
+    }
+}
+
 pub fn new_like_default(
     expr_ia1: BoxedExpression,
     expr_ia2: BoxedExpression,
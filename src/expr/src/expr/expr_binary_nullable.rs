@@ -15,7 +15,8 @@
 //! For expression that only accept two nullable arguments as input.
 
 use risingwave_common::array::{
-    BoolArray, DecimalArray, F32Array, F64Array, I16Array, I32Array, I64Array,
+    BoolArray, DecimalArray, F32Array, F64Array, I16Array, I32Array, I64Array, JsonbArray,
+    Utf8Array,
 };
 use risingwave_common::error::Result;
 use risingwave_common::types::DataType;
@@ -24,6 +25,10 @@ use risingwave_pb::expr::expr_node::Type;
 use super::BoxedExpression;
 use crate::expr::template::BinaryNullableExpression;
 use crate::vector_op::conjunction::{and, or};
+use crate::vector_op::jsonb::{
+    jsonb_access_field, jsonb_access_field_str, jsonb_access_index, jsonb_access_index_str,
+    jsonb_access_path,
+};
 
 // TODO: consider implement it using generic function.
 macro_rules! gen_stream_null_by_row_count_expr {
@@ -93,6 +98,40 @@ pub fn new_nullable_binary_expr(
         Type::Or => Box::new(
             BinaryNullableExpression::<BoolArray, BoolArray, BoolArray, _>::new(l, r, ret, or),
         ),
+        Type::JsonbAccessInner => match r.return_type() {
+            DataType::Int32 => Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                I32Array,
+                JsonbArray,
+                _,
+            >::new(l, r, ret, jsonb_access_index)),
+            _ => Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                Utf8Array,
+                JsonbArray,
+                _,
+            >::new(l, r, ret, jsonb_access_field)),
+        },
+        Type::JsonbAccessStr => match r.return_type() {
+            DataType::Int32 => Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                I32Array,
+                Utf8Array,
+                _,
+            >::new(l, r, ret, jsonb_access_index_str)),
+            _ => Box::new(BinaryNullableExpression::<
+                JsonbArray,
+                Utf8Array,
+                Utf8Array,
+                _,
+            >::new(l, r, ret, jsonb_access_field_str)),
+        },
+        Type::JsonbAccessPath => Box::new(BinaryNullableExpression::<
+            JsonbArray,
+            Utf8Array,
+            JsonbArray,
+            _,
+        >::new(l, r, ret, jsonb_access_path)),
         tp => {
             unimplemented!(
                 "The expression {:?} using vectorized expression framework is not supported yet!",
@@ -77,9 +77,10 @@ impl Expression for CaseExpression {
             if let Some((_, t)) = when_thens
                 .iter()
                 .map(|(w, t)| (w.value_at(idx), t.value_at(idx)))
-                .find(|(w, _)| *w.unwrap().into_scalar_impl().as_bool())
+                // A null condition is not satisfied.
+                .find(|(w, _)| w.map_or(false, |w| *w.into_scalar_impl().as_bool()))
             {
-                let t = Some(t.unwrap().into_scalar_impl());
+                let t = t.map(|t| t.into_scalar_impl());
                 output_array.append_datum(&t)?;
             } else if let Some(els) = els.as_mut() {
                 let t = els.datum_at(idx);
@@ -167,4 +168,33 @@ mod tests {
         assert_eq!(output.datum_at(2), Some(3.1f32.into()));
         assert_eq!(output.datum_at(3), None);
     }
+
+    #[test]
+    fn test_null_condition() {
+        let ret_type = DataType::Float32;
+        // when x <= 3 then 3.1
+        let when_clauses = vec![WhenClause::new(
+            new_binary_expr(
+                Type::LessThanOrEqual,
+                DataType::Boolean,
+                Box::new(InputRefExpression::new(DataType::Int32, 0)),
+                Box::new(LiteralExpression::new(DataType::Float32, Some(3f32.into()))),
+            ),
+            Box::new(LiteralExpression::new(
+                DataType::Float32,
+                Some(3.1f32.into()),
+            )),
+        )];
+        // else 4.1
+        let els = Box::new(LiteralExpression::new(
+            DataType::Float32,
+            Some(4.1f32.into()),
+        ));
+        let searched_case_expr = CaseExpression::new(ret_type, when_clauses, Some(els));
+        let col = create_column_i32(&[Some(3), None]).unwrap();
+        let input = DataChunk::builder().columns([col].to_vec()).build();
+        let output = searched_case_expr.eval(&input).unwrap();
+        assert_eq!(output.datum_at(0), Some(3.1f32.into()));
+        assert_eq!(output.datum_at(1), Some(4.1f32.into()));
+    }
 }
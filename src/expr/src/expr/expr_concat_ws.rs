@@ -0,0 +1,129 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{
+    Array, ArrayBuilder, ArrayImpl, ArrayRef, DataChunk, Utf8Array, Utf8ArrayBuilder,
+};
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+
+use crate::expr::{BoxedExpression, Expression};
+
+/// `concat_ws(separator, string, ...)` concatenates the non-null strings with the separator, and
+/// `concat(string, ...)` is the one with an empty separator. The result is null only if the
+/// separator is null.
+#[derive(Debug)]
+pub struct ConcatWsExpression {
+    return_type: DataType,
+    sep_expr: BoxedExpression,
+    string_exprs: Vec<BoxedExpression>,
+}
+
+impl ConcatWsExpression {
+    pub fn new(
+        return_type: DataType,
+        sep_expr: BoxedExpression,
+        string_exprs: Vec<BoxedExpression>,
+    ) -> Self {
+        ConcatWsExpression {
+            return_type,
+            sep_expr,
+            string_exprs,
+        }
+    }
+}
+
+impl Expression for ConcatWsExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let sep_column = self.sep_expr.eval(input)?;
+        let sep_column = sep_column.as_utf8();
+
+        let string_columns = self
+            .string_exprs
+            .iter()
+            .map(|c| c.eval(input))
+            .collect::<Result<Vec<_>>>()?;
+        let string_columns_ref = string_columns.iter().map(|c| c.as_utf8()).collect_vec();
+
+        let mut builder = Utf8ArrayBuilder::new(input.capacity())?;
+        for row_idx in 0..input.capacity() {
+            if let Some(visibility) = input.get_visibility_ref() {
+                if !visibility.is_set(row_idx)? {
+                    continue;
+                }
+            }
+            let sep = match sep_column.value_at(row_idx) {
+                Some(sep) => sep,
+                None => {
+                    builder.append(None)?;
+                    continue;
+                }
+            };
+
+            let mut writer = builder.writer().begin();
+            let mut strings = string_columns_ref
+                .iter()
+                .filter_map(|c| c.value_at(row_idx));
+            if let Some(string) = strings.next() {
+                writer.write_ref(string)?;
+            }
+            for string in strings {
+                writer.write_ref(sep)?;
+                writer.write_ref(string)?;
+            }
+            builder = writer.finish()?.into_inner();
+        }
+        Ok(Arc::new(ArrayImpl::from(builder.finish()?)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::column;
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+    use crate::expr::{InputRefExpression, LiteralExpression};
+
+    #[test]
+    fn test_concat_ws() {
+        let concat_ws = ConcatWsExpression::new(
+            DataType::Varchar,
+            InputRefExpression::new(DataType::Varchar, 0).boxed(),
+            vec![
+                InputRefExpression::new(DataType::Varchar, 1).boxed(),
+                InputRefExpression::new(DataType::Varchar, 2).boxed(),
+                LiteralExpression::new(DataType::Varchar, Some(ScalarImpl::Utf8("c".to_string())))
+                    .boxed(),
+            ],
+        );
+        let sep = column! { Utf8Array, [Some(","), Some(""), Some(","), None] };
+        let v1 = column! { Utf8Array, [Some("a"), Some("a"), None, Some("a")] };
+        let v2 = column! { Utf8Array, [Some("b"), None, None, Some("b")] };
+        let chunk = DataChunk::builder().columns(vec![sep, v1, v2]).build();
+        let result = concat_ws.eval(&chunk).unwrap();
+        let result = result.as_utf8();
+        assert_eq!(result.value_at(0), Some("a,b,c"));
+        assert_eq!(result.value_at(1), Some("ac"));
+        assert_eq!(result.value_at(2), Some("c"));
+        assert_eq!(result.value_at(3), None);
+    }
+}
@@ -23,7 +23,10 @@ use risingwave_common::array::{
 };
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{ErrorCode, Result, RwError};
-use risingwave_common::types::{DataType, Datum, Decimal, IntervalUnit, Scalar, ScalarImpl};
+use risingwave_common::types::{
+    DataType, Datum, Decimal, IntervalUnit, JsonbVal, NaiveDateTimeWrapper, NaiveDateWrapper,
+    NaiveTimeWrapper, Scalar, ScalarImpl,
+};
 use risingwave_common::{ensure, for_all_variants};
 use risingwave_pb::data::data_type::IntervalType::*;
 use risingwave_pb::data::data_type::{IntervalType, TypeName};
@@ -103,6 +106,9 @@ fn literal_type_match(return_type: &DataType, literal: Option<&ScalarImpl>) -> b
                     | (DataType::Timestamp, ScalarImpl::NaiveDateTime(_))
                     | (DataType::Decimal, ScalarImpl::Decimal(_))
                     | (DataType::Interval, ScalarImpl::Interval(_))
+                    | (DataType::Jsonb, ScalarImpl::Jsonb(_))
+                    | (DataType::Bytea, ScalarImpl::Bytea(_))
+                    | (DataType::List { .. }, ScalarImpl::List(_))
             )
         }
         None => true,
@@ -220,6 +226,26 @@ impl<'a> TryFrom<&'a ExprNode> for LiteralExpression {
                             InternalError(format!("Failed to deserialize decimal, reason: {:?}", e))
                         })?,
                 ),
+                TypeName::Date => ScalarImpl::NaiveDate(NaiveDateWrapper::from_protobuf(
+                    i32::from_be_bytes(prost_value.get_body().as_slice().try_into().map_err(
+                        |e| InternalError(format!("Failed to deserialize date, reason: {:?}", e)),
+                    )?),
+                )?),
+                TypeName::Time => ScalarImpl::NaiveTime(NaiveTimeWrapper::from_protobuf(
+                    i64::from_be_bytes(prost_value.get_body().as_slice().try_into().map_err(
+                        |e| InternalError(format!("Failed to deserialize time, reason: {:?}", e)),
+                    )?),
+                )?),
+                TypeName::Timestamp => ScalarImpl::NaiveDateTime(
+                    NaiveDateTimeWrapper::from_protobuf(i64::from_be_bytes(
+                        prost_value.get_body().as_slice().try_into().map_err(|e| {
+                            InternalError(format!(
+                                "Failed to deserialize timestamp, reason: {:?}",
+                                e
+                            ))
+                        })?,
+                    ))?,
+                ),
                 TypeName::Interval => {
                     let bytes = prost_value.get_body();
                     ScalarImpl::Interval(make_interval(
@@ -227,6 +253,15 @@ impl<'a> TryFrom<&'a ExprNode> for LiteralExpression {
                         prost.get_return_type()?.get_interval_type()?,
                     )?)
                 }
+                TypeName::Jsonb => ScalarImpl::Jsonb(
+                    JsonbVal::from_str(std::str::from_utf8(prost_value.get_body()).map_err(
+                        |e| InternalError(format!("Failed to deserialize jsonb, reason: {:?}", e)),
+                    )?)
+                    .map_err(|e| {
+                        InternalError(format!("Failed to deserialize jsonb, reason: {:?}", e))
+                    })?,
+                ),
+                TypeName::Bytea => ScalarImpl::Bytea(prost_value.get_body().as_slice().into()),
                 _ => {
                     return Err(InternalError(format!(
                         "Unrecognized type name: {:?}",
@@ -0,0 +1,124 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cell::Cell;
+use std::convert::TryFrom;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::SystemTime;
+
+use risingwave_common::array::{ArrayBuilder, ArrayRef, DataChunk, I64ArrayBuilder};
+use risingwave_common::ensure;
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_common::util::epoch::Epoch;
+use risingwave_pb::expr::expr_node::Type;
+use risingwave_pb::expr::ExprNode;
+
+use crate::expr::Expression;
+
+tokio::task_local! {
+    /// The epoch that `now()` is evaluated to, i.e. the snapshot of a batch query or the latest
+    /// barrier of a streaming actor.
+    static NOW_EPOCH: Cell<u64>;
+}
+
+/// Runs `f` with `now()` evaluated to the physical time of `epoch`.
+pub async fn with_now_epoch<F: Future>(epoch: u64, f: F) -> F::Output {
+    NOW_EPOCH.scope(Cell::new(epoch), f).await
+}
+
+/// Advances the epoch of `now()` in the scope of [`with_now_epoch`], e.g. when a streaming actor
+/// receives a barrier. Does nothing out of the scope.
+pub fn set_now_epoch(epoch: u64) {
+    let _ = NOW_EPOCH.try_with(|now_epoch| now_epoch.set(epoch));
+}
+
+/// `now()` returns the same `timestamp with time zone` for all the rows evaluated within an epoch,
+/// so that it's deterministic in a query or between two barriers.
+#[derive(Debug)]
+pub struct NowExpression;
+
+impl NowExpression {
+    /// Returns `now()` in microseconds since the UNIX epoch.
+    fn now() -> Result<i64> {
+        let epoch = NOW_EPOCH.try_with(|epoch| epoch.get()).map_err(|_| {
+            RwError::from(InternalError(
+                "now() must be evaluated in the scope of an epoch".to_string(),
+            ))
+        })?;
+        let since_unix_epoch = Epoch(epoch)
+            .as_system_time()
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap();
+        Ok(since_unix_epoch.as_micros() as i64)
+    }
+}
+
+impl Expression for NowExpression {
+    fn return_type(&self) -> DataType {
+        DataType::Timestampz
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let now = Self::now()?;
+        let mut builder = I64ArrayBuilder::new(input.cardinality())?;
+        for _ in 0..input.cardinality() {
+            builder.append(Some(now))?;
+        }
+        Ok(Arc::new(builder.finish()?.into()))
+    }
+}
+
+impl<'a> TryFrom<&'a ExprNode> for NowExpression {
+    type Error = RwError;
+
+    fn try_from(prost: &'a ExprNode) -> Result<Self> {
+        ensure!(prost.get_expr_type()? == Type::Now);
+        ensure!(DataType::from(prost.get_return_type()?) == DataType::Timestampz);
+        Ok(NowExpression)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_now() {
+        let chunk = DataChunk::new_dummy(3);
+        assert!(NowExpression.eval(&chunk).is_err());
+
+        let epoch = Epoch::from_unix_millis(1_650_000_000_000).unwrap();
+        with_now_epoch(epoch.0, async {
+            let result = NowExpression.eval(&chunk).unwrap();
+            assert_eq!(result.len(), 3);
+            assert_eq!(
+                result.datum_at(2),
+                Some(ScalarImpl::Int64(1_650_000_000_000_000))
+            );
+
+            set_now_epoch(epoch.0 + 1);
+            let result = NowExpression.eval(&chunk).unwrap();
+            assert_eq!(
+                result.datum_at(0),
+                Some(ScalarImpl::Int64(1_650_000_000_001_000))
+            );
+        })
+        .await;
+    }
+}
@@ -0,0 +1,265 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use risingwave_common::array::{Array, ArrayImpl, ArrayRef, DataChunk, Utf8Array};
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
+
+use crate::expr::{BoxedExpression, Expression};
+use crate::vector_op::regexp::{regexp_eq, regexp_match, regexp_replace, RegexpContext};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegexpKind {
+    /// `s ~ pattern` and `s ~* pattern`.
+    Eq,
+    /// `s SIMILAR TO pattern [ESCAPE escape]`.
+    SimilarTo,
+    /// `regexp_match(s, pattern [, flags])`.
+    Match,
+    /// `regexp_replace(s, pattern, replacement [, flags])`.
+    Replace,
+}
+
+impl RegexpKind {
+    /// Compiles `pattern` with `option`, which is the escape character for `SIMILAR TO` and the
+    /// flags for the others.
+    pub fn compile(self, pattern: &str, option: Option<&str>) -> Result<RegexpContext> {
+        if self == Self::SimilarTo {
+            let escape = match option.map(|o| (o.chars().next(), o.chars().count())) {
+                None => None,
+                Some((c, 1)) => c,
+                Some(_) => {
+                    return Err(InvalidInputSyntax(format!(
+                        "invalid escape string: \"{}\"",
+                        option.unwrap()
+                    ))
+                    .into())
+                }
+            };
+            return RegexpContext::from_similar(pattern, escape);
+        }
+        let ctx = RegexpContext::new(pattern, option.unwrap_or_default())?;
+        if ctx.global && self != Self::Replace {
+            return Err(InvalidInputSyntax(
+                "the \"global\" option is only supported by regexp_replace".to_string(),
+            )
+            .into());
+        }
+        Ok(ctx)
+    }
+}
+
+#[derive(Debug)]
+pub enum RegexpPattern {
+    /// A constant pattern is compiled only once when building the expression. `None` means the
+    /// pattern or the flags are null, so are all the results.
+    Constant(Option<RegexpContext>),
+    /// Otherwise the pattern is compiled for each row.
+    Dynamic(BoxedExpression),
+}
+
+/// `RegexpExpression` evaluates regular expression operators and functions over strings.
+#[derive(Debug)]
+pub struct RegexpExpression {
+    kind: RegexpKind,
+    text: BoxedExpression,
+    pattern: RegexpPattern,
+    /// Flags or the escape character, which must be constant. Only used by dynamic patterns.
+    option: Option<String>,
+    /// The replacement of `regexp_replace`.
+    replacement: Option<BoxedExpression>,
+    return_type: DataType,
+}
+
+impl RegexpExpression {
+    pub fn new(
+        kind: RegexpKind,
+        text: BoxedExpression,
+        pattern: RegexpPattern,
+        option: Option<String>,
+        replacement: Option<BoxedExpression>,
+        return_type: DataType,
+    ) -> Self {
+        debug_assert_eq!(replacement.is_some(), kind == RegexpKind::Replace);
+        Self {
+            kind,
+            text,
+            pattern,
+            option,
+            replacement,
+            return_type,
+        }
+    }
+
+    fn eval_row(
+        &self,
+        idx: usize,
+        text: &Utf8Array,
+        pattern: Option<&ArrayImpl>,
+        replacement: Option<&ArrayImpl>,
+    ) -> Result<Datum> {
+        let text = match text.value_at(idx) {
+            Some(text) => text,
+            None => return Ok(None),
+        };
+        let compiled;
+        let ctx = match &self.pattern {
+            RegexpPattern::Constant(ctx) => ctx.as_ref(),
+            RegexpPattern::Dynamic(_) => match pattern.unwrap().as_utf8().value_at(idx) {
+                Some(pattern) => {
+                    compiled = self.kind.compile(pattern, self.option.as_deref())?;
+                    Some(&compiled)
+                }
+                None => None,
+            },
+        };
+        let ctx = match ctx {
+            Some(ctx) => ctx,
+            None => return Ok(None),
+        };
+        Ok(match self.kind {
+            RegexpKind::Eq | RegexpKind::SimilarTo => Some(ScalarImpl::Bool(regexp_eq(ctx, text))),
+            RegexpKind::Match => regexp_match(ctx, text).map(ScalarImpl::List),
+            RegexpKind::Replace => replacement
+                .unwrap()
+                .as_utf8()
+                .value_at(idx)
+                .map(|r| ScalarImpl::Utf8(regexp_replace(ctx, text, r))),
+        })
+    }
+}
+
+impl Expression for RegexpExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let text_array = self.text.eval(input)?;
+        let pattern_array = match &self.pattern {
+            RegexpPattern::Constant(_) => None,
+            RegexpPattern::Dynamic(pattern) => Some(pattern.eval(input)?),
+        };
+        let replacement_array = match &self.replacement {
+            Some(replacement) => Some(replacement.eval(input)?),
+            None => None,
+        };
+
+        let mut output_array = self.return_type.create_array_builder(input.capacity())?;
+        for idx in 0..input.capacity() {
+            if let Some(visibility) = input.get_visibility_ref() {
+                if !visibility.is_set(idx)? {
+                    continue;
+                }
+            }
+            let datum = self.eval_row(
+                idx,
+                text_array.as_utf8(),
+                pattern_array.as_deref(),
+                replacement_array.as_deref(),
+            )?;
+            output_array.append_datum(&datum)?;
+        }
+        Ok(Arc::new(output_array.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::ListValue;
+    use risingwave_common::column;
+
+    use super::*;
+    use crate::expr::InputRefExpression;
+
+    fn constant(kind: RegexpKind, pattern: &str, option: Option<&str>) -> RegexpPattern {
+        RegexpPattern::Constant(Some(kind.compile(pattern, option).unwrap()))
+    }
+
+    #[test]
+    fn test_regexp_eq_expr() {
+        let expr = RegexpExpression::new(
+            RegexpKind::Eq,
+            InputRefExpression::new(DataType::Varchar, 0).boxed(),
+            constant(RegexpKind::Eq, "^b.r", Some("i")),
+            None,
+            None,
+            DataType::Boolean,
+        );
+        let column = column! { Utf8Array, [Some("bar"), Some("foo"), None, Some("BAR")] };
+        let chunk = DataChunk::builder().columns(vec![column]).build();
+        let result = expr.eval(&chunk).unwrap();
+        assert_eq!(result.datum_at(0), Some(ScalarImpl::Bool(true)));
+        assert_eq!(result.datum_at(1), Some(ScalarImpl::Bool(false)));
+        assert_eq!(result.datum_at(2), None);
+        assert_eq!(result.datum_at(3), Some(ScalarImpl::Bool(true)));
+    }
+
+    #[test]
+    fn test_regexp_dynamic_pattern() {
+        let expr = RegexpExpression::new(
+            RegexpKind::Match,
+            InputRefExpression::new(DataType::Varchar, 0).boxed(),
+            RegexpPattern::Dynamic(InputRefExpression::new(DataType::Varchar, 1).boxed()),
+            None,
+            None,
+            DataType::List {
+                datatype: Box::new(DataType::Varchar),
+            },
+        );
+        let texts = column! { Utf8Array, [Some("foobar"), Some("foobar"), Some("foobar")] };
+        let patterns = column! { Utf8Array, [Some("o(b)"), Some("x"), None] };
+        let chunk = DataChunk::builder().columns(vec![texts, patterns]).build();
+        let result = expr.eval(&chunk).unwrap();
+        assert_eq!(
+            result.datum_at(0),
+            Some(ScalarImpl::List(ListValue::new(vec![Some(
+                ScalarImpl::Utf8("b".into())
+            )])))
+        );
+        assert_eq!(result.datum_at(1), None);
+        assert_eq!(result.datum_at(2), None);
+    }
+
+    #[test]
+    fn test_regexp_replace_expr() {
+        let expr = RegexpExpression::new(
+            RegexpKind::Replace,
+            InputRefExpression::new(DataType::Varchar, 0).boxed(),
+            constant(RegexpKind::Replace, "o+", Some("g")),
+            None,
+            Some(InputRefExpression::new(DataType::Varchar, 1).boxed()),
+            DataType::Varchar,
+        );
+        let texts = column! { Utf8Array, [Some("foo boo"), Some("bar")] };
+        let replacements = column! { Utf8Array, [Some("0"), None] };
+        let chunk = DataChunk::builder()
+            .columns(vec![texts, replacements])
+            .build();
+        let result = expr.eval(&chunk).unwrap();
+        assert_eq!(result.datum_at(0), Some(ScalarImpl::Utf8("f0 b0".into())));
+        assert_eq!(result.datum_at(1), None);
+    }
+
+    #[test]
+    fn test_regexp_compile_options() {
+        assert!(RegexpKind::Match.compile("a", Some("g")).is_err());
+        assert!(RegexpKind::Replace.compile("a", Some("g")).is_ok());
+        assert!(RegexpKind::SimilarTo.compile("a", Some("##")).is_err());
+        assert!(RegexpKind::SimilarTo.compile("a#%", Some("#")).is_ok());
+    }
+}
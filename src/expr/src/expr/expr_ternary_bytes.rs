@@ -19,7 +19,9 @@ use risingwave_common::types::DataType;
 
 use crate::expr::template::TernaryBytesExpression;
 use crate::expr::BoxedExpression;
+use crate::vector_op::pad::{lpad, rpad};
 use crate::vector_op::replace::replace;
+use crate::vector_op::split_part::split_part;
 use crate::vector_op::substr::substr_start_for;
 use crate::vector_op::translate::translate;
 
@@ -74,6 +76,57 @@ pub fn new_translate_expr(
     )
 }
 
+pub fn new_split_part_expr(
+    s: BoxedExpression,
+    delimiter: BoxedExpression,
+    n: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(
+        TernaryBytesExpression::<Utf8Array, Utf8Array, I32Array, _>::new(
+            s,
+            delimiter,
+            n,
+            return_type,
+            split_part,
+        ),
+    )
+}
+
+pub fn new_lpad_expr(
+    s: BoxedExpression,
+    length: BoxedExpression,
+    fill: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(
+        TernaryBytesExpression::<Utf8Array, I32Array, Utf8Array, _>::new(
+            s,
+            length,
+            fill,
+            return_type,
+            lpad,
+        ),
+    )
+}
+
+pub fn new_rpad_expr(
+    s: BoxedExpression,
+    length: BoxedExpression,
+    fill: BoxedExpression,
+    return_type: DataType,
+) -> BoxedExpression {
+    Box::new(
+        TernaryBytesExpression::<Utf8Array, I32Array, Utf8Array, _>::new(
+            s,
+            length,
+            fill,
+            return_type,
+            rpad,
+        ),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_common::array::DataChunk;
@@ -181,4 +234,47 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_split_part_pad() {
+        let literal_str = |s: &str| {
+            Box::new(LiteralExpression::new(
+                DataType::Varchar,
+                Some(ScalarImpl::from(String::from(s))),
+            ))
+        };
+        let literal_i32 = |v: i32| {
+            Box::new(LiteralExpression::new(
+                DataType::Int32,
+                Some(ScalarImpl::Int32(v)),
+            ))
+        };
+
+        let expr = new_split_part_expr(
+            literal_str("a,b,c"),
+            literal_str(","),
+            literal_i32(2),
+            DataType::Varchar,
+        );
+        let res = expr.eval(&DataChunk::new_dummy(1)).unwrap();
+        assert_eq!(res.to_datum(), Some(ScalarImpl::from(String::from("b"))));
+
+        let expr = new_lpad_expr(
+            literal_str("1"),
+            literal_i32(3),
+            literal_str("0"),
+            DataType::Varchar,
+        );
+        let res = expr.eval(&DataChunk::new_dummy(1)).unwrap();
+        assert_eq!(res.to_datum(), Some(ScalarImpl::from(String::from("001"))));
+
+        let expr = new_rpad_expr(
+            literal_str("1"),
+            literal_i32(3),
+            literal_str("0"),
+            DataType::Varchar,
+        );
+        let res = expr.eval(&DataChunk::new_dummy(1)).unwrap();
+        assert_eq!(res.to_datum(), Some(ScalarImpl::from(String::from("100"))));
+    }
 }
@@ -0,0 +1,470 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! User-defined scalar functions implemented in WebAssembly.
+//!
+//! A function named `f` is an export `f` of the module. Arguments and results are passed as wasm
+//! values:
+//! - `boolean`, `smallint` and `int` as `i32`, `bigint` as `i64`, `real` as `f32` and `double
+//!   precision` as `f64`.
+//! - A `varchar` argument as 2 `i32`s, the offset and length of the string in the memory of the
+//!   module. The string is copied into a buffer allocated by the exported `alloc(len: i32) -> i32`.
+//! - A `varchar` result as an `i64`, with the offset in the high 32 bits and the length in the low
+//!   32 bits.
+//!
+//! The functions are strict: they are not called on null arguments, and the results are null.
+//!
+//! The modules are sandboxed. No host functions are linked, so they can't do any I/O, and each
+//! call is limited in memory and in the instructions executed.
+
+use std::fmt;
+use std::sync::Arc;
+
+use itertools::Itertools;
+use lazy_static::lazy_static;
+use risingwave_common::array::{ArrayRef, DataChunk};
+use risingwave_common::error::ErrorCode::{InternalError, InvalidInputSyntax};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{DataType, Datum, ScalarImpl, ScalarRefImpl};
+use wasmtime::{
+    Config, Engine, ExternType, Func, Instance, Memory, Module, Store, StoreLimits,
+    StoreLimitsBuilder, Val, ValType,
+};
+
+use crate::expr::{BoxedExpression, Expression};
+
+/// The max size of the memory of an instance.
+const MAX_MEMORY_SIZE: usize = 64 << 20;
+/// The number of instructions a call can execute, roughly.
+const FUEL_PER_ROW: u64 = 1 << 24;
+
+lazy_static! {
+    static ref ENGINE: Engine = {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        Engine::new(&config).expect("failed to create the wasm engine")
+    };
+}
+
+/// A function exported by a WebAssembly module, see the [module-level documentation](self).
+#[derive(Clone)]
+pub struct WasmFunction {
+    name: String,
+    arg_types: Vec<DataType>,
+    return_type: DataType,
+    module: Module,
+}
+
+impl WasmFunction {
+    /// Compiles the module, and checks that it exports the function with a signature matching
+    /// the argument and return types. `body` is either the binary or the text format.
+    pub fn new(
+        name: &str,
+        arg_types: Vec<DataType>,
+        return_type: DataType,
+        body: &[u8],
+    ) -> Result<Self> {
+        let invalid = |msg: String| -> RwError {
+            InvalidInputSyntax(format!("function {}: {}", name, msg)).into()
+        };
+        let module =
+            Module::new(&ENGINE, body).map_err(|e| invalid(format!("invalid module: {}", e)))?;
+
+        let mut params = vec![];
+        for data_type in &arg_types {
+            params.extend_from_slice(param_types(data_type).map_err(&invalid)?);
+        }
+        let result = result_type(&return_type).map_err(&invalid)?;
+        match module.get_export(name) {
+            Some(ExternType::Func(ty))
+                if ty.params().eq(params.iter().cloned()) && ty.results().eq([result]) => {}
+            Some(ExternType::Func(ty)) => {
+                return Err(invalid(format!(
+                    "expects the export to be {:?} -> {:?}, but it's {:?} -> {:?}",
+                    params,
+                    result,
+                    ty.params().collect_vec(),
+                    ty.results().collect_vec()
+                )))
+            }
+            _ => return Err(invalid(format!("the module doesn't export `{}`", name))),
+        }
+
+        if arg_types.contains(&DataType::Varchar) || return_type == DataType::Varchar {
+            let exports_memory = matches!(module.get_export("memory"), Some(ExternType::Memory(_)));
+            let exports_alloc = matches!(
+                module.get_export("alloc"),
+                Some(ExternType::Func(ty))
+                    if ty.params().eq([ValType::I32]) && ty.results().eq([ValType::I32])
+            );
+            if !exports_memory || !exports_alloc {
+                return Err(invalid(
+                    "strings are passed in the memory, which requires the module to export \
+                     `memory` and `alloc(i32) -> i32`"
+                        .to_string(),
+                ));
+            }
+        }
+
+        Ok(Self {
+            name: name.to_string(),
+            arg_types,
+            return_type,
+            module,
+        })
+    }
+
+    /// Creates an instance to make at most `num_calls` calls.
+    fn instantiate(&self, num_calls: usize) -> Result<WasmInstance> {
+        let limits = StoreLimitsBuilder::new()
+            .memory_size(MAX_MEMORY_SIZE)
+            .build();
+        let mut store = Store::new(&ENGINE, limits);
+        store.limiter(|limits| limits);
+        store
+            .add_fuel(FUEL_PER_ROW * num_calls as u64)
+            .map_err(|e| self.error(e))?;
+        let instance = Instance::new(&mut store, &self.module, &[]).map_err(|e| self.error(e))?;
+        let func = instance.get_func(&mut store, &self.name).unwrap();
+        let memory = instance.get_memory(&mut store, "memory");
+        let alloc = instance.get_func(&mut store, "alloc");
+        Ok(WasmInstance {
+            function: self,
+            store,
+            func,
+            memory,
+            alloc,
+        })
+    }
+
+    fn error(&self, e: impl fmt::Display) -> RwError {
+        InternalError(format!("function {}: {}", self.name, e)).into()
+    }
+}
+
+impl fmt::Debug for WasmFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WasmFunction")
+            .field("name", &self.name)
+            .field("arg_types", &self.arg_types)
+            .field("return_type", &self.return_type)
+            .finish()
+    }
+}
+
+/// The wasm types a value is passed as.
+fn param_types(data_type: &DataType) -> std::result::Result<&'static [ValType], String> {
+    match data_type {
+        DataType::Boolean | DataType::Int16 | DataType::Int32 => Ok(&[ValType::I32]),
+        DataType::Int64 => Ok(&[ValType::I64]),
+        DataType::Float32 => Ok(&[ValType::F32]),
+        DataType::Float64 => Ok(&[ValType::F64]),
+        DataType::Varchar => Ok(&[ValType::I32, ValType::I32]),
+        _ => Err(format!("type {:?} is not supported", data_type)),
+    }
+}
+
+/// The wasm type a value is returned as.
+fn result_type(data_type: &DataType) -> std::result::Result<ValType, String> {
+    match data_type {
+        DataType::Varchar => Ok(ValType::I64),
+        _ => param_types(data_type).map(|types| types[0].clone()),
+    }
+}
+
+/// An instance of the module, whose memory is dropped along with it.
+struct WasmInstance<'a> {
+    function: &'a WasmFunction,
+    store: Store<StoreLimits>,
+    func: Func,
+    memory: Option<Memory>,
+    alloc: Option<Func>,
+}
+
+impl WasmInstance<'_> {
+    fn call(&mut self, args: &[ScalarRefImpl<'_>]) -> Result<ScalarImpl> {
+        let mut params = Vec::with_capacity(args.len());
+        for arg in args {
+            match *arg {
+                ScalarRefImpl::Bool(v) => params.push(Val::I32(v as i32)),
+                ScalarRefImpl::Int16(v) => params.push(Val::I32(v as i32)),
+                ScalarRefImpl::Int32(v) => params.push(Val::I32(v)),
+                ScalarRefImpl::Int64(v) => params.push(Val::I64(v)),
+                ScalarRefImpl::Float32(v) => params.push(Val::F32(v.0.to_bits())),
+                ScalarRefImpl::Float64(v) => params.push(Val::F64(v.0.to_bits())),
+                ScalarRefImpl::Utf8(v) => {
+                    let offset = self.write_bytes(v.as_bytes())?;
+                    params.push(Val::I32(offset));
+                    params.push(Val::I32(v.len() as i32));
+                }
+                _ => unreachable!("the argument types are checked when the function is created"),
+            }
+        }
+
+        let mut results = [Val::I32(0)];
+        self.func
+            .call(&mut self.store, &params, &mut results)
+            .map_err(|e| self.function.error(e))?;
+        let result = &results[0];
+        Ok(match self.function.return_type {
+            DataType::Boolean => ScalarImpl::Bool(result.unwrap_i32() != 0),
+            DataType::Int16 => {
+                ScalarImpl::Int16(i16::try_from(result.unwrap_i32()).map_err(|_| {
+                    self.function
+                        .error("the result is out of the range of smallint")
+                })?)
+            }
+            DataType::Int32 => ScalarImpl::Int32(result.unwrap_i32()),
+            DataType::Int64 => ScalarImpl::Int64(result.unwrap_i64()),
+            DataType::Float32 => ScalarImpl::Float32(result.unwrap_f32().into()),
+            DataType::Float64 => ScalarImpl::Float64(result.unwrap_f64().into()),
+            DataType::Varchar => {
+                let packed = result.unwrap_i64() as u64;
+                ScalarImpl::Utf8(self.read_string((packed >> 32) as usize, packed as u32 as usize)?)
+            }
+            _ => unreachable!("the return type is checked when the function is created"),
+        })
+    }
+
+    /// Copies the bytes into a buffer allocated by the module, and returns the offset.
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<i32> {
+        let mut results = [Val::I32(0)];
+        self.alloc
+            .unwrap()
+            .call(
+                &mut self.store,
+                &[Val::I32(bytes.len() as i32)],
+                &mut results,
+            )
+            .map_err(|e| self.function.error(e))?;
+        let offset = results[0].unwrap_i32();
+        self.memory
+            .unwrap()
+            .write(&mut self.store, offset as usize, bytes)
+            .map_err(|e| {
+                self.function
+                    .error(format!("`alloc` returned a bad buffer: {}", e))
+            })?;
+        Ok(offset)
+    }
+
+    fn read_string(&self, offset: usize, len: usize) -> Result<String> {
+        let bytes = self
+            .memory
+            .unwrap()
+            .data(&self.store)
+            .get(offset..offset + len)
+            .ok_or_else(|| {
+                self.function
+                    .error("the result string is out of the memory")
+            })?;
+        String::from_utf8(bytes.to_vec())
+            .map_err(|_| self.function.error("the result string is not valid UTF-8"))
+    }
+}
+
+/// `UdfExpression` calls a [`WasmFunction`] row by row. A new instance of the module is created
+/// for each chunk, so that the memory allocated for the arguments is released with it.
+#[derive(Debug)]
+pub struct UdfExpression {
+    children: Vec<BoxedExpression>,
+    return_type: DataType,
+    function: WasmFunction,
+}
+
+impl UdfExpression {
+    pub fn new(
+        children: Vec<BoxedExpression>,
+        return_type: DataType,
+        function: WasmFunction,
+    ) -> Self {
+        Self {
+            children,
+            return_type,
+            function,
+        }
+    }
+}
+
+impl Expression for UdfExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let arrays: Vec<ArrayRef> = self
+            .children
+            .iter()
+            .map(|child| child.eval(input))
+            .try_collect()?;
+
+        let mut instance = self.function.instantiate(input.cardinality())?;
+        let mut output_array = self.return_type.create_array_builder(input.capacity())?;
+        for idx in 0..input.capacity() {
+            if let Some(visibility) = input.get_visibility_ref() {
+                if !visibility.is_set(idx)? {
+                    continue;
+                }
+            }
+            let args: Option<Vec<_>> = arrays.iter().map(|array| array.value_at(idx)).collect();
+            let datum: Datum = match args {
+                Some(args) => Some(instance.call(&args)?),
+                None => None,
+            };
+            output_array.append_datum(&datum)?;
+        }
+        Ok(Arc::new(output_array.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{F64Array, I32Array, Utf8Array};
+    use risingwave_common::column;
+
+    use super::*;
+    use crate::expr::InputRefExpression;
+
+    const GCD: &str = r#"
+        (module
+          (func (export "gcd") (param $a i32) (param $b i32) (result i32)
+            (local $t i32)
+            (block $done
+              (loop $loop
+                (br_if $done (i32.eqz (local.get $b)))
+                (local.set $t (i32.rem_s (local.get $a) (local.get $b)))
+                (local.set $a (local.get $b))
+                (local.set $b (local.get $t))
+                (br $loop)))
+            (local.get $a)))
+    "#;
+
+    /// `double_it(s)` repeats the string twice, with a bump allocator.
+    const DOUBLE_IT: &str = r#"
+        (module
+          (memory (export "memory") 1)
+          (global $next (mut i32) (i32.const 0))
+          (func $alloc (export "alloc") (param $len i32) (result i32)
+            (local $ptr i32)
+            (local.set $ptr (global.get $next))
+            (global.set $next (i32.add (global.get $next) (local.get $len)))
+            (local.get $ptr))
+          (func (export "double_it") (param $ptr i32) (param $len i32) (result i64)
+            (local $out i32)
+            (local.set $out (call $alloc (i32.mul (local.get $len) (i32.const 2))))
+            (memory.copy (local.get $out) (local.get $ptr) (local.get $len))
+            (memory.copy
+              (i32.add (local.get $out) (local.get $len)) (local.get $ptr) (local.get $len))
+            (i64.or
+              (i64.shl (i64.extend_i32_u (local.get $out)) (i64.const 32))
+              (i64.extend_i32_u (i32.mul (local.get $len) (i32.const 2))))))
+    "#;
+
+    const SPIN: &str = r#"
+        (module
+          (func (export "spin") (param f64) (result f64)
+            (loop $loop (br $loop))
+            (local.get 0)))
+    "#;
+
+    #[test]
+    fn test_udf_gcd() {
+        let function = WasmFunction::new(
+            "gcd",
+            vec![DataType::Int32, DataType::Int32],
+            DataType::Int32,
+            GCD.as_bytes(),
+        )
+        .unwrap();
+        let expr = UdfExpression::new(
+            vec![
+                InputRefExpression::new(DataType::Int32, 0).boxed(),
+                InputRefExpression::new(DataType::Int32, 1).boxed(),
+            ],
+            DataType::Int32,
+            function,
+        );
+        let v1 = column! { I32Array, [Some(12), Some(7), None] };
+        let v2 = column! { I32Array, [Some(18), Some(5), Some(1)] };
+        let chunk = DataChunk::builder().columns(vec![v1, v2]).build();
+        let result = expr.eval(&chunk).unwrap();
+        assert_eq!(result.datum_at(0), Some(ScalarImpl::Int32(6)));
+        assert_eq!(result.datum_at(1), Some(ScalarImpl::Int32(1)));
+        assert_eq!(result.datum_at(2), None);
+    }
+
+    #[test]
+    fn test_udf_string() {
+        let function = WasmFunction::new(
+            "double_it",
+            vec![DataType::Varchar],
+            DataType::Varchar,
+            DOUBLE_IT.as_bytes(),
+        )
+        .unwrap();
+        let expr = UdfExpression::new(
+            vec![InputRefExpression::new(DataType::Varchar, 0).boxed()],
+            DataType::Varchar,
+            function,
+        );
+        let v1 = column! { Utf8Array, [Some("ab"), None, Some("")] };
+        let chunk = DataChunk::builder().columns(vec![v1]).build();
+        let result = expr.eval(&chunk).unwrap();
+        assert_eq!(
+            result.datum_at(0),
+            Some(ScalarImpl::Utf8("abab".to_string()))
+        );
+        assert_eq!(result.datum_at(1), None);
+        assert_eq!(result.datum_at(2), Some(ScalarImpl::Utf8("".to_string())));
+    }
+
+    #[test]
+    fn test_udf_out_of_fuel() {
+        let function = WasmFunction::new(
+            "spin",
+            vec![DataType::Float64],
+            DataType::Float64,
+            SPIN.as_bytes(),
+        )
+        .unwrap();
+        let expr = UdfExpression::new(
+            vec![InputRefExpression::new(DataType::Float64, 0).boxed()],
+            DataType::Float64,
+            function,
+        );
+        let v1 = column! { F64Array, [Some(1.0f64.into())] };
+        let chunk = DataChunk::builder().columns(vec![v1]).build();
+        assert!(expr.eval(&chunk).is_err());
+    }
+
+    #[test]
+    fn test_invalid_udf() {
+        let new = |name: &str, arg_types: Vec<DataType>, return_type, body: &str| {
+            WasmFunction::new(name, arg_types, return_type, body.as_bytes())
+        };
+        // Not a module.
+        assert!(new("gcd", vec![], DataType::Int32, "gcd").is_err());
+        // The export doesn't exist.
+        assert!(new("lcm", vec![DataType::Int32; 2], DataType::Int32, GCD).is_err());
+        // The signature doesn't match.
+        assert!(new("gcd", vec![DataType::Int64; 2], DataType::Int32, GCD).is_err());
+        assert!(new("gcd", vec![DataType::Int32; 2], DataType::Float64, GCD).is_err());
+        // Unsupported types.
+        assert!(new("gcd", vec![DataType::Int32; 2], DataType::Date, GCD).is_err());
+        // Strings require `memory` and `alloc`.
+        let no_alloc =
+            r#"(module (func (export "len") (param i32 i32) (result i32) (local.get 1)))"#;
+        assert!(new("len", vec![DataType::Varchar], DataType::Int32, no_alloc).is_err());
+    }
+}
@@ -20,19 +20,26 @@ use risingwave_common::types::*;
 use risingwave_pb::expr::expr_node::Type as ProstType;
 
 use super::template::{UnaryBytesExpression, UnaryExpression};
+use crate::expr::expr_array::ListCastExpression;
 use crate::expr::expr_is_null::{IsNotNullExpression, IsNullExpression};
 use crate::expr::pg_sleep::PgSleepExpression;
 use crate::expr::template::UnaryNullableExpression;
-use crate::expr::BoxedExpression;
-use crate::vector_op::arithmetic_op::general_neg;
+use crate::expr::{BoxedExpression, Expression};
+use crate::vector_op::arithmetic_op::{general_abs, general_neg};
 use crate::vector_op::ascii::ascii;
 use crate::vector_op::cast::*;
 use crate::vector_op::cmp::{is_false, is_not_false, is_not_true, is_true};
 use crate::vector_op::conjunction;
+use crate::vector_op::initcap::initcap;
+use crate::vector_op::jsonb::jsonb_typeof;
 use crate::vector_op::length::length_default;
 use crate::vector_op::lower::lower;
 use crate::vector_op::ltrim::ltrim;
+use crate::vector_op::math::{ceil_decimal, ceil_f64, floor_decimal, floor_f64, ln_f64};
+use crate::vector_op::md5::md5;
+use crate::vector_op::reverse::reverse;
 use crate::vector_op::rtrim::rtrim;
+use crate::vector_op::sqrt::{sqrt_decimal, sqrt_f64};
 use crate::vector_op::trim::trim;
 use crate::vector_op::upper::upper;
 
@@ -103,6 +110,11 @@ macro_rules! gen_cast {
 
             { boolean, varchar, bool_to_str },
 
+            { varchar, jsonb, str_to_jsonb },
+            { jsonb, varchar, jsonb_to_str },
+            { varchar, bytea, str_to_bytea },
+            { bytea, varchar, bytea_to_str },
+
             { int16, int32, general_cast },
             { int16, int64, general_cast },
             { int16, float32, general_cast },
@@ -136,31 +148,35 @@ macro_rules! gen_cast {
             { decimal, float32, to_f32 },
             { decimal, float64, to_f64 },
 
-            { date, timestamp, date_to_timestamp }
+            { date, timestamp, date_to_timestamp },
+            { timestamp, date, timestamp_to_date }
         }
     };
 }
 
-/// This macro helps to create neg expression.
-/// It receives all the types that impl `CheckedNeg` trait.
+/// This macro helps to create numeric expressions whose return type is the input type, e.g. `Neg`
+/// and `Abs`. It receives all the types that impl `CheckedNeg` trait.
+/// * `$expr_type`: the expression type, for the error message
+/// * `$func`: the scalar function, generic over the input type
 /// * `$child`: child expression
 /// * `$ret`: return expression
 /// * `$input`: input type
-macro_rules! gen_neg_impl {
-    ($child:expr, $ret:expr, $($input:ident),*) => {
+macro_rules! gen_unary_numeric_impl {
+    ($expr_type:expr, $func:ident, $child:expr, $ret:expr, $($input:ident),*) => {
         match $child.return_type() {
             $(
                 $input! {type_match_pattern} => Box::new(
                     UnaryExpression::<$input! {type_array}, $input! {type_array}, _>::new(
                         $child,
                         $ret.clone(),
-                        general_neg,
+                        $func,
                     )
                 ),
             )*
             _ => {
                 return Err(ErrorCode::NotImplemented(format!(
-                    "Neg is not supported on {:?}",
+                    "{:?} is not supported on {:?}",
+                    $expr_type,
                     $child.return_type()
                 ), 112.into())
                 .into());
@@ -169,9 +185,11 @@ macro_rules! gen_neg_impl {
     };
 }
 
-macro_rules! gen_neg {
-    ($child:tt, $ret:tt) => {
-        gen_neg_impl! {
+macro_rules! gen_unary_numeric {
+    ($expr_type:expr, $func:ident, $child:tt, $ret:tt) => {
+        gen_unary_numeric_impl! {
+            $expr_type,
+            $func,
             $child,
             $ret,
             int16,
@@ -192,6 +210,10 @@ pub fn new_unary_expr(
     use crate::expr::data_types::*;
 
     let expr: BoxedExpression = match (expr_type, return_type.clone(), child_expr.return_type()) {
+        (ProstType::Cast, DataType::List { .. }, _)
+        | (ProstType::Cast, _, DataType::List { .. }) => {
+            ListCastExpression::new(child_expr, return_type)?.boxed()
+        }
         (ProstType::Cast, _, _) => gen_cast! { child_expr, return_type, },
         (ProstType::Not, _, _) => {
             Box::new(UnaryNullableExpression::<BoolArray, BoolArray, _>::new(
@@ -240,15 +262,89 @@ pub fn new_unary_expr(
             return_type,
             lower,
         )),
+        (ProstType::Reverse, _, _) => Box::new(UnaryBytesExpression::<Utf8Array, _>::new(
+            child_expr,
+            return_type,
+            reverse,
+        )),
+        (ProstType::Md5, _, _) => Box::new(UnaryBytesExpression::<Utf8Array, _>::new(
+            child_expr,
+            return_type,
+            md5,
+        )),
+        (ProstType::Initcap, _, _) => Box::new(UnaryBytesExpression::<Utf8Array, _>::new(
+            child_expr,
+            return_type,
+            initcap,
+        )),
         (ProstType::Ascii, _, _) => Box::new(UnaryExpression::<Utf8Array, I32Array, _>::new(
             child_expr,
             return_type,
             ascii,
         )),
         (ProstType::Neg, _, _) => {
-            gen_neg! { child_expr, return_type }
+            gen_unary_numeric! { ProstType::Neg, general_neg, child_expr, return_type }
+        }
+        (ProstType::Abs, _, _) => {
+            gen_unary_numeric! { ProstType::Abs, general_abs, child_expr, return_type }
+        }
+        (ProstType::Sqrt, DataType::Float64, DataType::Float64) => {
+            Box::new(UnaryExpression::<F64Array, F64Array, _>::new(
+                child_expr,
+                return_type,
+                sqrt_f64,
+            ))
+        }
+        (ProstType::Sqrt, DataType::Decimal, DataType::Decimal) => {
+            Box::new(UnaryExpression::<DecimalArray, DecimalArray, _>::new(
+                child_expr,
+                return_type,
+                sqrt_decimal,
+            ))
+        }
+        (ProstType::Ceil, DataType::Float64, DataType::Float64) => {
+            Box::new(UnaryExpression::<F64Array, F64Array, _>::new(
+                child_expr,
+                return_type,
+                ceil_f64,
+            ))
+        }
+        (ProstType::Ceil, DataType::Decimal, DataType::Decimal) => {
+            Box::new(UnaryExpression::<DecimalArray, DecimalArray, _>::new(
+                child_expr,
+                return_type,
+                ceil_decimal,
+            ))
+        }
+        (ProstType::Floor, DataType::Float64, DataType::Float64) => {
+            Box::new(UnaryExpression::<F64Array, F64Array, _>::new(
+                child_expr,
+                return_type,
+                floor_f64,
+            ))
+        }
+        (ProstType::Floor, DataType::Decimal, DataType::Decimal) => {
+            Box::new(UnaryExpression::<DecimalArray, DecimalArray, _>::new(
+                child_expr,
+                return_type,
+                floor_decimal,
+            ))
+        }
+        (ProstType::Ln, DataType::Float64, DataType::Float64) => {
+            Box::new(UnaryExpression::<F64Array, F64Array, _>::new(
+                child_expr,
+                return_type,
+                ln_f64,
+            ))
         }
         (ProstType::PgSleep, _, DataType::Decimal) => Box::new(PgSleepExpression::new(child_expr)),
+        (ProstType::JsonbTypeof, _, DataType::Jsonb) => {
+            Box::new(UnaryExpression::<JsonbArray, Utf8Array, _>::new(
+                child_expr,
+                return_type,
+                jsonb_typeof,
+            ))
+        }
 
         (expr, ret, child) => {
             return Err(ErrorCode::NotImplemented(format!(
@@ -0,0 +1,129 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{ArrayRef, DataChunk};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Datum, DatumRef};
+
+use crate::expr::{BoxedExpression, Expression};
+
+/// A function evaluated on the arguments of a row.
+pub type VariadicFunction = fn(&[DatumRef<'_>]) -> Datum;
+
+/// `VariadicExpression` evaluates a function taking any number of arguments row by row. Unlike
+/// the expression templates, neither the number nor the types of the arguments are fixed, e.g.
+/// `coalesce(v1, v2, v3)` on any type.
+#[derive(Debug)]
+pub struct VariadicExpression {
+    children: Vec<BoxedExpression>,
+    return_type: DataType,
+    func: VariadicFunction,
+}
+
+impl VariadicExpression {
+    pub fn new(
+        children: Vec<BoxedExpression>,
+        return_type: DataType,
+        func: VariadicFunction,
+    ) -> Self {
+        Self {
+            children,
+            return_type,
+            func,
+        }
+    }
+}
+
+impl Expression for VariadicExpression {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<ArrayRef> {
+        let arrays: Vec<ArrayRef> = self
+            .children
+            .iter()
+            .map(|child| child.eval(input))
+            .try_collect()?;
+
+        let mut output_array = self.return_type.create_array_builder(input.capacity())?;
+        let mut args = Vec::with_capacity(arrays.len());
+        for idx in 0..input.capacity() {
+            if let Some(visibility) = input.get_visibility_ref() {
+                if !visibility.is_set(idx)? {
+                    continue;
+                }
+            }
+            args.clear();
+            args.extend(arrays.iter().map(|array| array.value_at(idx)));
+            let datum = (self.func)(&args);
+            output_array.append_datum(&datum)?;
+        }
+        Ok(Arc::new(output_array.finish()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{I32Array, Utf8Array};
+    use risingwave_common::column;
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+    use crate::expr::{InputRefExpression, LiteralExpression};
+    use crate::vector_op::conditional::{coalesce, greatest};
+
+    #[test]
+    fn test_coalesce() {
+        let expr = VariadicExpression::new(
+            vec![
+                InputRefExpression::new(DataType::Varchar, 0).boxed(),
+                InputRefExpression::new(DataType::Varchar, 1).boxed(),
+                LiteralExpression::new(DataType::Varchar, Some(ScalarImpl::Utf8("c".to_string())))
+                    .boxed(),
+            ],
+            DataType::Varchar,
+            coalesce,
+        );
+        let v1 = column! { Utf8Array, [Some("a"), None, None] };
+        let v2 = column! { Utf8Array, [Some("b"), Some("b"), None] };
+        let chunk = DataChunk::builder().columns(vec![v1, v2]).build();
+        let result = expr.eval(&chunk).unwrap();
+        assert_eq!(result.datum_at(0), Some(ScalarImpl::Utf8("a".to_string())));
+        assert_eq!(result.datum_at(1), Some(ScalarImpl::Utf8("b".to_string())));
+        assert_eq!(result.datum_at(2), Some(ScalarImpl::Utf8("c".to_string())));
+    }
+
+    #[test]
+    fn test_greatest() {
+        let expr = VariadicExpression::new(
+            vec![
+                InputRefExpression::new(DataType::Int32, 0).boxed(),
+                InputRefExpression::new(DataType::Int32, 1).boxed(),
+            ],
+            DataType::Int32,
+            greatest,
+        );
+        let v1 = column! { I32Array, [Some(1), None, None] };
+        let v2 = column! { I32Array, [Some(2), Some(3), None] };
+        let chunk = DataChunk::builder().columns(vec![v1, v2]).build();
+        let result = expr.eval(&chunk).unwrap();
+        assert_eq!(result.datum_at(0), Some(ScalarImpl::Int32(2)));
+        assert_eq!(result.datum_at(1), Some(ScalarImpl::Int32(3)));
+        assert_eq!(result.datum_at(2), None);
+    }
+}
@@ -15,17 +15,23 @@
 mod agg;
 pub mod build_expr_from_prost;
 pub mod data_types;
+mod expr_array;
 mod expr_binary_bytes;
 pub mod expr_binary_nonnull;
 pub mod expr_binary_nullable;
 mod expr_case;
+mod expr_concat_ws;
 mod expr_field;
 mod expr_in;
 mod expr_input_ref;
 mod expr_is_null;
 mod expr_literal;
+mod expr_now;
+mod expr_regexp;
 mod expr_ternary_bytes;
+mod expr_udf;
 pub mod expr_unary;
+mod expr_variadic;
 mod pg_sleep;
 mod template;
 
@@ -36,6 +42,9 @@ use std::sync::Arc;
 pub use agg::AggKind;
 pub use expr_input_ref::InputRefExpression;
 pub use expr_literal::*;
+pub use expr_now::{set_now_epoch, with_now_epoch};
+pub(crate) use expr_regexp::RegexpPattern;
+pub use expr_udf::{UdfExpression, WasmFunction};
 use risingwave_common::array::{ArrayRef, DataChunk, Row};
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::Result;
@@ -44,6 +53,7 @@ use risingwave_pb::expr::ExprNode;
 
 use crate::expr::build_expr_from_prost::*;
 use crate::expr::expr_field::FieldExpression;
+use crate::expr::expr_now::NowExpression;
 
 pub type ExpressionRef = Arc<dyn Expression>;
 
@@ -73,17 +83,24 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
 
     match prost.get_expr_type()? {
         Cast | Upper | Lower | Not | PgSleep | IsTrue | IsNotTrue | IsFalse | IsNotFalse
-        | IsNull | IsNotNull | Neg | Ascii => build_unary_expr_prost(prost),
+        | IsNull | IsNotNull | Neg | Ascii | Sqrt | Abs | Ceil | Floor | Ln | JsonbTypeof
+        | Reverse | Md5 | Initcap => build_unary_expr_prost(prost),
         Equal | NotEqual | LessThan | LessThanOrEqual | GreaterThan | GreaterThanOrEqual => {
             build_binary_expr_prost(prost)
         }
-        Add | Subtract | Multiply | Divide | Modulus => build_binary_expr_prost(prost),
-        Extract | RoundDigit | TumbleStart | Position => build_binary_expr_prost(prost),
+        Add | Subtract | Multiply | Divide | Modulus | Power => build_binary_expr_prost(prost),
+        Extract | RoundDigit | TumbleStart | Position | JsonbContains | Encode | Decode
+        | StartsWith => build_binary_expr_prost(prost),
+        DateTrunc | ToChar | ToTimestamp | AtTimeZone => build_binary_expr_prost(prost),
         StreamNullByRowCount | And | Or => build_nullable_binary_expr_prost(prost),
+        JsonbAccessInner | JsonbAccessStr | JsonbAccessPath => {
+            build_nullable_binary_expr_prost(prost)
+        }
         Substr => build_substr_expr(prost),
         Length => build_length_expr(prost),
         Replace => build_replace_expr(prost),
         Like => build_like_expr(prost),
+        RegexpEq | SimilarTo | RegexpMatch | RegexpReplace => build_regexp_expr(prost),
         Trim => build_trim_expr(prost),
         Ltrim => build_ltrim_expr(prost),
         Rtrim => build_rtrim_expr(prost),
@@ -91,8 +108,16 @@ pub fn build_from_prost(prost: &ExprNode) -> Result<BoxedExpression> {
         InputRef => InputRefExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression),
         Case => build_case_expr(prost),
         Translate => build_translate_expr(prost),
+        ConcatWs => build_concat_ws_expr(prost),
+        ConcatOp | Left | Right | Repeat => build_binary_bytes_expr(prost),
+        SplitPart | Lpad | Rpad => build_ternary_bytes_expr(prost),
         In => build_in_expr(prost),
         Field => FieldExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression),
+        Now => NowExpression::try_from(prost).map(|d| Box::new(d) as BoxedExpression),
+        Array | ArrayAccess | ArrayRangeAccess | ArrayLength | ArrayAppend | ArrayCat
+        | ArrayContains | ArrayAnyEqual | StringToArray => build_array_expr(prost),
+        Coalesce | Nullif | Greatest | Least => build_variadic_expr(prost),
+        Udf => build_udf_expr(prost),
         _ => Err(InternalError(format!(
             "Unsupported expression type: {:?}",
             prost.get_expr_type()
@@ -0,0 +1,266 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::Ordering;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use itertools::{multizip, Itertools};
+use risingwave_common::array::{
+    Array, ArrayBuilder, ArrayImpl, ArrayRef, DataChunk, I32Array, I64Array, IntervalArray,
+    NaiveDateTimeArray,
+};
+use risingwave_common::error::ErrorCode::{
+    InternalError, InvalidInputSyntax, NumericValueOutOfRange,
+};
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, IntervalUnit, NaiveDateTimeWrapper, Scalar, ScalarRef};
+
+use super::{BoxedTableFunction, TableFunction};
+use crate::expr::BoxedExpression;
+use crate::vector_op::arithmetic_op::timestamp_interval_add;
+
+/// The values of a series that can be advanced by a step of type `S`.
+pub trait SeriesValue<S>: Scalar + PartialOrd {
+    fn add_step(&self, step: &S) -> Result<Self>;
+}
+
+impl SeriesValue<i32> for i32 {
+    fn add_step(&self, step: &i32) -> Result<Self> {
+        self.checked_add(*step)
+            .ok_or_else(|| NumericValueOutOfRange.into())
+    }
+}
+
+impl SeriesValue<i64> for i64 {
+    fn add_step(&self, step: &i64) -> Result<Self> {
+        self.checked_add(*step)
+            .ok_or_else(|| NumericValueOutOfRange.into())
+    }
+}
+
+impl SeriesValue<IntervalUnit> for NaiveDateTimeWrapper {
+    fn add_step(&self, step: &IntervalUnit) -> Result<Self> {
+        timestamp_interval_add::<NaiveDateTimeWrapper, IntervalUnit, NaiveDateTimeWrapper>(
+            *self, *step,
+        )
+    }
+}
+
+/// `generate_series(start, stop, step)` returns the values from `start` to `stop` inclusively,
+/// advanced by `step` each time.
+#[derive(Debug)]
+pub struct GenerateSeries<T: Array, S: Array> {
+    start: BoxedExpression,
+    stop: BoxedExpression,
+    step: BoxedExpression,
+    return_type: DataType,
+    _phantom: PhantomData<(T, S)>,
+}
+
+impl<T: Array, S: Array> GenerateSeries<T, S>
+where
+    T::OwnedItem: SeriesValue<S::OwnedItem>,
+    for<'a> &'a T: From<&'a ArrayImpl>,
+    for<'a> &'a S: From<&'a ArrayImpl>,
+{
+    fn new(
+        start: BoxedExpression,
+        stop: BoxedExpression,
+        step: BoxedExpression,
+        return_type: DataType,
+    ) -> Self {
+        Self {
+            start,
+            stop,
+            step,
+            return_type,
+            _phantom: Default::default(),
+        }
+    }
+
+    fn eval_row(
+        &self,
+        start: T::OwnedItem,
+        stop: T::OwnedItem,
+        step: S::OwnedItem,
+    ) -> Result<ArrayRef> {
+        let ascending = match start.add_step(&step)?.partial_cmp(&start) {
+            Some(Ordering::Greater) => true,
+            Some(Ordering::Less) => false,
+            _ => return Err(InvalidInputSyntax("step size cannot equal zero".to_string()).into()),
+        };
+        let mut builder = T::Builder::new(0)?;
+        let mut cur = start;
+        while if ascending { cur <= stop } else { cur >= stop } {
+            builder.append(Some(cur.as_scalar_ref()))?;
+            // The series ends when the next value overflows.
+            cur = match cur.add_step(&step) {
+                Ok(next) => next,
+                Err(_) => break,
+            };
+        }
+        Ok(Arc::new(builder.finish()?.into()))
+    }
+}
+
+impl<T: Array, S: Array> TableFunction for GenerateSeries<T, S>
+where
+    T::OwnedItem: SeriesValue<S::OwnedItem>,
+    for<'a> &'a T: From<&'a ArrayImpl>,
+    for<'a> &'a S: From<&'a ArrayImpl>,
+{
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<Vec<ArrayRef>> {
+        let ret_start = self.start.eval(input)?;
+        let arr_start: &T = ret_start.as_ref().into();
+        let ret_stop = self.stop.eval(input)?;
+        let arr_stop: &T = ret_stop.as_ref().into();
+        let ret_step = self.step.eval(input)?;
+        let arr_step: &S = ret_step.as_ref().into();
+
+        multizip((arr_start.iter(), arr_stop.iter(), arr_step.iter()))
+            .map(|(start, stop, step)| match (start, stop, step) {
+                (Some(start), Some(stop), Some(step)) => self.eval_row(
+                    start.to_owned_scalar(),
+                    stop.to_owned_scalar(),
+                    step.to_owned_scalar(),
+                ),
+                // Any null argument results in an empty set.
+                _ => Ok(Arc::new(T::Builder::new(0)?.finish()?.into())),
+            })
+            .try_collect()
+    }
+}
+
+pub fn new_generate_series(
+    args: Vec<BoxedExpression>,
+    return_type: DataType,
+) -> Result<BoxedTableFunction> {
+    let Some((start, stop, step)) = args.into_iter().collect_tuple() else {
+        return Err(InternalError("generate_series expects 3 arguments".to_string()).into());
+    };
+    match (start.return_type(), step.return_type()) {
+        (DataType::Int32, DataType::Int32) => {
+            Ok(GenerateSeries::<I32Array, I32Array>::new(start, stop, step, return_type).boxed())
+        }
+        (DataType::Int64, DataType::Int64) => {
+            Ok(GenerateSeries::<I64Array, I64Array>::new(start, stop, step, return_type).boxed())
+        }
+        (DataType::Timestamp, DataType::Interval) => Ok(GenerateSeries::<
+            NaiveDateTimeArray,
+            IntervalArray,
+        >::new(
+            start, stop, step, return_type
+        )
+        .boxed()),
+        (start_type, step_type) => Err(InternalError(format!(
+            "generate_series is not supported for {:?} with step {:?}",
+            start_type, step_type
+        ))
+        .into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::{Datum, ScalarImpl};
+
+    use super::*;
+    use crate::expr::{Expression, LiteralExpression};
+    use crate::vector_op::cast::str_to_timestamp;
+
+    fn eval_series(
+        data_type: DataType,
+        step_type: DataType,
+        start: ScalarImpl,
+        stop: ScalarImpl,
+        step: ScalarImpl,
+    ) -> Result<Vec<Datum>> {
+        let series = new_generate_series(
+            vec![
+                LiteralExpression::new(data_type.clone(), Some(start)).boxed(),
+                LiteralExpression::new(data_type.clone(), Some(stop)).boxed(),
+                LiteralExpression::new(step_type, Some(step)).boxed(),
+            ],
+            data_type,
+        )?;
+        let mut sets = series.eval(&DataChunk::new_dummy(1))?;
+        assert_eq!(sets.len(), 1);
+        let set = sets.pop().unwrap();
+        Ok((0..set.len()).map(|i| set.datum_at(i)).collect())
+    }
+
+    #[test]
+    fn test_generate_series_int() {
+        let series = |start: i64, stop: i64, step: i64| -> Result<Vec<Option<i64>>> {
+            let set = eval_series(
+                DataType::Int64,
+                DataType::Int64,
+                start.into(),
+                stop.into(),
+                step.into(),
+            )?;
+            Ok(set.into_iter().map(|v| v.map(|v| v.into_int64())).collect())
+        };
+        assert_eq!(series(1, 3, 1).unwrap(), vec![Some(1), Some(2), Some(3)]);
+        assert_eq!(series(0, 9, 4).unwrap(), vec![Some(0), Some(4), Some(8)]);
+        assert_eq!(series(3, 1, -1).unwrap(), vec![Some(3), Some(2), Some(1)]);
+        assert_eq!(series(4, 3, 1).unwrap(), vec![]);
+        assert_eq!(
+            series(i64::MAX - 1, i64::MAX, 1).unwrap(),
+            vec![Some(i64::MAX - 1), Some(i64::MAX)]
+        );
+        assert!(series(1, 3, 0).is_err());
+
+        let set = eval_series(
+            DataType::Int32,
+            DataType::Int32,
+            1i32.into(),
+            5i32.into(),
+            2i32.into(),
+        )
+        .unwrap();
+        assert_eq!(
+            set,
+            vec![Some(1i32.into()), Some(3i32.into()), Some(5i32.into())]
+        );
+    }
+
+    #[test]
+    fn test_generate_series_timestamp() {
+        let timestamp = |s: &str| Some(ScalarImpl::from(str_to_timestamp(s).unwrap()));
+        let series = |step: IntervalUnit| {
+            eval_series(
+                DataType::Timestamp,
+                DataType::Interval,
+                timestamp("2022-01-01 00:00:00").unwrap(),
+                timestamp("2022-03-15 00:00:00").unwrap(),
+                step.into(),
+            )
+        };
+        assert_eq!(
+            series(IntervalUnit::from_month(1)).unwrap(),
+            vec![
+                timestamp("2022-01-01 00:00:00"),
+                timestamp("2022-02-01 00:00:00"),
+                timestamp("2022-03-01 00:00:00"),
+            ]
+        );
+        assert!(series(IntervalUnit::from_days(0)).is_err());
+    }
+}
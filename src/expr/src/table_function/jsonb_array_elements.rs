@@ -0,0 +1,70 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{
+    Array, ArrayBuilder, ArrayRef, DataChunk, JsonbArray, JsonbArrayBuilder,
+};
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Scalar};
+
+use super::{BoxedTableFunction, TableFunction};
+use crate::expr::BoxedExpression;
+use crate::vector_op::jsonb::jsonb_array_elements;
+
+/// `jsonb_array_elements(jsonb)` expands a JSON array into a set of rows, one for each element.
+#[derive(Debug)]
+pub struct JsonbArrayElements {
+    return_type: DataType,
+    jsonb: BoxedExpression,
+}
+
+impl TableFunction for JsonbArrayElements {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<Vec<ArrayRef>> {
+        let ret_jsonb = self.jsonb.eval(input)?;
+        let arr_jsonb: &JsonbArray = ret_jsonb.as_ref().into();
+
+        arr_jsonb
+            .iter()
+            .map(|jsonb| {
+                let elements = jsonb
+                    .map(jsonb_array_elements)
+                    .transpose()?
+                    .unwrap_or_default();
+                let mut builder = JsonbArrayBuilder::new(elements.len())?;
+                for element in &elements {
+                    builder.append(Some(element.as_scalar_ref()))?;
+                }
+                Ok(Arc::new(builder.finish()?.into()))
+            })
+            .try_collect()
+    }
+}
+
+pub fn new_jsonb_array_elements(
+    args: Vec<BoxedExpression>,
+    return_type: DataType,
+) -> Result<BoxedTableFunction> {
+    let Some((jsonb,)) = args.into_iter().collect_tuple() else {
+        return Err(InternalError("jsonb_array_elements expects 1 argument".to_string()).into());
+    };
+    Ok(JsonbArrayElements { return_type, jsonb }.boxed())
+}
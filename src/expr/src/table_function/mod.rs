@@ -0,0 +1,243 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Table functions, a.k.a. set-returning functions, which return a set of rows for each input row,
+//! e.g. `unnest` and `generate_series`.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{
+    ArrayBuilder, ArrayBuilderImpl, ArrayRef, DataChunk, I64ArrayBuilder,
+};
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_pb::expr::project_set_select_item::SelectItem;
+use risingwave_pb::expr::table_function::Type;
+use risingwave_pb::expr::{
+    ProjectSetSelectItem as SelectItemProst, TableFunction as TableFunctionProst,
+};
+
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression};
+
+mod generate_series;
+mod jsonb_array_elements;
+mod regexp_matches;
+mod unnest;
+
+pub use generate_series::*;
+pub use jsonb_array_elements::*;
+pub use regexp_matches::*;
+pub use unnest::*;
+
+/// Instance of a table function.
+pub trait TableFunction: std::fmt::Debug + Sync + Send {
+    fn return_type(&self) -> DataType;
+
+    /// Evaluates the table function on each visible row of `input`, and returns the set of rows
+    /// for each of them as an array.
+    fn eval(&self, input: &DataChunk) -> Result<Vec<ArrayRef>>;
+
+    fn boxed(self) -> BoxedTableFunction
+    where
+        Self: Sized + Send + 'static,
+    {
+        Box::new(self)
+    }
+}
+
+pub type BoxedTableFunction = Box<dyn TableFunction>;
+
+pub fn build_from_prost(prost: &TableFunctionProst) -> Result<BoxedTableFunction> {
+    let return_type = DataType::from(prost.get_return_type()?);
+    let build_args =
+        || -> Result<Vec<_>> { prost.args.iter().map(expr_build_from_prost).try_collect() };
+    match prost.get_function_type()? {
+        Type::GenerateSeries => new_generate_series(build_args()?, return_type),
+        Type::Unnest => new_unnest(build_args()?, return_type),
+        Type::RegexpMatches => new_regexp_matches(&prost.args, return_type),
+        Type::JsonbArrayElements => new_jsonb_array_elements(build_args()?, return_type),
+        Type::Invalid => Err(InternalError(format!(
+            "Unsupported table function type: {:?}",
+            prost.get_function_type()
+        ))
+        .into()),
+    }
+}
+
+/// An item of the select list of `ProjectSet`, which is either a table function or a scalar
+/// expression.
+#[derive(Debug)]
+pub enum ProjectSetSelectItem {
+    TableFunction(BoxedTableFunction),
+    Expr(BoxedExpression),
+}
+
+impl ProjectSetSelectItem {
+    pub fn from_prost(prost: &SelectItemProst) -> Result<Self> {
+        match prost.select_item.as_ref().ok_or_else(|| {
+            RwError::from(InternalError("empty select item of ProjectSet".to_string()))
+        })? {
+            SelectItem::Expr(expr) => expr_build_from_prost(expr).map(Self::Expr),
+            SelectItem::TableFunction(tf) => build_from_prost(tf).map(Self::TableFunction),
+        }
+    }
+
+    pub fn return_type(&self) -> DataType {
+        match self {
+            Self::TableFunction(tf) => tf.return_type(),
+            Self::Expr(expr) => expr.return_type(),
+        }
+    }
+}
+
+/// Evaluates the select list of `ProjectSet` on a compacted `input`.
+///
+/// Each input row produces as many rows as the longest set returned by the table functions, where
+/// shorter sets are padded with nulls and scalar expressions are repeated. The first column of the
+/// output is the `projected_row_id`, i.e. the index of the output row among those produced by the
+/// same input row. Returns the output columns with the index of the input row of each output row.
+pub fn eval_project_set(
+    select_list: &[ProjectSetSelectItem],
+    input: &DataChunk,
+) -> Result<(Vec<Column>, Vec<usize>)> {
+    enum Evaluated {
+        Set(Vec<ArrayRef>),
+        Scalar(ArrayRef),
+    }
+
+    let results: Vec<_> = select_list
+        .iter()
+        .map(|item| match item {
+            ProjectSetSelectItem::TableFunction(tf) => tf.eval(input).map(Evaluated::Set),
+            ProjectSetSelectItem::Expr(expr) => expr.eval(input).map(Evaluated::Scalar),
+        })
+        .try_collect()?;
+
+    let mut projected_row_ids = I64ArrayBuilder::new(input.cardinality())?;
+    let mut builders: Vec<ArrayBuilderImpl> = select_list
+        .iter()
+        .map(|item| item.return_type().create_array_builder(input.cardinality()))
+        .try_collect()?;
+    let mut input_row_indices = Vec::with_capacity(input.cardinality());
+
+    for row_idx in 0..input.cardinality() {
+        let len = results
+            .iter()
+            .filter_map(|result| match result {
+                Evaluated::Set(sets) => Some(sets[row_idx].len()),
+                Evaluated::Scalar(_) => None,
+            })
+            .max()
+            .unwrap_or(1);
+        for i in 0..len {
+            projected_row_ids.append(Some(i as i64))?;
+            input_row_indices.push(row_idx);
+            for (builder, result) in builders.iter_mut().zip_eq(results.iter()) {
+                match result {
+                    Evaluated::Set(sets) if i < sets[row_idx].len() => {
+                        builder.append_array_element(&sets[row_idx], i)?
+                    }
+                    Evaluated::Set(_) => builder.append_null()?,
+                    Evaluated::Scalar(array) => builder.append_array_element(array, row_idx)?,
+                }
+            }
+        }
+    }
+
+    let columns = std::iter::once(projected_row_ids.finish()?.into())
+        .chain(
+            builders
+                .into_iter()
+                .map(|b| b.finish())
+                .try_collect::<_, Vec<_>, _>()?,
+        )
+        .map(|array| Column::new(Arc::new(array)))
+        .collect();
+    Ok((columns, input_row_indices))
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::DataChunkTestExt;
+    use risingwave_common::types::ScalarImpl;
+
+    use super::*;
+    use crate::expr::{InputRefExpression, LiteralExpression};
+
+    #[test]
+    fn test_eval_project_set() {
+        let input = DataChunk::from_pretty(
+            "i i
+             1 2
+             3 1
+             2 0",
+        );
+        let series = |stop: usize| {
+            new_generate_series(
+                vec![
+                    LiteralExpression::new(DataType::Int32, Some(ScalarImpl::Int32(1))).boxed(),
+                    InputRefExpression::new(DataType::Int32, stop).boxed(),
+                    LiteralExpression::new(DataType::Int32, Some(ScalarImpl::Int32(1))).boxed(),
+                ],
+                DataType::Int32,
+            )
+            .unwrap()
+        };
+        let select_list = vec![
+            ProjectSetSelectItem::TableFunction(series(0)),
+            ProjectSetSelectItem::TableFunction(series(1)),
+            ProjectSetSelectItem::Expr(InputRefExpression::new(DataType::Int32, 0).boxed()),
+        ];
+        let (columns, input_row_indices) = eval_project_set(&select_list, &input).unwrap();
+        let column = |i: usize| -> Vec<_> {
+            let array = columns[i].array_ref();
+            (0..array.len()).map(|j| array.datum_at(j)).collect()
+        };
+        let ints = |values: &[Option<i32>]| -> Vec<_> {
+            values.iter().map(|v| v.map(ScalarImpl::Int32)).collect()
+        };
+
+        assert_eq!(input_row_indices, vec![0, 0, 1, 1, 1, 2, 2]);
+        assert_eq!(
+            column(0),
+            [0, 1, 0, 1, 2, 0, 1]
+                .into_iter()
+                .map(|i| Some(ScalarImpl::Int64(i)))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            column(1),
+            ints(&[Some(1), None, Some(1), Some(2), Some(3), Some(1), Some(2)])
+        );
+        assert_eq!(
+            column(2),
+            ints(&[Some(1), Some(2), Some(1), None, None, None, None])
+        );
+        assert_eq!(
+            column(3),
+            ints(&[
+                Some(1),
+                Some(1),
+                Some(3),
+                Some(3),
+                Some(3),
+                Some(2),
+                Some(2)
+            ])
+        );
+    }
+}
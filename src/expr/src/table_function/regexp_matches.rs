@@ -0,0 +1,180 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{Array, ArrayRef, DataChunk};
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_pb::expr::{expr_node, ExprNode};
+
+use super::{BoxedTableFunction, TableFunction};
+use crate::expr::build_expr_from_prost::eval_constant_string;
+use crate::expr::{build_from_prost as expr_build_from_prost, BoxedExpression, RegexpPattern};
+use crate::vector_op::regexp::{regexp_matches, RegexpContext};
+
+/// `regexp_matches(s, pattern [, flags])` returns the captured groups of the first match as a row,
+/// or of all the matches if the `g` flag is given.
+#[derive(Debug)]
+pub struct RegexpMatches {
+    return_type: DataType,
+    text: BoxedExpression,
+    pattern: RegexpPattern,
+    /// Flags, which must be constant. Only used by dynamic patterns.
+    flags: Option<String>,
+}
+
+impl TableFunction for RegexpMatches {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<Vec<ArrayRef>> {
+        let text_array = self.text.eval(input)?;
+        let pattern_array = match &self.pattern {
+            RegexpPattern::Constant(_) => None,
+            RegexpPattern::Dynamic(pattern) => Some(pattern.eval(input)?),
+        };
+
+        (0..input.capacity())
+            .map(|idx| {
+                let compiled;
+                let ctx = match &self.pattern {
+                    RegexpPattern::Constant(ctx) => ctx.as_ref(),
+                    RegexpPattern::Dynamic(_) => {
+                        match pattern_array.as_ref().unwrap().as_utf8().value_at(idx) {
+                            Some(pattern) => {
+                                compiled = RegexpContext::new(
+                                    pattern,
+                                    self.flags.as_deref().unwrap_or_default(),
+                                )?;
+                                Some(&compiled)
+                            }
+                            None => None,
+                        }
+                    }
+                };
+                let mut builder = self.return_type.create_array_builder(0)?;
+                // Any null argument results in an empty set.
+                if let (Some(ctx), Some(text)) = (ctx, text_array.as_utf8().value_at(idx)) {
+                    for groups in regexp_matches(ctx, text) {
+                        builder.append_datum(&Some(ScalarImpl::List(groups)))?;
+                    }
+                }
+                Ok(Arc::new(builder.finish()?))
+            })
+            .try_collect()
+    }
+}
+
+/// Builds `regexp_matches` from the arguments in protobuf, so that a constant pattern is compiled
+/// only once.
+pub fn new_regexp_matches(args: &[ExprNode], return_type: DataType) -> Result<BoxedTableFunction> {
+    if !(2..=3).contains(&args.len()) {
+        return Err(InternalError("regexp_matches expects 2 or 3 arguments".to_string()).into());
+    }
+    let text = expr_build_from_prost(&args[0])?;
+    let flags = args.get(2).map(eval_constant_string).transpose()?;
+    let pattern = match &flags {
+        // Null flags make all the sets empty.
+        Some(None) => RegexpPattern::Constant(None),
+        _ if args[1].get_expr_type()? == expr_node::Type::ConstantValue => {
+            let flags = flags.clone().flatten().unwrap_or_default();
+            let ctx = eval_constant_string(&args[1])?
+                .map(|pattern| RegexpContext::new(&pattern, &flags))
+                .transpose()?;
+            RegexpPattern::Constant(ctx)
+        }
+        _ => RegexpPattern::Dynamic(expr_build_from_prost(&args[1])?),
+    };
+    Ok(RegexpMatches {
+        return_type,
+        text,
+        pattern,
+        flags: flags.flatten(),
+    }
+    .boxed())
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{ListValue, Utf8Array};
+    use risingwave_common::column;
+    use risingwave_common::types::Datum;
+    use risingwave_pb::data::data_type::TypeName;
+    use risingwave_pb::data::DataType as ProstDataType;
+    use risingwave_pb::expr::expr_node::RexNode;
+    use risingwave_pb::expr::{ConstantValue, InputRefExpr};
+
+    use super::*;
+
+    fn varchar(value: &str) -> ExprNode {
+        ExprNode {
+            expr_type: expr_node::Type::ConstantValue as i32,
+            return_type: Some(ProstDataType {
+                type_name: TypeName::Varchar as i32,
+                ..Default::default()
+            }),
+            rex_node: Some(RexNode::Constant(ConstantValue {
+                body: value.as_bytes().to_vec(),
+            })),
+        }
+    }
+
+    fn eval_matches(pattern: &str, flags: &str) -> Vec<Datum> {
+        let text = ExprNode {
+            expr_type: expr_node::Type::InputRef as i32,
+            return_type: Some(ProstDataType {
+                type_name: TypeName::Varchar as i32,
+                ..Default::default()
+            }),
+            rex_node: Some(RexNode::InputRef(InputRefExpr { column_idx: 0 })),
+        };
+        let matches = new_regexp_matches(
+            &[text, varchar(pattern), varchar(flags)],
+            DataType::List {
+                datatype: Box::new(DataType::Varchar),
+            },
+        )
+        .unwrap();
+        let chunk = DataChunk::builder()
+            .columns(vec![column! { Utf8Array, [Some("foobarbequebaz")] }])
+            .build();
+        let sets = matches.eval(&chunk).unwrap();
+        (0..sets[0].len()).map(|i| sets[0].datum_at(i)).collect()
+    }
+
+    #[test]
+    fn test_regexp_matches() {
+        let groups = |values: &[&str]| {
+            Some(ScalarImpl::List(ListValue::new(
+                values
+                    .iter()
+                    .map(|v| Some(ScalarImpl::Utf8(v.to_string())))
+                    .collect(),
+            )))
+        };
+        assert_eq!(
+            eval_matches("(bar)(beque)", ""),
+            vec![groups(&["bar", "beque"])]
+        );
+        assert_eq!(
+            eval_matches("ba(.)", "g"),
+            vec![groups(&["r"]), groups(&["z"])]
+        );
+        assert!(eval_matches("x", "g").is_empty());
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::array::{Array, ArrayRef, DataChunk, ListArray};
+use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::Result;
+use risingwave_common::types::DataType;
+
+use super::{BoxedTableFunction, TableFunction};
+use crate::expr::BoxedExpression;
+
+/// `unnest(list)` expands a list into a set of rows, one for each element.
+#[derive(Debug)]
+pub struct Unnest {
+    return_type: DataType,
+    list: BoxedExpression,
+}
+
+impl TableFunction for Unnest {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn eval(&self, input: &DataChunk) -> Result<Vec<ArrayRef>> {
+        let ret_list = self.list.eval(input)?;
+        let arr_list: &ListArray = ret_list.as_ref().into();
+
+        arr_list
+            .iter()
+            .map(|list| {
+                let values = list.map(|list| list.values_ref()).unwrap_or_default();
+                let mut builder = self.return_type.create_array_builder(values.len())?;
+                for value in values {
+                    builder.append_datum_ref(value)?;
+                }
+                Ok(Arc::new(builder.finish()?))
+            })
+            .try_collect()
+    }
+}
+
+pub fn new_unnest(args: Vec<BoxedExpression>, return_type: DataType) -> Result<BoxedTableFunction> {
+    let Some((list,)) = args.into_iter().collect_tuple() else {
+        return Err(InternalError("unnest expects 1 argument".to_string()).into());
+    };
+    Ok(Unnest { return_type, list }.boxed())
+}
@@ -18,11 +18,14 @@ use risingwave_common::types::*;
 use risingwave_pb::expr::AggCall;
 
 use crate::expr::AggKind;
+use crate::vector_op::agg::approx_count_distinct::ApproxCountDistinct;
+use crate::vector_op::agg::array_agg::ArrayAgg;
 use crate::vector_op::agg::count_star::CountStar;
 use crate::vector_op::agg::functions::*;
 use crate::vector_op::agg::general_agg::*;
 use crate::vector_op::agg::general_distinct_agg::*;
 use crate::vector_op::agg::general_sorted_grouper::EqGroups;
+use crate::vector_op::agg::string_agg::StringAgg;
 
 /// An `Aggregator` supports `update` data and `output` result.
 pub trait Aggregator: Send + 'static {
@@ -60,6 +63,8 @@ pub struct AggStateFactory {
     // When agg func is count(*), the args is empty and input type is None.
     input_type: Option<DataType>,
     input_col_idx: usize,
+    /// The second argument, which is only taken by `string_agg` as the delimiter.
+    delimiter_col_idx: Option<usize>,
     agg_kind: AggKind,
     return_type: DataType,
     distinct: bool,
@@ -77,15 +82,25 @@ impl AggStateFactory {
                 Ok(Self {
                     input_type: Some(input_type),
                     input_col_idx,
+                    delimiter_col_idx: None,
                     agg_kind,
                     return_type,
                     distinct,
                 })
             }
+            [ref value, ref delimiter] if agg_kind == AggKind::StringAgg && !distinct => Ok(Self {
+                input_type: Some(DataType::from(value.get_type()?)),
+                input_col_idx: value.get_input()?.get_column_idx() as usize,
+                delimiter_col_idx: Some(delimiter.get_input()?.get_column_idx() as usize),
+                agg_kind,
+                return_type,
+                distinct,
+            }),
             [] => match (&agg_kind, return_type.clone()) {
                 (AggKind::Count, DataType::Int64) => Ok(Self {
                     input_type: None,
                     input_col_idx: 0,
+                    delimiter_col_idx: None,
                     agg_kind,
                     return_type,
                     distinct,
@@ -103,7 +118,12 @@ impl AggStateFactory {
     }
 
     pub fn create_agg_state(&self) -> Result<Box<dyn Aggregator>> {
-        if let Some(input_type) = self.input_type.clone() {
+        if let Some(delimiter_col_idx) = self.delimiter_col_idx {
+            Ok(Box::new(StringAgg::new(
+                self.input_col_idx,
+                delimiter_col_idx,
+            )))
+        } else if let Some(input_type) = self.input_type.clone() {
             create_agg_state_unary(
                 input_type,
                 self.input_col_idx,
@@ -130,6 +150,18 @@ pub fn create_agg_state_unary(
 ) -> Result<Box<dyn Aggregator>> {
     use crate::expr::data_types::*;
 
+    // The sketch works on the hashes of the values, so any input type is accepted.
+    if let (AggKind::ApproxCountDistinct, DataType::Int64) = (agg_type, &return_type) {
+        return Ok(Box::new(ApproxCountDistinct::new(
+            return_type,
+            input_col_idx,
+        )));
+    }
+
+    if let (AggKind::ArrayAgg, DataType::List { .. }, false) = (agg_type, &return_type, distinct) {
+        return Ok(Box::new(ArrayAgg::new(return_type, input_col_idx)));
+    }
+
     macro_rules! gen_arms {
         [$(($agg:ident, $fn:expr, $in:tt, $ret:tt)),* $(,)?] => {
             match (
@@ -198,6 +230,8 @@ pub fn create_agg_state_unary(
         (Max, max, decimal, decimal),
         (Max, max, boolean, boolean), // TODO(#359): remove once unnecessary
         (Max, max_str, varchar, varchar),
+        (BoolAnd, bool_and, boolean, boolean),
+        (BoolOr, bool_or, boolean, boolean),
         // Global Agg
         (Sum, sum, int64, int64),
         // We remark that SingleValue does not produce a runtime error when it receives zero row.
@@ -263,6 +297,10 @@ mod tests {
         test_create! { bool_type, Min, bool_type, is_ok } // TODO(#359): revert to is_err
         test_create! { char_type, Min, char_type, is_ok }
 
+        test_create! { bool_type, BoolAnd, bool_type, is_ok }
+        test_create! { bool_type, BoolOr, bool_type, is_ok }
+        test_create! { int64_type, BoolAnd, bool_type, is_err }
+
         test_create! { int64_type, SingleValue, int64_type, is_ok }
         test_create! { decimal_type, SingleValue, decimal_type, is_ok }
         test_create! { bool_type, SingleValue, bool_type, is_ok }
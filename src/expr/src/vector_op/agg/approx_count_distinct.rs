@@ -0,0 +1,248 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::hash::Hasher;
+
+use risingwave_common::array::*;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::*;
+use risingwave_common::util::value_encoding::serialize_cell_not_null;
+use twox_hash::XxHash64;
+
+use crate::vector_op::agg::aggregator::Aggregator;
+use crate::vector_op::agg::general_sorted_grouper::EqGroups;
+
+/// The number of bits of the hash used to choose a register.
+const INDEX_BITS: u32 = 12;
+/// The number of registers. The standard error is about `1.04 / sqrt(NUM_REGISTERS)`, i.e. 1.6%.
+const NUM_REGISTERS: usize = 1 << INDEX_BITS;
+/// The seed of the hash function. Sketches are persisted and merged across nodes, so the hash of a
+/// value must never change.
+const HASH_SEED: u64 = 0;
+
+/// A `HyperLogLog` sketch estimating the number of distinct values.
+///
+/// Each register keeps the maximum rank, i.e. the position of the first set bit, of the hashes
+/// falling into it. The ranks are less than 128, so the registers can be encoded as an ASCII
+/// string, which is how the sketch is stored and exchanged between aggregation phases.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HyperLogLog {
+    registers: Vec<u8>,
+}
+
+impl Default for HyperLogLog {
+    fn default() -> Self {
+        Self {
+            registers: vec![0; NUM_REGISTERS],
+        }
+    }
+}
+
+impl HyperLogLog {
+    /// Adds a value to the sketch. Nulls are ignored.
+    ///
+    /// The value is hashed by its value encoding with `XxHash64`, both of which are stable.
+    pub fn add(&mut self, datum: &Datum) -> Result<()> {
+        if datum.is_some() {
+            let mut hasher = XxHash64::with_seed(HASH_SEED);
+            hasher.write(&serialize_cell_not_null(datum)?);
+            self.add_hash(hasher.finish());
+        }
+        Ok(())
+    }
+
+    fn add_hash(&mut self, hash: u64) {
+        let index = (hash >> (64 - INDEX_BITS)) as usize;
+        // Set a sentinel bit so that the rank never exceeds `64 - INDEX_BITS + 1`.
+        let rest = (hash << INDEX_BITS) | (1 << (INDEX_BITS - 1));
+        let rank = rest.leading_zeros() as u8 + 1;
+        self.registers[index] = self.registers[index].max(rank);
+    }
+
+    /// Merges another sketch into this one.
+    pub fn merge(&mut self, other: &HyperLogLog) {
+        for (register, other) in self.registers.iter_mut().zip(other.registers.iter()) {
+            *register = (*register).max(*other);
+        }
+    }
+
+    /// Estimates the number of distinct values added to the sketch.
+    pub fn estimate(&self) -> i64 {
+        let m = NUM_REGISTERS as f64;
+        let alpha = 0.7213 / (1.0 + 1.079 / m);
+        let sum: f64 = self
+            .registers
+            .iter()
+            .map(|rank| 2f64.powi(-(*rank as i32)))
+            .sum();
+        let raw = alpha * m * m / sum;
+        let zeros = self.registers.iter().filter(|rank| **rank == 0).count();
+        if raw <= 2.5 * m && zeros > 0 {
+            // Use linear counting for small cardinalities.
+            (m * (m / zeros as f64).ln()).round() as i64
+        } else {
+            raw.round() as i64
+        }
+    }
+
+    /// Encodes the registers as a string.
+    pub fn encode(&self) -> String {
+        // The ranks are all ASCII characters.
+        String::from_utf8(self.registers.clone()).unwrap()
+    }
+
+    /// Decodes the registers from a string produced by [`HyperLogLog::encode`].
+    pub fn decode(encoded: &str) -> Result<Self> {
+        if encoded.len() != NUM_REGISTERS {
+            return Err(ErrorCode::InternalError(format!(
+                "invalid HyperLogLog sketch of length {}",
+                encoded.len()
+            ))
+            .into());
+        }
+        Ok(Self {
+            registers: encoded.as_bytes().to_vec(),
+        })
+    }
+}
+
+/// Where the `approx_count_distinct` aggregation happens.
+pub struct ApproxCountDistinct {
+    return_type: DataType,
+    input_col_idx: usize,
+    sketch: HyperLogLog,
+}
+
+impl ApproxCountDistinct {
+    pub fn new(return_type: DataType, input_col_idx: usize) -> Self {
+        Self {
+            return_type,
+            input_col_idx,
+            sketch: HyperLogLog::default(),
+        }
+    }
+}
+
+impl Aggregator for ApproxCountDistinct {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn update_with_row(&mut self, input: &DataChunk, row_id: usize) -> Result<()> {
+        let array = input.column_at(self.input_col_idx).array_ref();
+        self.sketch.add(&array.datum_at(row_id))
+    }
+
+    fn update(&mut self, input: &DataChunk) -> Result<()> {
+        let array = input.column_at(self.input_col_idx).array_ref();
+        for row_id in 0..array.len() {
+            self.sketch.add(&array.datum_at(row_id))?;
+        }
+        Ok(())
+    }
+
+    fn output(&self, builder: &mut ArrayBuilderImpl) -> Result<()> {
+        match builder {
+            ArrayBuilderImpl::Int64(b) => b.append(Some(self.sketch.estimate())),
+            _ => Err(ErrorCode::InternalError(
+                "Unexpected builder for approx_count_distinct.".into(),
+            )
+            .into()),
+        }
+    }
+
+    fn update_and_output_with_sorted_groups(
+        &mut self,
+        input: &DataChunk,
+        builder: &mut ArrayBuilderImpl,
+        groups: &EqGroups,
+    ) -> Result<()> {
+        let builder = match builder {
+            ArrayBuilderImpl::Int64(b) => b,
+            _ => {
+                return Err(ErrorCode::InternalError(
+                    "Unexpected builder for approx_count_distinct.".into(),
+                )
+                .into())
+            }
+        };
+        let array = input.column_at(self.input_col_idx).array_ref();
+        let mut groups_iter = groups.get_starting_indices().iter().peekable();
+        for row_id in 0..array.len() {
+            if groups_iter.peek() == Some(&&row_id) {
+                groups_iter.next();
+                builder.append(Some(self.sketch.estimate()))?;
+                self.sketch = HyperLogLog::default();
+            }
+            self.sketch.add(&array.datum_at(row_id))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use risingwave_common::array::column::Column;
+
+    use super::*;
+
+    #[test]
+    fn test_hyper_log_log() {
+        let mut sketch = HyperLogLog::default();
+        assert_eq!(sketch.estimate(), 0);
+        for i in 0..10000i64 {
+            // Every value is added twice.
+            sketch.add(&Some(ScalarImpl::Int64(i % 5000))).unwrap();
+        }
+        sketch.add(&None).unwrap();
+        let estimate = sketch.estimate();
+        assert!((4700..=5300).contains(&estimate), "estimate: {}", estimate);
+
+        let mut other = HyperLogLog::default();
+        for i in 2500..7500i64 {
+            other.add(&Some(ScalarImpl::Int64(i))).unwrap();
+        }
+        let other = HyperLogLog::decode(&other.encode()).unwrap();
+        sketch.merge(&other);
+        let estimate = sketch.estimate();
+        assert!((7050..=7950).contains(&estimate), "estimate: {}", estimate);
+    }
+
+    #[test]
+    fn test_hyper_log_log_stable_hash() {
+        // The sketch of a value must not change across releases, as sketches are persisted.
+        let mut sketch = HyperLogLog::default();
+        sketch.add(&Some(ScalarImpl::Int64(5))).unwrap();
+        let registers = sketch.encode().into_bytes();
+        assert_eq!(registers[3076], 8);
+        assert_eq!(registers.iter().filter(|rank| **rank != 0).count(), 1);
+    }
+
+    #[test]
+    fn test_approx_count_distinct() -> Result<()> {
+        let input = I32Array::from_slice(&[Some(1), Some(2), Some(2), None, Some(3)]).unwrap();
+        let data_chunk = DataChunk::builder()
+            .columns(vec![Column::new(Arc::new(input.into()))])
+            .build();
+        let mut agg = ApproxCountDistinct::new(DataType::Int64, 0);
+        agg.update(&data_chunk)?;
+        let mut builder = ArrayBuilderImpl::Int64(I64ArrayBuilder::new(0)?);
+        agg.output(&mut builder)?;
+        let output = builder.finish()?;
+        assert_eq!(output.datum_at(0), Some(ScalarImpl::Int64(3)));
+        Ok(())
+    }
+}
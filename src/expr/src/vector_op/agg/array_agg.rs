@@ -0,0 +1,121 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::*;
+use risingwave_common::error::Result;
+use risingwave_common::types::*;
+
+use crate::vector_op::agg::aggregator::Aggregator;
+use crate::vector_op::agg::general_sorted_grouper::EqGroups;
+
+/// Where the `array_agg` aggregation happens. Nulls are collected as well, and the result is null
+/// instead of an empty list when there are no rows.
+pub struct ArrayAgg {
+    return_type: DataType,
+    input_col_idx: usize,
+    result: Vec<Datum>,
+}
+
+impl ArrayAgg {
+    pub fn new(return_type: DataType, input_col_idx: usize) -> Self {
+        Self {
+            return_type,
+            input_col_idx,
+            result: vec![],
+        }
+    }
+
+    fn get_result(&self) -> Datum {
+        if self.result.is_empty() {
+            None
+        } else {
+            Some(ListValue::new(self.result.clone()).into())
+        }
+    }
+}
+
+impl Aggregator for ArrayAgg {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn update_with_row(&mut self, input: &DataChunk, row_id: usize) -> Result<()> {
+        let array = input.column_at(self.input_col_idx).array_ref();
+        self.result.push(array.datum_at(row_id));
+        Ok(())
+    }
+
+    fn update(&mut self, input: &DataChunk) -> Result<()> {
+        let array = input.column_at(self.input_col_idx).array_ref();
+        for row_id in 0..array.len() {
+            self.result.push(array.datum_at(row_id));
+        }
+        Ok(())
+    }
+
+    fn output(&self, builder: &mut ArrayBuilderImpl) -> Result<()> {
+        builder.append_datum(&self.get_result())
+    }
+
+    fn update_and_output_with_sorted_groups(
+        &mut self,
+        input: &DataChunk,
+        builder: &mut ArrayBuilderImpl,
+        groups: &EqGroups,
+    ) -> Result<()> {
+        let array = input.column_at(self.input_col_idx).array_ref();
+        let mut groups_iter = groups.get_starting_indices().iter().peekable();
+        for row_id in 0..array.len() {
+            if groups_iter.peek() == Some(&&row_id) {
+                groups_iter.next();
+                builder.append_datum(&self.get_result())?;
+                self.result.clear();
+            }
+            self.result.push(array.datum_at(row_id));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use risingwave_common::array::column::Column;
+
+    use super::*;
+
+    #[test]
+    fn test_array_agg() -> Result<()> {
+        let input = I32Array::from_slice(&[Some(1), None, Some(3)]).unwrap();
+        let data_chunk = DataChunk::builder()
+            .columns(vec![Column::new(Arc::new(input.into()))])
+            .build();
+        let return_type = DataType::List {
+            datatype: Box::new(DataType::Int32),
+        };
+        let mut agg = ArrayAgg::new(return_type.clone(), 0);
+        let mut builder = return_type.create_array_builder(0)?;
+        agg.output(&mut builder)?;
+        agg.update(&data_chunk)?;
+        agg.output(&mut builder)?;
+        let output = builder.finish()?;
+        assert_eq!(output.datum_at(0), None);
+        assert_eq!(
+            output.datum_at(1),
+            Some(ListValue::new(vec![Some(1.into()), None, Some(3.into())]).into())
+        );
+        Ok(())
+    }
+}
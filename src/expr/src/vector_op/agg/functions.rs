@@ -100,6 +100,24 @@ pub fn max_str<'a>(r: Option<&'a str>, i: Option<&'a str>) -> Result<Option<&'a
     max(r, i)
 }
 
+pub fn bool_and(result: Option<bool>, input: Option<bool>) -> Result<Option<bool>> {
+    let res = match (result, input) {
+        (None, _) => input,
+        (_, None) => result,
+        (Some(r), Some(i)) => Some(r && i),
+    };
+    Ok(res)
+}
+
+pub fn bool_or(result: Option<bool>, input: Option<bool>) -> Result<Option<bool>> {
+    let res = match (result, input) {
+        (None, _) => input,
+        (_, None) => result,
+        (Some(r), Some(i)) => Some(r || i),
+    };
+    Ok(res)
+}
+
 /// create table t(v1 int);
 /// insert into t values (null);
 /// select count(*) from t; gives 1.
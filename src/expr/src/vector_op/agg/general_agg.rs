@@ -349,6 +349,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn vec_bool_and_or() -> Result<()> {
+        let test_case =
+            |agg_type: AggKind, input: &[Option<bool>], expected: &[Option<bool>]| -> Result<()> {
+                let input = BoolArray::from_slice(input).unwrap();
+                let actual = eval_agg(
+                    DataType::Boolean,
+                    Arc::new(input.into()),
+                    &agg_type,
+                    DataType::Boolean,
+                    ArrayBuilderImpl::Bool(BoolArrayBuilder::new(0)?),
+                )?;
+                let actual = actual.as_bool().iter().collect::<Vec<_>>();
+                assert_eq!(actual, expected);
+                Ok(())
+            };
+        test_case(
+            AggKind::BoolAnd,
+            &[Some(true), None, Some(true)],
+            &[Some(true)],
+        )?;
+        test_case(AggKind::BoolAnd, &[Some(true), Some(false)], &[Some(false)])?;
+        test_case(
+            AggKind::BoolOr,
+            &[Some(false), None, Some(true)],
+            &[Some(true)],
+        )?;
+        test_case(AggKind::BoolOr, &[None], &[None])
+    }
+
     #[test]
     fn vec_count_int32() -> Result<()> {
         let test_case = |input: ArrayImpl, expected: &[Option<i64>]| -> Result<()> {
@@ -13,11 +13,15 @@
 // limitations under the License.
 
 mod aggregator;
+mod approx_count_distinct;
+mod array_agg;
 mod count_star;
 mod functions;
 mod general_agg;
 mod general_distinct_agg;
 mod general_sorted_grouper;
+mod string_agg;
 
 pub use aggregator::{AggStateFactory, BoxedAggState};
+pub use approx_count_distinct::HyperLogLog;
 pub use general_sorted_grouper::{create_sorted_grouper, BoxedSortedGrouper, EqGroups};
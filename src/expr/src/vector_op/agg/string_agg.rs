@@ -0,0 +1,146 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::*;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::*;
+
+use crate::vector_op::agg::aggregator::Aggregator;
+use crate::vector_op::agg::general_sorted_grouper::EqGroups;
+
+/// Where the `string_agg(value, delimiter)` aggregation happens. Null values are skipped, and each
+/// value except the first one is preceded by the delimiter of its own row.
+pub struct StringAgg {
+    value_col_idx: usize,
+    delimiter_col_idx: usize,
+    result: Option<String>,
+}
+
+impl StringAgg {
+    pub fn new(value_col_idx: usize, delimiter_col_idx: usize) -> Self {
+        Self {
+            value_col_idx,
+            delimiter_col_idx,
+            result: None,
+        }
+    }
+
+    fn get_arrays<'a>(&self, input: &'a DataChunk) -> Result<(&'a Utf8Array, &'a Utf8Array)> {
+        match (
+            input.column_at(self.value_col_idx).array_ref(),
+            input.column_at(self.delimiter_col_idx).array_ref(),
+        ) {
+            (ArrayImpl::Utf8(value), ArrayImpl::Utf8(delimiter)) => Ok((value, delimiter)),
+            _ => Err(ErrorCode::InternalError("Input fail to match Utf8.".into()).into()),
+        }
+    }
+
+    fn append(&mut self, value: Option<&str>, delimiter: Option<&str>) {
+        if let Some(value) = value {
+            match &mut self.result {
+                Some(result) => {
+                    if let Some(delimiter) = delimiter {
+                        result.push_str(delimiter);
+                    }
+                    result.push_str(value);
+                }
+                None => self.result = Some(value.to_string()),
+            }
+        }
+    }
+}
+
+impl Aggregator for StringAgg {
+    fn return_type(&self) -> DataType {
+        DataType::Varchar
+    }
+
+    fn update_with_row(&mut self, input: &DataChunk, row_id: usize) -> Result<()> {
+        let (value, delimiter) = self.get_arrays(input)?;
+        self.append(value.value_at(row_id), delimiter.value_at(row_id));
+        Ok(())
+    }
+
+    fn update(&mut self, input: &DataChunk) -> Result<()> {
+        let (value, delimiter) = self.get_arrays(input)?;
+        for (value, delimiter) in value.iter().zip(delimiter.iter()) {
+            self.append(value, delimiter);
+        }
+        Ok(())
+    }
+
+    fn output(&self, builder: &mut ArrayBuilderImpl) -> Result<()> {
+        match builder {
+            ArrayBuilderImpl::Utf8(b) => b.append(self.result.as_deref()),
+            _ => Err(ErrorCode::InternalError("Unexpected builder for string_agg.".into()).into()),
+        }
+    }
+
+    fn update_and_output_with_sorted_groups(
+        &mut self,
+        input: &DataChunk,
+        builder: &mut ArrayBuilderImpl,
+        groups: &EqGroups,
+    ) -> Result<()> {
+        let builder = match builder {
+            ArrayBuilderImpl::Utf8(b) => b,
+            _ => {
+                return Err(
+                    ErrorCode::InternalError("Unexpected builder for string_agg.".into()).into(),
+                )
+            }
+        };
+        let (value, delimiter) = self.get_arrays(input)?;
+        let mut groups_iter = groups.get_starting_indices().iter().peekable();
+        for (row_id, (value, delimiter)) in value.iter().zip(delimiter.iter()).enumerate() {
+            if groups_iter.peek() == Some(&&row_id) {
+                groups_iter.next();
+                builder.append(self.result.take().as_deref())?;
+            }
+            self.append(value, delimiter);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use risingwave_common::array::column::Column;
+
+    use super::*;
+
+    #[test]
+    fn test_string_agg() -> Result<()> {
+        let value = Utf8Array::from_slice(&[Some("a"), None, Some("b"), Some("c")]).unwrap();
+        let delimiter = Utf8Array::from_slice(&[Some(","), Some(","), Some(","), None]).unwrap();
+        let data_chunk = DataChunk::builder()
+            .columns(vec![
+                Column::new(Arc::new(value.into())),
+                Column::new(Arc::new(delimiter.into())),
+            ])
+            .build();
+        let mut agg = StringAgg::new(0, 1);
+        agg.update(&data_chunk)?;
+        let mut builder = ArrayBuilderImpl::Utf8(Utf8ArrayBuilder::new(0)?);
+        agg.output(&mut builder)?;
+        let output = builder.finish()?;
+        assert_eq!(
+            output.datum_at(0),
+            Some(ScalarImpl::Utf8("a,bc".to_string()))
+        );
+        Ok(())
+    }
+}
@@ -98,6 +98,15 @@ pub fn general_neg<T1: CheckedNeg>(expr: T1) -> Result<T1> {
     }
 }
 
+#[inline(always)]
+pub fn general_abs<T1: CheckedNeg + PartialOrd + Default>(expr: T1) -> Result<T1> {
+    if expr < T1::default() {
+        general_neg(expr)
+    } else {
+        Ok(expr)
+    }
+}
+
 #[inline(always)]
 pub fn general_atm<T1, T2, T3, F>(l: T1, r: T2, atm: F) -> Result<T3>
 where
@@ -262,7 +271,7 @@ mod tests {
 
     use risingwave_common::types::Decimal;
 
-    use crate::vector_op::arithmetic_op::general_add;
+    use crate::vector_op::arithmetic_op::{general_abs, general_add};
 
     #[test]
     fn test() {
@@ -271,4 +280,15 @@ mod tests {
             Decimal::from_str("2").unwrap()
         );
     }
+
+    #[test]
+    fn test_abs() {
+        assert_eq!(general_abs(-1i32).unwrap(), 1);
+        assert_eq!(general_abs(1i64).unwrap(), 1);
+        assert!(general_abs(i16::MIN).is_err());
+        assert_eq!(
+            general_abs(Decimal::from_str("-1.5").unwrap()).unwrap(),
+            Decimal::from_str("1.5").unwrap()
+        );
+    }
 }
@@ -0,0 +1,366 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Functions and operators over lists, which are arrays in PostgreSQL. Indexes are 1-based.
+
+use risingwave_common::array::{ListRef, ListValue};
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{Datum, DatumRef, ScalarImpl, ScalarRefImpl, ToOwnedDatum};
+
+/// `list[index]`. Returns `NULL` if the index is out of range.
+pub fn array_access(list: ListRef<'_>, index: i32) -> Datum {
+    if index < 1 {
+        return None;
+    }
+    list.values_ref()
+        .get(index as usize - 1)
+        .and_then(|v| v.to_owned_datum())
+}
+
+/// `list[start:end]`. The bounds are clamped to the list, so an empty list is returned if they're
+/// out of range.
+pub fn array_range_access(list: ListRef<'_>, start: i32, end: i32) -> ListValue {
+    let values = list.values_ref();
+    let start = start.max(1) as usize;
+    let end = (end.max(0) as usize).min(values.len());
+    if start > end {
+        return ListValue::new(vec![]);
+    }
+    ListValue::new(
+        values[start - 1..end]
+            .iter()
+            .map(|v| v.to_owned_datum())
+            .collect(),
+    )
+}
+
+/// `array_length(list, dimension)`. The lengths of inner dimensions are taken from the first
+/// element. Returns `NULL` if the list is empty or the dimension doesn't exist.
+pub fn array_length(list: ListRef<'_>, dimension: i32) -> Option<i32> {
+    if dimension < 1 {
+        return None;
+    }
+    let mut list = list;
+    for _ in 1..dimension {
+        list = match list.values_ref().first() {
+            Some(Some(ScalarRefImpl::List(inner))) => *inner,
+            _ => return None,
+        };
+    }
+    match list.values_ref().len() {
+        0 => None,
+        len => Some(len as i32),
+    }
+}
+
+/// `array_append(list, element)`. A `NULL` list is treated as empty.
+pub fn array_append(list: Option<ListRef<'_>>, element: DatumRef<'_>) -> ListValue {
+    let mut values = list
+        .map(|list| list.values_ref())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|v| v.to_owned_datum())
+        .collect::<Vec<_>>();
+    values.push(element.to_owned_datum());
+    ListValue::new(values)
+}
+
+/// `array_cat(left, right)`. A `NULL` list is treated as empty, unless both are `NULL`.
+pub fn array_cat(left: Option<ListRef<'_>>, right: Option<ListRef<'_>>) -> Option<ListValue> {
+    if left.is_none() && right.is_none() {
+        return None;
+    }
+    Some(ListValue::new(
+        left.into_iter()
+            .chain(right)
+            .flat_map(|list| list.values_ref())
+            .map(|v| v.to_owned_datum())
+            .collect(),
+    ))
+}
+
+/// `left @> right`, i.e. every element of `right` appears in `left`. `NULL` elements never match.
+pub fn array_contains(left: ListRef<'_>, right: ListRef<'_>) -> bool {
+    let left = left.values_ref();
+    right
+        .values_ref()
+        .iter()
+        .all(|r| r.is_some() && left.contains(r))
+}
+
+/// `element = ANY(list)`. Following the three-valued logic, the result is `NULL` rather than
+/// `false` if there's no match but `NULL` is involved.
+pub fn array_any_equal(element: DatumRef<'_>, list: ListRef<'_>) -> Option<bool> {
+    let values = list.values_ref();
+    if values.is_empty() {
+        return Some(false);
+    }
+    element?;
+    if values.contains(&element) {
+        Some(true)
+    } else if values.iter().any(Option::is_none) {
+        None
+    } else {
+        Some(false)
+    }
+}
+
+/// `string_to_array(string, delimiter, null_string)`. A `NULL` delimiter splits the string into
+/// characters, and an empty one makes the whole string the only element. Elements equal to
+/// `null_string` become `NULL`.
+pub fn string_to_array(s: &str, delimiter: Option<&str>, null_string: Option<&str>) -> ListValue {
+    let to_datum = |element: &str| match null_string {
+        Some(null_string) if element == null_string => None,
+        _ => Some(ScalarImpl::Utf8(element.to_string())),
+    };
+    if s.is_empty() {
+        return ListValue::new(vec![]);
+    }
+    let values = match delimiter {
+        None => s
+            .char_indices()
+            .map(|(i, c)| to_datum(&s[i..i + c.len_utf8()]))
+            .collect(),
+        Some("") => vec![to_datum(s)],
+        Some(delimiter) => s.split(delimiter).map(to_datum).collect(),
+    };
+    ListValue::new(values)
+}
+
+/// Parses a list written in PostgreSQL array syntax, e.g. `{1,NULL,"a b",{2,3}}`, into the texts
+/// of its elements, which are then cast to the element type. A nested list is kept as a whole,
+/// e.g. `{2,3}`, to be parsed again when cast to the inner list type.
+pub fn parse_array_elements(s: &str) -> Result<Vec<Option<String>>> {
+    let err =
+        || -> RwError { InvalidInputSyntax(format!("malformed array literal: \"{}\"", s)).into() };
+    let body = s
+        .trim()
+        .strip_prefix('{')
+        .and_then(|s| s.strip_suffix('}'))
+        .ok_or_else(err)?;
+    if body.trim().is_empty() {
+        return Ok(vec![]);
+    }
+
+    let mut elements = vec![];
+    let mut chars = body.chars().peekable();
+    loop {
+        while chars.next_if(|c| c.is_ascii_whitespace()).is_some() {}
+        let element = match chars.peek() {
+            Some('"') => {
+                chars.next();
+                let mut element = String::new();
+                loop {
+                    match chars.next().ok_or_else(err)? {
+                        '"' => break,
+                        '\\' => element.push(chars.next().ok_or_else(err)?),
+                        c => element.push(c),
+                    }
+                }
+                Some(element)
+            }
+            Some('{') => {
+                let mut element = String::new();
+                let mut depth = 0;
+                let mut quoted = false;
+                loop {
+                    let c = chars.next().ok_or_else(err)?;
+                    element.push(c);
+                    match c {
+                        '\\' => element.push(chars.next().ok_or_else(err)?),
+                        '"' => quoted = !quoted,
+                        '{' if !quoted => depth += 1,
+                        '}' if !quoted => {
+                            depth -= 1;
+                            if depth == 0 {
+                                break;
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Some(element)
+            }
+            _ => {
+                let mut element = String::new();
+                while let Some(c) = chars.next_if(|c| *c != ',') {
+                    match c {
+                        '\\' => element.push(chars.next().ok_or_else(err)?),
+                        '"' | '{' | '}' => return Err(err()),
+                        c => element.push(c),
+                    }
+                }
+                let element = element.trim_end();
+                if element.is_empty() {
+                    return Err(err());
+                }
+                if element.eq_ignore_ascii_case("null") {
+                    None
+                } else {
+                    Some(element.to_string())
+                }
+            }
+        };
+        elements.push(element);
+
+        while chars.next_if(|c| c.is_ascii_whitespace()).is_some() {}
+        match chars.next() {
+            None => break,
+            Some(',') => {}
+            Some(_) => return Err(err()),
+        }
+    }
+    Ok(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::Scalar;
+
+    use super::*;
+
+    fn int_list(values: &[Option<i32>]) -> ListValue {
+        ListValue::new(values.iter().map(|v| v.map(ScalarImpl::Int32)).collect())
+    }
+
+    #[test]
+    fn test_array_access() {
+        let list = int_list(&[Some(1), None, Some(3)]);
+        let list = list.as_scalar_ref();
+        assert_eq!(array_access(list, 1), Some(ScalarImpl::Int32(1)));
+        assert_eq!(array_access(list, 2), None);
+        assert_eq!(array_access(list, 0), None);
+        assert_eq!(array_access(list, 4), None);
+
+        assert_eq!(array_range_access(list, 2, 3), int_list(&[None, Some(3)]));
+        assert_eq!(
+            array_range_access(list, -1, i32::MAX),
+            int_list(&[Some(1), None, Some(3)])
+        );
+        assert_eq!(array_range_access(list, 3, 2), int_list(&[]));
+        assert_eq!(array_range_access(list, 4, 5), int_list(&[]));
+    }
+
+    #[test]
+    fn test_array_length() {
+        let list = int_list(&[Some(1), Some(2)]);
+        assert_eq!(array_length(list.as_scalar_ref(), 1), Some(2));
+        assert_eq!(array_length(list.as_scalar_ref(), 2), None);
+        assert_eq!(array_length(int_list(&[]).as_scalar_ref(), 1), None);
+
+        let nested = ListValue::new(vec![
+            Some(ScalarImpl::List(int_list(&[Some(1), Some(2), Some(3)]))),
+            Some(ScalarImpl::List(int_list(&[Some(4), Some(5), Some(6)]))),
+        ]);
+        assert_eq!(array_length(nested.as_scalar_ref(), 1), Some(2));
+        assert_eq!(array_length(nested.as_scalar_ref(), 2), Some(3));
+    }
+
+    #[test]
+    fn test_array_append_cat() {
+        let list = int_list(&[Some(1)]);
+        assert_eq!(
+            array_append(Some(list.as_scalar_ref()), Some(ScalarRefImpl::Int32(2))),
+            int_list(&[Some(1), Some(2)])
+        );
+        assert_eq!(array_append(None, None), int_list(&[None]));
+        assert_eq!(
+            array_cat(Some(list.as_scalar_ref()), Some(list.as_scalar_ref())),
+            Some(int_list(&[Some(1), Some(1)]))
+        );
+        assert_eq!(
+            array_cat(None, Some(list.as_scalar_ref())),
+            Some(int_list(&[Some(1)]))
+        );
+        assert_eq!(array_cat(None, None), None);
+    }
+
+    #[test]
+    fn test_array_contains_any() {
+        let list = int_list(&[Some(1), Some(2), None]);
+        let list = list.as_scalar_ref();
+        let contains =
+            |values: &[Option<i32>]| array_contains(list, int_list(values).as_scalar_ref());
+        assert!(contains(&[Some(2), Some(1), Some(2)]));
+        assert!(contains(&[]));
+        assert!(!contains(&[Some(3)]));
+        assert!(!contains(&[None]));
+
+        let one = Some(ScalarRefImpl::Int32(1));
+        let three = Some(ScalarRefImpl::Int32(3));
+        assert_eq!(array_any_equal(one, list), Some(true));
+        assert_eq!(array_any_equal(three, list), None);
+        assert_eq!(array_any_equal(None, list), None);
+        let list = int_list(&[Some(1)]);
+        assert_eq!(array_any_equal(three, list.as_scalar_ref()), Some(false));
+        let empty = int_list(&[]);
+        assert_eq!(array_any_equal(None, empty.as_scalar_ref()), Some(false));
+    }
+
+    #[test]
+    fn test_string_to_array() {
+        let str_list = |values: &[Option<&str>]| {
+            ListValue::new(
+                values
+                    .iter()
+                    .map(|v| v.map(|v| ScalarImpl::Utf8(v.to_string())))
+                    .collect(),
+            )
+        };
+        assert_eq!(
+            string_to_array("a,b,,c", Some(","), Some("")),
+            str_list(&[Some("a"), Some("b"), None, Some("c")])
+        );
+        assert_eq!(
+            string_to_array("a,b", Some(""), None),
+            str_list(&[Some("a,b")])
+        );
+        assert_eq!(
+            string_to_array("你好", None, Some("好")),
+            str_list(&[Some("你"), None])
+        );
+        assert_eq!(string_to_array("", Some(","), None), str_list(&[]));
+    }
+
+    #[test]
+    fn test_parse_array_elements() {
+        let parse = |s: &str| parse_array_elements(s).unwrap();
+        let some = |s: &str| Some(s.to_string());
+        assert_eq!(parse("{}"), Vec::<Option<String>>::new());
+        assert_eq!(
+            parse(" { 1 , 2,3 } "),
+            vec![some("1"), some("2"), some("3")]
+        );
+        assert_eq!(
+            parse(r#"{a b,NULL,"NULL","",  "x,\"y\"", c\,d}"#),
+            vec![
+                some("a b"),
+                None,
+                some("NULL"),
+                some(""),
+                some(r#"x,"y""#),
+                some("c,d")
+            ]
+        );
+        assert_eq!(
+            parse(r#"{{1,2},{"}",NULL}}"#),
+            vec![some("{1,2}"), some(r#"{"}",NULL}"#)]
+        );
+
+        for s in ["", "1,2", "{1,2", "{1,,2}", "{1,2,}", "{\"a}", "{{1,2}"] {
+            assert!(parse_array_elements(s).is_err(), "{}", s);
+        }
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::cmp::{max, min};
+use std::fmt::Write;
+
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+
+/// Number of bytes, as `length(bytea)` and `octet_length(bytea)`.
+#[inline(always)]
+pub fn bytea_length(v: &[u8]) -> Result<i64> {
+    Ok(v.len() as i64)
+}
+
+/// `substr(bytea, start)`. `start` is 1-based, as the one for strings.
+#[inline(always)]
+pub fn bytea_substr_start(v: &[u8], start: i32) -> Result<Box<[u8]>> {
+    let start = min(max(start - 1, 0) as usize, v.len());
+    Ok(v[start..].into())
+}
+
+/// `substr(bytea, start, count)`.
+#[inline(always)]
+pub fn bytea_substr_start_for(v: &[u8], start: i32, count: i32) -> Result<Box<[u8]>> {
+    if count < 0 {
+        return Err(InvalidInputSyntax(format!(
+            "length in substr should be non-negative: {}",
+            count
+        ))
+        .into());
+    }
+    let begin = min(max(start - 1, 0) as usize, v.len());
+    let end = min(max(start - 1 + count, 0) as usize, v.len());
+    Ok(v[begin..max(begin, end)].into())
+}
+
+/// `encode(bytea, format)`, where `format` is one of `hex`, `base64` and `escape`.
+#[inline(always)]
+pub fn encode(v: &[u8], format: &str) -> Result<String> {
+    match format.to_ascii_lowercase().as_str() {
+        "hex" => Ok(hex::encode(v)),
+        "base64" => Ok(base64::encode(v)),
+        "escape" => Ok(encode_escape(v)),
+        _ => Err(unrecognized_encoding(format)),
+    }
+}
+
+/// `decode(varchar, format)`, the inverse of [`encode`].
+#[inline(always)]
+pub fn decode(s: &str, format: &str) -> Result<Box<[u8]>> {
+    let bytes = match format.to_ascii_lowercase().as_str() {
+        "hex" => hex::decode(s).map_err(|e| invalid_input("hex", e))?,
+        "base64" => base64::decode(s).map_err(|e| invalid_input("base64", e))?,
+        "escape" => decode_escape(s)?,
+        _ => return Err(unrecognized_encoding(format)),
+    };
+    Ok(bytes.into())
+}
+
+/// Parses the text representation of a bytea value, which is either in the hex format (`\x0a1b`)
+/// or in the escape format (`a\000b`).
+pub fn parse_bytea(s: &str) -> Result<Box<[u8]>> {
+    match s.strip_prefix("\\x") {
+        Some(hex) => Ok(hex::decode(hex)
+            .map_err(|e| invalid_input("hex", e))?
+            .into()),
+        None => Ok(decode_escape(s)?.into()),
+    }
+}
+
+/// In the escape format, printable ASCII characters are kept as is, backslashes are doubled and
+/// all other bytes are written as 3-digit octal numbers following a backslash.
+fn encode_escape(v: &[u8]) -> String {
+    let mut s = String::with_capacity(v.len());
+    for &b in v {
+        match b {
+            b'\\' => s.push_str("\\\\"),
+            0x20..=0x7e => s.push(b as char),
+            _ => write!(s, "\\{:03o}", b).unwrap(),
+        }
+    }
+    s
+}
+
+fn decode_escape(s: &str) -> Result<Vec<u8>> {
+    let bytes = s.as_bytes();
+    let mut result = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'\\' {
+            result.push(bytes[i]);
+            i += 1;
+        } else if bytes.get(i + 1) == Some(&b'\\') {
+            result.push(b'\\');
+            i += 2;
+        } else {
+            let octal = bytes
+                .get(i + 1..i + 4)
+                .filter(|d| d[0] <= b'3' && d.iter().all(|c| (b'0'..=b'7').contains(c)))
+                .ok_or_else(|| RwError::from(InvalidInputSyntax("type bytea".to_string())))?;
+            result.push(octal.iter().fold(0, |acc, d| acc * 8 + (d - b'0')));
+            i += 4;
+        }
+    }
+    Ok(result)
+}
+
+fn unrecognized_encoding(format: &str) -> RwError {
+    InvalidInputSyntax(format!("unrecognized encoding: \"{}\"", format)).into()
+}
+
+fn invalid_input(format: &str, e: impl std::fmt::Display) -> RwError {
+    InvalidInputSyntax(format!("invalid {} data: {}", format, e)).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bytea_substr() {
+        let v = b"\x00\x01\x02\x03\x04";
+        assert_eq!(bytea_length(v).unwrap(), 5);
+        assert_eq!(&*bytea_substr_start(v, 4).unwrap(), b"\x03\x04");
+        assert_eq!(&*bytea_substr_start(v, 10).unwrap(), b"");
+        assert_eq!(&*bytea_substr_start_for(v, 2, 2).unwrap(), b"\x01\x02");
+        assert_eq!(&*bytea_substr_start_for(v, -1, 3).unwrap(), b"\x00");
+        assert!(bytea_substr_start_for(v, 1, -1).is_err());
+    }
+
+    #[test]
+    fn test_encode_decode() {
+        let v = b"a\\b\x00\xff";
+        let cases = [
+            ("hex", "615c6200ff"),
+            ("base64", "YVxiAP8="),
+            ("escape", "a\\\\b\\000\\377"),
+        ];
+        for (format, encoded) in cases {
+            assert_eq!(encode(v, format).unwrap(), encoded);
+            assert_eq!(&*decode(encoded, format).unwrap(), v);
+        }
+        assert!(encode(v, "utf8").is_err());
+        assert!(decode("\\9", "escape").is_err());
+        assert!(decode("zz", "hex").is_err());
+    }
+
+    #[test]
+    fn test_parse_bytea() {
+        assert_eq!(&*parse_bytea("\\xdeadBEEF").unwrap(), b"\xde\xad\xbe\xef");
+        assert_eq!(&*parse_bytea("ab\\001").unwrap(), b"ab\x01");
+        assert!(parse_bytea("\\x0").is_err());
+    }
+}
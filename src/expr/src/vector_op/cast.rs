@@ -21,9 +21,12 @@ use num_traits::ToPrimitive;
 use risingwave_common::error::ErrorCode::{InternalError, InvalidInputSyntax, ParseError};
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::types::{
-    Decimal, NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper, OrderedF32, OrderedF64,
+    Decimal, JsonbRef, JsonbVal, NaiveDateTimeWrapper, NaiveDateWrapper, NaiveTimeWrapper,
+    OrderedF32, OrderedF64, ScalarRefImpl,
 };
 
+use crate::vector_op::bytea::parse_bytea;
+
 /// String literals for bool type.
 ///
 /// See [`https://www.postgresql.org/docs/9.5/datatype-boolean.html`]
@@ -105,6 +108,11 @@ pub fn date_to_timestamp(elem: NaiveDateWrapper) -> Result<NaiveDateTimeWrapper>
     Ok(NaiveDateTimeWrapper::new(elem.0.and_hms(0, 0, 0)))
 }
 
+#[inline(always)]
+pub fn timestamp_to_date(elem: NaiveDateTimeWrapper) -> Result<NaiveDateWrapper> {
+    Ok(NaiveDateWrapper::new(elem.0.date()))
+}
+
 /// Define the cast function to primitive types.
 ///
 /// Due to the orphan rule, some data can't implement `TryFrom` trait for basic type.
@@ -203,3 +211,24 @@ pub fn bool_to_str(input: bool) -> Result<String> {
         false => Ok("false".into()),
     }
 }
+
+#[inline(always)]
+pub fn str_to_jsonb(elem: &str) -> Result<JsonbVal> {
+    JsonbVal::from_str(elem).map_err(|e| RwError::from(ParseError(Box::new(e))))
+}
+
+#[inline(always)]
+pub fn jsonb_to_str(elem: JsonbRef<'_>) -> Result<String> {
+    Ok(elem.to_string())
+}
+
+#[inline(always)]
+pub fn str_to_bytea(elem: &str) -> Result<Box<[u8]>> {
+    parse_bytea(elem)
+}
+
+/// Bytea values are always converted to the hex format, e.g. `\xdeadbeef`.
+#[inline(always)]
+pub fn bytea_to_str(elem: &[u8]) -> Result<String> {
+    Ok(ScalarRefImpl::Bytea(elem).to_string())
+}
@@ -0,0 +1,71 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conditional expressions over arguments of the same type. They are evaluated row by row, so
+//! they work on any type.
+
+use risingwave_common::types::{Datum, DatumRef, ToOwnedDatum};
+
+/// `coalesce(value, ...)`. Returns the first argument that is not `NULL`.
+pub fn coalesce(args: &[DatumRef<'_>]) -> Datum {
+    args.iter().flatten().next().map(|v| v.into_scalar_impl())
+}
+
+/// `nullif(value1, value2)`. Returns `NULL` if the arguments are equal, otherwise `value1`.
+pub fn nullif(args: &[DatumRef<'_>]) -> Datum {
+    match args {
+        [Some(l), Some(r)] if l == r => None,
+        [l, _] => l.to_owned_datum(),
+        _ => unreachable!("nullif takes 2 arguments"),
+    }
+}
+
+/// `greatest(value, ...)`. `NULL` arguments are ignored, and the result is `NULL` only if all of
+/// them are `NULL`.
+pub fn greatest(args: &[DatumRef<'_>]) -> Datum {
+    args.iter().flatten().max().map(|v| v.into_scalar_impl())
+}
+
+/// `least(value, ...)`. `NULL` arguments are ignored, and the result is `NULL` only if all of
+/// them are `NULL`.
+pub fn least(args: &[DatumRef<'_>]) -> Datum {
+    args.iter().flatten().min().map(|v| v.into_scalar_impl())
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::{ScalarImpl, ScalarRefImpl};
+
+    use super::*;
+
+    #[test]
+    fn test_conditional() {
+        let one = Some(ScalarRefImpl::Int32(1));
+        let two = Some(ScalarRefImpl::Int32(2));
+        let int = |v| Some(ScalarImpl::Int32(v));
+
+        assert_eq!(coalesce(&[None, two, one]), int(2));
+        assert_eq!(coalesce(&[None, None]), None);
+
+        assert_eq!(nullif(&[one, one]), None);
+        assert_eq!(nullif(&[one, two]), int(1));
+        assert_eq!(nullif(&[one, None]), int(1));
+        assert_eq!(nullif(&[None, one]), None);
+
+        assert_eq!(greatest(&[one, None, two]), int(2));
+        assert_eq!(least(&[one, None, two]), int(1));
+        assert_eq!(greatest(&[None, None]), None);
+        assert_eq!(least(&[None]), None);
+    }
+}
@@ -0,0 +1,109 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::{Datelike, Duration, NaiveDate, Timelike};
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::Result;
+use risingwave_common::types::NaiveDateTimeWrapper;
+
+/// `date_trunc(field, timestamp)` truncates the timestamp to the precision of `field`.
+#[inline(always)]
+pub fn date_trunc_timestamp(
+    field: &str,
+    timestamp: NaiveDateTimeWrapper,
+) -> Result<NaiveDateTimeWrapper> {
+    let dt = timestamp.0;
+    let (date, time) = (dt.date(), dt.time());
+    let year = date.year();
+    let first_day_of_year = |year| NaiveDate::from_ymd(year, 1, 1);
+    let truncated = match field.to_ascii_lowercase().as_str() {
+        "microseconds" => date.and_hms_nano(
+            time.hour(),
+            time.minute(),
+            time.second(),
+            time.nanosecond() / 1_000 * 1_000,
+        ),
+        "milliseconds" => date.and_hms_nano(
+            time.hour(),
+            time.minute(),
+            time.second(),
+            time.nanosecond() / 1_000_000 * 1_000_000,
+        ),
+        "second" => date.and_hms(time.hour(), time.minute(), time.second()),
+        "minute" => date.and_hms(time.hour(), time.minute(), 0),
+        "hour" => date.and_hms(time.hour(), 0, 0),
+        "day" => date.and_hms(0, 0, 0),
+        // Weeks start on Monday as in ISO 8601.
+        "week" => {
+            (date - Duration::days(date.weekday().num_days_from_monday() as i64)).and_hms(0, 0, 0)
+        }
+        "month" => NaiveDate::from_ymd(year, date.month(), 1).and_hms(0, 0, 0),
+        "quarter" => NaiveDate::from_ymd(year, (date.month() - 1) / 3 * 3 + 1, 1).and_hms(0, 0, 0),
+        "year" => first_day_of_year(year).and_hms(0, 0, 0),
+        "decade" => first_day_of_year(year.div_euclid(10) * 10).and_hms(0, 0, 0),
+        // Centuries and millenniums start from the year 1, e.g. the 21st century starts from 2001.
+        "century" => first_day_of_year((year - 1).div_euclid(100) * 100 + 1).and_hms(0, 0, 0),
+        "millennium" => first_day_of_year((year - 1).div_euclid(1000) * 1000 + 1).and_hms(0, 0, 0),
+        _ => {
+            return Err(
+                InvalidInputSyntax(format!("timestamp units \"{}\" not recognized", field)).into(),
+            )
+        }
+    };
+    Ok(NaiveDateTimeWrapper::new(truncated))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+
+    fn timestamp(s: &str) -> NaiveDateTimeWrapper {
+        NaiveDateTimeWrapper::new(NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f").unwrap())
+    }
+
+    #[test]
+    fn test_date_trunc() {
+        let ts = timestamp("2022-05-18 13:45:12.345678");
+        let cases = [
+            ("microseconds", "2022-05-18 13:45:12.345678"),
+            ("milliseconds", "2022-05-18 13:45:12.345"),
+            ("second", "2022-05-18 13:45:12"),
+            ("MINUTE", "2022-05-18 13:45:00"),
+            ("hour", "2022-05-18 13:00:00"),
+            ("day", "2022-05-18 00:00:00"),
+            ("week", "2022-05-16 00:00:00"),
+            ("month", "2022-05-01 00:00:00"),
+            ("quarter", "2022-04-01 00:00:00"),
+            ("year", "2022-01-01 00:00:00"),
+            ("decade", "2020-01-01 00:00:00"),
+            ("century", "2001-01-01 00:00:00"),
+            ("millennium", "2001-01-01 00:00:00"),
+        ];
+        for (field, expected) in cases {
+            assert_eq!(
+                date_trunc_timestamp(field, ts).unwrap(),
+                timestamp(expected),
+                "{}",
+                field
+            );
+        }
+        assert_eq!(
+            date_trunc_timestamp("century", timestamp("2000-12-31 23:59:59")).unwrap(),
+            timestamp("1901-01-01 00:00:00")
+        );
+        assert!(date_trunc_timestamp("fortnight", ts).is_err());
+    }
+}
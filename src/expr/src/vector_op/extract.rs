@@ -13,54 +13,79 @@
 // limitations under the License.
 
 use chrono::{Datelike, Timelike};
-use risingwave_common::error::ErrorCode::InternalError;
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::types::{Decimal, NaiveDateTimeWrapper, NaiveDateWrapper};
 
-fn extract_time<T>(time: T, time_unit: &str) -> Result<Decimal>
+fn extract_time<T>(time: T, time_unit: &str) -> Option<Decimal>
 where
     T: Timelike,
 {
-    match time_unit {
-        "HOUR" => Ok(time.hour().into()),
-        "MINUTE" => Ok(time.minute().into()),
-        "SECOND" => Ok(time.second().into()),
-        _ => Err(RwError::from(InternalError(format!(
-            "Unsupported time unit {} in extract function",
-            time_unit
-        )))),
-    }
+    let micros = time.second() as i64 * 1_000_000 + time.nanosecond() as i64 / 1_000;
+    Some(match time_unit {
+        "HOUR" => time.hour().into(),
+        "MINUTE" => time.minute().into(),
+        // Including the fractional part.
+        "SECOND" => Decimal::new(micros, 6),
+        "MILLISECONDS" => Decimal::new(micros, 3),
+        "MICROSECONDS" => micros.into(),
+        _ => return None,
+    })
 }
 
-fn extract_date<T>(date: T, time_unit: &str) -> Result<Decimal>
+fn extract_date<T>(date: T, time_unit: &str) -> Option<Decimal>
 where
     T: Datelike,
 {
-    match time_unit {
-        "DAY" => Ok(date.day().into()),
-        "MONTH" => Ok(date.month().into()),
-        "YEAR" => Ok(date.year().into()),
+    let year = date.year();
+    Some(match time_unit {
+        "DAY" => date.day().into(),
+        "MONTH" => date.month().into(),
+        "YEAR" => year.into(),
         // Sun = 0 and Sat = 6
-        "DOW" => Ok(date.weekday().num_days_from_sunday().into()),
-        "DOY" => Ok(date.ordinal().into()),
-        _ => Err(RwError::from(InternalError(format!(
-            "Unsupported time unit {} in extract function",
-            time_unit
-        )))),
-    }
+        "DOW" => date.weekday().num_days_from_sunday().into(),
+        // Mon = 1 and Sun = 7
+        "ISODOW" => date.weekday().number_from_monday().into(),
+        "DOY" => date.ordinal().into(),
+        "WEEK" => date.iso_week().week().into(),
+        "ISOYEAR" => date.iso_week().year().into(),
+        "QUARTER" => (date.month0() / 3 + 1).into(),
+        "DECADE" => year.div_euclid(10).into(),
+        // The 21st century starts from 2001.
+        "CENTURY" => ((year - 1).div_euclid(100) + 1).into(),
+        "MILLENNIUM" => ((year - 1).div_euclid(1000) + 1).into(),
+        _ => return None,
+    })
+}
+
+fn unsupported_time_unit(time_unit: &str) -> RwError {
+    InvalidInputSyntax(format!(
+        "Unsupported time unit {} in extract function",
+        time_unit
+    ))
+    .into()
 }
 
 pub fn extract_from_date(time_unit: &str, date: NaiveDateWrapper) -> Result<Decimal> {
-    extract_date(date.0, time_unit)
+    let time_unit = time_unit.to_ascii_uppercase();
+    if time_unit == "EPOCH" {
+        let seconds = date.0.and_hms(0, 0, 0).timestamp();
+        return Ok(seconds.into());
+    }
+    extract_date(date.0, &time_unit).ok_or_else(|| unsupported_time_unit(&time_unit))
 }
 
 pub fn extract_from_timestamp(time_unit: &str, timestamp: NaiveDateTimeWrapper) -> Result<Decimal> {
+    let time_unit = time_unit.to_ascii_uppercase();
     let time = timestamp.0;
-    let mut res = extract_date(time, time_unit);
-    if res.is_err() {
-        res = extract_time(time, time_unit);
+    if time_unit == "EPOCH" {
+        // Seconds since 1970-01-01 00:00:00, including the fractional part.
+        let micros = time.timestamp() * 1_000_000 + time.timestamp_subsec_micros() as i64;
+        return Ok(Decimal::new(micros, 6));
     }
-    res
+    extract_date(time, &time_unit)
+        .or_else(|| extract_time(time, &time_unit))
+        .ok_or_else(|| unsupported_time_unit(&time_unit))
 }
 
 #[cfg(test)]
@@ -78,6 +103,15 @@ mod tests {
         assert_eq!(extract_from_date("YEAR", date).unwrap(), 2021.into());
         assert_eq!(extract_from_date("DOW", date).unwrap(), 1.into());
         assert_eq!(extract_from_date("DOY", date).unwrap(), 326.into());
+        assert_eq!(extract_from_date("isodow", date).unwrap(), 1.into());
+        assert_eq!(extract_from_date("WEEK", date).unwrap(), 47.into());
+        assert_eq!(extract_from_date("ISOYEAR", date).unwrap(), 2021.into());
+        assert_eq!(extract_from_date("QUARTER", date).unwrap(), 4.into());
+        assert_eq!(extract_from_date("DECADE", date).unwrap(), 202.into());
+        assert_eq!(extract_from_date("CENTURY", date).unwrap(), 21.into());
+        assert_eq!(extract_from_date("MILLENNIUM", date).unwrap(), 3.into());
+        assert_eq!(extract_from_date("EPOCH", date).unwrap(), 1637539200.into());
+        assert!(extract_from_date("HOUR", date).is_err());
     }
 
     #[test]
@@ -88,5 +122,27 @@ mod tests {
         assert_eq!(extract_from_timestamp("HOUR", time).unwrap(), 12.into());
         assert_eq!(extract_from_timestamp("MINUTE", time).unwrap(), 4.into());
         assert_eq!(extract_from_timestamp("SECOND", time).unwrap(), 2.into());
+
+        let time = NaiveDateTimeWrapper::new(
+            NaiveDateTime::parse_from_str("1970-01-01 00:01:02.345678", "%Y-%m-%d %H:%M:%S%.f")
+                .unwrap(),
+        );
+        let decimal = |s: &str| s.parse::<Decimal>().unwrap();
+        assert_eq!(
+            extract_from_timestamp("second", time).unwrap(),
+            decimal("2.345678")
+        );
+        assert_eq!(
+            extract_from_timestamp("MILLISECONDS", time).unwrap(),
+            decimal("2345.678")
+        );
+        assert_eq!(
+            extract_from_timestamp("MICROSECONDS", time).unwrap(),
+            2345678.into()
+        );
+        assert_eq!(
+            extract_from_timestamp("EPOCH", time).unwrap(),
+            decimal("62.345678")
+        );
     }
 }
@@ -0,0 +1,161 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{JsonbRef, JsonbVal, ScalarRef};
+
+/// `jsonb -> text`. Returns `NULL` if the key is absent or the value is not an object.
+#[inline(always)]
+pub fn jsonb_access_field(v: Option<JsonbRef<'_>>, key: Option<&str>) -> Result<Option<JsonbVal>> {
+    Ok(v.zip(key)
+        .and_then(|(v, key)| v.access_object_field(key))
+        .map(|v| v.to_owned_scalar()))
+}
+
+/// `jsonb -> integer`. Returns `NULL` if the index is out of range or the value is not an array.
+#[inline(always)]
+pub fn jsonb_access_index(v: Option<JsonbRef<'_>>, idx: Option<i32>) -> Result<Option<JsonbVal>> {
+    Ok(v.zip(idx)
+        .and_then(|(v, idx)| v.access_array_element(idx as i64))
+        .map(|v| v.to_owned_scalar()))
+}
+
+/// `jsonb ->> text`.
+#[inline(always)]
+pub fn jsonb_access_field_str(
+    v: Option<JsonbRef<'_>>,
+    key: Option<&str>,
+) -> Result<Option<String>> {
+    Ok(v.zip(key)
+        .and_then(|(v, key)| v.access_object_field(key))
+        .and_then(|v| v.as_text()))
+}
+
+/// `jsonb ->> integer`.
+#[inline(always)]
+pub fn jsonb_access_index_str(v: Option<JsonbRef<'_>>, idx: Option<i32>) -> Result<Option<String>> {
+    Ok(v.zip(idx)
+        .and_then(|(v, idx)| v.access_array_element(idx as i64))
+        .and_then(|v| v.as_text()))
+}
+
+/// `jsonb #> path`, where `path` is written as an array literal of keys, e.g. `'{a,0,b}'`.
+#[inline(always)]
+pub fn jsonb_access_path(v: Option<JsonbRef<'_>>, path: Option<&str>) -> Result<Option<JsonbVal>> {
+    let (Some(v), Some(path)) = (v, path) else {
+        return Ok(None);
+    };
+    let keys = path
+        .trim()
+        .strip_prefix('{')
+        .and_then(|p| p.strip_suffix('}'))
+        .ok_or_else(|| {
+            RwError::from(InvalidInputSyntax(format!(
+                "malformed path literal: \"{}\"",
+                path
+            )))
+        })?;
+    let result = if keys.trim().is_empty() {
+        Some(v)
+    } else {
+        v.access_path(keys.split(',').map(|k| k.trim().trim_matches('"')))
+    };
+    Ok(result.map(|v| v.to_owned_scalar()))
+}
+
+/// `jsonb @> jsonb`.
+#[inline(always)]
+pub fn jsonb_contains(left: JsonbRef<'_>, right: JsonbRef<'_>) -> Result<bool> {
+    Ok(left.contains(right))
+}
+
+#[inline(always)]
+pub fn jsonb_typeof(v: JsonbRef<'_>) -> Result<String> {
+    Ok(v.type_name().to_string())
+}
+
+/// Expands a JSON array into its elements, as `jsonb_array_elements`.
+pub fn jsonb_array_elements(v: JsonbRef<'_>) -> Result<Vec<JsonbVal>> {
+    match v.array_elements() {
+        Some(elements) => Ok(elements.map(|e| e.to_owned_scalar()).collect()),
+        None => Err(InvalidInputSyntax(format!(
+            "cannot extract elements from a jsonb {}",
+            v.type_name()
+        ))
+        .into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::Scalar;
+
+    use super::*;
+
+    #[test]
+    fn test_jsonb_access() {
+        let v: JsonbVal = r#"{"a": {"b": [10, "x", null]}, "c": "text"}"#.parse().unwrap();
+        let v = Some(v.as_scalar_ref());
+
+        let a = jsonb_access_field(v, Some("a")).unwrap().unwrap();
+        assert_eq!(a.to_string(), r#"{"b":[10,"x",null]}"#);
+        assert_eq!(jsonb_access_field(v, Some("z")).unwrap(), None);
+        assert_eq!(jsonb_access_field(v, None).unwrap(), None);
+        assert_eq!(jsonb_access_index(v, Some(0)).unwrap(), None);
+        assert_eq!(
+            jsonb_access_field_str(v, Some("c")).unwrap().as_deref(),
+            Some("text")
+        );
+
+        let b = jsonb_access_path(v, Some("{a,b}")).unwrap().unwrap();
+        let b = Some(b.as_scalar_ref());
+        assert_eq!(
+            jsonb_access_index(b, Some(-3))
+                .unwrap()
+                .unwrap()
+                .to_string(),
+            "10"
+        );
+        assert_eq!(
+            jsonb_access_index_str(b, Some(1)).unwrap().as_deref(),
+            Some("x")
+        );
+        assert_eq!(jsonb_access_index_str(b, Some(2)).unwrap(), None);
+        assert_eq!(
+            jsonb_access_path(v, Some("{a, b, 1}"))
+                .unwrap()
+                .unwrap()
+                .to_string(),
+            r#""x""#
+        );
+        assert_eq!(jsonb_access_path(v, Some("{a,c}")).unwrap(), None);
+        assert!(jsonb_access_path(v, Some("a,b")).is_err());
+    }
+
+    #[test]
+    fn test_jsonb_functions() {
+        let v: JsonbVal = r#"[1, {"a": true}]"#.parse().unwrap();
+        let v = v.as_scalar_ref();
+        let sub: JsonbVal = r#"[{"a": true}]"#.parse().unwrap();
+        assert!(jsonb_contains(v, sub.as_scalar_ref()).unwrap());
+        assert!(!jsonb_contains(sub.as_scalar_ref(), v).unwrap());
+
+        assert_eq!(jsonb_typeof(v).unwrap(), "array");
+        let elements = jsonb_array_elements(v).unwrap();
+        assert_eq!(elements.len(), 2);
+        assert_eq!(jsonb_typeof(elements[1].as_scalar_ref()).unwrap(), "object");
+        assert!(jsonb_array_elements(elements[0].as_scalar_ref()).is_err());
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::ErrorCode::{InvalidInputSyntax, NumericValueOutOfRange};
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::{Decimal, OrderedF64};
+
+fn invalid_input(message: &str) -> RwError {
+    InvalidInputSyntax(message.to_string()).into()
+}
+
+#[inline(always)]
+pub fn ceil_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.ceil().into())
+}
+
+#[inline(always)]
+pub fn ceil_decimal(input: Decimal) -> Result<Decimal> {
+    Ok(input.ceil())
+}
+
+#[inline(always)]
+pub fn floor_f64(input: OrderedF64) -> Result<OrderedF64> {
+    Ok(input.0.floor().into())
+}
+
+#[inline(always)]
+pub fn floor_decimal(input: Decimal) -> Result<Decimal> {
+    Ok(input.floor())
+}
+
+#[inline(always)]
+pub fn power_f64(base: OrderedF64, exponent: OrderedF64) -> Result<OrderedF64> {
+    let (base, exponent) = (base.0, exponent.0);
+    if base == 0.0 && exponent < 0.0 {
+        return Err(invalid_input(
+            "zero raised to a negative power is undefined",
+        ));
+    }
+    if base < 0.0 && exponent.fract() != 0.0 {
+        return Err(invalid_input(
+            "a negative number raised to a non-integer power yields a complex result",
+        ));
+    }
+    let result = base.powf(exponent);
+    if result.is_infinite() && base.is_finite() && exponent.is_finite() {
+        return Err(NumericValueOutOfRange.into());
+    }
+    Ok(result.into())
+}
+
+#[inline(always)]
+pub fn ln_f64(input: OrderedF64) -> Result<OrderedF64> {
+    if input.0 == 0.0 {
+        Err(invalid_input("cannot take logarithm of zero"))
+    } else if input.0 < 0.0 {
+        Err(invalid_input("cannot take logarithm of a negative number"))
+    } else {
+        Ok(input.0.ln().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_ceil_floor() {
+        assert_eq!(ceil_f64(1.2.into()).unwrap(), OrderedF64::from(2.0));
+        assert_eq!(ceil_f64((-1.2).into()).unwrap(), OrderedF64::from(-1.0));
+        assert_eq!(floor_f64(1.8.into()).unwrap(), OrderedF64::from(1.0));
+        assert_eq!(floor_f64((-1.2).into()).unwrap(), OrderedF64::from(-2.0));
+
+        let decimal = |s: &str| Decimal::from_str(s).unwrap();
+        assert_eq!(ceil_decimal(decimal("1.2")).unwrap(), decimal("2"));
+        assert_eq!(floor_decimal(decimal("-1.2")).unwrap(), decimal("-2"));
+    }
+
+    #[test]
+    fn test_power() {
+        assert_eq!(
+            power_f64(2.0.into(), 10.0.into()).unwrap(),
+            OrderedF64::from(1024.0)
+        );
+        assert_eq!(
+            power_f64((-2.0).into(), 3.0.into()).unwrap(),
+            OrderedF64::from(-8.0)
+        );
+        assert_eq!(
+            power_f64(4.0.into(), (-0.5).into()).unwrap(),
+            OrderedF64::from(0.5)
+        );
+        assert!(power_f64(0.0.into(), (-1.0).into()).is_err());
+        assert!(power_f64((-2.0).into(), 0.5.into()).is_err());
+        assert!(power_f64(10.0.into(), 1000.0.into()).is_err());
+    }
+
+    #[test]
+    fn test_ln() {
+        assert_eq!(ln_f64(1.0.into()).unwrap(), OrderedF64::from(0.0));
+        assert_eq!(
+            ln_f64(std::f64::consts::E.into()).unwrap(),
+            OrderedF64::from(1.0)
+        );
+        assert!(ln_f64(0.0.into()).is_err());
+        assert!(ln_f64((-1.0).into()).is_err());
+    }
+}
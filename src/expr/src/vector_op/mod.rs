@@ -14,20 +14,38 @@
 
 pub mod agg;
 pub mod arithmetic_op;
+pub mod array;
 pub mod ascii;
+pub mod bytea;
 pub mod cast;
 pub mod cmp;
+pub mod concat_op;
+pub mod conditional;
 pub mod conjunction;
+pub mod date_trunc;
 pub mod extract;
+pub mod initcap;
+pub mod jsonb;
 pub mod length;
 pub mod like;
 pub mod lower;
 pub mod ltrim;
+pub mod math;
+pub mod md5;
+pub mod pad;
 pub mod position;
+pub mod regexp;
+pub mod repeat;
 pub mod replace;
+pub mod reverse;
 pub mod round;
 pub mod rtrim;
+pub mod split_part;
+pub mod sqrt;
+pub mod starts_with;
 pub mod substr;
+pub mod timestampz;
+pub mod to_char;
 pub mod translate;
 pub mod trim;
 pub mod tumble;
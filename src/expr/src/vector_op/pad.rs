@@ -0,0 +1,93 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::array::{BytesGuard, BytesWriter, PartialBytesWriter};
+use risingwave_common::error::Result;
+
+/// Writes `count` characters by repeating `fill`, which is not empty.
+fn write_fill(writer: &mut PartialBytesWriter, fill: &str, count: usize) -> Result<()> {
+    let fill_len = fill.chars().count();
+    for _ in 0..count / fill_len {
+        writer.write_ref(fill)?;
+    }
+    let end = fill
+        .char_indices()
+        .nth(count % fill_len)
+        .map_or(fill.len(), |(i, _)| i);
+    writer.write_ref(&fill[..end])
+}
+
+/// Pads `s` to `length` characters, or truncates it on the right if it's longer. Returns the
+/// string to write and the number of characters to fill.
+fn pad_or_truncate(s: &str, length: i32, fill: &str) -> (&str, usize) {
+    let length = length.max(0) as usize;
+    match s.char_indices().nth(length) {
+        Some((end, _)) => (&s[..end], 0),
+        None if fill.is_empty() => (s, 0),
+        None => (s, length - s.chars().count()),
+    }
+}
+
+/// `lpad(string, length, fill)`. Fills up `s` to `length` on the left.
+#[inline(always)]
+pub fn lpad(s: &str, length: i32, fill: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    let (s, count) = pad_or_truncate(s, length, fill);
+    let mut writer = writer.begin();
+    if count > 0 {
+        write_fill(&mut writer, fill, count)?;
+    }
+    writer.write_ref(s)?;
+    writer.finish()
+}
+
+/// `rpad(string, length, fill)`. Fills up `s` to `length` on the right.
+#[inline(always)]
+pub fn rpad(s: &str, length: i32, fill: &str, writer: BytesWriter) -> Result<BytesGuard> {
+    let (s, count) = pad_or_truncate(s, length, fill);
+    let mut writer = writer.begin();
+    writer.write_ref(s)?;
+    if count > 0 {
+        write_fill(&mut writer, fill, count)?;
+    }
+    writer.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
+
+    use super::*;
+
+    #[test]
+    fn test_pad() -> Result<()> {
+        let cases = [
+            ("hi", 5, "xy", "xyxhi", "hixyx"),
+            ("hi", 4, "xy", "xyhi", "hixy"),
+            ("hello", 2, "xy", "he", "he"),
+            ("hi", 5, "", "hi", "hi"),
+            ("hi", -1, "xy", "", ""),
+            ("你好", 4, "啊", "啊啊你好", "你好啊啊"),
+        ];
+
+        for (s, length, fill, expected_lpad, expected_rpad) in cases {
+            let builder = Utf8ArrayBuilder::new(2)?;
+            let guard = lpad(s, length, fill, builder.writer())?;
+            let guard = rpad(s, length, fill, guard.into_inner().writer())?;
+            let array = guard.into_inner().finish()?;
+            assert_eq!(array.value_at(0).unwrap(), expected_lpad);
+            assert_eq!(array.value_at(1).unwrap(), expected_rpad);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,271 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use regex::{Captures, Regex, RegexBuilder};
+use risingwave_common::array::ListValue;
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::ScalarImpl;
+
+/// A compiled regular expression, along with the flags that decide how it is applied.
+#[derive(Debug, Clone)]
+pub struct RegexpContext {
+    pub regex: Regex,
+    /// Whether to apply on all matches (the `g` flag) rather than the first one only.
+    pub global: bool,
+}
+
+impl RegexpContext {
+    /// Compiles a POSIX regular expression with flags such as `gi`, as in
+    /// `regexp_replace(s, pattern, replacement, flags)`.
+    pub fn new(pattern: &str, flags: &str) -> Result<Self> {
+        let mut builder = RegexBuilder::new(pattern);
+        // Unlike the `regex` crate, `.` matches newlines in Postgres by default.
+        builder.dot_matches_new_line(true);
+        let mut global = false;
+        for flag in flags.chars() {
+            match flag {
+                'c' => builder.case_insensitive(false),
+                'i' => builder.case_insensitive(true),
+                'n' | 'm' => builder.multi_line(true).dot_matches_new_line(false),
+                's' => builder.multi_line(false).dot_matches_new_line(true),
+                'x' => builder.ignore_whitespace(true),
+                'g' => {
+                    global = true;
+                    continue;
+                }
+                _ => {
+                    return Err(InvalidInputSyntax(format!(
+                        "invalid regular expression option: \"{}\"",
+                        flag
+                    ))
+                    .into())
+                }
+            };
+        }
+        let regex = builder.build().map_err(|e| {
+            RwError::from(InvalidInputSyntax(format!(
+                "invalid regular expression: {}",
+                e
+            )))
+        })?;
+        Ok(Self { regex, global })
+    }
+
+    /// Compiles a `SIMILAR TO` pattern, which has to match the whole string. `escape` defaults to
+    /// backslash.
+    pub fn from_similar(pattern: &str, escape: Option<char>) -> Result<Self> {
+        Self::new(&similar_to_regex(pattern, escape.unwrap_or('\\'))?, "")
+    }
+}
+
+/// `s ~ pattern`, `s ~* pattern` and `s SIMILAR TO pattern`.
+#[inline(always)]
+pub fn regexp_eq(ctx: &RegexpContext, s: &str) -> bool {
+    ctx.regex.is_match(s)
+}
+
+/// `regexp_match(s, pattern [, flags])`. Returns the captured groups of the first match, or the
+/// whole match if there is no group in the pattern.
+#[inline(always)]
+pub fn regexp_match(ctx: &RegexpContext, s: &str) -> Option<ListValue> {
+    ctx.regex.captures(s).map(|caps| captures_to_list(&caps))
+}
+
+/// `regexp_matches(s, pattern [, flags])`. Same as [`regexp_match`] but returns all the matches
+/// when the `g` flag is given.
+pub fn regexp_matches(ctx: &RegexpContext, s: &str) -> Vec<ListValue> {
+    let matches = ctx
+        .regex
+        .captures_iter(s)
+        .map(|caps| captures_to_list(&caps));
+    if ctx.global {
+        matches.collect()
+    } else {
+        matches.take(1).collect()
+    }
+}
+
+/// `regexp_replace(s, pattern, replacement [, flags])`. `\1` to `\9` in `replacement` refer to
+/// the captured groups and `\&` to the whole match.
+pub fn regexp_replace(ctx: &RegexpContext, s: &str, replacement: &str) -> String {
+    let replacement = convert_replacement(replacement);
+    if ctx.global {
+        ctx.regex.replace_all(s, replacement.as_str()).into_owned()
+    } else {
+        ctx.regex.replace(s, replacement.as_str()).into_owned()
+    }
+}
+
+fn captures_to_list(caps: &Captures<'_>) -> ListValue {
+    let to_datum = |m: Option<regex::Match<'_>>| m.map(|m| ScalarImpl::Utf8(m.as_str().into()));
+    if caps.len() == 1 {
+        ListValue::new(vec![to_datum(caps.get(0))])
+    } else {
+        ListValue::new(caps.iter().skip(1).map(to_datum).collect())
+    }
+}
+
+/// Converts the Postgres replacement syntax to the one of the `regex` crate.
+fn convert_replacement(replacement: &str) -> String {
+    let mut result = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(d @ '1'..='9') => {
+                    result.push_str("${");
+                    result.push(d);
+                    result.push('}');
+                }
+                Some('&') => result.push_str("${0}"),
+                Some('\\') => result.push('\\'),
+                Some('$') => result.push_str("\\$$"),
+                Some(other) => {
+                    result.push('\\');
+                    result.push(other);
+                }
+                None => result.push('\\'),
+            },
+            '$' => result.push_str("$$"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// Translates a `SIMILAR TO` pattern into an anchored regular expression. `%` and `_` are the
+/// wildcards as in `LIKE`, `.` is an ordinary character, and the other regex operators such as
+/// `|`, `*` and `(...)` keep their meaning.
+fn similar_to_regex(pattern: &str, escape: char) -> Result<String> {
+    let mut result = String::with_capacity(pattern.len() + 6);
+    result.push_str("^(?:");
+    let mut in_bracket = false;
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        if c == escape {
+            let escaped = chars.next().ok_or_else(|| {
+                RwError::from(InvalidInputSyntax(
+                    "SIMILAR TO pattern must not end with escape character".to_string(),
+                ))
+            })?;
+            result.push_str(&regex::escape(escaped.encode_utf8(&mut [0; 4])));
+            continue;
+        }
+        match c {
+            '[' if in_bracket => result.push_str("\\["),
+            '[' => {
+                in_bracket = true;
+                result.push('[');
+            }
+            ']' if in_bracket => {
+                in_bracket = false;
+                result.push(']');
+            }
+            c if in_bracket => result.push(c),
+            '%' => result.push_str(".*"),
+            '_' => result.push('.'),
+            '|' | '*' | '+' | '?' | '(' | ')' | '{' | '}' => result.push(c),
+            c => result.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+        }
+    }
+    result.push_str(")$");
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list(values: &[Option<&str>]) -> ListValue {
+        ListValue::new(
+            values
+                .iter()
+                .map(|v| v.map(|v| ScalarImpl::Utf8(v.into())))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_regexp_eq() {
+        let ctx = RegexpContext::new("^a.c$", "").unwrap();
+        assert!(regexp_eq(&ctx, "abc"));
+        assert!(regexp_eq(&ctx, "a\nc"));
+        assert!(!regexp_eq(&ctx, "ABC"));
+        let ctx = RegexpContext::new("^a.c$", "i").unwrap();
+        assert!(regexp_eq(&ctx, "ABC"));
+        assert!(RegexpContext::new("a", "z").is_err());
+        assert!(RegexpContext::new("(a", "").is_err());
+    }
+
+    #[test]
+    fn test_similar_to() {
+        let cases = [
+            ("abc", "abc", None, true),
+            ("abc", "a", None, false),
+            ("abc", "%(b|d)%", None, true),
+            ("abc", "(b|c)%", None, false),
+            ("a.c", "a.c", None, true),
+            ("abc", "a.c", None, false),
+            ("abc", "_b_", None, true),
+            ("a_c", "a#_c", Some('#'), true),
+            ("abc", "a#_c", Some('#'), false),
+            ("abc", "[a-c]*", None, true),
+            ("a%", "a\\%", None, true),
+        ];
+        for (s, pattern, escape, expected) in cases {
+            let ctx = RegexpContext::from_similar(pattern, escape).unwrap();
+            assert_eq!(regexp_eq(&ctx, s), expected, "{} SIMILAR TO {}", s, pattern);
+        }
+        assert!(RegexpContext::from_similar("ab\\", None).is_err());
+    }
+
+    #[test]
+    fn test_regexp_match() {
+        let ctx = RegexpContext::new("b(a)(x)?", "").unwrap();
+        assert_eq!(
+            regexp_match(&ctx, "foobarbequebaz"),
+            Some(list(&[Some("a"), None]))
+        );
+        let ctx = RegexpContext::new("ba.", "").unwrap();
+        assert_eq!(regexp_match(&ctx, "foobarbaz"), Some(list(&[Some("bar")])));
+        assert_eq!(regexp_match(&ctx, "foo"), None);
+    }
+
+    #[test]
+    fn test_regexp_matches() {
+        let ctx = RegexpContext::new("ba(.)", "").unwrap();
+        assert_eq!(regexp_matches(&ctx, "foobarbaz"), vec![list(&[Some("r")])]);
+        let ctx = RegexpContext::new("ba(.)", "g").unwrap();
+        assert_eq!(
+            regexp_matches(&ctx, "foobarbaz"),
+            vec![list(&[Some("r")]), list(&[Some("z")])]
+        );
+        assert!(regexp_matches(&ctx, "foo").is_empty());
+    }
+
+    #[test]
+    fn test_regexp_replace() {
+        let ctx = RegexpContext::new("b(.)", "").unwrap();
+        assert_eq!(regexp_replace(&ctx, "foobarbaz", "X\\1Y"), "fooXaYrbaz");
+        let ctx = RegexpContext::new("b(.)", "g").unwrap();
+        assert_eq!(regexp_replace(&ctx, "foobarbaz", "X\\1Y"), "fooXaYrXaYz");
+        assert_eq!(
+            regexp_replace(&ctx, "foobarbaz", "[\\&]$1"),
+            "foo[ba]$1r[ba]$1z"
+        );
+        let ctx = RegexpContext::new("B", "gi").unwrap();
+        assert_eq!(regexp_replace(&ctx, "abcb", "\\\\"), "a\\c\\");
+    }
+}
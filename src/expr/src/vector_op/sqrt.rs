@@ -0,0 +1,52 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::{Decimal, OrderedF64};
+
+fn negative_sqrt_error() -> ErrorCode {
+    ErrorCode::InvalidInputSyntax("cannot take square root of a negative number".to_string())
+}
+
+#[inline(always)]
+pub fn sqrt_f64(input: OrderedF64) -> Result<OrderedF64> {
+    if input.0 < 0.0 {
+        Err(negative_sqrt_error().into())
+    } else {
+        Ok(input.0.sqrt().into())
+    }
+}
+
+#[inline(always)]
+pub fn sqrt_decimal(input: Decimal) -> Result<Decimal> {
+    input.sqrt().ok_or_else(|| negative_sqrt_error().into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(sqrt_f64(4.0.into()).unwrap(), OrderedF64::from(2.0));
+        assert!(sqrt_f64((-1.0).into()).is_err());
+        assert_eq!(
+            sqrt_decimal(Decimal::from_str("2.25").unwrap()).unwrap(),
+            Decimal::from_str("1.5").unwrap()
+        );
+        assert!(sqrt_decimal(Decimal::from_str("-1").unwrap()).is_err());
+    }
+}
@@ -48,6 +48,41 @@ pub fn substr_start_for(
     writer.write_ref(&s[begin..end])
 }
 
+/// Returns the byte offset where the first `n` characters end, clamped to the string.
+fn offset_from_start(s: &str, n: usize) -> usize {
+    s.char_indices().nth(n).map_or(s.len(), |(i, _)| i)
+}
+
+/// Returns the byte offset where the last `n` characters begin, clamped to the string.
+fn offset_from_end(s: &str, n: usize) -> usize {
+    match n {
+        0 => s.len(),
+        n => s.char_indices().rev().nth(n - 1).map_or(0, |(i, _)| i),
+    }
+}
+
+/// `left(string, n)`. Returns the first `n` characters, or all but the last `|n|` characters if
+/// `n` is negative.
+#[inline(always)]
+pub fn left(s: &str, n: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    let end = match n {
+        n if n >= 0 => offset_from_start(s, n as usize),
+        n => offset_from_end(s, n.unsigned_abs() as usize),
+    };
+    writer.write_ref(&s[..end])
+}
+
+/// `right(string, n)`. Returns the last `n` characters, or all but the first `|n|` characters if
+/// `n` is negative.
+#[inline(always)]
+pub fn right(s: &str, n: i32, writer: BytesWriter) -> Result<BytesGuard> {
+    let start = match n {
+        n if n >= 0 => offset_from_end(s, n as usize),
+        n => offset_from_start(s, n.unsigned_abs() as usize),
+    };
+    writer.write_ref(&s[start..])
+}
+
 #[cfg(test)]
 mod tests {
     use risingwave_common::array::{Array, ArrayBuilder, Utf8ArrayBuilder};
@@ -90,4 +125,26 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_left_right() -> Result<()> {
+        let cases = [
+            ("abcde", 2, "ab", "de"),
+            ("abcde", -2, "abc", "cde"),
+            ("abcde", 0, "", ""),
+            ("abcde", 10, "abcde", "abcde"),
+            ("abcde", -10, "", ""),
+            ("你好世界", 1, "你", "界"),
+        ];
+
+        for (s, n, expected_left, expected_right) in cases {
+            let builder = Utf8ArrayBuilder::new(2)?;
+            let guard = left(s, n, builder.writer())?;
+            let guard = right(s, n, guard.into_inner().writer())?;
+            let array = guard.into_inner().finish()?;
+            assert_eq!(array.value_at(0).unwrap(), expected_left);
+            assert_eq!(array.value_at(1).unwrap(), expected_right);
+        }
+        Ok(())
+    }
 }
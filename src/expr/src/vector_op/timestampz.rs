@@ -0,0 +1,107 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Conversions between `timestamp` and `timestamp with time zone`, the latter of which is stored
+//! as microseconds since the UNIX epoch.
+
+use chrono::{LocalResult, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::Result;
+use risingwave_common::types::NaiveDateTimeWrapper;
+
+/// Looks up a time zone by its IANA name, e.g. `Asia/Shanghai`. Names are case-sensitive except
+/// for `UTC` and `GMT`.
+pub fn lookup_time_zone(time_zone: &str) -> Result<Tz> {
+    time_zone.parse::<Tz>().or_else(|_| {
+        if time_zone.eq_ignore_ascii_case("UTC") {
+            Ok(Tz::UTC)
+        } else if time_zone.eq_ignore_ascii_case("GMT") {
+            Ok(Tz::GMT)
+        } else {
+            Err(InvalidInputSyntax(format!("time zone \"{}\" not recognized", time_zone)).into())
+        }
+    })
+}
+
+/// `timestamp AT TIME ZONE zone` interprets the timestamp as the local time in `zone`.
+#[inline(always)]
+pub fn timestamp_at_time_zone(input: NaiveDateTimeWrapper, time_zone: &str) -> Result<i64> {
+    let tz = lookup_time_zone(time_zone)?;
+    let instant = match tz.from_local_datetime(&input.0) {
+        LocalResult::Single(t) => t,
+        // An ambiguous time when the clock is turned back takes the earlier offset.
+        LocalResult::Ambiguous(t, _) => t,
+        // A time skipped when the clock is turned forward takes the offset before the transition.
+        LocalResult::None => {
+            let offset = tz.offset_from_utc_datetime(&input.0).fix();
+            tz.from_utc_datetime(&(input.0 - offset))
+        }
+    };
+    Ok(instant.timestamp_nanos() / 1000)
+}
+
+/// `timestamptz AT TIME ZONE zone` converts the instant to the local time in `zone`.
+#[inline(always)]
+pub fn timestampz_at_time_zone(input: i64, time_zone: &str) -> Result<NaiveDateTimeWrapper> {
+    let tz = lookup_time_zone(time_zone)?;
+    let utc = NaiveDateTime::from_timestamp(
+        input.div_euclid(1_000_000),
+        (input.rem_euclid(1_000_000) * 1000) as u32,
+    );
+    Ok(NaiveDateTimeWrapper::new(
+        tz.from_utc_datetime(&utc).naive_local(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector_op::cast::{str_to_timestamp, str_to_timestampz};
+
+    #[test]
+    fn test_lookup_time_zone() {
+        assert_eq!(
+            lookup_time_zone("Asia/Shanghai").unwrap(),
+            Tz::Asia__Shanghai
+        );
+        assert_eq!(lookup_time_zone("utc").unwrap(), Tz::UTC);
+        assert!(lookup_time_zone("Mars/Olympus_Mons").is_err());
+    }
+
+    #[test]
+    fn test_at_time_zone() {
+        let ts = str_to_timestamp("2022-05-18 08:00:00").unwrap();
+        let tsz = str_to_timestampz("2022-05-18 00:00:00 +00:00").unwrap();
+        assert_eq!(timestamp_at_time_zone(ts, "Asia/Shanghai").unwrap(), tsz);
+        assert_eq!(timestampz_at_time_zone(tsz, "Asia/Shanghai").unwrap(), ts);
+        assert_eq!(
+            timestampz_at_time_zone(tsz, "UTC").unwrap(),
+            str_to_timestamp("2022-05-18 00:00:00").unwrap()
+        );
+        assert!(timestamp_at_time_zone(ts, "Nowhere").is_err());
+
+        // 02:30 does not exist when the clock is turned forward in New York.
+        let skipped = str_to_timestamp("2022-03-13 02:30:00").unwrap();
+        assert_eq!(
+            timestamp_at_time_zone(skipped, "America/New_York").unwrap(),
+            str_to_timestampz("2022-03-13 07:30:00 +00:00").unwrap()
+        );
+        // Times before 1970 are negative.
+        let ts = str_to_timestamp("1969-12-31 23:59:59").unwrap();
+        let tsz = timestamp_at_time_zone(ts, "UTC").unwrap();
+        assert_eq!(tsz, -1_000_000);
+        assert_eq!(timestampz_at_time_zone(tsz, "UTC").unwrap(), ts);
+    }
+}
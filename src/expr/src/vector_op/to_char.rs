@@ -0,0 +1,428 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! `to_char` and `to_timestamp` with the template patterns of Postgres, e.g.
+//! `to_char(ts, 'YYYY-MM-DD HH24:MI:SS')`.
+//!
+//! See <https://www.postgresql.org/docs/current/functions-formatting.html>.
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use risingwave_common::error::ErrorCode::InvalidInputSyntax;
+use risingwave_common::error::{Result, RwError};
+use risingwave_common::types::NaiveDateTimeWrapper;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Case {
+    Upper,
+    Capitalized,
+    Lower,
+}
+
+impl Case {
+    fn apply(self, s: &str) -> String {
+        match self {
+            Case::Upper => s.to_uppercase(),
+            Case::Capitalized => s.to_string(),
+            Case::Lower => s.to_lowercase(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    /// Hour of day (00-23).
+    Hour24,
+    /// Hour of day (01-12).
+    Hour12,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+    /// Meridiem indicator, `AM` or `PM`.
+    Meridiem(Case),
+    Year,
+    /// Last 2 digits of year.
+    Year2,
+    IsoYear,
+    IsoWeek,
+    /// Full month name, blank-padded to 9 chars.
+    MonthName(Case),
+    /// Abbreviated month name.
+    MonthAbbr(Case),
+    Month,
+    /// Full day name, blank-padded to 9 chars.
+    DayName(Case),
+    /// Abbreviated day name.
+    DayAbbr(Case),
+    DayOfYear,
+    DayOfMonth,
+    /// Day of the week, Sunday (1) to Saturday (7).
+    DayOfWeek,
+    /// ISO 8601 day of the week, Monday (1) to Sunday (7).
+    IsoDayOfWeek,
+    Quarter,
+}
+
+/// The template patterns, in the order of matching so that longer ones come first.
+const PATTERNS: &[(&str, Field)] = &[
+    ("HH24", Field::Hour24),
+    ("HH12", Field::Hour12),
+    ("HH", Field::Hour12),
+    ("MI", Field::Minute),
+    ("SS", Field::Second),
+    ("MS", Field::Millisecond),
+    ("US", Field::Microsecond),
+    ("AM", Field::Meridiem(Case::Upper)),
+    ("PM", Field::Meridiem(Case::Upper)),
+    ("am", Field::Meridiem(Case::Lower)),
+    ("pm", Field::Meridiem(Case::Lower)),
+    ("YYYY", Field::Year),
+    ("YY", Field::Year2),
+    ("IYYY", Field::IsoYear),
+    ("IW", Field::IsoWeek),
+    ("ID", Field::IsoDayOfWeek),
+    ("MONTH", Field::MonthName(Case::Upper)),
+    ("Month", Field::MonthName(Case::Capitalized)),
+    ("month", Field::MonthName(Case::Lower)),
+    ("MON", Field::MonthAbbr(Case::Upper)),
+    ("Mon", Field::MonthAbbr(Case::Capitalized)),
+    ("mon", Field::MonthAbbr(Case::Lower)),
+    ("MM", Field::Month),
+    ("DAY", Field::DayName(Case::Upper)),
+    ("Day", Field::DayName(Case::Capitalized)),
+    ("day", Field::DayName(Case::Lower)),
+    ("DY", Field::DayAbbr(Case::Upper)),
+    ("Dy", Field::DayAbbr(Case::Capitalized)),
+    ("dy", Field::DayAbbr(Case::Lower)),
+    ("DDD", Field::DayOfYear),
+    ("DD", Field::DayOfMonth),
+    ("D", Field::DayOfWeek),
+    ("Q", Field::Quarter),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    Literal(char),
+    /// A field with whether it's prefixed by `FM` (fill mode), which suppresses the padding.
+    Field(Field, bool),
+}
+
+/// Splits a template into fields and literal characters. Text in double quotes is always literal.
+fn parse_template(template: &str) -> Vec<Item> {
+    let mut items = vec![];
+    let mut rest = template;
+    while !rest.is_empty() {
+        if let Some(quoted) = rest.strip_prefix('"') {
+            let end = quoted.find('"').unwrap_or(quoted.len());
+            items.extend(quoted[..end].chars().map(Item::Literal));
+            rest = quoted.get(end + 1..).unwrap_or_default();
+            continue;
+        }
+        let (fill_mode, unprefixed) = match rest.strip_prefix("FM") {
+            Some(unprefixed) => (true, unprefixed),
+            None => (false, rest),
+        };
+        if let Some((pattern, field)) = PATTERNS.iter().find(|(p, _)| unprefixed.starts_with(p)) {
+            items.push(Item::Field(*field, fill_mode));
+            rest = &unprefixed[pattern.len()..];
+        } else {
+            let c = rest.chars().next().unwrap();
+            items.push(Item::Literal(c));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    items
+}
+
+/// `to_char(timestamp, template)`.
+#[inline(always)]
+pub fn to_char_timestamp(data: NaiveDateTimeWrapper, template: &str) -> Result<String> {
+    let dt = data.0;
+    let mut result = String::new();
+    for item in parse_template(template) {
+        let (field, fill_mode) = match item {
+            Item::Literal(c) => {
+                result.push(c);
+                continue;
+            }
+            Item::Field(field, fill_mode) => (field, fill_mode),
+        };
+        let number = |n: i64, width: usize| match fill_mode {
+            true => n.to_string(),
+            false => format!("{:0width$}", n, width = width),
+        };
+        let name = |s: &str, case: Case| match fill_mode {
+            true => case.apply(s),
+            false => format!("{:9}", case.apply(s)),
+        };
+        let hour12 = match dt.hour() % 12 {
+            0 => 12,
+            h => h,
+        };
+        let month_name = MONTH_NAMES[dt.month0() as usize];
+        let day_name = DAY_NAMES[dt.weekday().num_days_from_sunday() as usize];
+        let s = match field {
+            Field::Hour24 => number(dt.hour() as i64, 2),
+            Field::Hour12 => number(hour12 as i64, 2),
+            Field::Minute => number(dt.minute() as i64, 2),
+            Field::Second => number(dt.second() as i64, 2),
+            Field::Millisecond => number(dt.nanosecond() as i64 / 1_000_000, 3),
+            Field::Microsecond => number(dt.nanosecond() as i64 / 1_000, 6),
+            Field::Meridiem(case) => case.apply(if dt.hour() < 12 { "AM" } else { "PM" }),
+            Field::Year => number(dt.year() as i64, 4),
+            Field::Year2 => number(dt.year().rem_euclid(100) as i64, 2),
+            Field::IsoYear => number(dt.iso_week().year() as i64, 4),
+            Field::IsoWeek => number(dt.iso_week().week() as i64, 2),
+            Field::MonthName(case) => name(month_name, case),
+            Field::MonthAbbr(case) => case.apply(&month_name[..3]),
+            Field::Month => number(dt.month() as i64, 2),
+            Field::DayName(case) => name(day_name, case),
+            Field::DayAbbr(case) => case.apply(&day_name[..3]),
+            Field::DayOfYear => number(dt.ordinal() as i64, 3),
+            Field::DayOfMonth => number(dt.day() as i64, 2),
+            Field::DayOfWeek => (dt.weekday().num_days_from_sunday() + 1).to_string(),
+            Field::IsoDayOfWeek => dt.weekday().number_from_monday().to_string(),
+            Field::Quarter => (dt.month0() / 3 + 1).to_string(),
+        };
+        result.push_str(&s);
+    }
+    Ok(result)
+}
+
+fn invalid_value(s: &str, template: &str) -> RwError {
+    InvalidInputSyntax(format!(
+        "invalid value \"{}\" for template \"{}\"",
+        s, template
+    ))
+    .into()
+}
+
+/// Consumes up to `max_width` digits, with an optional leading minus sign, from the front of `s`.
+fn take_number(s: &mut &str, max_width: usize) -> Option<i64> {
+    let negative = s.starts_with('-');
+    let start = negative as usize;
+    let len = s[start..]
+        .chars()
+        .take(max_width)
+        .take_while(|c| c.is_ascii_digit())
+        .count();
+    if len == 0 {
+        return None;
+    }
+    let n: i64 = s[start..start + len].parse().ok()?;
+    *s = &s[start + len..];
+    Some(if negative { -n } else { n })
+}
+
+/// Consumes one of `names` or its 3-letter abbreviation, case-insensitively, from the front of
+/// `s`. Returns the index of the name.
+fn take_name(s: &mut &str, names: &[&str], abbreviated: bool) -> Option<usize> {
+    names.iter().position(|name| {
+        let name = if abbreviated { &name[..3] } else { name };
+        match s.get(..name.len()) {
+            Some(prefix) if prefix.eq_ignore_ascii_case(name) => {
+                *s = &s[name.len()..];
+                true
+            }
+            _ => false,
+        }
+    })
+}
+
+/// `to_timestamp(string, template)`. Fields absent from the template default to the beginning of
+/// year 1, and the day of the week is ignored as in Postgres.
+#[inline(always)]
+pub fn to_timestamp(s: &str, template: &str) -> Result<NaiveDateTimeWrapper> {
+    let err = || invalid_value(s, template);
+    let mut rest = s;
+    let (mut year, mut month, mut day, mut day_of_year) = (None, None, None, None);
+    let (mut hour, mut minute, mut second, mut micros, mut pm) = (0, 0, 0, 0, None);
+    for item in parse_template(template) {
+        let field = match item {
+            Item::Literal(c) => {
+                // Whitespaces in the template match any number of them, and other literal
+                // characters skip one character in the input.
+                if c.is_whitespace() {
+                    rest = rest.trim_start();
+                } else if let Some(next) = rest.chars().next() {
+                    rest = &rest[next.len_utf8()..];
+                }
+                continue;
+            }
+            Item::Field(field, _) => field,
+        };
+        rest = rest.trim_start();
+        match field {
+            Field::Hour24 => hour = take_number(&mut rest, 2).ok_or_else(err)?,
+            Field::Hour12 => {
+                hour = take_number(&mut rest, 2).ok_or_else(err)?;
+                if !(1..=12).contains(&hour) {
+                    return Err(err());
+                }
+            }
+            Field::Minute => minute = take_number(&mut rest, 2).ok_or_else(err)?,
+            Field::Second => second = take_number(&mut rest, 2).ok_or_else(err)?,
+            // Fractions of a second are scaled by the number of digits, e.g. `5` means 500 ms.
+            Field::Millisecond | Field::Microsecond => {
+                let len = rest.len();
+                let n = take_number(&mut rest, if field == Field::Millisecond { 3 } else { 6 })
+                    .ok_or_else(err)?;
+                micros = n * 10_i64.pow(6 - (len - rest.len()) as u32);
+            }
+            Field::Meridiem(_) => {
+                pm = Some(
+                    match rest.get(..2).map(|m| m.to_ascii_uppercase()).as_deref() {
+                        Some("AM") => false,
+                        Some("PM") => true,
+                        _ => return Err(err()),
+                    },
+                );
+                rest = &rest[2..];
+            }
+            Field::Year => year = Some(take_number(&mut rest, 4).ok_or_else(err)?),
+            // Years of 2 digits are adjusted to the nearest one to 2020.
+            Field::Year2 => {
+                let n = take_number(&mut rest, 2).ok_or_else(err)?;
+                year = Some(if n < 70 { 2000 + n } else { 1900 + n });
+            }
+            Field::MonthName(_) | Field::MonthAbbr(_) => {
+                let abbreviated = matches!(field, Field::MonthAbbr(_));
+                let idx = take_name(&mut rest, &MONTH_NAMES, abbreviated).ok_or_else(err)?;
+                month = Some(idx as i64 + 1);
+            }
+            Field::Month => month = Some(take_number(&mut rest, 2).ok_or_else(err)?),
+            Field::DayName(_) | Field::DayAbbr(_) => {
+                let abbreviated = matches!(field, Field::DayAbbr(_));
+                take_name(&mut rest, &DAY_NAMES, abbreviated).ok_or_else(err)?;
+            }
+            Field::DayOfYear => day_of_year = Some(take_number(&mut rest, 3).ok_or_else(err)?),
+            Field::DayOfMonth => day = Some(take_number(&mut rest, 2).ok_or_else(err)?),
+            Field::DayOfWeek | Field::IsoDayOfWeek | Field::Quarter => {
+                take_number(&mut rest, 1).ok_or_else(err)?;
+            }
+            Field::IsoYear | Field::IsoWeek => {
+                return Err(InvalidInputSyntax(
+                    "ISO week date is not supported in to_timestamp".to_string(),
+                )
+                .into())
+            }
+        }
+    }
+    if !rest.trim().is_empty() {
+        return Err(err());
+    }
+
+    let year = year.unwrap_or(1) as i32;
+    let date = match (day_of_year, month, day) {
+        (Some(ordinal), None, None) => NaiveDate::from_yo_opt(year, ordinal as u32),
+        (None, month, day) => {
+            NaiveDate::from_ymd_opt(year, month.unwrap_or(1) as u32, day.unwrap_or(1) as u32)
+        }
+        _ => None,
+    }
+    .ok_or_else(err)?;
+    let hour = match pm {
+        Some(pm) => hour % 12 + if pm { 12 } else { 0 },
+        None => hour,
+    };
+    let time =
+        NaiveTime::from_hms_micro_opt(hour as u32, minute as u32, second as u32, micros as u32)
+            .ok_or_else(err)?;
+    Ok(NaiveDateTimeWrapper::new(NaiveDateTime::new(date, time)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector_op::cast::str_to_timestamp;
+
+    fn timestamp(s: &str) -> NaiveDateTimeWrapper {
+        NaiveDateTimeWrapper::new(NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f").unwrap())
+    }
+
+    #[test]
+    fn test_to_char() {
+        let ts = timestamp("2022-05-08 15:04:05.123456");
+        let cases = [
+            ("YYYY-MM-DD HH24:MI:SS", "2022-05-08 15:04:05"),
+            ("YY-FMMM-FMDD HH12:MI:SS.MS AM", "22-5-8 03:04:05.123 PM"),
+            ("SS.US pm", "05.123456 pm"),
+            ("Day, DD Month YYYY", "Sunday   , 08 May       2022"),
+            ("FMDay, FMDD FMMonth", "Sunday, 8 May"),
+            ("DY MON dy mon", "SUN MAY sun may"),
+            ("DDD D ID Q IYYY-IW", "128 1 7 2 2022-18"),
+            ("\"Quarter\" Q", "Quarter 2"),
+            ("100% YYYY", "100% 2022"),
+        ];
+        for (template, expected) in cases {
+            assert_eq!(to_char_timestamp(ts, template).unwrap(), expected);
+        }
+        let midnight = str_to_timestamp("2022-05-08 00:30:00").unwrap();
+        assert_eq!(to_char_timestamp(midnight, "HH12 AM").unwrap(), "12 AM");
+    }
+
+    #[test]
+    fn test_to_timestamp() {
+        let cases = [
+            (
+                "2022-05-08 15:04:05",
+                "YYYY-MM-DD HH24:MI:SS",
+                "2022-05-08 15:04:05",
+            ),
+            ("08 May 2022", "DD Month YYYY", "2022-05-08 00:00:00"),
+            ("sun, 8 may 22", "Dy, DD Mon YY", "2022-05-08 00:00:00"),
+            ("03:04:05.5 PM", "HH12:MI:SS.MS AM", "0001-01-01 15:04:05.5"),
+            ("12:00 am", "HH:MI am", "0001-01-01 00:00:00"),
+            ("2022 128", "YYYY DDD", "2022-05-08 00:00:00"),
+            ("20220508", "YYYYMMDD", "2022-05-08 00:00:00"),
+        ];
+        for (s, template, expected) in cases {
+            assert_eq!(
+                to_timestamp(s, template).unwrap(),
+                timestamp(expected),
+                "{}",
+                s
+            );
+        }
+        assert!(to_timestamp("2022-13-01", "YYYY-MM-DD").is_err());
+        assert!(to_timestamp("2022-05-08 junk", "YYYY-MM-DD").is_err());
+        assert!(to_timestamp("May", "DD").is_err());
+        assert!(to_timestamp("2022-18", "IYYY-IW").is_err());
+    }
+}
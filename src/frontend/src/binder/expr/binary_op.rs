@@ -13,10 +13,11 @@
 // limitations under the License.
 
 use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
 use risingwave_sqlparser::ast::{BinaryOperator, Expr};
 
 use crate::binder::Binder;
-use crate::expr::{ExprImpl, ExprType, FunctionCall};
+use crate::expr::{Expr as _, ExprImpl, ExprType, FunctionCall};
 
 impl Binder {
     pub(super) fn bind_binary_op(
@@ -25,6 +26,22 @@ impl Binder {
         op: BinaryOperator,
         right: Expr,
     ) -> Result<FunctionCall> {
+        let right = match (op.clone(), right) {
+            (BinaryOperator::Eq, Expr::AnyOp(array)) => {
+                return self.bind_any_equal(left, *array, false)
+            }
+            (BinaryOperator::NotEq, Expr::AllOp(array)) => {
+                return self.bind_any_equal(left, *array, true)
+            }
+            (op, Expr::AnyOp(_) | Expr::AllOp(_)) => {
+                return Err(ErrorCode::NotImplemented(
+                    format!("{:?} with ANY or ALL", op),
+                    112.into(),
+                )
+                .into())
+            }
+            (_, right) => right,
+        };
         let bound_left = self.bind_expr(left)?;
         let bound_right = self.bind_expr(right)?;
         let func_type = match op {
@@ -43,6 +60,26 @@ impl Binder {
             BinaryOperator::Or => ExprType::Or,
             BinaryOperator::Like => ExprType::Like,
             BinaryOperator::NotLike => return self.bind_not_like(bound_left, bound_right),
+            BinaryOperator::PGRegexMatch => ExprType::RegexpEq,
+            BinaryOperator::PGRegexIMatch => {
+                return self.bind_regexp_eq(bound_left, bound_right, false, true)
+            }
+            BinaryOperator::PGRegexNotMatch => {
+                return self.bind_regexp_eq(bound_left, bound_right, true, false)
+            }
+            BinaryOperator::PGRegexNotIMatch => {
+                return self.bind_regexp_eq(bound_left, bound_right, true, true)
+            }
+            BinaryOperator::Arrow => ExprType::JsonbAccessInner,
+            BinaryOperator::LongArrow => ExprType::JsonbAccessStr,
+            BinaryOperator::HashArrow => ExprType::JsonbAccessPath,
+            BinaryOperator::StringConcat => return self.bind_concat_op(bound_left, bound_right),
+            BinaryOperator::AtArrow => {
+                return match bound_left.return_type() {
+                    DataType::List { .. } => self.bind_array_contains(bound_left, bound_right),
+                    _ => self.bind_jsonb_contains(bound_left, bound_right),
+                }
+            }
             _ => return Err(ErrorCode::NotImplemented(format!("{:?}", op), 112.into()).into()),
         };
         FunctionCall::new(func_type, vec![bound_left, bound_right])
@@ -56,4 +93,90 @@ impl Binder {
         )
         .unwrap())
     }
+
+    /// Bind `~*`, `!~` and `!~*`. The case-insensitive ones pass the `i` flag to the backend.
+    fn bind_regexp_eq(
+        &mut self,
+        left: ExprImpl,
+        right: ExprImpl,
+        negated: bool,
+        case_insensitive: bool,
+    ) -> Result<FunctionCall> {
+        let mut inputs = vec![left, right];
+        if case_insensitive {
+            inputs.push(ExprImpl::literal_varchar("i".to_string()));
+        }
+        let func_call = FunctionCall::new(ExprType::RegexpEq, inputs)?;
+        if negated {
+            FunctionCall::new(ExprType::Not, vec![func_call.into()])
+        } else {
+            Ok(func_call)
+        }
+    }
+
+    /// Both sides of `@>` are jsonb. As string literals are bound to varchar, they are cast to
+    /// jsonb here, e.g. `v @> '{"a": 1}'`.
+    fn bind_jsonb_contains(&mut self, left: ExprImpl, right: ExprImpl) -> Result<FunctionCall> {
+        FunctionCall::new(
+            ExprType::JsonbContains,
+            vec![
+                left.cast_assign(DataType::Jsonb)?,
+                right.cast_assign(DataType::Jsonb)?,
+            ],
+        )
+    }
+
+    /// Bind `@>` over lists. A string literal on the right is cast to the list type of the left,
+    /// e.g. `v @> '{1,2}'`.
+    fn bind_array_contains(&mut self, left: ExprImpl, right: ExprImpl) -> Result<FunctionCall> {
+        let right = match right.return_type() {
+            DataType::Varchar => right.cast_assign(left.return_type())?,
+            _ => right,
+        };
+        FunctionCall::new(ExprType::ArrayContains, vec![left, right])
+    }
+
+    /// Bind `||`. It concatenates lists or appends an element to a list if the left side is a list,
+    /// and concatenates strings otherwise, where non-string values are cast to varchar, e.g.
+    /// `'a' || 1`.
+    fn bind_concat_op(&mut self, left: ExprImpl, right: ExprImpl) -> Result<FunctionCall> {
+        match (left.return_type(), right.return_type()) {
+            (DataType::List { .. }, DataType::List { .. }) => {
+                FunctionCall::new(ExprType::ArrayCat, vec![left, right])
+            }
+            (DataType::List { .. }, _) => {
+                FunctionCall::new(ExprType::ArrayAppend, vec![left, right])
+            }
+            (_, DataType::List { .. }) => Err(ErrorCode::NotImplemented(
+                "prepending an element to a list".to_string(),
+                112.into(),
+            )
+            .into()),
+            _ => FunctionCall::new(
+                ExprType::ConcatOp,
+                vec![
+                    left.cast_assign(DataType::Varchar)?,
+                    right.cast_assign(DataType::Varchar)?,
+                ],
+            ),
+        }
+    }
+
+    /// Bind `a = ANY(array)`, and `a <> ALL(array)` as its negation. A string literal is cast to
+    /// the list type of the element, e.g. `a = ANY('{1,2}')`.
+    fn bind_any_equal(&mut self, left: Expr, array: Expr, negated: bool) -> Result<FunctionCall> {
+        let left = self.bind_expr(left)?;
+        let mut array = self.bind_expr(array)?;
+        if array.return_type() == DataType::Varchar && !array.is_null() {
+            array = array.cast_assign(DataType::List {
+                datatype: Box::new(left.return_type()),
+            })?;
+        }
+        let func_call = FunctionCall::new(ExprType::ArrayAnyEqual, vec![left, array])?;
+        if negated {
+            FunctionCall::new(ExprType::Not, vec![func_call.into()])
+        } else {
+            Ok(func_call)
+        }
+    }
 }
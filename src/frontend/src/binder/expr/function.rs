@@ -12,18 +12,27 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::str::FromStr;
+use std::sync::Arc;
+
 use itertools::Itertools;
+use risingwave_common::catalog::DEFAULT_SCHEMA_NAME;
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_expr::expr::AggKind;
 use risingwave_sqlparser::ast::{Function, FunctionArg, FunctionArgExpr};
 
 use crate::binder::bind_context::Clause;
 use crate::binder::Binder;
-use crate::expr::{AggCall, Expr, ExprImpl, ExprType, FunctionCall, Literal};
+use crate::catalog::function_catalog::FunctionCatalog;
+use crate::expr::{
+    AggCall, Expr, ExprImpl, ExprType, FunctionCall, Literal, TableFunction, TableFunctionType,
+    UserDefinedFunction,
+};
 
 impl Binder {
     pub(super) fn bind_function(&mut self, f: Function) -> Result<ExprImpl> {
+        let distinct = f.distinct;
         let mut inputs = f
             .args
             .into_iter()
@@ -40,11 +49,26 @@ impl Binder {
                 "min" => Some(AggKind::Min),
                 "max" => Some(AggKind::Max),
                 "avg" => Some(AggKind::Avg),
+                "approx_count_distinct" => Some(AggKind::ApproxCountDistinct),
+                "bool_and" => Some(AggKind::BoolAnd),
+                "bool_or" => Some(AggKind::BoolOr),
+                "array_agg" => Some(AggKind::ArrayAgg),
+                "string_agg" => Some(AggKind::StringAgg),
+                "stddev_pop" => Some(AggKind::StddevPop),
+                "stddev_samp" | "stddev" => Some(AggKind::StddevSamp),
+                "var_pop" => Some(AggKind::VarPop),
+                "var_samp" | "variance" => Some(AggKind::VarSamp),
                 _ => None,
             };
             if let Some(kind) = agg_kind {
                 self.ensure_aggregate_allowed()?;
-                return Ok(ExprImpl::AggCall(Box::new(AggCall::new(kind, inputs)?)));
+                return Ok(ExprImpl::AggCall(Box::new(AggCall::new(
+                    kind, inputs, distinct,
+                )?)));
+            }
+            if let Ok(function_type) = TableFunctionType::from_str(function_name.as_str()) {
+                self.ensure_table_function_allowed()?;
+                return Ok(TableFunction::new(function_type, inputs)?.into());
             }
             let function_type = match function_name.as_str() {
                 "substr" => ExprType::Substr,
@@ -56,20 +80,83 @@ impl Binder {
                 "position" => ExprType::Position,
                 "ltrim" => ExprType::Ltrim,
                 "rtrim" => ExprType::Rtrim,
+                "jsonb_typeof" => ExprType::JsonbTypeof,
+                "encode" => ExprType::Encode,
+                "decode" => ExprType::Decode,
+                "regexp_match" => ExprType::RegexpMatch,
+                "regexp_replace" => ExprType::RegexpReplace,
+                "array_length" => ExprType::ArrayLength,
+                "array_append" => ExprType::ArrayAppend,
+                "array_cat" => ExprType::ArrayCat,
+                "concat" => {
+                    inputs.insert(0, ExprImpl::literal_varchar(String::new()));
+                    inputs = Self::cast_args_to_varchar(inputs)?;
+                    ExprType::ConcatWs
+                }
+                "concat_ws" => {
+                    inputs = Self::cast_args_to_varchar(inputs)?;
+                    ExprType::ConcatWs
+                }
+                "split_part" => ExprType::SplitPart,
+                "left" => ExprType::Left,
+                "right" => ExprType::Right,
+                "lpad" | "rpad" => {
+                    // The string is padded with spaces by default.
+                    if inputs.len() == 2 {
+                        inputs.push(ExprImpl::literal_varchar(" ".to_string()));
+                    }
+                    if function_name == "lpad" {
+                        ExprType::Lpad
+                    } else {
+                        ExprType::Rpad
+                    }
+                }
+                "repeat" => ExprType::Repeat,
+                "reverse" => ExprType::Reverse,
+                "starts_with" => ExprType::StartsWith,
+                "md5" => ExprType::Md5,
+                "initcap" => ExprType::Initcap,
+                "string_to_array" => ExprType::StringToArray,
+                "coalesce" => ExprType::Coalesce,
+                "nullif" => ExprType::Nullif,
+                "greatest" => ExprType::Greatest,
+                "least" => ExprType::Least,
+                "abs" => ExprType::Abs,
+                "ceil" | "ceiling" => ExprType::Ceil,
+                "floor" => ExprType::Floor,
+                "power" | "pow" => ExprType::Power,
+                "sqrt" => ExprType::Sqrt,
+                "ln" => ExprType::Ln,
                 "round" => {
                     inputs = Self::rewrite_round_args(inputs);
                     ExprType::RoundDigit
                 }
+                "date_trunc" | "date_part" | "to_char" | "to_timestamp" | "now"
+                | "current_timestamp" | "current_date" | "localtimestamp" => {
+                    return self.bind_time_function(&function_name, inputs);
+                }
                 _ => {
+                    if let Some(function) =
+                        self.get_user_defined_function(DEFAULT_SCHEMA_NAME, &f.name.0[0].value)
+                    {
+                        return Ok(UserDefinedFunction::new(function, inputs)?.into());
+                    }
                     return Err(ErrorCode::NotImplemented(
                         format!("unsupported function: {:?}", function_name),
                         112.into(),
                     )
-                    .into())
+                    .into());
                 }
             };
             Ok(FunctionCall::new(function_type, inputs)?.into())
         } else {
+            if let [schema_name, function_name] = f.name.0.as_slice() {
+                if let Some(function) =
+                    self.get_user_defined_function(&schema_name.value, &function_name.value)
+                {
+                    return Ok(UserDefinedFunction::new(function, inputs)?.into());
+                }
+            }
             Err(ErrorCode::NotImplemented(
                 format!("unsupported function: {:?}", f.name),
                 112.into(),
@@ -108,6 +195,88 @@ impl Binder {
         }
     }
 
+    /// `concat` and `concat_ws` take arguments of any type, which are cast to varchar.
+    fn cast_args_to_varchar(inputs: Vec<ExprImpl>) -> Result<Vec<ExprImpl>> {
+        inputs
+            .into_iter()
+            .map(|input| input.cast_assign(DataType::Varchar))
+            .collect()
+    }
+
+    /// Binds the date/time functions, converting `timestamp with time zone` from and to the local
+    /// time of the session where the function works on `timestamp`.
+    fn bind_time_function(&mut self, name: &str, inputs: Vec<ExprImpl>) -> Result<ExprImpl> {
+        let is_timestampz = |inputs: &[ExprImpl], i: usize| {
+            inputs.get(i).map(|e| e.return_type()) == Some(DataType::Timestampz)
+        };
+        match name {
+            "date_trunc" if is_timestampz(&inputs, 1) => {
+                let mut inputs = inputs;
+                let input = self.at_session_time_zone(inputs.pop().unwrap())?;
+                inputs.push(input);
+                let truncated = FunctionCall::new(ExprType::DateTrunc, inputs)?.into();
+                self.at_session_time_zone(truncated)
+            }
+            "date_trunc" => Ok(FunctionCall::new(ExprType::DateTrunc, inputs)?.into()),
+            "date_part" => {
+                let mut inputs = inputs.into_iter();
+                let (field, arg) = match (inputs.next(), inputs.next(), inputs.next()) {
+                    (Some(ExprImpl::Literal(field)), Some(arg), None)
+                        if field.return_type() == DataType::Varchar =>
+                    {
+                        (field, arg)
+                    }
+                    _ => {
+                        return Err(ErrorCode::BindError(
+                            "date_part expects a constant field and a date/time value".to_string(),
+                        )
+                        .into())
+                    }
+                };
+                let field = match field.get_data() {
+                    Some(ScalarImpl::Utf8(field)) => field.clone(),
+                    _ => return Ok(Literal::new(None, DataType::Decimal).into()),
+                };
+                self.bind_extract_field(field, arg)
+            }
+            "to_char" if is_timestampz(&inputs, 0) => {
+                let mut inputs = inputs;
+                inputs[0] = self.at_session_time_zone(inputs[0].clone())?;
+                Ok(FunctionCall::new(ExprType::ToChar, inputs)?.into())
+            }
+            "to_char" => Ok(FunctionCall::new(ExprType::ToChar, inputs)?.into()),
+            // The parsed local time is in the session time zone.
+            "to_timestamp" => {
+                let local = FunctionCall::new(ExprType::ToTimestamp, inputs)?.into();
+                self.at_session_time_zone(local)
+            }
+            "now" | "current_timestamp" => Ok(FunctionCall::new(ExprType::Now, inputs)?.into()),
+            "localtimestamp" => {
+                let now = FunctionCall::new(ExprType::Now, inputs)?.into();
+                self.at_session_time_zone(now)
+            }
+            "current_date" => {
+                let now = FunctionCall::new(ExprType::Now, inputs)?.into();
+                self.at_session_time_zone(now)?
+                    .cast_explicit(DataType::Date)
+            }
+            _ => unreachable!("not a date/time function: {}", name),
+        }
+    }
+
+    /// Looks up a user-defined function. Unlike the built-in functions, the name is case-sensitive,
+    /// as it's also the name of the export in the module.
+    fn get_user_defined_function(
+        &self,
+        schema_name: &str,
+        function_name: &str,
+    ) -> Option<Arc<FunctionCatalog>> {
+        self.catalog
+            .get_function_by_name(&self.db_name, schema_name, function_name)
+            .ok()
+            .map(|function| Arc::new(function.clone()))
+    }
+
     fn ensure_aggregate_allowed(&self) -> Result<()> {
         if let Some(clause) = self.context.clause {
             if clause == Clause::Values || clause == Clause::Where {
@@ -121,6 +290,19 @@ impl Binder {
         Ok(())
     }
 
+    fn ensure_table_function_allowed(&self) -> Result<()> {
+        if let Some(clause) = self.context.clause {
+            if clause == Clause::Values || clause == Clause::Where {
+                return Err(ErrorCode::InvalidInputSyntax(format!(
+                    "table functions are not allowed in {}",
+                    clause
+                ))
+                .into());
+            }
+        }
+        Ok(())
+    }
+
     pub(in crate::binder) fn bind_function_expr_arg(
         &mut self,
         arg_expr: FunctionArgExpr,
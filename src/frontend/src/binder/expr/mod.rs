@@ -63,6 +63,15 @@ impl Binder {
             Expr::Trim { expr, trim_where } => Ok(ExprImpl::FunctionCall(Box::new(
                 self.bind_trim(*expr, trim_where)?,
             ))),
+            Expr::Substring {
+                expr,
+                substring_from,
+                substring_for,
+            } => Ok(ExprImpl::FunctionCall(Box::new(self.bind_substring(
+                *expr,
+                substring_from,
+                substring_for,
+            )?))),
             Expr::Identifier(ident) => self.bind_column(&[ident]),
             Expr::CompoundIdentifier(idents) => self.bind_column(&idents),
             Expr::FieldIdentifier(field_expr, idents) => {
@@ -90,12 +99,32 @@ impl Binder {
             } => Ok(ExprImpl::FunctionCall(Box::new(
                 self.bind_between(*expr, negated, *low, *high)?,
             ))),
+            Expr::SimilarTo {
+                expr,
+                negated,
+                pattern,
+                escape_char,
+            } => Ok(ExprImpl::FunctionCall(Box::new(self.bind_similar_to(
+                *expr,
+                negated,
+                *pattern,
+                escape_char,
+            )?))),
             Expr::Extract { field, expr } => self.bind_extract(field, *expr),
+            Expr::AtTimeZone {
+                timestamp,
+                time_zone,
+            } => self.bind_at_time_zone(*timestamp, time_zone),
             Expr::InList {
                 expr,
                 list,
                 negated,
             } => self.bind_in_list(*expr, list, negated),
+            Expr::Array(exprs) => self.bind_array(exprs),
+            Expr::ArrayIndex { obj, index } => self.bind_array_index(*obj, *index),
+            Expr::ArrayRangeIndex { obj, start, end } => {
+                self.bind_array_range_index(*obj, start, end)
+            }
             _ => Err(ErrorCode::NotImplemented(
                 format!("unsupported expression {:?}", expr),
                 112.into(),
@@ -107,22 +136,58 @@ impl Binder {
     pub(super) fn bind_extract(&mut self, field: DateTimeField, expr: Expr) -> Result<ExprImpl> {
         let arg = self.bind_expr(expr)?;
         let arg_type = arg.return_type();
+        self.bind_extract_field(field.to_string(), arg)
+            .map_err(|_| {
+                ErrorCode::NotImplemented(
+                    format!(
+                        "function extract({} from {:?}) doesn't exist",
+                        field, arg_type
+                    ),
+                    112.into(),
+                )
+                .into()
+            })
+    }
+
+    /// Binds `EXTRACT(field FROM arg)`, which is also `date_part(field, arg)`. A `timestamp with
+    /// time zone` is converted to the local time of the session, except for `EPOCH` which is the
+    /// same in all time zones.
+    pub(super) fn bind_extract_field(&mut self, field: String, arg: ExprImpl) -> Result<ExprImpl> {
+        let arg = match arg.return_type() {
+            DataType::Timestampz if field.eq_ignore_ascii_case("epoch") => {
+                let utc = self.bind_string("UTC".to_string())?;
+                FunctionCall::new(ExprType::AtTimeZone, vec![arg, utc.into()])?.into()
+            }
+            DataType::Timestampz => self.at_session_time_zone(arg)?,
+            _ => arg,
+        };
         Ok(FunctionCall::new(
             ExprType::Extract,
-            vec![self.bind_string(field.to_string())?.into(), arg],
-        )
-        .map_err(|_| {
-            ErrorCode::NotImplemented(
-                format!(
-                    "function extract({} from {:?}) doesn't exist",
-                    field, arg_type
-                ),
-                112.into(),
-            )
-        })?
+            vec![self.bind_string(field)?.into(), arg],
+        )?
+        .into())
+    }
+
+    pub(super) fn bind_at_time_zone(&mut self, input: Expr, time_zone: String) -> Result<ExprImpl> {
+        let input = self.bind_expr(input)?;
+        // `date AT TIME ZONE zone` is the midnight of the date in the time zone.
+        let input = match input.return_type() {
+            DataType::Date => input.cast_implicit(DataType::Timestamp)?,
+            _ => input,
+        };
+        Ok(FunctionCall::new(
+            ExprType::AtTimeZone,
+            vec![input, self.bind_string(time_zone)?.into()],
+        )?
         .into())
     }
 
+    /// Converts between a `timestamp with time zone` and the local `timestamp` of the session.
+    pub(super) fn at_session_time_zone(&mut self, input: ExprImpl) -> Result<ExprImpl> {
+        let time_zone = self.bind_string(self.session_timezone.clone())?;
+        Ok(FunctionCall::new(ExprType::AtTimeZone, vec![input, time_zone.into()])?.into())
+    }
+
     pub(super) fn bind_in_list(
         &mut self,
         expr: Expr,
@@ -144,6 +209,38 @@ impl Binder {
         }
     }
 
+    pub(super) fn bind_array(&mut self, exprs: Vec<Expr>) -> Result<ExprImpl> {
+        let elements = exprs
+            .into_iter()
+            .map(|e| self.bind_expr(e))
+            .collect::<Result<_>>()?;
+        Ok(FunctionCall::new(ExprType::Array, elements)?.into())
+    }
+
+    pub(super) fn bind_array_index(&mut self, obj: Expr, index: Expr) -> Result<ExprImpl> {
+        let inputs = vec![self.bind_expr(obj)?, self.bind_expr(index)?];
+        Ok(FunctionCall::new(ExprType::ArrayAccess, inputs)?.into())
+    }
+
+    /// An omitted bound of the slice covers the list to the end, e.g. `arr[2:]`.
+    pub(super) fn bind_array_range_index(
+        &mut self,
+        obj: Expr,
+        start: Option<Box<Expr>>,
+        end: Option<Box<Expr>>,
+    ) -> Result<ExprImpl> {
+        let obj = self.bind_expr(obj)?;
+        let start = match start {
+            Some(start) => self.bind_expr(*start)?,
+            None => ExprImpl::literal_int(1),
+        };
+        let end = match end {
+            Some(end) => self.bind_expr(*end)?,
+            None => ExprImpl::literal_int(i32::MAX),
+        };
+        Ok(FunctionCall::new(ExprType::ArrayRangeAccess, vec![obj, start, end])?.into())
+    }
+
     pub(super) fn bind_unary_expr(&mut self, op: UnaryOperator, expr: Expr) -> Result<ExprImpl> {
         let func_type = match op {
             UnaryOperator::Not => ExprType::Not,
@@ -194,6 +291,26 @@ impl Binder {
         FunctionCall::new(func_type, inputs)
     }
 
+    /// Bind `substring(expr [from start] [for count])` as `substr`.
+    pub(super) fn bind_substring(
+        &mut self,
+        expr: Expr,
+        substring_from: Option<Box<Expr>>,
+        substring_for: Option<Box<Expr>>,
+    ) -> Result<FunctionCall> {
+        let mut inputs = vec![
+            self.bind_expr(expr)?,
+            match substring_from {
+                Some(from) => self.bind_expr(*from)?,
+                None => ExprImpl::literal_int(1),
+            },
+        ];
+        if let Some(count) = substring_for {
+            inputs.push(self.bind_expr(*count)?);
+        }
+        FunctionCall::new(ExprType::Substr, inputs)
+    }
+
     /// Bind `expr (not) between low and high`
     pub(super) fn bind_between(
         &mut self,
@@ -232,6 +349,26 @@ impl Binder {
         Ok(func_call)
     }
 
+    /// Bind `expr (not) similar to pattern (escape escape_char)`
+    pub(super) fn bind_similar_to(
+        &mut self,
+        expr: Expr,
+        negated: bool,
+        pattern: Expr,
+        escape_char: Option<char>,
+    ) -> Result<FunctionCall> {
+        let mut inputs = vec![self.bind_expr(expr)?, self.bind_expr(pattern)?];
+        if let Some(escape_char) = escape_char {
+            inputs.push(ExprImpl::literal_varchar(escape_char.to_string()));
+        }
+        let func_call = FunctionCall::new(ExprType::SimilarTo, inputs)?;
+        if negated {
+            FunctionCall::new(ExprType::Not, vec![func_call.into()])
+        } else {
+            Ok(func_call)
+        }
+    }
+
     pub(super) fn bind_case(
         &mut self,
         operand: Option<Box<Expr>>,
@@ -297,6 +434,8 @@ pub fn bind_data_type(data_type: &AstDataType) -> Result<DataType> {
         AstDataType::Array(datatype) => DataType::List {
             datatype: Box::new(bind_data_type(datatype)?),
         },
+        AstDataType::Jsonb => DataType::Jsonb,
+        AstDataType::Bytea => DataType::Bytea,
         AstDataType::Char(..) => {
             return Err(ErrorCode::NotImplemented(
                 "CHAR is not supported, please use VARCHAR instead\n".to_string(),
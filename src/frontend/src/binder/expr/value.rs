@@ -101,6 +101,8 @@ impl Binder {
                 let ms = seconds.checked_mul(1000)?;
                 Some(IntervalUnit::from_millis(ms))
             }
+            // Other fields are only used in `EXTRACT`.
+            _ => None,
         })()
         .ok_or_else(|| {
             RwError::from(ErrorCode::InvalidInputSyntax(format!(
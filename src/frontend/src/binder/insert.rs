@@ -78,6 +78,7 @@ impl Binder {
                 limit: None,
                 offset: None,
                 fetch: None,
+                as_of: None,
             } if order.is_empty() => {
                 let values = self.bind_values(values, Some(expected_types))?;
                 let body = BoundSetExpr::Values(values.into());
@@ -47,6 +47,8 @@ pub struct Binder {
     // TODO: maybe we can only lock the database, but not the whole catalog.
     catalog: CatalogReadGuard,
     db_name: String,
+    /// The session time zone, which `timestamp with time zone` is interpreted in.
+    session_timezone: String,
     context: BindContext,
     /// A stack holding contexts of outer queries when binding a subquery.
     ///
@@ -57,10 +59,11 @@ pub struct Binder {
 }
 
 impl Binder {
-    pub fn new(catalog: CatalogReadGuard, db_name: String) -> Binder {
+    pub fn new(catalog: CatalogReadGuard, db_name: String, session_timezone: String) -> Binder {
         Binder {
             catalog,
             db_name,
+            session_timezone,
             context: BindContext::new(),
             upper_contexts: vec![],
             next_subquery_id: 0,
@@ -103,7 +106,7 @@ pub mod test_utils {
     pub fn mock_binder_with_catalog(catalog: Catalog, db_name: String) -> Binder {
         let catalog = Arc::new(RwLock::new(catalog));
         let catalog_reader = CatalogReader::new(catalog);
-        Binder::new(catalog_reader.read_guard(), db_name)
+        Binder::new(catalog_reader.read_guard(), db_name, "UTC".to_string())
     }
     #[cfg(test)]
     pub fn mock_binder() -> Binder {
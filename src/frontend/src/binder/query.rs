@@ -64,6 +64,14 @@ impl Binder {
 
     /// Bind a [`Query`] using the current [`BindContext`](super::BindContext).
     pub(super) fn bind_query_inner(&mut self, query: Query) -> Result<BoundQuery> {
+        if let Some(as_of) = query.as_of {
+            // `AS OF` of a top-level query is taken by `handle_query` before binding.
+            return Err(ErrorCode::BindError(format!(
+                "\"{}\" is only allowed in a top-level SELECT",
+                as_of
+            ))
+            .into());
+        }
         let limit = query.get_limit_value();
         let offset = query.get_offset_value();
         let body = self.bind_set_expr(query.body)?;
@@ -24,6 +24,7 @@ use crate::binder::Binder;
 
 mod join;
 mod subquery;
+mod table_function;
 mod table_or_source;
 mod window_table_function;
 pub use join::BoundJoin;
@@ -32,6 +33,7 @@ pub use table_or_source::{BoundBaseTable, BoundSource, BoundTableSource};
 pub use window_table_function::{BoundWindowTableFunction, WindowTableFunctionKind};
 
 use crate::catalog::column_catalog::ColumnCatalog;
+use crate::expr::{TableFunction, TableFunctionType};
 
 /// A validated item that refers to a table-like entity, including base table, subquery, join, etc.
 /// It is usually part of the `from` clause.
@@ -42,6 +44,7 @@ pub enum Relation {
     Subquery(Box<BoundSubquery>),
     Join(Box<BoundJoin>),
     WindowTableFunction(Box<BoundWindowTableFunction>),
+    TableFunction(Box<TableFunction>),
 }
 
 impl Binder {
@@ -127,6 +130,12 @@ impl Binder {
                 if args.is_empty() {
                     let (schema_name, table_name) = Self::resolve_table_name(name)?;
                     self.bind_table_or_source(&schema_name, &table_name, alias)
+                } else if let Ok(function_type) =
+                    TableFunctionType::from_str(&name.0[0].value.to_lowercase())
+                {
+                    Ok(Relation::TableFunction(Box::new(
+                        self.bind_table_function(function_type, args, alias)?,
+                    )))
                 } else {
                     let kind =
                         WindowTableFunctionKind::from_str(&name.0[0].value).map_err(|_| {
@@ -0,0 +1,69 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use risingwave_common::catalog::{ColumnDesc, ColumnId};
+use risingwave_common::error::ErrorCode;
+use risingwave_sqlparser::ast::{FunctionArg, TableAlias};
+
+use super::{Binder, Result};
+use crate::catalog::column_catalog::ColumnCatalog;
+use crate::expr::{Expr, TableFunction, TableFunctionType};
+
+impl Binder {
+    /// Binds a table function in the `FROM` clause, e.g. `SELECT * FROM generate_series(1, 10)`.
+    /// The output column is named after the function unless an alias is given.
+    pub(super) fn bind_table_function(
+        &mut self,
+        function_type: TableFunctionType,
+        args: Vec<FunctionArg>,
+        alias: Option<TableAlias>,
+    ) -> Result<TableFunction> {
+        // Columns of other relations in the `FROM` clause are not visible to the arguments, as
+        // `LATERAL` is not supported yet.
+        self.push_context();
+        let args = args
+            .into_iter()
+            .map(|arg| self.bind_function_arg(arg))
+            .flatten_ok()
+            .try_collect();
+        self.pop_context();
+        let args: Vec<_> = args?;
+        if args.iter().any(|arg| arg.has_correlated_input_ref()) {
+            return Err(ErrorCode::NotImplemented(
+                format!(
+                    "correlated arguments of table function {}",
+                    function_type.name()
+                ),
+                None.into(),
+            )
+            .into());
+        }
+
+        let table_function = TableFunction::new(function_type, args)?;
+        let columns = [ColumnCatalog {
+            column_desc: ColumnDesc {
+                data_type: table_function.return_type(),
+                column_id: ColumnId::new(0),
+                name: table_function.name().to_string(),
+                field_descs: vec![],
+                type_name: "".to_string(),
+            },
+            is_hidden: false,
+        }];
+        self.bind_context(columns, table_function.name().to_string(), alias)?;
+
+        Ok(table_function)
+    }
+}
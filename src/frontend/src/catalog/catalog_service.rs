@@ -20,14 +20,15 @@ use risingwave_common::catalog::{CatalogVersion, TableId};
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError};
 use risingwave_pb::catalog::{
-    Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    Database as ProstDatabase, Function as ProstFunction, Schema as ProstSchema,
+    Source as ProstSource, Table as ProstTable,
 };
 use risingwave_pb::stream_plan::StreamNode;
 use risingwave_rpc_client::MetaClient;
 use tokio::sync::watch::Receiver;
 
 use super::root_catalog::Catalog;
-use super::DatabaseId;
+use super::{DatabaseId, FunctionId};
 
 pub type CatalogReadGuard = ArcRwLockReadGuard<RawRwLock, Catalog>;
 
@@ -69,6 +70,10 @@ pub trait CatalogWriter: Send + Sync {
     async fn drop_materialized_view(&self, table_id: TableId) -> Result<()>;
 
     async fn drop_source(&self, source_id: u32) -> Result<()>;
+
+    async fn create_function(&self, function: ProstFunction) -> Result<()>;
+
+    async fn drop_function(&self, function_id: FunctionId) -> Result<()>;
 }
 
 #[derive(Clone)]
@@ -146,6 +151,16 @@ impl CatalogWriter for CatalogWriterImpl {
         let version = self.meta_client.drop_source(source_id).await?;
         self.wait_version(version).await
     }
+
+    async fn create_function(&self, function: ProstFunction) -> Result<()> {
+        let (_id, version) = self.meta_client.create_function(function).await?;
+        self.wait_version(version).await
+    }
+
+    async fn drop_function(&self, function_id: FunctionId) -> Result<()> {
+        let version = self.meta_client.drop_function(function_id).await?;
+        self.wait_version(version).await
+    }
 }
 
 impl CatalogWriterImpl {
@@ -15,12 +15,15 @@
 use std::collections::HashMap;
 
 use risingwave_common::catalog::TableId;
-use risingwave_pb::catalog::{Schema as ProstSchema, Source as ProstSource, Table as ProstTable};
+use risingwave_pb::catalog::{
+    Function as ProstFunction, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+};
 use risingwave_pb::stream_plan::source_node::SourceType;
 
 use super::source_catalog::SourceCatalog;
+use crate::catalog::function_catalog::FunctionCatalog;
 use crate::catalog::table_catalog::TableCatalog;
-use crate::catalog::SchemaId;
+use crate::catalog::{FunctionId, SchemaId};
 
 pub type SourceId = u32;
 
@@ -33,6 +36,8 @@ pub struct SchemaCatalog {
     table_name_by_id: HashMap<TableId, String>,
     source_by_name: HashMap<String, SourceCatalog>,
     source_name_by_id: HashMap<SourceId, String>,
+    function_by_name: HashMap<String, FunctionCatalog>,
+    function_name_by_id: HashMap<FunctionId, String>,
 }
 
 impl SchemaCatalog {
@@ -65,6 +70,21 @@ impl SchemaCatalog {
         self.source_by_name.remove(&name).unwrap();
     }
 
+    pub fn create_function(&mut self, prost: &ProstFunction) {
+        let name = prost.name.clone();
+        let id = prost.id;
+
+        self.function_by_name
+            .try_insert(name.clone(), FunctionCatalog::from(prost))
+            .unwrap();
+        self.function_name_by_id.try_insert(id, name).unwrap();
+    }
+
+    pub fn drop_function(&mut self, id: FunctionId) {
+        let name = self.function_name_by_id.remove(&id).unwrap();
+        self.function_by_name.remove(&name).unwrap();
+    }
+
     pub fn iter_table(&self) -> impl Iterator<Item = &TableCatalog> {
         self.table_by_name
             .iter()
@@ -111,6 +131,10 @@ impl SchemaCatalog {
         self.source_by_name.get(source_name)
     }
 
+    pub fn get_function_by_name(&self, function_name: &str) -> Option<&FunctionCatalog> {
+        self.function_by_name.get(function_name)
+    }
+
     pub fn id(&self) -> SchemaId {
         self.id
     }
@@ -125,6 +149,8 @@ impl From<&ProstSchema> for SchemaCatalog {
             table_name_by_id: HashMap::new(),
             source_by_name: HashMap::new(),
             source_name_by_id: HashMap::new(),
+            function_by_name: HashMap::new(),
+            function_name_by_id: HashMap::new(),
         }
     }
 }
@@ -14,6 +14,7 @@
 use itertools::Itertools;
 use risingwave_pb::catalog::source::Info;
 use risingwave_pb::catalog::Source as ProstSource;
+use risingwave_pb::plan_common::RowFormatType;
 use risingwave_pb::stream_plan::source_node::SourceType;
 
 use super::column_catalog::ColumnCatalog;
@@ -27,6 +28,11 @@ pub struct SourceCatalog {
     pub columns: Vec<ColumnCatalog>,
     pub pk_col_ids: Vec<ColumnId>,
     pub source_type: SourceType,
+    /// The column that watermarks are generated on, if any.
+    pub watermark_column_id: Option<ColumnId>,
+    /// Whether the source only emits inserts. A table source accepts updates and deletes by DML,
+    /// and so does a connector source with change logs.
+    pub append_only: bool,
 }
 
 impl SourceCatalog {
@@ -56,23 +62,31 @@ impl From<&ProstSource> for SourceCatalog {
     fn from(prost: &ProstSource) -> Self {
         let id = prost.id;
         let name = prost.name.clone();
-        let (source_type, prost_columns, pk_col_ids) = match &prost.info {
-            Some(Info::StreamSource(source)) => (
-                SourceType::Source,
-                source.columns.clone(),
-                source
-                    .pk_column_ids
-                    .iter()
-                    .map(|id| ColumnId::new(*id))
-                    .collect(),
-            ),
-            Some(Info::TableSource(source)) => (
-                SourceType::Table,
-                source.columns.clone(),
-                vec![TABLE_SOURCE_PK_COLID],
-            ),
-            None => unreachable!(),
-        };
+        let (source_type, prost_columns, pk_col_ids, watermark_column_id, append_only) =
+            match &prost.info {
+                Some(Info::StreamSource(source)) => (
+                    SourceType::Source,
+                    source.columns.clone(),
+                    source
+                        .pk_column_ids
+                        .iter()
+                        .map(|id| ColumnId::new(*id))
+                        .collect(),
+                    source
+                        .watermark
+                        .as_ref()
+                        .map(|watermark| ColumnId::new(watermark.column_id)),
+                    source.row_format != RowFormatType::DebeziumJson as i32,
+                ),
+                Some(Info::TableSource(source)) => (
+                    SourceType::Table,
+                    source.columns.clone(),
+                    vec![TABLE_SOURCE_PK_COLID],
+                    None,
+                    false,
+                ),
+                None => unreachable!(),
+            };
         let columns = prost_columns.into_iter().map(ColumnCatalog::from).collect();
         Self {
             id,
@@ -80,6 +94,8 @@ impl From<&ProstSource> for SourceCatalog {
             columns,
             pk_col_ids,
             source_type,
+            watermark_column_id,
+            append_only,
         }
     }
 }
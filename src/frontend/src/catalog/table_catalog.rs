@@ -47,6 +47,9 @@ pub struct TableCatalog {
 
     /// If set to Some(TableId), then this table is an index on another table.
     pub is_index_on: Option<TableId>,
+
+    /// Rows written longer than TTL seconds ago are dropped by compaction.
+    pub ttl_seconds: Option<u32>,
 }
 
 impl TableCatalog {
@@ -123,6 +126,7 @@ impl TableCatalog {
                 .iter()
                 .map(|k| *k as i32)
                 .collect_vec(),
+            ttl_seconds: self.ttl_seconds.unwrap_or_default(),
         }
     }
 }
@@ -180,6 +184,11 @@ impl From<ProstTable> for TableCatalog {
                 .map(|k| *k as usize)
                 .collect_vec(),
             pks: tb.pk.iter().map(|x| *x as _).collect(),
+            ttl_seconds: if tb.ttl_seconds > 0 {
+                Some(tb.ttl_seconds)
+            } else {
+                None
+            },
         }
     }
 }
@@ -247,6 +256,7 @@ mod tests {
             distribution_keys: vec![],
             optional_associated_source_id: OptionalAssociatedSourceId::AssociatedSourceId(233)
                 .into(),
+            ttl_seconds: 3600,
         }
         .into();
 
@@ -293,6 +303,7 @@ mod tests {
                     order: OrderType::Ascending
                 }],
                 distribution_keys: vec![],
+                ttl_seconds: Some(3600),
             }
         );
     }
@@ -24,6 +24,7 @@ pub struct AggCall {
     agg_kind: AggKind,
     return_type: DataType,
     inputs: Vec<ExprImpl>,
+    distinct: bool,
 }
 
 impl std::fmt::Debug for AggCall {
@@ -33,6 +34,7 @@ impl std::fmt::Debug for AggCall {
                 .field("agg_kind", &self.agg_kind)
                 .field("return_type", &self.return_type)
                 .field("inputs", &self.inputs)
+                .field("distinct", &self.distinct)
                 .finish()
         } else {
             let mut builder = f.debug_tuple(&format!("{}", self.agg_kind));
@@ -70,6 +72,29 @@ impl AggCall {
                 _ => return None,
             },
             (AggKind::Count, _) => DataType::Int64,
+            (
+                AggKind::StddevPop | AggKind::StddevSamp | AggKind::VarPop | AggKind::VarSamp,
+                [input],
+            ) => match input {
+                DataType::Int16 | DataType::Int32 | DataType::Int64 | DataType::Decimal => {
+                    DataType::Decimal
+                }
+                DataType::Float32 | DataType::Float64 => DataType::Float64,
+                _ => return None,
+            },
+            (AggKind::BoolAnd | AggKind::BoolOr, [input]) => match input {
+                DataType::Boolean => DataType::Boolean,
+                _ => return None,
+            },
+            (AggKind::ArrayAgg, [input]) => DataType::List {
+                datatype: Box::new(input.clone()),
+            },
+            (AggKind::StringAgg, [value, delimiter]) => match (value, delimiter) {
+                (DataType::Varchar, DataType::Varchar) => DataType::Varchar,
+                _ => return None,
+            },
+            (AggKind::ApproxCountDistinct, [_]) => DataType::Int64,
+            (AggKind::ApproxCountDistinctMerge, [DataType::Varchar]) => DataType::Int64,
             (other_kind, other_inputs) => {
                 todo!(
                     "Unsupported aggregate function: {:?} with {} inputs",
@@ -83,7 +108,7 @@ impl AggCall {
 
     /// Returns error if the function name matches with an existing function
     /// but with illegal arguments.
-    pub fn new(agg_kind: AggKind, inputs: Vec<ExprImpl>) -> Result<Self> {
+    pub fn new(agg_kind: AggKind, inputs: Vec<ExprImpl>, distinct: bool) -> Result<Self> {
         // TODO(TaoWu): Add arguments validator.
         let data_types = inputs.iter().map(ExprImpl::return_type).collect_vec();
         let return_type = Self::infer_return_type(&agg_kind, &data_types).ok_or_else(|| {
@@ -97,11 +122,12 @@ impl AggCall {
             agg_kind,
             return_type,
             inputs,
+            distinct,
         })
     }
 
-    pub fn decompose(self) -> (AggKind, Vec<ExprImpl>) {
-        (self.agg_kind, self.inputs)
+    pub fn decompose(self) -> (AggKind, Vec<ExprImpl>, bool) {
+        (self.agg_kind, self.inputs, self.distinct)
     }
 
     pub fn agg_kind(&self) -> AggKind {
@@ -112,6 +138,11 @@ impl AggCall {
     pub fn inputs(&self) -> &[ExprImpl] {
         self.inputs.as_ref()
     }
+
+    /// Whether the agg call only applies to the distinct values of its inputs.
+    pub fn distinct(&self) -> bool {
+        self.distinct
+    }
 }
 impl Expr for AggCall {
     fn return_type(&self) -> DataType {
@@ -12,11 +12,15 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use itertools::Itertools;
 use num_integer::Integer as _;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::DataType;
 
-use super::{align_types, cast_ok, infer_type, CastContext, Expr, ExprImpl, Literal};
+use super::{
+    align_types, cast_ok, infer_conditional_type, infer_list_type, infer_math_type, infer_type,
+    CastContext, Expr, ExprImpl, Literal,
+};
 use crate::expr::ExprType;
 
 #[derive(Clone, Eq, PartialEq, Hash)]
@@ -106,6 +110,51 @@ impl FunctionCall {
                 align_types(inputs.iter_mut())?;
                 Ok(DataType::Boolean)
             }
+            // `infer_type` doesn't know the element type of lists.
+            ExprType::RegexpMatch | ExprType::StringToArray => infer_type(
+                func_type,
+                inputs.iter().map(|expr| expr.return_type()).collect(),
+            )
+            .map(|_| DataType::List {
+                datatype: Box::new(DataType::Varchar),
+            }),
+            // The arguments of `concat_ws` are cast to varchar by the binder.
+            ExprType::ConcatWs => {
+                if inputs.len() >= 2
+                    && inputs
+                        .iter()
+                        .all(|expr| expr.return_type() == DataType::Varchar)
+                {
+                    Ok(DataType::Varchar)
+                } else {
+                    Err(ErrorCode::BindError(format!(
+                        "function {:?}({}) does not exist",
+                        func_type,
+                        inputs
+                            .iter()
+                            .map(|e| format!("{:?}", e.return_type()))
+                            .join(", ")
+                    ))
+                    .into())
+                }
+            }
+            ExprType::Array
+            | ExprType::ArrayAccess
+            | ExprType::ArrayRangeAccess
+            | ExprType::ArrayLength
+            | ExprType::ArrayAppend
+            | ExprType::ArrayCat
+            | ExprType::ArrayContains
+            | ExprType::ArrayAnyEqual => infer_list_type(func_type, &mut inputs),
+            ExprType::Coalesce | ExprType::Nullif | ExprType::Greatest | ExprType::Least => {
+                infer_conditional_type(func_type, &mut inputs)
+            }
+            ExprType::Abs
+            | ExprType::Ceil
+            | ExprType::Floor
+            | ExprType::Power
+            | ExprType::Sqrt
+            | ExprType::Ln => infer_math_type(func_type, &mut inputs),
             _ => infer_type(
                 func_type,
                 inputs.iter().map(|expr| expr.return_type()).collect(),
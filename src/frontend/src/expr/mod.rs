@@ -18,7 +18,8 @@ use paste::paste;
 use risingwave_common::error::Result;
 use risingwave_common::types::{DataType, Scalar};
 use risingwave_expr::expr::AggKind;
-use risingwave_pb::expr::ExprNode;
+use risingwave_pb::expr::project_set_select_item::SelectItem;
+use risingwave_pb::expr::{ExprNode, ProjectSetSelectItem};
 
 mod agg_call;
 mod correlated_input_ref;
@@ -26,6 +27,8 @@ mod function_call;
 mod input_ref;
 mod literal;
 mod subquery;
+mod table_function;
+mod user_defined_function;
 
 mod expr_rewriter;
 mod expr_visitor;
@@ -38,12 +41,17 @@ pub use function_call::FunctionCall;
 pub use input_ref::{as_alias_display, input_ref_to_column_indices, InputRef, InputRefDisplay};
 pub use literal::Literal;
 pub use subquery::{Subquery, SubqueryKind};
+pub use table_function::{TableFunction, TableFunctionType};
+pub use user_defined_function::UserDefinedFunction;
 
 pub type ExprType = risingwave_pb::expr::expr_node::Type;
 
 pub use expr_rewriter::ExprRewriter;
 pub use expr_visitor::ExprVisitor;
-pub use type_inference::{align_types, cast_ok, infer_type, least_restrictive, CastContext};
+pub use type_inference::{
+    align_types, cast_ok, infer_conditional_type, infer_list_type, infer_math_type, infer_type,
+    least_restrictive, CastContext,
+};
 pub use utils::*;
 
 /// the trait of bound exprssions
@@ -64,6 +72,8 @@ pub enum ExprImpl {
     FunctionCall(Box<FunctionCall>),
     AggCall(Box<AggCall>),
     Subquery(Box<Subquery>),
+    TableFunction(Box<TableFunction>),
+    UserDefinedFunction(Box<UserDefinedFunction>),
 }
 
 impl ExprImpl {
@@ -79,10 +89,16 @@ impl ExprImpl {
         Literal::new(Some(v.to_scalar_value()), DataType::Boolean).into()
     }
 
+    /// A literal varchar value.
+    #[inline(always)]
+    pub fn literal_varchar(v: String) -> Self {
+        Literal::new(Some(v.to_scalar_value()), DataType::Varchar).into()
+    }
+
     /// A `count(*)` aggregate function.
     #[inline(always)]
     pub fn count_star() -> Self {
-        AggCall::new(AggKind::Count, vec![]).unwrap().into()
+        AggCall::new(AggKind::Count, vec![], false).unwrap().into()
     }
 
     /// Collect all `InputRef`s' indexes in the expression.
@@ -114,6 +130,18 @@ impl ExprImpl {
     pub fn cast_explicit(self, target: DataType) -> Result<ExprImpl> {
         FunctionCall::new_cast(self, target, CastContext::Explicit)
     }
+
+    /// Serialize the expression as an item of the select list of `ProjectSet`, where table
+    /// functions can only appear at the top level.
+    pub fn to_project_set_select_item_proto(&self) -> ProjectSetSelectItem {
+        let select_item = match self {
+            ExprImpl::TableFunction(tf) => SelectItem::TableFunction(tf.to_protobuf()),
+            expr => SelectItem::Expr(expr.to_expr_proto()),
+        };
+        ProjectSetSelectItem {
+            select_item: Some(select_item),
+        }
+    }
 }
 
 /// Implement helper functions which recursively checks whether an variant is included in the
@@ -148,7 +176,7 @@ macro_rules! impl_has_variant {
     };
 }
 
-impl_has_variant! {InputRef, Literal, FunctionCall, AggCall, Subquery}
+impl_has_variant! {InputRef, Literal, FunctionCall, AggCall, Subquery, TableFunction, UserDefinedFunction}
 
 impl ExprImpl {
     // We need to traverse inside subqueries.
@@ -192,6 +220,8 @@ impl Expr for ExprImpl {
             ExprImpl::AggCall(expr) => expr.return_type(),
             ExprImpl::Subquery(expr) => expr.return_type(),
             ExprImpl::CorrelatedInputRef(expr) => expr.return_type(),
+            ExprImpl::TableFunction(expr) => expr.return_type(),
+            ExprImpl::UserDefinedFunction(expr) => expr.return_type(),
         }
     }
 
@@ -203,6 +233,8 @@ impl Expr for ExprImpl {
             ExprImpl::AggCall(e) => e.to_expr_proto(),
             ExprImpl::Subquery(e) => e.to_expr_proto(),
             ExprImpl::CorrelatedInputRef(e) => e.to_expr_proto(),
+            ExprImpl::TableFunction(e) => e.to_expr_proto(),
+            ExprImpl::UserDefinedFunction(e) => e.to_expr_proto(),
         }
     }
 }
@@ -243,6 +275,18 @@ impl From<CorrelatedInputRef> for ExprImpl {
     }
 }
 
+impl From<TableFunction> for ExprImpl {
+    fn from(tf: TableFunction) -> Self {
+        ExprImpl::TableFunction(Box::new(tf))
+    }
+}
+
+impl From<UserDefinedFunction> for ExprImpl {
+    fn from(udf: UserDefinedFunction) -> Self {
+        ExprImpl::UserDefinedFunction(Box::new(udf))
+    }
+}
+
 impl From<Condition> for ExprImpl {
     fn from(c: Condition) -> Self {
         merge_expr_by_binary(
@@ -268,6 +312,10 @@ impl std::fmt::Debug for ExprImpl {
                 Self::CorrelatedInputRef(arg0) => {
                     f.debug_tuple("CorrelatedInputRef").field(arg0).finish()
                 }
+                Self::TableFunction(arg0) => f.debug_tuple("TableFunction").field(arg0).finish(),
+                Self::UserDefinedFunction(arg0) => {
+                    f.debug_tuple("UserDefinedFunction").field(arg0).finish()
+                }
             };
         }
         match self {
@@ -277,6 +325,8 @@ impl std::fmt::Debug for ExprImpl {
             Self::AggCall(x) => write!(f, "{:?}", x),
             Self::Subquery(x) => write!(f, "{:?}", x),
             Self::CorrelatedInputRef(x) => write!(f, "{:?}", x),
+            Self::TableFunction(x) => write!(f, "{:?}", x),
+            Self::UserDefinedFunction(x) => write!(f, "{:?}", x),
         }
     }
 }
@@ -0,0 +1,250 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::str::FromStr;
+
+use itertools::Itertools;
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
+use risingwave_pb::expr::table_function::Type;
+use risingwave_pb::expr::TableFunction as TableFunctionProst;
+
+use super::{align_types, Expr, ExprImpl};
+
+/// A table function takes a row as input and returns a table. It is also known as Set-Returning
+/// Function.
+///
+/// See also [`TableFunction`](risingwave_expr::table_function::TableFunction) trait in expr
+/// crate.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct TableFunction {
+    pub args: Vec<ExprImpl>,
+    pub return_type: DataType,
+    pub function_type: TableFunctionType,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum TableFunctionType {
+    GenerateSeries,
+    Unnest,
+    RegexpMatches,
+    JsonbArrayElements,
+}
+
+impl TableFunctionType {
+    fn to_protobuf(self) -> Type {
+        match self {
+            TableFunctionType::GenerateSeries => Type::GenerateSeries,
+            TableFunctionType::Unnest => Type::Unnest,
+            TableFunctionType::RegexpMatches => Type::RegexpMatches,
+            TableFunctionType::JsonbArrayElements => Type::JsonbArrayElements,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            TableFunctionType::GenerateSeries => "generate_series",
+            TableFunctionType::Unnest => "unnest",
+            TableFunctionType::RegexpMatches => "regexp_matches",
+            TableFunctionType::JsonbArrayElements => "jsonb_array_elements",
+        }
+    }
+}
+
+impl FromStr for TableFunctionType {
+    type Err = ();
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s {
+            "generate_series" => Ok(TableFunctionType::GenerateSeries),
+            "unnest" => Ok(TableFunctionType::Unnest),
+            "regexp_matches" => Ok(TableFunctionType::RegexpMatches),
+            "jsonb_array_elements" => Ok(TableFunctionType::JsonbArrayElements),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TableFunction {
+    /// Create a table function, with its arguments aligned to the types supported by the
+    /// function. Returns error if the arguments are illegal.
+    pub fn new(function_type: TableFunctionType, args: Vec<ExprImpl>) -> Result<Self> {
+        match function_type {
+            TableFunctionType::GenerateSeries => Self::new_generate_series(args),
+            TableFunctionType::Unnest => Self::new_unnest(args),
+            TableFunctionType::RegexpMatches => Self::new_regexp_matches(args),
+            TableFunctionType::JsonbArrayElements => Self::new_jsonb_array_elements(args),
+        }
+    }
+
+    fn new_generate_series(mut args: Vec<ExprImpl>) -> Result<Self> {
+        let invalid_args = |args: &[ExprImpl]| -> RwError {
+            ErrorCode::BindError(format!(
+                "function generate_series({}) does not exist",
+                args.iter()
+                    .map(|arg| format!("{:?}", arg.return_type()))
+                    .join(", ")
+            ))
+            .into()
+        };
+        if !(2..=3).contains(&args.len()) {
+            return Err(invalid_args(&args));
+        }
+
+        let return_type = match align_types(args.iter_mut().take(2))? {
+            DataType::Int16 | DataType::Int32 | DataType::Int64 => {
+                if args.len() == 2 {
+                    args.push(ExprImpl::literal_int(1));
+                }
+                match align_types(args.iter_mut())? {
+                    DataType::Int16 | DataType::Int32 => {
+                        args = args
+                            .into_iter()
+                            .map(|arg| arg.cast_implicit(DataType::Int32))
+                            .try_collect()?;
+                        DataType::Int32
+                    }
+                    DataType::Int64 => DataType::Int64,
+                    _ => return Err(invalid_args(&args)),
+                }
+            }
+            DataType::Timestamp if args.len() == 3 => {
+                let step = args.pop().unwrap();
+                args.push(step.cast_implicit(DataType::Interval)?);
+                DataType::Timestamp
+            }
+            _ => return Err(invalid_args(&args)),
+        };
+
+        Ok(TableFunction {
+            args,
+            return_type,
+            function_type: TableFunctionType::GenerateSeries,
+        })
+    }
+
+    fn new_unnest(args: Vec<ExprImpl>) -> Result<Self> {
+        let return_type = match args.as_slice() {
+            [arg] => match arg.return_type() {
+                DataType::List { datatype } => Some(*datatype),
+                _ => None,
+            },
+            _ => None,
+        }
+        .ok_or_else(|| {
+            RwError::from(ErrorCode::BindError(format!(
+                "function unnest({}) does not exist",
+                args.iter()
+                    .map(|arg| format!("{:?}", arg.return_type()))
+                    .join(", ")
+            )))
+        })?;
+
+        Ok(TableFunction {
+            args,
+            return_type,
+            function_type: TableFunctionType::Unnest,
+        })
+    }
+
+    fn new_regexp_matches(args: Vec<ExprImpl>) -> Result<Self> {
+        if !(2..=3).contains(&args.len()) {
+            return Err(ErrorCode::BindError(format!(
+                "function regexp_matches({}) does not exist",
+                args.iter()
+                    .map(|arg| format!("{:?}", arg.return_type()))
+                    .join(", ")
+            ))
+            .into());
+        }
+        let args = args
+            .into_iter()
+            .map(|arg| arg.cast_implicit(DataType::Varchar))
+            .try_collect()?;
+
+        Ok(TableFunction {
+            args,
+            return_type: DataType::List {
+                datatype: Box::new(DataType::Varchar),
+            },
+            function_type: TableFunctionType::RegexpMatches,
+        })
+    }
+
+    fn new_jsonb_array_elements(args: Vec<ExprImpl>) -> Result<Self> {
+        match args.as_slice() {
+            [arg] if arg.return_type() == DataType::Jsonb => {}
+            _ => {
+                return Err(ErrorCode::BindError(format!(
+                    "function jsonb_array_elements({}) does not exist",
+                    args.iter()
+                        .map(|arg| format!("{:?}", arg.return_type()))
+                        .join(", ")
+                ))
+                .into())
+            }
+        }
+
+        Ok(TableFunction {
+            args,
+            return_type: DataType::Jsonb,
+            function_type: TableFunctionType::JsonbArrayElements,
+        })
+    }
+
+    pub fn to_protobuf(&self) -> TableFunctionProst {
+        TableFunctionProst {
+            function_type: self.function_type.to_protobuf() as i32,
+            args: self.args.iter().map(|c| c.to_expr_proto()).collect_vec(),
+            return_type: Some(self.return_type.to_protobuf()),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        self.function_type.name()
+    }
+}
+
+impl std::fmt::Debug for TableFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("TableFunction")
+                .field("function_type", &self.function_type)
+                .field("return_type", &self.return_type)
+                .field("args", &self.args)
+                .finish()
+        } else {
+            let mut builder = f.debug_tuple(self.name());
+            self.args.iter().for_each(|arg| {
+                builder.field(arg);
+            });
+            builder.finish()
+        }
+    }
+}
+
+impl Expr for TableFunction {
+    fn return_type(&self) -> DataType {
+        self.return_type.clone()
+    }
+
+    fn to_expr_proto(&self) -> risingwave_pb::expr::ExprNode {
+        // A table function is always planned as `ProjectSet` or `TableFunction` operators, and
+        // never appears in a plain expression.
+        unreachable!(
+            "TableFunction {:?} has not been planned as ProjectSet",
+            self
+        )
+    }
+}
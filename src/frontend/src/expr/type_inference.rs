@@ -19,7 +19,7 @@ use std::sync::Arc;
 use std::vec;
 
 use itertools::{iproduct, Itertools};
-use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::error::{ErrorCode, Result, RwError};
 use risingwave_common::types::DataType;
 
 use crate::expr::{Expr as _, ExprImpl, ExprType};
@@ -43,6 +43,8 @@ enum DataTypeName {
     Interval,
     Struct,
     List,
+    Jsonb,
+    Bytea,
 }
 
 fn name_of(ty: &DataType) -> DataTypeName {
@@ -62,6 +64,8 @@ fn name_of(ty: &DataType) -> DataTypeName {
         DataType::Interval => DataTypeName::Interval,
         DataType::Struct { .. } => DataTypeName::Struct,
         DataType::List { .. } => DataTypeName::List,
+        DataType::Jsonb => DataTypeName::Jsonb,
+        DataType::Bytea => DataTypeName::Bytea,
     }
 }
 
@@ -91,6 +95,8 @@ pub fn infer_type(func_type: ExprType, inputs_type: Vec<DataType>) -> Result<Dat
         DataTypeName::List => DataType::List {
             datatype: Box::new(DataType::Int32),
         },
+        DataTypeName::Jsonb => DataType::Jsonb,
+        DataTypeName::Bytea => DataType::Bytea,
     })
 }
 
@@ -173,6 +179,8 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         T::Timestampz,
         T::Time,
         T::Interval,
+        T::Jsonb,
+        T::Bytea,
     ];
     let num_types = [
         T::Int16,
@@ -232,6 +240,19 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         FuncSign::new(E::RoundDigit, vec![T::Decimal, T::Int32]),
         T::Decimal,
     );
+    for t in num_types {
+        map.insert(FuncSign::new(E::Abs, vec![t]), t);
+    }
+    for e in [E::Sqrt, E::Ceil, E::Floor] {
+        for t in [T::Float64, T::Decimal] {
+            map.insert(FuncSign::new(e, vec![t]), t);
+        }
+    }
+    map.insert(
+        FuncSign::new(E::Power, vec![T::Float64, T::Float64]),
+        T::Float64,
+    );
+    map.insert(FuncSign::new(E::Ln, vec![T::Float64]), T::Float64);
 
     // temporal expressions
     for (base, delta) in [
@@ -269,6 +290,27 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
             T::Timestamp,
         );
     }
+    map.insert(
+        FuncSign::new(E::DateTrunc, vec![T::Varchar, T::Timestamp]),
+        T::Timestamp,
+    );
+    map.insert(
+        FuncSign::new(E::ToChar, vec![T::Timestamp, T::Varchar]),
+        T::Varchar,
+    );
+    map.insert(
+        FuncSign::new(E::ToTimestamp, vec![T::Varchar, T::Varchar]),
+        T::Timestamp,
+    );
+    map.insert(
+        FuncSign::new(E::AtTimeZone, vec![T::Timestamp, T::Varchar]),
+        T::Timestampz,
+    );
+    map.insert(
+        FuncSign::new(E::AtTimeZone, vec![T::Timestampz, T::Varchar]),
+        T::Timestamp,
+    );
+    map.insert(FuncSign::new(E::Now, vec![]), T::Timestampz);
 
     // string expressions
     for e in [E::Trim, E::Ltrim, E::Rtrim, E::Lower, E::Upper] {
@@ -302,6 +344,84 @@ fn build_type_derive_map() -> HashMap<FuncSign, DataTypeName> {
         FuncSign::new(E::Like, vec![T::Varchar, T::Varchar]),
         T::Boolean,
     );
+    for e in [E::Reverse, E::Md5, E::Initcap] {
+        map.insert(FuncSign::new(e, vec![T::Varchar]), T::Varchar);
+    }
+    map.insert(
+        FuncSign::new(E::ConcatOp, vec![T::Varchar, T::Varchar]),
+        T::Varchar,
+    );
+    for e in [E::Left, E::Right, E::Repeat] {
+        map.insert(FuncSign::new(e, vec![T::Varchar, T::Int32]), T::Varchar);
+    }
+    for e in [E::Lpad, E::Rpad] {
+        map.insert(
+            FuncSign::new(e, vec![T::Varchar, T::Int32, T::Varchar]),
+            T::Varchar,
+        );
+    }
+    map.insert(
+        FuncSign::new(E::SplitPart, vec![T::Varchar, T::Varchar, T::Int32]),
+        T::Varchar,
+    );
+    map.insert(
+        FuncSign::new(E::StartsWith, vec![T::Varchar, T::Varchar]),
+        T::Boolean,
+    );
+    // with an optional trailing argument of the string to be taken as null
+    for n in [2, 3] {
+        map.insert(
+            FuncSign::new(E::StringToArray, vec![T::Varchar; n]),
+            T::List,
+        );
+    }
+
+    // regular expressions, with an optional trailing argument of flags or escape character
+    for (e, num_args, ret) in [
+        (E::RegexpEq, 2, T::Boolean),
+        (E::SimilarTo, 2, T::Boolean),
+        (E::RegexpMatch, 2, T::List),
+        (E::RegexpReplace, 3, T::Varchar),
+    ] {
+        for n in [num_args, num_args + 1] {
+            map.insert(FuncSign::new(e, vec![T::Varchar; n]), ret);
+        }
+    }
+
+    // bytea expressions
+    map.insert(FuncSign::new(E::Length, vec![T::Bytea]), T::Int32);
+    map.insert(FuncSign::new(E::Substr, vec![T::Bytea, T::Int32]), T::Bytea);
+    map.insert(
+        FuncSign::new(E::Substr, vec![T::Bytea, T::Int32, T::Int32]),
+        T::Bytea,
+    );
+    map.insert(
+        FuncSign::new(E::Encode, vec![T::Bytea, T::Varchar]),
+        T::Varchar,
+    );
+    map.insert(
+        FuncSign::new(E::Decode, vec![T::Varchar, T::Varchar]),
+        T::Bytea,
+    );
+
+    // jsonb expressions
+    for (e, ret) in [
+        (E::JsonbAccessInner, T::Jsonb),
+        (E::JsonbAccessStr, T::Varchar),
+    ] {
+        for key in [T::Varchar, T::Int32] {
+            map.insert(FuncSign::new(e, vec![T::Jsonb, key]), ret);
+        }
+    }
+    map.insert(
+        FuncSign::new(E::JsonbAccessPath, vec![T::Jsonb, T::Varchar]),
+        T::Jsonb,
+    );
+    map.insert(
+        FuncSign::new(E::JsonbContains, vec![T::Jsonb, T::Jsonb]),
+        T::Boolean,
+    );
+    map.insert(FuncSign::new(E::JsonbTypeof, vec![T::Jsonb]), T::Varchar);
 
     map
 }
@@ -356,6 +476,117 @@ pub fn align_types<'a>(exprs: impl Iterator<Item = &'a mut ExprImpl>) -> Result<
     Ok(ret_type)
 }
 
+/// Infers the return type of functions and operators over lists, and adds implicit casts to align
+/// the arguments. They are not in the signature map as their types depend on the element types.
+pub fn infer_list_type(func_type: ExprType, inputs: &mut [ExprImpl]) -> Result<DataType> {
+    let not_exist = |inputs: &[ExprImpl]| -> RwError {
+        ErrorCode::BindError(format!(
+            "function {:?}({}) does not exist",
+            func_type,
+            inputs
+                .iter()
+                .map(|e| format!("{:?}", e.return_type()))
+                .join(", ")
+        ))
+        .into()
+    };
+    let list_type = |datatype: DataType| DataType::List {
+        datatype: Box::new(datatype),
+    };
+    let element_type = |list: &ExprImpl| match list.return_type() {
+        DataType::List { datatype } => Some(*datatype),
+        _ => None,
+    };
+
+    match (func_type, &mut *inputs) {
+        (ExprType::Array, []) => {
+            Err(ErrorCode::BindError("cannot determine type of empty array".to_string()).into())
+        }
+        (ExprType::Array, elements) => align_types(elements.iter_mut()).map(list_type),
+        (ExprType::ArrayAccess, [list, index]) if element_type(list).is_some() => {
+            cast_implicit_in_place(index, DataType::Int32)?;
+            Ok(element_type(list).unwrap())
+        }
+        (ExprType::ArrayRangeAccess, [list, start, end]) if element_type(list).is_some() => {
+            cast_implicit_in_place(start, DataType::Int32)?;
+            cast_implicit_in_place(end, DataType::Int32)?;
+            Ok(list.return_type())
+        }
+        (ExprType::ArrayLength, [list, dimension]) if element_type(list).is_some() => {
+            cast_implicit_in_place(dimension, DataType::Int32)?;
+            Ok(DataType::Int32)
+        }
+        (ExprType::ArrayCat | ExprType::ArrayContains, [_, _]) => {
+            let ret_type = align_types(inputs.iter_mut())?;
+            match (func_type, ret_type) {
+                (ExprType::ArrayCat, ret_type @ DataType::List { .. }) => Ok(ret_type),
+                (_, DataType::List { .. }) => Ok(DataType::Boolean),
+                _ => Err(not_exist(inputs)),
+            }
+        }
+        // The list is aligned with the element, e.g. `array_append(int[], bigint)` is
+        // `array_append(bigint[], bigint)`.
+        (ExprType::ArrayAppend, [list, element]) | (ExprType::ArrayAnyEqual, [element, list])
+            if list.is_null() || element_type(list).is_some() =>
+        {
+            let mut ret_type = match element_type(list) {
+                Some(datatype) => datatype,
+                None if element.is_null() => return Err(not_exist(inputs)),
+                None => element.return_type(),
+            };
+            if !element.is_null() {
+                ret_type = least_restrictive(ret_type, element.return_type())?;
+            }
+            cast_implicit_in_place(list, list_type(ret_type.clone()))?;
+            cast_implicit_in_place(element, ret_type.clone())?;
+            match func_type {
+                ExprType::ArrayAppend => Ok(list_type(ret_type)),
+                _ => Ok(DataType::Boolean),
+            }
+        }
+        _ => Err(not_exist(inputs)),
+    }
+}
+
+/// Infers the return type of conditional expressions, whose arguments are aligned to the same type
+/// as the result. `nullif` takes 2 arguments, and the others take at least 1.
+pub fn infer_conditional_type(func_type: ExprType, inputs: &mut [ExprImpl]) -> Result<DataType> {
+    let arity_ok = match func_type {
+        ExprType::Nullif => inputs.len() == 2,
+        _ => !inputs.is_empty(),
+    };
+    if !arity_ok {
+        return Err(ErrorCode::BindError(format!(
+            "function {:?} does not take {} arguments",
+            func_type,
+            inputs.len()
+        ))
+        .into());
+    }
+    align_types(inputs.iter_mut())
+}
+
+/// Infers the return type of math functions. If there's no variant for the argument types, the
+/// arguments are implicitly cast to `double precision`, the preferred numeric type, e.g.
+/// `ln(int)` is called as `ln(double precision)`.
+pub fn infer_math_type(func_type: ExprType, inputs: &mut [ExprImpl]) -> Result<DataType> {
+    let inputs_type = inputs.iter().map(|e| e.return_type()).collect_vec();
+    if let Ok(ret_type) = infer_type(func_type, inputs_type) {
+        return Ok(ret_type);
+    }
+    for input in inputs.iter_mut() {
+        cast_implicit_in_place(input, DataType::Float64)?;
+    }
+    infer_type(func_type, inputs.iter().map(|e| e.return_type()).collect())
+}
+
+fn cast_implicit_in_place(e: &mut ExprImpl, target: DataType) -> Result<()> {
+    let mut dummy = ExprImpl::literal_bool(false);
+    std::mem::swap(&mut dummy, e);
+    *e = dummy.cast_implicit(target)?;
+    Ok(())
+}
+
 /// The context a cast operation is invoked in. An implicit cast operation is allowed in a context
 /// that allows explicit casts, but not vice versa. See details in
 /// [PG](https://www.postgresql.org/docs/current/catalog-pg-cast.html).
@@ -368,6 +599,12 @@ pub enum CastContext {
 
 /// Checks whether casting from `source` to `target` is ok in `allows` context.
 pub fn cast_ok(source: &DataType, target: &DataType, allows: &CastContext) -> bool {
+    if let (DataType::List { datatype: source }, DataType::List { datatype: target }) =
+        (source, target)
+    {
+        // Lists are cast element-wise.
+        return source == target || cast_ok(source, target, allows);
+    }
     let k = (name_of(source), name_of(target));
     matches!(CAST_MAP.get(&k), Some(context) if context <= allows)
 }
@@ -419,6 +656,9 @@ fn build_cast_map() -> HashMap<(DataTypeName, DataTypeName), CastContext> {
         T::Timestampz,
         T::Time,
         T::Interval,
+        T::Jsonb,
+        T::Bytea,
+        T::List,
     ] {
         m.insert((t, T::Varchar), CastContext::Assign);
         // Casting from string is explicit-only in PG.
@@ -464,6 +704,7 @@ lazy_static::lazy_static! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::expr::Literal;
 
     fn test_simple_infer_type(
         func_type: ExprType,
@@ -563,6 +804,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_math_functions() {
+        let decimal = || ExprImpl::from(Literal::new(None, DataType::Decimal));
+        for (func_type, mut inputs, expected) in [
+            (
+                ExprType::Abs,
+                vec![ExprImpl::literal_int(1)],
+                DataType::Int32,
+            ),
+            (ExprType::Ceil, vec![decimal()], DataType::Decimal),
+            (
+                ExprType::Floor,
+                vec![ExprImpl::literal_int(1)],
+                DataType::Float64,
+            ),
+            (ExprType::Ln, vec![decimal()], DataType::Float64),
+            (
+                ExprType::Power,
+                vec![ExprImpl::literal_int(2), decimal()],
+                DataType::Float64,
+            ),
+        ] {
+            assert_eq!(infer_math_type(func_type, &mut inputs).unwrap(), expected);
+            for input in inputs {
+                assert_eq!(input.return_type(), expected);
+            }
+        }
+
+        let mut inputs = vec![ExprImpl::literal_bool(true)];
+        assert!(infer_math_type(ExprType::Sqrt, &mut inputs).is_err());
+    }
+
+    #[test]
+    fn test_conditional_functions() {
+        let mut inputs = vec![
+            ExprImpl::from(Literal::new(None, DataType::Varchar)),
+            ExprImpl::literal_int(1),
+            ExprImpl::from(Literal::new(None, DataType::Decimal)),
+        ];
+        let ret = infer_conditional_type(ExprType::Coalesce, &mut inputs).unwrap();
+        assert_eq!(ret, DataType::Decimal);
+        assert!(inputs.iter().all(|e| e.return_type() == DataType::Decimal));
+
+        let mut inputs = vec![ExprImpl::literal_int(1)];
+        assert!(infer_conditional_type(ExprType::Nullif, &mut inputs).is_err());
+        assert!(infer_conditional_type(ExprType::Greatest, &mut []).is_err());
+        let mut inputs = vec![ExprImpl::literal_int(1), ExprImpl::literal_bool(true)];
+        assert!(infer_conditional_type(ExprType::Least, &mut inputs).is_err());
+    }
+
     fn gen_cast_table(allows: CastContext) -> Vec<String> {
         use itertools::Itertools as _;
         use DataType as T;
@@ -0,0 +1,96 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use itertools::Itertools;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
+
+use super::{Expr, ExprImpl};
+use crate::catalog::function_catalog::FunctionCatalog;
+
+/// A call to a user-defined scalar function.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct UserDefinedFunction {
+    pub args: Vec<ExprImpl>,
+    pub catalog: Arc<FunctionCatalog>,
+}
+
+impl UserDefinedFunction {
+    /// Create a call to the function, with its arguments implicitly casted to the declared types.
+    pub fn new(catalog: Arc<FunctionCatalog>, args: Vec<ExprImpl>) -> Result<Self> {
+        if args.len() != catalog.arg_types.len() {
+            return Err(ErrorCode::BindError(format!(
+                "function {}({}) does not exist",
+                catalog.name,
+                args.iter()
+                    .map(|arg| format!("{:?}", arg.return_type()))
+                    .join(", ")
+            ))
+            .into());
+        }
+        let args = args
+            .into_iter()
+            .zip_eq(catalog.arg_types.iter())
+            .map(|(arg, ty)| arg.cast_implicit(ty.clone()))
+            .try_collect()?;
+        Ok(Self { args, catalog })
+    }
+}
+
+impl std::fmt::Debug for UserDefinedFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            f.debug_struct("UserDefinedFunction")
+                .field("name", &self.catalog.name)
+                .field("return_type", &self.catalog.return_type)
+                .field("args", &self.args)
+                .finish()
+        } else {
+            let mut builder = f.debug_tuple(&self.catalog.name);
+            self.args.iter().for_each(|arg| {
+                builder.field(arg);
+            });
+            builder.finish()
+        }
+    }
+}
+
+impl Expr for UserDefinedFunction {
+    fn return_type(&self) -> DataType {
+        self.catalog.return_type.clone()
+    }
+
+    fn to_expr_proto(&self) -> risingwave_pb::expr::ExprNode {
+        use risingwave_pb::expr::expr_node::*;
+        use risingwave_pb::expr::*;
+        ExprNode {
+            expr_type: Type::Udf.into(),
+            return_type: Some(self.return_type().to_protobuf()),
+            rex_node: Some(RexNode::Udf(UserDefinedFunction {
+                children: self.args.iter().map(Expr::to_expr_proto).collect(),
+                name: self.catalog.name.clone(),
+                arg_types: self
+                    .catalog
+                    .arg_types
+                    .iter()
+                    .map(|t| t.to_protobuf())
+                    .collect(),
+                language: self.catalog.language.clone(),
+                body: self.catalog.body.clone(),
+            })),
+        }
+    }
+}
@@ -13,10 +13,14 @@
 // limitations under the License.
 
 use fixedbitset::FixedBitSet;
-use risingwave_common::types::ScalarImpl;
+use risingwave_common::array::DataChunk;
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_expr::expr::build_from_prost;
 use risingwave_pb::expr::expr_node::Type;
 
-use super::{ExprImpl, ExprRewriter, ExprVisitor, FunctionCall, InputRef};
+use super::{
+    AggCall, Expr, ExprImpl, ExprRewriter, ExprVisitor, FunctionCall, InputRef, Literal, Subquery,
+};
 use crate::expr::ExprType;
 
 fn split_expr_by(expr: ExprImpl, op: ExprType, rets: &mut Vec<ExprImpl>) {
@@ -122,6 +126,140 @@ impl ExprRewriter for BooleanConstantFolding {
     }
 }
 
+/// Fold constant expressions and apply algebraic simplifications on a expr.
+/// e.g. `CAST('10' AS INT) * 2` will become `20`, and `Not(Not(A))` will become `A`
+pub fn fold_constant(expr: ExprImpl) -> ExprImpl {
+    let mut rewriter = ConstantFolding {};
+    rewriter.rewrite_expr(expr)
+}
+
+/// Fold constants in a conjunction of a predicate, see [`fold_constant`].
+/// Besides, `A = A` will become `A IS NOT NULL` if `A` is deterministic. Note that this only holds
+/// when the expr is used as a filter, where a null result has the same meaning as `false`.
+pub fn fold_constant_predicate(expr: ExprImpl) -> ExprImpl {
+    match fold_constant(expr) {
+        ExprImpl::FunctionCall(func_call)
+            if func_call.get_expr_type() == Type::Equal
+                && func_call.inputs()[0] == func_call.inputs()[1]
+                && is_deterministic(&func_call.inputs()[0]) =>
+        {
+            let (_, lhs, _) = func_call.decompose_as_binary();
+            FunctionCall::new(Type::IsNotNull, vec![lhs])
+                .unwrap()
+                .into()
+        }
+        expr => expr,
+    }
+}
+
+/// Check whether a expr always gives the same result on the same input, i.e. it contains no
+/// subqueries, aggregations or non-deterministic functions.
+pub fn is_deterministic(expr: &ExprImpl) -> bool {
+    struct HasNonDeterministic {
+        has: bool,
+    }
+
+    impl ExprVisitor for HasNonDeterministic {
+        fn visit_function_call(&mut self, func_call: &FunctionCall) {
+            // `now()` changes between epochs in streaming.
+            if matches!(func_call.get_expr_type(), Type::PgSleep | Type::Now) {
+                self.has = true;
+            }
+            func_call
+                .inputs()
+                .iter()
+                .for_each(|expr| self.visit_expr(expr));
+        }
+
+        fn visit_agg_call(&mut self, _: &AggCall) {
+            self.has = true;
+        }
+
+        fn visit_subquery(&mut self, _: &Subquery) {
+            self.has = true;
+        }
+    }
+
+    let mut visitor = HasNonDeterministic { has: false };
+    visitor.visit_expr(expr);
+    !visitor.has
+}
+
+/// Fold constants and simplify a expr
+struct ConstantFolding {}
+
+impl ExprRewriter for ConstantFolding {
+    /// Rewrite the inputs first, then try to simplify the [`FunctionCall`] or evaluate it if all
+    /// of its inputs are [`Literal`]s.
+    fn rewrite_function_call(&mut self, func_call: FunctionCall) -> ExprImpl {
+        let (func_type, inputs, ret) = func_call.decompose();
+        let mut inputs: Vec<_> = inputs
+            .into_iter()
+            .map(|expr| self.rewrite_expr(expr))
+            .collect();
+        match func_type {
+            Type::Not => {
+                if let ExprImpl::FunctionCall(inner) = &inputs[0]
+                    && inner.get_expr_type() == Type::Not
+                {
+                    // Not(Not(A)) <=> A
+                    let (_, input) = inner.clone().decompose_as_unary();
+                    return input;
+                }
+            }
+            Type::And | Type::Or => {
+                if let Some(idx) = inputs
+                    .iter()
+                    .position(|expr| try_get_bool_constant(expr).is_some())
+                {
+                    assert_eq!(inputs.len(), 2);
+                    let constant_lhs = inputs.swap_remove(idx);
+                    let rhs = inputs.pop().unwrap();
+                    return match func_type {
+                        Type::And => boolean_constant_fold_and(constant_lhs, rhs),
+                        _ => boolean_constant_fold_or(constant_lhs, rhs),
+                    };
+                }
+            }
+            _ => {}
+        }
+        let func_call = FunctionCall::new_unchecked(func_type, inputs, ret);
+        match eval_constant_function_call(&func_call) {
+            Some(literal) => literal.into(),
+            None => func_call.into(),
+        }
+    }
+}
+
+/// Evaluate a deterministic [`FunctionCall`] whose inputs are all [`Literal`]s on a one-row chunk.
+/// Return None if the function call can not be evaluated in frontend, e.g. the evaluation fails,
+/// and leave the error to be reported at runtime.
+fn eval_constant_function_call(func_call: &FunctionCall) -> Option<Literal> {
+    // Nested types can not be serialized as literals yet.
+    let is_nested = |ty: DataType| matches!(ty, DataType::Struct { .. } | DataType::List { .. });
+    let foldable = !matches!(func_call.get_expr_type(), Type::PgSleep | Type::Now)
+        && !is_nested(func_call.return_type())
+        && func_call
+            .inputs()
+            .iter()
+            .all(|expr| matches!(expr, ExprImpl::Literal(_)) && !is_nested(expr.return_type()));
+    if !foldable {
+        return None;
+    }
+    let expr = build_from_prost(&func_call.to_expr_proto()).ok()?;
+    let array = expr.eval(&DataChunk::new_dummy(1)).ok()?;
+    // The physical type of the result may differ from the inferred one, e.g. `Length` returns
+    // `Int64` values for an `Int32` expr. Do not fold such calls.
+    let builder = func_call.return_type().create_array_builder(1).ok()?;
+    if array.get_ident() != builder.get_ident() {
+        return None;
+    }
+    let literal = Literal::new(array.datum_at(0), func_call.return_type());
+    // Make sure the folded literal can be built in the backend, which does not support all types.
+    build_from_prost(&literal.to_expr_proto()).ok()?;
+    Some(literal)
+}
+
 /// Try to get bool constant from a [`ExprImpl`].
 /// If `expr` is not a [`ExprImpl::Literal`], or the Literal is not a boolean, this function will
 /// return None. Otherwise it will return the boolean value.
@@ -336,8 +474,8 @@ mod tests {
     use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_pb::expr::expr_node::Type;
 
-    use super::{fold_boolean_constant, push_down_not};
-    use crate::expr::{ExprImpl, FunctionCall, InputRef};
+    use super::{fold_boolean_constant, fold_constant, fold_constant_predicate, push_down_not};
+    use crate::expr::{ExprImpl, FunctionCall, InputRef, Literal};
 
     #[test]
     fn constant_boolean_folding_basic_and() {
@@ -522,4 +660,128 @@ mod tests {
         assert_eq!(rhs_type, Type::Not);
         assert!(rhs_input.as_input_ref().is_some());
     }
+
+    #[test]
+    fn constant_folding_function_call() {
+        // expr := Cast('10' As Int32) * 2
+        let expr: ExprImpl = FunctionCall::new(
+            Type::Multiply,
+            vec![
+                FunctionCall::new_unchecked(
+                    Type::Cast,
+                    vec![
+                        Literal::new(Some(ScalarImpl::Utf8("10".into())), DataType::Varchar).into(),
+                    ],
+                    DataType::Int32,
+                )
+                .into(),
+                ExprImpl::literal_int(2),
+            ],
+        )
+        .unwrap()
+        .into();
+
+        let res = fold_constant(expr);
+        assert!(res.as_literal().is_some());
+        let res = res.as_literal().unwrap();
+        assert_eq!(*res.get_data(), Some(ScalarImpl::Int32(20)));
+
+        // expr := A + (1 + 2)
+        let expr: ExprImpl = FunctionCall::new(
+            Type::Add,
+            vec![
+                InputRef::new(0, DataType::Int32).into(),
+                FunctionCall::new(
+                    Type::Add,
+                    vec![ExprImpl::literal_int(1), ExprImpl::literal_int(2)],
+                )
+                .unwrap()
+                .into(),
+            ],
+        )
+        .unwrap()
+        .into();
+
+        let res = fold_constant(expr);
+        let (_, lhs, rhs) = res
+            .as_function_call()
+            .unwrap()
+            .clone()
+            .decompose_as_binary();
+        assert_eq!(lhs.as_input_ref().unwrap().index(), 0);
+        assert_eq!(
+            *rhs.as_literal().unwrap().get_data(),
+            Some(ScalarImpl::Int32(3))
+        );
+    }
+
+    #[test]
+    fn constant_folding_keep_runtime_error() {
+        // expr := 1 / 0
+        let expr: ExprImpl = FunctionCall::new(
+            Type::Divide,
+            vec![ExprImpl::literal_int(1), ExprImpl::literal_int(0)],
+        )
+        .unwrap()
+        .into();
+
+        let res = fold_constant(expr.clone());
+        assert_eq!(res, expr);
+    }
+
+    #[test]
+    fn constant_folding_simplify() {
+        // expr := Not(Not(A))
+        let expr: ExprImpl = FunctionCall::new(
+            Type::Not,
+            vec![
+                FunctionCall::new(Type::Not, vec![InputRef::new(0, DataType::Boolean).into()])
+                    .unwrap()
+                    .into(),
+            ],
+        )
+        .unwrap()
+        .into();
+
+        let res = fold_constant(expr);
+        assert_eq!(res.as_input_ref().unwrap().index(), 0);
+
+        // expr := A And (1 = 1)
+        let expr: ExprImpl = FunctionCall::new(
+            Type::And,
+            vec![
+                InputRef::new(0, DataType::Boolean).into(),
+                FunctionCall::new(
+                    Type::Equal,
+                    vec![ExprImpl::literal_int(1), ExprImpl::literal_int(1)],
+                )
+                .unwrap()
+                .into(),
+            ],
+        )
+        .unwrap()
+        .into();
+
+        let res = fold_constant(expr);
+        assert_eq!(res.as_input_ref().unwrap().index(), 0);
+
+        // expr := A = A
+        let expr: ExprImpl = FunctionCall::new(
+            Type::Equal,
+            vec![
+                InputRef::new(0, DataType::Int32).into(),
+                InputRef::new(0, DataType::Int32).into(),
+            ],
+        )
+        .unwrap()
+        .into();
+
+        // The result of `A = A` is null when `A` is null, so only simplify it in predicates.
+        let res = fold_constant(expr.clone());
+        assert_eq!(res, expr);
+        let res = fold_constant_predicate(expr);
+        let (func_type, input) = res.as_function_call().unwrap().clone().decompose_as_unary();
+        assert_eq!(func_type, Type::IsNotNull);
+        assert_eq!(input.as_input_ref().unwrap().index(), 0);
+    }
 }
@@ -0,0 +1,194 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use itertools::Itertools;
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::DataType;
+use risingwave_expr::expr::WasmFunction;
+use risingwave_pb::catalog::Function as ProstFunction;
+use risingwave_sqlparser::ast::{DataType as AstDataType, FunctionArgDef, Ident, ObjectName};
+
+use crate::binder::expr::bind_data_type;
+use crate::binder::Binder;
+use crate::catalog::CatalogError;
+use crate::session::OptimizerContext;
+
+/// The only language of user-defined functions for now.
+const WASM: &str = "wasm";
+
+pub async fn handle_create_function(
+    context: OptimizerContext,
+    name: ObjectName,
+    args: Vec<FunctionArgDef>,
+    return_type: AstDataType,
+    language: Ident,
+    definition: String,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    if !language.value.eq_ignore_ascii_case(WASM) {
+        return Err(ErrorCode::NotImplemented(
+            format!("function language {}", language),
+            None.into(),
+        )
+        .into());
+    }
+
+    let (schema_name, function_name) = Binder::resolve_table_name(name)?;
+    let arg_types: Vec<DataType> = args
+        .iter()
+        .map(|arg| bind_data_type(&arg.data_type))
+        .try_collect()?;
+    let return_type = bind_data_type(&return_type)?;
+    let body = load_module(&definition)?;
+    // Compile the module to report the errors before the function is used.
+    WasmFunction::new(
+        &function_name,
+        arg_types.clone(),
+        return_type.clone(),
+        &body,
+    )?;
+
+    let (database_id, schema_id) = {
+        let catalog_reader = session.env().catalog_reader().read_guard();
+        let database = catalog_reader.get_database_by_name(session.database())?;
+        let schema = catalog_reader.get_schema_by_name(session.database(), &schema_name)?;
+        if schema.get_function_by_name(&function_name).is_some() {
+            return Err(CatalogError::Duplicated("function", function_name).into());
+        }
+        (database.id(), schema.id())
+    };
+
+    let function = ProstFunction {
+        id: 0,
+        schema_id,
+        database_id,
+        name: function_name,
+        arg_types: arg_types.iter().map(|t| t.to_protobuf()).collect(),
+        return_type: Some(return_type.to_protobuf()),
+        language: WASM.to_string(),
+        body,
+    };
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.create_function(function).await?;
+
+    Ok(PgResponse::empty_result(StatementType::CREATE_FUNCTION))
+}
+
+/// Decodes the base64-encoded module given inline. Files on the frontend are never read, so that
+/// clients can't access the host through the definition.
+fn load_module(definition: &str) -> Result<Vec<u8>> {
+    base64::decode(definition).map_err(|_| {
+        ErrorCode::InvalidInputSyntax("the function is not a base64-encoded module".to_string())
+            .into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
+    use risingwave_common::types::DataType;
+
+    use crate::test_utils::LocalFrontend;
+
+    const GCD: &str = r#"
+        (module
+          (func (export "gcd") (param $a i32) (param $b i32) (result i32)
+            (local $t i32)
+            (block $done
+              (loop $loop
+                (br_if $done (i32.eqz (local.get $b)))
+                (local.set $t (i32.rem_s (local.get $a) (local.get $b)))
+                (local.set $a (local.get $b))
+                (local.set $b (local.get $t))
+                (br $loop)))
+            (local.get $a)))
+    "#;
+
+    /// `add_one(int) -> int` in the binary format.
+    const ADD_ONE_BASE64: &str = "AGFzbQEAAAABBgFgAX8BfwMCAQAHCwEHYWRkX29uZQAACgkBBwAgAEEBags=";
+
+    #[tokio::test]
+    async fn test_create_function_from_text_format() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let sql = format!(
+            "CREATE FUNCTION gcd(a INT, b INT) RETURNS INT LANGUAGE wasm AS '{}'",
+            base64::encode(GCD)
+        );
+        frontend.run_sql(&sql).await.unwrap();
+
+        let session = frontend.session_ref();
+        let function = session
+            .env()
+            .catalog_reader()
+            .read_guard()
+            .get_function_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "gcd")
+            .unwrap()
+            .clone();
+        assert_eq!(function.arg_types, vec![DataType::Int32, DataType::Int32]);
+        assert_eq!(function.return_type, DataType::Int32);
+        assert_eq!(function.body, GCD.as_bytes());
+
+        // The arguments are casted implicitly.
+        frontend
+            .to_batch_plan("SELECT gcd(12::smallint, 18)")
+            .await
+            .unwrap();
+        assert!(frontend.to_batch_plan("SELECT gcd(12)").await.is_err());
+
+        assert_eq!(
+            frontend.run_sql(&sql).await.unwrap_err().to_string(),
+            "Catalog error: function with name gcd exists"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_function_from_base64() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql(format!(
+                "CREATE FUNCTION add_one(INT) RETURNS INT LANGUAGE wasm AS '{}'",
+                ADD_ONE_BASE64
+            ))
+            .await
+            .unwrap();
+        frontend.to_batch_plan("SELECT add_one(1)").await.unwrap();
+
+        frontend.run_sql("DROP FUNCTION add_one").await.unwrap();
+        assert!(frontend.to_batch_plan("SELECT add_one(1)").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_create_invalid_function() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        // The signature doesn't match the export.
+        let sql = format!(
+            "CREATE FUNCTION add_one(BIGINT) RETURNS INT LANGUAGE wasm AS '{}'",
+            ADD_ONE_BASE64
+        );
+        assert!(frontend.run_sql(sql).await.is_err());
+        // Not a module.
+        let sql = "CREATE FUNCTION add_one(INT) RETURNS INT LANGUAGE wasm AS 'not a module'";
+        assert!(frontend.run_sql(sql).await.is_err());
+        // Files on the frontend are not read.
+        let sql = "CREATE FUNCTION add_one(INT) RETURNS INT LANGUAGE wasm AS '/etc/passwd'";
+        assert_eq!(
+            frontend.run_sql(sql).await.unwrap_err().to_string(),
+            "Invalid input syntax: the function is not a base64-encoded module"
+        );
+        // Not supported language.
+        let sql = "CREATE FUNCTION add_one(INT) RETURNS INT LANGUAGE python AS 'add_one.py'";
+        assert!(frontend.run_sql(sql).await.is_err());
+    }
+}
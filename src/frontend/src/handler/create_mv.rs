@@ -15,8 +15,9 @@
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::Result;
 use risingwave_pb::catalog::Table as ProstTable;
-use risingwave_sqlparser::ast::{ObjectName, Query};
+use risingwave_sqlparser::ast::{EmitMode, ObjectName, Query, SqlOption};
 
+use super::create_table::bind_ttl_seconds;
 use crate::binder::Binder;
 use crate::optimizer::property::Distribution;
 use crate::optimizer::PlanRef;
@@ -29,6 +30,7 @@ pub fn gen_create_mv_plan(
     context: OptimizerContextRef,
     query: Box<Query>,
     name: ObjectName,
+    emit_mode: Option<EmitMode>,
 ) -> Result<(PlanRef, ProstTable)> {
     let (schema_name, table_name) = Binder::resolve_table_name(name)?;
     let (database_id, schema_id) = session
@@ -41,13 +43,15 @@ pub fn gen_create_mv_plan(
         let mut binder = Binder::new(
             session.env().catalog_reader().read_guard(),
             session.database().to_string(),
+            session.timezone(),
         );
         binder.bind_query(*query)?
     };
 
     let mut plan_root = Planner::new(context).plan_query(bound)?;
     plan_root.set_required_dist(Distribution::any().clone());
-    let materialize = plan_root.gen_create_mv_plan(table_name)?;
+    let emit_on_window_close = emit_mode == Some(EmitMode::OnWindowClose);
+    let materialize = plan_root.gen_create_mv_plan(table_name, emit_on_window_close)?;
     let table = materialize.table().to_prost(schema_id, database_id);
     let plan: PlanRef = materialize.into();
 
@@ -58,11 +62,16 @@ pub async fn handle_create_mv(
     context: OptimizerContext,
     name: ObjectName,
     query: Box<Query>,
+    with_options: Vec<SqlOption>,
+    emit_mode: Option<EmitMode>,
 ) -> Result<PgResponse> {
     let session = context.session_ctx.clone();
+    let ttl_seconds = bind_ttl_seconds(&with_options)?;
 
     let (table, stream_plan) = {
-        let (plan, table) = gen_create_mv_plan(&session, context.into(), query, name)?;
+        let (plan, mut table) =
+            gen_create_mv_plan(&session, context.into(), query, name, emit_mode)?;
+        table.ttl_seconds = ttl_seconds.unwrap_or_default();
         let stream_plan = plan.to_stream_prost();
         (table, stream_plan)
     };
@@ -84,8 +93,12 @@ pub mod tests {
     use itertools::Itertools;
     use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
     use risingwave_common::types::DataType;
+    use risingwave_sqlparser::ast::Statement;
+    use risingwave_sqlparser::parser::Parser;
 
+    use super::gen_create_mv_plan;
     use crate::catalog::gen_row_id_column_name;
+    use crate::session::OptimizerContext;
     use crate::test_utils::{create_proto_file, LocalFrontend, PROTO_FILE_DATA};
 
     #[tokio::test]
@@ -149,4 +162,97 @@ pub mod tests {
         };
         assert_eq!(columns, expected_columns);
     }
+
+    #[tokio::test]
+    async fn test_create_mv_emit_on_window_close() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend
+            .run_sql("create table t (w timestamp, v int)")
+            .await
+            .unwrap();
+        frontend
+            .run_sql(
+                "create source s (w timestamp, v int, watermark for w as w - interval '5s')
+                with ('kafka.topic' = 'abc', 'kafka.servers' = 'localhost:1001')
+                row format json",
+            )
+            .await
+            .unwrap();
+        let session = frontend.session_ref();
+
+        let gen_plan = |sql: &str| {
+            let Statement::CreateView { name, query, emit_mode, .. } =
+                Parser::parse_sql(sql).unwrap().remove(0) else {
+                    unreachable!()
+                };
+            let context = OptimizerContext::new(session.clone());
+            gen_create_mv_plan(&session, context.into(), query, name, emit_mode)
+        };
+
+        // The results are only emitted once per window, so the stream is append-only.
+        let (plan, _) = gen_plan(
+            "create materialized view mv1 as select w, count(*) from s group by w emit on window close",
+        )
+        .unwrap();
+        assert!(plan.append_only());
+        // The windows can't be closed without a watermark on the first group key.
+        assert!(gen_plan(
+            "create materialized view mv1 as select w, count(*) from t group by w emit on window close",
+        )
+        .is_err());
+        assert!(gen_plan(
+            "create materialized view mv1 as select v, w, count(*) from s group by v, w emit on window close",
+        )
+        .is_err());
+        let (plan, _) =
+            gen_plan("create materialized view mv2 as select w, count(*) from t group by w")
+                .unwrap();
+        assert!(!plan.append_only());
+
+        assert!(
+            gen_plan("create materialized view mv3 as select v from t emit on window close")
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_mv_on_table_function() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        let err = frontend
+            .run_sql("create materialized view mv1 as select * from generate_series(1, 10)")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Feature is not yet implemented: table function generate_series in streaming queries, No tracking issue"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_mv_approx_count_distinct() {
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend.run_sql("create table t (v int)").await.unwrap();
+        frontend
+            .run_sql(
+                "create source s (v int)
+                with ('kafka.topic' = 'abc', 'kafka.servers' = 'localhost:1001')
+                row format json",
+            )
+            .await
+            .unwrap();
+
+        // The sketch can't retract the updates and deletes of a table.
+        let err = frontend
+            .run_sql("create materialized view mv1 as select approx_count_distinct(v) from t")
+            .await
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Feature is not yet implemented: approx_count_distinct on a stream with updates or deletes, No tracking issue"
+        );
+        frontend
+            .run_sql("create materialized view mv2 as select approx_count_distinct(v) from s")
+            .await
+            .unwrap();
+    }
 }
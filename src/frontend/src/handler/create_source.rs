@@ -17,13 +17,17 @@ use std::collections::HashMap;
 use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::ErrorCode::ProtocolError;
-use risingwave_common::error::{Result, RwError};
+use risingwave_common::error::{ErrorCode, Result, RwError};
+use risingwave_common::types::DataType;
 use risingwave_pb::catalog::source::Info;
-use risingwave_pb::catalog::{Source as ProstSource, StreamSourceInfo};
+use risingwave_pb::catalog::{
+    Source as ProstSource, SourceWatermark as ProstSourceWatermark, StreamSourceInfo,
+};
 use risingwave_pb::plan_common::{ColumnCatalog as ProstColumnCatalog, RowFormatType};
 use risingwave_source::ProtobufParser;
 use risingwave_sqlparser::ast::{
-    CreateSourceStatement, ObjectName, ProtobufSchema, SourceSchema, SqlOption, Value,
+    BinaryOperator, CreateSourceStatement, DateTimeField, Expr, ObjectName, ProtobufSchema,
+    SourceSchema, SourceWatermark, SqlOption, Value,
 };
 
 use super::create_table::{bind_sql_columns, gen_materialized_source_plan};
@@ -79,6 +83,102 @@ fn handle_source_with_properties(options: Vec<SqlOption>) -> Result<HashMap<Stri
         .collect()
 }
 
+/// Binds `WATERMARK FOR col AS col - INTERVAL '...'` of a source. The watermark column must be a
+/// timestamp column, and the interval must not contain months.
+fn bind_source_watermark(
+    watermarks: &[SourceWatermark],
+    columns: &[ProstColumnCatalog],
+) -> Result<Option<ProstSourceWatermark>> {
+    let watermark = match watermarks {
+        [] => return Ok(None),
+        [watermark] => watermark,
+        _ => {
+            return Err(ErrorCode::NotImplemented(
+                "multiple watermarks on a source".to_string(),
+                None.into(),
+            )
+            .into())
+        }
+    };
+    let invalid_watermark =
+        || ErrorCode::InvalidInputSyntax(format!("invalid watermark: {}", watermark));
+
+    let column_name = &watermark.column.value;
+    let column_desc = columns
+        .iter()
+        .filter_map(|column| column.column_desc.as_ref())
+        .find(|column_desc| &column_desc.name == column_name)
+        .ok_or_else(|| ErrorCode::ItemNotFound(format!("column \"{}\"", column_name)))?;
+    if DataType::from(column_desc.get_column_type()?) != DataType::Timestamp {
+        return Err(ErrorCode::InvalidInputSyntax(format!(
+            "watermark column \"{}\" must be of type timestamp",
+            column_name
+        ))
+        .into());
+    }
+
+    let (watermark_column, delay) = match &watermark.expr {
+        Expr::Identifier(ident) => (ident, None),
+        Expr::BinaryOp {
+            left,
+            op: BinaryOperator::Minus,
+            right,
+        } => match (left.as_ref(), right.as_ref()) {
+            (
+                Expr::Identifier(ident),
+                Expr::Value(Value::Interval {
+                    value,
+                    leading_field,
+                    leading_precision: None,
+                    last_field: None,
+                    fractional_seconds_precision: None,
+                }),
+            ) => (ident, Some((value, leading_field))),
+            _ => return Err(invalid_watermark().into()),
+        },
+        _ => return Err(invalid_watermark().into()),
+    };
+    if &watermark_column.value != column_name {
+        return Err(invalid_watermark().into());
+    }
+
+    let delay_ms = match delay {
+        None => 0,
+        Some((value, leading_field)) => {
+            // Besides `INTERVAL '5' SECOND`, accept a unit in the string, like `INTERVAL '5s'`.
+            let value = value.trim();
+            let (number, unit) = value.split_at(
+                value
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(value.len()),
+            );
+            let unit_ms = match (leading_field, unit.trim().to_lowercase().as_str()) {
+                (Some(DateTimeField::Second), "")
+                | (None, "" | "s" | "sec" | "secs")
+                | (None, "second" | "seconds") => 1000,
+                (Some(DateTimeField::Minute), "")
+                | (None, "m" | "min" | "mins")
+                | (None, "minute" | "minutes") => 60 * 1000,
+                (Some(DateTimeField::Hour), "") | (None, "h" | "hour" | "hours") => 60 * 60 * 1000,
+                (Some(DateTimeField::Day), "") | (None, "d" | "day" | "days") => {
+                    24 * 60 * 60 * 1000
+                }
+                _ => return Err(invalid_watermark().into()),
+            };
+            number
+                .parse::<u64>()
+                .ok()
+                .and_then(|number| number.checked_mul(unit_ms))
+                .ok_or_else(invalid_watermark)?
+        }
+    };
+
+    Ok(Some(ProstSourceWatermark {
+        column_id: column_desc.column_id,
+        delay_ms,
+    }))
+}
+
 pub async fn handle_create_source(
     context: OptimizerContext,
     is_materialized: bool,
@@ -93,18 +193,23 @@ pub async fn handle_create_source(
                 row_format: RowFormatType::Protobuf as i32,
                 row_schema_location: protobuf_schema.row_schema_location.0.clone(),
                 row_id_index: 0,
+                watermark: bind_source_watermark(&stmt.watermarks, &columns)?,
+                columns,
+                pk_column_ids: vec![0],
+            }
+        }
+        SourceSchema::Json => {
+            let columns = bind_sql_columns(stmt.columns)?;
+            StreamSourceInfo {
+                properties: handle_source_with_properties(stmt.with_properties.0)?,
+                row_format: RowFormatType::Json as i32,
+                row_schema_location: "".to_string(),
+                row_id_index: 0,
+                watermark: bind_source_watermark(&stmt.watermarks, &columns)?,
                 columns,
                 pk_column_ids: vec![0],
             }
         }
-        SourceSchema::Json => StreamSourceInfo {
-            properties: handle_source_with_properties(stmt.with_properties.0)?,
-            row_format: RowFormatType::Json as i32,
-            row_schema_location: "".to_string(),
-            row_id_index: 0,
-            columns: bind_sql_columns(stmt.columns)?,
-            pk_column_ids: vec![0],
-        },
     };
 
     let session = context.session_ctx.clone();
@@ -131,10 +236,60 @@ pub mod tests {
 
     use risingwave_common::catalog::{DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME};
     use risingwave_common::types::DataType;
+    use risingwave_sqlparser::ast::Statement;
+    use risingwave_sqlparser::parser::Parser;
 
+    use super::*;
     use crate::catalog::gen_row_id_column_name;
     use crate::test_utils::{create_proto_file, LocalFrontend, PROTO_FILE_DATA};
 
+    fn bind_watermark_of(sql: &str) -> Result<Option<ProstSourceWatermark>> {
+        let stmt = match Parser::parse_sql(sql).unwrap().into_iter().next().unwrap() {
+            Statement::CreateSource { stmt, .. } => stmt,
+            _ => unreachable!(),
+        };
+        bind_source_watermark(&stmt.watermarks, &bind_sql_columns(stmt.columns)?)
+    }
+
+    #[test]
+    fn test_bind_source_watermark() {
+        let watermark_of = |watermark: &str| {
+            bind_watermark_of(&format!(
+                "CREATE SOURCE s (v INT, ts TIMESTAMP, WATERMARK FOR {}) ROW FORMAT JSON",
+                watermark
+            ))
+        };
+        let expected = |delay_ms| {
+            Some(ProstSourceWatermark {
+                column_id: 2,
+                delay_ms,
+            })
+        };
+
+        assert_eq!(watermark_of("ts AS ts").unwrap(), expected(0));
+        assert_eq!(
+            watermark_of("ts AS ts - INTERVAL '5s'").unwrap(),
+            expected(5000)
+        );
+        assert_eq!(
+            watermark_of("ts AS ts - INTERVAL '2' MINUTE").unwrap(),
+            expected(120000)
+        );
+        assert_eq!(
+            watermark_of("ts AS ts - INTERVAL '1 hour'").unwrap(),
+            expected(3600000)
+        );
+        assert!(watermark_of("v AS v").is_err());
+        assert!(watermark_of("ts AS ts + INTERVAL '5s'").is_err());
+        assert!(watermark_of("ts AS ts - INTERVAL '1' MONTH").is_err());
+        assert!(watermark_of("ts AS v").is_err());
+        assert!(watermark_of("t AS t").is_err());
+        assert_eq!(
+            bind_watermark_of("CREATE SOURCE s (v INT) ROW FORMAT JSON").unwrap(),
+            None
+        );
+    }
+
     #[tokio::test]
     async fn test_create_source_handler() {
         let proto_file = create_proto_file(PROTO_FILE_DATA);
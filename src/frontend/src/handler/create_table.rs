@@ -18,11 +18,11 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::catalog::{ColumnDesc, ColumnId};
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 use risingwave_pb::catalog::source::Info;
 use risingwave_pb::catalog::{Source as ProstSource, Table as ProstTable, TableSourceInfo};
 use risingwave_pb::plan_common::ColumnCatalog;
-use risingwave_sqlparser::ast::{ColumnDef, ObjectName};
+use risingwave_sqlparser::ast::{ColumnDef, ObjectName, SqlOption, Value};
 
 use super::create_source::make_prost_source;
 use crate::binder::expr::bind_data_type;
@@ -65,6 +65,43 @@ pub fn bind_sql_columns(columns: Vec<ColumnDef>) -> Result<Vec<ColumnCatalog>> {
     Ok(columns_catalog)
 }
 
+/// Binds the `ttl` option in the `WITH` clause of `CREATE TABLE` or `CREATE MATERIALIZED VIEW`,
+/// which is either a number of seconds, or a string of a number and a time unit, e.g. `'1 day'`.
+/// Returns `None` if there's no `ttl` option.
+pub(crate) fn bind_ttl_seconds(with_options: &[SqlOption]) -> Result<Option<u32>> {
+    let option = match with_options
+        .iter()
+        .find(|option| option.name.value.eq_ignore_ascii_case("ttl"))
+    {
+        Some(option) => option,
+        None => return Ok(None),
+    };
+    let invalid_ttl = || ErrorCode::InvalidInputSyntax(format!("invalid ttl: {}", option.value));
+    let (number, unit) = match &option.value {
+        Value::Number(number, _) => (number.as_str(), "second"),
+        Value::SingleQuotedString(s) => s
+            .trim()
+            .split_once(char::is_whitespace)
+            .map(|(number, unit)| (number, unit.trim()))
+            .unwrap_or((s.trim(), "second")),
+        _ => return Err(invalid_ttl().into()),
+    };
+    let number: u32 = number.parse().map_err(|_| invalid_ttl())?;
+    let unit_seconds = match unit.to_lowercase().trim_end_matches('s') {
+        "second" | "sec" => 1,
+        "minute" | "min" => 60,
+        "hour" => 60 * 60,
+        "day" => 24 * 60 * 60,
+        "week" => 7 * 24 * 60 * 60,
+        _ => return Err(invalid_ttl().into()),
+    };
+    let ttl_seconds = number
+        .checked_mul(unit_seconds)
+        .filter(|ttl_seconds| *ttl_seconds > 0)
+        .ok_or_else(invalid_ttl)?;
+    Ok(Some(ttl_seconds))
+}
+
 pub(crate) fn gen_create_table_plan(
     session: &SessionImpl,
     context: OptimizerContextRef,
@@ -102,7 +139,7 @@ pub(crate) fn gen_materialized_source_plan(
             Order::any().clone(),
             required_cols,
         )
-        .gen_create_mv_plan(source.name.clone())?
+        .gen_create_mv_plan(source.name.clone(), false)?
     };
     let table = materialize
         .table()
@@ -115,12 +152,15 @@ pub async fn handle_create_table(
     context: OptimizerContext,
     table_name: ObjectName,
     columns: Vec<ColumnDef>,
+    with_options: Vec<SqlOption>,
 ) -> Result<PgResponse> {
     let session = context.session_ctx.clone();
+    let ttl_seconds = bind_ttl_seconds(&with_options)?;
 
     let (plan, source, table) = {
-        let (plan, source, table) =
+        let (plan, source, mut table) =
             gen_create_table_plan(&session, context.into(), table_name.clone(), columns)?;
+        table.ttl_seconds = ttl_seconds.unwrap_or_default();
         let plan = plan.to_stream_prost();
 
         (plan, source, table)
@@ -193,4 +233,28 @@ mod tests {
 
         assert_eq!(columns, expected_columns);
     }
+
+    #[tokio::test]
+    async fn test_create_table_with_ttl() {
+        let sql = "create table t (v1 int) with (ttl = '1 day');";
+        let frontend = LocalFrontend::new(Default::default()).await;
+        frontend.run_sql(sql).await.unwrap();
+
+        let session = frontend.session_ref();
+        let table = session
+            .env()
+            .catalog_reader()
+            .read_guard()
+            .get_table_by_name(DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_NAME, "t")
+            .unwrap()
+            .clone();
+        assert_eq!(table.ttl_seconds, Some(24 * 60 * 60));
+
+        for sql in [
+            "create table t2 (v1 int) with (ttl = '1 fortnight');",
+            "create table t2 (v1 int) with (ttl = 0);",
+        ] {
+            assert!(frontend.run_sql(sql).await.is_err());
+        }
+    }
 }
@@ -32,6 +32,7 @@ pub async fn handle_dml(context: OptimizerContext, stmt: Statement) -> Result<Pg
         let mut binder = Binder::new(
             session.env().catalog_reader().read_guard(),
             session.database().to_string(),
+            session.timezone(),
         );
         binder.bind(stmt)?
     };
@@ -0,0 +1,40 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use pgwire::pg_response::{PgResponse, StatementType};
+use risingwave_common::error::Result;
+use risingwave_sqlparser::ast::ObjectName;
+
+use crate::binder::Binder;
+use crate::session::OptimizerContext;
+
+pub async fn handle_drop_function(
+    context: OptimizerContext,
+    name: ObjectName,
+) -> Result<PgResponse> {
+    let session = context.session_ctx;
+    let (schema_name, function_name) = Binder::resolve_table_name(name)?;
+
+    let function_id = session
+        .env()
+        .catalog_reader()
+        .read_guard()
+        .get_function_by_name(session.database(), &schema_name, &function_name)?
+        .id;
+
+    let catalog_writer = session.env().catalog_writer();
+    catalog_writer.drop_function(function_id).await?;
+
+    Ok(PgResponse::empty_result(StatementType::DROP_FUNCTION))
+}
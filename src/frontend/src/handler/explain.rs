@@ -39,8 +39,9 @@ pub(super) fn handle_explain(
             materialized: true,
             query,
             name,
+            emit_mode,
             ..
-        } => gen_create_mv_plan(&*session, planner.ctx(), query, name)?.0,
+        } => gen_create_mv_plan(&*session, planner.ctx(), query, name, emit_mode)?.0,
 
         Statement::CreateTable { name, columns, .. } => {
             gen_create_table_plan(&*session, planner.ctx(), name, columns)?.0
@@ -51,6 +52,7 @@ pub(super) fn handle_explain(
                 let mut binder = Binder::new(
                     session.env().catalog_reader().read_guard(),
                     session.database().to_string(),
+                    session.timezone(),
                 );
                 binder.bind(stmt)?
             };
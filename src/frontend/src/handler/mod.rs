@@ -20,12 +20,14 @@ use risingwave_sqlparser::ast::{DropStatement, ObjectName, ObjectType, Statement
 
 use crate::session::{OptimizerContext, SessionImpl};
 
+pub mod create_function;
 pub mod create_index;
 pub mod create_mv;
 pub mod create_source;
 pub mod create_table;
 mod describe;
 pub mod dml;
+pub mod drop_function;
 pub mod drop_mv;
 pub mod drop_source;
 pub mod drop_table;
@@ -33,7 +35,7 @@ mod explain;
 mod flush;
 #[allow(dead_code)]
 pub mod query;
-mod set;
+pub mod set;
 mod show;
 pub mod util;
 
@@ -47,9 +49,12 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
             is_materialized,
             stmt,
         } => create_source::handle_create_source(context, is_materialized, stmt).await,
-        Statement::CreateTable { name, columns, .. } => {
-            create_table::handle_create_table(context, name, columns).await
-        }
+        Statement::CreateTable {
+            name,
+            columns,
+            with_options,
+            ..
+        } => create_table::handle_create_table(context, name, columns, with_options).await,
         Statement::Describe { name } => describe::handle_describe(context, name).await,
         // TODO: support complex sql for `show columns from <table>`
         Statement::ShowColumn { name } => describe::handle_describe(context, name).await,
@@ -62,6 +67,7 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
                 ObjectType::Table => drop_table::handle_drop_table(context, name).await,
                 ObjectType::MaterializedView => drop_mv::handle_drop_mv(context, name).await,
                 ObjectType::Source => drop_source::handle_drop_source(context, name).await,
+                ObjectType::Function => drop_function::handle_drop_function(context, name).await,
                 _ => Err(ErrorCode::InvalidInputSyntax(format!(
                     "DROP {} is unsupported",
                     object_type
@@ -76,8 +82,27 @@ pub(super) async fn handle(session: Arc<SessionImpl>, stmt: Statement) -> Result
             or_replace: false,
             name,
             query,
+            with_options,
+            emit_mode,
             ..
-        } => create_mv::handle_create_mv(context, name, query).await,
+        } => create_mv::handle_create_mv(context, name, query, with_options, emit_mode).await,
+        Statement::CreateFunction {
+            name,
+            args,
+            return_type,
+            language,
+            definition,
+        } => {
+            create_function::handle_create_function(
+                context,
+                name,
+                args,
+                return_type,
+                language,
+                definition,
+            )
+            .await
+        }
         Statement::Flush => flush::handle_flush(context).await,
         Statement::SetVariable {
             local: _,
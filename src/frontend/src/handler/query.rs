@@ -15,8 +15,11 @@
 use futures_async_stream::for_await;
 use pgwire::pg_field_descriptor::PgFieldDescriptor;
 use pgwire::pg_response::{PgResponse, StatementType};
-use risingwave_common::error::Result;
-use risingwave_sqlparser::ast::Statement;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::util::epoch::Epoch;
+use risingwave_expr::vector_op::cast::{str_to_timestamp, str_to_timestampz};
+use risingwave_expr::vector_op::timestampz::timestamp_at_time_zone;
+use risingwave_sqlparser::ast::{AsOf, Statement};
 use tracing::info;
 
 use crate::binder::{Binder, BoundStatement};
@@ -32,19 +35,29 @@ use crate::session::{OptimizerContext, SessionImpl};
 /// TODO: Use session config to set this.
 pub static IMPLICIT_FLUSH: &str = "RW_IMPLICIT_FLUSH";
 
-pub async fn handle_query(context: OptimizerContext, stmt: Statement) -> Result<PgResponse> {
+pub async fn handle_query(context: OptimizerContext, mut stmt: Statement) -> Result<PgResponse> {
     let stmt_type = to_statement_type(&stmt);
     let session = context.session_ctx.clone();
 
+    let as_of = match &mut stmt {
+        Statement::Query(query) => query
+            .as_of
+            .take()
+            .map(|as_of| resolve_as_of(as_of, &session.timezone()))
+            .transpose()?,
+        _ => None,
+    };
+
     let bound = {
         let mut binder = Binder::new(
             session.env().catalog_reader().read_guard(),
             session.database().to_string(),
+            session.timezone(),
         );
         binder.bind(stmt)?
     };
 
-    let (data_stream, pg_descs) = distribute_execute(context, bound).await?;
+    let (data_stream, pg_descs) = distribute_execute(context, bound, as_of).await?;
 
     let mut rows = vec![];
     #[for_await]
@@ -98,9 +111,35 @@ fn to_statement_type(stmt: &Statement) -> StatementType {
     }
 }
 
+/// Resolve the epoch to read from the `AS OF` clause. Timestamps without a time zone offset are in
+/// the session `time_zone`, the same as `timestamp AT TIME ZONE`.
+fn resolve_as_of(as_of: AsOf, time_zone: &str) -> Result<u64> {
+    match as_of {
+        AsOf::Epoch(epoch) => Ok(epoch),
+        AsOf::Timestamp(ts) => {
+            let micros = match str_to_timestampz(&ts) {
+                Ok(micros) => micros,
+                Err(_) => timestamp_at_time_zone(str_to_timestamp(&ts)?, time_zone)?,
+            };
+            u64::try_from(micros.div_euclid(1000))
+                .ok()
+                .and_then(Epoch::from_unix_millis)
+                .map(|epoch| epoch.0)
+                .ok_or_else(|| {
+                    ErrorCode::InvalidInputSyntax(format!(
+                        "timestamp '{}' is earlier than any epoch",
+                        ts
+                    ))
+                    .into()
+                })
+        }
+    }
+}
+
 async fn distribute_execute(
     context: OptimizerContext,
     stmt: BoundStatement,
+    as_of: Option<u64>,
 ) -> Result<(impl DataChunkStream, Vec<PgFieldDescriptor>)> {
     let session = context.session_ctx.clone();
     // Subblock to make sure PlanRef (an Rc) is dropped before `await` below.
@@ -130,7 +169,31 @@ async fn distribute_execute(
     let execution_context: ExecutionContextRef = ExecutionContext::new(session.clone()).into();
     let query_manager = execution_context.session().env().query_manager().clone();
     Ok((
-        query_manager.schedule(execution_context, query).await?,
+        query_manager
+            .schedule(execution_context, query, as_of)
+            .await?,
         pg_descs,
     ))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_as_of() {
+        let resolve = |ts: &str, time_zone: &str| {
+            resolve_as_of(AsOf::Timestamp(ts.to_string()), time_zone).unwrap()
+        };
+        let epoch = resolve("2022-07-01 00:00:00", "UTC");
+        assert_eq!(resolve("2022-07-01 08:00:00", "Asia/Shanghai"), epoch);
+        assert_eq!(resolve("2022-06-30 20:00:00", "America/New_York"), epoch);
+        // An explicit offset overrides the session time zone.
+        assert_eq!(
+            resolve("2022-07-01 08:00:00 +08:00", "America/New_York"),
+            epoch
+        );
+        assert_eq!(resolve_as_of(AsOf::Epoch(42), "Asia/Shanghai").unwrap(), 42);
+        assert!(resolve_as_of(AsOf::Timestamp("1960-01-01 00:00:00".to_string()), "UTC").is_err());
+    }
+}
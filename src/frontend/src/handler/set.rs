@@ -14,15 +14,31 @@
 
 use pgwire::pg_response::{PgResponse, StatementType};
 use risingwave_common::error::Result;
-use risingwave_sqlparser::ast::{Ident, SetVariableValue};
+use risingwave_expr::vector_op::timestampz::lookup_time_zone;
+use risingwave_sqlparser::ast::{Ident, SetVariableValue, Value};
 
 use crate::session::OptimizerContext;
 
+/// The time zone used to interpret `timestamp with time zone`, e.g. in `EXTRACT` and `to_char`.
+/// Defaults to `UTC`.
+pub static TIMEZONE: &str = "timezone";
+
 pub(super) fn handle_set(
     context: OptimizerContext,
     name: Ident,
     value: Vec<SetVariableValue>,
 ) -> Result<PgResponse> {
+    if name.value.eq_ignore_ascii_case(TIMEZONE) {
+        let time_zone = match &value[0] {
+            SetVariableValue::Literal(Value::SingleQuotedString(s)) => s.clone(),
+            SetVariableValue::Ident(ident) => ident.value.clone(),
+            value => to_string(value),
+        };
+        lookup_time_zone(&time_zone)?;
+        context.session_ctx.set_config(TIMEZONE, &time_zone);
+        return Ok(PgResponse::empty_result(StatementType::SET_OPTION));
+    }
+
     let string_val = to_string(&value[0]);
     // Currently store the config variable simply as String -> ConfigEntry(String).
     // In future we can add converter/parser to make the API more robust.
@@ -26,6 +26,11 @@ fn pg_value_format(d: ScalarRefImpl) -> String {
         ScalarRefImpl::Bool(b) => if b { "t" } else { "f" }.to_string(),
         ScalarRefImpl::Float32(v) => pg_float_format(v),
         ScalarRefImpl::Float64(v) => pg_float_format(v),
+        ScalarRefImpl::List(list) => {
+            let mut s = String::new();
+            list.fmt_pg_array(&mut s, &pg_value_format).unwrap();
+            s
+        }
         _ => d.to_string(),
     }
 }
@@ -80,12 +85,15 @@ pub fn data_type_to_type_oid(data_type: DataType) -> TypeOid {
         DataType::Interval => TypeOid::Varchar,
         DataType::Struct { .. } => TypeOid::Varchar,
         DataType::List { .. } => TypeOid::Varchar,
+        DataType::Jsonb => TypeOid::Jsonb,
+        DataType::Bytea => TypeOid::Bytea,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use risingwave_common::array::*;
+    use risingwave_common::types::{Scalar, ScalarImpl};
     use risingwave_common::{column, column_nonnull};
 
     use super::*;
@@ -151,5 +159,8 @@ mod tests {
         assert_eq!(&f(S::Float64(f64::NEG_INFINITY.into())), "-Infinity");
         assert_eq!(&f(S::Bool(true)), "t");
         assert_eq!(&f(S::Bool(false)), "f");
+
+        let list = ListValue::new(vec![Some(ScalarImpl::Bool(true)), None]);
+        assert_eq!(&f(S::List(list.as_scalar_ref())), "{t,NULL}");
     }
 }
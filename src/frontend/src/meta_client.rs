@@ -24,6 +24,8 @@ use risingwave_rpc_client::{HummockMetaClient, MetaClient};
 pub trait FrontendMetaClient: Send + Sync {
     async fn pin_snapshot(&self, last_pinned: u64) -> Result<u64>;
 
+    async fn pin_specific_snapshot(&self, epoch: u64) -> Result<u64>;
+
     async fn flush(&self) -> Result<()>;
 
     async fn unpin_snapshot(&self, epoch: u64) -> Result<()>;
@@ -37,6 +39,10 @@ impl FrontendMetaClient for FrontendMetaClientImpl {
         self.0.pin_snapshot(last_pinned).await
     }
 
+    async fn pin_specific_snapshot(&self, epoch: u64) -> Result<u64> {
+        self.0.pin_specific_snapshot(epoch).await
+    }
+
     async fn flush(&self) -> Result<()> {
         self.0.flush().await
     }
@@ -86,6 +86,9 @@ impl ObserverManager {
                 for source in snapshot.source {
                     catalog_guard.create_source(source)
                 }
+                for function in snapshot.function {
+                    catalog_guard.create_function(&function)
+                }
                 self.worker_node_manager.refresh_worker_node(snapshot.nodes);
             }
             _ => {
@@ -128,6 +131,15 @@ impl ObserverManager {
                 }
                 _ => panic!("receive an unsupported notify {:?}", resp),
             },
+            Some(Info::Function(function)) => match resp.operation() {
+                Operation::Add => catalog_guard.create_function(function),
+                Operation::Delete => catalog_guard.drop_function(
+                    function.database_id,
+                    function.schema_id,
+                    function.id,
+                ),
+                _ => panic!("receive an unsupported notify {:?}", resp),
+            },
             _ => unreachable!(),
         }
         assert!(
@@ -151,7 +163,8 @@ impl ObserverManager {
             Some(Info::DatabaseV2(_))
             | Some(Info::SchemaV2(_))
             | Some(Info::TableV2(_))
-            | Some(Info::Source(_)) => {
+            | Some(Info::Source(_))
+            | Some(Info::Function(_)) => {
                 self.handle_catalog_v2_notification(resp);
             }
             Some(Info::Node(node)) => {
@@ -26,13 +26,14 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools as _;
 use property::{Distribution, Order};
 use risingwave_common::catalog::Schema;
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_expr::expr::AggKind;
 
 use self::heuristic::{ApplyOrder, HeuristicOptimizer};
-use self::plan_node::{Convention, LogicalProject, StreamMaterialize};
+use self::plan_node::{Convention, LogicalProject, PlanTreeNodeUnary, StreamMaterialize};
 use self::rule::*;
 use crate::catalog::TableId;
-use crate::expr::InputRef;
+use crate::expr::{ExprImpl, InputRef};
 
 /// `PlanRoot` is used to describe a plan. planner will construct a `PlanRoot` with `LogicalNode`.
 /// and required distribution and order. And `PlanRoot` can generate corresponding streaming or
@@ -128,6 +129,13 @@ impl PlanRoot {
             heuristic_optimizer.optimize(plan)
         };
 
+        // Constant Folding
+        plan = {
+            let rules = vec![ConstantFoldingRule::create()];
+            let heuristic_optimizer = HeuristicOptimizer::new(ApplyOrder::BottomUp, rules);
+            heuristic_optimizer.optimize(plan)
+        };
+
         // Predicate Push-down
         plan = {
             let rules = vec![
@@ -171,10 +179,11 @@ impl PlanRoot {
     }
 
     /// Generate create index or create materialize view plan.
-    fn gen_stream_plan(&mut self) -> PlanRef {
+    fn gen_stream_plan(&mut self) -> Result<PlanRef> {
         let plan = match self.plan.convention() {
             Convention::Logical => {
                 let plan = self.gen_optimized_logical_plan();
+                Self::check_streamable(&plan)?;
                 let (plan, out_col_change) = plan.logical_rewrite_for_stream();
                 self.required_dist = out_col_change
                     .rewrite_required_distribution(&self.required_dist)
@@ -191,6 +200,7 @@ impl PlanRoot {
                 .enforce_if_not_satisfies(self.plan.clone(), Order::any()),
             _ => unreachable!(),
         };
+        Self::check_agg_retractable(&plan)?;
 
         // Rewrite joins with index to delta join
         let plan = {
@@ -199,12 +209,69 @@ impl PlanRoot {
             heuristic_optimizer.optimize(plan)
         };
 
-        plan
+        Ok(plan)
     }
 
-    /// Optimize and generate a create materialize view plan.
-    pub fn gen_create_mv_plan(&mut self, mv_name: String) -> Result<StreamMaterialize> {
-        let stream_plan = self.gen_stream_plan();
+    /// Returns an error if the logical `plan` contains nodes that have no streaming counterpart
+    /// yet, i.e. table functions and `VALUES` in the `FROM` clause.
+    fn check_streamable(plan: &PlanRef) -> Result<()> {
+        if let Some(table_function) = plan.as_logical_table_function() {
+            return Err(ErrorCode::NotImplemented(
+                format!(
+                    "table function {} in streaming queries",
+                    table_function.table_function().name()
+                ),
+                None.into(),
+            )
+            .into());
+        }
+        if plan.as_logical_values().is_some() {
+            return Err(ErrorCode::NotImplemented(
+                "VALUES in streaming queries".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        plan.inputs().iter().try_for_each(Self::check_streamable)
+    }
+
+    /// Returns an error if the stream `plan` contains aggregations that can not be retracted over
+    /// an input with updates or deletes, i.e. `approx_count_distinct`.
+    fn check_agg_retractable(plan: &PlanRef) -> Result<()> {
+        let agg_calls = if let Some(agg) = plan.as_stream_hash_agg() {
+            Some(agg.agg_calls())
+        } else {
+            plan.as_stream_simple_agg().map(|agg| agg.agg_calls())
+        };
+        if let Some(agg_calls) = agg_calls {
+            if !plan.inputs()[0].append_only()
+                && agg_calls
+                    .iter()
+                    .any(|agg_call| agg_call.agg_kind == AggKind::ApproxCountDistinct)
+            {
+                return Err(ErrorCode::NotImplemented(
+                    "approx_count_distinct on a stream with updates or deletes".to_string(),
+                    None.into(),
+                )
+                .into());
+            }
+        }
+        plan.inputs()
+            .iter()
+            .try_for_each(Self::check_agg_retractable)
+    }
+
+    /// Optimize and generate a create materialize view plan. If `emit_on_window_close` is set, the
+    /// top-most hash aggregation only emits the final results of windows.
+    pub fn gen_create_mv_plan(
+        &mut self,
+        mv_name: String,
+        emit_on_window_close: bool,
+    ) -> Result<StreamMaterialize> {
+        let mut stream_plan = self.gen_stream_plan()?;
+        if emit_on_window_close {
+            stream_plan = Self::set_emit_on_window_close(stream_plan)?;
+        }
         StreamMaterialize::create(
             stream_plan,
             mv_name,
@@ -220,7 +287,7 @@ impl PlanRoot {
         mv_name: String,
         index_on: TableId,
     ) -> Result<StreamMaterialize> {
-        let stream_plan = self.gen_stream_plan();
+        let stream_plan = self.gen_stream_plan()?;
         StreamMaterialize::create(
             stream_plan,
             mv_name,
@@ -230,6 +297,74 @@ impl PlanRoot {
         )
     }
 
+    /// Makes the top-most hash aggregation of `plan` emit on window close, and rebuilds the nodes
+    /// above it, which must all be unary. The windows are closed by the watermarks of the first
+    /// group key, so there must be watermarks on it.
+    fn set_emit_on_window_close(plan: PlanRef) -> Result<PlanRef> {
+        if let Some(agg) = plan.as_stream_hash_agg() {
+            if !Self::watermark_columns(&agg.input()).contains(agg.distribution_keys()[0]) {
+                return Err(ErrorCode::InvalidInputSyntax(
+                    "EMIT ON WINDOW CLOSE requires a watermark on the first GROUP BY column"
+                        .to_string(),
+                )
+                .into());
+            }
+            return Ok(agg.with_emit_on_window_close().into());
+        }
+        let inputs = plan.inputs();
+        if inputs.len() != 1 {
+            return Err(ErrorCode::NotImplemented(
+                "EMIT ON WINDOW CLOSE without a GROUP BY aggregation on top".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        let input = Self::set_emit_on_window_close(inputs[0].clone())?;
+        Ok(plan.clone_with_inputs(&[input]))
+    }
+
+    /// Returns the output columns of a stream `plan` which have watermarks, following how the
+    /// streaming executors derive watermarks.
+    fn watermark_columns(plan: &PlanRef) -> FixedBitSet {
+        let mut columns = FixedBitSet::with_capacity(plan.schema().len());
+        if let Some(source) = plan.as_stream_source() {
+            if let Some(idx) = source.watermark_col_idx() {
+                columns.insert(idx);
+            }
+        } else if let Some(project) = plan.as_stream_project() {
+            let input_columns = Self::watermark_columns(&project.input());
+            for (idx, expr) in project.exprs().iter().enumerate() {
+                if let ExprImpl::InputRef(input_ref) = expr {
+                    if input_columns.contains(input_ref.index()) {
+                        columns.insert(idx);
+                    }
+                }
+            }
+        } else if let Some(hop_window) = plan.as_stream_hop_window() {
+            let input_columns = Self::watermark_columns(&hop_window.input());
+            columns.extend(input_columns.ones());
+            if input_columns.contains(hop_window.time_col_idx()) {
+                columns.insert(hop_window.window_start_col_idx());
+                columns.insert(hop_window.window_end_col_idx());
+            }
+        } else if let Some(agg) = plan.as_stream_hash_agg() {
+            // Group keys come first in the output.
+            if Self::watermark_columns(&agg.input()).contains(agg.distribution_keys()[0]) {
+                columns.insert(0);
+            }
+        } else if let Some(local_agg) = plan.as_stream_local_hash_agg() {
+            let input_columns = Self::watermark_columns(&local_agg.input());
+            for (idx, key) in local_agg.group_keys().iter().enumerate() {
+                if input_columns.contains(*key) {
+                    columns.insert(idx);
+                }
+            }
+        } else if plan.as_stream_filter().is_some() || plan.as_stream_exchange().is_some() {
+            columns = Self::watermark_columns(&plan.inputs()[0]);
+        }
+        columns
+    }
+
     /// Set the plan root's required dist.
     pub fn set_required_dist(&mut self, required_dist: Distribution) {
         self.required_dist = required_dist;
@@ -14,7 +14,9 @@
 
 use std::fmt;
 
-use crate::expr::{ExprType, FunctionCall, InputRef};
+use risingwave_common::types::{DataType, IntervalUnit, ScalarImpl};
+
+use crate::expr::{Expr, ExprImpl, ExprType, FunctionCall, InputRef, InputRefDisplay};
 use crate::utils::Condition;
 
 /// The join predicate used in optimizer
@@ -31,6 +33,29 @@ pub struct EqJoinPredicate {
     left_cols_num: usize,
 }
 
+/// The time bound of an interval join, i.e. `left_time_col - right_time_col` is in `[lower,
+/// upper]`. The time columns are indexed in the schema of their own side.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IntervalJoinBound {
+    pub left_time_col: usize,
+    pub right_time_col: usize,
+    pub lower: IntervalUnit,
+    pub upper: IntervalUnit,
+}
+
+impl fmt::Display for IntervalJoinBound {
+    fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{} - {} in [{}, {}]",
+            InputRefDisplay(self.left_time_col),
+            InputRefDisplay(self.right_time_col),
+            self.lower,
+            self.upper
+        )
+    }
+}
+
 impl fmt::Display for EqJoinPredicate {
     fn fmt(&self, f: &mut fmt::Formatter) -> std::fmt::Result {
         let mut eq_keys = self.eq_keys().iter();
@@ -143,4 +168,171 @@ impl EqJoinPredicate {
             .map(|(_, right)| right.index() - self.left_cols_num)
             .collect()
     }
+
+    /// Finds the band predicates on a pair of timestamp columns from both sides in the other
+    /// conditions, e.g. `l.ts BETWEEN r.ts AND r.ts + INTERVAL '1' HOUR`, which bound
+    /// `l.ts - r.ts` from both below and above. Strict comparisons are treated as inclusive, so the
+    /// bound may be looser than the conditions, which are still evaluated by the join.
+    pub fn interval_bound(&self) -> Option<IntervalJoinBound> {
+        let mut lower_bounds = vec![];
+        let mut upper_bounds = vec![];
+        for expr in &self.other_cond.conjunctions {
+            if let Some((cols, is_lower, bound)) = self.band_predicate(expr) {
+                if is_lower {
+                    lower_bounds.push((cols, bound));
+                } else {
+                    upper_bounds.push((cols, bound));
+                }
+            }
+        }
+        lower_bounds.into_iter().find_map(|(cols, lower)| {
+            upper_bounds
+                .iter()
+                .find(|(upper_cols, _)| *upper_cols == cols)
+                .map(|(_, upper)| IntervalJoinBound {
+                    left_time_col: cols.0,
+                    right_time_col: cols.1 - self.left_cols_num,
+                    lower,
+                    upper: *upper,
+                })
+        })
+    }
+
+    /// Matches `x + a CMP y + b`, where `x` and `y` are timestamp columns from different sides and
+    /// `a` and `b` are optional interval literals. Returns the `(left, right)` columns, whether it
+    /// is a lower bound of `left - right`, and the bound.
+    fn band_predicate(&self, expr: &ExprImpl) -> Option<((usize, usize), bool, IntervalUnit)> {
+        let ExprImpl::FunctionCall(function_call) = expr else {
+            return None;
+        };
+        let is_lower = match function_call.get_expr_type() {
+            ExprType::GreaterThan | ExprType::GreaterThanOrEqual => true,
+            ExprType::LessThan | ExprType::LessThanOrEqual => false,
+            _ => return None,
+        };
+        let (x, a) = Self::time_col_with_offset(&function_call.inputs()[0])?;
+        let (y, b) = Self::time_col_with_offset(&function_call.inputs()[1])?;
+        let is_left = |col: usize| col < self.left_cols_num;
+        if is_left(x) && !is_left(y) {
+            // `x - y CMP b - a`
+            Some(((x, y), is_lower, b - a))
+        } else if !is_left(x) && is_left(y) {
+            // `y - x CMP' a - b`, where `CMP'` is the flipped comparison.
+            Some(((y, x), !is_lower, a - b))
+        } else {
+            None
+        }
+    }
+
+    /// Matches `col`, `col + interval`, `interval + col` and `col - interval`.
+    fn time_col_with_offset(expr: &ExprImpl) -> Option<(usize, IntervalUnit)> {
+        let interval_of = |expr: &ExprImpl| match expr {
+            ExprImpl::Literal(literal) => match literal.get_data() {
+                Some(ScalarImpl::Interval(interval)) => Some(*interval),
+                _ => None,
+            },
+            _ => None,
+        };
+        match expr {
+            ExprImpl::InputRef(input_ref) if input_ref.return_type() == DataType::Timestamp => {
+                Some((input_ref.index(), IntervalUnit::default()))
+            }
+            ExprImpl::FunctionCall(function_call) if function_call.inputs().len() == 2 => {
+                let (lhs, rhs) = (&function_call.inputs()[0], &function_call.inputs()[1]);
+                match function_call.get_expr_type() {
+                    ExprType::Add => match Self::time_col_with_offset(lhs) {
+                        Some((col, offset)) => Some((col, offset + interval_of(rhs)?)),
+                        None => {
+                            let (col, offset) = Self::time_col_with_offset(rhs)?;
+                            Some((col, offset + interval_of(lhs)?))
+                        }
+                    },
+                    ExprType::Subtract => {
+                        let (col, offset) = Self::time_col_with_offset(lhs)?;
+                        Some((col, offset - interval_of(rhs)?))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::Literal;
+
+    #[test]
+    fn test_interval_bound() {
+        let ts = |index| -> ExprImpl { InputRef::new(index, DataType::Timestamp).into() };
+        let hours = |hours| -> ExprImpl {
+            Literal::new(
+                Some(ScalarImpl::Interval(IntervalUnit::from_minutes(60 * hours))),
+                DataType::Interval,
+            )
+            .into()
+        };
+        let call = |func_type, lhs, rhs| -> ExprImpl {
+            FunctionCall::new(func_type, vec![lhs, rhs]).unwrap().into()
+        };
+
+        // `$1 BETWEEN $3 AND $3 + '1 hour'`, where `$0` and `$2` are the eq keys.
+        let on_clause = Condition {
+            conjunctions: vec![
+                call(
+                    ExprType::Equal,
+                    InputRef::new(0, DataType::Int32).into(),
+                    InputRef::new(2, DataType::Int32).into(),
+                ),
+                call(ExprType::GreaterThanOrEqual, ts(1), ts(3)),
+                call(
+                    ExprType::LessThanOrEqual,
+                    ts(1),
+                    call(ExprType::Add, ts(3), hours(1)),
+                ),
+            ],
+        };
+        let predicate = EqJoinPredicate::create(2, 2, on_clause);
+        assert_eq!(
+            predicate.interval_bound(),
+            Some(IntervalJoinBound {
+                left_time_col: 1,
+                right_time_col: 1,
+                lower: IntervalUnit::default(),
+                upper: IntervalUnit::from_minutes(60),
+            })
+        );
+
+        // `$3 - '2 hours' < $1`, which is a lower bound only.
+        let on_clause = Condition {
+            conjunctions: vec![call(
+                ExprType::LessThan,
+                call(ExprType::Subtract, ts(3), hours(2)),
+                ts(1),
+            )],
+        };
+        let predicate = EqJoinPredicate::create(2, 2, on_clause.clone());
+        assert_eq!(predicate.interval_bound(), None);
+
+        // Together with `$1 < $3 + '1 hour'`, `$1 - $3` is in `['-2 hours', '1 hour']`.
+        let on_clause = on_clause.and(Condition {
+            conjunctions: vec![call(
+                ExprType::LessThan,
+                ts(1),
+                call(ExprType::Add, hours(1), ts(3)),
+            )],
+        });
+        let predicate = EqJoinPredicate::create(2, 2, on_clause);
+        assert_eq!(
+            predicate.interval_bound(),
+            Some(IntervalJoinBound {
+                left_time_col: 1,
+                right_time_col: 1,
+                lower: IntervalUnit::from_minutes(-120),
+                upper: IntervalUnit::from_minutes(60),
+            })
+        );
+    }
 }
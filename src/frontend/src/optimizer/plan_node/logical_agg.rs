@@ -16,19 +16,22 @@ use std::collections::HashMap;
 use std::fmt;
 
 use fixedbitset::FixedBitSet;
+use itertools::Itertools;
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_expr::expr::AggKind;
 use risingwave_pb::expr::AggCall as ProstAggCall;
 
 use super::{
     BatchHashAgg, BatchSimpleAgg, ColPrunable, PlanBase, PlanNode, PlanRef, PlanTreeNodeUnary,
-    StreamHashAgg, StreamSimpleAgg, ToBatch, ToStream,
+    StreamHashAgg, StreamLocalHashAgg, StreamLocalSimpleAgg, StreamSimpleAgg, ToBatch, ToStream,
+};
+use crate::expr::{
+    AggCall, Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef, Literal,
 };
-use crate::expr::{AggCall, Expr, ExprImpl, ExprRewriter, ExprType, FunctionCall, InputRef};
 use crate::optimizer::plan_node::LogicalProject;
-use crate::optimizer::property::Distribution;
+use crate::optimizer::property::{Distribution, Order};
 use crate::utils::ColIndexMapping;
 
 /// Aggregation Call
@@ -42,15 +45,30 @@ pub struct PlanAggCall {
 
     /// Column indexes of input columns
     pub inputs: Vec<InputRef>,
+
+    /// Whether the aggregation function only applies to the distinct values of the inputs
+    pub distinct: bool,
 }
 
 impl fmt::Debug for PlanAggCall {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut builder = f.debug_tuple(&format!("{}", self.agg_kind));
-        self.inputs.iter().for_each(|child| {
-            builder.field(child);
-        });
-        builder.finish()
+        if self.distinct {
+            write!(
+                f,
+                "{}(distinct {})",
+                self.agg_kind,
+                self.inputs
+                    .iter()
+                    .map(|child| format!("{:?}", child))
+                    .join(", ")
+            )
+        } else {
+            let mut builder = f.debug_tuple(&format!("{}", self.agg_kind));
+            self.inputs.iter().for_each(|child| {
+                builder.field(child);
+            });
+            builder.finish()
+        }
     }
 }
 
@@ -60,8 +78,7 @@ impl PlanAggCall {
             r#type: self.agg_kind.to_prost().into(),
             return_type: Some(self.return_type.to_protobuf()),
             args: self.inputs.iter().map(InputRef::to_agg_arg_proto).collect(),
-            // TODO: support distinct
-            distinct: false,
+            distinct: self.distinct,
         }
     }
 
@@ -70,10 +87,50 @@ impl PlanAggCall {
             agg_kind: AggKind::Count,
             return_type: DataType::Int64,
             inputs: vec![],
+            distinct: false,
         }
     }
+
+    /// Split the aggregation into the call of the local phase and that of the global phase, which
+    /// merges the partial results of the local phase at `partial_idx`. Returns `None` if the
+    /// aggregation is not decomposable. As the local phase only aggregates the changes of an
+    /// epoch, aggregations which can not be retracted are decomposable only if the input is
+    /// append-only.
+    pub fn split_two_phase(
+        &self,
+        partial_idx: usize,
+        input_append_only: bool,
+    ) -> Option<(PlanAggCall, PlanAggCall)> {
+        if self.distinct {
+            return None;
+        }
+        let (local, global_kind) = match self.agg_kind {
+            AggKind::Count | AggKind::Sum => (self.clone(), AggKind::Sum),
+            AggKind::Min | AggKind::Max if input_append_only => (self.clone(), self.agg_kind),
+            AggKind::ApproxCountDistinct if input_append_only => (
+                PlanAggCall {
+                    return_type: DataType::Varchar,
+                    ..self.clone()
+                },
+                AggKind::ApproxCountDistinctMerge,
+            ),
+            _ => return None,
+        };
+        let global = PlanAggCall {
+            agg_kind: global_kind,
+            return_type: self.return_type.clone(),
+            inputs: vec![InputRef::new(partial_idx, local.return_type.clone())],
+            distinct: false,
+        };
+        Some((local, global))
+    }
 }
 
+/// Split the aggregations into a local phase and a global phase in streaming, if all of them are
+/// decomposable. The local phase pre-aggregates the changes before the shuffle by group keys, or
+/// before gathering them to a single node without group keys, which reduces the data exchanged.
+pub static TWO_PHASE_AGG: &str = "RW_TWO_PHASE_AGG";
+
 /// `LogicalAgg` groups input data by their group keys and computes aggregation functions.
 ///
 /// It corresponds to the `GROUP BY` operator in a SQL query statement together with the aggregate
@@ -133,6 +190,107 @@ impl ExprHandler {
             error: None,
         })
     }
+
+    /// Push a `PlanAggCall` to agg and return the reference to its result.
+    fn push_agg_call(
+        &mut self,
+        agg_kind: AggKind,
+        inputs: Vec<InputRef>,
+        return_type: DataType,
+    ) -> ExprImpl {
+        self.agg_calls.push(PlanAggCall {
+            agg_kind,
+            return_type: return_type.clone(),
+            inputs,
+            distinct: false,
+        });
+        InputRef::new(self.group_key_len + self.agg_calls.len() - 1, return_type).into()
+    }
+
+    /// Rewrite the statistical aggregations over `x` with `input_refs` of `[x, x * x]`:
+    /// - `var_pop(x) = (sum(x * x) - sum(x) * sum(x) / count(x)) / count(x)`
+    /// - `var_samp(x) = (sum(x * x) - sum(x) * sum(x) / count(x)) / (count(x) - 1)`
+    /// - `stddev_pop(x) = sqrt(var_pop(x))`
+    /// - `stddev_samp(x) = sqrt(var_samp(x))`
+    fn rewrite_statistical_agg_call(
+        &mut self,
+        agg_kind: AggKind,
+        input_refs: Vec<InputRef>,
+        return_type: DataType,
+    ) -> ExprImpl {
+        let call =
+            |func_type, inputs| ExprImpl::from(FunctionCall::new(func_type, inputs).unwrap());
+        let one = ExprImpl::from(Literal::new(Some(ScalarImpl::Int64(1)), DataType::Int64));
+
+        let sum = self.push_agg_call(
+            AggKind::Sum,
+            vec![input_refs[0].clone()],
+            return_type.clone(),
+        );
+        let sum_of_squares = self.push_agg_call(
+            AggKind::Sum,
+            vec![input_refs[1].clone()],
+            return_type.clone(),
+        );
+        let count =
+            self.push_agg_call(AggKind::Count, vec![input_refs[0].clone()], DataType::Int64);
+
+        let numerator = call(
+            ExprType::Subtract,
+            vec![
+                sum_of_squares,
+                call(
+                    ExprType::Divide,
+                    vec![
+                        call(ExprType::Multiply, vec![sum.clone(), sum]),
+                        count.clone().cast_implicit(return_type.clone()).unwrap(),
+                    ],
+                ),
+            ],
+        );
+        let denominator = match agg_kind {
+            AggKind::StddevPop | AggKind::VarPop => {
+                count.cast_implicit(return_type.clone()).unwrap()
+            }
+            // The sample variance of a single row is null, instead of a division by zero.
+            AggKind::StddevSamp | AggKind::VarSamp => call(
+                ExprType::Case,
+                vec![
+                    call(ExprType::GreaterThan, vec![count.clone(), one.clone()]),
+                    call(ExprType::Subtract, vec![count, one])
+                        .cast_implicit(return_type.clone())
+                        .unwrap(),
+                ],
+            ),
+            _ => unreachable!(),
+        };
+        let variance = call(ExprType::Divide, vec![numerator, denominator]);
+
+        match agg_kind {
+            AggKind::VarPop | AggKind::VarSamp => variance,
+            AggKind::StddevPop | AggKind::StddevSamp => {
+                let variance = if return_type == DataType::Float64 {
+                    // The rounding errors of floats may make the variance slightly negative.
+                    let zero = ExprImpl::from(Literal::new(
+                        Some(ScalarImpl::Float64(0.0.into())),
+                        DataType::Float64,
+                    ));
+                    call(
+                        ExprType::Case,
+                        vec![
+                            call(ExprType::LessThan, vec![variance.clone(), zero.clone()]),
+                            zero,
+                            variance,
+                        ],
+                    )
+                } else {
+                    variance
+                };
+                call(ExprType::Sqrt, vec![variance])
+            }
+            _ => unreachable!(),
+        }
+    }
 }
 
 impl ExprRewriter for ExprHandler {
@@ -144,17 +302,45 @@ impl ExprRewriter for ExprHandler {
     /// Note that the rewriter does not traverse into inputs of agg calls.
     fn rewrite_agg_call(&mut self, agg_call: AggCall) -> ExprImpl {
         let return_type = agg_call.return_type();
-        let (agg_kind, inputs) = agg_call.decompose();
+        let (agg_kind, inputs, distinct) = agg_call.decompose();
 
         for i in &inputs {
             if i.has_agg_call() {
                 self.error = Some(ErrorCode::InvalidInputSyntax(
                     "Aggregation calls should not be nested".into(),
                 ));
-                return AggCall::new(agg_kind, inputs).unwrap().into();
+                return AggCall::new(agg_kind, inputs, distinct).unwrap().into();
             }
         }
 
+        let is_statistical = matches!(
+            agg_kind,
+            AggKind::StddevPop | AggKind::StddevSamp | AggKind::VarPop | AggKind::VarSamp
+        );
+        let inputs = if is_statistical {
+            if distinct {
+                self.error = Some(ErrorCode::NotImplemented(
+                    format!("{}(distinct)", agg_kind),
+                    None.into(),
+                ));
+                return AggCall::new(agg_kind, inputs, distinct).unwrap().into();
+            }
+            // Project the input and its square in the return type, so that they are summed up
+            // without overflow.
+            let input = inputs
+                .into_iter()
+                .exactly_one()
+                .unwrap()
+                .cast_implicit(return_type.clone())
+                .unwrap();
+            let square = FunctionCall::new(ExprType::Multiply, vec![input.clone(), input.clone()])
+                .unwrap()
+                .into();
+            vec![input, square]
+        } else {
+            inputs
+        };
+
         let mut index = self.project.len();
         let mut input_refs = vec![];
         self.project.extend(inputs.into_iter().filter(|expr| {
@@ -180,6 +366,7 @@ impl ExprRewriter for ExprHandler {
                 agg_kind: AggKind::Sum,
                 return_type: left_return_type.clone(),
                 inputs: input_refs.clone(),
+                distinct,
             });
             let left = ExprImpl::from(InputRef::new(
                 self.group_key_len + self.agg_calls.len() - 1,
@@ -196,6 +383,7 @@ impl ExprRewriter for ExprHandler {
                 agg_kind: AggKind::Count,
                 return_type: right_return_type.clone(),
                 inputs: input_refs,
+                distinct,
             });
 
             let right = InputRef::new(
@@ -204,11 +392,14 @@ impl ExprRewriter for ExprHandler {
             );
 
             ExprImpl::from(FunctionCall::new(ExprType::Divide, vec![left, right.into()]).unwrap())
+        } else if is_statistical {
+            self.rewrite_statistical_agg_call(agg_kind, input_refs, return_type)
         } else {
             self.agg_calls.push(PlanAggCall {
                 agg_kind,
                 return_type: return_type.clone(),
                 inputs: input_refs,
+                distinct,
             });
             ExprImpl::from(InputRef::new(
                 self.group_key_len + self.agg_calls.len() - 1,
@@ -368,6 +559,66 @@ impl LogicalAgg {
     pub fn decompose(self) -> (Vec<PlanAggCall>, Vec<usize>, PlanRef) {
         (self.agg_calls, self.group_keys, self.input)
     }
+
+    /// Generate a plan like `StreamHashAgg - StreamExchange - StreamLocalHashAgg - input`, or
+    /// `StreamSimpleAgg - StreamExchange - StreamLocalSimpleAgg - input` without group keys, if
+    /// two-phase aggregation is enabled and all the agg calls are decomposable.
+    fn to_two_phase_stream(&self) -> Option<PlanRef> {
+        let enabled = self
+            .base
+            .ctx
+            .inner()
+            .session_ctx
+            .get_config(TWO_PHASE_AGG)
+            .map_or(false, |config| config.is_set(false));
+        if !enabled {
+            return None;
+        }
+
+        let input = self.input().to_stream();
+        let required_dist = if self.group_keys().is_empty() {
+            Distribution::Single
+        } else {
+            Distribution::HashShard(self.group_keys().to_vec())
+        };
+        // Pre-aggregation saves nothing if the input is already shuffled by the group keys.
+        if input.distribution().satisfies(&required_dist) {
+            return None;
+        }
+        let group_key_len = self.group_keys().len();
+        let (local_agg_calls, global_agg_calls): (Vec<_>, Vec<_>) = self
+            .agg_calls()
+            .iter()
+            .enumerate()
+            .map(|(idx, agg_call)| {
+                agg_call.split_two_phase(group_key_len + idx, input.append_only())
+            })
+            .collect::<Option<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        let local_agg = LogicalAgg::new(local_agg_calls, self.group_keys().to_vec(), input);
+        if group_key_len == 0 {
+            let exchange = Distribution::Single.enforce_if_not_satisfies(
+                StreamLocalSimpleAgg::new(local_agg).into(),
+                Order::any(),
+            );
+            return Some(
+                StreamSimpleAgg::new(LogicalAgg::new(global_agg_calls, vec![], exchange)).into(),
+            );
+        }
+        let global_group_keys = (0..group_key_len).collect_vec();
+        let exchange = Distribution::HashShard(global_group_keys.clone())
+            .enforce_if_not_satisfies(StreamLocalHashAgg::new(local_agg).into(), Order::any());
+        Some(
+            StreamHashAgg::new(LogicalAgg::new(
+                global_agg_calls,
+                global_group_keys,
+                exchange,
+            ))
+            .into(),
+        )
+    }
 }
 
 impl PlanTreeNodeUnary for LogicalAgg {
@@ -488,7 +739,9 @@ impl ToBatch for LogicalAgg {
 
 impl ToStream for LogicalAgg {
     fn to_stream(&self) -> PlanRef {
-        if self.group_keys().is_empty() {
+        if let Some(plan) = self.to_two_phase_stream() {
+            plan
+        } else if self.group_keys().is_empty() {
             StreamSimpleAgg::new(
                 self.clone_with_input(
                     self.input()
@@ -524,6 +777,7 @@ impl ToStream for LogicalAgg {
                 agg_kind: AggKind::Count,
                 return_type: DataType::Int64,
                 inputs: vec![],
+                distinct: false,
             },
         );
 
@@ -604,7 +858,8 @@ mod tests {
 
         // Test case: select v1, min(v2) from test group by v1;
         {
-            let min_v2 = AggCall::new(AggKind::Min, vec![input_ref_2.clone().into()]).unwrap();
+            let min_v2 =
+                AggCall::new(AggKind::Min, vec![input_ref_2.clone().into()], false).unwrap();
             let select_exprs = vec![input_ref_1.clone().into(), min_v2.into()];
             let group_exprs = vec![input_ref_1.clone().into()];
 
@@ -622,8 +877,10 @@ mod tests {
 
         // Test case: select v1, min(v2) + max(v3) from t group by v1;
         {
-            let min_v2 = AggCall::new(AggKind::Min, vec![input_ref_2.clone().into()]).unwrap();
-            let max_v3 = AggCall::new(AggKind::Max, vec![input_ref_3.clone().into()]).unwrap();
+            let min_v2 =
+                AggCall::new(AggKind::Min, vec![input_ref_2.clone().into()], false).unwrap();
+            let max_v3 =
+                AggCall::new(AggKind::Max, vec![input_ref_3.clone().into()], false).unwrap();
             let func_call =
                 FunctionCall::new(ExprType::Add, vec![min_v2.into(), max_v3.into()]).unwrap();
             let select_exprs = vec![input_ref_1.clone().into(), ExprImpl::from(func_call)];
@@ -656,7 +913,7 @@ mod tests {
                 vec![input_ref_1.into(), input_ref_3.into()],
             )
             .unwrap();
-            let agg_call = AggCall::new(AggKind::Min, vec![v1_mult_v3.into()]).unwrap();
+            let agg_call = AggCall::new(AggKind::Min, vec![v1_mult_v3.into()], false).unwrap();
             let select_exprs = vec![input_ref_2.clone().into(), agg_call.into()];
             let group_exprs = vec![input_ref_2.into()];
 
@@ -701,6 +958,7 @@ mod tests {
             agg_kind: AggKind::Min,
             return_type: ty.clone(),
             inputs: vec![InputRef::new(2, ty.clone())],
+            distinct: false,
         };
         let agg = LogicalAgg::new(vec![agg_call], vec![1], values.into());
 
@@ -754,6 +1012,7 @@ mod tests {
             agg_kind: AggKind::Min,
             return_type: ty.clone(),
             inputs: vec![InputRef::new(2, ty.clone())],
+            distinct: false,
         };
         let agg = LogicalAgg::new(vec![agg_call], vec![1], values.into());
 
@@ -816,11 +1075,13 @@ mod tests {
                 agg_kind: AggKind::Min,
                 return_type: ty.clone(),
                 inputs: vec![InputRef::new(2, ty.clone())],
+                distinct: false,
             },
             PlanAggCall {
                 agg_kind: AggKind::Max,
                 return_type: ty.clone(),
                 inputs: vec![InputRef::new(1, ty.clone())],
+                distinct: false,
             },
         ];
         let agg = LogicalAgg::new(agg_calls, vec![1, 2], values.into());
@@ -83,16 +83,14 @@ impl LogicalJoin {
         Self::new(left, right, join_type, Condition::with_expr(on_clause)).into()
     }
 
-    // FIXME: please note that the modification here is just a temporary fix for bug of LogicalJoin.
-    // Related issue is #1849.
+    /// Semi and anti joins only output the columns of one side, while other joins output the
+    /// columns of both sides.
     pub fn out_column_num(left_len: usize, right_len: usize, join_type: JoinType) -> usize {
         match join_type {
-            JoinType::Inner
-            | JoinType::LeftOuter
-            | JoinType::RightOuter
-            | JoinType::FullOuter
-            | JoinType::LeftSemi => left_len + right_len,
-            JoinType::LeftAnti => left_len,
+            JoinType::Inner | JoinType::LeftOuter | JoinType::RightOuter | JoinType::FullOuter => {
+                left_len + right_len
+            }
+            JoinType::LeftSemi | JoinType::LeftAnti => left_len,
             JoinType::RightSemi | JoinType::RightAnti => right_len,
         }
     }
@@ -104,14 +102,10 @@ impl LogicalJoin {
         join_type: JoinType,
     ) -> ColIndexMapping {
         match join_type {
-            JoinType::LeftSemi
-            | JoinType::Inner
-            | JoinType::LeftOuter
-            | JoinType::RightOuter
-            | JoinType::FullOuter => {
+            JoinType::Inner | JoinType::LeftOuter | JoinType::RightOuter | JoinType::FullOuter => {
                 ColIndexMapping::identity_or_none(left_len + right_len, left_len)
             }
-            JoinType::LeftAnti => ColIndexMapping::identity(left_len),
+            JoinType::LeftSemi | JoinType::LeftAnti => ColIndexMapping::identity(left_len),
             JoinType::RightSemi | JoinType::RightAnti => ColIndexMapping::empty(right_len),
         }
     }
@@ -123,14 +117,10 @@ impl LogicalJoin {
         join_type: JoinType,
     ) -> ColIndexMapping {
         match join_type {
-            JoinType::LeftSemi
-            | JoinType::Inner
-            | JoinType::LeftOuter
-            | JoinType::RightOuter
-            | JoinType::FullOuter => {
+            JoinType::Inner | JoinType::LeftOuter | JoinType::RightOuter | JoinType::FullOuter => {
                 ColIndexMapping::with_shift_offset(left_len + right_len, -(left_len as isize))
             }
-            JoinType::LeftAnti => ColIndexMapping::empty(left_len),
+            JoinType::LeftSemi | JoinType::LeftAnti => ColIndexMapping::empty(left_len),
             JoinType::RightSemi | JoinType::RightAnti => ColIndexMapping::identity(right_len),
         }
     }
@@ -185,6 +175,27 @@ impl LogicalJoin {
         )
     }
 
+    /// get the Mapping of columnIndex from output column index to the index in the concatenation
+    /// of left and right columns, which `on` refers to
+    pub fn o2i_col_mapping(&self) -> ColIndexMapping {
+        let left_len = self.left().schema().len();
+        let o2l = self.o2l_col_mapping();
+        let o2r = self.o2r_col_mapping();
+        let map = (0..self.schema().len())
+            .map(|i| {
+                o2l.try_map(i)
+                    .or_else(|| o2r.try_map(i).map(|r| r + left_len))
+            })
+            .collect();
+        ColIndexMapping::with_target_size(map, left_len + self.right().schema().len())
+    }
+
+    /// get the Mapping of columnIndex from the index in the concatenation of left and right
+    /// columns to output column index
+    pub fn i2o_col_mapping(&self) -> ColIndexMapping {
+        self.o2i_col_mapping().inverse()
+    }
+
     pub(super) fn derive_schema(
         left_schema: &Schema,
         right_schema: &Schema,
@@ -293,7 +304,12 @@ impl ColPrunable for LogicalJoin {
 
         let left_len = self.left.schema().fields.len();
 
-        let mut visitor = CollectInputRef::new(required_cols.clone());
+        // The required output columns in the column indices of `on`.
+        let o2i = self.o2i_col_mapping();
+        let mut left_right_required_cols = FixedBitSet::with_capacity(o2i.target_size());
+        left_right_required_cols.extend(required_cols.ones().map(|i| o2i.map(i)));
+
+        let mut visitor = CollectInputRef::new(left_right_required_cols);
         self.on.visit_expr(&mut visitor);
         let left_right_required_cols = visitor.collect();
 
@@ -319,11 +335,16 @@ impl ColPrunable for LogicalJoin {
             on,
         );
 
-        if required_cols == &left_right_required_cols {
+        if join.schema().len() == required_cols.count_ones(..) {
             join.into()
         } else {
+            let i2o = join.i2o_col_mapping();
             let mut remaining_columns = FixedBitSet::with_capacity(join.schema().fields().len());
-            remaining_columns.extend(required_cols.ones().map(|i| mapping.map(i)));
+            remaining_columns.extend(
+                required_cols
+                    .ones()
+                    .map(|i| i2o.map(mapping.map(o2i.map(i)))),
+            );
             LogicalProject::with_mapping(
                 join.into(),
                 ColIndexMapping::with_remaining_columns(&remaining_columns),
@@ -382,6 +403,13 @@ impl ToStream for LogicalJoin {
             .right()
             .to_stream_with_dist_required(&Distribution::HashShard(predicate.right_eq_indexes()));
         let logical_join = self.clone_with_left_right(left, right);
+        // Append-only inputs joined with a time bound only need to keep the rows within the bound.
+        let interval_bound =
+            if logical_join.left().append_only() && logical_join.right().append_only() {
+                predicate.interval_bound()
+            } else {
+                None
+            };
 
         if predicate.has_eq() {
             // Convert to Hash Join for equal joins
@@ -394,11 +422,15 @@ impl ToStream for LogicalJoin {
                     self.left.schema().len(),
                 );
                 let logical_join = logical_join.clone_with_cond(eq_cond.eq_cond());
-                let hash_join = StreamHashJoin::new(logical_join, eq_cond).into();
+                let hash_join = StreamHashJoin::new(logical_join, eq_cond)
+                    .with_interval_bound(interval_bound)
+                    .into();
                 let logical_filter = LogicalFilter::new(hash_join, predicate.non_eq_cond());
                 StreamFilter::new(logical_filter).into()
             } else {
-                StreamHashJoin::new(logical_join, predicate).into()
+                StreamHashJoin::new(logical_join, predicate)
+                    .with_interval_bound(interval_bound)
+                    .into()
             }
         } else {
             // Convert to Nested-loop Join for non-equal joins
@@ -0,0 +1,369 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::DataType;
+
+use super::{
+    BatchProjectSet, ColPrunable, LogicalProject, PlanBase, PlanNode, PlanRef, PlanTreeNodeUnary,
+    StreamProjectSet, ToBatch, ToStream,
+};
+use crate::expr::{assert_input_ref, Expr, ExprImpl, ExprRewriter, ExprVisitor, InputRef};
+use crate::optimizer::plan_node::CollectInputRef;
+use crate::optimizer::property::{Distribution, Order};
+use crate::utils::ColIndexMapping;
+
+/// `LogicalProjectSet` projects one row multiple times according to `select_list`.
+///
+/// Different from `Project`, it supports [`TableFunction`](crate::expr::TableFunction)s.
+/// The output of `ProjectSet` is the cartesian product of the results of all table functions for
+/// each input row, where shorter sets are padded with nulls. An additional column
+/// `projected_row_id` is prepended to the output, which is the index of the row among those
+/// produced by the same input row, so that it can be used as part of the primary key.
+#[derive(Debug, Clone)]
+pub struct LogicalProjectSet {
+    pub base: PlanBase,
+    select_list: Vec<ExprImpl>,
+    input: PlanRef,
+}
+
+impl LogicalProjectSet {
+    pub fn new(input: PlanRef, select_list: Vec<ExprImpl>) -> Self {
+        assert!(
+            select_list.iter().any(|e| e.has_table_function()),
+            "ProjectSet should have at least one table function."
+        );
+        for expr in &select_list {
+            assert_input_ref!(expr, input.schema().fields().len());
+            assert!(!expr.has_subquery());
+            assert!(!expr.has_agg_call());
+        }
+
+        let ctx = input.ctx();
+        let schema = Self::derive_schema(&select_list, input.schema());
+        let pk_indices = Self::derive_pk(input.schema(), input.pk_indices(), &select_list);
+        let base = PlanBase::new_logical(ctx, schema, pk_indices);
+        LogicalProjectSet {
+            base,
+            select_list,
+            input,
+        }
+    }
+
+    /// Creates a `LogicalProjectSet` for the select list, followed by a `LogicalProject` which
+    /// removes the `projected_row_id` column and applies the aliases.
+    ///
+    /// Table functions nested in other expressions are not supported yet.
+    pub fn create(
+        input: PlanRef,
+        select_list: Vec<ExprImpl>,
+        expr_alias: Vec<Option<String>>,
+    ) -> PlanRef {
+        let project_set = Self::new(input, select_list);
+        let exprs = project_set
+            .schema()
+            .fields()
+            .iter()
+            .enumerate()
+            .skip(1)
+            .map(|(i, field)| InputRef::new(i, field.data_type()).into())
+            .collect();
+        LogicalProject::create(project_set.into(), exprs, expr_alias)
+    }
+
+    /// Get the Mapping of columnIndex from output column index to input column index.
+    fn o2i_col_mapping_inner(input_len: usize, select_list: &[ExprImpl]) -> ColIndexMapping {
+        let mut map = vec![None; 1 + select_list.len()];
+        for (i, item) in select_list.iter().enumerate() {
+            map[1 + i] = match item {
+                ExprImpl::InputRef(input) => Some(input.index()),
+                _ => None,
+            }
+        }
+        ColIndexMapping::with_target_size(map, input_len)
+    }
+
+    /// Get the Mapping of columnIndex from input column index to output column index, if a input
+    /// column corresponds more than one out columns, mapping to any one.
+    fn i2o_col_mapping_inner(input_len: usize, select_list: &[ExprImpl]) -> ColIndexMapping {
+        Self::o2i_col_mapping_inner(input_len, select_list).inverse()
+    }
+
+    pub fn o2i_col_mapping(&self) -> ColIndexMapping {
+        Self::o2i_col_mapping_inner(self.input.schema().len(), self.select_list())
+    }
+
+    pub fn i2o_col_mapping(&self) -> ColIndexMapping {
+        Self::i2o_col_mapping_inner(self.input.schema().len(), self.select_list())
+    }
+
+    fn derive_schema(select_list: &[ExprImpl], input_schema: &Schema) -> Schema {
+        let o2i = Self::o2i_col_mapping_inner(input_schema.len(), select_list);
+        let fields = std::iter::once(Field::with_name(DataType::Int64, "projected_row_id"))
+            .chain(select_list.iter().enumerate().map(|(idx, expr)| {
+                let id = idx + 1;
+                match o2i.try_map(id) {
+                    Some(input_idx) => input_schema.fields()[input_idx].clone(),
+                    None => match expr {
+                        ExprImpl::TableFunction(tf) => {
+                            Field::with_name(tf.return_type(), tf.name())
+                        }
+                        _ => Field::with_name(expr.return_type(), format!("expr#{}", id)),
+                    },
+                }
+            }))
+            .collect();
+        Schema { fields }
+    }
+
+    fn derive_pk(
+        input_schema: &Schema,
+        input_pk: &[usize],
+        select_list: &[ExprImpl],
+    ) -> Vec<usize> {
+        let i2o = Self::i2o_col_mapping_inner(input_schema.len(), select_list);
+        let pk = input_pk
+            .iter()
+            .map(|pk_col| i2o.try_map(*pk_col))
+            .collect::<Option<Vec<_>>>()
+            .unwrap_or_default();
+        // add `projected_row_id` to pk
+        std::iter::once(0).chain(pk.into_iter()).collect()
+    }
+
+    pub fn select_list(&self) -> &Vec<ExprImpl> {
+        &self.select_list
+    }
+
+    pub(super) fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        f.debug_struct(name)
+            .field("select_list", self.select_list())
+            .finish()
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalProjectSet {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(input, self.select_list.clone())
+    }
+
+    fn rewrite_with_input(
+        &self,
+        input: PlanRef,
+        mut input_col_change: ColIndexMapping,
+    ) -> (Self, ColIndexMapping) {
+        let select_list = self
+            .select_list
+            .clone()
+            .into_iter()
+            .map(|item| input_col_change.rewrite_expr(item))
+            .collect();
+        let project_set = Self::new(input, select_list);
+        // change the input columns index will not change the output column index
+        let out_col_change = ColIndexMapping::identity(self.schema().len());
+        (project_set, out_col_change)
+    }
+}
+
+impl_plan_tree_node_for_unary! {LogicalProjectSet}
+
+impl fmt::Display for LogicalProjectSet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalProjectSet")
+    }
+}
+
+impl ColPrunable for LogicalProjectSet {
+    fn prune_col(&self, required_cols: &FixedBitSet) -> PlanRef {
+        self.must_contain_columns(required_cols);
+
+        // Table functions change the cardinality of the output, so they are always kept, as well
+        // as the `projected_row_id`.
+        let mut kept_cols = FixedBitSet::with_capacity(self.schema().len());
+        kept_cols.extend((0..self.schema().len()).filter(|&i| {
+            i == 0 || required_cols.contains(i) || self.select_list[i - 1].has_table_function()
+        }));
+
+        // Record each InputRef's index.
+        let mut visitor = CollectInputRef::with_capacity(self.input.schema().fields().len());
+        kept_cols.ones().skip(1).for_each(|id| {
+            visitor.visit_expr(&self.select_list[id - 1]);
+        });
+        let child_required_cols = visitor.collect();
+        let mut mapping = ColIndexMapping::with_remaining_columns(&child_required_cols);
+
+        // Rewrite each InputRef with new index.
+        let select_list = kept_cols
+            .ones()
+            .skip(1)
+            .map(|id| mapping.rewrite_expr(self.select_list[id - 1].clone()))
+            .collect();
+        let project_set: PlanRef =
+            Self::new(self.input.prune_col(&child_required_cols), select_list).into();
+
+        if kept_cols == *required_cols {
+            project_set
+        } else {
+            // Remove the columns which are kept only for the table functions.
+            let mut remaining = FixedBitSet::with_capacity(kept_cols.count_ones(..));
+            remaining.extend(
+                kept_cols
+                    .ones()
+                    .enumerate()
+                    .filter(|(_, i)| required_cols.contains(*i))
+                    .map(|(new_idx, _)| new_idx),
+            );
+            LogicalProject::with_mapping(
+                project_set,
+                ColIndexMapping::with_remaining_columns(&remaining),
+            )
+        }
+    }
+}
+
+impl ToBatch for LogicalProjectSet {
+    fn to_batch(&self) -> PlanRef {
+        let new_input = self.input().to_batch();
+        let new_logical = self.clone_with_input(new_input);
+        BatchProjectSet::new(new_logical).into()
+    }
+}
+
+impl ToStream for LogicalProjectSet {
+    fn to_stream_with_dist_required(&self, required_dist: &Distribution) -> PlanRef {
+        let input_required = match required_dist {
+            Distribution::HashShard(_) => self
+                .o2i_col_mapping()
+                .rewrite_required_distribution(required_dist)
+                .unwrap_or(Distribution::AnyShard),
+            Distribution::AnyShard => Distribution::AnyShard,
+            _ => Distribution::Any,
+        };
+        let new_input = self.input().to_stream_with_dist_required(&input_required);
+        let new_logical = self.clone_with_input(new_input);
+        let stream_plan = StreamProjectSet::new(new_logical);
+        required_dist.enforce_if_not_satisfies(stream_plan.into(), Order::any())
+    }
+
+    fn to_stream(&self) -> PlanRef {
+        self.to_stream_with_dist_required(Distribution::any())
+    }
+
+    fn logical_rewrite_for_stream(&self) -> (PlanRef, ColIndexMapping) {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream();
+        let (project_set, out_col_change) =
+            self.rewrite_with_input(input.clone(), input_col_change);
+
+        // Add missing columns of input_pk into the select list.
+        let input_pk = input.pk_indices();
+        let i2o = Self::i2o_col_mapping_inner(input.schema().len(), project_set.select_list());
+        let col_need_to_add = input_pk.iter().cloned().filter(|i| i2o.try_map(*i) == None);
+        let input_schema = input.schema();
+        let select_list =
+            project_set
+                .select_list()
+                .iter()
+                .cloned()
+                .chain(col_need_to_add.map(|idx| {
+                    InputRef::new(idx, input_schema.fields[idx].data_type.clone()).into()
+                }))
+                .collect_vec();
+        let project_set = Self::new(input, select_list);
+        // The added columns is at the end, so it will not change the existing column indices.
+        (project_set.into(), out_col_change)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::catalog::Field;
+    use risingwave_common::types::DataType;
+
+    use super::*;
+    use crate::expr::{TableFunction, TableFunctionType};
+    use crate::optimizer::plan_node::LogicalValues;
+    use crate::session::OptimizerContext;
+
+    #[tokio::test]
+    /// Pruning
+    /// ```text
+    /// ProjectSet(v1, generate_series(1, v2), v3)
+    ///   TableScan(v1, v2, v3)
+    /// ```
+    /// with required columns [1] will result in
+    /// ```text
+    /// Project($0)
+    ///   ProjectSet(v1, generate_series(1, v2))
+    ///     TableScan(v1, v2)
+    /// ```
+    async fn test_prune_project_set() {
+        let ty = DataType::Int32;
+        let ctx = OptimizerContext::mock().await;
+        let fields: Vec<Field> = vec![
+            Field::with_name(ty.clone(), "v1"),
+            Field::with_name(ty.clone(), "v2"),
+            Field::with_name(ty.clone(), "v3"),
+        ];
+        let values = LogicalValues::new(
+            vec![],
+            Schema {
+                fields: fields.clone(),
+            },
+            ctx,
+        );
+        let series = TableFunction::new(
+            TableFunctionType::GenerateSeries,
+            vec![
+                ExprImpl::literal_int(1),
+                InputRef::new(1, ty.clone()).into(),
+            ],
+        )
+        .unwrap();
+        let project_set = LogicalProjectSet::new(
+            values.into(),
+            vec![
+                InputRef::new(0, ty.clone()).into(),
+                series.into(),
+                InputRef::new(2, ty.clone()).into(),
+            ],
+        );
+        assert_eq!(project_set.pk_indices(), &[0]);
+
+        // Require `v1` only.
+        let mut required_cols = FixedBitSet::with_capacity(project_set.schema().len());
+        required_cols.insert(1);
+        let plan = project_set.prune_col(&required_cols);
+
+        let project = plan.as_logical_project().unwrap();
+        assert_eq!(project.exprs().len(), 1);
+        assert_eq!(project.schema().fields()[0].name, "v1");
+
+        let project_set = project.input();
+        let project_set = project_set.as_logical_project_set().unwrap();
+        assert_eq!(project_set.select_list().len(), 2);
+        assert_eq!(project_set.schema().len(), 3);
+        assert!(project_set.select_list()[1].has_table_function());
+
+        let values = project_set.input();
+        assert_eq!(values.schema().fields(), &fields[0..2]);
+    }
+}
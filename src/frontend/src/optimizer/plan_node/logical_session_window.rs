@@ -0,0 +1,224 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use fixedbitset::FixedBitSet;
+use itertools::Itertools;
+use risingwave_common::catalog::Field;
+use risingwave_common::types::{DataType, IntervalUnit};
+
+use super::{
+    BatchSessionWindow, ColPrunable, LogicalProject, PlanBase, PlanNode, PlanRef,
+    PlanTreeNodeUnary, StreamSessionWindow, ToBatch, ToStream,
+};
+use crate::expr::{InputRef, InputRefDisplay};
+use crate::optimizer::property::Distribution;
+use crate::utils::ColIndexMapping;
+
+/// `LogicalSessionWindow` implements Session Table Function.
+///
+/// Rows of the same partition belong to the same session if they are connected by rows whose time
+/// columns are less than `gap` apart. Each row is output with the `window_start` and `window_end`
+/// of its session, i.e. the minimum time and the maximum time plus `gap` of the session.
+#[derive(Debug, Clone)]
+pub struct LogicalSessionWindow {
+    pub base: PlanBase,
+    input: PlanRef,
+    pub(super) time_col: InputRef,
+    pub(super) gap: IntervalUnit,
+    pub(super) partition_keys: Vec<InputRef>,
+}
+
+impl LogicalSessionWindow {
+    fn new(
+        input: PlanRef,
+        time_col: InputRef,
+        gap: IntervalUnit,
+        partition_keys: Vec<InputRef>,
+    ) -> Self {
+        let ctx = input.ctx();
+        let schema = input
+            .schema()
+            .clone()
+            .into_fields()
+            .into_iter()
+            .chain([
+                Field::with_name(DataType::Timestamp, "window_start"),
+                Field::with_name(DataType::Timestamp, "window_end"),
+            ])
+            .collect();
+        // Each input row is output exactly once, with the window of its session.
+        let pk_indices = input.pk_indices().to_vec();
+        let base = PlanBase::new_logical(ctx, schema, pk_indices);
+        LogicalSessionWindow {
+            base,
+            input,
+            time_col,
+            gap,
+            partition_keys,
+        }
+    }
+
+    pub fn create(
+        input: PlanRef,
+        time_col: InputRef,
+        gap: IntervalUnit,
+        partition_keys: Vec<InputRef>,
+    ) -> PlanRef {
+        Self::new(input, time_col, gap, partition_keys).into()
+    }
+
+    pub fn partition_key_indices(&self) -> Vec<usize> {
+        self.partition_keys.iter().map(|key| key.index).collect()
+    }
+
+    /// The distribution required on the input, so that all rows of a partition are processed
+    /// together.
+    pub fn required_input_dist(&self) -> Distribution {
+        if self.partition_keys.is_empty() {
+            Distribution::Single
+        } else {
+            Distribution::HashShard(self.partition_key_indices())
+        }
+    }
+
+    pub fn o2i_col_mapping(&self) -> ColIndexMapping {
+        ColIndexMapping::identity_or_none(self.schema().len(), self.input.schema().len())
+    }
+
+    pub fn i2o_col_mapping(&self) -> ColIndexMapping {
+        ColIndexMapping::identity_or_none(self.input.schema().len(), self.schema().len())
+    }
+
+    pub fn fmt_with_name(&self, f: &mut fmt::Formatter, name: &str) -> fmt::Result {
+        write!(
+            f,
+            "{} {{ time_col: {} gap: {}",
+            name,
+            InputRefDisplay(self.time_col.index),
+            self.gap
+        )?;
+        if !self.partition_keys.is_empty() {
+            write!(
+                f,
+                " partition_keys: [{}]",
+                self.partition_keys
+                    .iter()
+                    .map(|key| InputRefDisplay(key.index).to_string())
+                    .join(", ")
+            )?;
+        }
+        write!(f, " }}")
+    }
+}
+
+impl PlanTreeNodeUnary for LogicalSessionWindow {
+    fn input(&self) -> PlanRef {
+        self.input.clone()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(
+            input,
+            self.time_col.clone(),
+            self.gap,
+            self.partition_keys.clone(),
+        )
+    }
+
+    #[must_use]
+    fn rewrite_with_input(
+        &self,
+        input: PlanRef,
+        input_col_change: ColIndexMapping,
+    ) -> (Self, ColIndexMapping) {
+        let rewrite_input_ref = |input_ref: &InputRef| {
+            let mut input_ref = input_ref.clone();
+            input_ref.index = input_col_change.map(input_ref.index);
+            input_ref
+        };
+        let time_col = rewrite_input_ref(&self.time_col);
+        let partition_keys = self.partition_keys.iter().map(rewrite_input_ref).collect();
+        let new_session = Self::new(input.clone(), time_col, self.gap, partition_keys);
+
+        let (mut mapping, new_input_col_num) = input_col_change.into_parts();
+        assert_eq!(new_input_col_num, input.schema().len());
+        assert_eq!(new_input_col_num + 2, new_session.schema().len());
+        mapping.push(Some(new_input_col_num));
+        mapping.push(Some(new_input_col_num + 1));
+
+        (new_session, ColIndexMapping::new(mapping))
+    }
+}
+
+impl_plan_tree_node_for_unary! {LogicalSessionWindow}
+
+impl fmt::Display for LogicalSessionWindow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with_name(f, "LogicalSessionWindow")
+    }
+}
+
+impl ColPrunable for LogicalSessionWindow {
+    fn prune_col(&self, required_cols: &FixedBitSet) -> PlanRef {
+        self.must_contain_columns(required_cols);
+
+        // The time column and the partition keys are always needed to compute the sessions.
+        let mut input_required_cols = self.o2i_col_mapping().rewrite_bitset(required_cols);
+        input_required_cols.insert(self.time_col.index);
+        for key in &self.partition_keys {
+            input_required_cols.insert(key.index);
+        }
+        let input = self.input.prune_col(&input_required_cols);
+        let input_change = ColIndexMapping::with_remaining_columns(&input_required_cols);
+        let (new_session, out_change) = self.rewrite_with_input(input, input_change);
+
+        if new_session.schema().len() == required_cols.count_ones(..) {
+            return new_session.into();
+        }
+        let mut proj_map = vec![None; new_session.schema().len()];
+        for (tar, src) in required_cols.ones().enumerate() {
+            proj_map[out_change.map(src)] = Some(tar);
+        }
+        LogicalProject::with_mapping(
+            new_session.into(),
+            ColIndexMapping::with_target_size(proj_map, required_cols.count_ones(..)),
+        )
+    }
+}
+
+impl ToBatch for LogicalSessionWindow {
+    fn to_batch(&self) -> PlanRef {
+        let new_input = self.input().to_batch();
+        let new_logical = self.clone_with_input(new_input);
+        BatchSessionWindow::new(new_logical).into()
+    }
+}
+
+impl ToStream for LogicalSessionWindow {
+    fn to_stream(&self) -> PlanRef {
+        let new_input = self
+            .input()
+            .to_stream_with_dist_required(&self.required_input_dist());
+        let new_logical = self.clone_with_input(new_input);
+        StreamSessionWindow::new(new_logical).into()
+    }
+
+    fn logical_rewrite_for_stream(&self) -> (PlanRef, ColIndexMapping) {
+        let (input, input_col_change) = self.input.logical_rewrite_for_stream();
+        let (session, out_col_change) = self.rewrite_with_input(input, input_col_change);
+        (session.into(), out_col_change)
+    }
+}
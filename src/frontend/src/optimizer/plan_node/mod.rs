@@ -222,9 +222,12 @@ mod batch_hop_window;
 mod batch_insert;
 mod batch_limit;
 mod batch_project;
+mod batch_project_set;
 mod batch_seq_scan;
+mod batch_session_window;
 mod batch_simple_agg;
 mod batch_sort;
+mod batch_table_function;
 mod batch_topn;
 mod batch_values;
 mod logical_agg;
@@ -236,8 +239,11 @@ mod logical_insert;
 mod logical_join;
 mod logical_limit;
 mod logical_project;
+mod logical_project_set;
 mod logical_scan;
+mod logical_session_window;
 mod logical_source;
+mod logical_table_function;
 mod logical_topn;
 mod logical_values;
 mod stream_delta_join;
@@ -247,8 +253,12 @@ mod stream_hash_agg;
 mod stream_hash_join;
 mod stream_hop_window;
 mod stream_index_scan;
+mod stream_local_hash_agg;
+mod stream_local_simple_agg;
 mod stream_materialize;
 mod stream_project;
+mod stream_project_set;
+mod stream_session_window;
 mod stream_simple_agg;
 mod stream_source;
 mod stream_table_scan;
@@ -263,9 +273,12 @@ pub use batch_hop_window::BatchHopWindow;
 pub use batch_insert::BatchInsert;
 pub use batch_limit::BatchLimit;
 pub use batch_project::BatchProject;
+pub use batch_project_set::BatchProjectSet;
 pub use batch_seq_scan::BatchSeqScan;
+pub use batch_session_window::BatchSessionWindow;
 pub use batch_simple_agg::BatchSimpleAgg;
 pub use batch_sort::BatchSort;
+pub use batch_table_function::BatchTableFunction;
 pub use batch_topn::BatchTopN;
 pub use batch_values::BatchValues;
 pub use logical_agg::{LogicalAgg, PlanAggCall};
@@ -277,8 +290,11 @@ pub use logical_insert::LogicalInsert;
 pub use logical_join::LogicalJoin;
 pub use logical_limit::LogicalLimit;
 pub use logical_project::LogicalProject;
+pub use logical_project_set::LogicalProjectSet;
 pub use logical_scan::LogicalScan;
+pub use logical_session_window::LogicalSessionWindow;
 pub use logical_source::LogicalSource;
+pub use logical_table_function::LogicalTableFunction;
 pub use logical_topn::LogicalTopN;
 pub use logical_values::LogicalValues;
 pub use stream_delta_join::StreamDeltaJoin;
@@ -288,8 +304,12 @@ pub use stream_hash_agg::StreamHashAgg;
 pub use stream_hash_join::StreamHashJoin;
 pub use stream_hop_window::StreamHopWindow;
 pub use stream_index_scan::StreamIndexScan;
+pub use stream_local_hash_agg::StreamLocalHashAgg;
+pub use stream_local_simple_agg::StreamLocalSimpleAgg;
 pub use stream_materialize::StreamMaterialize;
 pub use stream_project::StreamProject;
+pub use stream_project_set::StreamProjectSet;
+pub use stream_session_window::StreamSessionWindow;
 pub use stream_simple_agg::StreamSimpleAgg;
 pub use stream_source::StreamSource;
 pub use stream_table_scan::StreamTableScan;
@@ -327,6 +347,9 @@ macro_rules! for_all_plan_nodes {
             , { Logical, Limit }
             , { Logical, TopN }
             , { Logical, HopWindow }
+            , { Logical, SessionWindow }
+            , { Logical, TableFunction }
+            , { Logical, ProjectSet }
             // , { Logical, Sort } we don't need a LogicalSort, just require the Order
             , { Batch, SimpleAgg }
             , { Batch, HashAgg }
@@ -342,6 +365,9 @@ macro_rules! for_all_plan_nodes {
             , { Batch, Limit }
             , { Batch, TopN }
             , { Batch, HopWindow }
+            , { Batch, SessionWindow }
+            , { Batch, TableFunction }
+            , { Batch, ProjectSet }
             , { Stream, Project }
             , { Stream, Filter }
             , { Stream, TableScan }
@@ -349,12 +375,16 @@ macro_rules! for_all_plan_nodes {
             , { Stream, HashJoin }
             , { Stream, Exchange }
             , { Stream, HashAgg }
+            , { Stream, LocalHashAgg }
+            , { Stream, LocalSimpleAgg }
             , { Stream, SimpleAgg }
             , { Stream, Materialize }
             , { Stream, TopN }
             , { Stream, HopWindow }
+            , { Stream, SessionWindow }
             , { Stream, DeltaJoin }
             , { Stream, IndexScan }
+            , { Stream, ProjectSet }
         }
     };
 }
@@ -378,6 +408,9 @@ macro_rules! for_logical_plan_nodes {
             , { Logical, Limit }
             , { Logical, TopN }
             , { Logical, HopWindow }
+            , { Logical, SessionWindow }
+            , { Logical, TableFunction }
+            , { Logical, ProjectSet }
             // , { Logical, Sort} not sure if we will support Order by clause in subquery/view/MV
             // if we dont support thatk, we don't need LogicalSort, just require the Order at the top of query
         }
@@ -404,6 +437,9 @@ macro_rules! for_batch_plan_nodes {
             , { Batch, Insert }
             , { Batch, Delete }
             , { Batch, HopWindow }
+            , { Batch, SessionWindow }
+            , { Batch, TableFunction }
+            , { Batch, ProjectSet }
         }
     };
 }
@@ -421,12 +457,16 @@ macro_rules! for_stream_plan_nodes {
             , { Stream, TableScan }
             , { Stream, Source }
             , { Stream, HashAgg }
+            , { Stream, LocalHashAgg }
+            , { Stream, LocalSimpleAgg }
             , { Stream, SimpleAgg }
             , { Stream, Materialize }
             , { Stream, TopN }
             , { Stream, HopWindow }
+            , { Stream, SessionWindow }
             , { Stream, DeltaJoin }
             , { Stream, IndexScan }
+            , { Stream, ProjectSet }
         }
     };
 }
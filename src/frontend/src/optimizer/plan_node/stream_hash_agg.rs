@@ -26,10 +26,22 @@ use crate::optimizer::property::Distribution;
 pub struct StreamHashAgg {
     pub base: PlanBase,
     logical: LogicalAgg,
+    /// Whether to emit only the final result of each group after the watermark of the first group
+    /// key passes it, instead of every update.
+    emit_on_window_close: bool,
 }
 
 impl StreamHashAgg {
     pub fn new(logical: LogicalAgg) -> Self {
+        Self::new_inner(logical, false)
+    }
+
+    /// Returns the same aggregation which emits on window close. The output is append-only.
+    pub fn with_emit_on_window_close(&self) -> Self {
+        Self::new_inner(self.logical.clone(), true)
+    }
+
+    fn new_inner(logical: LogicalAgg, emit_on_window_close: bool) -> Self {
         let ctx = logical.base.ctx.clone();
         let pk_indices = logical.base.pk_indices.to_vec();
         let input = logical.input();
@@ -48,9 +60,20 @@ impl StreamHashAgg {
                     .rewrite_provided_distribution(input_dist)
             }
         };
-        // Hash agg executor might change the append-only behavior of the stream.
-        let base = PlanBase::new_stream(ctx, logical.schema().clone(), pk_indices, dist, false);
-        StreamHashAgg { base, logical }
+        // Hash agg executor might change the append-only behavior of the stream, unless it only
+        // emits the final results.
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            pk_indices,
+            dist,
+            emit_on_window_close,
+        );
+        StreamHashAgg {
+            base,
+            logical,
+            emit_on_window_close,
+        }
     }
 
     pub fn agg_calls(&self) -> &[PlanAggCall] {
@@ -60,11 +83,16 @@ impl StreamHashAgg {
     pub fn distribution_keys(&self) -> &[usize] {
         self.logical.group_keys()
     }
+
+    pub fn emit_on_window_close(&self) -> bool {
+        self.emit_on_window_close
+    }
 }
 
 impl fmt::Display for StreamHashAgg {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.debug_struct("StreamHashAgg")
+        let mut builder = f.debug_struct("StreamHashAgg");
+        builder
             .field(
                 "group_keys",
                 &self
@@ -74,8 +102,11 @@ impl fmt::Display for StreamHashAgg {
                     .map(InputRefDisplay)
                     .collect_vec(),
             )
-            .field("aggs", &self.agg_calls())
-            .finish()
+            .field("aggs", &self.agg_calls());
+        if self.emit_on_window_close {
+            builder.field("emit_on_window_close", &true);
+        }
+        builder.finish()
     }
 }
 
@@ -85,7 +116,10 @@ impl PlanTreeNodeUnary for StreamHashAgg {
     }
 
     fn clone_with_input(&self, input: PlanRef) -> Self {
-        Self::new(self.logical.clone_with_input(input))
+        Self::new_inner(
+            self.logical.clone_with_input(input),
+            self.emit_on_window_close,
+        )
     }
 }
 impl_plan_tree_node_for_unary! { StreamHashAgg }
@@ -106,6 +140,7 @@ impl ToStreamProst for StreamHashAgg {
                 .map(PlanAggCall::to_protobuf)
                 .collect_vec(),
             table_ids: vec![],
+            emit_on_window_close: self.emit_on_window_close,
         })
     }
 }
@@ -17,12 +17,12 @@ use std::fmt;
 use itertools::Itertools;
 use risingwave_pb::plan_common::JoinType;
 use risingwave_pb::stream_plan::stream_node::Node;
-use risingwave_pb::stream_plan::HashJoinNode;
+use risingwave_pb::stream_plan::{HashJoinNode, IntervalJoinBound as IntervalJoinBoundProst};
 
 use super::{LogicalJoin, PlanBase, PlanRef, PlanTreeNodeBinary, StreamDeltaJoin, ToStreamProst};
 use crate::catalog::TableId;
 use crate::expr::Expr;
-use crate::optimizer::plan_node::EqJoinPredicate;
+use crate::optimizer::plan_node::{EqJoinPredicate, IntervalJoinBound};
 use crate::optimizer::property::Distribution;
 use crate::utils::ColIndexMapping;
 
@@ -42,6 +42,10 @@ pub struct StreamHashJoin {
     /// be create automatically when building the executors on meta service. For testing purpose
     /// only. Will remove after we have fully support shared state and index.
     is_delta: bool,
+
+    /// If set, the inputs are append-only and the rows can be evicted from the join states once
+    /// they fall out of the time bound relative to the progress of the other side.
+    interval_bound: Option<IntervalJoinBound>,
 }
 
 pub static DELTA_JOIN: &str = "RW_FORCE_DELTA_JOIN";
@@ -81,9 +85,21 @@ impl StreamHashJoin {
             logical,
             eq_join_predicate,
             is_delta: force_delta,
+            interval_bound: None,
         }
     }
 
+    /// Turns this hash join into an interval join with the time bound.
+    pub fn with_interval_bound(mut self, interval_bound: Option<IntervalJoinBound>) -> Self {
+        assert!(
+            interval_bound.is_none()
+                || (self.logical.left().append_only() && self.logical.right().append_only()),
+            "interval join requires append-only inputs"
+        );
+        self.interval_bound = interval_bound;
+        self
+    }
+
     /// Get join type
     pub fn join_type(&self) -> JoinType {
         self.logical.join_type()
@@ -130,15 +146,21 @@ impl fmt::Display for StreamHashJoin {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(
             f,
-            "{} {{ type: {:?}, predicate: {} }}",
+            "{} {{ type: {:?}, predicate: {}",
             if self.is_delta {
                 "StreamDeltaHashJoin"
+            } else if self.interval_bound.is_some() {
+                "StreamIntervalJoin"
             } else {
                 "StreamHashJoin"
             },
             self.logical.join_type(),
             self.eq_join_predicate()
-        )
+        )?;
+        if let Some(interval_bound) = &self.interval_bound {
+            write!(f, ", interval: {}", interval_bound)?;
+        }
+        write!(f, " }}")
     }
 }
 
@@ -156,6 +178,7 @@ impl PlanTreeNodeBinary for StreamHashJoin {
             self.logical.clone_with_left_right(left, right),
             self.eq_join_predicate.clone(),
         )
+        .with_interval_bound(self.interval_bound.clone())
     }
 }
 
@@ -190,6 +213,15 @@ impl ToStreamProst for StreamHashJoin {
                 .map(|idx| *idx as i32)
                 .collect_vec(),
             is_delta_join: self.is_delta,
+            interval_bound: self
+                .interval_bound
+                .as_ref()
+                .map(|bound| IntervalJoinBoundProst {
+                    left_time_col: bound.left_time_col as i32,
+                    right_time_col: bound.right_time_col as i32,
+                    lower: Some(bound.lower.into()),
+                    upper: Some(bound.upper.into()),
+                }),
             ..Default::default()
         })
     }
@@ -41,6 +41,18 @@ impl StreamHopWindow {
         );
         Self { base, logical }
     }
+
+    pub fn time_col_idx(&self) -> usize {
+        self.logical.time_col.index()
+    }
+
+    pub fn window_start_col_idx(&self) -> usize {
+        self.logical.window_start_col_idx()
+    }
+
+    pub fn window_end_col_idx(&self) -> usize {
+        self.logical.window_end_col_idx()
+    }
 }
 
 impl fmt::Display for StreamHopWindow {
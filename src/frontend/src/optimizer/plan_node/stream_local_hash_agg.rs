@@ -0,0 +1,108 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use itertools::Itertools;
+use risingwave_pb::stream_plan::stream_node::Node as ProstStreamNode;
+
+use super::logical_agg::PlanAggCall;
+use super::{LogicalAgg, PlanBase, PlanRef, PlanTreeNodeUnary, ToStreamProst};
+use crate::expr::InputRefDisplay;
+
+/// `StreamLocalHashAgg` pre-aggregates the rows of each group within an epoch before the shuffle
+/// by group keys. Its output is the partial results of the aggregations, which are merged by the
+/// [`super::StreamHashAgg`] of the global phase.
+#[derive(Debug, Clone)]
+pub struct StreamLocalHashAgg {
+    pub base: PlanBase,
+    logical: LogicalAgg,
+}
+
+impl StreamLocalHashAgg {
+    pub fn new(logical: LogicalAgg) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let pk_indices = logical.base.pk_indices.to_vec();
+        let dist = logical
+            .i2o_col_mapping()
+            .rewrite_provided_distribution(logical.input().distribution());
+        // The partial results are emitted as inserts, but they carry the retractions of the input
+        // (e.g. a negative count), so it's append-only only if the input is.
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            pk_indices,
+            dist,
+            logical.input().append_only(),
+        );
+        StreamLocalHashAgg { base, logical }
+    }
+
+    pub fn agg_calls(&self) -> &[PlanAggCall] {
+        self.logical.agg_calls()
+    }
+
+    pub fn group_keys(&self) -> &[usize] {
+        self.logical.group_keys()
+    }
+}
+
+impl fmt::Display for StreamLocalHashAgg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StreamLocalHashAgg")
+            .field(
+                "group_keys",
+                &self
+                    .group_keys()
+                    .iter()
+                    .copied()
+                    .map(InputRefDisplay)
+                    .collect_vec(),
+            )
+            .field("aggs", &self.agg_calls())
+            .finish()
+    }
+}
+
+impl PlanTreeNodeUnary for StreamLocalHashAgg {
+    fn input(&self) -> PlanRef {
+        self.logical.input()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_input(input))
+    }
+}
+impl_plan_tree_node_for_unary! { StreamLocalHashAgg }
+
+impl ToStreamProst for StreamLocalHashAgg {
+    fn to_stream_prost_body(&self) -> ProstStreamNode {
+        use risingwave_pb::stream_plan::*;
+
+        ProstStreamNode::LocalHashAggNode(HashAggNode {
+            distribution_keys: self
+                .group_keys()
+                .iter()
+                .map(|idx| *idx as i32)
+                .collect_vec(),
+            agg_calls: self
+                .agg_calls()
+                .iter()
+                .map(PlanAggCall::to_protobuf)
+                .collect_vec(),
+            table_ids: vec![],
+            emit_on_window_close: false,
+        })
+    }
+}
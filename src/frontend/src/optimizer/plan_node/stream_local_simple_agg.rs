@@ -0,0 +1,86 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_pb::stream_plan::stream_node::Node as ProstStreamNode;
+
+use super::logical_agg::PlanAggCall;
+use super::{LogicalAgg, PlanBase, PlanRef, PlanTreeNodeUnary, ToStreamProst};
+use crate::optimizer::property::Distribution;
+
+/// `StreamLocalSimpleAgg` pre-aggregates the rows of each parallel unit within an epoch before they
+/// are gathered to a single node. Its output is the partial results of the aggregations, which are
+/// merged by the [`super::StreamSimpleAgg`] of the global phase.
+#[derive(Debug, Clone)]
+pub struct StreamLocalSimpleAgg {
+    pub base: PlanBase,
+    logical: LogicalAgg,
+}
+
+impl StreamLocalSimpleAgg {
+    pub fn new(logical: LogicalAgg) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let pk_indices = logical.base.pk_indices.to_vec();
+        // The partial results of different parallel units have no relation with the input columns.
+        let dist = Distribution::AnyShard;
+        // Same as `StreamLocalHashAgg`, the partial results carry the retractions of the input.
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            pk_indices,
+            dist,
+            logical.input().append_only(),
+        );
+        StreamLocalSimpleAgg { base, logical }
+    }
+
+    pub fn agg_calls(&self) -> &[PlanAggCall] {
+        self.logical.agg_calls()
+    }
+}
+
+impl fmt::Display for StreamLocalSimpleAgg {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StreamLocalSimpleAgg")
+            .field("aggs", &self.agg_calls())
+            .finish()
+    }
+}
+
+impl PlanTreeNodeUnary for StreamLocalSimpleAgg {
+    fn input(&self) -> PlanRef {
+        self.logical.input()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_input(input))
+    }
+}
+impl_plan_tree_node_for_unary! { StreamLocalSimpleAgg }
+
+impl ToStreamProst for StreamLocalSimpleAgg {
+    fn to_stream_prost_body(&self) -> ProstStreamNode {
+        use risingwave_pb::stream_plan::*;
+
+        ProstStreamNode::LocalSimpleAggNode(SimpleAggNode {
+            agg_calls: self
+                .agg_calls()
+                .iter()
+                .map(PlanAggCall::to_protobuf)
+                .collect(),
+            distribution_keys: vec![],
+        })
+    }
+}
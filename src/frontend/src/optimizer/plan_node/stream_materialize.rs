@@ -171,6 +171,7 @@ impl StreamMaterialize {
             pks: pk_indices.clone(),
             is_index_on,
             distribution_keys: base.dist.dist_column_indices().to_vec(),
+            ttl_seconds: None,
         };
 
         Ok(Self { base, input, table })
@@ -18,7 +18,7 @@ use risingwave_pb::stream_plan::stream_node::Node as ProstStreamNode;
 use risingwave_pb::stream_plan::ProjectNode;
 
 use super::{LogicalProject, PlanBase, PlanRef, PlanTreeNodeUnary, ToStreamProst};
-use crate::expr::Expr;
+use crate::expr::{Expr, ExprImpl};
 
 /// `StreamProject` implements [`super::LogicalProject`] to evaluate specified expressions on input
 /// rows.
@@ -53,6 +53,10 @@ impl StreamProject {
         );
         StreamProject { base, logical }
     }
+
+    pub fn exprs(&self) -> &Vec<ExprImpl> {
+        self.logical.exprs()
+    }
 }
 
 impl PlanTreeNodeUnary for StreamProject {
@@ -0,0 +1,81 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt;
+
+use risingwave_pb::stream_plan::stream_node::Node as ProstStreamNode;
+use risingwave_pb::stream_plan::SessionWindowNode;
+
+use super::{LogicalSessionWindow, PlanBase, PlanRef, PlanTreeNodeUnary, ToStreamProst};
+
+/// [`StreamSessionWindow`] keeps the rows of each partition in its state, and retracts the
+/// previous output rows whenever their sessions are merged or split, so the output is never
+/// append-only.
+#[derive(Debug, Clone)]
+pub struct StreamSessionWindow {
+    pub base: PlanBase,
+    logical: LogicalSessionWindow,
+}
+
+impl StreamSessionWindow {
+    pub fn new(logical: LogicalSessionWindow) -> Self {
+        let ctx = logical.base.ctx.clone();
+        let pk_indices = logical.base.pk_indices.to_vec();
+        let distribution = logical
+            .i2o_col_mapping()
+            .rewrite_provided_distribution(logical.input().distribution());
+
+        let base = PlanBase::new_stream(
+            ctx,
+            logical.schema().clone(),
+            pk_indices,
+            distribution,
+            false,
+        );
+        Self { base, logical }
+    }
+}
+
+impl fmt::Display for StreamSessionWindow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.logical.fmt_with_name(f, "StreamSessionWindow")
+    }
+}
+
+impl PlanTreeNodeUnary for StreamSessionWindow {
+    fn input(&self) -> PlanRef {
+        self.logical.input()
+    }
+
+    fn clone_with_input(&self, input: PlanRef) -> Self {
+        Self::new(self.logical.clone_with_input(input))
+    }
+}
+
+impl_plan_tree_node_for_unary! {StreamSessionWindow}
+
+impl ToStreamProst for StreamSessionWindow {
+    fn to_stream_prost_body(&self) -> ProstStreamNode {
+        ProstStreamNode::SessionWindowNode(SessionWindowNode {
+            time_col: Some(self.logical.time_col.to_proto()),
+            gap: Some(self.logical.gap.into()),
+            partition_keys: self
+                .logical
+                .partition_keys
+                .iter()
+                .map(|key| key.to_proto())
+                .collect(),
+        })
+    }
+}
@@ -35,11 +35,21 @@ impl StreamSource {
             logical.schema().clone(),
             logical.pk_indices().to_vec(),
             Distribution::any().clone(),
-            false, // TODO: determine the `append-only` field of source
+            logical.source_catalog.append_only,
         );
         Self { base, logical }
     }
 
+    /// The index of the column that watermarks are generated on, if any.
+    pub fn watermark_col_idx(&self) -> Option<usize> {
+        let source_catalog = &self.logical.source_catalog;
+        let column_id = source_catalog.watermark_column_id?;
+        source_catalog
+            .columns
+            .iter()
+            .position(|c| c.column_id() == column_id)
+    }
+
     pub fn column_names(&self) -> Vec<String> {
         self.schema()
             .fields()
@@ -0,0 +1,59 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use super::super::plan_node::*;
+use super::{BoxedRule, Rule};
+use crate::expr::fold_constant;
+
+/// Fold constant expressions and simplify the expressions in [`LogicalProject`], the predicate of
+/// [`LogicalFilter`] and the join condition of [`LogicalJoin`], so that they are evaluated once in
+/// the frontend instead of on every row.
+pub struct ConstantFoldingRule {}
+impl Rule for ConstantFoldingRule {
+    fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
+        if let Some(project) = plan.as_logical_project() {
+            let exprs = project
+                .exprs()
+                .iter()
+                .cloned()
+                .map(fold_constant)
+                .collect::<Vec<_>>();
+            if exprs == *project.exprs() {
+                return None;
+            }
+            Some(
+                LogicalProject::new(project.input(), exprs, project.expr_alias().to_owned()).into(),
+            )
+        } else if let Some(filter) = plan.as_logical_filter() {
+            let predicate = filter.predicate().clone().fold_constant();
+            if predicate.conjunctions == filter.predicate().conjunctions {
+                return None;
+            }
+            Some(LogicalFilter::create(filter.input(), predicate))
+        } else if let Some(join) = plan.as_logical_join() {
+            let on = join.on().clone().fold_constant();
+            if on.conjunctions == join.on().conjunctions {
+                return None;
+            }
+            Some(join.clone_with_cond(on).into())
+        } else {
+            None
+        }
+    }
+}
+
+impl ConstantFoldingRule {
+    pub fn create() -> BoxedRule {
+        Box::new(ConstantFoldingRule {})
+    }
+}
@@ -54,7 +54,12 @@ impl Rule for FilterJoinRule {
         let left_col_num = join.left().schema().len();
         let right_col_num = join.right().schema().len();
 
-        let mut new_filter_predicate = filter.predicate().clone();
+        // The filter refers to the output columns of the join, which are only one side for semi
+        // and anti joins, while the pushed predicates refer to the columns of both sides.
+        let mut new_filter_predicate = filter
+            .predicate()
+            .clone()
+            .rewrite_expr(&mut join.o2i_col_mapping());
 
         let (left_from_filter, right_from_filter, on) = self.push_down(
             &mut new_filter_predicate,
@@ -86,6 +91,8 @@ impl Rule for FilterJoinRule {
         let new_left = LogicalFilter::create(join.left(), left_predicate);
         let new_right = LogicalFilter::create(join.right(), right_predicate);
         let new_join = LogicalJoin::new(new_left, new_right, join_type, new_on);
+        let new_filter_predicate =
+            new_filter_predicate.rewrite_expr(&mut new_join.i2o_col_mapping());
 
         Some(LogicalFilter::create(new_join.into(), new_filter_predicate))
     }
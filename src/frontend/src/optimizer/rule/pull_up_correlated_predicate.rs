@@ -29,7 +29,10 @@ pub struct PullUpCorrelatedPredicate {}
 impl Rule for PullUpCorrelatedPredicate {
     fn apply(&self, plan: PlanRef) -> Option<PlanRef> {
         let apply = plan.as_logical_apply()?;
-        if !matches!(apply.join_type(), JoinType::LeftOuter | JoinType::LeftSemi) {
+        if !matches!(
+            apply.join_type(),
+            JoinType::LeftOuter | JoinType::LeftSemi | JoinType::LeftAnti
+        ) {
             return None;
         }
 
@@ -17,14 +17,16 @@ use std::rc::Rc;
 use itertools::Itertools;
 use risingwave_common::error::{ErrorCode, Result};
 use risingwave_common::types::ScalarImpl;
+use risingwave_common::util::session_window::SessionGap;
 
 use crate::binder::{
     BoundBaseTable, BoundJoin, BoundSource, BoundWindowTableFunction, Relation,
     WindowTableFunctionKind,
 };
-use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef};
+use crate::expr::{ExprImpl, ExprType, FunctionCall, InputRef, TableFunction};
 use crate::optimizer::plan_node::{
-    LogicalHopWindow, LogicalJoin, LogicalProject, LogicalScan, LogicalSource, PlanRef,
+    LogicalHopWindow, LogicalJoin, LogicalProject, LogicalScan, LogicalSessionWindow,
+    LogicalSource, LogicalTableFunction, PlanRef,
 };
 use crate::planner::Planner;
 
@@ -37,6 +39,7 @@ impl Planner {
             Relation::Join(join) => self.plan_join(*join),
             Relation::WindowTableFunction(tf) => self.plan_window_table_function(*tf),
             Relation::Source(s) => self.plan_source(*s),
+            Relation::TableFunction(tf) => self.plan_table_function(*tf),
         }
     }
 
@@ -57,6 +60,10 @@ impl Planner {
         Ok(LogicalSource::new(Rc::new(source.catalog), self.ctx()).into())
     }
 
+    pub(super) fn plan_table_function(&mut self, table_function: TableFunction) -> Result<PlanRef> {
+        Ok(LogicalTableFunction::new(table_function, self.ctx()).into())
+    }
+
     pub(super) fn plan_join(&mut self, join: BoundJoin) -> Result<PlanRef> {
         let left = self.plan_relation(join.left)?;
         let right = self.plan_relation(join.right)?;
@@ -81,6 +88,11 @@ impl Planner {
                 table_function.time_col,
                 table_function.args,
             ),
+            Session => self.plan_session_window(
+                table_function.input,
+                table_function.time_col,
+                table_function.args,
+            ),
         }
     }
 
@@ -156,4 +168,43 @@ impl Planner {
             window_size,
         ))
     }
+
+    /// Plans `SESSION(table, time_col, gap [, partition_key ...])`.
+    fn plan_session_window(
+        &mut self,
+        input: Relation,
+        time_col: InputRef,
+        args: Vec<ExprImpl>,
+    ) -> Result<PlanRef> {
+        let input = self.plan_relation(input)?;
+        let mut args = args.into_iter();
+        let Some(ExprImpl::Literal(gap)) = args.next() else {
+            return Err(ErrorCode::BindError("Invalid arguments for SESSION window function".to_string()).into());
+        };
+        let Some(ScalarImpl::Interval(gap)) = *gap.get_data() else {
+            return Err(ErrorCode::BindError("Invalid arguments for SESSION window function".to_string()).into());
+        };
+        if SessionGap::from_interval(gap).is_none() {
+            return Err(ErrorCode::BindError(format!(
+                "the gap of SESSION window function should be a positive interval without months, got {}",
+                gap
+            ))
+            .into());
+        }
+        let partition_keys = args
+            .map(|arg| match arg {
+                ExprImpl::InputRef(input_ref) => Ok(*input_ref),
+                _ => Err(ErrorCode::BindError(
+                    "the partition keys of SESSION window function should be columns".to_string(),
+                )
+                .into()),
+            })
+            .collect::<Result<_>>()?;
+        Ok(LogicalSessionWindow::create(
+            input,
+            time_col,
+            gap,
+            partition_keys,
+        ))
+    }
 }
@@ -24,7 +24,8 @@ use crate::expr::{
 };
 pub use crate::optimizer::plan_node::LogicalFilter;
 use crate::optimizer::plan_node::{
-    LogicalAgg, LogicalApply, LogicalJoin, LogicalProject, LogicalValues, PlanAggCall, PlanRef,
+    LogicalAgg, LogicalApply, LogicalJoin, LogicalProject, LogicalProjectSet, LogicalValues,
+    PlanAggCall, PlanRef,
 };
 use crate::planner::Planner;
 use crate::utils::Condition;
@@ -52,14 +53,48 @@ impl Planner {
         // Plan the SELECT clause.
         // TODO: select-agg, group-by, having can also contain subquery exprs.
         let has_agg_call = select_items.iter().any(|expr| expr.has_agg_call());
+        let has_table_function = select_items.iter().any(|expr| expr.has_table_function());
         if !group_by.is_empty() || has_agg_call {
+            if has_table_function {
+                return Err(ErrorCode::NotImplemented(
+                    "table functions with aggregation".to_string(),
+                    None.into(),
+                )
+                .into());
+            }
             LogicalAgg::create(select_items, aliases, group_by, root)
         } else {
             if select_items.iter().any(|e| e.has_subquery()) {
                 (root, select_items) = self.substitute_subqueries(root, select_items)?;
             }
-            Ok(LogicalProject::create(root, select_items, aliases))
+            if has_table_function {
+                self.plan_project_set(root, select_items, aliases)
+            } else {
+                Ok(LogicalProject::create(root, select_items, aliases))
+            }
+        }
+    }
+
+    /// Plans a select list containing table functions as [`LogicalProjectSet`], where the table
+    /// functions are evaluated for each input row and the results are expanded into multiple rows.
+    fn plan_project_set(
+        &mut self,
+        root: PlanRef,
+        select_items: Vec<ExprImpl>,
+        aliases: Vec<Option<String>>,
+    ) -> Result<PlanRef> {
+        let nested = |expr: &ExprImpl| match expr {
+            ExprImpl::TableFunction(tf) => tf.args.iter().any(|arg| arg.has_table_function()),
+            _ => expr.has_table_function(),
+        };
+        if select_items.iter().any(nested) {
+            return Err(ErrorCode::NotImplemented(
+                "table functions nested in other expressions".to_string(),
+                None.into(),
+            )
+            .into());
         }
+        Ok(LogicalProjectSet::create(root, select_items, aliases))
     }
 
     /// Helper to create a dummy node as child of [`LogicalProject`].
@@ -38,13 +38,17 @@ impl HummockSnapshotManager {
     pub async fn get_epoch(&self) -> Result<u64> {
         let mut core_guard = self.core.lock().await;
         if core_guard.is_outdated {
-            let epoch = self
-                .meta_client
-                .pin_snapshot(core_guard.last_pinned)
-                .await?;
+            // Pass the greatest epoch pinned by us, so that meta won't unpin the historical
+            // snapshots pinned by `pin_specific_snapshot`.
+            let last_pinned = core_guard
+                .reference_number
+                .keys()
+                .copied()
+                .fold(core_guard.last_pinned, u64::max);
+            let epoch = self.meta_client.pin_snapshot(last_pinned).await?;
             core_guard.is_outdated = false;
             core_guard.last_pinned = epoch;
-            core_guard.reference_number.insert(epoch, 1);
+            *core_guard.reference_number.entry(epoch).or_default() += 1;
         } else {
             let last_pinned = core_guard.last_pinned;
             *core_guard.reference_number.get_mut(&last_pinned).unwrap() += 1;
@@ -52,6 +56,19 @@ impl HummockSnapshotManager {
         Ok(core_guard.last_pinned)
     }
 
+    /// Pin the snapshot of a historical `epoch`, which is used by `SELECT ... AS OF`. It should
+    /// be released via `unpin_snapshot` as well.
+    pub async fn pin_specific_snapshot(&self, epoch: u64) -> Result<u64> {
+        let mut core_guard = self.core.lock().await;
+        if let Some(reference_number) = core_guard.reference_number.get_mut(&epoch) {
+            *reference_number += 1;
+        } else {
+            let epoch = self.meta_client.pin_specific_snapshot(epoch).await?;
+            core_guard.reference_number.insert(epoch, 1);
+        }
+        Ok(epoch)
+    }
+
     pub async fn unpin_snapshot(&self, epoch: u64) -> Result<()> {
         let mut core_guard = self.core.lock().await;
         let reference_number = core_guard.reference_number.get_mut(&epoch);
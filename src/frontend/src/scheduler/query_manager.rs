@@ -97,13 +97,23 @@ impl QueryManager {
         Ok(query_result_fetcher.run())
     }
 
+    /// Schedule a distributed query. It reads the latest snapshot, or the snapshot of `as_of`
+    /// epoch if it's specified.
     pub async fn schedule(
         &self,
         _context: ExecutionContextRef,
         query: Query,
+        as_of: Option<u64>,
     ) -> Result<impl DataChunkStream> {
         // Cheat compiler to resolve type
-        let epoch = self.hummock_snapshot_manager.get_epoch().await?;
+        let epoch = match as_of {
+            Some(epoch) => {
+                self.hummock_snapshot_manager
+                    .pin_specific_snapshot(epoch)
+                    .await?
+            }
+            None => self.hummock_snapshot_manager.get_epoch().await?,
+        };
 
         let query_execution = QueryExecution::new(
             query,
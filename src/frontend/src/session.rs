@@ -38,6 +38,7 @@ use crate::catalog::catalog_service::{CatalogReader, CatalogWriter, CatalogWrite
 use crate::catalog::root_catalog::Catalog;
 use crate::handler::handle;
 use crate::handler::query::IMPLICIT_FLUSH;
+use crate::handler::set::TIMEZONE;
 use crate::meta_client::{FrontendMetaClient, FrontendMetaClientImpl};
 use crate::observer::observer_manager::ObserverManager;
 use crate::optimizer::plan_node::PlanNodeId;
@@ -319,6 +320,13 @@ impl SessionImpl {
         reader.get(key).cloned()
     }
 
+    /// The session time zone set by `SET timezone`.
+    pub fn timezone(&self) -> String {
+        self.get_config(TIMEZONE)
+            .map(|entry| entry.str_val)
+            .unwrap_or_else(|| "UTC".to_string())
+    }
+
     fn init_config_map() -> RwLock<HashMap<String, ConfigEntry>> {
         let mut map = HashMap::new();
         // FIXME: May need better init way + default config.
@@ -326,6 +334,7 @@ impl SessionImpl {
             IMPLICIT_FLUSH.to_string(),
             ConfigEntry::new("false".to_string()),
         );
+        map.insert(TIMEZONE.to_string(), ConfigEntry::new("UTC".to_string()));
         RwLock::new(map)
     }
 }
@@ -25,7 +25,8 @@ use risingwave_common::catalog::{TableId, DEFAULT_DATABASE_NAME, DEFAULT_SCHEMA_
 use risingwave_common::error::Result;
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
 use risingwave_pb::catalog::{
-    Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    Database as ProstDatabase, Function as ProstFunction, Schema as ProstSchema,
+    Source as ProstSource, Table as ProstTable,
 };
 use risingwave_pb::stream_plan::StreamNode;
 use risingwave_sqlparser::ast::Statement;
@@ -91,6 +92,7 @@ impl LocalFrontend {
                 let mut binder = Binder::new(
                     session.env().catalog_reader().read_guard(),
                     session.database().to_string(),
+                    session.timezone(),
                 );
                 binder.bind(Statement::Query(query.clone()))?
             };
@@ -192,6 +194,21 @@ impl CatalogWriter for MockCatalogWriter {
             .drop_table(database_id, schema_id, table_id);
         Ok(())
     }
+
+    async fn create_function(&self, mut function: ProstFunction) -> Result<()> {
+        function.id = self.gen_id();
+        self.catalog.write().create_function(&function);
+        self.add_id(function.id, function.database_id, function.schema_id);
+        Ok(())
+    }
+
+    async fn drop_function(&self, function_id: u32) -> Result<()> {
+        let (database_id, schema_id) = self.drop_id(function_id);
+        self.catalog
+            .write()
+            .drop_function(database_id, schema_id, function_id);
+        Ok(())
+    }
 }
 
 impl MockCatalogWriter {
@@ -242,6 +259,10 @@ impl FrontendMetaClient for MockFrontendMetaClient {
         Ok(0)
     }
 
+    async fn pin_specific_snapshot(&self, epoch: u64) -> Result<u64> {
+        Ok(epoch)
+    }
+
     async fn flush(&self) -> Result<()> {
         Ok(())
     }
@@ -19,8 +19,9 @@ use fixedbitset::FixedBitSet;
 use itertools::Itertools;
 
 use crate::expr::{
-    factorization_expr, fold_boolean_constant, push_down_not, to_conjunctions,
-    try_get_bool_constant, ExprImpl, ExprRewriter, ExprType, ExprVisitor, InputRef,
+    factorization_expr, fold_boolean_constant, fold_constant_predicate, push_down_not,
+    to_conjunctions, try_get_bool_constant, ExprImpl, ExprRewriter, ExprType, ExprVisitor,
+    InputRef,
 };
 
 #[derive(Debug, Clone)]
@@ -212,6 +213,19 @@ impl Condition {
         }
     }
 
+    /// Fold constants in each conjunction, see [`fold_constant_predicate`].
+    #[must_use]
+    pub fn fold_constant(self) -> Self {
+        Self {
+            conjunctions: self
+                .conjunctions
+                .into_iter()
+                .map(fold_constant_predicate)
+                .collect(),
+        }
+        .simplify()
+    }
+
     pub fn visit_expr(&self, visitor: &mut impl ExprVisitor) {
         self.conjunctions
             .iter()
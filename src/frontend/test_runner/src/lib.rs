@@ -246,8 +246,13 @@ impl TestCase {
                     }
                     result = Some(ret);
                 }
-                Statement::CreateTable { name, columns, .. } => {
-                    create_table::handle_create_table(context, name, columns).await?;
+                Statement::CreateTable {
+                    name,
+                    columns,
+                    with_options,
+                    ..
+                } => {
+                    create_table::handle_create_table(context, name, columns, with_options).await?;
                 }
                 Statement::CreateSource {
                     is_materialized,
@@ -269,9 +274,12 @@ impl TestCase {
                     or_replace: false,
                     name,
                     query,
+                    with_options,
+                    emit_mode,
                     ..
                 } => {
-                    create_mv::handle_create_mv(context, name, query).await?;
+                    create_mv::handle_create_mv(context, name, query, with_options, emit_mode)
+                        .await?;
                 }
                 Statement::Drop(drop_statement) => {
                     let table_object_name = ObjectName(vec![drop_statement.name]);
@@ -295,6 +303,7 @@ impl TestCase {
             let mut binder = Binder::new(
                 session.env().catalog_reader().read_guard(),
                 session.database().to_string(),
+                session.timezone(),
             );
             match binder.bind(stmt.clone()) {
                 Ok(bound) => bound,
@@ -354,6 +363,7 @@ impl TestCase {
                 context,
                 Box::new(q),
                 ObjectName(vec!["test".into()]),
+                None,
             )?;
 
             // Only generate stream_plan if it is specified in test case
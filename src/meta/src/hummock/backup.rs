@@ -0,0 +1,503 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use itertools::Itertools;
+use prost::Message;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_hummock_sdk::{HummockVersionId, FIRST_VERSION_ID};
+use risingwave_pb::catalog::{
+    Database as DatabaseV2, Function as FunctionV2, Schema as SchemaV2, Source as SourceV2,
+    Table as TableV2,
+};
+use risingwave_pb::hummock::meta_snapshot::ColumnFamily;
+use risingwave_pb::hummock::{
+    BackupTask, BackupTaskAssignment, BackupTaskRefId, HummockVersion, MetaSnapshot, SstableIdInfo,
+};
+use risingwave_pb::meta::{Database, Schema, Table};
+
+use crate::cluster::META_NODE_ID;
+use crate::hummock::model::{sstable_id_info, CurrentHummockVersionId};
+use crate::hummock::{CompactorManagerRef, HummockManagerRef};
+use crate::manager::{IdCategory, MetaSrvEnv};
+use crate::model::{CatalogVersionGenerator, MetadataModel, ParallelUnitMapping, TableFragments};
+use crate::storage::{MetaStore, Snapshot, Transaction, DEFAULT_COLUMN_FAMILY};
+
+/// Column families included in a backup besides the hummock version, including catalogs, table
+/// fragments and id generators.
+fn backup_column_families() -> Vec<String> {
+    vec![
+        Database::cf_name(),
+        Schema::cf_name(),
+        Table::cf_name(),
+        CatalogVersionGenerator::cf_name(),
+        DatabaseV2::cf_name(),
+        SchemaV2::cf_name(),
+        TableV2::cf_name(),
+        SourceV2::cf_name(),
+        FunctionV2::cf_name(),
+        TableFragments::cf_name(),
+        ParallelUnitMapping::cf_name(),
+        DEFAULT_COLUMN_FAMILY.to_string(),
+    ]
+}
+
+/// `BackupManager` takes backups of meta and SST data.
+///
+/// A backup is taken in these steps:
+/// - 1 Pin the current hummock version, so that the SSTs it references won't be vacuumed.
+/// - 2 Take a consistent snapshot of the meta store.
+/// - 3 Dispatch a `BackupTask` to a compactor, which copies the SSTs and the snapshot to
+///   `{backup_prefix}/{backup_id}` in object store.
+/// - 4 The compactor reports the result via `report_backup_task`, and the version is unpinned.
+///
+/// Backups dispatched to compactors are persisted as `BackupTaskAssignment`s. A backup is aborted
+/// and its version unpinned if it's not reported in time, or if its compactor is removed. Backups
+/// still pending when meta restarts are aborted as well.
+///
+/// A backup can be restored into a fresh meta store via `restore_meta_snapshot`.
+pub struct BackupManager<S: MetaStore> {
+    env: MetaSrvEnv<S>,
+    hummock_manager: HummockManagerRef<S>,
+    compactor_manager: CompactorManagerRef,
+    /// Backups which have been dispatched to compactors but are not reported yet.
+    ongoing_backups: parking_lot::Mutex<HashMap<u64, BackupTaskAssignment>>,
+}
+
+impl<S> BackupManager<S>
+where
+    S: MetaStore,
+{
+    pub async fn new(
+        env: MetaSrvEnv<S>,
+        hummock_manager: HummockManagerRef<S>,
+        compactor_manager: CompactorManagerRef,
+    ) -> Result<Self> {
+        // Backups pending before restart are aborted, because their versions are not guaranteed
+        // to be pinned anymore.
+        for assignment in BackupTaskAssignment::list(env.meta_store()).await? {
+            tracing::warn!(
+                "Abort backup {} which is not finished before restart",
+                assignment.backup_id
+            );
+            hummock_manager
+                .unpin_version(META_NODE_ID, [assignment.version_id])
+                .await?;
+            BackupTaskAssignment::delete(env.meta_store(), &assignment.key()?).await?;
+        }
+        Ok(Self {
+            env,
+            hummock_manager,
+            compactor_manager,
+            ongoing_backups: Default::default(),
+        })
+    }
+
+    /// Start a backup and return its id. The backup finishes asynchronously in a compactor.
+    pub async fn backup(&self) -> Result<u64> {
+        let compactor = self.compactor_manager.next_compactor().ok_or_else(|| {
+            ErrorCode::MetaError("No compactor is available for backup".to_string())
+        })?;
+        let backup_id = self
+            .env
+            .id_gen_manager()
+            .generate::<{ IdCategory::Backup }>()
+            .await? as u64;
+
+        // The version is pinned before the meta store snapshot is taken, so that the catalog
+        // contains every table whose data is in the version.
+        let hummock_version = self
+            .hummock_manager
+            .pin_version(META_NODE_ID, HummockVersionId::MAX)
+            .await?;
+        let assignment = BackupTaskAssignment {
+            backup_id,
+            version_id: hummock_version.id,
+            context_id: compactor.context_id(),
+            assign_timestamp: sstable_id_info::get_timestamp_now(),
+        };
+        if let Err(e) = assignment.insert(self.env.meta_store()).await {
+            self.unpin_version_if_unused(hummock_version.id).await?;
+            return Err(e);
+        }
+        self.ongoing_backups.lock().insert(backup_id, assignment);
+
+        let backup_task = match self.build_backup_task(backup_id, hummock_version).await {
+            Ok(backup_task) => backup_task,
+            Err(e) => {
+                self.finish_backup(backup_id).await?;
+                return Err(e);
+            }
+        };
+        if let Err(e) = compactor.send_backup_task(backup_task).await {
+            tracing::warn!("Failed to send backup task. {}", e);
+            self.compactor_manager
+                .remove_compactor(compactor.context_id());
+            self.finish_backup(backup_id).await?;
+            return Err(e);
+        }
+        tracing::info!(
+            "Start backup {} in compactor {}",
+            backup_id,
+            compactor.context_id()
+        );
+        Ok(backup_id)
+    }
+
+    pub async fn report_backup_task(&self, backup_id: u64, succeeded: bool) -> Result<()> {
+        if !self.ongoing_backups.lock().contains_key(&backup_id) {
+            tracing::warn!(
+                "Backup {} is reported but it's not ongoing. It may have been aborted",
+                backup_id
+            );
+            return Ok(());
+        }
+        if succeeded {
+            tracing::info!("Finish backup {}", backup_id);
+        } else {
+            tracing::warn!("Backup {} failed", backup_id);
+        }
+        self.finish_backup(backup_id).await
+    }
+
+    async fn build_backup_task(
+        &self,
+        backup_id: u64,
+        hummock_version: HummockVersion,
+    ) -> Result<BackupTask> {
+        let snapshot = self.env.meta_store().snapshot().await;
+        let mut column_families = vec![];
+        for cf in backup_column_families() {
+            let (keys, values) = snapshot.list_cf_kv(&cf).await?.into_iter().unzip();
+            column_families.push(ColumnFamily {
+                name: cf,
+                keys,
+                values,
+            });
+        }
+        let sstable_ids = hummock_version
+            .levels
+            .iter()
+            .flat_map(|level| level.table_infos.iter().map(|sst| sst.id))
+            .collect_vec();
+        Ok(BackupTask {
+            backup_id,
+            backup_prefix: self.env.opts.backup_prefix.clone(),
+            sstable_ids,
+            meta_snapshot: Some(MetaSnapshot {
+                backup_id,
+                hummock_version: Some(hummock_version),
+                column_families,
+            }),
+        })
+    }
+
+    /// Abort ongoing backups which are not reported within `timeout`, or whose compactors have
+    /// been removed.
+    pub async fn abort_expired_backups(&self, timeout: Duration) -> Result<()> {
+        let now = sstable_id_info::get_timestamp_now();
+        let ongoing_backups = self.ongoing_backups.lock().values().cloned().collect_vec();
+        for assignment in ongoing_backups {
+            if now >= assignment.assign_timestamp + timeout.as_secs() {
+                tracing::warn!("Abort backup {} due to timeout", assignment.backup_id);
+            } else if !self.compactor_manager.has_compactor(assignment.context_id) {
+                tracing::warn!(
+                    "Abort backup {} because compactor {} is removed",
+                    assignment.backup_id,
+                    assignment.context_id
+                );
+            } else {
+                continue;
+            }
+            self.finish_backup(assignment.backup_id).await?;
+        }
+        Ok(())
+    }
+
+    /// Remove `backup_id` from ongoing backups and unpin its version, unless it's still used by
+    /// other ongoing backups.
+    async fn finish_backup(&self, backup_id: u64) -> Result<()> {
+        let assignment = match self.ongoing_backups.lock().remove(&backup_id) {
+            None => return Ok(()),
+            Some(assignment) => assignment,
+        };
+        BackupTaskAssignment::delete(self.env.meta_store(), &BackupTaskRefId { id: backup_id })
+            .await?;
+        self.unpin_version_if_unused(assignment.version_id).await
+    }
+
+    async fn unpin_version_if_unused(&self, version_id: HummockVersionId) -> Result<()> {
+        if self
+            .ongoing_backups
+            .lock()
+            .values()
+            .any(|assignment| assignment.version_id == version_id)
+        {
+            return Ok(());
+        }
+        self.hummock_manager
+            .unpin_version(META_NODE_ID, [version_id])
+            .await
+    }
+}
+
+/// Write `meta_snapshot` into a fresh `meta_store`. The hummock version in the snapshot becomes
+/// the only version, and all SSTs it references are tracked in meta.
+pub async fn restore_meta_snapshot<S: MetaStore>(
+    meta_store: &S,
+    meta_snapshot: MetaSnapshot,
+) -> Result<()> {
+    if CurrentHummockVersionId::get(meta_store).await?.is_some() {
+        return Err(ErrorCode::MetaError(
+            "Cannot restore a backup into a non-empty meta store".to_string(),
+        )
+        .into());
+    }
+    let mut hummock_version = meta_snapshot.hummock_version.ok_or_else(|| {
+        ErrorCode::MetaError("Hummock version is missing in the backup".to_string())
+    })?;
+    hummock_version.id = FIRST_VERSION_ID;
+
+    let mut trx = Transaction::default();
+    for cf in meta_snapshot.column_families {
+        for (key, value) in cf.keys.into_iter().zip_eq(cf.values.into_iter()) {
+            trx.put(cf.name.clone(), key, value);
+        }
+    }
+    hummock_version.upsert_in_transaction(&mut trx)?;
+    CurrentHummockVersionId::new().upsert_in_transaction(&mut trx)?;
+    let now = sstable_id_info::get_timestamp_now();
+    for level in &hummock_version.levels {
+        for sst in &level.table_infos {
+            SstableIdInfo {
+                id: sst.id,
+                id_create_timestamp: now,
+                meta_create_timestamp: now,
+                meta_delete_timestamp: sstable_id_info::INVALID_TIMESTAMP,
+            }
+            .upsert_in_transaction(&mut trx)?;
+        }
+    }
+    meta_store.txn(trx).await?;
+    Ok(())
+}
+
+/// Decode a `MetaSnapshot` written by a backup task.
+pub fn decode_meta_snapshot(buf: &[u8]) -> Result<MetaSnapshot> {
+    MetaSnapshot::decode(buf).map_err(|e| ErrorCode::ProstError(e).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::hummock::test_utils::{
+        add_test_tables, get_sorted_committed_sstable_ids, setup_compute_env,
+    };
+    use crate::hummock::CompactorManager;
+    use crate::storage::MemStore;
+
+    #[tokio::test]
+    async fn test_backup_and_restore() {
+        let (env, hummock_manager, _cluster_manager, worker_node) = setup_compute_env(80).await;
+        let context_id = worker_node.id;
+        let compactor_manager = Arc::new(CompactorManager::new());
+        let backup_manager =
+            BackupManager::new(env, hummock_manager.clone(), compactor_manager.clone())
+                .await
+                .unwrap();
+
+        // No compactor is available.
+        assert!(backup_manager.backup().await.is_err());
+        assert_eq!(
+            hummock_manager
+                .get_version_pin_count(hummock_manager.get_current_version().await.id)
+                .await
+                .unwrap(),
+            0
+        );
+
+        add_test_tables(hummock_manager.as_ref(), context_id).await;
+        let mut receiver = compactor_manager.add_compactor(context_id);
+        let backup_id = backup_manager.backup().await.unwrap();
+        let backup_task = receiver.recv().await.unwrap().unwrap().backup_task.unwrap();
+        assert_eq!(backup_task.backup_id, backup_id);
+        assert_eq!(backup_task.backup_prefix, "backup");
+        let current_version = hummock_manager.get_current_version().await;
+        assert_eq!(
+            hummock_manager
+                .get_version_pin_count(current_version.id)
+                .await
+                .unwrap(),
+            1
+        );
+        let expected_sst_ids = get_sorted_committed_sstable_ids(&current_version);
+        assert_eq!(
+            backup_task
+                .sstable_ids
+                .iter()
+                .cloned()
+                .sorted()
+                .collect_vec(),
+            expected_sst_ids
+        );
+
+        backup_manager
+            .report_backup_task(backup_id, true)
+            .await
+            .unwrap();
+        assert_eq!(
+            hummock_manager
+                .get_version_pin_count(current_version.id)
+                .await
+                .unwrap(),
+            0
+        );
+
+        // Restore into a fresh meta store.
+        let meta_snapshot =
+            decode_meta_snapshot(&backup_task.meta_snapshot.as_ref().unwrap().encode_to_vec())
+                .unwrap();
+        let meta_store = MemStore::default();
+        restore_meta_snapshot(&meta_store, meta_snapshot.clone())
+            .await
+            .unwrap();
+        let versions = HummockVersion::list(&meta_store).await.unwrap();
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions[0].levels, current_version.levels);
+        assert_eq!(
+            versions[0].max_committed_epoch,
+            current_version.max_committed_epoch
+        );
+        let sstable_id_infos = SstableIdInfo::list(&meta_store).await.unwrap();
+        assert_eq!(
+            sstable_id_infos
+                .iter()
+                .map(|info| info.id)
+                .sorted()
+                .collect_vec(),
+            expected_sst_ids
+        );
+        // Restoring into a non-empty meta store is rejected.
+        assert!(restore_meta_snapshot(&meta_store, meta_snapshot)
+            .await
+            .is_err());
+    }
+
+    async fn get_current_version_pin_count(hummock_manager: &HummockManagerRef<MemStore>) -> u64 {
+        hummock_manager
+            .get_version_pin_count(hummock_manager.get_current_version().await.id)
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_abort_expired_backups() {
+        let (env, hummock_manager, _cluster_manager, worker_node) = setup_compute_env(80).await;
+        let context_id = worker_node.id;
+        let compactor_manager = Arc::new(CompactorManager::new());
+        let backup_manager = BackupManager::new(
+            env.clone(),
+            hummock_manager.clone(),
+            compactor_manager.clone(),
+        )
+        .await
+        .unwrap();
+        add_test_tables(hummock_manager.as_ref(), context_id).await;
+
+        let _receiver = compactor_manager.add_compactor(context_id);
+        let backup_id = backup_manager.backup().await.unwrap();
+        assert_eq!(get_current_version_pin_count(&hummock_manager).await, 1);
+        assert_eq!(
+            BackupTaskAssignment::list(env.meta_store())
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        // Neither timed out nor the compactor is removed.
+        backup_manager
+            .abort_expired_backups(Duration::from_secs(3600))
+            .await
+            .unwrap();
+        assert_eq!(get_current_version_pin_count(&hummock_manager).await, 1);
+
+        // The compactor is removed.
+        compactor_manager.remove_compactor(context_id);
+        backup_manager
+            .abort_expired_backups(Duration::from_secs(3600))
+            .await
+            .unwrap();
+        assert_eq!(get_current_version_pin_count(&hummock_manager).await, 0);
+        assert!(BackupTaskAssignment::list(env.meta_store())
+            .await
+            .unwrap()
+            .is_empty());
+        // A report of an aborted backup is ignored.
+        backup_manager
+            .report_backup_task(backup_id, true)
+            .await
+            .unwrap();
+        assert_eq!(get_current_version_pin_count(&hummock_manager).await, 0);
+
+        // The backup times out.
+        let _receiver = compactor_manager.add_compactor(context_id);
+        backup_manager.backup().await.unwrap();
+        assert_eq!(get_current_version_pin_count(&hummock_manager).await, 1);
+        backup_manager
+            .abort_expired_backups(Duration::ZERO)
+            .await
+            .unwrap();
+        assert_eq!(get_current_version_pin_count(&hummock_manager).await, 0);
+        assert!(BackupTaskAssignment::list(env.meta_store())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_abort_backups_on_restart() {
+        let (env, hummock_manager, _cluster_manager, worker_node) = setup_compute_env(80).await;
+        let context_id = worker_node.id;
+        let compactor_manager = Arc::new(CompactorManager::new());
+        let backup_manager = BackupManager::new(
+            env.clone(),
+            hummock_manager.clone(),
+            compactor_manager.clone(),
+        )
+        .await
+        .unwrap();
+        add_test_tables(hummock_manager.as_ref(), context_id).await;
+        let _receiver = compactor_manager.add_compactor(context_id);
+        backup_manager.backup().await.unwrap();
+        assert_eq!(get_current_version_pin_count(&hummock_manager).await, 1);
+
+        // Pending backups are aborted when `BackupManager` is recovered from the meta store.
+        BackupManager::new(
+            env.clone(),
+            hummock_manager.clone(),
+            compactor_manager.clone(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(get_current_version_pin_count(&hummock_manager).await, 0);
+        assert!(BackupTaskAssignment::list(env.meta_store())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -129,6 +129,7 @@ impl CompactStatus {
                 }),
             }),
             task_status: false,
+            table_ttls: Default::default(),
         };
         self.next_compact_task_id += 1;
         Some(compact_task)
@@ -16,7 +16,7 @@ use std::sync::Arc;
 
 use risingwave_common::error::{ErrorCode, Result, ToErrorStr};
 use risingwave_hummock_sdk::HummockContextId;
-use risingwave_pb::hummock::{CompactTask, SubscribeCompactTasksResponse, VacuumTask};
+use risingwave_pb::hummock::{BackupTask, CompactTask, SubscribeCompactTasksResponse, VacuumTask};
 use tokio::sync::mpsc::{Receiver, Sender};
 
 const STREAM_BUFFER_SIZE: usize = 4;
@@ -38,6 +38,18 @@ impl Compactor {
             .send(Ok(SubscribeCompactTasksResponse {
                 compact_task,
                 vacuum_task,
+                backup_task: None,
+            }))
+            .await
+            .map_err(|e| ErrorCode::InternalError(e.to_error_str()).into())
+    }
+
+    pub async fn send_backup_task(&self, backup_task: BackupTask) -> Result<()> {
+        self.sender
+            .send(Ok(SubscribeCompactTasksResponse {
+                compact_task: None,
+                vacuum_task: None,
+                backup_task: Some(backup_task),
             }))
             .await
             .map_err(|e| ErrorCode::InternalError(e.to_error_str()).into())
@@ -120,6 +132,14 @@ impl CompactorManager {
         rx
     }
 
+    pub fn has_compactor(&self, context_id: HummockContextId) -> bool {
+        self.inner
+            .read()
+            .compactors
+            .iter()
+            .any(|c| c.context_id == context_id)
+    }
+
     pub fn remove_compactor(&self, context_id: HummockContextId) {
         tracing::info!("Removed compactor {}", context_id);
         self.inner
@@ -171,6 +191,7 @@ mod tests {
                 write: Some(TableSetStatistics::default()),
             }),
             task_status: false,
+            table_ttls: Default::default(),
         }
     }
 
@@ -12,7 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use std::collections::{BTreeMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ops::DerefMut;
 use std::sync::Arc;
 use std::time::Duration;
@@ -20,11 +20,12 @@ use std::time::Duration;
 use itertools::Itertools;
 use prost::Message;
 use risingwave_common::error::{ErrorCode, Result};
-use risingwave_common::util::epoch::INVALID_EPOCH;
+use risingwave_common::util::epoch::{Epoch, INVALID_EPOCH};
 use risingwave_hummock_sdk::compact::compact_task_to_string;
 use risingwave_hummock_sdk::{
     HummockContextId, HummockEpoch, HummockRefCount, HummockSSTableId, HummockVersionId,
 };
+use risingwave_pb::catalog::Table;
 use risingwave_pb::hummock::{
     CompactTask, CompactTaskAssignment, HummockPinnedSnapshot, HummockPinnedVersion,
     HummockSnapshot, HummockStaleSstables, HummockVersion, Level, LevelType, SstableIdInfo,
@@ -43,7 +44,7 @@ use crate::hummock::model::{
     INVALID_TIMESTAMP,
 };
 use crate::manager::{IdCategory, MetaSrvEnv};
-use crate::model::{MetadataModel, ValTransaction, VarTransaction, Worker};
+use crate::model::{MetadataModel, TableFragments, ValTransaction, VarTransaction, Worker};
 use crate::rpc::metrics::MetaMetrics;
 use crate::storage::{Error, MetaStore, Transaction};
 
@@ -515,6 +516,69 @@ where
         })
     }
 
+    /// Pin the snapshot of a historical `epoch` for time travel queries. The pin belongs to
+    /// `context_id` and should be released via `unpin_snapshot`.
+    /// Return an error if `epoch` is not committed yet, or it's older than the safe epoch of the
+    /// current version or of any ongoing compaction, which means the data visible at `epoch` may
+    /// have been compacted and vacuumed.
+    pub async fn pin_specific_snapshot(
+        &self,
+        context_id: HummockContextId,
+        epoch: HummockEpoch,
+    ) -> Result<HummockSnapshot> {
+        let compaction_guard = self.compaction.lock().await;
+        let mut versioning_guard = self.versioning.write().await;
+
+        let (max_committed_epoch, safe_epoch) = {
+            let current_version = versioning_guard.current_version_ref();
+            let ongoing_watermark = compaction_guard
+                .compact_task_assignment
+                .values()
+                .filter_map(|assignment| assignment.compact_task.as_ref())
+                .map(|compact_task| compact_task.watermark)
+                .fold(current_version.safe_epoch, std::cmp::max);
+            (current_version.max_committed_epoch, ongoing_watermark)
+        };
+        if epoch > max_committed_epoch {
+            return Err(ErrorCode::MetaError(format!(
+                "Epoch {} is not committed yet, max committed epoch is {}",
+                epoch, max_committed_epoch
+            ))
+            .into());
+        }
+        if epoch == INVALID_EPOCH || epoch < safe_epoch {
+            return Err(ErrorCode::MetaError(format!(
+                "Epoch {} has been vacuumed, the earliest readable epoch is {}",
+                epoch, safe_epoch
+            ))
+            .into());
+        }
+
+        let mut pinned_snapshots = VarTransaction::new(&mut versioning_guard.pinned_snapshots);
+        let mut context_pinned_snapshot = pinned_snapshots.new_entry_txn_or_default(
+            context_id,
+            HummockPinnedSnapshot {
+                context_id,
+                snapshot_id: vec![],
+            },
+        );
+        if !context_pinned_snapshot.snapshot_id.contains(&epoch) {
+            context_pinned_snapshot.pin_snapshot(epoch);
+            commit_multi_var!(self, Some(context_id), context_pinned_snapshot)?;
+        } else {
+            abort_multi_var!(context_pinned_snapshot);
+        }
+
+        #[cfg(test)]
+        {
+            drop(versioning_guard);
+            drop(compaction_guard);
+            self.check_state_consistency().await;
+        }
+
+        Ok(HummockSnapshot { epoch })
+    }
+
     pub async fn unpin_snapshot(
         &self,
         context_id: HummockContextId,
@@ -547,6 +611,7 @@ where
         &self,
         assignee_context_id: HummockContextId,
     ) -> Result<Option<CompactTask>> {
+        let table_ttls = self.table_ttls().await?;
         let mut compaction_guard = self.compaction.lock().await;
 
         let compaction = compaction_guard.deref_mut();
@@ -573,14 +638,6 @@ where
         let ret = match compact_task {
             None => Ok(None),
             Some(mut compact_task) => {
-                compact_task_assignment.insert(
-                    compact_task.task_id,
-                    CompactTaskAssignment {
-                        compact_task: Some(compact_task.clone()),
-                        context_id: assignee_context_id,
-                    },
-                );
-                should_commit = true;
                 compact_task.watermark = {
                     let versioning_guard = self.versioning.read().await;
                     let current_version_id = versioning_guard.current_version_id.id();
@@ -594,7 +651,17 @@ where
                         .values()
                         .flat_map(|v| v.snapshot_id.clone())
                         .fold(max_committed_epoch, std::cmp::min)
+                        .min(self.retention_watermark())
                 };
+                compact_task.table_ttls = table_ttls;
+                compact_task_assignment.insert(
+                    compact_task.task_id,
+                    CompactTaskAssignment {
+                        compact_task: Some(compact_task.clone()),
+                        context_id: assignee_context_id,
+                    },
+                );
+                should_commit = true;
                 Ok(Some(compact_task))
             }
         };
@@ -970,6 +1037,52 @@ where
         Ok(count as HummockRefCount)
     }
 
+    /// Return true if the version is committed within `MetaOpts::hummock_version_retention`, and
+    /// should be retained for time travel queries.
+    pub async fn is_version_retained(&self, version_id: HummockVersionId) -> bool {
+        let retention_watermark = self.retention_watermark();
+        let versioning_guard = self.versioning.read().await;
+        versioning_guard
+            .hummock_versions
+            .get(&version_id)
+            .map(|version| version.max_committed_epoch >= retention_watermark)
+            .unwrap_or(false)
+    }
+
+    /// Returns TTL in seconds of tables with TTL, including the internal tables of materialized
+    /// views with TTL.
+    async fn table_ttls(&self) -> Result<HashMap<u32, u32>> {
+        let meta_store = self.env.meta_store();
+        let ttls: HashMap<u32, u32> = Table::list(meta_store)
+            .await?
+            .into_iter()
+            .filter(|table| table.ttl_seconds > 0)
+            .map(|table| (table.id, table.ttl_seconds))
+            .collect();
+        if ttls.is_empty() {
+            return Ok(ttls);
+        }
+        let mut table_ttls = ttls.clone();
+        for table_fragments in TableFragments::list(meta_store).await? {
+            if let Some(ttl_seconds) = ttls.get(&table_fragments.table_id().table_id) {
+                for table_id in table_fragments.internal_table_ids() {
+                    table_ttls.insert(table_id.table_id, *ttl_seconds);
+                }
+            }
+        }
+        Ok(table_ttls)
+    }
+
+    /// Snapshots committed at or after the returned epoch are retained for time travel queries.
+    /// Return `HummockEpoch::MAX` if the retention is disabled.
+    fn retention_watermark(&self) -> HummockEpoch {
+        let retention = self.env.opts.hummock_version_retention;
+        if retention.is_zero() {
+            return HummockEpoch::MAX;
+        }
+        Epoch::physical_now().saturating_sub(retention.as_millis() as u64)
+    }
+
     /// Get the `SSTable` ids which are guaranteed not to be included after `version_id`, thus they
     /// can be deleted if all versions LE than `version_id` are not referenced.
     #[cfg(test)]
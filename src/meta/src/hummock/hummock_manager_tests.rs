@@ -22,6 +22,7 @@ use risingwave_hummock_sdk::compact::compact_task_to_string;
 use risingwave_hummock_sdk::{
     HummockContextId, HummockSSTableId, FIRST_VERSION_ID, INVALID_VERSION_ID,
 };
+use risingwave_pb::catalog::Table;
 use risingwave_pb::common::{HostAddress, WorkerType};
 use risingwave_pb::hummock::{
     HummockPinnedSnapshot, HummockPinnedVersion, HummockSnapshot, HummockVersion,
@@ -901,6 +902,71 @@ async fn test_pin_snapshot_response_lost() {
     );
 }
 
+#[tokio::test]
+async fn test_pin_specific_snapshot() {
+    let (env, hummock_manager, _cluster_manager, worker_node) = setup_compute_env(80).await;
+    let context_id = worker_node.id;
+
+    // Nothing is committed yet.
+    assert!(hummock_manager
+        .pin_specific_snapshot(context_id, INVALID_EPOCH)
+        .await
+        .is_err());
+
+    for epoch in 1..3 {
+        let test_tables = generate_test_tables(
+            epoch,
+            vec![hummock_manager.get_new_table_id().await.unwrap()],
+        );
+        hummock_manager
+            .add_tables(context_id, test_tables, epoch)
+            .await
+            .unwrap();
+        hummock_manager.commit_epoch(epoch).await.unwrap();
+    }
+
+    // Pin a historical snapshot.
+    for _ in 0..2 {
+        let snapshot = hummock_manager
+            .pin_specific_snapshot(context_id, 1)
+            .await
+            .unwrap();
+        assert_eq!(snapshot.epoch, 1);
+        assert_eq!(
+            pin_snapshots_sum(&HummockPinnedSnapshot::list(env.meta_store()).await.unwrap()),
+            1
+        );
+    }
+
+    // Pinning the latest snapshot doesn't unpin the historical one, as long as frontend passes
+    // the greatest epoch it knows.
+    let epoch = hummock_manager
+        .pin_snapshot(context_id, 1)
+        .await
+        .unwrap()
+        .epoch;
+    assert_eq!(epoch, 2);
+    assert_eq!(
+        pin_snapshots_sum(&HummockPinnedSnapshot::list(env.meta_store()).await.unwrap()),
+        2
+    );
+
+    // Epoch not committed yet.
+    assert!(hummock_manager
+        .pin_specific_snapshot(context_id, 3)
+        .await
+        .is_err());
+
+    hummock_manager
+        .unpin_snapshot(context_id, vec![HummockSnapshot { epoch: 1 }])
+        .await
+        .unwrap();
+    assert_eq!(
+        pin_snapshots_sum(&HummockPinnedSnapshot::list(env.meta_store()).await.unwrap()),
+        1
+    );
+}
+
 #[tokio::test]
 async fn test_print_compact_task() -> Result<()> {
     let (_, hummock_manager, _cluster_manager, worker_node) = setup_compute_env(80).await;
@@ -936,6 +1002,41 @@ async fn test_print_compact_task() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_compact_task_table_ttls() -> Result<()> {
+    let (env, hummock_manager, _cluster_manager, worker_node) = setup_compute_env(80).await;
+    let context_id = worker_node.id;
+    Table {
+        id: 1,
+        ttl_seconds: 3600,
+        ..Default::default()
+    }
+    .insert(env.meta_store())
+    .await?;
+    Table {
+        id: 2,
+        ..Default::default()
+    }
+    .insert(env.meta_store())
+    .await?;
+
+    let epoch: u64 = 1;
+    let original_tables = generate_test_tables(epoch, get_sst_ids(&hummock_manager, 2).await);
+    hummock_manager
+        .add_tables(context_id, original_tables, epoch)
+        .await?;
+    hummock_manager.commit_epoch(epoch).await?;
+
+    // Only tables with TTL are included.
+    let compact_task = hummock_manager.get_compact_task(context_id).await?.unwrap();
+    assert_eq!(
+        compact_task.table_ttls.into_iter().collect_vec(),
+        vec![(1, 3600)]
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_invalid_sst_id() {
     let (_, hummock_manager, _cluster_manager, worker_node) = setup_compute_env(80).await;
@@ -124,6 +124,10 @@ impl HummockMetaClient for MockHummockMetaClient {
     async fn report_vacuum_task(&self, _vacuum_task: VacuumTask) -> Result<()> {
         Ok(())
     }
+
+    async fn report_backup_task(&self, _backup_id: u64, _succeeded: bool) -> Result<()> {
+        Ok(())
+    }
 }
 
 impl MockHummockMetaClient {
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod backup;
 mod compaction;
 mod compaction_group;
 mod compaction_scheduler;
@@ -31,6 +32,7 @@ mod vacuum;
 use std::sync::Arc;
 use std::time::Duration;
 
+pub use backup::*;
 pub use compaction_scheduler::CompactionScheduler;
 pub use compactor_manager::*;
 pub use hummock_manager::*;
@@ -53,6 +55,7 @@ pub async fn start_hummock_workers<S>(
     vacuum_trigger: Arc<VacuumTrigger<S>>,
     notification_manager: NotificationManagerRef,
     compaction_scheduler: CompactionSchedulerRef<S>,
+    backup_manager: Arc<BackupManager<S>>,
 ) -> Vec<(JoinHandle<()>, UnboundedSender<()>)>
 where
     S: MetaStore,
@@ -60,6 +63,7 @@ where
     vec![
         start_compaction_scheduler(compaction_scheduler),
         start_vacuum_scheduler(vacuum_trigger),
+        start_backup_checker(backup_manager),
         subscribe_cluster_membership_change(
             hummock_manager,
             compactor_manager,
@@ -178,3 +182,38 @@ where
     });
     (join_handle, shutdown_tx)
 }
+
+/// Ongoing backups are checked at this rate.
+const BACKUP_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+/// A backup is aborted if it's not reported after this interval.
+const BACKUP_TASK_TIMEOUT: Duration = Duration::from_secs(60 * 60 * 6);
+/// Starts a task to periodically abort expired backups.
+pub fn start_backup_checker<S>(
+    backup_manager: Arc<BackupManager<S>>,
+) -> (JoinHandle<()>, UnboundedSender<()>)
+where
+    S: MetaStore,
+{
+    let (shutdown_tx, mut shutdown_rx) = tokio::sync::mpsc::unbounded_channel();
+    let join_handle = tokio::spawn(async move {
+        let mut min_check_interval = tokio::time::interval(BACKUP_CHECK_INTERVAL);
+        loop {
+            tokio::select! {
+                // Wait for interval
+                _ = min_check_interval.tick() => {},
+                // Shutdown backup checker
+                _ = shutdown_rx.recv() => {
+                    tracing::info!("Backup checker is shutting down");
+                    return;
+                }
+            }
+            if let Err(err) = backup_manager
+                .abort_expired_backups(BACKUP_TASK_TIMEOUT)
+                .await
+            {
+                tracing::warn!("Abort expired backups error {}", err);
+            }
+        }
+    });
+    (join_handle, shutdown_tx)
+}
@@ -64,6 +64,7 @@ where
     ///   version.
     /// - And it's not being referenced, and we know it won't be referenced in the future because
     ///   only greatest version can be newly referenced.
+    /// - And it's not retained for time travel queries, see `MetaOpts::hummock_version_retention`.
     pub async fn vacuum_version_metadata(&self) -> risingwave_common::error::Result<u64> {
         let mut vacuum_count: u64 = 0;
         let version_ids = self.hummock_manager.list_version_ids_asc().await?;
@@ -80,6 +81,10 @@ where
                 // The smallest version is still referenced.
                 return Ok(vacuum_count);
             }
+            if self.hummock_manager.is_version_retained(*version_id).await {
+                // The smallest version and all greater ones are within the retention.
+                return Ok(vacuum_count);
+            }
 
             // Delete version metadata and mark SST as orphan (set meta_delete_timestamp).
             // TODO delete in batch
@@ -87,6 +87,16 @@ pub struct MetaNodeOpts {
     #[clap(long, default_value = "100")]
     checkpoint_interval: u32,
 
+    /// Hummock versions and snapshots committed within this interval (in seconds) are retained
+    /// for `SELECT ... AS OF` queries. Zero disables the retention.
+    #[clap(long, default_value = "0")]
+    hummock_version_retention_sec: u64,
+
+    /// Path prefix in the object store under which backups are written. Each backup is stored in
+    /// `{backup_prefix}/{backup_id}`.
+    #[clap(long, default_value = "backup")]
+    backup_prefix: String,
+
     /// Whether to enable fail-on-recovery. If not set, default to enable. Should only be used in
     /// e2e tests.
     #[clap(long)]
@@ -110,6 +120,7 @@ pub async fn start(opts: MetaNodeOpts) {
     };
     let max_heartbeat_interval = Duration::from_millis(opts.max_heartbeat_interval as u64);
     let checkpoint_interval = Duration::from_millis(opts.checkpoint_interval as u64);
+    let hummock_version_retention = Duration::from_secs(opts.hummock_version_retention_sec);
 
     tracing::info!("Meta server listening at {}", addr);
     let (join_handle, _shutdown_send) = rpc_serve(
@@ -122,6 +133,8 @@ pub async fn start(opts: MetaNodeOpts) {
         MetaOpts {
             enable_recovery: !opts.disable_recovery,
             checkpoint_interval,
+            hummock_version_retention,
+            backup_prefix: opts.backup_prefix,
         },
     )
     .await
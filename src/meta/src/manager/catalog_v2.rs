@@ -23,7 +23,7 @@ use risingwave_common::ensure;
 use risingwave_common::error::ErrorCode::{CatalogError, InternalError};
 use risingwave_common::error::{Result, RwError};
 use risingwave_pb::catalog::table::OptionalAssociatedSourceId;
-use risingwave_pb::catalog::{Database, Schema, Source, Table};
+use risingwave_pb::catalog::{Database, Function, Schema, Source, Table};
 use risingwave_pb::meta::subscribe_response::{Info, Operation};
 use tokio::sync::{Mutex, MutexGuard};
 
@@ -37,8 +37,15 @@ pub type SchemaId = u32;
 pub type TableId = u32;
 pub type SourceId = u32;
 pub type RelationId = u32;
+pub type FunctionId = u32;
 
-pub type Catalog = (Vec<Database>, Vec<Schema>, Vec<Table>, Vec<Source>);
+pub type Catalog = (
+    Vec<Database>,
+    Vec<Schema>,
+    Vec<Table>,
+    Vec<Source>,
+    Vec<Function>,
+);
 
 pub struct CatalogManager<S: MetaStore> {
     env: MetaSrvEnv<S>,
@@ -406,6 +413,47 @@ where
         }
     }
 
+    pub async fn create_function(&self, function: &Function) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        if !core.has_function(function) {
+            function.insert(self.env.meta_store()).await?;
+            core.add_function(function);
+
+            let version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Add, Info::Function(function.to_owned()))
+                .await;
+
+            Ok(version)
+        } else {
+            Err(RwError::from(InternalError(
+                "function already exists".to_string(),
+            )))
+        }
+    }
+
+    pub async fn drop_function(&self, function_id: FunctionId) -> Result<CatalogVersion> {
+        let mut core = self.core.lock().await;
+        let function = Function::select(self.env.meta_store(), &function_id).await?;
+        if let Some(function) = function {
+            Function::delete(self.env.meta_store(), &function_id).await?;
+            core.drop_function(&function);
+
+            let version = self
+                .env
+                .notification_manager()
+                .notify_frontend(Operation::Delete, Info::Function(function))
+                .await;
+
+            Ok(version)
+        } else {
+            Err(RwError::from(InternalError(
+                "function doesn't exist".to_string(),
+            )))
+        }
+    }
+
     pub async fn start_create_materialized_source_procedure(
         &self,
         source: &Source,
@@ -577,6 +625,7 @@ type SchemaKey = (DatabaseId, String);
 type TableKey = (DatabaseId, SchemaId, String);
 type SourceKey = (DatabaseId, SchemaId, String);
 type RelationKey = (DatabaseId, SchemaId, String);
+type FunctionKey = (DatabaseId, SchemaId, String);
 
 /// [`CatalogManagerCore`] caches meta catalog information and maintains dependent relationship
 /// between tables.
@@ -590,6 +639,8 @@ pub struct CatalogManagerCore<S: MetaStore> {
     sources: HashSet<SourceKey>,
     /// Cached table key information.
     tables: HashSet<TableKey>,
+    /// Cached function key information.
+    functions: HashSet<FunctionKey>,
     /// Relation refer count mapping.
     relation_ref_count: HashMap<RelationId, usize>,
 
@@ -606,6 +657,7 @@ where
         let schemas = Schema::list(env.meta_store()).await?;
         let sources = Source::list(env.meta_store()).await?;
         let tables = Table::list(env.meta_store()).await?;
+        let functions = Function::list(env.meta_store()).await?;
 
         let mut relation_ref_count = HashMap::new();
 
@@ -627,6 +679,12 @@ where
             (table.database_id, table.schema_id, table.name)
         }));
 
+        let functions = HashSet::from_iter(
+            functions
+                .into_iter()
+                .map(|function| (function.database_id, function.schema_id, function.name)),
+        );
+
         let in_progress_creation_tracker = HashSet::new();
 
         Ok(Self {
@@ -635,6 +693,7 @@ where
             schemas,
             sources,
             tables,
+            functions,
             relation_ref_count,
             in_progress_creation_tracker,
         })
@@ -646,6 +705,7 @@ where
             Schema::list(self.env.meta_store()).await?,
             Table::list(self.env.meta_store()).await?,
             Source::list(self.env.meta_store()).await?,
+            Function::list(self.env.meta_store()).await?,
         ))
     }
 
@@ -710,6 +770,30 @@ where
             .remove(&(source.database_id, source.schema_id, source.name.clone()))
     }
 
+    fn has_function(&self, function: &Function) -> bool {
+        self.functions.contains(&(
+            function.database_id,
+            function.schema_id,
+            function.name.clone(),
+        ))
+    }
+
+    fn add_function(&mut self, function: &Function) {
+        self.functions.insert((
+            function.database_id,
+            function.schema_id,
+            function.name.clone(),
+        ));
+    }
+
+    fn drop_function(&mut self, function: &Function) -> bool {
+        self.functions.remove(&(
+            function.database_id,
+            function.schema_id,
+            function.name.clone(),
+        ))
+    }
+
     pub async fn get_source(&self, id: SourceId) -> Result<Option<Source>> {
         Source::select(self.env.meta_store(), &id).await
     }
@@ -51,6 +51,11 @@ where
 pub struct MetaOpts {
     pub enable_recovery: bool,
     pub checkpoint_interval: Duration,
+    /// Hummock versions and snapshots committed within this interval are retained, so that they
+    /// can be read by time travel queries. Zero disables the retention.
+    pub hummock_version_retention: Duration,
+    /// Path prefix in the object store under which backups of meta and SST data are written.
+    pub backup_prefix: String,
 }
 
 impl Default for MetaOpts {
@@ -58,6 +63,8 @@ impl Default for MetaOpts {
         Self {
             enable_recovery: false,
             checkpoint_interval: Duration::from_millis(100),
+            hummock_version_retention: Duration::ZERO,
+            backup_prefix: "backup".to_string(),
         }
     }
 }
@@ -132,6 +132,8 @@ pub mod IdCategory {
     pub const HummockSSTableId: IdCategoryType = 8;
     pub const ParallelUnit: IdCategoryType = 9;
     pub const Source: IdCategoryType = 10;
+    pub const Backup: IdCategoryType = 11;
+    pub const Function: IdCategoryType = 12;
 }
 
 pub type IdGeneratorManagerRef<S> = Arc<IdGeneratorManager<S>>;
@@ -150,6 +152,8 @@ pub struct IdGeneratorManager<S> {
     hummock_snapshot: Arc<StoredIdGenerator<S>>,
     hummock_ss_table_id: Arc<StoredIdGenerator<S>>,
     parallel_unit: Arc<StoredIdGenerator<S>>,
+    backup: Arc<StoredIdGenerator<S>>,
+    function: Arc<StoredIdGenerator<S>>,
 }
 
 impl<S> IdGeneratorManager<S>
@@ -180,6 +184,8 @@ where
             parallel_unit: Arc::new(
                 StoredIdGenerator::new(meta_store.clone(), "parallel_unit", None).await,
             ),
+            backup: Arc::new(StoredIdGenerator::new(meta_store.clone(), "backup", Some(1)).await),
+            function: Arc::new(StoredIdGenerator::new(meta_store.clone(), "function", None).await),
         }
     }
 
@@ -196,6 +202,8 @@ where
             IdCategory::Worker => &self.worker,
             IdCategory::HummockSSTableId => &self.hummock_ss_table_id,
             IdCategory::ParallelUnit => &self.parallel_unit,
+            IdCategory::Backup => &self.backup,
+            IdCategory::Function => &self.function,
             _ => unreachable!(),
         }
     }
@@ -13,7 +13,7 @@
 // limitations under the License.
 
 use risingwave_common::error::Result;
-use risingwave_pb::catalog::{Database, Schema, Source, Table};
+use risingwave_pb::catalog::{Database, Function, Schema, Source, Table};
 
 use crate::model::MetadataModel;
 
@@ -25,6 +25,8 @@ const CATALOG_TABLE_CF_NAME: &str = "cf/catalog_table";
 const CATALOG_SCHEMA_CF_NAME: &str = "cf/catalog_schema";
 /// Column family name for database catalog.
 const CATALOG_DATABASE_CF_NAME: &str = "cf/catalog_database";
+/// Column family name for function catalog.
+const CATALOG_FUNCTION_CF_NAME: &str = "cf/catalog_function";
 
 macro_rules! impl_model_for_catalog {
     ($name:ident, $cf:ident, $key_ty:ty, $key_fn:ident) => {
@@ -55,3 +57,4 @@ impl_model_for_catalog!(Source, CATALOG_SOURCE_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Table, CATALOG_TABLE_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Schema, CATALOG_SCHEMA_CF_NAME, u32, get_id);
 impl_model_for_catalog!(Database, CATALOG_DATABASE_CF_NAME, u32, get_id);
+impl_model_for_catalog!(Function, CATALOG_FUNCTION_CF_NAME, u32, get_id);
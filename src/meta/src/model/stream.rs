@@ -208,6 +208,35 @@ impl TableFragments {
         table_ids
     }
 
+    /// Resolve ids of internal tables, which store states of operators.
+    fn resolve_internal_table(stream_node: &StreamNode, table_ids: &mut HashSet<TableId>) {
+        match stream_node.node.as_ref() {
+            Some(Node::HashJoinNode(node)) => {
+                table_ids.insert(TableId::new(node.left_table_id));
+                table_ids.insert(TableId::new(node.right_table_id));
+            }
+            Some(Node::HashAggNode(node)) => {
+                table_ids.extend(node.table_ids.iter().map(|id| TableId::new(*id)));
+            }
+            _ => {}
+        }
+
+        for child in &stream_node.input {
+            Self::resolve_internal_table(child, table_ids);
+        }
+    }
+
+    /// Returns ids of internal tables of the streaming job.
+    pub fn internal_table_ids(&self) -> HashSet<TableId> {
+        let mut table_ids = HashSet::new();
+        self.fragments.values().for_each(|fragment| {
+            let actor = &fragment.actors[0];
+            Self::resolve_internal_table(actor.nodes.as_ref().unwrap(), &mut table_ids);
+        });
+
+        table_ids
+    }
+
     /// Returns states of actors group by node id.
     pub fn node_actor_states(&self) -> BTreeMap<WorkerId, Vec<(ActorId, ActorState)>> {
         let mut map = BTreeMap::default();
@@ -197,6 +197,15 @@ pub async fn rpc_serve_with_store<S: MetaStore>(
         hummock_manager.clone(),
         compactor_manager.clone(),
     ));
+    let backup_manager = Arc::new(
+        hummock::BackupManager::new(
+            env.clone(),
+            hummock_manager.clone(),
+            compactor_manager.clone(),
+        )
+        .await
+        .unwrap(),
+    );
 
     let heartbeat_srv = HeartbeatServiceImpl::new(cluster_manager.clone());
     let catalog_srv = CatalogServiceImpl::<S>::new(env.clone(), catalog_manager);
@@ -219,6 +228,7 @@ pub async fn rpc_serve_with_store<S: MetaStore>(
         hummock_manager.clone(),
         compactor_manager.clone(),
         vacuum_trigger.clone(),
+        backup_manager.clone(),
     );
     let notification_manager = env.notification_manager_ref();
     let notification_srv =
@@ -236,6 +246,7 @@ pub async fn rpc_serve_with_store<S: MetaStore>(
             vacuum_trigger,
             notification_manager,
             compaction_scheduler,
+            backup_manager,
         )
         .await,
     );
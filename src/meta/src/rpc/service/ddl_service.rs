@@ -371,6 +371,54 @@ where
             version,
         }))
     }
+
+    async fn create_function(
+        &self,
+        request: Request<CreateFunctionRequest>,
+    ) -> Result<Response<CreateFunctionResponse>, Status> {
+        let id = self
+            .env
+            .id_gen_manager()
+            .generate::<{ IdCategory::Function }>()
+            .await
+            .map_err(tonic_err)? as u32;
+        let mut function = request
+            .into_inner()
+            .get_function()
+            .map_err(tonic_err)?
+            .clone();
+        function.id = id;
+        let version = self
+            .catalog_manager
+            .create_function(&function)
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(CreateFunctionResponse {
+            status: None,
+            function_id: id,
+            version,
+        }))
+    }
+
+    async fn drop_function(
+        &self,
+        request: Request<DropFunctionRequest>,
+    ) -> Result<Response<DropFunctionResponse>, Status> {
+        let function_id = request.into_inner().function_id;
+        // The functions are embedded in the plans calling them, so there are no dependencies to
+        // check.
+        let version = self
+            .catalog_manager
+            .drop_function(function_id)
+            .await
+            .map_err(tonic_err)?;
+
+        Ok(Response::new(DropFunctionResponse {
+            status: None,
+            version,
+        }))
+    }
 }
 
 impl<S> DdlServiceImpl<S>
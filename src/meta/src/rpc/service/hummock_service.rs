@@ -19,7 +19,7 @@ use risingwave_pb::hummock::hummock_manager_service_server::HummockManagerServic
 use risingwave_pb::hummock::*;
 use tonic::{Request, Response, Status};
 
-use crate::hummock::{CompactorManager, HummockManagerRef, VacuumTrigger};
+use crate::hummock::{BackupManager, CompactorManager, HummockManagerRef, VacuumTrigger};
 use crate::rpc::service::RwReceiverStream;
 use crate::storage::MetaStore;
 
@@ -30,6 +30,7 @@ where
     hummock_manager: HummockManagerRef<S>,
     compactor_manager: Arc<CompactorManager>,
     vacuum_trigger: Arc<VacuumTrigger<S>>,
+    backup_manager: Arc<BackupManager<S>>,
 }
 
 impl<S> HummockServiceImpl<S>
@@ -40,11 +41,13 @@ where
         hummock_manager: HummockManagerRef<S>,
         compactor_manager: Arc<CompactorManager>,
         vacuum_trigger: Arc<VacuumTrigger<S>>,
+        backup_manager: Arc<BackupManager<S>>,
     ) -> Self {
         HummockServiceImpl {
             hummock_manager,
             compactor_manager,
             vacuum_trigger,
+            backup_manager,
         }
     }
 }
@@ -146,6 +149,24 @@ where
         }
     }
 
+    async fn pin_specific_snapshot(
+        &self,
+        request: Request<PinSpecificSnapshotRequest>,
+    ) -> Result<Response<PinSnapshotResponse>, Status> {
+        let req = request.into_inner();
+        let result = self
+            .hummock_manager
+            .pin_specific_snapshot(req.context_id, req.epoch)
+            .await;
+        match result {
+            Ok(hummock_snapshot) => Ok(Response::new(PinSnapshotResponse {
+                status: None,
+                snapshot: Some(hummock_snapshot),
+            })),
+            Err(e) => Err(e.to_grpc_status()),
+        }
+    }
+
     async fn unpin_snapshot(
         &self,
         request: Request<UnpinSnapshotRequest>,
@@ -228,4 +249,31 @@ where
         }
         Ok(Response::new(ReportVacuumTaskResponse { status: None }))
     }
+
+    async fn report_backup_task(
+        &self,
+        request: Request<ReportBackupTaskRequest>,
+    ) -> Result<Response<ReportBackupTaskResponse>, Status> {
+        let req = request.into_inner();
+        self.backup_manager
+            .report_backup_task(req.backup_id, req.succeeded)
+            .await
+            .map_err(|e| e.to_grpc_status())?;
+        Ok(Response::new(ReportBackupTaskResponse { status: None }))
+    }
+
+    async fn backup_meta(
+        &self,
+        _request: Request<BackupMetaRequest>,
+    ) -> Result<Response<BackupMetaResponse>, Status> {
+        let backup_id = self
+            .backup_manager
+            .backup()
+            .await
+            .map_err(|e| e.to_grpc_status())?;
+        Ok(Response::new(BackupMetaResponse {
+            status: None,
+            backup_id,
+        }))
+    }
 }
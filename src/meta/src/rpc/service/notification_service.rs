@@ -76,7 +76,7 @@ where
             }
             WorkerType::Frontend => {
                 let catalog_guard = self.catalog_manager.get_catalog_core_guard().await;
-                let (database, schema, table, source) = catalog_guard
+                let (database, schema, table, source, function) = catalog_guard
                     .get_catalog()
                     .await
                     .map_err(|e| e.to_grpc_status())?;
@@ -92,6 +92,7 @@ where
                     source,
                     table,
                     view: Default::default(),
+                    function,
                 };
                 tx.send(Ok(SubscribeResponse {
                     status: None,
@@ -123,7 +123,7 @@ struct ListViewer {
 }
 
 impl SnapshotViewer for ListViewer {
-    type Output = Vec<Vec<u8>>;
+    type Output = Vec<(Vec<u8>, Vec<u8>)>;
 
     type OutputFuture<'a> = impl Future<Output = Result<(i64, Self::Output)>> + 'a;
 
@@ -142,8 +142,12 @@ impl SnapshotViewer for ListViewer {
                     "Etcd response missing header"
                 )));
             };
-            let value = res.kvs().iter().map(|kv| kv.value().to_vec()).collect();
-            Ok((new_revision, value))
+            let kvs = res
+                .kvs()
+                .iter()
+                .map(|kv| (kv.key().to_vec(), kv.value().to_vec()))
+                .collect();
+            Ok((new_revision, kvs))
         }
     }
 }
@@ -154,7 +158,20 @@ impl Snapshot for EtcdSnapshot {
         let view = ListViewer {
             key: encode_etcd_key(cf, &[]),
         };
-        self.view_inner(view).await
+        let kvs = self.view_inner(view).await?;
+        Ok(kvs.into_iter().map(|(_, value)| value).collect())
+    }
+
+    async fn list_cf_kv(&self, cf: &str) -> Result<Vec<(Vec<u8>, Vec<u8>)>> {
+        let prefix = encode_etcd_key(cf, &[]);
+        let view = ListViewer {
+            key: prefix.clone(),
+        };
+        let kvs = self.view_inner(view).await?;
+        Ok(kvs
+            .into_iter()
+            .map(|(key, value)| (key[prefix.len()..].to_vec(), value))
+            .collect())
     }
 
     async fn get_cf(&self, cf: &str, key: &[u8]) -> Result<Vec<u8>> {
@@ -25,6 +25,7 @@ pub const DEFAULT_COLUMN_FAMILY: &str = "default";
 #[async_trait]
 pub trait Snapshot: Sync + Send + 'static {
     async fn list_cf(&self, cf: &str) -> Result<Vec<Vec<u8>>>;
+    async fn list_cf_kv(&self, cf: &str) -> Result<Vec<(Vec<u8>, Vec<u8>)>>;
     async fn get_cf(&self, cf: &str, key: &[u8]) -> Result<Vec<u8>>;
 }
 
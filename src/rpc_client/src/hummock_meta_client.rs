@@ -37,4 +37,5 @@ pub trait HummockMetaClient: Send + Sync + 'static {
     async fn abort_epoch(&self, epoch: HummockEpoch) -> Result<()>;
     async fn subscribe_compact_tasks(&self) -> Result<Streaming<SubscribeCompactTasksResponse>>;
     async fn report_vacuum_task(&self, vacuum_task: VacuumTask) -> Result<()>;
+    async fn report_backup_task(&self, backup_id: u64, succeeded: bool) -> Result<()>;
 }
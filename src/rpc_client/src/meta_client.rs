@@ -23,27 +23,30 @@ use risingwave_common::try_match_expand;
 use risingwave_common::util::addr::HostAddr;
 use risingwave_hummock_sdk::{HummockEpoch, HummockSSTableId, HummockVersionId};
 use risingwave_pb::catalog::{
-    Database as ProstDatabase, Schema as ProstSchema, Source as ProstSource, Table as ProstTable,
+    Database as ProstDatabase, Function as ProstFunction, Schema as ProstSchema,
+    Source as ProstSource, Table as ProstTable,
 };
 use risingwave_pb::common::{WorkerNode, WorkerType};
 use risingwave_pb::ddl_service::ddl_service_client::DdlServiceClient;
 use risingwave_pb::ddl_service::{
-    CreateDatabaseRequest, CreateDatabaseResponse, CreateMaterializedSourceRequest,
-    CreateMaterializedSourceResponse, CreateMaterializedViewRequest,
-    CreateMaterializedViewResponse, CreateSchemaRequest, CreateSchemaResponse, CreateSourceRequest,
-    CreateSourceResponse, DropMaterializedSourceRequest, DropMaterializedSourceResponse,
+    CreateDatabaseRequest, CreateDatabaseResponse, CreateFunctionRequest, CreateFunctionResponse,
+    CreateMaterializedSourceRequest, CreateMaterializedSourceResponse,
+    CreateMaterializedViewRequest, CreateMaterializedViewResponse, CreateSchemaRequest,
+    CreateSchemaResponse, CreateSourceRequest, CreateSourceResponse, DropFunctionRequest,
+    DropFunctionResponse, DropMaterializedSourceRequest, DropMaterializedSourceResponse,
     DropMaterializedViewRequest, DropMaterializedViewResponse, DropSourceRequest,
     DropSourceResponse,
 };
 use risingwave_pb::hummock::hummock_manager_service_client::HummockManagerServiceClient;
 use risingwave_pb::hummock::{
-    AbortEpochRequest, AbortEpochResponse, AddTablesRequest, AddTablesResponse, CommitEpochRequest,
-    CommitEpochResponse, CompactTask, GetNewTableIdRequest, GetNewTableIdResponse, HummockSnapshot,
-    HummockVersion, PinSnapshotRequest, PinSnapshotResponse, PinVersionRequest, PinVersionResponse,
-    ReportCompactionTasksRequest, ReportCompactionTasksResponse, ReportVacuumTaskRequest,
-    ReportVacuumTaskResponse, SstableInfo, SubscribeCompactTasksRequest,
-    SubscribeCompactTasksResponse, UnpinSnapshotRequest, UnpinSnapshotResponse,
-    UnpinVersionRequest, UnpinVersionResponse, VacuumTask,
+    AbortEpochRequest, AbortEpochResponse, AddTablesRequest, AddTablesResponse, BackupMetaRequest,
+    BackupMetaResponse, CommitEpochRequest, CommitEpochResponse, CompactTask, GetNewTableIdRequest,
+    GetNewTableIdResponse, HummockSnapshot, HummockVersion, PinSnapshotRequest,
+    PinSnapshotResponse, PinSpecificSnapshotRequest, PinVersionRequest, PinVersionResponse,
+    ReportBackupTaskRequest, ReportBackupTaskResponse, ReportCompactionTasksRequest,
+    ReportCompactionTasksResponse, ReportVacuumTaskRequest, ReportVacuumTaskResponse, SstableInfo,
+    SubscribeCompactTasksRequest, SubscribeCompactTasksResponse, UnpinSnapshotRequest,
+    UnpinSnapshotResponse, UnpinVersionRequest, UnpinVersionResponse, VacuumTask,
 };
 use risingwave_pb::meta::catalog_service_client::CatalogServiceClient;
 use risingwave_pb::meta::cluster_service_client::ClusterServiceClient;
@@ -220,6 +223,20 @@ impl MetaClient {
         Ok(resp.version)
     }
 
+    pub async fn create_function(&self, function: ProstFunction) -> Result<(u32, CatalogVersion)> {
+        let request = CreateFunctionRequest {
+            function: Some(function),
+        };
+        let resp = self.inner.create_function(request).await?;
+        Ok((resp.function_id, resp.version))
+    }
+
+    pub async fn drop_function(&self, function_id: u32) -> Result<CatalogVersion> {
+        let request = DropFunctionRequest { function_id };
+        let resp = self.inner.drop_function(request).await?;
+        Ok(resp.version)
+    }
+
     /// Unregister the current node to the cluster.
     pub async fn unregister(&self, addr: HostAddr) -> Result<()> {
         let request = DeleteWorkerNodeRequest {
@@ -289,6 +306,24 @@ impl MetaClient {
         self.inner.flush(request).await?;
         Ok(())
     }
+
+    /// Trigger a backup of meta and SST data. Return the id of the backup, which finishes
+    /// asynchronously.
+    pub async fn backup_meta(&self) -> Result<u64> {
+        let resp = self.inner.backup_meta(BackupMetaRequest {}).await?;
+        Ok(resp.backup_id)
+    }
+
+    /// Pin the snapshot of a historical `epoch` for time travel queries. Fails if the epoch is
+    /// not committed yet, or has already been vacuumed.
+    pub async fn pin_specific_snapshot(&self, epoch: HummockEpoch) -> Result<HummockEpoch> {
+        let req = PinSpecificSnapshotRequest {
+            context_id: self.worker_id(),
+            epoch,
+        };
+        let resp = self.inner.pin_specific_snapshot(req).await?;
+        Ok(resp.snapshot.unwrap().epoch)
+    }
 }
 
 #[async_trait]
@@ -387,6 +422,15 @@ impl HummockMetaClient for MetaClient {
         self.inner.report_vacuum_task(req).await?;
         Ok(())
     }
+
+    async fn report_backup_task(&self, backup_id: u64, succeeded: bool) -> Result<()> {
+        let req = ReportBackupTaskRequest {
+            backup_id,
+            succeeded,
+        };
+        self.inner.report_backup_task(req).await?;
+        Ok(())
+    }
 }
 
 /// Client to meta server. Cloning the instance is lightweight.
@@ -465,15 +509,20 @@ macro_rules! for_all_meta_rpc {
             ,{ ddl_client, drop_materialized_source, DropMaterializedSourceRequest, DropMaterializedSourceResponse }
             ,{ ddl_client, drop_materialized_view, DropMaterializedViewRequest, DropMaterializedViewResponse }
             ,{ ddl_client, drop_source, DropSourceRequest, DropSourceResponse }
+            ,{ ddl_client, create_function, CreateFunctionRequest, CreateFunctionResponse }
+            ,{ ddl_client, drop_function, DropFunctionRequest, DropFunctionResponse }
             ,{ hummock_client, pin_version, PinVersionRequest, PinVersionResponse }
             ,{ hummock_client, unpin_version, UnpinVersionRequest, UnpinVersionResponse }
             ,{ hummock_client, pin_snapshot, PinSnapshotRequest, PinSnapshotResponse }
+            ,{ hummock_client, pin_specific_snapshot, PinSpecificSnapshotRequest, PinSnapshotResponse }
             ,{ hummock_client, unpin_snapshot, UnpinSnapshotRequest, UnpinSnapshotResponse }
             ,{ hummock_client, add_tables, AddTablesRequest, AddTablesResponse }
             ,{ hummock_client, report_compaction_tasks, ReportCompactionTasksRequest, ReportCompactionTasksResponse }
             ,{ hummock_client, get_new_table_id, GetNewTableIdRequest, GetNewTableIdResponse }
             ,{ hummock_client, subscribe_compact_tasks, SubscribeCompactTasksRequest, Streaming<SubscribeCompactTasksResponse> }
             ,{ hummock_client, report_vacuum_task, ReportVacuumTaskRequest, ReportVacuumTaskResponse }
+            ,{ hummock_client, report_backup_task, ReportBackupTaskRequest, ReportBackupTaskResponse }
+            ,{ hummock_client, backup_meta, BackupMetaRequest, BackupMetaResponse }
             ,{ hummock_client, commit_epoch, CommitEpochRequest, CommitEpochResponse }
             ,{ hummock_client, abort_epoch, AbortEpochRequest, AbortEpochResponse }
         }
@@ -70,6 +70,14 @@ impl From<&ColumnDesc> for SourceColumnDesc {
     }
 }
 
+/// `SourceWatermarkDesc` describes the event-time watermark of a source, which lags behind the
+/// max value of the timestamp column `column_id` by `delay_ms`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SourceWatermarkDesc {
+    pub column_id: ColumnId,
+    pub delay_ms: u64,
+}
+
 /// `SourceDesc` is used to describe a `Source`
 #[derive(Clone, Debug)]
 pub struct SourceDesc {
@@ -81,6 +89,8 @@ pub struct SourceDesc {
     // TODO: change to Option<usize> when pk supported in the future.
     pub row_id_index: usize,
     pub row_id_generator: Arc<Mutex<RowIdGenerator>>,
+
+    pub watermark: Option<SourceWatermarkDesc>,
 }
 
 impl SourceDesc {
@@ -150,6 +160,14 @@ impl SourceManager for MemSourceManager {
             }
         };
 
+        let watermark = info
+            .watermark
+            .as_ref()
+            .map(|watermark| SourceWatermarkDesc {
+                column_id: ColumnId::from(watermark.column_id),
+                delay_ms: watermark.delay_ms,
+            });
+
         let source = SourceImpl::Connector(ConnectorSource {
             config: properties,
             columns: columns.clone(),
@@ -165,6 +183,7 @@ impl SourceManager for MemSourceManager {
                 self.worker_id,
                 *UNIX_SINGULARITY_DATE_EPOCH,
             ))),
+            watermark,
         };
 
         let mut tables = self.get_sources()?;
@@ -200,6 +219,7 @@ impl SourceManager for MemSourceManager {
                 self.worker_id,
                 *UNIX_SINGULARITY_DATE_EPOCH,
             ))),
+            watermark: None,
         };
 
         sources.insert(*table_id, desc);
@@ -279,6 +299,7 @@ mod tests {
             row_id_index: 0,
             pk_column_ids: vec![0],
             columns,
+            watermark: None,
         };
         let source_id = TableId::default();
 
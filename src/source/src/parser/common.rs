@@ -16,7 +16,9 @@ use chrono::NaiveDate;
 use num_traits::FromPrimitive;
 use risingwave_common::error::ErrorCode::{self, InternalError};
 use risingwave_common::error::{Result, RwError};
-use risingwave_common::types::{DataType, Decimal, NaiveDateWrapper, ScalarImpl, ScalarRef};
+use risingwave_common::types::{
+    DataType, Decimal, JsonbVal, NaiveDateWrapper, ScalarImpl, ScalarRef,
+};
 use serde_json::Value;
 
 #[inline(always)]
@@ -95,6 +97,11 @@ pub(crate) fn json_parse_value(
                 Err(e) => Err(e),
             },
         },
+        DataType::Jsonb => {
+            make_ScalarImpl!(value, |v: &Value| ScalarImpl::Jsonb(JsonbVal::from_value(
+                v.clone()
+            )))
+        }
         _ => Err(ErrorCode::NotImplemented(
             "unsupported type for json_parse_value".to_string(),
             None.into(),
@@ -15,15 +15,25 @@
 use risingwave_common::array::Op;
 use risingwave_common::error::ErrorCode::ProtocolError;
 use risingwave_common::error::{Result, RwError};
-use risingwave_common::types::Datum;
+use risingwave_common::types::{DataType, Datum, JsonbVal, ScalarImpl};
 use serde_json::Value;
 
 use crate::parser::common::json_parse_value;
 use crate::{Event, SourceColumnDesc, SourceParser};
 
 /// Parser for JSON format
-#[derive(Debug)]
-pub struct JSONParser;
+#[derive(Debug, Default)]
+pub struct JSONParser {
+    /// If set, the whole payload is mapped into the `JSONB` columns instead of being flattened
+    /// into columns by field names.
+    payload_as_jsonb: bool,
+}
+
+impl JSONParser {
+    pub fn new(payload_as_jsonb: bool) -> Self {
+        Self { payload_as_jsonb }
+    }
+}
 
 impl SourceParser for JSONParser {
     fn parse(&self, payload: &[u8], columns: &[SourceColumnDesc]) -> Result<Event> {
@@ -37,6 +47,9 @@ impl SourceParser for JSONParser {
                 .map(|column| {
                     if column.skip_parse {
                         None
+                    } else if self.payload_as_jsonb {
+                        (column.data_type == DataType::Jsonb)
+                            .then(|| ScalarImpl::Jsonb(JsonbVal::from_value(value.clone())))
                     } else {
                         json_parse_value(column, value.get(&column.name)).ok()
                     }
@@ -49,14 +62,14 @@ impl SourceParser for JSONParser {
 #[cfg(test)]
 mod tests {
     use risingwave_common::catalog::ColumnId;
-    use risingwave_common::types::{DataType, ScalarImpl};
+    use risingwave_common::types::{DataType, JsonbVal, ScalarImpl};
 
     use super::super::common::str_to_date;
     use crate::{JSONParser, SourceColumnDesc, SourceParser};
 
     #[test]
     fn test_json_parser() {
-        let parser = JSONParser {};
+        let parser = JSONParser::default();
 
         let payload = r#"{"i32":1,"bool":true,"i16":1,"i64":12345678,"f32":1.23,"f64":1.2345,"varchar":"varchar","date":"2021-01-01"}"#.as_bytes();
         let descs = vec![
@@ -139,4 +152,35 @@ mod tests {
         let result = parser.parse(payload, &descs);
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_json_parser_payload_as_jsonb() {
+        let parser = JSONParser::new(true);
+
+        let payload = r#"{"id": 1, "tags": ["a", "b"]}"#.as_bytes();
+        let descs = vec![
+            SourceColumnDesc {
+                name: "payload".to_string(),
+                data_type: DataType::Jsonb,
+                column_id: ColumnId::from(0),
+                skip_parse: false,
+            },
+            SourceColumnDesc {
+                name: "_row_id".to_string(),
+                data_type: DataType::Int64,
+                column_id: ColumnId::from(1),
+                skip_parse: true,
+            },
+        ];
+
+        let event = parser.parse(payload, &descs).unwrap();
+        let row = event.rows.first().unwrap();
+        assert_eq!(
+            row[0],
+            Some(ScalarImpl::Jsonb(
+                r#"{"id":1,"tags":["a","b"]}"#.parse::<JsonbVal>().unwrap()
+            ))
+        );
+        assert_eq!(row[1], None);
+    }
 }
@@ -68,9 +68,16 @@ impl SourceParserImpl {
         schema_location: &str,
     ) -> Result<Arc<Self>> {
         const PROTOBUF_MESSAGE_KEY: &str = "proto.message";
+        const JSON_PAYLOAD_AS_JSONB_KEY: &str = "json.payload_as_jsonb";
 
         let parser = match format {
-            SourceFormat::Json => SourceParserImpl::Json(JSONParser {}),
+            SourceFormat::Json => {
+                let payload_as_jsonb = properties
+                    .0
+                    .get(JSON_PAYLOAD_AS_JSONB_KEY)
+                    .map_or(false, |v| v.eq_ignore_ascii_case("true"));
+                SourceParserImpl::Json(JSONParser::new(payload_as_jsonb))
+            }
             SourceFormat::Protobuf => {
                 let message_name = properties.get(PROTOBUF_MESSAGE_KEY)?;
                 SourceParserImpl::Protobuf(ProtobufParser::new(schema_location, &message_name)?)
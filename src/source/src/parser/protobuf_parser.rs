@@ -197,6 +197,7 @@ fn protobuf_type_mapping(f: &FieldDescriptor, descriptors: &Descriptors) -> Resu
         FieldType::Int32 | FieldType::SFixed32 | FieldType::SInt32 => DataType::Int32,
         FieldType::Bool => DataType::Boolean,
         FieldType::String => DataType::Varchar,
+        FieldType::Bytes => DataType::Bytea,
         FieldType::Message(m) => {
             let vec = m
                 .fields()
@@ -257,6 +258,9 @@ impl SourceParser for ProtobufParser {
                 DataType::Varchar => {
                     protobuf_match_type!(value, ScalarImpl::Utf8, { String }, String)
                 }
+                DataType::Bytea => {
+                    protobuf_match_type!(value, ScalarImpl::Bytea, { Bytes }, Box<[u8]>)
+                }
                 DataType::Date => {
                     value.and_then(|v| match v {
                         Value::String(b) => str_to_date(&b).ok(),
@@ -71,6 +71,8 @@ pub enum DataType {
     String,
     /// Bytea
     Bytea,
+    /// Jsonb
+    Jsonb,
     /// Custom type such as enums
     Custom(ObjectName),
     /// Arrays
@@ -116,6 +118,7 @@ impl fmt::Display for DataType {
             DataType::Text => write!(f, "TEXT"),
             DataType::String => write!(f, "STRING"),
             DataType::Bytea => write!(f, "BYTEA"),
+            DataType::Jsonb => write!(f, "JSONB"),
             DataType::Array(ty) => write!(f, "{}[]", ty),
             DataType::Custom(ty) => write!(f, "{}", ty),
         }
@@ -37,9 +37,9 @@ pub use self::ddl::{
 };
 pub use self::operator::{BinaryOperator, UnaryOperator};
 pub use self::query::{
-    Cte, Fetch, Join, JoinConstraint, JoinOperator, LateralView, Offset, OffsetRows, OrderByExpr,
-    Query, Select, SelectItem, SetExpr, SetOperator, TableAlias, TableFactor, TableWithJoins, Top,
-    Values, With,
+    AsOf, Cte, Fetch, Join, JoinConstraint, JoinOperator, LateralView, Offset, OffsetRows,
+    OrderByExpr, Query, Select, SelectItem, SetExpr, SetOperator, TableAlias, TableFactor,
+    TableWithJoins, Top, Values, With,
 };
 pub use self::statement::*;
 pub use self::value::{DateTimeField, TrimWhereField, Value};
@@ -213,6 +213,13 @@ pub enum Expr {
         low: Box<Expr>,
         high: Box<Expr>,
     },
+    /// `<expr> [ NOT ] SIMILAR TO <pattern> [ ESCAPE <escape_char> ]`
+    SimilarTo {
+        expr: Box<Expr>,
+        negated: bool,
+        pattern: Box<Expr>,
+        escape_char: Option<char>,
+    },
     /// Binary operation e.g. `1 + 1` or `foo > bar`
     BinaryOp {
         left: Box<Expr>,
@@ -220,10 +227,7 @@ pub enum Expr {
         right: Box<Expr>,
     },
     /// Unary operation e.g. `NOT foo`
-    UnaryOp {
-        op: UnaryOperator,
-        expr: Box<Expr>,
-    },
+    UnaryOp { op: UnaryOperator, expr: Box<Expr> },
     /// CAST an expression to a different data type e.g. `CAST(foo AS VARCHAR(123))`
     Cast {
         expr: Box<Expr>,
@@ -235,6 +239,12 @@ pub enum Expr {
         expr: Box<Expr>,
         data_type: DataType,
     },
+    /// AT TIME ZONE converts `timestamp without time zone` to/from `timestamp with time zone` with
+    /// explicitly specified zone
+    AtTimeZone {
+        timestamp: Box<Expr>,
+        time_zone: String,
+    },
     /// EXTRACT(DateTimeField FROM <expr>)
     Extract {
         field: DateTimeField,
@@ -266,13 +276,14 @@ pub enum Expr {
     /// A constant of form `<data_type> 'value'`.
     /// This can represent ANSI SQL `DATE`, `TIME`, and `TIMESTAMP` literals (such as `DATE
     /// '2020-01-01'`), as well as constants of other types (a non-standard PostgreSQL extension).
-    TypedString {
-        data_type: DataType,
-        value: String,
-    },
-    MapAccess {
-        column: Box<Expr>,
-        keys: Vec<Expr>,
+    TypedString { data_type: DataType, value: String },
+    /// An array element access, e.g. `arr[1]`.
+    ArrayIndex { obj: Box<Expr>, index: Box<Expr> },
+    /// An array slice, e.g. `arr[1:3]`. Either bound can be omitted, e.g. `arr[:3]`.
+    ArrayRangeIndex {
+        obj: Box<Expr>,
+        start: Option<Box<Expr>>,
+        end: Option<Box<Expr>>,
     },
     /// Scalar function call e.g. `LEFT(foo, 5)`
     Function(Function),
@@ -304,22 +315,27 @@ pub enum Expr {
     /// The `ARRAY` expr. Alternative syntax for `ARRAY` is by utilizing curly braces, e.g. {1, 2,
     /// 3},
     Array(Vec<Expr>),
+    /// `ANY(array)` or `SOME(array)` as the right operand of a comparison, e.g. `a = ANY(arr)`.
+    AnyOp(Box<Expr>),
+    /// `ALL(array)` as the right operand of a comparison, e.g. `a <> ALL(arr)`.
+    AllOp(Box<Expr>),
 }
 
 impl fmt::Display for Expr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Expr::Identifier(s) => write!(f, "{}", s),
-            Expr::MapAccess { column, keys } => {
-                write!(f, "{}", column)?;
-                for k in keys {
-                    match k {
-                        k @ Expr::Value(Value::Number(_, _)) => write!(f, "[{}]", k)?,
-                        Expr::Value(Value::SingleQuotedString(s)) => write!(f, "[\"{}\"]", s)?,
-                        _ => write!(f, "[{}]", k)?,
-                    }
+            Expr::ArrayIndex { obj, index } => write!(f, "{}[{}]", obj, index),
+            Expr::ArrayRangeIndex { obj, start, end } => {
+                write!(f, "{}[", obj)?;
+                if let Some(start) = start {
+                    write!(f, "{}", start)?;
                 }
-                Ok(())
+                write!(f, ":")?;
+                if let Some(end) = end {
+                    write!(f, "{}", end)?;
+                }
+                write!(f, "]")
             }
             Expr::CompoundIdentifier(s) => write!(f, "{}", display_separated(s, ".")),
             Expr::FieldIdentifier(ast, s) => write!(f, "{}.{}", ast, display_separated(s, ".")),
@@ -364,6 +380,28 @@ impl fmt::Display for Expr {
                 low,
                 high
             ),
+            Expr::SimilarTo {
+                expr,
+                negated,
+                pattern,
+                escape_char,
+            } => {
+                write!(
+                    f,
+                    "{} {}SIMILAR TO {}",
+                    expr,
+                    if *negated { "NOT " } else { "" },
+                    pattern
+                )?;
+                if let Some(c) = escape_char {
+                    write!(
+                        f,
+                        " ESCAPE '{}'",
+                        value::escape_single_quote_string(&c.to_string())
+                    )?;
+                }
+                Ok(())
+            }
             Expr::BinaryOp { left, op, right } => write!(f, "{} {} {}", left, op, right),
             Expr::UnaryOp { op, expr } => {
                 if op == &UnaryOperator::PGPostfixFactorial {
@@ -375,6 +413,15 @@ impl fmt::Display for Expr {
             Expr::Cast { expr, data_type } => write!(f, "CAST({} AS {})", expr, data_type),
             Expr::TryCast { expr, data_type } => write!(f, "TRY_CAST({} AS {})", expr, data_type),
             Expr::Extract { field, expr } => write!(f, "EXTRACT({} FROM {})", field, expr),
+            Expr::AtTimeZone {
+                timestamp,
+                time_zone,
+            } => write!(
+                f,
+                "{} AT TIME ZONE '{}'",
+                timestamp,
+                value::escape_single_quote_string(time_zone)
+            ),
             Expr::Collate { expr, collation } => write!(f, "{} COLLATE {}", expr, collation),
             Expr::Nested(ast) => write!(f, "({})", ast),
             Expr::Value(v) => write!(f, "{}", v),
@@ -489,6 +536,8 @@ impl fmt::Display for Expr {
                     .as_slice()
                     .join(", ")
             ),
+            Expr::AnyOp(expr) => write!(f, "ANY({})", expr),
+            Expr::AllOp(expr) => write!(f, "ALL({})", expr),
         }
     }
 }
@@ -731,6 +780,8 @@ pub enum Statement {
         columns: Vec<Ident>,
         query: Box<Query>,
         with_options: Vec<SqlOption>,
+        /// `EMIT IMMEDIATELY` or `EMIT ON WINDOW CLOSE`
+        emit_mode: Option<EmitMode>,
     },
     /// CREATE TABLE
     CreateTable {
@@ -820,6 +871,17 @@ pub enum Statement {
         schema_name: ObjectName,
         if_not_exists: bool,
     },
+    /// CREATE FUNCTION
+    ///
+    /// Postgres: <https://www.postgresql.org/docs/current/sql-createfunction.html>
+    CreateFunction {
+        name: ObjectName,
+        args: Vec<FunctionArgDef>,
+        return_type: DataType,
+        language: Ident,
+        /// The string after `AS`, e.g. the path of a WebAssembly module.
+        definition: String,
+    },
     /// CREATE DATABASE
     CreateDatabase {
         db_name: ObjectName,
@@ -1012,6 +1074,7 @@ impl fmt::Display for Statement {
                 query,
                 materialized,
                 with_options,
+                emit_mode,
             } => {
                 write!(
                     f,
@@ -1026,7 +1089,11 @@ impl fmt::Display for Statement {
                 if !columns.is_empty() {
                     write!(f, " ({})", display_comma_separated(columns))?;
                 }
-                write!(f, " AS {}", query)
+                write!(f, " AS {}", query)?;
+                if let Some(emit_mode) = emit_mode {
+                    write!(f, " EMIT {}", emit_mode)?;
+                }
+                Ok(())
             }
             Statement::CreateTable {
                 name,
@@ -1171,6 +1238,21 @@ impl fmt::Display for Statement {
                 if_not_exists = if *if_not_exists { "IF NOT EXISTS " } else { "" },
                 name = schema_name
             ),
+            Statement::CreateFunction {
+                name,
+                args,
+                return_type,
+                language,
+                definition,
+            } => write!(
+                f,
+                "CREATE FUNCTION {}({}) RETURNS {} LANGUAGE {} AS '{}'",
+                name,
+                display_comma_separated(args),
+                return_type,
+                language,
+                value::escape_single_quote_string(definition)
+            ),
             Statement::Grant {
                 privileges,
                 objects,
@@ -1455,6 +1537,23 @@ impl fmt::Display for FunctionArg {
     }
 }
 
+/// An argument in `CREATE FUNCTION`, e.g. `a INT` or `INT`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FunctionArgDef {
+    pub name: Option<Ident>,
+    pub data_type: DataType,
+}
+
+impl fmt::Display for FunctionArgDef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "{} ", name)?;
+        }
+        write!(f, "{}", self.data_type)
+    }
+}
+
 /// A function call
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -1464,10 +1563,16 @@ pub struct Function {
     pub over: Option<WindowSpec>,
     // aggregate functions may specify eg `COUNT(DISTINCT x)`
     pub distinct: bool,
+    // Some functions must be called without trailing parentheses, e.g. `CURRENT_TIMESTAMP`. This
+    // flag is used for formatting.
+    pub special: bool,
 }
 
 impl fmt::Display for Function {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.special {
+            return write!(f, "{}", self.name);
+        }
         write!(
             f,
             "{}({}{})",
@@ -1492,6 +1597,7 @@ pub enum ObjectType {
     Schema,
     Source,
     MaterializedSource,
+    Function,
 }
 
 impl fmt::Display for ObjectType {
@@ -1504,6 +1610,7 @@ impl fmt::Display for ObjectType {
             ObjectType::Schema => "SCHEMA",
             ObjectType::Source => "SOURCE",
             ObjectType::MaterializedSource => "MATERIALIZED SOURCE",
+            ObjectType::Function => "FUNCTION",
         })
     }
 }
@@ -1524,9 +1631,11 @@ impl ParseTo for ObjectType {
             ObjectType::Index
         } else if parser.parse_keyword(Keyword::SCHEMA) {
             ObjectType::Schema
+        } else if parser.parse_keyword(Keyword::FUNCTION) {
+            ObjectType::Function
         } else {
             return parser.expected(
-                "TABLE, VIEW, INDEX, MATERIALIZED VIEW, SOURCE, MATERIALIZED SOURCE, or SCHEMA after DROP",
+                "TABLE, VIEW, INDEX, MATERIALIZED VIEW, SOURCE, MATERIALIZED SOURCE, SCHEMA, or FUNCTION after DROP",
                 parser.peek_token(),
             );
         };
@@ -1534,6 +1643,40 @@ impl ParseTo for ObjectType {
     }
 }
 
+/// When a materialized view emits the results of windowed aggregations.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum EmitMode {
+    /// Emit every update of the results.
+    Immediately,
+    /// Emit only the final result of a window, after the window is closed by the watermark.
+    OnWindowClose,
+}
+
+impl fmt::Display for EmitMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            EmitMode::Immediately => "IMMEDIATELY",
+            EmitMode::OnWindowClose => "ON WINDOW CLOSE",
+        })
+    }
+}
+
+impl ParseTo for EmitMode {
+    fn parse_to(parser: &mut Parser) -> Result<Self, ParserError> {
+        if parser.parse_keyword(Keyword::IMMEDIATELY) {
+            Ok(EmitMode::Immediately)
+        } else if parser.parse_keywords(&[Keyword::ON, Keyword::WINDOW, Keyword::CLOSE]) {
+            Ok(EmitMode::OnWindowClose)
+        } else {
+            parser.expected(
+                "IMMEDIATELY or ON WINDOW CLOSE after EMIT",
+                parser.peek_token(),
+            )
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct SqlOption {
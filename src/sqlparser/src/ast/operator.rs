@@ -86,6 +86,14 @@ pub enum BinaryOperator {
     PGRegexIMatch,
     PGRegexNotMatch,
     PGRegexNotIMatch,
+    /// `->`, which accesses a field of a JSON object or an element of a JSON array
+    Arrow,
+    /// `->>`, same as `->` but returns text
+    LongArrow,
+    /// `#>`, which accesses a JSON value at the given path
+    HashArrow,
+    /// `@>`, which checks whether the left JSON value contains the right one
+    AtArrow,
 }
 
 impl fmt::Display for BinaryOperator {
@@ -121,6 +129,10 @@ impl fmt::Display for BinaryOperator {
             BinaryOperator::PGRegexIMatch => "~*",
             BinaryOperator::PGRegexNotMatch => "!~",
             BinaryOperator::PGRegexNotIMatch => "!~*",
+            BinaryOperator::Arrow => "->",
+            BinaryOperator::LongArrow => "->>",
+            BinaryOperator::HashArrow => "#>",
+            BinaryOperator::AtArrow => "@>",
         })
     }
 }
@@ -35,6 +35,8 @@ pub struct Query {
     pub offset: Option<Offset>,
     /// `FETCH { FIRST | NEXT } <N> [ PERCENT ] { ROW | ROWS } | { ONLY | WITH TIES }`
     pub fetch: Option<Fetch>,
+    /// `AS OF { <epoch> | TIMESTAMP '<timestamp>' }`
+    pub as_of: Option<AsOf>,
 }
 
 impl Query {
@@ -76,6 +78,9 @@ impl fmt::Display for Query {
         if let Some(ref fetch) = self.fetch {
             write!(f, " {}", fetch)?;
         }
+        if let Some(ref as_of) = self.as_of {
+            write!(f, " {}", as_of)?;
+        }
         Ok(())
     }
 }
@@ -558,6 +563,29 @@ impl fmt::Display for Fetch {
     }
 }
 
+/// Read a historical snapshot of the storage instead of the latest one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AsOf {
+    /// `AS OF <epoch>`
+    Epoch(u64),
+    /// `AS OF TIMESTAMP '<timestamp>'`
+    Timestamp(String),
+}
+
+impl fmt::Display for AsOf {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AsOf::Epoch(epoch) => write!(f, "AS OF {}", epoch),
+            AsOf::Timestamp(ts) => write!(
+                f,
+                "AS OF TIMESTAMP '{}'",
+                value::escape_single_quote_string(ts)
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Top {
@@ -21,7 +21,7 @@ use serde::{Deserialize, Serialize};
 
 use super::ObjectType;
 use crate::ast::{
-    display_comma_separated, ColumnDef, Ident, ObjectName, SqlOption, TableConstraint,
+    display_comma_separated, ColumnDef, Expr, Ident, ObjectName, SqlOption, TableConstraint,
 };
 use crate::keywords::Keyword;
 use crate::parser::{Parser, ParserError};
@@ -75,11 +75,26 @@ pub struct CreateSourceStatement {
     pub if_not_exists: bool,
     pub columns: Vec<ColumnDef>,
     pub constraints: Vec<TableConstraint>,
+    pub watermarks: Vec<SourceWatermark>,
     pub source_name: ObjectName,
     pub with_properties: WithProperties,
     pub source_schema: SourceSchema,
 }
 
+/// `WATERMARK FOR column AS expr` in the column list of `CREATE SOURCE`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SourceWatermark {
+    pub column: Ident,
+    pub expr: Expr,
+}
+
+impl fmt::Display for SourceWatermark {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "WATERMARK FOR {} AS {}", self.column, self.expr)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum SourceSchema {
@@ -156,7 +171,7 @@ impl ParseTo for CreateSourceStatement {
         impl_parse_to!(source_name: ObjectName, p);
 
         // parse columns
-        let (columns, constraints) = p.parse_columns()?;
+        let (columns, constraints, watermarks) = p.parse_columns_with_watermarks()?;
 
         impl_parse_to!(with_properties: WithProperties, p);
         impl_parse_to!([Keyword::ROW, Keyword::FORMAT], p);
@@ -165,6 +180,7 @@ impl ParseTo for CreateSourceStatement {
             if_not_exists,
             columns,
             constraints,
+            watermarks,
             source_name,
             with_properties,
             source_schema,
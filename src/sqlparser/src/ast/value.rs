@@ -119,6 +119,18 @@ pub enum DateTimeField {
     Hour,
     Minute,
     Second,
+    Century,
+    Decade,
+    Dow,
+    Doy,
+    Epoch,
+    Isodow,
+    Isoyear,
+    Microseconds,
+    Millennium,
+    Milliseconds,
+    Quarter,
+    Week,
 }
 
 impl fmt::Display for DateTimeField {
@@ -130,6 +142,18 @@ impl fmt::Display for DateTimeField {
             DateTimeField::Hour => "HOUR",
             DateTimeField::Minute => "MINUTE",
             DateTimeField::Second => "SECOND",
+            DateTimeField::Century => "CENTURY",
+            DateTimeField::Decade => "DECADE",
+            DateTimeField::Dow => "DOW",
+            DateTimeField::Doy => "DOY",
+            DateTimeField::Epoch => "EPOCH",
+            DateTimeField::Isodow => "ISODOW",
+            DateTimeField::Isoyear => "ISOYEAR",
+            DateTimeField::Microseconds => "MICROSECONDS",
+            DateTimeField::Millennium => "MILLENNIUM",
+            DateTimeField::Milliseconds => "MILLISECONDS",
+            DateTimeField::Quarter => "QUARTER",
+            DateTimeField::Week => "WEEK",
         })
     }
 }
@@ -112,6 +112,7 @@ define_keywords!(
     CAST,
     CEIL,
     CEILING,
+    CENTURY,
     CHAIN,
     CHAR,
     CHARACTER,
@@ -166,6 +167,7 @@ define_keywords!(
     DAY,
     DEALLOCATE,
     DEC,
+    DECADE,
     DECIMAL,
     DECLARE,
     DEFAULT,
@@ -179,15 +181,19 @@ define_keywords!(
     DISCONNECT,
     DISTINCT,
     DOUBLE,
+    DOW,
+    DOY,
     DROP,
     DYNAMIC,
     EACH,
     ELEMENT,
     ELSE,
+    EMIT,
     END,
     END_EXEC = "END-EXEC",
     END_FRAME,
     END_PARTITION,
+    EPOCH,
     EQUALS,
     ERROR,
     ESCAPE,
@@ -234,6 +240,7 @@ define_keywords!(
     IF,
     IGNORE,
     ILIKE,
+    IMMEDIATELY,
     IN,
     INDEX,
     INDICATOR,
@@ -248,9 +255,12 @@ define_keywords!(
     INTERVAL,
     INTO,
     IS,
+    ISODOW,
     ISOLATION,
+    ISOYEAR,
     JOIN,
     JSON,
+    JSONB,
     KEY,
     LANGUAGE,
     LARGE,
@@ -274,6 +284,9 @@ define_keywords!(
     MERGE,
     MESSAGE,
     METHOD,
+    MICROSECONDS,
+    MILLENNIUM,
+    MILLISECONDS,
     MIN,
     MINUTE,
     MOD,
@@ -339,6 +352,7 @@ define_keywords!(
     PROCEDURE,
     PROTOBUF,
     PURGE,
+    QUARTER,
     RANGE,
     RANK,
     RCFILE,
@@ -483,6 +497,8 @@ define_keywords!(
     VIEW,
     VIEWS,
     VIRTUAL,
+    WATERMARK,
+    WEEK,
     WHEN,
     WHENEVER,
     WHERE,
@@ -520,6 +536,7 @@ pub const RESERVED_FOR_TABLE_ALIAS: &[Keyword] = &[
     Keyword::UNION,
     Keyword::EXCEPT,
     Keyword::INTERSECT,
+    Keyword::EMIT,
     // Reserved only as a table alias in the `FROM`/`JOIN` clauses:
     Keyword::ON,
     Keyword::JOIN,
@@ -558,6 +575,7 @@ pub const RESERVED_FOR_COLUMN_ALIAS: &[Keyword] = &[
     Keyword::UNION,
     Keyword::EXCEPT,
     Keyword::INTERSECT,
+    Keyword::EMIT,
     Keyword::CLUSTER,
     // Reserved only as a column alias in the `SELECT` clause
     Keyword::FROM,
@@ -116,6 +116,8 @@ pub struct Parser {
 }
 
 impl Parser {
+    // `AT TIME ZONE` binds tighter than `*` and `/`, but looser than `::`.
+    const AT_TIME_ZONE_PREC: u8 = 41;
     const BETWEEN_PREC: u8 = 20;
     const PLUS_MINUS_PREC: u8 = 30;
     const UNARY_NOT_PREC: u8 = 15;
@@ -383,6 +385,9 @@ impl Parser {
                 Keyword::CAST => self.parse_cast_expr(),
                 Keyword::TRY_CAST => self.parse_try_cast_expr(),
                 Keyword::EXISTS => self.parse_exists_expr(),
+                Keyword::CURRENT_TIMESTAMP | Keyword::CURRENT_DATE | Keyword::LOCALTIMESTAMP => {
+                    self.parse_time_function(ObjectName(vec![w.to_ident()]))
+                }
                 Keyword::EXTRACT => self.parse_extract_expr(),
                 Keyword::SUBSTRING => self.parse_substring_expr(),
                 Keyword::TRIM => self.parse_trim_expr(),
@@ -592,9 +597,26 @@ impl Parser {
             args,
             over,
             distinct,
+            special: false,
         }))
     }
 
+    /// Parses a time function that can be called without parentheses, e.g. `CURRENT_TIMESTAMP`.
+    pub fn parse_time_function(&mut self, name: ObjectName) -> Result<Expr, ParserError> {
+        if self.consume_token(&Token::LParen) {
+            self.prev_token();
+            self.parse_function(name)
+        } else {
+            Ok(Expr::Function(Function {
+                name,
+                args: vec![],
+                over: None,
+                distinct: false,
+                special: true,
+            }))
+        }
+    }
+
     pub fn parse_window_frame_units(&mut self) -> Result<WindowFrameUnits, ParserError> {
         match self.next_token() {
             Token::Word(w) => match w.keyword {
@@ -849,6 +871,18 @@ impl Parser {
                 Keyword::HOUR => Ok(DateTimeField::Hour),
                 Keyword::MINUTE => Ok(DateTimeField::Minute),
                 Keyword::SECOND => Ok(DateTimeField::Second),
+                Keyword::CENTURY => Ok(DateTimeField::Century),
+                Keyword::DECADE => Ok(DateTimeField::Decade),
+                Keyword::DOW => Ok(DateTimeField::Dow),
+                Keyword::DOY => Ok(DateTimeField::Doy),
+                Keyword::EPOCH => Ok(DateTimeField::Epoch),
+                Keyword::ISODOW => Ok(DateTimeField::Isodow),
+                Keyword::ISOYEAR => Ok(DateTimeField::Isoyear),
+                Keyword::MICROSECONDS => Ok(DateTimeField::Microseconds),
+                Keyword::MILLENNIUM => Ok(DateTimeField::Millennium),
+                Keyword::MILLISECONDS => Ok(DateTimeField::Milliseconds),
+                Keyword::QUARTER => Ok(DateTimeField::Quarter),
+                Keyword::WEEK => Ok(DateTimeField::Week),
                 _ => self.expected("date/time field", Token::Word(w))?,
             },
             unexpected => self.expected("date/time field", unexpected),
@@ -962,6 +996,10 @@ impl Parser {
             Token::TildeAsterisk => Some(BinaryOperator::PGRegexIMatch),
             Token::ExclamationMarkTilde => Some(BinaryOperator::PGRegexNotMatch),
             Token::ExclamationMarkTildeAsterisk => Some(BinaryOperator::PGRegexNotIMatch),
+            Token::Arrow => Some(BinaryOperator::Arrow),
+            Token::LongArrow => Some(BinaryOperator::LongArrow),
+            Token::HashArrow => Some(BinaryOperator::HashArrow),
+            Token::AtArrow => Some(BinaryOperator::AtArrow),
             Token::Word(w) => match w.keyword {
                 Keyword::AND => Some(BinaryOperator::And),
                 Keyword::OR => Some(BinaryOperator::Or),
@@ -983,10 +1021,36 @@ impl Parser {
         };
 
         if let Some(op) = regular_binary_operator {
+            let is_comparison = matches!(
+                op,
+                BinaryOperator::Eq
+                    | BinaryOperator::NotEq
+                    | BinaryOperator::Gt
+                    | BinaryOperator::GtEq
+                    | BinaryOperator::Lt
+                    | BinaryOperator::LtEq
+            );
+            let right = match self.peek_token() {
+                Token::Word(w)
+                    if is_comparison
+                        && matches!(w.keyword, Keyword::ANY | Keyword::SOME | Keyword::ALL)
+                        && self.peek_nth_token(1) == Token::LParen =>
+                {
+                    self.next_token();
+                    self.expect_token(&Token::LParen)?;
+                    let array = Box::new(self.parse_expr()?);
+                    self.expect_token(&Token::RParen)?;
+                    match w.keyword {
+                        Keyword::ALL => Expr::AllOp(array),
+                        _ => Expr::AnyOp(array),
+                    }
+                }
+                _ => self.parse_subexpr(precedence)?,
+            };
             Ok(Expr::BinaryOp {
                 left: Box::new(expr),
                 op,
-                right: Box::new(self.parse_subexpr(precedence)?),
+                right: Box::new(right),
             })
         } else if let Token::Word(w) = &tok {
             match w.keyword {
@@ -1017,15 +1081,25 @@ impl Parser {
                         )
                     }
                 }
-                Keyword::NOT | Keyword::IN | Keyword::BETWEEN => {
+                Keyword::AT => {
+                    self.expect_keywords(&[Keyword::TIME, Keyword::ZONE])?;
+                    let time_zone = self.parse_literal_string()?;
+                    Ok(Expr::AtTimeZone {
+                        timestamp: Box::new(expr),
+                        time_zone,
+                    })
+                }
+                Keyword::NOT | Keyword::IN | Keyword::BETWEEN | Keyword::SIMILAR => {
                     self.prev_token();
                     let negated = self.parse_keyword(Keyword::NOT);
                     if self.parse_keyword(Keyword::IN) {
                         self.parse_in(expr, negated)
                     } else if self.parse_keyword(Keyword::BETWEEN) {
                         self.parse_between(expr, negated)
+                    } else if self.parse_keyword(Keyword::SIMILAR) {
+                        self.parse_similar_to(expr, negated)
                     } else {
-                        self.expected("IN or BETWEEN after NOT", self.peek_token())
+                        self.expected("IN, BETWEEN or SIMILAR TO after NOT", self.peek_token())
                     }
                 }
                 // Can only happen if `get_next_precedence` got out of sync with this function
@@ -1040,31 +1114,40 @@ impl Parser {
                 expr: Box::new(expr),
             })
         } else if Token::LBracket == tok {
-            self.parse_map_access(expr)
+            self.parse_array_index(expr)
         } else {
             // Can only happen if `get_next_precedence` got out of sync with this function
             parser_err!(format!("No infix parser for token {:?}", tok))
         }
     }
 
-    pub fn parse_map_access(&mut self, expr: Expr) -> Result<Expr, ParserError> {
-        let key = self.parse_map_key()?;
-        let tok = self.consume_token(&Token::RBracket);
-        debug!("Tok: {}", tok);
-        let mut key_parts: Vec<Expr> = vec![key];
-        while self.consume_token(&Token::LBracket) {
-            let key = self.parse_map_key()?;
-            let tok = self.consume_token(&Token::RBracket);
-            debug!("Tok: {}", tok);
-            key_parts.push(key);
-        }
-        match expr {
-            e @ Expr::Identifier(_) | e @ Expr::CompoundIdentifier(_) => Ok(Expr::MapAccess {
-                column: Box::new(e),
-                keys: key_parts,
-            }),
-            _ => Ok(expr),
-        }
+    /// Parses an array subscript `[index]` or slice `[start:end]` following an expression, where
+    /// the `[` has been consumed.
+    pub fn parse_array_index(&mut self, expr: Expr) -> Result<Expr, ParserError> {
+        let start = if self.peek_token() == Token::Colon {
+            None
+        } else {
+            Some(Box::new(self.parse_expr()?))
+        };
+        let expr = if self.consume_token(&Token::Colon) {
+            let end = if self.peek_token() == Token::RBracket {
+                None
+            } else {
+                Some(Box::new(self.parse_expr()?))
+            };
+            Expr::ArrayRangeIndex {
+                obj: Box::new(expr),
+                start,
+                end,
+            }
+        } else {
+            Expr::ArrayIndex {
+                obj: Box::new(expr),
+                index: start.unwrap(),
+            }
+        };
+        self.expect_token(&Token::RBracket)?;
+        Ok(expr)
     }
 
     /// Parses the parens following the `[ NOT ] IN` operator
@@ -1103,6 +1186,29 @@ impl Parser {
         })
     }
 
+    /// Parses `SIMILAR TO <pattern> [ ESCAPE <escape_char> ]`, assuming the `SIMILAR` keyword
+    /// (and a possible `NOT` before it) has already been consumed.
+    pub fn parse_similar_to(&mut self, expr: Expr, negated: bool) -> Result<Expr, ParserError> {
+        self.expect_keyword(Keyword::TO)?;
+        let pattern = self.parse_subexpr(Self::BETWEEN_PREC)?;
+        let escape_char = if self.parse_keyword(Keyword::ESCAPE) {
+            let escape = self.parse_literal_string()?;
+            let mut chars = escape.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c),
+                _ => return parser_err!(format!("invalid escape string: '{}'", escape)),
+            }
+        } else {
+            None
+        };
+        Ok(Expr::SimilarTo {
+            expr: Box::new(expr),
+            negated,
+            pattern: Box::new(pattern),
+            escape_char,
+        })
+    }
+
     /// Parse a postgresql casting style which is in the form of `expr::datatype`
     pub fn parse_pg_cast(&mut self, expr: Expr) -> Result<Expr, ParserError> {
         Ok(Expr::Cast {
@@ -1129,13 +1235,25 @@ impl Parser {
                 Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
                 Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
+                Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(Self::BETWEEN_PREC),
                 _ => Ok(0),
             },
+            Token::Word(w) if w.keyword == Keyword::AT => {
+                match (self.peek_nth_token(1), self.peek_nth_token(2)) {
+                    (Token::Word(w), Token::Word(w2))
+                        if w.keyword == Keyword::TIME && w2.keyword == Keyword::ZONE =>
+                    {
+                        Ok(Self::AT_TIME_ZONE_PREC)
+                    }
+                    _ => Ok(0),
+                }
+            }
             Token::Word(w) if w.keyword == Keyword::IS => Ok(17),
             Token::Word(w) if w.keyword == Keyword::IN => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::BETWEEN => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::LIKE => Ok(Self::BETWEEN_PREC),
             Token::Word(w) if w.keyword == Keyword::ILIKE => Ok(Self::BETWEEN_PREC),
+            Token::Word(w) if w.keyword == Keyword::SIMILAR => Ok(Self::BETWEEN_PREC),
             Token::Eq
             | Token::Lt
             | Token::LtEq
@@ -1150,12 +1268,13 @@ impl Parser {
             | Token::Spaceship => Ok(20),
             Token::Pipe => Ok(21),
             Token::Caret | Token::Sharp | Token::ShiftRight | Token::ShiftLeft => Ok(22),
+            Token::Arrow | Token::LongArrow | Token::HashArrow | Token::AtArrow => Ok(22),
             Token::Ampersand => Ok(23),
             Token::Plus | Token::Minus => Ok(Self::PLUS_MINUS_PREC),
             Token::Mul | Token::Div | Token::Mod | Token::StringConcat => Ok(40),
             Token::DoubleColon => Ok(50),
             Token::ExclamationMark => Ok(50),
-            Token::LBracket => Ok(10),
+            Token::LBracket => Ok(50),
             _ => Ok(0),
         }
     }
@@ -1386,6 +1505,8 @@ impl Parser {
             self.parse_create_index(true)
         } else if self.parse_keyword(Keyword::SCHEMA) {
             self.parse_create_schema()
+        } else if self.parse_keyword(Keyword::FUNCTION) {
+            self.parse_create_function()
         } else {
             self.expected("an object type after CREATE", self.peek_token())
         }
@@ -1400,6 +1521,65 @@ impl Parser {
         })
     }
 
+    /// Parse `CREATE FUNCTION name ([argname] argtype, ...) RETURNS type LANGUAGE lang AS
+    /// 'definition'`, where `LANGUAGE` and `AS` can be in either order.
+    pub fn parse_create_function(&mut self) -> Result<Statement, ParserError> {
+        let name = self.parse_object_name()?;
+        self.expect_token(&Token::LParen)?;
+        let args = if self.consume_token(&Token::RParen) {
+            vec![]
+        } else {
+            let args = self.parse_comma_separated(Parser::parse_function_arg_def)?;
+            self.expect_token(&Token::RParen)?;
+            args
+        };
+        self.expect_keyword(Keyword::RETURNS)?;
+        let return_type = self.parse_data_type()?;
+
+        let mut language = None;
+        let mut definition = None;
+        loop {
+            if language.is_none() && self.parse_keyword(Keyword::LANGUAGE) {
+                language = Some(self.parse_identifier()?);
+            } else if definition.is_none() && self.parse_keyword(Keyword::AS) {
+                definition = Some(self.parse_literal_string()?);
+            } else {
+                break;
+            }
+        }
+        let language = match language {
+            Some(language) => language,
+            None => return self.expected("LANGUAGE", self.peek_token()),
+        };
+        let definition = match definition {
+            Some(definition) => definition,
+            None => return self.expected("AS", self.peek_token()),
+        };
+        Ok(Statement::CreateFunction {
+            name,
+            args,
+            return_type,
+            language,
+            definition,
+        })
+    }
+
+    /// Parse an argument of `CREATE FUNCTION`. It's named if a non-keyword word is followed by
+    /// the type, e.g. `a INT`.
+    fn parse_function_arg_def(&mut self) -> Result<FunctionArgDef, ParserError> {
+        let name = match (self.peek_token(), self.peek_nth_token(1)) {
+            (Token::Word(w), next)
+                if w.keyword == Keyword::NoKeyword
+                    && !matches!(next, Token::Comma | Token::RParen) =>
+            {
+                Some(self.parse_identifier()?)
+            }
+            _ => None,
+        };
+        let data_type = self.parse_data_type()?;
+        Ok(FunctionArgDef { name, data_type })
+    }
+
     pub fn parse_create_view(
         &mut self,
         materialized: bool,
@@ -1412,6 +1592,11 @@ impl Parser {
         let with_options = self.parse_options(Keyword::WITH)?;
         self.expect_keyword(Keyword::AS)?;
         let query = Box::new(self.parse_query()?);
+        let emit_mode = if materialized && self.parse_keyword(Keyword::EMIT) {
+            Some(EmitMode::parse_to(self)?)
+        } else {
+            None
+        };
         // Optional `WITH [ CASCADED | LOCAL ] CHECK OPTION` is widely supported here.
         Ok(Statement::CreateView {
             name,
@@ -1420,6 +1605,7 @@ impl Parser {
             materialized,
             or_replace,
             with_options,
+            emit_mode,
         })
     }
 
@@ -1507,15 +1693,37 @@ impl Parser {
     }
 
     pub fn parse_columns(&mut self) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>), ParserError> {
+        let (columns, constraints, _) = self.parse_columns_inner(false)?;
+        Ok((columns, constraints))
+    }
+
+    /// Parses the column list of `CREATE SOURCE`, which may contain `WATERMARK FOR` clauses besides
+    /// columns and constraints.
+    pub fn parse_columns_with_watermarks(
+        &mut self,
+    ) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>, Vec<SourceWatermark>), ParserError> {
+        self.parse_columns_inner(true)
+    }
+
+    fn parse_columns_inner(
+        &mut self,
+        allow_watermark: bool,
+    ) -> Result<(Vec<ColumnDef>, Vec<TableConstraint>, Vec<SourceWatermark>), ParserError> {
         let mut columns = vec![];
         let mut constraints = vec![];
+        let mut watermarks = vec![];
         if !self.consume_token(&Token::LParen) || self.consume_token(&Token::RParen) {
-            return Ok((columns, constraints));
+            return Ok((columns, constraints, watermarks));
         }
 
         loop {
             if let Some(constraint) = self.parse_optional_table_constraint()? {
                 constraints.push(constraint);
+            } else if allow_watermark && self.parse_keywords(&[Keyword::WATERMARK, Keyword::FOR]) {
+                let column = self.parse_identifier()?;
+                self.expect_keyword(Keyword::AS)?;
+                let expr = self.parse_expr()?;
+                watermarks.push(SourceWatermark { column, expr });
             } else if let Token::Word(_) = self.peek_token() {
                 columns.push(self.parse_column_def()?);
             } else {
@@ -1530,7 +1738,7 @@ impl Parser {
             }
         }
 
-        Ok((columns, constraints))
+        Ok((columns, constraints, watermarks))
     }
 
     fn parse_column_def(&mut self) -> Result<ColumnDef, ParserError> {
@@ -1904,21 +2112,6 @@ impl Parser {
         }
     }
 
-    /// Parse a map key string
-    pub fn parse_map_key(&mut self) -> Result<Expr, ParserError> {
-        match self.next_token() {
-            Token::Word(Word { value, keyword, .. }) if keyword == Keyword::NoKeyword => {
-                if self.peek_token() == Token::LParen {
-                    return self.parse_function(ObjectName(vec![Ident::new(value)]));
-                }
-                Ok(Expr::Value(Value::SingleQuotedString(value)))
-            }
-            Token::SingleQuotedString(s) => Ok(Expr::Value(Value::SingleQuotedString(s))),
-            Token::Number(s, _) => Ok(Expr::Value(Value::Number(s, false))),
-            unexpected => self.expected("literal string, number or function", unexpected),
-        }
-    }
-
     /// Parse a SQL datatype (in the context of a CREATE TABLE statement for example) and convert
     /// into an array of that datatype if needed
     pub fn parse_data_type(&mut self) -> Result<DataType, ParserError> {
@@ -1987,6 +2180,7 @@ impl Parser {
                     }
                 }
                 Keyword::BYTEA => Ok(DataType::Bytea),
+                Keyword::JSONB => Ok(DataType::Jsonb),
                 Keyword::NUMERIC | Keyword::DECIMAL | Keyword::DEC => {
                     let (precision, scale) = self.parse_optional_precision_scale()?;
                     Ok(DataType::Decimal(precision, scale))
@@ -2008,6 +2202,12 @@ impl Parser {
         &mut self,
         reserved_kwds: &[Keyword],
     ) -> Result<Option<Ident>, ParserError> {
+        // `AS OF` starts the snapshot clause of a query, rather than an alias.
+        if matches!(self.peek_token(), Token::Word(w) if w.keyword == Keyword::AS)
+            && matches!(self.peek_nth_token(1), Token::Word(w) if w.keyword == Keyword::OF)
+        {
+            return Ok(None);
+        }
         let after_as = self.parse_keyword(Keyword::AS);
         match self.next_token() {
             // Accept any identifier after `AS` (though many dialects have restrictions on
@@ -2248,6 +2448,12 @@ impl Parser {
                 None
             };
 
+            let as_of = if self.parse_keywords(&[Keyword::AS, Keyword::OF]) {
+                Some(self.parse_as_of()?)
+            } else {
+                None
+            };
+
             Ok(Query {
                 with,
                 body,
@@ -2255,6 +2461,7 @@ impl Parser {
                 limit,
                 offset,
                 fetch,
+                as_of,
             })
         } else {
             let insert = self.parse_insert()?;
@@ -2266,10 +2473,25 @@ impl Parser {
                 order_by: vec![],
                 offset: None,
                 fetch: None,
+                as_of: None,
             })
         }
     }
 
+    /// Parse the snapshot to read after `AS OF`
+    pub fn parse_as_of(&mut self) -> Result<AsOf, ParserError> {
+        if self.parse_keyword(Keyword::TIMESTAMP) {
+            return Ok(AsOf::Timestamp(self.parse_literal_string()?));
+        }
+        match self.next_token() {
+            Token::Number(s, _) => s.parse::<u64>().map(AsOf::Epoch).map_err(|e| {
+                ParserError::ParserError(format!("Could not parse '{}' as epoch: {}", s, e))
+            }),
+            Token::SingleQuotedString(s) => Ok(AsOf::Timestamp(s)),
+            unexpected => self.expected("epoch or timestamp", unexpected),
+        }
+    }
+
     /// Parse a CTE (`alias [( col1, col2, ... )] AS (subquery)`)
     fn parse_cte(&mut self) -> Result<Cte, ParserError> {
         let name = self.parse_identifier()?;
@@ -377,6 +377,7 @@ fn parse_select_count_wildcard() {
             args: vec![FunctionArg::Unnamed(FunctionArgExpr::Wildcard)],
             over: None,
             distinct: false,
+            special: false,
         }),
         expr_from_projection(only(&select.projection))
     );
@@ -395,6 +396,7 @@ fn parse_select_count_distinct() {
             }))],
             over: None,
             distinct: true,
+            special: false,
         }),
         expr_from_projection(only(&select.projection))
     );
@@ -760,6 +762,44 @@ fn parse_ilike() {
     chk(true);
 }
 
+#[test]
+fn parse_similar_to() {
+    fn chk(negated: bool) {
+        let sql = &format!(
+            "SELECT * FROM customers WHERE name {}SIMILAR TO '%(a|b)'",
+            if negated { "NOT " } else { "" }
+        );
+        let select = verified_only_select(sql);
+        assert_eq!(
+            Expr::SimilarTo {
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                negated,
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("%(a|b)".to_string()))),
+                escape_char: None,
+            },
+            select.selection.unwrap()
+        );
+
+        let sql = &format!(
+            "SELECT * FROM customers WHERE name {}SIMILAR TO '%#_a' ESCAPE '#' IS NULL",
+            if negated { "NOT " } else { "" }
+        );
+        let select = verified_only_select(sql);
+        assert_eq!(
+            Expr::IsNull(Box::new(Expr::SimilarTo {
+                expr: Box::new(Expr::Identifier(Ident::new("name"))),
+                negated,
+                pattern: Box::new(Expr::Value(Value::SingleQuotedString("%#_a".to_string()))),
+                escape_char: Some('#'),
+            })),
+            select.selection.unwrap()
+        );
+    }
+    chk(false);
+    chk(true);
+    assert!(parse_sql_statements("SELECT 'a' SIMILAR TO 'a' ESCAPE 'ab'").is_err());
+}
+
 #[test]
 fn parse_in_list() {
     fn chk(negated: bool) {
@@ -1093,6 +1133,7 @@ fn parse_select_having() {
                 args: vec![FunctionArg::Unnamed(FunctionArgExpr::Wildcard)],
                 over: None,
                 distinct: false,
+                special: false,
             })),
             op: BinaryOperator::Gt,
             right: Box::new(Expr::Value(number("1")))
@@ -1201,6 +1242,10 @@ fn parse_extract() {
     verified_stmt("SELECT EXTRACT(HOUR FROM d)");
     verified_stmt("SELECT EXTRACT(MINUTE FROM d)");
     verified_stmt("SELECT EXTRACT(SECOND FROM d)");
+    verified_stmt("SELECT EXTRACT(MILLISECONDS FROM d)");
+    verified_stmt("SELECT EXTRACT(EPOCH FROM d)");
+    verified_stmt("SELECT EXTRACT(DOW FROM d)");
+    verified_stmt("SELECT EXTRACT(QUARTER FROM d)");
 
     let res = parse_sql_statements("SELECT EXTRACT(MILLISECOND FROM d)");
     assert_eq!(
@@ -1209,6 +1254,55 @@ fn parse_extract() {
     );
 }
 
+#[test]
+fn parse_at_time_zone() {
+    let sql = "SELECT t AT TIME ZONE 'Asia/Shanghai' FROM t";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::AtTimeZone {
+            timestamp: Box::new(Expr::Identifier(Ident::new("t"))),
+            time_zone: "Asia/Shanghai".to_string(),
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+
+    // `AT TIME ZONE` binds tighter than arithmetic operators.
+    let select = verified_only_select("SELECT a + b AT TIME ZONE 'UTC' FROM t");
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::Identifier(Ident::new("a"))),
+            op: BinaryOperator::Plus,
+            right: Box::new(Expr::AtTimeZone {
+                timestamp: Box::new(Expr::Identifier(Ident::new("b"))),
+                time_zone: "UTC".to_string(),
+            }),
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+
+    assert!(parse_sql_statements("SELECT t AT TIME 'UTC'").is_err());
+    assert!(parse_sql_statements("SELECT t AT TIME ZONE tz").is_err());
+}
+
+#[test]
+fn parse_time_functions() {
+    for name in ["CURRENT_TIMESTAMP", "CURRENT_DATE", "LOCALTIMESTAMP"] {
+        let select = verified_only_select(&format!("SELECT {}", name));
+        assert_eq!(
+            &Expr::Function(Function {
+                name: ObjectName(vec![Ident::new(name)]),
+                args: vec![],
+                over: None,
+                distinct: false,
+                special: true,
+            }),
+            expr_from_projection(only(&select.projection)),
+        );
+    }
+    verified_stmt("SELECT CURRENT_TIMESTAMP()");
+    verified_stmt("SELECT now()");
+}
+
 #[test]
 fn parse_create_table() {
     let sql = "CREATE TABLE uk_cities (\
@@ -1790,6 +1884,7 @@ fn parse_named_argument_function() {
             ],
             over: None,
             distinct: false,
+            special: false,
         }),
         expr_from_projection(only(&select.projection))
     );
@@ -1823,6 +1918,7 @@ fn parse_window_functions() {
                 window_frame: None,
             }),
             distinct: false,
+            special: false,
         }),
         expr_from_projection(&select.projection[0])
     );
@@ -2067,6 +2163,7 @@ fn parse_delimited_identifiers() {
             args: vec![],
             over: None,
             distinct: false,
+            special: false,
         }),
         expr_from_projection(&select.projection[1]),
     );
@@ -2767,6 +2864,7 @@ fn parse_create_view() {
             or_replace,
             materialized,
             with_options,
+            emit_mode,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<Ident>::new(), columns);
@@ -2774,6 +2872,7 @@ fn parse_create_view() {
             assert!(!materialized);
             assert!(!or_replace);
             assert_eq!(with_options, vec![]);
+            assert_eq!(emit_mode, None);
         }
         _ => unreachable!(),
     }
@@ -2813,10 +2912,12 @@ fn parse_create_view_with_columns() {
             with_options,
             query,
             materialized,
+            emit_mode,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![Ident::new("has"), Ident::new("cols")]);
             assert_eq!(with_options, vec![]);
+            assert_eq!(emit_mode, None);
             assert_eq!("SELECT 1, 2", query.to_string());
             assert!(!materialized);
             assert!(!or_replace)
@@ -2835,10 +2936,12 @@ fn parse_create_or_replace_view() {
             with_options,
             query,
             materialized,
+            emit_mode,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![]);
             assert_eq!(with_options, vec![]);
+            assert_eq!(emit_mode, None);
             assert_eq!("SELECT 1", query.to_string());
             assert!(!materialized);
             assert!(or_replace)
@@ -2862,10 +2965,12 @@ fn parse_create_or_replace_materialized_view() {
             with_options,
             query,
             materialized,
+            emit_mode,
         } => {
             assert_eq!("v", name.to_string());
             assert_eq!(columns, vec![]);
             assert_eq!(with_options, vec![]);
+            assert_eq!(emit_mode, None);
             assert_eq!("SELECT 1", query.to_string());
             assert!(materialized);
             assert!(or_replace)
@@ -2885,18 +2990,51 @@ fn parse_create_materialized_view() {
             query,
             materialized,
             with_options,
+            emit_mode,
         } => {
             assert_eq!("myschema.myview", name.to_string());
             assert_eq!(Vec::<Ident>::new(), columns);
             assert_eq!("SELECT foo FROM bar", query.to_string());
             assert!(materialized);
             assert_eq!(with_options, vec![]);
+            assert_eq!(emit_mode, None);
             assert!(!or_replace);
         }
         _ => unreachable!(),
     }
 }
 
+#[test]
+fn parse_create_materialized_view_emit_mode() {
+    let sql =
+        "CREATE MATERIALIZED VIEW v AS SELECT COUNT(*) FROM t GROUP BY w EMIT ON WINDOW CLOSE";
+    match verified_stmt(sql) {
+        Statement::CreateView {
+            query, emit_mode, ..
+        } => {
+            assert_eq!("SELECT COUNT(*) FROM t GROUP BY w", query.to_string());
+            assert_eq!(emit_mode, Some(EmitMode::OnWindowClose));
+        }
+        _ => unreachable!(),
+    }
+
+    let sql = "CREATE MATERIALIZED VIEW v AS SELECT * FROM t EMIT IMMEDIATELY";
+    match verified_stmt(sql) {
+        Statement::CreateView { emit_mode, .. } => {
+            assert_eq!(emit_mode, Some(EmitMode::Immediately));
+        }
+        _ => unreachable!(),
+    }
+
+    let res = parse_sql_statements("CREATE MATERIALIZED VIEW v AS SELECT * FROM t EMIT LATER");
+    assert_eq!(
+        ParserError::ParserError(
+            "Expected IMMEDIATELY or ON WINDOW CLOSE after EMIT, found: LATER".to_string()
+        ),
+        res.unwrap_err()
+    );
+}
+
 #[test]
 fn parse_drop_table() {
     let sql = "DROP TABLE foo";
@@ -3113,6 +3251,24 @@ fn parse_fetch() {
     }
 }
 
+#[test]
+fn parse_as_of() {
+    let ast = verified_query("SELECT foo FROM bar AS OF 233");
+    assert_eq!(ast.as_of, Some(AsOf::Epoch(233)));
+    let ast = verified_query("SELECT foo FROM bar AS b ORDER BY foo LIMIT 1 AS OF 233");
+    assert_eq!(ast.as_of, Some(AsOf::Epoch(233)));
+    let ast = verified_query("SELECT foo FROM bar AS OF TIMESTAMP '2022-06-01 00:00:00'");
+    assert_eq!(
+        ast.as_of,
+        Some(AsOf::Timestamp("2022-06-01 00:00:00".to_string()))
+    );
+    one_statement_parses_to(
+        "SELECT foo FROM bar AS OF '2022-06-01 00:00:00'",
+        "SELECT foo FROM bar AS OF TIMESTAMP '2022-06-01 00:00:00'",
+    );
+    assert!(parse_sql_statements("SELECT foo FROM bar AS OF foo").is_err());
+}
+
 #[test]
 fn parse_fetch_variations() {
     one_statement_parses_to(
@@ -16,7 +16,7 @@
 
 #[macro_use]
 mod test_utils;
-use risingwave_sqlparser::ast::Expr::{Identifier, MapAccess};
+use risingwave_sqlparser::ast::Expr::{ArrayIndex, ArrayRangeIndex, Identifier};
 use risingwave_sqlparser::ast::*;
 use risingwave_sqlparser::parser::ParserError;
 use test_utils::*;
@@ -362,6 +362,62 @@ fn parse_drop_schema_if_exists() {
     }
 }
 
+#[test]
+fn parse_create_function() {
+    let sql = "CREATE FUNCTION add(a INT, INT) RETURNS INT LANGUAGE wasm AS '/path/to/add.wasm'";
+    match verified_stmt(sql) {
+        Statement::CreateFunction {
+            name,
+            args,
+            return_type,
+            language,
+            definition,
+        } => {
+            assert_eq!("add", name.to_string());
+            assert_eq!(
+                vec![
+                    FunctionArgDef {
+                        name: Some(Ident::new("a")),
+                        data_type: DataType::Int(None),
+                    },
+                    FunctionArgDef {
+                        name: None,
+                        data_type: DataType::Int(None),
+                    },
+                ],
+                args
+            );
+            assert_eq!(DataType::Int(None), return_type);
+            assert_eq!(Ident::new("wasm"), language);
+            assert_eq!("/path/to/add.wasm", definition);
+        }
+        _ => unreachable!(),
+    }
+
+    // `AS` can precede `LANGUAGE`, and argument types can be keywords of multiple words.
+    one_statement_parses_to(
+        "CREATE FUNCTION f(double precision, character varying) RETURNS BOOLEAN AS 'AGFzbQ==' LANGUAGE wasm",
+        "CREATE FUNCTION f(DOUBLE, CHARACTER VARYING) RETURNS BOOLEAN LANGUAGE wasm AS 'AGFzbQ=='",
+    );
+
+    let res = parse_sql_statements("CREATE FUNCTION f() RETURNS INT AS 'f.wasm'");
+    assert_eq!(
+        ParserError::ParserError("Expected LANGUAGE, found: EOF".to_string()),
+        res.unwrap_err()
+    );
+}
+
+#[test]
+fn parse_drop_function() {
+    match verified_stmt("DROP FUNCTION IF EXISTS add") {
+        Statement::Drop(stmt) => {
+            assert_eq!(stmt.object_type, ObjectType::Function);
+            assert!(stmt.if_exists);
+        }
+        _ => unreachable!(),
+    }
+}
+
 #[test]
 fn parse_copy_example() {
     let sql = r#"COPY public.actor (actor_id, first_name, last_name, last_update, value) FROM stdin;
@@ -696,51 +752,150 @@ fn parse_pg_regex_match_ops() {
 }
 
 #[test]
-fn parse_map_access_expr() {
-    let zero = "0".to_string();
+fn parse_pg_json_ops() {
+    let pg_json_ops = &[
+        ("->", BinaryOperator::Arrow),
+        ("->>", BinaryOperator::LongArrow),
+        ("#>", BinaryOperator::HashArrow),
+        ("@>", BinaryOperator::AtArrow),
+    ];
+
+    for (str_op, op) in pg_json_ops {
+        let select = verified_only_select(&format!("SELECT info {} 'a' FROM orders", &str_op));
+        assert_eq!(
+            SelectItem::UnnamedExpr(Expr::BinaryOp {
+                left: Box::new(Expr::Identifier(Ident::new("info"))),
+                op: op.clone(),
+                right: Box::new(Expr::Value(Value::SingleQuotedString("a".into()))),
+            }),
+            select.projection[0]
+        );
+    }
+
+    let select = verified_only_select("SELECT info -> 'a' ->> 'b' = 'c' FROM orders");
+    assert_eq!(
+        SelectItem::UnnamedExpr(Expr::BinaryOp {
+            left: Box::new(Expr::BinaryOp {
+                left: Box::new(Expr::BinaryOp {
+                    left: Box::new(Expr::Identifier(Ident::new("info"))),
+                    op: BinaryOperator::Arrow,
+                    right: Box::new(Expr::Value(Value::SingleQuotedString("a".into()))),
+                }),
+                op: BinaryOperator::LongArrow,
+                right: Box::new(Expr::Value(Value::SingleQuotedString("b".into()))),
+            }),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::Value(Value::SingleQuotedString("c".into()))),
+        }),
+        select.projection[0]
+    );
+}
+
+#[test]
+fn parse_array_index_expr() {
+    let num = |n: &str| Box::new(Expr::Value(Value::Number(n.to_string(), false)));
+    let foo = || Box::new(Identifier(Ident::new("foo")));
+
     let sql = "SELECT foo[0] FROM foos";
     let select = verified_only_select(sql);
     assert_eq!(
-        &MapAccess {
-            column: Box::new(Identifier(Ident {
-                value: "foo".to_string(),
-                quote_style: None
-            })),
-            keys: vec![Expr::Value(Value::Number(zero.clone(), false))]
+        &ArrayIndex {
+            obj: foo(),
+            index: num("0")
         },
         expr_from_projection(only(&select.projection)),
     );
-    let sql = "SELECT foo[0][0] FROM foos";
+    let sql = "SELECT foo[0][1] FROM foos";
     let select = verified_only_select(sql);
     assert_eq!(
-        &MapAccess {
-            column: Box::new(Identifier(Ident {
-                value: "foo".to_string(),
-                quote_style: None
-            })),
-            keys: vec![
-                Expr::Value(Value::Number(zero.clone(), false)),
-                Expr::Value(Value::Number(zero.clone(), false))
-            ]
+        &ArrayIndex {
+            obj: Box::new(ArrayIndex {
+                obj: foo(),
+                index: num("0")
+            }),
+            index: num("1")
         },
         expr_from_projection(only(&select.projection)),
     );
-    let sql = r#"SELECT bar[0]["baz"]["fooz"] FROM foos"#;
+    let sql = "SELECT foo[1 + 1] FROM foos";
     let select = verified_only_select(sql);
     assert_eq!(
-        &MapAccess {
-            column: Box::new(Identifier(Ident {
-                value: "bar".to_string(),
-                quote_style: None
-            })),
-            keys: vec![
-                Expr::Value(Value::Number(zero, false)),
-                Expr::Value(Value::SingleQuotedString("baz".to_string())),
-                Expr::Value(Value::SingleQuotedString("fooz".to_string()))
-            ]
+        &ArrayIndex {
+            obj: foo(),
+            index: Box::new(Expr::BinaryOp {
+                left: num("1"),
+                op: BinaryOperator::Plus,
+                right: num("1"),
+            })
         },
         expr_from_projection(only(&select.projection)),
     );
+    // Subscripts bind tighter than binary operators.
+    let sql = "SELECT 1 + foo[0] FROM foos";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: num("1"),
+            op: BinaryOperator::Plus,
+            right: Box::new(ArrayIndex {
+                obj: foo(),
+                index: num("0")
+            }),
+        },
+        expr_from_projection(only(&select.projection)),
+    );
+    let sql = "SELECT foo[1:2], foo[:2], foo[1:] FROM foos";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &ArrayRangeIndex {
+            obj: foo(),
+            start: Some(num("1")),
+            end: Some(num("2"))
+        },
+        expr_from_projection(&select.projection[0]),
+    );
+    assert_eq!(
+        &ArrayRangeIndex {
+            obj: foo(),
+            start: None,
+            end: Some(num("2"))
+        },
+        expr_from_projection(&select.projection[1]),
+    );
+    assert_eq!(
+        &ArrayRangeIndex {
+            obj: foo(),
+            start: Some(num("1")),
+            end: None
+        },
+        expr_from_projection(&select.projection[2]),
+    );
+}
+
+#[test]
+fn parse_any_all_expr() {
+    let sql = "SELECT 1 = ANY(foo), 1 <> ALL(foo) FROM foos";
+    let select = verified_only_select(sql);
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::AnyOp(Box::new(Identifier(Ident::new("foo"))))),
+        },
+        expr_from_projection(&select.projection[0]),
+    );
+    assert_eq!(
+        &Expr::BinaryOp {
+            left: Box::new(Expr::Value(Value::Number("1".to_string(), false))),
+            op: BinaryOperator::NotEq,
+            right: Box::new(Expr::AllOp(Box::new(Identifier(Ident::new("foo"))))),
+        },
+        expr_from_projection(&select.projection[1]),
+    );
+    one_statement_parses_to(
+        "SELECT 1 = SOME(foo) FROM foos",
+        "SELECT 1 = ANY(foo) FROM foos",
+    );
 }
 
 #[test]
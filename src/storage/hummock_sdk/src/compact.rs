@@ -32,6 +32,12 @@ pub fn compact_task_to_string(compact_task: &CompactTask) -> String {
         "Compaction task status: {:?} \n",
         compact_task.task_status
     ));
+    if !compact_task.table_ttls.is_empty() {
+        s.push_str(&format!(
+            "Compaction table TTLs: {:?} \n",
+            compact_task.table_ttls
+        ));
+    }
     s.push_str("Compaction SSTables structure: \n");
     for level_entry in &compact_task.input_ssts {
         let tables: Vec<u64> = level_entry
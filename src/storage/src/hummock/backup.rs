@@ -0,0 +1,181 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use bytes::Bytes;
+use prost::Message;
+use risingwave_pb::hummock::BackupTask;
+use risingwave_rpc_client::HummockMetaClient;
+
+use super::{HummockError, HummockResult};
+use crate::hummock::SstableStoreRef;
+
+/// Path of the SST meta file in backup `backup_id`.
+pub fn get_backup_sst_meta_path(backup_prefix: &str, backup_id: u64, sst_id: u64) -> String {
+    format!("{}/{}/{}.meta", backup_prefix, backup_id, sst_id)
+}
+
+/// Path of the SST data file in backup `backup_id`.
+pub fn get_backup_sst_data_path(backup_prefix: &str, backup_id: u64, sst_id: u64) -> String {
+    format!("{}/{}/{}.data", backup_prefix, backup_id, sst_id)
+}
+
+/// Path of the encoded `MetaSnapshot` in backup `backup_id`. It's written after all SSTs are
+/// copied, so a backup is complete iff this file exists.
+pub fn get_backup_meta_snapshot_path(backup_prefix: &str, backup_id: u64) -> String {
+    format!("{}/{}/meta_snapshot", backup_prefix, backup_id)
+}
+
+pub struct Backup;
+
+impl Backup {
+    pub async fn backup(
+        sstable_store: SstableStoreRef,
+        backup_task: BackupTask,
+        hummock_meta_client: Arc<dyn HummockMetaClient>,
+    ) -> HummockResult<()> {
+        let result = Self::copy_to_backup(sstable_store, &backup_task).await;
+        hummock_meta_client
+            .report_backup_task(backup_task.backup_id, result.is_ok())
+            .await
+            .map_err(|e| {
+                HummockError::meta_error(format!("failed to report backup task: {e:?}"))
+            })?;
+        result
+    }
+
+    async fn copy_to_backup(
+        sstable_store: SstableStoreRef,
+        backup_task: &BackupTask,
+    ) -> HummockResult<()> {
+        let store = sstable_store.store();
+        let prefix = backup_task.backup_prefix.as_str();
+        let backup_id = backup_task.backup_id;
+        for sst_id in &backup_task.sstable_ids {
+            // Meta
+            let meta = store
+                .read(sstable_store.get_sst_meta_path(*sst_id).as_str(), None)
+                .await
+                .map_err(HummockError::object_io_error)?;
+            store
+                .upload(
+                    get_backup_sst_meta_path(prefix, backup_id, *sst_id).as_str(),
+                    meta,
+                )
+                .await
+                .map_err(HummockError::object_io_error)?;
+            // Data
+            let data = store
+                .read(sstable_store.get_sst_data_path(*sst_id).as_str(), None)
+                .await
+                .map_err(HummockError::object_io_error)?;
+            store
+                .upload(
+                    get_backup_sst_data_path(prefix, backup_id, *sst_id).as_str(),
+                    data,
+                )
+                .await
+                .map_err(HummockError::object_io_error)?;
+        }
+
+        let meta_snapshot = backup_task
+            .meta_snapshot
+            .as_ref()
+            .ok_or_else(|| HummockError::meta_error("meta snapshot is missing in backup task"))?;
+        store
+            .upload(
+                get_backup_meta_snapshot_path(prefix, backup_id).as_str(),
+                Bytes::from(meta_snapshot.encode_to_vec()),
+            )
+            .await
+            .map_err(HummockError::object_io_error)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+    use risingwave_meta::hummock::test_utils::setup_compute_env;
+    use risingwave_meta::hummock::MockHummockMetaClient;
+    use risingwave_pb::hummock::MetaSnapshot;
+
+    use super::*;
+    use crate::hummock::iterator::test_utils::{default_builder_opt_for_test, mock_sstable_store};
+    use crate::hummock::test_utils::gen_default_test_sstable;
+
+    #[tokio::test]
+    async fn test_backup() {
+        let sstable_store = mock_sstable_store();
+        let sst_ids = (1..5).collect_vec();
+        for sstable_id in &sst_ids {
+            gen_default_test_sstable(
+                default_builder_opt_for_test(),
+                *sstable_id,
+                sstable_store.clone(),
+            )
+            .await;
+        }
+        let meta_snapshot = MetaSnapshot {
+            backup_id: 1,
+            hummock_version: None,
+            column_families: vec![],
+        };
+        let backup_task = BackupTask {
+            backup_id: 1,
+            backup_prefix: "backup".to_string(),
+            sstable_ids: sst_ids.clone(),
+            meta_snapshot: Some(meta_snapshot.clone()),
+        };
+        let (_env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+            setup_compute_env(8080).await;
+        let mock_hummock_meta_client = Arc::new(MockHummockMetaClient::new(
+            hummock_manager_ref.clone(),
+            worker_node.id,
+        ));
+        Backup::backup(sstable_store.clone(), backup_task, mock_hummock_meta_client)
+            .await
+            .unwrap();
+
+        let store = sstable_store.store();
+        for sst_id in sst_ids {
+            assert_eq!(
+                store
+                    .read(&get_backup_sst_data_path("backup", 1, sst_id), None)
+                    .await
+                    .unwrap(),
+                store
+                    .read(&sstable_store.get_sst_data_path(sst_id), None)
+                    .await
+                    .unwrap()
+            );
+            assert_eq!(
+                store
+                    .read(&get_backup_sst_meta_path("backup", 1, sst_id), None)
+                    .await
+                    .unwrap(),
+                store
+                    .read(&sstable_store.get_sst_meta_path(sst_id), None)
+                    .await
+                    .unwrap()
+            );
+        }
+        let encoded = store
+            .read(&get_backup_meta_snapshot_path("backup", 1), None)
+            .await
+            .unwrap();
+        assert_eq!(MetaSnapshot::decode(encoded).unwrap(), meta_snapshot);
+    }
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -21,11 +22,11 @@ use futures::Future;
 use itertools::Itertools;
 use risingwave_common::config::StorageConfig;
 use risingwave_hummock_sdk::compact::compact_task_to_string;
-use risingwave_hummock_sdk::key::{get_epoch, Epoch, FullKey};
+use risingwave_hummock_sdk::key::{get_epoch, user_key, Epoch, FullKey};
 use risingwave_hummock_sdk::key_range::KeyRange;
 use risingwave_hummock_sdk::VersionedComparator;
 use risingwave_pb::hummock::{
-    CompactTask, LevelType, SstableInfo, SubscribeCompactTasksResponse, VacuumTask,
+    BackupTask, CompactTask, LevelType, SstableInfo, SubscribeCompactTasksResponse, VacuumTask,
 };
 use risingwave_rpc_client::HummockMetaClient;
 use tokio::sync::mpsc::UnboundedSender;
@@ -38,9 +39,13 @@ use super::multi_builder::CapacitySplitTableBuilder;
 use super::shared_buffer::shared_buffer_batch::SharedBufferBatch;
 use super::sstable_store::SstableStoreRef;
 use super::{
-    HummockError, HummockResult, HummockStorage, SSTableBuilder, SSTableIterator, Sstable,
+    BloomKeyExtractor, BloomKeyExtractorRef, HummockError, HummockResult, HummockStorage,
+    RangeTombstone, SSTableBuilder, SSTableIterator, Sstable,
 };
+use crate::hummock::backup::Backup;
 use crate::hummock::vacuum::Vacuum;
+use crate::hummock::value::HummockValue;
+use crate::keyspace::get_table_id;
 use crate::monitor::StateStoreMetrics;
 
 /// A `CompactorContext` describes the context of a compactor.
@@ -60,6 +65,10 @@ pub struct CompactorContext {
 
     /// True if it is a memory compaction (from shared buffer).
     pub is_share_buffer_compact: bool,
+
+    /// Bloom key lengths registered by local tables, used to build prefix bloom filters. Remote
+    /// compactors inherit the ones stored in the input SSTs as well.
+    pub bloom_key_extractor: BloomKeyExtractorRef,
 }
 
 #[derive(Clone)]
@@ -124,6 +133,7 @@ impl Compactor {
             is_target_ultimate_and_leveling: false,
             metrics: None,
             task_status: false,
+            table_ttls: Default::default(),
         };
 
         let parallelism = compact_task.splits.len();
@@ -131,9 +141,14 @@ impl Compactor {
         let mut output_ssts = Vec::with_capacity(parallelism);
         let mut compaction_futures = vec![];
         let compactor = Compactor::new(context, compact_task.clone());
+        let range_tombstones = buffers
+            .iter()
+            .flat_map(|m| m.range_tombstones().iter().cloned())
+            .collect_vec();
 
         for (split_index, _) in compact_task.splits.iter().enumerate() {
             let compactor = compactor.clone();
+            let range_tombstones = range_tombstones.clone();
             let iter = {
                 let iters = buffers.iter().map(|m| {
                     Box::new(m.clone().into_forward_iter()) as BoxedForwardHummockIterator
@@ -141,7 +156,9 @@ impl Compactor {
                 MergeIterator::new(iters, stats.clone())
             };
             compaction_futures.push(tokio::spawn(async move {
-                compactor.compact_key_range(split_index, iter).await
+                compactor
+                    .compact_key_range(split_index, iter, range_tombstones)
+                    .await
             }));
         }
 
@@ -195,7 +212,10 @@ impl Compactor {
             let compactor = compactor.clone();
             compaction_futures.push(tokio::spawn(async move {
                 let merge_iter = compactor.build_sst_iter().await?;
-                compactor.compact_key_range(split_index, merge_iter).await
+                let range_tombstones = compactor.collect_range_tombstones().await?;
+                compactor
+                    .compact_key_range(split_index, merge_iter, range_tombstones)
+                    .await
             }));
         }
 
@@ -272,10 +292,15 @@ impl Compactor {
     }
 
     /// Compact the given key range and merge iterator.
+    ///
+    /// Keys deleted by `range_tombstones` are dropped once the deletion is visible to all readers.
+    /// The range tombstones themselves are carried to the output ssts, unless there's no data
+    /// under the target level.
     async fn compact_key_range(
         &self,
         split_index: usize,
         iter: MergeIterator,
+        range_tombstones: Vec<RangeTombstone>,
     ) -> HummockResult<(usize, Vec<Sstable>)> {
         let split = self.compact_task.splits[split_index].clone();
         let kr = KeyRange {
@@ -284,6 +309,24 @@ impl Compactor {
             inf: split.get_inf(),
         };
 
+        // Only keep the part of range tombstones that falls into the split.
+        let split_left = if kr.left.is_empty() {
+            &[][..]
+        } else {
+            user_key(&kr.left)
+        };
+        let split_right = if kr.right.is_empty() {
+            &[][..]
+        } else {
+            user_key(&kr.right)
+        };
+        let range_tombstones = range_tombstones
+            .iter()
+            .filter_map(|range_tombstone| range_tombstone.clip(split_left, split_right))
+            .collect_vec();
+
+        let bloom_key_extractor = self.collect_bloom_key_extractor().await?;
+
         // NOTICE: should be user_key overlap, NOT full_key overlap!
         let mut builder = CapacitySplitTableBuilder::new(|| async {
             let table_id = self
@@ -292,7 +335,8 @@ impl Compactor {
                 .get_new_table_id()
                 .await
                 .map_err(HummockError::meta_error)?;
-            let builder = HummockStorage::get_builder(&self.context.options);
+            let builder =
+                HummockStorage::get_builder(&self.context.options, bloom_key_extractor.clone());
             Ok((table_id, builder))
         });
 
@@ -302,14 +346,30 @@ impl Compactor {
         } else {
             None
         };
-        Compactor::compact_and_build_sst(
+        let ttl_reclaimed_bytes = Compactor::compact_and_build_sst(
             &mut builder,
             kr,
             iter,
             !self.compact_task.is_target_ultimate_and_leveling,
             self.compact_task.watermark,
+            &range_tombstones,
+            &self.ttl_watermarks(),
         )
         .await?;
+        self.context
+            .stats
+            .compaction_ttl_reclaimed_bytes
+            .inc_by(ttl_reclaimed_bytes);
+
+        // A range tombstone can be garbage-collected if it's visible to all readers and there's
+        // no data under the target level.
+        let watermark = self.compact_task.watermark;
+        let gc_range_tombstones = self.compact_task.is_target_ultimate_and_leveling;
+        builder
+            .add_range_tombstones(range_tombstones.into_iter().filter(|range_tombstone| {
+                !(gc_range_tombstones && range_tombstone.epoch <= watermark)
+            }))
+            .await?;
         if let Some(timer) = build_l0_sst_timer {
             timer.observe_duration();
         }
@@ -343,6 +403,18 @@ impl Compactor {
         Ok((split_index, ssts))
     }
 
+    /// Returns the epoch before which keys are expired, of each table with TTL.
+    fn ttl_watermarks(&self) -> HashMap<u32, Epoch> {
+        let now = risingwave_common::util::epoch::Epoch::physical_now();
+        self.compact_task
+            .table_ttls
+            .iter()
+            .map(|(table_id, ttl_seconds)| {
+                (*table_id, now.saturating_sub(*ttl_seconds as u64 * 1000))
+            })
+            .collect()
+    }
+
     /// Build the merge iterator based on the given input ssts.
     async fn build_sst_iter(&self) -> HummockResult<MergeIterator> {
         let mut table_iters: Vec<BoxedForwardHummockIterator> = Vec::new();
@@ -384,6 +456,31 @@ impl Compactor {
         Ok(MergeIterator::new(table_iters, self.context.stats.clone()))
     }
 
+    /// Collects the range tombstones of the input ssts.
+    async fn collect_range_tombstones(&self) -> HummockResult<Vec<RangeTombstone>> {
+        let mut range_tombstones = vec![];
+        for level in &self.compact_task.input_ssts {
+            for table_info in &level.table_infos {
+                let table = self.context.sstable_store.sstable(table_info.id).await?;
+                range_tombstones.extend(table.value().meta.range_tombstones.iter().cloned());
+            }
+        }
+        Ok(range_tombstones)
+    }
+
+    /// Merges the bloom key extractors of the input ssts into the local one, so that prefix bloom
+    /// filters are kept after compaction.
+    async fn collect_bloom_key_extractor(&self) -> HummockResult<BloomKeyExtractor> {
+        let mut bloom_key_extractor = self.context.bloom_key_extractor.read().clone();
+        for level in &self.compact_task.input_ssts {
+            for table_info in &level.table_infos {
+                let table = self.context.sstable_store.sstable(table_info.id).await?;
+                bloom_key_extractor.merge(&table.value().meta.bloom_key_extractor);
+            }
+        }
+        Ok(bloom_key_extractor)
+    }
+
     pub async fn try_vacuum(
         vacuum_task: Option<VacuumTask>,
         sstable_store: SstableStoreRef,
@@ -408,6 +505,29 @@ impl Compactor {
         }
     }
 
+    pub async fn try_backup(
+        backup_task: Option<BackupTask>,
+        sstable_store: SstableStoreRef,
+        hummock_meta_client: Arc<dyn HummockMetaClient>,
+    ) {
+        if let Some(backup_task) = backup_task {
+            let backup_id = backup_task.backup_id;
+            tracing::info!(
+                "Try to backup {} SSTs as backup {}",
+                backup_task.sstable_ids.len(),
+                backup_id
+            );
+            match Backup::backup(sstable_store, backup_task, hummock_meta_client).await {
+                Ok(_) => {
+                    tracing::info!("Finish backup {}", backup_id);
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to backup {}. {}", backup_id, e);
+                }
+            }
+        }
+    }
+
     /// The background compaction thread that receives compaction tasks from hummock compaction
     /// manager and runs compaction tasks.
     pub fn start_compactor(
@@ -422,6 +542,7 @@ impl Compactor {
             sstable_store: sstable_store.clone(),
             stats,
             is_share_buffer_compact: false,
+            bloom_key_extractor: Default::default(),
         });
         let (shutdown_tx, mut shutdown_rx) = tokio::sync::mpsc::unbounded_channel();
         let stream_retry_interval = Duration::from_secs(60);
@@ -474,6 +595,7 @@ impl Compactor {
                         Ok(Some(SubscribeCompactTasksResponse {
                             compact_task,
                             vacuum_task,
+                            backup_task,
                         })) => {
                             if let Some(compact_task) = compact_task {
                                 Compactor::compact(compactor_context.clone(), compact_task).await;
@@ -485,6 +607,13 @@ impl Compactor {
                                 hummock_meta_client.clone(),
                             )
                             .await;
+
+                            Compactor::try_backup(
+                                backup_task,
+                                sstable_store.clone(),
+                                hummock_meta_client.clone(),
+                            )
+                            .await;
                         }
                         Err(e) => {
                             tracing::warn!("Failed to consume stream. {}", e.message());
@@ -508,7 +637,9 @@ impl Compactor {
         mut iter: MergeIterator,
         has_user_key_overlap: bool,
         watermark: Epoch,
-    ) -> HummockResult<()>
+        range_tombstones: &[RangeTombstone],
+        ttl_watermarks: &HashMap<u32, Epoch>,
+    ) -> HummockResult<u64>
     where
         B: FnMut() -> F,
         F: Future<Output = HummockResult<(u64, SSTableBuilder)>>,
@@ -521,16 +652,24 @@ impl Compactor {
 
         let mut skip_key = BytesMut::new();
         let mut last_key = BytesMut::new();
+        // Whether the versions of `skip_key` are skipped because they are expired.
+        let mut skip_expired = false;
+        // Bytes of keys and values dropped because they are expired.
+        let mut ttl_reclaimed_bytes = 0;
 
         while iter.is_valid() {
             let iter_key = iter.key();
 
             if !skip_key.is_empty() {
                 if VersionedComparator::same_user_key(iter_key, &skip_key) {
+                    if skip_expired {
+                        ttl_reclaimed_bytes += (iter_key.len() + iter.value().encoded_len()) as u64;
+                    }
                     iter.next().await?;
                     continue;
                 } else {
                     skip_key.clear();
+                    skip_expired = false;
                 }
             }
 
@@ -551,6 +690,47 @@ impl Compactor {
 
             let epoch = get_epoch(iter_key);
 
+            // Drop the key if it's deleted by a range tombstone visible to all readers. Older
+            // versions of the same user key are deleted as well.
+            if range_tombstones
+                .iter()
+                .any(|range_tombstone| range_tombstone.covers(user_key(iter_key), epoch, watermark))
+            {
+                skip_key = BytesMut::from(iter_key);
+                iter.next().await?;
+                continue;
+            }
+
+            // Drop the key if it's expired by the TTL of its table. Older versions of the same user
+            // key are expired as well. If there may be older versions in lower levels, the latest
+            // version is kept as a tombstone, so that the older ones are not exposed.
+            if let Some(ttl_watermark) =
+                get_table_id(user_key(iter_key)).and_then(|table_id| ttl_watermarks.get(&table_id))
+                && epoch < *ttl_watermark
+            {
+                skip_key = BytesMut::from(iter_key);
+                skip_expired = true;
+                let value = iter.value();
+                if is_new_user_key && has_user_key_overlap {
+                    if !value.is_delete() {
+                        ttl_reclaimed_bytes += (value.encoded_len()
+                            - HummockValue::<&[u8]>::delete().encoded_len())
+                            as u64;
+                    }
+                    sst_builder
+                        .add_full_key(
+                            FullKey::from_slice(iter_key),
+                            HummockValue::delete(),
+                            is_new_user_key,
+                        )
+                        .await?;
+                } else {
+                    ttl_reclaimed_bytes += (iter_key.len() + value.encoded_len()) as u64;
+                }
+                iter.next().await?;
+                continue;
+            }
+
             // Among keys with same user key, only retain keys which satisfy `epoch` >= `watermark`,
             // and the latest key which satisfies `epoch` < `watermark`
             if epoch < watermark {
@@ -568,6 +748,6 @@ impl Compactor {
 
             iter.next().await?;
         }
-        Ok(())
+        Ok(ttl_reclaimed_bytes)
     }
 }
@@ -18,6 +18,7 @@ mod tests {
     use std::sync::Arc;
 
     use bytes::Bytes;
+    use risingwave_common::catalog::TableId;
     use risingwave_common::config::StorageConfig;
     use risingwave_meta::hummock::test_utils::setup_compute_env;
     use risingwave_meta::hummock::MockHummockMetaClient;
@@ -25,6 +26,7 @@ mod tests {
 
     use crate::hummock::compactor::{Compactor, CompactorContext};
     use crate::hummock::{HummockStorage, SstableStore};
+    use crate::keyspace::Keyspace;
     use crate::monitor::StateStoreMetrics;
     use crate::object::{InMemObjectStore, ObjectStoreImpl};
     use crate::storage_value::StorageValue;
@@ -86,6 +88,7 @@ mod tests {
             hummock_meta_client: hummock_meta_client.clone(),
             stats: Arc::new(StateStoreMetrics::unused()),
             is_share_buffer_compact: false,
+            bloom_key_extractor: storage.bloom_key_extractor().clone(),
         };
 
         // 1. add sstables
@@ -160,4 +163,72 @@ mod tests {
 
         assert!(compact_task.is_none());
     }
+
+    #[tokio::test]
+    async fn test_compaction_ttl() {
+        let (_env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+            setup_compute_env(8080).await;
+        let hummock_meta_client = Arc::new(MockHummockMetaClient::new(
+            hummock_manager_ref.clone(),
+            worker_node.id,
+        ));
+        let storage = get_hummock_storage(hummock_meta_client.clone()).await;
+        let stats = Arc::new(StateStoreMetrics::unused());
+        let compact_ctx = CompactorContext {
+            options: storage.options().clone(),
+            sstable_store: storage.sstable_store(),
+            hummock_meta_client: hummock_meta_client.clone(),
+            stats: stats.clone(),
+            is_share_buffer_compact: false,
+            bloom_key_extractor: storage.bloom_key_extractor().clone(),
+        };
+
+        // 1. add sstables of two tables, with epochs far earlier than now
+        let expired_key =
+            Keyspace::table_root(storage.clone(), &TableId::new(1)).prefixed_key(b"key");
+        let live_key = Keyspace::table_root(storage.clone(), &TableId::new(2)).prefixed_key(b"key");
+        let val = Bytes::from(&b"value"[..]);
+        let mut epoch: u64 = 1;
+        for _ in 0..2 {
+            epoch += 1;
+            storage
+                .ingest_batch(
+                    vec![
+                        (
+                            Bytes::from(expired_key.clone()),
+                            StorageValue::new_default_put(val.clone()),
+                        ),
+                        (
+                            Bytes::from(live_key.clone()),
+                            StorageValue::new_default_put(val.clone()),
+                        ),
+                    ],
+                    epoch,
+                )
+                .await
+                .unwrap();
+            storage.sync(Some(epoch)).await.unwrap();
+            hummock_meta_client.commit_epoch(epoch).await.unwrap();
+        }
+
+        // 2. get compact task, and set TTL of table 1
+        let mut compact_task = hummock_manager_ref
+            .get_compact_task(worker_node.id)
+            .await
+            .unwrap()
+            .unwrap();
+        compact_task.table_ttls.insert(1, 1);
+
+        // 3. compact
+        Compactor::compact(Arc::new(compact_ctx), compact_task).await;
+        assert!(stats.compaction_ttl_reclaimed_bytes.get() > 0);
+
+        // 4. only keys of table 1 are dropped
+        let version = hummock_manager_ref.get_current_version().await;
+        storage
+            .local_version_manager()
+            .try_update_pinned_version(version);
+        assert!(storage.get(&expired_key, epoch).await.unwrap().is_none());
+        assert_eq!(storage.get(&live_key, epoch).await.unwrap().unwrap(), val);
+    }
 }
@@ -124,4 +124,10 @@ impl HummockMetaClient for MonitoredHummockMetaClient {
     async fn report_vacuum_task(&self, vacuum_task: VacuumTask) -> Result<()> {
         self.meta_client.report_vacuum_task(vacuum_task).await
     }
+
+    async fn report_backup_task(&self, backup_id: u64, succeeded: bool) -> Result<()> {
+        self.meta_client
+            .report_backup_task(backup_id, succeeded)
+            .await
+    }
 }
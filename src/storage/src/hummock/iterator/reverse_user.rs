@@ -20,7 +20,7 @@ use risingwave_hummock_sdk::key::{get_epoch, key_with_epoch, user_key as to_user
 use crate::hummock::iterator::{HummockIterator, ReverseMergeIterator};
 use crate::hummock::local_version::PinnedVersion;
 use crate::hummock::value::HummockValue;
-use crate::hummock::HummockResult;
+use crate::hummock::{HummockResult, RangeTombstone};
 
 /// [`ReverseUserIterator`] can be used by user directly.
 pub struct ReverseUserIterator {
@@ -48,6 +48,9 @@ pub struct ReverseUserIterator {
     /// Only reads values if `epoch <= self.read_epoch`.
     read_epoch: Epoch,
 
+    /// Range tombstones of the underlying tables.
+    range_tombstones: Vec<RangeTombstone>,
+
     /// Ensures the SSTs needed by `iterator` won't be vacuumed.
     _version: Option<Arc<PinnedVersion>>,
 }
@@ -78,10 +81,18 @@ impl ReverseUserIterator {
             last_val: Vec::new(),
             last_delete: true,
             read_epoch,
+            range_tombstones: vec![],
             _version: version,
         }
     }
 
+    /// Hides the keys deleted by `range_tombstones`, which should contain the range tombstones of
+    /// all the tables in `iterator`.
+    pub(crate) fn with_range_tombstones(mut self, range_tombstones: Vec<RangeTombstone>) -> Self {
+        self.range_tombstones = range_tombstones;
+        self
+    }
+
     fn out_of_range(&self, key: &[u8]) -> bool {
         match &self.key_range.0 {
             Included(begin_key) => key < begin_key.as_slice(),
@@ -166,14 +177,19 @@ impl ReverseUserIterator {
                 // whatever name. We may directly seek to the next key if we have
                 // been seeing the same key for too many times.
 
+                let deleted_by_range = self
+                    .range_tombstones
+                    .iter()
+                    .any(|range_tombstone| range_tombstone.covers(key, epoch, self.read_epoch));
+
                 // 1 and 2(a)
                 match self.iterator.value() {
-                    HummockValue::Put(_, val) => {
+                    HummockValue::Put(_, val) if !deleted_by_range => {
                         self.last_val.clear();
                         self.last_val.extend_from_slice(val);
                         self.last_delete = false;
                     }
-                    HummockValue::Delete(_) => {
+                    HummockValue::Put(..) | HummockValue::Delete(_) => {
                         self.last_delete = true;
                     }
                 }
@@ -21,7 +21,7 @@ use super::{ForwardHummockIterator, MergeIterator};
 use crate::hummock::iterator::ReverseUserIterator;
 use crate::hummock::local_version::PinnedVersion;
 use crate::hummock::value::HummockValue;
-use crate::hummock::HummockResult;
+use crate::hummock::{HummockResult, RangeTombstone};
 
 pub enum DirectedUserIterator {
     Forward(UserIterator),
@@ -96,6 +96,9 @@ pub struct UserIterator {
     /// Only reads values if `ts <= self.read_epoch`.
     read_epoch: Epoch,
 
+    /// Range tombstones of the underlying tables.
+    range_tombstones: Vec<RangeTombstone>,
+
     /// Ensures the SSTs needed by `iterator` won't be vacuumed.
     _version: Option<Arc<PinnedVersion>>,
 }
@@ -125,10 +128,18 @@ impl UserIterator {
             last_key: Vec::new(),
             last_val: Vec::new(),
             read_epoch,
+            range_tombstones: vec![],
             _version: version,
         }
     }
 
+    /// Hides the keys deleted by `range_tombstones`, which should contain the range tombstones of
+    /// all the tables in `iterator`.
+    pub(crate) fn with_range_tombstones(mut self, range_tombstones: Vec<RangeTombstone>) -> Self {
+        self.range_tombstones = range_tombstones;
+        self
+    }
+
     /// Gets the iterator move to the next step.
     ///
     /// Returned result:
@@ -146,9 +157,14 @@ impl UserIterator {
                 self.last_key.clear();
                 self.last_key.extend_from_slice(key);
 
+                let deleted_by_range = self
+                    .range_tombstones
+                    .iter()
+                    .any(|range_tombstone| range_tombstone.covers(key, epoch, self.read_epoch));
+
                 // handle delete operation
                 match self.iterator.value() {
-                    HummockValue::Put(_, val) => {
+                    HummockValue::Put(_, val) if !deleted_by_range => {
                         self.last_val.clear();
                         self.last_val.extend_from_slice(val);
 
@@ -160,10 +176,11 @@ impl UserIterator {
                         };
                         return Ok(());
                     }
-                    // It means that the key is deleted from the storage.
+                    // It means that the key is deleted from the storage, either by a point
+                    // deletion or a range tombstone.
                     // Deleted kv and the previous versions (if any) of the key should not be
                     // returned to user.
-                    HummockValue::Delete(_) => {}
+                    HummockValue::Put(..) | HummockValue::Delete(_) => {}
                 }
             }
 
@@ -28,7 +28,7 @@ use tokio_retry::strategy::jitter;
 use super::local_version::{LocalVerion, PinnedVersion, ReadVersion};
 use super::shared_buffer::shared_buffer_batch::SharedBufferBatch;
 use super::shared_buffer::shared_buffer_uploader::{SharedBufferUploader, UploadItem};
-use super::SstableStoreRef;
+use super::{BloomKeyExtractorRef, SstableStoreRef};
 use crate::hummock::utils::validate_table_key_range;
 use crate::hummock::{
     HummockEpoch, HummockError, HummockResult, HummockVersionId, INVALID_VERSION_ID,
@@ -111,6 +111,7 @@ impl LocalVersionManager {
         local_version_manager: Arc<LocalVersionManager>,
         stats: Arc<StateStoreMetrics>,
         hummock_meta_client: Arc<dyn HummockMetaClient>,
+        bloom_key_extractor: BloomKeyExtractorRef,
     ) {
         let local_version_manager_for_uploader = local_version_manager.clone();
         if let Some((version_unpin_worker_rx, shared_buffer_uploader_rx)) =
@@ -135,6 +136,7 @@ impl LocalVersionManager {
                 hummock_meta_client,
                 shared_buffer_uploader_rx,
                 stats,
+                bloom_key_extractor,
             );
             tokio::spawn(async move { uploader.run().await });
         }
@@ -22,6 +22,7 @@ use risingwave_common::config::StorageConfig;
 use risingwave_hummock_sdk::*;
 use risingwave_rpc_client::HummockMetaClient;
 
+pub mod backup;
 mod block_cache;
 pub use block_cache::*;
 mod sstable;
@@ -54,7 +55,7 @@ pub use error::*;
 use value::*;
 
 use self::iterator::HummockIterator;
-use self::key::user_key;
+use self::key::{get_epoch, user_key};
 pub use self::sstable_store::*;
 pub use self::state_store::HummockStateStoreIter;
 use super::monitor::StateStoreMetrics;
@@ -73,6 +74,9 @@ pub struct HummockStorage {
 
     /// Statistics
     stats: Arc<StateStoreMetrics>,
+
+    /// Bloom key lengths registered by tables, see [`crate::StateStore::register_bloom_key`].
+    bloom_key_extractor: BloomKeyExtractorRef,
 }
 
 impl HummockStorage {
@@ -95,6 +99,7 @@ impl HummockStorage {
         stats: Arc<StateStoreMetrics>,
     ) -> HummockResult<Self> {
         let local_version_manager = Arc::new(LocalVersionManager::new(options.clone()));
+        let bloom_key_extractor = BloomKeyExtractorRef::default();
 
         LocalVersionManager::start_workers(
             options.clone(),
@@ -102,6 +107,7 @@ impl HummockStorage {
             local_version_manager.clone(),
             stats.clone(),
             hummock_meta_client.clone(),
+            bloom_key_extractor.clone(),
         );
 
         // Ensure at least one available version in cache.
@@ -113,11 +119,15 @@ impl HummockStorage {
             hummock_meta_client,
             sstable_store,
             stats,
+            bloom_key_extractor,
         };
         Ok(instance)
     }
 
-    fn get_builder(options: &StorageConfig) -> SSTableBuilder {
+    fn get_builder(
+        options: &StorageConfig,
+        bloom_key_extractor: BloomKeyExtractor,
+    ) -> SSTableBuilder {
         SSTableBuilder::new(SSTableBuilderOptions {
             capacity: options.sstable_size as usize,
             block_capacity: options.block_size as usize,
@@ -126,14 +136,16 @@ impl HummockStorage {
             // TODO: Make this configurable.
             compression_algorithm: CompressionAlgorithm::None,
         })
+        .with_bloom_key_extractor(bloom_key_extractor)
     }
 
+    /// Gets the value of `key` from `table`, along with the epoch it is written in.
     async fn get_from_table(
         &self,
         table: TableHolder,
         internal_key: &[u8],
         key: &[u8],
-    ) -> HummockResult<Option<Bytes>> {
+    ) -> HummockResult<Option<(HummockEpoch, Bytes)>> {
         if table.value().surely_not_have_user_key(key) {
             self.stats.bloom_filter_true_negative_counts.inc();
             return Ok(None);
@@ -150,7 +162,10 @@ impl HummockStorage {
         // Iterator gets us the key, we tell if it's the key we want
         // or key next to it.
         let value = match user_key(iter.key()) == key {
-            true => iter.value().into_user_value().map(Bytes::copy_from_slice),
+            true => iter
+                .value()
+                .into_user_value()
+                .map(|value| (get_epoch(iter.key()), Bytes::copy_from_slice(value))),
             false => None,
         };
         Ok(value)
@@ -171,6 +186,10 @@ impl HummockStorage {
     pub fn local_version_manager(&self) -> &Arc<LocalVersionManager> {
         &self.local_version_manager
     }
+
+    pub fn bloom_key_extractor(&self) -> &BloomKeyExtractorRef {
+        &self.bloom_key_extractor
+    }
 }
 
 impl fmt::Debug for HummockStorage {
@@ -27,14 +27,20 @@ use crate::hummock::utils::range_overlap;
 pub struct SharedBuffer {
     /// `{ end key -> batch }`
     inner: BTreeMap<Vec<u8>, SharedBufferBatch>,
+    /// Batches with range tombstones only. They are not indexed by end key, because the exclusive
+    /// end key of a range tombstone may collide with the end key of another batch.
+    range_tombstone_batches: Vec<SharedBufferBatch>,
     size: u64,
 }
 
 impl SharedBuffer {
     pub fn write_batch(&mut self, batch: SharedBufferBatch) {
-        self.inner
-            .insert(batch.end_user_key().to_vec(), batch.clone());
         self.size += batch.size;
+        if batch.has_range_tombstones_only() {
+            self.range_tombstone_batches.push(batch);
+        } else {
+            self.inner.insert(batch.end_user_key().to_vec(), batch);
+        }
     }
 
     // Gets batches from shared buffer that overlap with the given key range.
@@ -56,20 +62,29 @@ impl SharedBuffer {
                 },
                 std::ops::Bound::Unbounded,
             ))
+            .map(|entry| entry.1)
+            .chain(self.range_tombstone_batches.iter())
             .filter(|m| {
                 range_overlap(
                     key_range,
-                    m.1.start_user_key(),
-                    m.1.end_user_key(),
+                    m.start_user_key(),
+                    m.end_user_key(),
                     reversed_range,
                 )
             })
-            .map(|entry| entry.1.clone())
+            .cloned()
             .collect()
     }
 
     pub fn delete_batch(&mut self, batch: SharedBufferBatch) -> Option<SharedBufferBatch> {
-        let deleted_batch = self.inner.remove(batch.end_user_key());
+        let deleted_batch = if batch.has_range_tombstones_only() {
+            self.range_tombstone_batches
+                .iter()
+                .position(|b| *b == batch)
+                .map(|idx| self.range_tombstone_batches.remove(idx))
+        } else {
+            self.inner.remove(batch.end_user_key())
+        };
         if let Some(batch) = &deleted_batch {
             self.size -= batch.size();
         }
@@ -22,7 +22,7 @@ use crate::hummock::iterator::{
     Backward, DirectionEnum, Forward, HummockIterator, HummockIteratorDirection,
 };
 use crate::hummock::value::HummockValue;
-use crate::hummock::{key, HummockEpoch, HummockResult};
+use crate::hummock::{key, HummockEpoch, HummockResult, RangeTombstone};
 use crate::storage_value::VALUE_META_SIZE;
 
 pub(crate) type SharedBufferItem = (Bytes, HummockValue<Bytes>);
@@ -32,12 +32,27 @@ pub(crate) type SharedBufferItem = (Bytes, HummockValue<Bytes>);
 #[derive(Clone, Debug, PartialEq)]
 pub struct SharedBufferBatch {
     pub(super) inner: Arc<[SharedBufferItem]>,
+    pub(super) range_tombstones: Arc<[RangeTombstone]>,
     pub(super) epoch: HummockEpoch,
     pub(super) size: u64,
 }
 
 impl SharedBufferBatch {
     pub fn new(sorted_items: Vec<SharedBufferItem>, epoch: HummockEpoch) -> Self {
+        Self::new_with_range_tombstones(sorted_items, vec![], epoch)
+    }
+
+    /// Creates a batch with both key-value pairs and range tombstones. Either of them can be empty,
+    /// but not both.
+    pub fn new_with_range_tombstones(
+        sorted_items: Vec<SharedBufferItem>,
+        range_tombstones: Vec<RangeTombstone>,
+        epoch: HummockEpoch,
+    ) -> Self {
+        assert!(!sorted_items.is_empty() || !range_tombstones.is_empty());
+        assert!(range_tombstones
+            .iter()
+            .all(|range_tombstone| range_tombstone.epoch == epoch));
         // size = Sum(length of full key + length of user value)
         let size: u64 = sorted_items
             .iter()
@@ -50,10 +65,15 @@ impl SharedBufferBatch {
                 };
                 (k.len() + vsize) as u64
             })
-            .sum();
+            .sum::<u64>()
+            + range_tombstones
+                .iter()
+                .map(|range_tombstone| range_tombstone.encoded_size() as u64)
+                .sum::<u64>();
 
         Self {
             inner: sorted_items.into(),
+            range_tombstones: range_tombstones.into(),
             epoch,
             size,
         }
@@ -89,12 +109,44 @@ impl SharedBufferBatch {
         &self.inner.last().unwrap().0
     }
 
+    /// Returns the smallest user key of the key-value pairs and range tombstones.
     pub fn start_user_key(&self) -> &[u8] {
-        key::user_key(&self.inner.first().unwrap().0)
+        self.inner
+            .first()
+            .map(|(k, _)| key::user_key(k))
+            .into_iter()
+            .chain(
+                self.range_tombstones
+                    .iter()
+                    .map(|range_tombstone| range_tombstone.start_user_key.as_slice()),
+            )
+            .min()
+            .unwrap()
     }
 
+    /// Returns the largest user key of the key-value pairs and range tombstones. Note that the
+    /// exclusive end key of a range tombstone is taken as if it were inclusive.
     pub fn end_user_key(&self) -> &[u8] {
-        key::user_key(&self.inner.last().unwrap().0)
+        self.inner
+            .last()
+            .map(|(k, _)| key::user_key(k))
+            .into_iter()
+            .chain(
+                self.range_tombstones
+                    .iter()
+                    .map(|range_tombstone| range_tombstone.end_user_key.as_slice()),
+            )
+            .max()
+            .unwrap()
+    }
+
+    /// Returns true if the batch contains no key-value pairs but only range tombstones.
+    pub fn has_range_tombstones_only(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    pub fn range_tombstones(&self) -> &[RangeTombstone] {
+        &self.range_tombstones
     }
 
     pub fn epoch(&self) -> u64 {
@@ -27,7 +27,7 @@ use super::shared_buffer_batch::SharedBufferBatch;
 use crate::hummock::compactor::{Compactor, CompactorContext};
 use crate::hummock::conflict_detector::ConflictDetector;
 use crate::hummock::local_version_manager::LocalVersionManager;
-use crate::hummock::{HummockError, HummockResult, SstableStoreRef};
+use crate::hummock::{BloomKeyExtractorRef, HummockError, HummockResult, SstableStoreRef};
 use crate::monitor::StateStoreMetrics;
 
 #[derive(Debug)]
@@ -59,6 +59,7 @@ pub struct SharedBufferUploader {
     local_version_manager: Arc<LocalVersionManager>,
     hummock_meta_client: Arc<dyn HummockMetaClient>,
     stats: Arc<StateStoreMetrics>,
+    bloom_key_extractor: BloomKeyExtractorRef,
 }
 
 impl SharedBufferUploader {
@@ -71,6 +72,7 @@ impl SharedBufferUploader {
         hummock_meta_client: Arc<dyn HummockMetaClient>,
         uploader_rx: mpsc::UnboundedReceiver<UploadItem>,
         stats: Arc<StateStoreMetrics>,
+        bloom_key_extractor: BloomKeyExtractorRef,
     ) -> Self {
         Self {
             threshold,
@@ -86,6 +88,7 @@ impl SharedBufferUploader {
             local_version_manager,
             hummock_meta_client,
             stats,
+            bloom_key_extractor,
             options,
         }
     }
@@ -163,6 +166,7 @@ impl SharedBufferUploader {
             sstable_store: self.sstable_store.clone(),
             stats: self.stats.clone(),
             is_share_buffer_compact: true,
+            bloom_key_extractor: self.bloom_key_extractor.clone(),
         };
 
         let tables = Compactor::compact_shared_buffer(
@@ -0,0 +1,218 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::ops::{Bound, RangeBounds};
+use std::sync::Arc;
+
+use bytes::{Buf, BufMut};
+
+use super::utils::{get_length_prefixed_slice, put_length_prefixed_slice};
+use crate::keyspace::keyspace_root;
+
+pub type BloomKeyExtractorRef = Arc<parking_lot::RwLock<BloomKeyExtractor>>;
+
+/// [`BloomKeyExtractor`] extracts the bloom key of a user key, which is the fixed-length prefix of
+/// the user key used to build the prefix bloom filter of an SST.
+///
+/// The length of bloom keys is registered per keyspace, e.g. the keyspace prefix of a state table
+/// plus the encoded distribution key. A user key gets no bloom key if its keyspace is not
+/// registered or it's shorter than the registered length.
+///
+/// The extractor used to build an SST is stored in its meta, so that readers and compactors
+/// extract bloom keys the same way as the writer without knowing the tables.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BloomKeyExtractor {
+    /// Keyspace root prefix -> length of bloom keys in the keyspace, including the root prefix.
+    bloom_key_lens: BTreeMap<Vec<u8>, usize>,
+}
+
+impl BloomKeyExtractor {
+    /// Registers the length of bloom keys in the keyspace of `keyspace_prefix`. The registration
+    /// is ignored if `keyspace_prefix` is not a valid keyspace.
+    pub fn register(&mut self, keyspace_prefix: &[u8], bloom_key_len: usize) {
+        if let Some(root) = keyspace_root(keyspace_prefix) && bloom_key_len > root.len() {
+            self.bloom_key_lens.insert(root.to_vec(), bloom_key_len);
+        }
+    }
+
+    /// Merges registrations of `other` into `self`. The shorter length wins on conflicts, so that
+    /// the bloom keys are still prefixes of the ones extracted by either of them.
+    pub fn merge(&mut self, other: &BloomKeyExtractor) {
+        for (root, len) in &other.bloom_key_lens {
+            self.bloom_key_lens
+                .entry(root.clone())
+                .and_modify(|l| *l = (*l).min(*len))
+                .or_insert(*len);
+        }
+    }
+
+    /// Returns the bloom key of `user_key`, if any.
+    ///
+    /// `user_key` can also be the common prefix of all keys in a scan: every key in the scan
+    /// shares the returned bloom key.
+    pub fn extract<'a>(&self, user_key: &'a [u8]) -> Option<&'a [u8]> {
+        let root = keyspace_root(user_key)?;
+        let bloom_key_len = *self.bloom_key_lens.get(root)?;
+        user_key.get(..bloom_key_len)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bloom_key_lens.is_empty()
+    }
+
+    /// Format:
+    ///
+    /// ```plain
+    /// | N (4B) | root len (4B) | root | bloom key len (4B) | ... |
+    /// ```
+    pub fn encode(&self, buf: &mut Vec<u8>) {
+        buf.put_u32_le(self.bloom_key_lens.len() as u32);
+        for (root, len) in &self.bloom_key_lens {
+            put_length_prefixed_slice(buf, root);
+            buf.put_u32_le(*len as u32);
+        }
+    }
+
+    pub fn decode(buf: &mut &[u8]) -> Self {
+        let count = buf.get_u32_le() as usize;
+        let mut bloom_key_lens = BTreeMap::new();
+        for _ in 0..count {
+            let root = get_length_prefixed_slice(buf);
+            let len = buf.get_u32_le() as usize;
+            bloom_key_lens.insert(root, len);
+        }
+        Self { bloom_key_lens }
+    }
+
+    #[inline]
+    pub fn encoded_size(&self) -> usize {
+        4 + self
+            .bloom_key_lens
+            .keys()
+            .map(|root| 8 /* root len + bloom key len */ + root.len())
+            .sum::<usize>()
+    }
+}
+
+/// Returns the longest prefix shared by all user keys in `key_range`. Unbounded ranges share no
+/// prefix.
+pub fn range_common_prefix<'a, R, B>(key_range: &'a R) -> &'a [u8]
+where
+    R: RangeBounds<B>,
+    B: AsRef<[u8]> + 'a,
+{
+    let start = match key_range.start_bound() {
+        Bound::Included(key) | Bound::Excluded(key) => key.as_ref(),
+        Bound::Unbounded => return &[],
+    };
+    let (end, end_inclusive) = match key_range.end_bound() {
+        Bound::Included(key) => (key.as_ref(), true),
+        Bound::Excluded(key) => (key.as_ref(), false),
+        Bound::Unbounded => return &[],
+    };
+    let common_len = start
+        .iter()
+        .zip(end.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    // In a prefix scan, the exclusive `end` is the `next_key` of the prefix, which is shared by
+    // all keys in the range, but not by `end` itself. The prefix is `end` with the last byte
+    // decremented, followed by any `0xff` bytes stripped by `next_key`.
+    if !end_inclusive
+        && let Some((&last, init)) = end.split_last()
+        && last > 0
+        && start.len() >= end.len()
+        && start.starts_with(init)
+        && start[init.len()] == last - 1
+    {
+        let len = end.len()
+            + start[end.len()..]
+                .iter()
+                .take_while(|b| **b == u8::MAX)
+                .count();
+        return &start[..len];
+    }
+    &start[..common_len]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bloom_key_extractor() {
+        let mut extractor = BloomKeyExtractor::default();
+        let table_root = b"t\0\0\0\x01";
+        extractor.register(table_root, 10);
+        // Not a keyspace.
+        extractor.register(b"x", 10);
+        assert_eq!(extractor.bloom_key_lens.len(), 1);
+
+        let key = [&table_root[..], b"0123456789"].concat();
+        assert_eq!(extractor.extract(&key), Some(&key[..10]));
+        // Too short to have a bloom key.
+        assert_eq!(extractor.extract(&key[..9]), None);
+        // Unregistered keyspace.
+        assert_eq!(extractor.extract(b"t\0\0\0\x020123456789"), None);
+
+        let mut other = BloomKeyExtractor::default();
+        other.register(table_root, 7);
+        other.register(b"s\0\0\0\0\0\0\0\x01", 12);
+        extractor.merge(&other);
+        assert_eq!(extractor.extract(&key), Some(&key[..7]));
+        assert_eq!(extractor.bloom_key_lens.len(), 2);
+
+        let mut buf = vec![];
+        extractor.encode(&mut buf);
+        assert_eq!(buf.len(), extractor.encoded_size());
+        assert_eq!(BloomKeyExtractor::decode(&mut &buf[..]), extractor);
+    }
+
+    #[test]
+    fn test_range_common_prefix() {
+        use risingwave_hummock_sdk::key::next_key;
+
+        assert_eq!(
+            range_common_prefix(&(b"abc".to_vec()..b"abe".to_vec())),
+            b"ab"
+        );
+        assert_eq!(
+            range_common_prefix(&(b"ab".to_vec()..=b"abc".to_vec())),
+            b"ab"
+        );
+        assert_eq!(range_common_prefix(&(b"ab".to_vec()..)), b"");
+        assert_eq!(range_common_prefix(&(..b"ab".to_vec())), b"");
+        // Prefix scans.
+        let prefix = b"ab\x01".to_vec();
+        assert_eq!(
+            range_common_prefix(&(prefix.clone()..next_key(&prefix))),
+            prefix
+        );
+        let prefix = b"ab\xff\xff".to_vec();
+        assert_eq!(
+            range_common_prefix(&(prefix.clone()..next_key(&prefix))),
+            prefix
+        );
+        let start = [&prefix[..], b"cd"].concat();
+        assert_eq!(range_common_prefix(&(start..next_key(&prefix))), prefix);
+        // The next key itself is included.
+        let prefix = b"ab".to_vec();
+        assert_eq!(
+            range_common_prefix(&(prefix.clone()..=next_key(&prefix))),
+            b"a"
+        );
+    }
+}
@@ -15,12 +15,13 @@
 use bytes::{BufMut, Bytes, BytesMut};
 use risingwave_common::hash::VirtualNode;
 use risingwave_hummock_sdk::key::user_key;
+use risingwave_hummock_sdk::VersionedComparator;
 
 use super::bloom::Bloom;
 use super::utils::CompressionAlgorithm;
 use super::{
-    BlockBuilder, BlockBuilderOptions, BlockMeta, SstableMeta, DEFAULT_BLOCK_SIZE,
-    DEFAULT_ENTRY_SIZE, DEFAULT_RESTART_INTERVAL, VERSION,
+    BlockBuilder, BlockBuilderOptions, BlockMeta, BloomKeyExtractor, RangeTombstone, SstableMeta,
+    DEFAULT_BLOCK_SIZE, DEFAULT_ENTRY_SIZE, DEFAULT_RESTART_INTERVAL, VERSION,
 };
 use crate::hummock::value::HummockValue;
 
@@ -65,11 +66,19 @@ pub struct SSTableBuilder {
     block_metas: Vec<BlockMeta>,
     /// Hashes of user keys.
     user_key_hashes: Vec<u32>,
+    /// Extracts bloom keys of user keys for the prefix bloom filter.
+    bloom_key_extractor: BloomKeyExtractor,
+    /// Hashes of distinct bloom keys.
+    bloom_key_hashes: Vec<u32>,
+    /// Last extracted bloom key.
+    last_bloom_key: Vec<u8>,
     /// Bitmap of value meta.
     bitmap: [u8; VNODE_BITMAP_LEN],
     /// Last added full key.
     last_full_key: Bytes,
     key_count: usize,
+    /// Range tombstones to be stored in the meta.
+    range_tombstones: Vec<RangeTombstone>,
 }
 
 impl SSTableBuilder {
@@ -80,12 +89,23 @@ impl SSTableBuilder {
             block_builder: None,
             block_metas: Vec::with_capacity(options.capacity / options.block_capacity + 1),
             user_key_hashes: Vec::with_capacity(options.capacity / DEFAULT_ENTRY_SIZE + 1),
+            bloom_key_extractor: BloomKeyExtractor::default(),
+            bloom_key_hashes: vec![],
+            last_bloom_key: vec![],
             bitmap: [0; VNODE_BITMAP_LEN],
             last_full_key: Bytes::default(),
             key_count: 0,
+            range_tombstones: vec![],
         }
     }
 
+    /// Builds a prefix bloom filter over bloom keys extracted by `bloom_key_extractor` as well.
+    #[must_use]
+    pub fn with_bloom_key_extractor(mut self, bloom_key_extractor: BloomKeyExtractor) -> Self {
+        self.bloom_key_extractor = bloom_key_extractor;
+        self
+    }
+
     /// Add kv pair to sstable.
     pub fn add(&mut self, full_key: &[u8], value: HummockValue<&[u8]>) {
         // Rotate block builder if the previous one has been built.
@@ -114,6 +134,13 @@ impl SSTableBuilder {
 
         let user_key = user_key(full_key);
         self.user_key_hashes.push(farmhash::fingerprint32(user_key));
+        // Keys are added in order, so keys sharing the same bloom key are adjacent.
+        if let Some(bloom_key) = self.bloom_key_extractor.extract(user_key)
+            && bloom_key != self.last_bloom_key.as_slice()
+        {
+            self.bloom_key_hashes.push(farmhash::fingerprint32(bloom_key));
+            self.last_bloom_key = bloom_key.to_vec();
+        }
 
         self.bitmap[(value_meta >> 3) as usize] |= 1 << (value_meta & 0b111);
 
@@ -128,6 +155,21 @@ impl SSTableBuilder {
         self.key_count += 1;
     }
 
+    /// Add a range tombstone to sstable. The key range of the sstable will be extended to cover
+    /// the tombstone.
+    pub fn add_range_tombstone(&mut self, range_tombstone: RangeTombstone) {
+        if !range_tombstone.is_empty() {
+            self.range_tombstones.push(range_tombstone);
+        }
+    }
+
+    /// Returns the user key of the first added kv pair, if any.
+    pub fn smallest_user_key(&self) -> Option<&[u8]> {
+        self.block_metas
+            .first()
+            .map(|block_meta| user_key(&block_meta.smallest_key))
+    }
+
     /// Finish building sst.
     ///
     /// Unlike most LSM-Tree implementations, sstable meta and data are encoded separately.
@@ -141,14 +183,39 @@ impl SSTableBuilder {
     /// | Block 0 | ... | Block N-1 | N (4B) |
     /// ```
     pub fn finish(mut self) -> (Bytes, SstableMeta) {
-        let smallest_key = self.block_metas[0].smallest_key.clone();
-        let largest_key = self.last_full_key.to_vec();
+        let mut smallest_key = self
+            .block_metas
+            .first()
+            .map(|block_meta| block_meta.smallest_key.clone());
+        let mut largest_key = (!self.last_full_key.is_empty()).then(|| self.last_full_key.to_vec());
+        // The exclusive end of a tombstone is encoded as a full key below any version of the end
+        // user key, so the table doesn't overlap with the one starting from the end user key.
+        for range_tombstone in &self.range_tombstones {
+            let start_key = range_tombstone.smallest_full_key();
+            let end_key = range_tombstone.largest_full_key();
+            if smallest_key.as_ref().map_or(true, |smallest_key| {
+                VersionedComparator::compare_key(&start_key, smallest_key)
+                    == std::cmp::Ordering::Less
+            }) {
+                smallest_key = Some(start_key);
+            }
+            if largest_key.as_ref().map_or(true, |largest_key| {
+                VersionedComparator::compare_key(&end_key, largest_key)
+                    == std::cmp::Ordering::Greater
+            }) {
+                largest_key = Some(end_key);
+            }
+        }
+        let smallest_key = smallest_key.unwrap_or_default();
+        let largest_key = largest_key.unwrap_or_default();
         self.build_block();
         self.buf.put_u32_le(self.block_metas.len() as u32);
 
         let meta = SstableMeta {
             block_metas: self.block_metas,
-            bloom_filter: if self.options.bloom_false_positive > 0.0 {
+            bloom_filter: if self.options.bloom_false_positive > 0.0
+                && !self.user_key_hashes.is_empty()
+            {
                 let bits_per_key = Bloom::bloom_bits_per_key(
                     self.user_key_hashes.len(),
                     self.options.bloom_false_positive,
@@ -162,6 +229,19 @@ impl SSTableBuilder {
             key_count: self.key_count as u32,
             smallest_key,
             largest_key,
+            range_tombstones: self.range_tombstones,
+            prefix_bloom_filter: if self.options.bloom_false_positive > 0.0
+                && !self.bloom_key_hashes.is_empty()
+            {
+                let bits_per_key = Bloom::bloom_bits_per_key(
+                    self.bloom_key_hashes.len(),
+                    self.options.bloom_false_positive,
+                );
+                Bloom::build_from_key_hashes(&self.bloom_key_hashes, bits_per_key).to_vec()
+            } else {
+                vec![]
+            },
+            bloom_key_extractor: self.bloom_key_extractor,
             version: VERSION,
         };
 
@@ -188,7 +268,7 @@ impl SSTableBuilder {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.user_key_hashes.is_empty()
+        self.user_key_hashes.is_empty() && self.range_tombstones.is_empty()
     }
 
     /// Returns true if we roughly reached capacity
@@ -199,6 +279,8 @@ impl SSTableBuilder {
 
 #[cfg(test)]
 pub(super) mod tests {
+    use risingwave_hummock_sdk::key::key_with_epoch;
+
     use super::*;
     use crate::hummock::iterator::test_utils::mock_sstable_store;
     use crate::hummock::test_utils::{
@@ -263,4 +345,51 @@ pub(super) mod tests {
         test_with_bloom_filter(false).await;
         test_with_bloom_filter(true).await;
     }
+
+    #[test]
+    fn test_prefix_bloom_filter() {
+        let table_root = b"t\0\0\0\x01";
+        let mut bloom_key_extractor = BloomKeyExtractor::default();
+        // Table root plus a 4-byte prefix.
+        bloom_key_extractor.register(table_root, table_root.len() + 4);
+        let mut b = SSTableBuilder::new(default_builder_opt_for_test())
+            .with_bloom_key_extractor(bloom_key_extractor.clone());
+        for prefix in 0..10u32 {
+            for i in 0..10u32 {
+                let user_key = [
+                    &table_root[..],
+                    &(prefix * 2).to_be_bytes(),
+                    &i.to_be_bytes(),
+                ]
+                .concat();
+                b.add(&key_with_epoch(user_key, 233), HummockValue::put(b"v"));
+            }
+        }
+        let (_, meta) = b.finish();
+        assert_eq!(meta.bloom_key_extractor, bloom_key_extractor);
+        let table = Sstable::new(0, meta);
+        assert!(table.has_prefix_bloom_filter());
+
+        for prefix in 0..10u32 {
+            let scan_prefix = [&table_root[..], &(prefix * 2).to_be_bytes()].concat();
+            assert_eq!(
+                table.surely_not_have_user_key_prefix(&scan_prefix),
+                Some(false)
+            );
+        }
+        // Too short to have a bloom key.
+        assert_eq!(table.surely_not_have_user_key_prefix(table_root), None);
+        // Unregistered keyspace.
+        assert_eq!(
+            table.surely_not_have_user_key_prefix(b"t\0\0\0\x020000"),
+            None
+        );
+        let true_negatives = (0..10u32)
+            .filter(|prefix| {
+                let scan_prefix = [&table_root[..], &(prefix * 2 + 1).to_be_bytes()].concat();
+                table.surely_not_have_user_key_prefix(&scan_prefix) == Some(true)
+            })
+            .count();
+        assert!(true_negatives > 0);
+    }
 }
@@ -21,9 +21,13 @@ mod block_iterator;
 pub use block_iterator::*;
 mod bloom;
 use bloom::Bloom;
+mod bloom_key;
+pub use bloom_key::*;
 pub mod builder;
 pub use builder::*;
 pub mod multi_builder;
+mod range_tombstone;
+pub use range_tombstone::*;
 mod sstable_iterator;
 use bytes::{Buf, BufMut};
 pub use sstable_iterator::*;
@@ -40,7 +44,12 @@ use super::{HummockError, HummockResult};
 
 const DEFAULT_META_BUFFER_CAPACITY: usize = 4096;
 const MAGIC: u32 = 0x5785ab73;
-const VERSION: u32 = 1;
+/// Version 3 appends the prefix bloom filter and its [`BloomKeyExtractor`] to [`SstableMeta`].
+const VERSION: u32 = 3;
+/// SSTs built by version 2 carry no prefix bloom filter.
+const VERSION_WITHOUT_PREFIX_BLOOM_FILTER: u32 = 2;
+/// SSTs built by version 1 carry no range tombstones either.
+const VERSION_WITHOUT_RANGE_TOMBSTONES: u32 = 1;
 
 #[derive(Clone, Debug)]
 /// [`Sstable`] is a handle for accessing SST.
@@ -68,6 +77,25 @@ impl Sstable {
         }
     }
 
+    pub fn has_prefix_bloom_filter(&self) -> bool {
+        !self.meta.prefix_bloom_filter.is_empty()
+    }
+
+    /// Judges whether the SST surely has no user key starting with `prefix` by the prefix bloom
+    /// filter.
+    ///
+    /// Returns `None` if the filter is not applicable, i.e. the SST has no prefix bloom filter, or
+    /// `prefix` is too short to have a bloom key.
+    pub fn surely_not_have_user_key_prefix(&self, prefix: &[u8]) -> Option<bool> {
+        if !self.has_prefix_bloom_filter() {
+            return None;
+        }
+        let bloom_key = self.meta.bloom_key_extractor.extract(prefix)?;
+        let hash = farmhash::fingerprint32(bloom_key);
+        let bloom = Bloom::new(&self.meta.prefix_bloom_filter);
+        Some(bloom.surely_not_have_hash(hash))
+    }
+
     pub fn block_count(&self) -> usize {
         self.meta.block_metas.len()
     }
@@ -135,6 +163,11 @@ pub struct SstableMeta {
     pub key_count: u32,
     pub smallest_key: Vec<u8>,
     pub largest_key: Vec<u8>,
+    /// Range tombstones written by `delete_range`, in no particular order.
+    pub range_tombstones: Vec<RangeTombstone>,
+    /// Bloom filter over the bloom keys extracted by `bloom_key_extractor`.
+    pub prefix_bloom_filter: Vec<u8>,
+    pub bloom_key_extractor: BloomKeyExtractor,
     /// Format version, for further compatibility.
     pub version: u32,
 }
@@ -150,6 +183,8 @@ impl SstableMeta {
     /// | estimated size (4B) | key count (4B) |
     /// | smallest key len (4B) | smallest key |
     /// | largest key len (4B) | largest key |
+    /// | M (4B) | range tombstone 0 | ... | range tombstone M-1 |
+    /// | prefix bloom filter len (4B) | prefix bloom filter | bloom key extractor |
     /// | checksum (8B) | version (4B) | magic (4B) |
     /// ```
     pub fn encode_to_bytes(&self) -> Vec<u8> {
@@ -164,6 +199,12 @@ impl SstableMeta {
         buf.put_u32_le(self.key_count as u32);
         put_length_prefixed_slice(&mut buf, &self.smallest_key);
         put_length_prefixed_slice(&mut buf, &self.largest_key);
+        buf.put_u32_le(self.range_tombstones.len() as u32);
+        for range_tombstone in &self.range_tombstones {
+            range_tombstone.encode(&mut buf);
+        }
+        put_length_prefixed_slice(&mut buf, &self.prefix_bloom_filter);
+        self.bloom_key_extractor.encode(&mut buf);
         let checksum = xxhash64_checksum(&buf);
         buf.put_u64_le(checksum);
         buf.put_u32_le(VERSION);
@@ -182,7 +223,10 @@ impl SstableMeta {
 
         cursor -= 4;
         let version = (&buf[cursor..cursor + 4]).get_u32_le();
-        if version != VERSION {
+        if version != VERSION
+            && version != VERSION_WITHOUT_PREFIX_BLOOM_FILTER
+            && version != VERSION_WITHOUT_RANGE_TOMBSTONES
+        {
             return Err(HummockError::invalid_format_version(version));
         }
 
@@ -202,6 +246,24 @@ impl SstableMeta {
         let key_count = buf.get_u32_le();
         let smallest_key = get_length_prefixed_slice(buf);
         let largest_key = get_length_prefixed_slice(buf);
+        let range_tombstones = if version == VERSION_WITHOUT_RANGE_TOMBSTONES {
+            vec![]
+        } else {
+            let range_tombstone_count = buf.get_u32_le() as usize;
+            let mut range_tombstones = Vec::with_capacity(range_tombstone_count);
+            for _ in 0..range_tombstone_count {
+                range_tombstones.push(RangeTombstone::decode(buf));
+            }
+            range_tombstones
+        };
+        let (prefix_bloom_filter, bloom_key_extractor) = if version == VERSION {
+            (
+                get_length_prefixed_slice(buf),
+                BloomKeyExtractor::decode(buf),
+            )
+        } else {
+            (vec![], BloomKeyExtractor::default())
+        };
 
         Ok(Self {
             block_metas,
@@ -211,6 +273,9 @@ impl SstableMeta {
             key_count,
             smallest_key,
             largest_key,
+            range_tombstones,
+            prefix_bloom_filter,
+            bloom_key_extractor,
             version,
         })
     }
@@ -233,6 +298,15 @@ impl SstableMeta {
             + self.smallest_key.len()
             + 4 // key len
             + self.largest_key.len()
+            + 4 // range tombstone count
+            + self
+            .range_tombstones
+            .iter()
+            .map(|range_tombstone| range_tombstone.encoded_size())
+            .sum::<usize>()
+            + 4 // prefix bloom filter len
+            + self.prefix_bloom_filter.len()
+            + self.bloom_key_extractor.encoded_size()
             + 8 // checksum
             + 4 // version
             + 4 // magic
@@ -264,6 +338,17 @@ mod tests {
             key_count: 123,
             smallest_key: b"0-smallest-key".to_vec(),
             largest_key: b"9-largest-key".to_vec(),
+            range_tombstones: vec![RangeTombstone::new(
+                b"1-start-key".to_vec(),
+                b"3-end-key".to_vec(),
+                233,
+            )],
+            prefix_bloom_filter: b"9876543210".to_vec(),
+            bloom_key_extractor: {
+                let mut extractor = BloomKeyExtractor::default();
+                extractor.register(b"t\0\0\0\x01", 9);
+                extractor
+            },
             version: VERSION,
         };
         let buf = meta.encode_to_bytes();
@@ -16,7 +16,7 @@ use bytes::Bytes;
 use futures::Future;
 use risingwave_hummock_sdk::key::{Epoch, FullKey};
 
-use super::SstableMeta;
+use super::{RangeTombstone, SstableMeta};
 use crate::hummock::value::HummockValue;
 use crate::hummock::{HummockResult, SSTableBuilder};
 
@@ -37,6 +37,9 @@ pub struct CapacitySplitTableBuilder<B> {
 
     /// Wrapped [`SSTableBuilder`]s. The last one is what we are operating on.
     builders: Vec<SSTableBuilderWrapper>,
+
+    /// Range tombstones to be distributed among the tables when finishing.
+    range_tombstones: Vec<RangeTombstone>,
 }
 
 impl<B, F> CapacitySplitTableBuilder<B>
@@ -49,6 +52,7 @@ where
         Self {
             get_id_and_builder,
             builders: Vec::new(),
+            range_tombstones: Vec::new(),
         }
     }
 
@@ -112,6 +116,34 @@ where
         Ok(())
     }
 
+    /// Adds range tombstones to the underlying builders.
+    ///
+    /// The tombstones are split among the tables in `finish`, so that each table only covers the
+    /// part between its first key and the first key of the next table. A table is created if
+    /// there's no builder yet, so that tombstones survive even if no key-value pair is added.
+    pub async fn add_range_tombstones(
+        &mut self,
+        range_tombstones: impl IntoIterator<Item = RangeTombstone>,
+    ) -> HummockResult<()> {
+        let range_tombstones = range_tombstones
+            .into_iter()
+            .filter(|range_tombstone| !range_tombstone.is_empty())
+            .collect::<Vec<_>>();
+        if range_tombstones.is_empty() {
+            return Ok(());
+        }
+        if self.builders.is_empty() {
+            let (id, builder) = (self.get_id_and_builder)().await?;
+            self.builders.push(SSTableBuilderWrapper {
+                id,
+                builder,
+                sealed: false,
+            });
+        }
+        self.range_tombstones.extend(range_tombstones);
+        Ok(())
+    }
+
     /// Marks the current builder as sealed. Next call of `add` will always create a new table.
     ///
     /// If there's no builder created, or current one is already sealed before, then this function
@@ -123,7 +155,26 @@ where
     }
 
     /// Finalizes all the tables to be ids, blocks and metadata.
-    pub fn finish(self) -> Vec<(u64, Bytes, SstableMeta)> {
+    pub fn finish(mut self) -> Vec<(u64, Bytes, SstableMeta)> {
+        let boundaries = self
+            .builders
+            .iter()
+            .skip(1)
+            .map(|b| b.builder.smallest_user_key().unwrap_or_default().to_vec())
+            .collect::<Vec<_>>();
+        for (idx, b) in self.builders.iter_mut().enumerate() {
+            let left = match idx {
+                0 => &[][..],
+                _ => boundaries[idx - 1].as_slice(),
+            };
+            let right = boundaries.get(idx).map(Vec::as_slice).unwrap_or_default();
+            for range_tombstone in &self.range_tombstones {
+                if let Some(range_tombstone) = range_tombstone.clip(left, right) {
+                    b.builder.add_range_tombstone(range_tombstone);
+                }
+            }
+        }
+
         self.builders
             .into_iter()
             .map(|b| {
@@ -140,6 +191,7 @@ mod tests {
     use std::sync::atomic::Ordering::SeqCst;
 
     use itertools::Itertools;
+    use risingwave_hummock_sdk::key_range::KeyRange;
 
     use super::*;
     use crate::hummock::sstable::utils::CompressionAlgorithm;
@@ -264,4 +316,58 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[tokio::test]
+    async fn test_range_tombstone_across_tables() {
+        let next_id = AtomicU64::new(1001);
+        let mut builder = CapacitySplitTableBuilder::new(|| async {
+            Ok((
+                next_id.fetch_add(1, SeqCst),
+                SSTableBuilder::new(default_builder_opt_for_test()),
+            ))
+        });
+
+        for key in [b"a", b"c", b"e"] {
+            builder
+                .add_user_key(key.to_vec(), HummockValue::put(b"v"), 100)
+                .await
+                .unwrap();
+            builder.seal_current();
+        }
+        builder
+            .add_range_tombstones([RangeTombstone::new(b"b".to_vec(), b"f".to_vec(), 200)])
+            .await
+            .unwrap();
+
+        let results = builder.finish();
+        assert_eq!(results.len(), 3);
+        let tombstones = results
+            .iter()
+            .map(|(_, _, meta)| meta.range_tombstones.clone())
+            .collect_vec();
+        assert_eq!(
+            tombstones,
+            vec![
+                vec![RangeTombstone::new(b"b".to_vec(), b"c".to_vec(), 200)],
+                vec![RangeTombstone::new(b"c".to_vec(), b"e".to_vec(), 200)],
+                vec![RangeTombstone::new(b"e".to_vec(), b"f".to_vec(), 200)],
+            ]
+        );
+
+        // The tables end with the exclusive end of the tombstones, and must not overlap with the
+        // next ones, which start from the same user keys.
+        let key_ranges = results
+            .iter()
+            .map(|(_, _, meta)| {
+                KeyRange::new(
+                    Bytes::from(meta.smallest_key.clone()),
+                    Bytes::from(meta.largest_key.clone()),
+                )
+            })
+            .collect_vec();
+        for (left, right) in key_ranges.iter().tuple_windows() {
+            assert!(!left.full_key_overlap(right));
+            assert!(left < right);
+        }
+    }
 }
@@ -0,0 +1,190 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use bytes::{Buf, BufMut};
+use risingwave_hummock_sdk::key::key_with_epoch;
+use risingwave_hummock_sdk::HummockEpoch;
+
+use super::utils::{get_length_prefixed_slice, put_length_prefixed_slice};
+
+/// The epoch of the full key that stands for the exclusive end of a tombstone in the key range of
+/// an sstable. It makes the smallest full key of the end user key, so a table ending with a
+/// tombstone doesn't overlap with the next table, which starts from the end user key.
+const TOMBSTONE_END_EPOCH: HummockEpoch = HummockEpoch::MAX;
+
+/// The epoch of the full key that stands for the inclusive start of a tombstone in the key range of
+/// an sstable. It comes right after [`TOMBSTONE_END_EPOCH`], and before the keys of any real epoch.
+const TOMBSTONE_START_EPOCH: HummockEpoch = HummockEpoch::MAX - 1;
+
+/// A [`RangeTombstone`] deletes all versions of the user keys in `[start_user_key,
+/// end_user_key)` which are written in an epoch strictly smaller than `epoch`.
+///
+/// Keys written in the same epoch as the tombstone are not deleted, so that the order of point
+/// writes and range deletions inside one epoch doesn't matter, and a state table can be truncated
+/// and refilled within a single epoch.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeTombstone {
+    pub start_user_key: Vec<u8>,
+    pub end_user_key: Vec<u8>,
+    pub epoch: HummockEpoch,
+}
+
+impl RangeTombstone {
+    pub fn new(start_user_key: Vec<u8>, end_user_key: Vec<u8>, epoch: HummockEpoch) -> Self {
+        Self {
+            start_user_key,
+            end_user_key,
+            epoch,
+        }
+    }
+
+    /// Returns true if the tombstone covers no user key.
+    pub fn is_empty(&self) -> bool {
+        self.start_user_key >= self.end_user_key
+    }
+
+    /// The smallest full key covered by the tombstone, as the left bound of an sstable.
+    pub fn smallest_full_key(&self) -> Vec<u8> {
+        key_with_epoch(self.start_user_key.clone(), TOMBSTONE_START_EPOCH)
+    }
+
+    /// The exclusive end of the tombstone, as the right bound of an sstable. It's smaller than
+    /// [`Self::smallest_full_key`] of a tombstone starting from the end user key, and any full key
+    /// of the end user key.
+    pub fn largest_full_key(&self) -> Vec<u8> {
+        key_with_epoch(self.end_user_key.clone(), TOMBSTONE_END_EPOCH)
+    }
+
+    /// Returns true if `user_key` falls into the range of the tombstone, regardless of epoch.
+    pub fn contains(&self, user_key: &[u8]) -> bool {
+        self.start_user_key.as_slice() <= user_key && user_key < self.end_user_key.as_slice()
+    }
+
+    /// Returns true if the version of `user_key` written in `key_epoch` is deleted by this
+    /// tombstone, when being read at `read_epoch`.
+    pub fn covers(
+        &self,
+        user_key: &[u8],
+        key_epoch: HummockEpoch,
+        read_epoch: HummockEpoch,
+    ) -> bool {
+        self.epoch <= read_epoch && key_epoch < self.epoch && self.contains(user_key)
+    }
+
+    /// Returns the part of the tombstone within `[left, right)`. An empty `left` or `right` means
+    /// unbounded on that side. Returns `None` if nothing is left after clipping.
+    pub fn clip(&self, left: &[u8], right: &[u8]) -> Option<Self> {
+        let start_user_key = if left > self.start_user_key.as_slice() {
+            left.to_vec()
+        } else {
+            self.start_user_key.clone()
+        };
+        let end_user_key = if !right.is_empty() && right < self.end_user_key.as_slice() {
+            right.to_vec()
+        } else {
+            self.end_user_key.clone()
+        };
+        let clipped = Self::new(start_user_key, end_user_key, self.epoch);
+        if clipped.is_empty() {
+            None
+        } else {
+            Some(clipped)
+        }
+    }
+
+    /// Format:
+    ///
+    /// ```plain
+    /// | start key len (4B) | start key | end key len (4B) | end key | epoch (8B) |
+    /// ```
+    pub fn encode(&self, buf: &mut Vec<u8>) {
+        put_length_prefixed_slice(buf, &self.start_user_key);
+        put_length_prefixed_slice(buf, &self.end_user_key);
+        buf.put_u64_le(self.epoch);
+    }
+
+    pub fn decode(buf: &mut &[u8]) -> Self {
+        let start_user_key = get_length_prefixed_slice(buf);
+        let end_user_key = get_length_prefixed_slice(buf);
+        let epoch = buf.get_u64_le();
+        Self {
+            start_user_key,
+            end_user_key,
+            epoch,
+        }
+    }
+
+    #[inline]
+    pub fn encoded_size(&self) -> usize {
+        16 /* key lens + epoch */ + self.start_user_key.len() + self.end_user_key.len()
+    }
+}
+
+/// Returns the largest epoch among `range_tombstones` that deletes `user_key` when being read at
+/// `read_epoch`, ignoring the epoch of the key itself.
+pub fn max_deleting_epoch<'a>(
+    range_tombstones: impl IntoIterator<Item = &'a RangeTombstone>,
+    user_key: &[u8],
+    read_epoch: HummockEpoch,
+) -> Option<HummockEpoch> {
+    range_tombstones
+        .into_iter()
+        .filter(|tombstone| tombstone.epoch <= read_epoch && tombstone.contains(user_key))
+        .map(|tombstone| tombstone.epoch)
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_range_tombstone_covers() {
+        let tombstone = RangeTombstone::new(b"b".to_vec(), b"d".to_vec(), 10);
+        assert!(tombstone.covers(b"b", 9, 10));
+        assert!(tombstone.covers(b"c", 1, u64::MAX));
+        // Exclusive end key.
+        assert!(!tombstone.covers(b"d", 9, 10));
+        assert!(!tombstone.covers(b"a", 9, 10));
+        // Keys written in the same epoch are kept.
+        assert!(!tombstone.covers(b"c", 10, 10));
+        // Invisible to earlier snapshots.
+        assert!(!tombstone.covers(b"c", 5, 9));
+    }
+
+    #[test]
+    fn test_range_tombstone_clip() {
+        let tombstone = RangeTombstone::new(b"b".to_vec(), b"f".to_vec(), 10);
+        assert_eq!(tombstone.clip(b"", b""), Some(tombstone.clone()));
+        assert_eq!(
+            tombstone.clip(b"c", b"e"),
+            Some(RangeTombstone::new(b"c".to_vec(), b"e".to_vec(), 10))
+        );
+        assert_eq!(
+            tombstone.clip(b"a", b""),
+            Some(RangeTombstone::new(b"b".to_vec(), b"f".to_vec(), 10))
+        );
+        assert_eq!(tombstone.clip(b"f", b""), None);
+        assert_eq!(tombstone.clip(b"", b"b"), None);
+    }
+
+    #[test]
+    fn test_range_tombstone_enc_dec() {
+        let tombstone = RangeTombstone::new(b"start".to_vec(), b"end-key".to_vec(), 233);
+        let mut buf = vec![];
+        tombstone.encode(&mut buf);
+        assert_eq!(buf.len(), tombstone.encoded_size());
+        assert_eq!(RangeTombstone::decode(&mut &buf[..]), tombstone);
+    }
+}
@@ -39,6 +39,10 @@ pub struct SSTableIterator {
     pub sst: TableHolder,
 
     sstable_store: SstableStoreRef,
+
+    /// The common prefix of all keys to scan, if any. The SST is skipped on seek if its prefix
+    /// bloom filter tells that it has no such key.
+    scan_prefix: Option<Vec<u8>>,
 }
 
 impl SSTableIterator {
@@ -48,9 +52,23 @@ impl SSTableIterator {
             cur_idx: 0,
             sst: table,
             sstable_store,
+            scan_prefix: None,
         }
     }
 
+    /// Only keys starting with `scan_prefix` will be read from the iterator.
+    pub fn with_scan_prefix(mut self, scan_prefix: &[u8]) -> Self {
+        self.scan_prefix = Some(scan_prefix.to_vec());
+        self
+    }
+
+    fn skip_by_prefix_bloom_filter(&self) -> bool {
+        self.scan_prefix.as_ref().map_or(false, |scan_prefix| {
+            self.sstable_store
+                .skip_by_prefix_bloom_filter(self.sst.value(), scan_prefix)
+        })
+    }
+
     /// Seeks to a block, and then seeks to the key if `seek_key` is given.
     async fn seek_idx(&mut self, idx: usize, seek_key: Option<&[u8]>) -> HummockResult<()> {
         tracing::trace!(
@@ -116,10 +134,18 @@ impl HummockIterator for SSTableIterator {
     }
 
     async fn rewind(&mut self) -> HummockResult<()> {
+        if self.skip_by_prefix_bloom_filter() {
+            self.block_iter = None;
+            return Ok(());
+        }
         self.seek_idx(0, None).await
     }
 
     async fn seek(&mut self, key: &[u8]) -> HummockResult<()> {
+        if self.skip_by_prefix_bloom_filter() {
+            self.block_iter = None;
+            return Ok(());
+        }
         let block_idx = self
             .sst
             .value()
@@ -185,6 +185,22 @@ impl SstableStore {
         }
     }
 
+    /// Returns true if `sst` can be skipped in a scan of keys starting with `scan_prefix`, judged
+    /// by its prefix bloom filter.
+    pub fn skip_by_prefix_bloom_filter(&self, sst: &Sstable, scan_prefix: &[u8]) -> bool {
+        match sst.surely_not_have_user_key_prefix(scan_prefix) {
+            Some(true) => {
+                self.stats.prefix_bloom_filter_true_negative_counts.inc();
+                true
+            }
+            Some(false) => {
+                self.stats.prefix_bloom_filter_might_positive_counts.inc();
+                false
+            }
+            None => false,
+        }
+    }
+
     pub fn get_sst_meta_path(&self, sst_id: u64) -> String {
         format!("{}/{}.meta", self.path, sst_id)
     }
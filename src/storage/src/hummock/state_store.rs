@@ -19,7 +19,7 @@ use std::ops::RangeBounds;
 use bytes::Bytes;
 use itertools::Itertools;
 use risingwave_hummock_sdk::key::{key_with_epoch, user_key, FullKey};
-use risingwave_hummock_sdk::VersionedComparator;
+use risingwave_hummock_sdk::{HummockEpoch, VersionedComparator};
 use risingwave_pb::hummock::LevelType;
 
 use super::iterator::{
@@ -28,7 +28,10 @@ use super::iterator::{
 };
 use super::shared_buffer::shared_buffer_batch::SharedBufferBatch;
 use super::utils::{validate_epoch, validate_table_key_range};
-use super::{HummockStorage, ReverseSSTableIterator, SSTableIterator};
+use super::{
+    max_deleting_epoch, range_common_prefix, HummockStorage, RangeTombstone,
+    ReverseSSTableIterator, SSTableIterator,
+};
 use crate::error::StorageResult;
 use crate::hummock::iterator::BoxedBackwardHummockIterator;
 use crate::hummock::utils::prune_ssts;
@@ -36,6 +39,33 @@ use crate::storage_value::StorageValue;
 use crate::store::*;
 use crate::{define_state_store_associated_type, StateStore, StateStoreIter};
 
+/// Collects the range tombstones that are visible at `epoch`.
+fn collect_range_tombstones(
+    range_tombstones: &mut Vec<RangeTombstone>,
+    new_range_tombstones: &[RangeTombstone],
+    epoch: HummockEpoch,
+) {
+    range_tombstones.extend(
+        new_range_tombstones
+            .iter()
+            .filter(|range_tombstone| range_tombstone.epoch <= epoch)
+            .cloned(),
+    );
+}
+
+/// Returns `None` if the value written in `value_epoch` is deleted by a range tombstone of
+/// `deleting_epoch`.
+fn filter_deleted_by_range(
+    value: Option<Bytes>,
+    value_epoch: HummockEpoch,
+    deleting_epoch: Option<HummockEpoch>,
+) -> Option<Bytes> {
+    match deleting_epoch {
+        Some(deleting_epoch) if value_epoch < deleting_epoch => None,
+        _ => value,
+    }
+}
+
 impl HummockStorage {
     async fn iter_inner<R, B>(
         &self,
@@ -51,6 +81,8 @@ impl HummockStorage {
         // `overlapped_forward_sstable_iters`
         let mut overlapped_forward_iters = vec![];
         let mut overlapped_backward_iters = vec![];
+        let mut range_tombstones = vec![];
+        let scan_prefix = range_common_prefix(&key_range);
 
         let (uncommitted_ssts, pinned_version) = {
             let read_version = self.local_version_manager.read_version(epoch)?;
@@ -63,6 +95,11 @@ impl HummockStorage {
             // Generate shared buffer iterators
             for shared_buffer in read_version.shared_buffer {
                 for batch in shared_buffer.get_overlap_batches(&key_range, reversed) {
+                    collect_range_tombstones(
+                        &mut range_tombstones,
+                        batch.range_tombstones(),
+                        epoch,
+                    );
                     if reversed {
                         overlapped_backward_iters
                             .push(Box::new(batch.into_backward_iter())
@@ -82,15 +119,21 @@ impl HummockStorage {
         let table_infos = prune_ssts(uncommitted_ssts.iter(), &key_range, reversed);
         for table_info in table_infos.into_iter().rev() {
             let table = self.sstable_store.sstable(table_info.id).await?;
+            collect_range_tombstones(
+                &mut range_tombstones,
+                &table.value().meta.range_tombstones,
+                epoch,
+            );
+            // The iterators skip the SST on seek if it's filtered out by the prefix bloom filter.
             if reversed {
-                overlapped_backward_iters.push(Box::new(ReverseSSTableIterator::new(
-                    table,
-                    self.sstable_store(),
-                )) as BoxedBackwardHummockIterator);
+                overlapped_backward_iters.push(Box::new(
+                    ReverseSSTableIterator::new(table, self.sstable_store())
+                        .with_scan_prefix(scan_prefix),
+                ) as BoxedBackwardHummockIterator);
             } else {
-                overlapped_forward_iters
-                    .push(Box::new(SSTableIterator::new(table, self.sstable_store()))
-                        as BoxedForwardHummockIterator);
+                overlapped_forward_iters.push(Box::new(
+                    SSTableIterator::new(table, self.sstable_store()).with_scan_prefix(scan_prefix),
+                ) as BoxedForwardHummockIterator);
             };
         }
 
@@ -106,22 +149,48 @@ impl HummockStorage {
                 LevelType::Overlapping => {
                     for table_info in table_infos.into_iter().rev() {
                         let table = self.sstable_store.sstable(table_info.id).await?;
+                        collect_range_tombstones(
+                            &mut range_tombstones,
+                            &table.value().meta.range_tombstones,
+                            epoch,
+                        );
                         if reversed {
-                            overlapped_backward_iters.push(Box::new(ReverseSSTableIterator::new(
-                                table,
-                                self.sstable_store(),
-                            ))
+                            overlapped_backward_iters.push(Box::new(
+                                ReverseSSTableIterator::new(table, self.sstable_store())
+                                    .with_scan_prefix(scan_prefix),
+                            )
                                 as BoxedBackwardHummockIterator);
                         } else {
-                            overlapped_forward_iters.push(Box::new(SSTableIterator::new(
-                                table,
-                                self.sstable_store(),
-                            ))
+                            overlapped_forward_iters.push(Box::new(
+                                SSTableIterator::new(table, self.sstable_store())
+                                    .with_scan_prefix(scan_prefix),
+                            )
                                 as BoxedForwardHummockIterator);
                         };
                     }
                 }
                 LevelType::Nonoverlapping => {
+                    // Range tombstones are stored in the meta, which has to be fetched in advance.
+                    // SSTs filtered out by prefix bloom filters are not concatenated.
+                    let mut filtered_table_infos = Vec::with_capacity(table_infos.len());
+                    for table_info in table_infos {
+                        let table = self.sstable_store.sstable(table_info.id).await?;
+                        collect_range_tombstones(
+                            &mut range_tombstones,
+                            &table.value().meta.range_tombstones,
+                            epoch,
+                        );
+                        if !self
+                            .sstable_store
+                            .skip_by_prefix_bloom_filter(table.value(), scan_prefix)
+                        {
+                            filtered_table_infos.push(table_info);
+                        }
+                    }
+                    let table_infos = filtered_table_infos;
+                    if table_infos.is_empty() {
+                        continue;
+                    }
                     if reversed {
                         overlapped_backward_iters.push(Box::new(ReverseConcatIterator::new(
                             table_infos.into_iter().rev().cloned().collect(),
@@ -151,27 +220,33 @@ impl HummockStorage {
         let mut user_iterator = if reversed {
             let reverse_merge_iterator =
                 ReverseMergeIterator::new(overlapped_backward_iters, self.stats.clone());
-            DirectedUserIterator::Backward(ReverseUserIterator::with_epoch(
-                reverse_merge_iterator,
-                (
-                    key_range.end_bound().map(|b| b.as_ref().to_owned()),
-                    key_range.start_bound().map(|b| b.as_ref().to_owned()),
-                ),
-                epoch,
-                Some(pinned_version),
-            ))
+            DirectedUserIterator::Backward(
+                ReverseUserIterator::with_epoch(
+                    reverse_merge_iterator,
+                    (
+                        key_range.end_bound().map(|b| b.as_ref().to_owned()),
+                        key_range.start_bound().map(|b| b.as_ref().to_owned()),
+                    ),
+                    epoch,
+                    Some(pinned_version),
+                )
+                .with_range_tombstones(range_tombstones),
+            )
         } else {
             let merge_iterator = MergeIterator::new(overlapped_forward_iters, self.stats.clone());
 
-            DirectedUserIterator::Forward(UserIterator::new(
-                merge_iterator,
-                (
-                    key_range.start_bound().map(|b| b.as_ref().to_owned()),
-                    key_range.end_bound().map(|b| b.as_ref().to_owned()),
-                ),
-                epoch,
-                Some(pinned_version),
-            ))
+            DirectedUserIterator::Forward(
+                UserIterator::new(
+                    merge_iterator,
+                    (
+                        key_range.start_bound().map(|b| b.as_ref().to_owned()),
+                        key_range.end_bound().map(|b| b.as_ref().to_owned()),
+                    ),
+                    epoch,
+                    Some(pinned_version),
+                )
+                .with_range_tombstones(range_tombstones),
+            )
         };
 
         user_iterator.rewind().await?;
@@ -192,6 +267,10 @@ impl StateStore for HummockStorage {
     /// failed due to other non-EOF errors.
     fn get<'a>(&'a self, key: &'a [u8], epoch: u64) -> Self::GetFuture<'_> {
         async move {
+            // The largest epoch of range tombstones covering `key` that we have met so far. As we
+            // search from newer data to older data, a value is deleted if it's written before it.
+            let mut deleting_epoch = None;
+
             let (uncommitted_ssts, pinned_version) = {
                 let read_version = self.local_version_manager.read_version(epoch)?;
 
@@ -201,10 +280,19 @@ impl StateStore for HummockStorage {
                 // Query shared buffer. Return the value without iterating SSTs if found
                 for shared_buffer in read_version.shared_buffer {
                     for batch in shared_buffer.get_overlap_batches(&(key..=key), false) {
+                        deleting_epoch = deleting_epoch.max(max_deleting_epoch(
+                            batch.range_tombstones(),
+                            key,
+                            epoch,
+                        ));
                         match batch.get(key) {
                             Some(v) => {
                                 self.stats.get_shared_buffer_hit_counts.inc();
-                                return Ok(v.into_user_value().map(|v| v.into()));
+                                return Ok(filter_deleted_by_range(
+                                    v.into_user_value().map(|v| v.into()),
+                                    batch.epoch(),
+                                    deleting_epoch,
+                                ));
                             }
                             None => continue,
                         }
@@ -222,8 +310,19 @@ impl StateStore for HummockStorage {
             for table_info in table_infos.into_iter().rev() {
                 let table = self.sstable_store.sstable(table_info.id).await?;
                 table_counts += 1;
-                if let Some(v) = self.get_from_table(table, &internal_key, key).await? {
-                    return Ok(Some(v));
+                deleting_epoch = deleting_epoch.max(max_deleting_epoch(
+                    &table.value().meta.range_tombstones,
+                    key,
+                    epoch,
+                ));
+                if let Some((value_epoch, v)) =
+                    self.get_from_table(table, &internal_key, key).await?
+                {
+                    return Ok(filter_deleted_by_range(
+                        Some(v),
+                        value_epoch,
+                        deleting_epoch,
+                    ));
                 }
             }
 
@@ -237,8 +336,19 @@ impl StateStore for HummockStorage {
                         for table_info in table_infos.into_iter().rev() {
                             let table = self.sstable_store.sstable(table_info.id).await?;
                             table_counts += 1;
-                            if let Some(v) = self.get_from_table(table, &internal_key, key).await? {
-                                return Ok(Some(v));
+                            deleting_epoch = deleting_epoch.max(max_deleting_epoch(
+                                &table.value().meta.range_tombstones,
+                                key,
+                                epoch,
+                            ));
+                            if let Some((value_epoch, v)) =
+                                self.get_from_table(table, &internal_key, key).await?
+                            {
+                                return Ok(filter_deleted_by_range(
+                                    Some(v),
+                                    value_epoch,
+                                    deleting_epoch,
+                                ));
                             }
                         }
                     }
@@ -261,8 +371,19 @@ impl StateStore for HummockStorage {
                             .sstable_store
                             .sstable(level.table_infos[table_idx].id)
                             .await?;
-                        if let Some(v) = self.get_from_table(table, &internal_key, key).await? {
-                            return Ok(Some(v));
+                        deleting_epoch = deleting_epoch.max(max_deleting_epoch(
+                            &table.value().meta.range_tombstones,
+                            key,
+                            epoch,
+                        ));
+                        if let Some((value_epoch, v)) =
+                            self.get_from_table(table, &internal_key, key).await?
+                        {
+                            return Ok(filter_deleted_by_range(
+                                Some(v),
+                                value_epoch,
+                                deleting_epoch,
+                            ));
                         }
                     }
                 }
@@ -376,6 +497,43 @@ impl StateStore for HummockStorage {
         }
     }
 
+    /// Writes a range tombstone to shared buffer. Like point deletions, the tombstone is flushed
+    /// to SSTs along with other writes of the same epoch, and is garbage-collected by compaction.
+    fn delete_range(
+        &self,
+        start_key: Bytes,
+        end_key: Bytes,
+        epoch: u64,
+    ) -> Self::DeleteRangeFuture<'_> {
+        async move {
+            let range_tombstone = RangeTombstone::new(start_key.to_vec(), end_key.to_vec(), epoch);
+            if range_tombstone.is_empty() {
+                return Ok(());
+            }
+            let batch =
+                SharedBufferBatch::new_with_range_tombstones(vec![], vec![range_tombstone], epoch);
+
+            self.local_version_manager
+                .write_shared_buffer(epoch, batch, false)
+                .await?;
+
+            if !self.options.async_checkpoint_enabled {
+                self.local_version_manager()
+                    .sync_shared_buffer(Some(epoch))
+                    .await?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Registers the bloom key length into the shared `BloomKeyExtractor`, which is used by all
+    /// SSTs built afterwards.
+    fn register_bloom_key(&self, keyspace_prefix: &[u8], bloom_key_len: usize) {
+        self.bloom_key_extractor
+            .write()
+            .register(keyspace_prefix, bloom_key_len);
+    }
+
     /// Returns an iterator that scan from the begin key to the end key
     /// The result is based on a snapshot corresponding to the given `epoch`.
     fn iter<R, B>(&self, key_range: R, epoch: u64) -> Self::IterFuture<'_, R, B>
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use bytes::Bytes;
+use itertools::Itertools;
+use risingwave_common::catalog::TableId;
 use risingwave_meta::hummock::test_utils::setup_compute_env;
 use risingwave_meta::hummock::MockHummockMetaClient;
 use risingwave_rpc_client::HummockMetaClient;
@@ -23,10 +25,11 @@ use super::HummockStorage;
 use crate::hummock::iterator::test_utils::mock_sstable_store_with_object_store;
 use crate::hummock::key::Epoch;
 use crate::hummock::test_utils::{count_iter, default_config_for_test};
+use crate::hummock::SstableStore;
 use crate::monitor::StateStoreMetrics;
 use crate::object::{InMemObjectStore, ObjectStoreImpl};
 use crate::storage_value::{StorageValue, VALUE_META_SIZE};
-use crate::StateStore;
+use crate::{Keyspace, StateStore};
 
 #[tokio::test]
 async fn test_basic() {
@@ -358,3 +361,154 @@ async fn test_reload_storage() {
     let len = count_iter(&mut iter).await;
     assert_eq!(len, 3);
 }
+
+#[tokio::test]
+async fn test_delete_range() {
+    let object_client = Arc::new(ObjectStoreImpl::Mem(InMemObjectStore::new()));
+    let sstable_store = mock_sstable_store_with_object_store(object_client.clone());
+    let hummock_options = Arc::new(default_config_for_test());
+    let (_env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+        setup_compute_env(8080).await;
+    let meta_client = Arc::new(MockHummockMetaClient::new(
+        hummock_manager_ref.clone(),
+        worker_node.id,
+    ));
+    let hummock_storage = HummockStorage::with_default_stats(
+        hummock_options,
+        sstable_store,
+        meta_client.clone(),
+        Arc::new(StateStoreMetrics::unused()),
+    )
+    .await
+    .unwrap();
+
+    let epoch1: u64 = 1;
+    let batch1 = vec![
+        (Bytes::from("aa"), StorageValue::new_default_put("111")),
+        (Bytes::from("bb"), StorageValue::new_default_put("222")),
+        (Bytes::from("cc"), StorageValue::new_default_put("333")),
+        (Bytes::from("dd"), StorageValue::new_default_put("444")),
+    ];
+    hummock_storage.ingest_batch(batch1, epoch1).await.unwrap();
+
+    // Delete [bb, dd) and rewrite cc in the same epoch.
+    let epoch2 = epoch1 + 1;
+    hummock_storage
+        .delete_range(Bytes::from("bb"), Bytes::from("dd"), epoch2)
+        .await
+        .unwrap();
+    let batch2 = vec![(Bytes::from("cc"), StorageValue::new_default_put("333333"))];
+    hummock_storage.ingest_batch(batch2, epoch2).await.unwrap();
+
+    let check = |epoch: u64, expected: Vec<(&'static str, &'static str)>| {
+        let hummock_storage = hummock_storage.clone();
+        async move {
+            let expected = expected
+                .into_iter()
+                .map(|(k, v)| (Bytes::from(k), Bytes::from(v)))
+                .collect::<Vec<_>>();
+            assert_eq!(
+                hummock_storage
+                    .scan(..=b"ee".to_vec(), None, epoch)
+                    .await
+                    .unwrap(),
+                expected
+            );
+            for (key, value) in &expected {
+                assert_eq!(
+                    hummock_storage.get(key, epoch).await.unwrap().as_ref(),
+                    Some(value)
+                );
+            }
+        }
+    };
+
+    // Read from shared buffer.
+    check(
+        epoch1,
+        vec![("aa", "111"), ("bb", "222"), ("cc", "333"), ("dd", "444")],
+    )
+    .await;
+    check(epoch2, vec![("aa", "111"), ("cc", "333333"), ("dd", "444")]).await;
+    assert_eq!(
+        hummock_storage
+            .get(&Bytes::from("bb"), epoch2)
+            .await
+            .unwrap(),
+        None
+    );
+
+    // Read from SSTs.
+    hummock_storage.sync(None).await.unwrap();
+    check(
+        epoch1,
+        vec![("aa", "111"), ("bb", "222"), ("cc", "333"), ("dd", "444")],
+    )
+    .await;
+    check(epoch2, vec![("aa", "111"), ("cc", "333333"), ("dd", "444")]).await;
+    assert_eq!(
+        hummock_storage
+            .get(&Bytes::from("bb"), epoch2)
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+#[tokio::test]
+async fn test_scan_skips_sst_by_prefix_bloom_filter() {
+    let object_client = Arc::new(ObjectStoreImpl::Mem(InMemObjectStore::new()));
+    let stats = Arc::new(StateStoreMetrics::unused());
+    let sstable_store = Arc::new(SstableStore::new(
+        object_client,
+        "test".to_string(),
+        stats.clone(),
+        64 << 20,
+        64 << 20,
+    ));
+    let hummock_options = Arc::new(default_config_for_test());
+    let (_env, hummock_manager_ref, _cluster_manager_ref, worker_node) =
+        setup_compute_env(8080).await;
+    let meta_client = Arc::new(MockHummockMetaClient::new(
+        hummock_manager_ref.clone(),
+        worker_node.id,
+    ));
+    let hummock_storage = HummockStorage::with_default_stats(
+        hummock_options,
+        sstable_store,
+        meta_client.clone(),
+        stats.clone(),
+    )
+    .await
+    .unwrap();
+
+    // Keys in the table are prefixed by a 4-byte group key.
+    let keyspace = Keyspace::table_root(hummock_storage.clone(), &TableId::new(1));
+    keyspace.register_bloom_key_len(4);
+    let key_of = |group: u32, i: u8| {
+        Bytes::from(keyspace.prefixed_key([&group.to_be_bytes()[..], &[i]].concat()))
+    };
+
+    // Each epoch is synced into an SST holding a single group.
+    for (epoch, group) in [(1, 0u32), (2, 2u32)] {
+        let batch = (0..10u8)
+            .map(|i| (key_of(group, i), StorageValue::new_default_put("v")))
+            .collect_vec();
+        hummock_storage.ingest_batch(batch, epoch).await.unwrap();
+        hummock_storage.sync(Some(epoch)).await.unwrap();
+    }
+
+    let group_keyspace = keyspace.append(0u32.to_be_bytes().to_vec());
+    let result = group_keyspace.scan(None, 2).await.unwrap();
+    assert_eq!(
+        result.into_iter().map(|(key, _)| key).collect_vec(),
+        (0..10u8).map(|i| key_of(0, i)).collect_vec()
+    );
+    // The SST of group 2 is skipped.
+    assert_eq!(stats.prefix_bloom_filter_true_negative_counts.get(), 1);
+
+    // Group 1 is in neither of the SSTs.
+    let group_keyspace = keyspace.append(1u32.to_be_bytes().to_vec());
+    assert!(group_keyspace.scan(None, 2).await.unwrap().is_empty());
+    assert_eq!(stats.prefix_bloom_filter_true_negative_counts.get(), 3);
+}
@@ -21,6 +21,26 @@ use risingwave_hummock_sdk::key::next_key;
 use crate::error::StorageResult;
 use crate::{StateStore, StateStoreIter};
 
+/// Returns the root prefix of the keyspace that `key` belongs to, i.e. the prefix created by
+/// [`Keyspace::shared_executor_root`], [`Keyspace::executor_root`] or [`Keyspace::table_root`].
+pub fn keyspace_root(key: &[u8]) -> Option<&[u8]> {
+    let root_len = match key.first()? {
+        b's' | b'e' => 9,
+        b't' => 5,
+        _ => return None,
+    };
+    key.get(..root_len)
+}
+
+/// Returns the table id if `key` belongs to the keyspace of a table, i.e. a keyspace created by
+/// [`Keyspace::table_root`].
+pub fn get_table_id(key: &[u8]) -> Option<u32> {
+    match keyspace_root(key)? {
+        [b't', table_id @ ..] => Some(u32::from_be_bytes(table_id.try_into().unwrap())),
+        _ => None,
+    }
+}
+
 /// Provides API to read key-value pairs of a prefix in the storage backend.
 #[derive(Clone)]
 pub struct Keyspace<S: StateStore> {
@@ -180,6 +200,14 @@ impl<S: StateStore> Keyspace<S> {
         Ok(strip_prefix_iterator)
     }
 
+    /// Registers the first `key_prefix_len` bytes of keys in the keyspace, following the keyspace
+    /// prefix, as the bloom key for prefix bloom filters. Scans with a prefix of at least this
+    /// length can then skip SSTs that don't contain the prefix.
+    pub fn register_bloom_key_len(&self, key_prefix_len: usize) {
+        self.store
+            .register_bloom_key(&self.prefix, self.prefix.len() + key_prefix_len);
+    }
+
     /// Gets the underlying state store.
     pub fn state_store(&self) -> S {
         self.store.clone()
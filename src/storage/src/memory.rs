@@ -23,6 +23,7 @@ use bytes::Bytes;
 use lazy_static::lazy_static;
 use tokio::sync::Mutex;
 
+use crate::hummock::RangeTombstone;
 use crate::storage_value::StorageValue;
 use crate::store::*;
 use crate::{define_state_store_associated_type, StateStore, StateStoreIter};
@@ -39,6 +40,10 @@ type KeyWithEpoch = (Bytes, Reverse<u64>);
 pub struct MemoryStateStore {
     /// Stores (key, epoch) -> user value. We currently don't consider value meta here.
     inner: Arc<Mutex<BTreeMap<KeyWithEpoch, Option<Bytes>>>>,
+
+    /// Range tombstones written by `delete_range`. They are never applied to `inner`, so that
+    /// snapshots before the deletion are still readable.
+    range_tombstones: Arc<Mutex<Vec<RangeTombstone>>>,
 }
 
 impl Default for MemoryStateStore {
@@ -69,6 +74,7 @@ impl MemoryStateStore {
     pub fn new() -> Self {
         Self {
             inner: Arc::new(Mutex::new(BTreeMap::new())),
+            range_tombstones: Arc::new(Mutex::new(Vec::new())),
         }
     }
 
@@ -114,6 +120,7 @@ impl StateStore for MemoryStateStore {
                 return Ok(vec![]);
             }
             let inner = self.inner.lock().await;
+            let range_tombstones = self.range_tombstones.lock().await;
 
             let mut last_key = None;
             for ((key, Reverse(key_epoch)), value) in inner.range(to_bytes_range(key_range)) {
@@ -121,7 +128,10 @@ impl StateStore for MemoryStateStore {
                     continue;
                 }
                 if Some(key) != last_key.as_ref() {
-                    if let Some(value) = value {
+                    let deleted_by_range = range_tombstones
+                        .iter()
+                        .any(|tombstone| tombstone.covers(key, *key_epoch, epoch));
+                    if let Some(value) = value && !deleted_by_range {
                         data.push((key.clone(), value.clone()));
                     }
                     last_key = Some(key.clone());
@@ -171,6 +181,21 @@ impl StateStore for MemoryStateStore {
         async move { unimplemented!() }
     }
 
+    fn delete_range(
+        &self,
+        start_key: Bytes,
+        end_key: Bytes,
+        epoch: u64,
+    ) -> Self::DeleteRangeFuture<'_> {
+        async move {
+            let range_tombstone = RangeTombstone::new(start_key.to_vec(), end_key.to_vec(), epoch);
+            if !range_tombstone.is_empty() {
+                self.range_tombstones.lock().await.push(range_tombstone);
+            }
+            Ok(())
+        }
+    }
+
     fn iter<R, B>(&self, key_range: R, epoch: u64) -> Self::IterFuture<'_, R, B>
     where
         R: RangeBounds<B> + Send,
@@ -294,4 +319,65 @@ mod tests {
         assert_eq!(state_store.get(b"b", 1).await.unwrap(), None);
         assert_eq!(state_store.get(b"c", 1).await.unwrap(), None);
     }
+
+    #[tokio::test]
+    async fn test_delete_range() {
+        let state_store = MemoryStateStore::new();
+        state_store
+            .ingest_batch(
+                vec![
+                    (
+                        b"a".to_vec().into(),
+                        StorageValue::new_default_put(b"v1".to_vec()),
+                    ),
+                    (
+                        b"b".to_vec().into(),
+                        StorageValue::new_default_put(b"v1".to_vec()),
+                    ),
+                    (
+                        b"c".to_vec().into(),
+                        StorageValue::new_default_put(b"v1".to_vec()),
+                    ),
+                ],
+                1,
+            )
+            .await
+            .unwrap();
+        state_store
+            .delete_range(b"a".to_vec().into(), b"c".to_vec().into(), 2)
+            .await
+            .unwrap();
+        // Keys written in the same epoch as the range tombstone are kept.
+        state_store
+            .ingest_batch(
+                vec![(
+                    b"b".to_vec().into(),
+                    StorageValue::new_default_put(b"v2".to_vec()),
+                )],
+                2,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            state_store.scan("a"..="c", None, 1).await.unwrap(),
+            vec![
+                (b"a".to_vec().into(), b"v1".to_vec().into()),
+                (b"b".to_vec().into(), b"v1".to_vec().into()),
+                (b"c".to_vec().into(), b"v1".to_vec().into())
+            ]
+        );
+        assert_eq!(
+            state_store.scan("a"..="c", None, 2).await.unwrap(),
+            vec![
+                (b"b".to_vec().into(), b"v2".to_vec().into()),
+                (b"c".to_vec().into(), b"v1".to_vec().into())
+            ]
+        );
+        assert_eq!(state_store.get(b"a", 2).await.unwrap(), None);
+        assert_eq!(
+            state_store.get(b"a", 1).await.unwrap(),
+            Some(b"v1".to_vec().into())
+        );
+    }
 }
@@ -198,6 +198,23 @@ where
     ) -> Self::ReplicateBatchFuture<'_> {
         async move { self.inner.replicate_batch(kv_pairs, epoch).await }
     }
+
+    fn delete_range(
+        &self,
+        start_key: Bytes,
+        end_key: Bytes,
+        epoch: u64,
+    ) -> Self::DeleteRangeFuture<'_> {
+        async move {
+            self.stats.delete_range_counts.inc();
+            self.inner.delete_range(start_key, end_key, epoch).await
+        }
+    }
+
+    fn register_bloom_key(&self, keyspace_prefix: &[u8], bloom_key_len: usize) {
+        self.inner
+            .register_bloom_key(keyspace_prefix, bloom_key_len)
+    }
 }
 
 /// A state store iterator wrapper for monitoring metrics.
@@ -32,6 +32,8 @@ macro_rules! for_all_metrics {
 
             bloom_filter_true_negative_counts: GenericCounter<AtomicU64>,
             bloom_filter_might_positive_counts: GenericCounter<AtomicU64>,
+            prefix_bloom_filter_true_negative_counts: GenericCounter<AtomicU64>,
+            prefix_bloom_filter_might_positive_counts: GenericCounter<AtomicU64>,
 
             range_scan_size: Histogram,
             range_scan_duration: Histogram,
@@ -42,6 +44,7 @@ macro_rules! for_all_metrics {
             write_batch_duration: Histogram,
             write_batch_size: Histogram,
             write_build_l0_sst_duration: Histogram,
+            delete_range_counts: GenericCounter<AtomicU64>,
 
             iter_merge_sstable_counts: Histogram,
             iter_merge_seek_duration: Histogram,
@@ -54,6 +57,7 @@ macro_rules! for_all_metrics {
             shared_buffer_to_sstable_size: Histogram,
 
             compaction_upload_sst_counts: GenericCounter<AtomicU64>,
+            compaction_ttl_reclaimed_bytes: GenericCounter<AtomicU64>,
         }
     };
 }
@@ -119,6 +123,20 @@ impl StateStoreMetrics {
         )
         .unwrap();
 
+        let prefix_bloom_filter_true_negative_counts = register_int_counter_with_registry!(
+            "state_store_prefix_bloom_filter_true_negative_counts",
+            "Total number of sstables that have been skipped in scans by prefix bloom filters",
+            registry
+        )
+        .unwrap();
+
+        let prefix_bloom_filter_might_positive_counts = register_int_counter_with_registry!(
+            "state_store_prefix_bloom_filter_might_positive_counts",
+            "Total number of sstables that have been considered possibly positive in scans by prefix bloom filters",
+            registry
+        )
+        .unwrap();
+
         // ----- range_scan -----
         let opts = histogram_opts!(
             "state_store_range_scan_size",
@@ -179,6 +197,13 @@ impl StateStoreMetrics {
         let write_build_l0_sst_duration =
             register_histogram_with_registry!(opts, registry).unwrap();
 
+        let delete_range_counts = register_int_counter_with_registry!(
+            "state_store_delete_range_counts",
+            "Total number of range deletions that have been issued to state store",
+            registry
+        )
+        .unwrap();
+
         let opts = histogram_opts!(
             "state_store_shared_buffer_to_l0_duration",
             "Histogram of time spent from compacting shared buffer to remote storage",
@@ -242,6 +267,13 @@ impl StateStoreMetrics {
         )
         .unwrap();
 
+        let compaction_ttl_reclaimed_bytes = register_int_counter_with_registry!(
+            "state_store_compaction_ttl_reclaimed_bytes",
+            "Total bytes of expired keys and values dropped by compaction",
+            registry
+        )
+        .unwrap();
+
         monitor_process(&registry).unwrap();
 
         Self {
@@ -252,6 +284,8 @@ impl StateStoreMetrics {
 
             bloom_filter_true_negative_counts,
             bloom_filter_might_positive_counts,
+            prefix_bloom_filter_true_negative_counts,
+            prefix_bloom_filter_might_positive_counts,
 
             range_scan_size,
             range_scan_duration,
@@ -262,6 +296,7 @@ impl StateStoreMetrics {
             write_batch_duration,
             write_batch_size,
             write_build_l0_sst_duration,
+            delete_range_counts,
 
             iter_merge_sstable_counts,
             iter_merge_seek_duration,
@@ -274,6 +309,7 @@ impl StateStoreMetrics {
             shared_buffer_to_sstable_size,
 
             compaction_upload_sst_counts,
+            compaction_ttl_reclaimed_bytes,
         }
     }
 
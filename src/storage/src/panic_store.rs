@@ -87,6 +87,17 @@ impl StateStore for PanicStateStore {
         }
     }
 
+    fn delete_range(
+        &self,
+        _start_key: Bytes,
+        _end_key: Bytes,
+        _epoch: u64,
+    ) -> Self::DeleteRangeFuture<'_> {
+        async move {
+            panic!("should not delete range from the state store!");
+        }
+    }
+
     fn iter<R, B>(&self, _key_range: R, _epoch: u64) -> Self::IterFuture<'_, R, B>
     where
         R: RangeBounds<B> + Send,
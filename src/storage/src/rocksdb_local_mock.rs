@@ -101,6 +101,15 @@ impl StateStore for RocksDBStateStore {
         async move { unimplemented!() }
     }
 
+    fn delete_range(
+        &self,
+        _start_key: Bytes,
+        _end_key: Bytes,
+        _epoch: u64,
+    ) -> Self::DeleteRangeFuture<'_> {
+        async move { unimplemented!() }
+    }
+
     fn wait_epoch(&self, _epoch: u64) -> Self::WaitEpochFuture<'_> {
         async move { unimplemented!() }
     }
@@ -35,6 +35,7 @@ macro_rules! define_state_store_associated_type {
         type ReverseScanFuture<'a, R, B> = impl ScanFutureTrait<'a, R, B> where R: 'static + Send, B: 'static + Send;
         type IngestBatchFuture<'a> = impl IngestBatchFutureTrait<'a>;
         type ReplicateBatchFuture<'a> = impl EmptyFutureTrait<'a>;
+        type DeleteRangeFuture<'a> = impl EmptyFutureTrait<'a>;
         type WaitEpochFuture<'a> = impl EmptyFutureTrait<'a>;
         type SyncFuture<'a> = impl EmptyFutureTrait<'a>;
         type IterFuture<'a, R, B> = impl Future<Output = $crate::error::StorageResult<Self::Iter<'a>>> + Send where R: 'static + Send, B: 'static + Send;
@@ -63,6 +64,8 @@ pub trait StateStore: Send + Sync + 'static + Clone {
 
     type ReplicateBatchFuture<'a>: EmptyFutureTrait<'a>;
 
+    type DeleteRangeFuture<'a>: EmptyFutureTrait<'a>;
+
     type WaitEpochFuture<'a>: EmptyFutureTrait<'a>;
 
     type SyncFuture<'a>: EmptyFutureTrait<'a>;
@@ -128,6 +131,17 @@ pub trait StateStore: Send + Sync + 'static + Clone {
         epoch: u64,
     ) -> Self::ReplicateBatchFuture<'_>;
 
+    /// Deletes all keys in `[start_key, end_key)` with a range tombstone, without reading them.
+    /// Like `ingest_batch`, the deletion becomes visible atomically with other writes of the
+    /// same `epoch`. Keys written in `epoch` itself are not affected by the deletion, no matter
+    /// whether they are ingested before or after it.
+    fn delete_range(
+        &self,
+        start_key: Bytes,
+        end_key: Bytes,
+        epoch: u64,
+    ) -> Self::DeleteRangeFuture<'_>;
+
     /// Opens and returns an iterator for given `key_range`.
     /// The returned iterator will iterate data based on a snapshot corresponding to the given
     /// `epoch`.
@@ -157,6 +171,11 @@ pub trait StateStore: Send + Sync + 'static + Clone {
     /// Otherwise, only data of the provided epoch will be synced.
     fn sync(&self, epoch: Option<u64>) -> Self::SyncFuture<'_>;
 
+    /// Registers the length of bloom keys in the keyspace of `keyspace_prefix`, so that scans
+    /// within one bloom key can be filtered by prefix bloom filters. `bloom_key_len` includes the
+    /// keyspace prefix. State stores without prefix bloom filters ignore it.
+    fn register_bloom_key(&self, _keyspace_prefix: &[u8], _bloom_key_len: usize) {}
+
     /// Creates a [`MonitoredStateStore`] from this state store, with given `stats`.
     fn monitored(self, stats: Arc<StateStoreMetrics>) -> MonitoredStateStore<Self> {
         MonitoredStateStore::new(self, stats)
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use bytes::Bytes;
+use risingwave_hummock_sdk::key::next_key;
 
 use crate::error::StorageResult;
 use crate::hummock::HummockError;
@@ -24,6 +25,9 @@ pub struct WriteBatch<S: StateStore> {
     store: S,
 
     batch: Vec<(Bytes, StorageValue)>,
+
+    /// Key ranges `[start, end)` to be deleted by range tombstones.
+    delete_ranges: Vec<(Bytes, Bytes)>,
 }
 
 impl<S> WriteBatch<S>
@@ -35,6 +39,7 @@ where
         Self {
             store,
             batch: Vec::new(),
+            delete_ranges: Vec::new(),
         }
     }
 
@@ -43,6 +48,7 @@ where
         Self {
             store,
             batch: Vec::with_capacity(capacity),
+            delete_ranges: Vec::new(),
         }
     }
 
@@ -74,20 +80,35 @@ where
         }
     }
 
-    /// Returns `true` if the batch contains no key-value pairs.
+    /// Returns `true` if the batch contains no key-value pairs and no range deletions.
     pub fn is_empty(&self) -> bool {
-        self.batch.is_empty()
+        self.batch.is_empty() && self.delete_ranges.is_empty()
+    }
+
+    /// Deletes all keys in `[start_key, end_key)` when the batch is ingested. Keys put in the same
+    /// batch are not affected.
+    pub fn delete_range(&mut self, start_key: impl Into<Bytes>, end_key: impl Into<Bytes>) {
+        self.delete_ranges.push((start_key.into(), end_key.into()));
     }
 
     /// Ingests this batch into the associated state store.
     pub async fn ingest(mut self, epoch: u64) -> StorageResult<()> {
         self.preprocess()?;
-        self.store.ingest_batch(self.batch, epoch).await?;
+        if !self.batch.is_empty() {
+            self.store.ingest_batch(self.batch, epoch).await?;
+        }
+        for (start_key, end_key) in self.delete_ranges {
+            self.store.delete_range(start_key, end_key, epoch).await?;
+        }
         Ok(())
     }
 
     /// Ingests this batch into the associated state store, without being persisted.
     pub async fn replicate_remote(mut self, epoch: u64) -> StorageResult<()> {
+        assert!(
+            self.delete_ranges.is_empty(),
+            "range deletion cannot be replicated"
+        );
         self.preprocess()?;
         self.store.replicate_batch(self.batch, epoch).await?;
         Ok(())
@@ -146,6 +167,20 @@ impl<'a, S: StateStore> KeySpaceWriteBatch<'a, S> {
         self.do_push(Some(key.as_ref()), StorageValue::new_default_delete());
     }
 
+    /// Deletes all keys in `[prefix | start_key, prefix | end_key)`.
+    pub fn delete_range(&mut self, start_key: impl AsRef<[u8]>, end_key: impl AsRef<[u8]>) {
+        let start_key = self.keyspace.prefixed_key(start_key);
+        let end_key = self.keyspace.prefixed_key(end_key);
+        self.global.delete_range(start_key, end_key);
+    }
+
+    /// Deletes all keys in the keyspace, like `[prefix, next prefix)`.
+    pub fn delete_all(&mut self) {
+        let start_key = self.keyspace.key().to_vec();
+        let end_key = next_key(&start_key);
+        self.global.delete_range(start_key, end_key);
+    }
+
     /// Same as `delete`, except that value meta is specified.
     pub fn delete_with_value_meta(&mut self, key: impl AsRef<[u8]>, value_meta: ValueMeta) {
         self.do_push(Some(key.as_ref()), StorageValue::new_delete(value_meta));
@@ -179,4 +214,32 @@ mod tests {
             .await
             .expect_err("Should panic here because of duplicate key.");
     }
+
+    #[tokio::test]
+    async fn test_delete_range() {
+        let state_store = MemoryStateStore::new();
+        let key_space = Keyspace::executor_root(state_store.clone(), 0x118);
+        let other_key_space = Keyspace::executor_root(state_store.clone(), 0x119);
+
+        let mut write_batch = WriteBatch::new(state_store.clone());
+        let mut key_space_batch = write_batch.prefixify(&key_space);
+        key_space_batch.put(Bytes::from("aa"), StorageValue::new_default_put("111"));
+        key_space_batch.put(Bytes::from("bb"), StorageValue::new_default_put("222"));
+        let mut other_key_space_batch = write_batch.prefixify(&other_key_space);
+        other_key_space_batch.put(Bytes::from("aa"), StorageValue::new_default_put("333"));
+        write_batch.ingest(1).await.unwrap();
+
+        let mut write_batch = WriteBatch::new(state_store.clone());
+        let mut key_space_batch = write_batch.prefixify(&key_space);
+        key_space_batch.delete_all();
+        key_space_batch.put(Bytes::from("cc"), StorageValue::new_default_put("444"));
+        write_batch.ingest(2).await.unwrap();
+
+        assert_eq!(key_space.scan(None, 1).await.unwrap().len(), 2);
+        assert_eq!(
+            key_space.scan_strip_prefix(None, 2).await.unwrap(),
+            vec![(Bytes::from("cc"), Bytes::from("444"))]
+        );
+        assert_eq!(other_key_space.scan(None, 2).await.unwrap().len(), 1);
+    }
 }
@@ -16,6 +16,8 @@ use std::sync::Arc;
 
 use futures::pin_mut;
 use risingwave_common::error::Result;
+use risingwave_common::util::epoch::INVALID_EPOCH;
+use risingwave_expr::expr::{set_now_epoch, with_now_epoch};
 use tokio_stream::StreamExt;
 use tracing_futures::Instrument;
 
@@ -44,6 +46,12 @@ where
     }
 
     pub async fn run(self) -> Result<()> {
+        // `now()` is evaluated to the time of the latest barrier, which is always the first
+        // message received by an actor.
+        with_now_epoch(INVALID_EPOCH, self.run_consumer()).await
+    }
+
+    async fn run_consumer(self) -> Result<()> {
         let span_name = format!("actor_poll_{:03}", self.id);
         let mut span = tracing::trace_span!(
             "actor_poll",
@@ -59,6 +67,8 @@ where
 
         // Drive the streaming task with an infinite loop
         while let Some(barrier) = stream.next().instrument(span).await.transpose()? {
+            set_now_epoch(barrier.epoch.curr);
+
             // Collect barriers to local barrier manager
             self.context
                 .lock_barrier_manager()
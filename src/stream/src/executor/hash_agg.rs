@@ -40,6 +40,7 @@ struct HashAggExecutorDispatcherArgs<S: StateStore> {
     pk_indices: PkIndices,
     executor_id: u64,
     op_info: String,
+    emit_on_window_close: bool,
 }
 
 impl<S: StateStore> HashKeyDispatcher for HashAggExecutorDispatcher<S> {
@@ -55,6 +56,7 @@ impl<S: StateStore> HashKeyDispatcher for HashAggExecutorDispatcher<S> {
             args.pk_indices,
             args.executor_id,
             args.op_info,
+            args.emit_on_window_close,
         )?
         .boxed())
     }
@@ -101,6 +103,7 @@ impl ExecutorBuilder for HashAggExecutorBuilder {
             pk_indices: params.pk_indices,
             executor_id: params.executor_id,
             op_info: params.op_info,
+            emit_on_window_close: node.emit_on_window_close,
         };
         HashAggExecutorDispatcher::dispatch_by_kind(kind, args)
     }
@@ -61,6 +61,7 @@ impl StreamConsumer for MockConsumer {
                 match msg {
                     Message::Chunk(chunk) => self.data.lock().unwrap().push(chunk),
                     Message::Barrier(barrier) => yield barrier,
+                    Message::Watermark(_) => {}
                 }
             }
         }
@@ -86,11 +87,13 @@ async fn test_merger_sum_aggr() {
                     kind: AggKind::RowCount,
                     args: AggArgs::None,
                     return_type: DataType::Int64,
+                    distinct: false,
                 },
                 AggCall {
                     kind: AggKind::Sum,
                     args: AggArgs::Unary(DataType::Int64, 0),
                     return_type: DataType::Int64,
+                    distinct: false,
                 },
             ],
             vec![],
@@ -155,11 +158,13 @@ async fn test_merger_sum_aggr() {
                 kind: AggKind::Sum,
                 args: AggArgs::Unary(DataType::Int64, 0),
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Sum,
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
+                distinct: false,
             },
         ],
         create_in_memory_keyspace(),
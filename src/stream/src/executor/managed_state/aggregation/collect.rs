@@ -0,0 +1,312 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use itertools::Itertools;
+use risingwave_common::array::stream_chunk::{Op, Ops};
+use risingwave_common::array::{ArrayImpl, ListValue, Row};
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
+use risingwave_common::util::ordered::OrderedArraysSerializer;
+use risingwave_common::util::sort_util::{OrderPair, OrderType};
+use risingwave_common::util::value_encoding::{deserialize_cell, serialize_cell};
+use risingwave_expr::expr::AggKind;
+use risingwave_storage::storage_value::StorageValue;
+use risingwave_storage::write_batch::WriteBatch;
+use risingwave_storage::{Keyspace, StateStore};
+
+use crate::executor::managed_state::aggregation::ManagedTableState;
+use crate::executor::managed_state::flush_status::BtreeMapFlushStatus as FlushStatus;
+
+/// Managed state for aggregations collecting all the rows of a group, i.e. `ARRAY_AGG` and
+/// `STRING_AGG`. The arguments of each row are stored under the primary key of the row, and
+/// collected in the order of the primary keys.
+///
+/// Like `ManagedStringAggState`, the cache follows an all-or-nothing policy: all the rows are
+/// loaded into memory on the first access after recovery.
+pub struct ManagedCollectState<S: StateStore> {
+    /// `ArrayAgg` or `StringAgg`.
+    kind: AggKind,
+
+    /// Types of the arguments.
+    arg_types: Vec<DataType>,
+
+    /// All the rows in the state, if `is_cache_loaded`.
+    cache: BTreeMap<Bytes, Row>,
+
+    /// Whether all the rows in the state store are loaded into the cache.
+    is_cache_loaded: bool,
+
+    /// Modifications not flushed to the state store.
+    flush_buffer: BTreeMap<Bytes, FlushStatus<Row>>,
+
+    /// Number of rows in the state.
+    total_count: usize,
+
+    /// The keyspace to operate on.
+    keyspace: Keyspace<S>,
+
+    /// Serializer of the primary keys, which follow the arguments in the data.
+    pk_serializer: OrderedArraysSerializer,
+}
+
+impl<S: StateStore> ManagedCollectState<S> {
+    /// Create a managed collect state based on `Keyspace`, where `pk_length` columns of the primary
+    /// key follow the arguments in the data.
+    pub fn new(
+        kind: AggKind,
+        arg_types: Vec<DataType>,
+        keyspace: Keyspace<S>,
+        row_count: usize,
+        pk_length: usize,
+    ) -> Self {
+        let order_pairs = (arg_types.len()..arg_types.len() + pk_length)
+            .map(|idx| OrderPair::new(idx, OrderType::Ascending))
+            .collect();
+        Self {
+            kind,
+            arg_types,
+            cache: BTreeMap::new(),
+            is_cache_loaded: row_count == 0,
+            flush_buffer: BTreeMap::new(),
+            total_count: row_count,
+            keyspace,
+            pk_serializer: OrderedArraysSerializer::new(order_pairs),
+        }
+    }
+
+    async fn load_cache(&mut self, epoch: u64) -> Result<()> {
+        if self.is_cache_loaded {
+            return Ok(());
+        }
+        debug_assert!(!self.is_dirty());
+        for (key, value) in self.keyspace.scan_strip_prefix(None, epoch).await? {
+            let mut deserializer = value_encoding::Deserializer::new(value);
+            let row = self
+                .arg_types
+                .iter()
+                .map(|ty| deserialize_cell(&mut deserializer, ty))
+                .try_collect()?;
+            self.cache.insert(key, Row(row));
+        }
+        self.is_cache_loaded = true;
+        Ok(())
+    }
+
+    fn serialize_row(row: &Row) -> Result<Vec<u8>> {
+        let mut value = vec![];
+        for datum in &row.0 {
+            value.extend(serialize_cell(datum)?);
+        }
+        Ok(value)
+    }
+
+    fn collect(&self) -> Datum {
+        if self.cache.is_empty() {
+            return None;
+        }
+        match self.kind {
+            AggKind::ArrayAgg => {
+                Some(ListValue::new(self.cache.values().map(|row| row[0].clone()).collect()).into())
+            }
+            AggKind::StringAgg => {
+                let mut result: Option<String> = None;
+                for row in self.cache.values() {
+                    if let Some(value) = &row[0] {
+                        let value = value.as_utf8();
+                        match &mut result {
+                            Some(result) => {
+                                if let Some(delimiter) = &row[1] {
+                                    result.push_str(delimiter.as_utf8());
+                                }
+                                result.push_str(value);
+                            }
+                            None => result = Some(value.clone()),
+                        }
+                    }
+                }
+                result.map(ScalarImpl::Utf8)
+            }
+            _ => unreachable!("unsupported collect agg: {:?}", self.kind),
+        }
+    }
+}
+
+#[async_trait]
+impl<S: StateStore> ManagedTableState<S> for ManagedCollectState<S> {
+    async fn apply_batch(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &[&ArrayImpl],
+        epoch: u64,
+    ) -> Result<()> {
+        debug_assert!(super::verify_batch(ops, visibility, data));
+        self.load_cache(epoch).await?;
+
+        let mut row_keys = vec![];
+        self.pk_serializer.serialize(data, &mut row_keys);
+
+        for (row_idx, (op, key)) in ops.iter().zip_eq(row_keys.into_iter()).enumerate() {
+            if let Some(visibility) = visibility && !visibility.is_set(row_idx)? {
+                continue;
+            }
+            let key = Bytes::from(key);
+            match op {
+                Op::Insert | Op::UpdateInsert => {
+                    let row = Row((0..self.arg_types.len())
+                        .map(|idx| data[idx].datum_at(row_idx))
+                        .collect());
+                    self.cache.insert(key.clone(), row.clone());
+                    FlushStatus::do_insert(self.flush_buffer.entry(key), row);
+                    self.total_count += 1;
+                }
+                Op::Delete | Op::UpdateDelete => {
+                    self.cache.remove(&key);
+                    FlushStatus::do_delete(self.flush_buffer.entry(key));
+                    self.total_count -= 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn get_output(&mut self, epoch: u64) -> Result<Datum> {
+        self.load_cache(epoch).await?;
+        Ok(self.collect())
+    }
+
+    fn is_dirty(&self) -> bool {
+        !self.flush_buffer.is_empty()
+    }
+
+    fn flush(&mut self, write_batch: &mut WriteBatch<S>) -> Result<()> {
+        let mut local = write_batch.prefixify(&self.keyspace);
+        for (key, status) in std::mem::take(&mut self.flush_buffer) {
+            match status.into_option() {
+                // TODO(Yuanxin): Implement value meta
+                Some(row) => local.put(
+                    key,
+                    StorageValue::new_default_put(Self::serialize_row(&row)?),
+                ),
+                None => local.delete(key),
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{I64Array, Utf8Array};
+
+    use super::*;
+    use crate::executor::test_utils::create_in_memory_keyspace;
+
+    #[tokio::test]
+    async fn test_managed_collect_state() {
+        let keyspace = create_in_memory_keyspace();
+        let store = keyspace.state_store();
+        let mut managed_state = ManagedCollectState::new(
+            AggKind::StringAgg,
+            vec![DataType::Varchar, DataType::Varchar],
+            keyspace.clone(),
+            0,
+            1,
+        );
+        let epoch: u64 = 0;
+
+        // the rows are collected in the order of the primary keys
+        managed_state
+            .apply_batch(
+                &[Op::Insert, Op::Insert, Op::Insert, Op::Insert],
+                None,
+                &[
+                    &Utf8Array::from_slice(&[Some("c"), Some("a"), None, Some("b")])
+                        .unwrap()
+                        .into(),
+                    &Utf8Array::from_slice(&[Some(","), Some(","), Some(","), Some("|")])
+                        .unwrap()
+                        .into(),
+                    &I64Array::from_slice(&[Some(3), Some(1), Some(4), Some(2)])
+                        .unwrap()
+                        .into(),
+                ],
+                epoch,
+            )
+            .await
+            .unwrap();
+        let mut write_batch = store.start_write_batch();
+        managed_state.flush(&mut write_batch).unwrap();
+        write_batch.ingest(epoch).await.unwrap();
+        assert_eq!(
+            managed_state.get_output(epoch).await.unwrap(),
+            Some(ScalarImpl::Utf8("a|b,c".to_string()))
+        );
+
+        // recover the state and delete a row
+        let row_count = managed_state.total_count;
+        let mut managed_state = ManagedCollectState::new(
+            AggKind::StringAgg,
+            vec![DataType::Varchar, DataType::Varchar],
+            keyspace.clone(),
+            row_count,
+            1,
+        );
+        managed_state
+            .apply_batch(
+                &[Op::Delete],
+                None,
+                &[
+                    &Utf8Array::from_slice(&[Some("a")]).unwrap().into(),
+                    &Utf8Array::from_slice(&[Some(",")]).unwrap().into(),
+                    &I64Array::from_slice(&[Some(1)]).unwrap().into(),
+                ],
+                epoch,
+            )
+            .await
+            .unwrap();
+        let mut write_batch = store.start_write_batch();
+        managed_state.flush(&mut write_batch).unwrap();
+        write_batch.ingest(epoch).await.unwrap();
+        assert_eq!(
+            managed_state.get_output(epoch).await.unwrap(),
+            Some(ScalarImpl::Utf8("b,c".to_string()))
+        );
+
+        // collect the values as an array, including nulls
+        let mut managed_state = ManagedCollectState::new(
+            AggKind::ArrayAgg,
+            vec![DataType::Varchar, DataType::Varchar],
+            keyspace,
+            row_count - 1,
+            1,
+        );
+        assert_eq!(
+            managed_state.get_output(epoch).await.unwrap(),
+            Some(
+                ListValue::new(vec![
+                    Some(ScalarImpl::Utf8("b".to_string())),
+                    Some(ScalarImpl::Utf8("c".to_string())),
+                    None,
+                ])
+                .into()
+            )
+        );
+    }
+}
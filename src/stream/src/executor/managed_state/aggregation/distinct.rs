@@ -0,0 +1,260 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{HashMap, HashSet};
+
+use itertools::Itertools;
+use risingwave_common::array::stream_chunk::{Op, Ops};
+use risingwave_common::array::{ArrayImpl, Row};
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::error::Result;
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
+use risingwave_common::util::value_encoding::{deserialize_cell_not_null, serialize_cell_not_null};
+use risingwave_storage::storage_value::StorageValue;
+use risingwave_storage::write_batch::WriteBatch;
+use risingwave_storage::{Keyspace, StateStore};
+
+use super::ManagedValueState;
+
+/// The prefix of the dedup table under the keyspace of the aggregation state, which is only used
+/// as a single value by [`ManagedValueState`].
+const DISTINCT_PREFIX: u8 = 1;
+
+/// Managed state for aggregations on distinct values, e.g. `COUNT(DISTINCT v)`. It keeps a dedup
+/// table mapping each value to the number of its occurrences in the state store, so that only the
+/// first insertion and the last deletion of a value are applied to the inner state.
+pub struct ManagedDistinctState<S: StateStore> {
+    /// The state of the aggregation on the deduplicated values.
+    inner: ManagedValueState<S>,
+
+    /// Cached counts of the values, fetched from the state store on demand.
+    cache: HashMap<ScalarImpl, i64>,
+
+    /// The values whose counts are modified since the last flush.
+    dirty_values: HashSet<ScalarImpl>,
+
+    /// The keyspace of the dedup table.
+    keyspace: Keyspace<S>,
+}
+
+impl<S: StateStore> ManagedDistinctState<S> {
+    /// Create a distinct managed state wrapping `inner`, whose dedup table is stored under
+    /// `keyspace`.
+    pub fn new(inner: ManagedValueState<S>, keyspace: &Keyspace<S>) -> Self {
+        Self {
+            inner,
+            cache: HashMap::new(),
+            dirty_values: HashSet::new(),
+            keyspace: keyspace.append_u8(DISTINCT_PREFIX),
+        }
+    }
+
+    fn serialize_value(value: &ScalarImpl) -> Result<Vec<u8>> {
+        Row(vec![Some(value.clone())]).serialize()
+    }
+
+    async fn get_count(&mut self, value: &ScalarImpl, epoch: u64) -> Result<i64> {
+        if let Some(count) = self.cache.get(value) {
+            return Ok(*count);
+        }
+        let count = match self
+            .keyspace
+            .get(Self::serialize_value(value)?, epoch)
+            .await?
+        {
+            Some(raw_data) => {
+                let mut deserializer = value_encoding::Deserializer::new(raw_data);
+                *deserialize_cell_not_null(&mut deserializer, DataType::Int64)?
+                    .unwrap()
+                    .as_int64()
+            }
+            None => 0,
+        };
+        self.cache.insert(value.clone(), count);
+        Ok(count)
+    }
+
+    /// Apply a batch of data to the state. The first array of `data` is the argument of the
+    /// aggregation, and the rows whose values are not newly present or absent are hidden from the
+    /// inner state.
+    pub async fn apply_batch(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &[&ArrayImpl],
+        epoch: u64,
+    ) -> Result<()> {
+        debug_assert!(super::verify_batch(ops, visibility, data));
+        let mut distinct_visibility = Vec::with_capacity(ops.len());
+        for (idx, op) in ops.iter().enumerate() {
+            if let Some(visibility) = visibility && !visibility.is_set(idx)? {
+                distinct_visibility.push(false);
+                continue;
+            }
+            // Nulls are ignored by the inner aggregations anyway.
+            let value = match data[0].datum_at(idx) {
+                Some(value) => value,
+                None => {
+                    distinct_visibility.push(true);
+                    continue;
+                }
+            };
+            let count = self.get_count(&value, epoch).await?;
+            let (new_count, visible) = match op {
+                Op::Insert | Op::UpdateInsert => (count + 1, count == 0),
+                Op::Delete | Op::UpdateDelete => (count - 1, count == 1),
+            };
+            distinct_visibility.push(visible);
+            self.cache.insert(value.clone(), new_count);
+            self.dirty_values.insert(value);
+        }
+        let distinct_visibility = Bitmap::try_from(distinct_visibility)?;
+        self.inner
+            .apply_batch(ops, Some(&distinct_visibility), data)
+            .await
+    }
+
+    /// Get the output of the state. Must flush before getting output.
+    pub async fn get_output(&mut self) -> Result<Datum> {
+        self.inner.get_output().await
+    }
+
+    /// Check if this state needs a flush.
+    pub fn is_dirty(&self) -> bool {
+        self.inner.is_dirty()
+    }
+
+    /// Flush the internal state and the modified counts to a write batch.
+    pub fn flush(&mut self, write_batch: &mut WriteBatch<S>) -> Result<()> {
+        self.inner.flush(write_batch)?;
+
+        let mut local = write_batch.prefixify(&self.keyspace);
+        for value in self.dirty_values.drain().sorted() {
+            let key = Self::serialize_value(&value)?;
+            let count = self.cache[&value];
+            if count == 0 {
+                local.delete(key);
+                self.cache.remove(&value);
+            } else {
+                local.put(
+                    key,
+                    StorageValue::new_default_put(serialize_cell_not_null(&Some(
+                        ScalarImpl::Int64(count),
+                    ))?),
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{I64Array, Op};
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_expr::expr::AggKind;
+
+    use super::*;
+    use crate::executor::test_utils::create_in_memory_keyspace;
+    use crate::executor_v2::aggregation::{AggArgs, AggCall};
+
+    fn create_test_count_distinct_state() -> AggCall {
+        AggCall {
+            kind: AggKind::Count,
+            args: AggArgs::Unary(DataType::Int64, 0),
+            return_type: DataType::Int64,
+            distinct: true,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_managed_distinct_state() {
+        let keyspace = create_in_memory_keyspace();
+        let store = keyspace.state_store();
+        let inner = ManagedValueState::new(
+            create_test_count_distinct_state(),
+            keyspace.clone(),
+            Some(0),
+        )
+        .await
+        .unwrap();
+        let mut managed_state = ManagedDistinctState::new(inner, &keyspace);
+        let epoch: u64 = 0;
+
+        // apply a batch with duplicated values and get the output
+        managed_state
+            .apply_batch(
+                &[Op::Insert, Op::Insert, Op::Insert, Op::Insert],
+                None,
+                &[&I64Array::from_slice(&[Some(1), Some(2), Some(1), None])
+                    .unwrap()
+                    .into()],
+                epoch,
+            )
+            .await
+            .unwrap();
+        let mut write_batch = store.start_write_batch();
+        managed_state.flush(&mut write_batch).unwrap();
+        write_batch.ingest(epoch).await.unwrap();
+        assert_eq!(
+            managed_state.get_output().await.unwrap(),
+            Some(ScalarImpl::Int64(2))
+        );
+
+        // deleting one of the duplicated values does not change the output
+        managed_state
+            .apply_batch(
+                &[Op::Delete, Op::Delete],
+                None,
+                &[&I64Array::from_slice(&[Some(1), Some(2)]).unwrap().into()],
+                epoch,
+            )
+            .await
+            .unwrap();
+        let mut write_batch = store.start_write_batch();
+        managed_state.flush(&mut write_batch).unwrap();
+        write_batch.ingest(epoch).await.unwrap();
+        assert_eq!(
+            managed_state.get_output().await.unwrap(),
+            Some(ScalarImpl::Int64(1))
+        );
+
+        // the counts are recovered from the state store
+        let epoch = epoch + 1;
+        let inner = ManagedValueState::new(
+            create_test_count_distinct_state(),
+            keyspace.clone(),
+            Some(1),
+        )
+        .await
+        .unwrap();
+        let mut managed_state = ManagedDistinctState::new(inner, &keyspace);
+        managed_state
+            .apply_batch(
+                &[Op::Insert, Op::Delete],
+                None,
+                &[&I64Array::from_slice(&[Some(2), Some(1)]).unwrap().into()],
+                epoch,
+            )
+            .await
+            .unwrap();
+        let mut write_batch = store.start_write_batch();
+        managed_state.flush(&mut write_batch).unwrap();
+        write_batch.ingest(epoch).await.unwrap();
+        assert_eq!(
+            managed_state.get_output().await.unwrap(),
+            Some(ScalarImpl::Int64(1))
+        );
+    }
+}
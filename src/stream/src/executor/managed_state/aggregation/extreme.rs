@@ -445,7 +445,7 @@ pub async fn create_streaming_extreme_state<S: StateStore>(
 
             match (agg_call.kind, agg_call.return_type.clone()) {
                 $(
-                    (AggKind::Max, $( $kind )|+) => Ok(Box::new(
+                    (AggKind::Max | AggKind::BoolOr, $( $kind )|+) => Ok(Box::new(
                         ManagedMaxState::<_, $array>::new(
                             keyspace,
                             agg_call.return_type.clone(),
@@ -455,7 +455,7 @@ pub async fn create_streaming_extreme_state<S: StateStore>(
                             key_hash_code.unwrap_or_default()
                         ).await?,
                     )),
-                    (AggKind::Min, $( $kind )|+) => Ok(Box::new(
+                    (AggKind::Min | AggKind::BoolAnd, $( $kind )|+) => Ok(Box::new(
                         ManagedMinState::<_, $array>::new(
                             keyspace,
                             agg_call.return_type.clone(),
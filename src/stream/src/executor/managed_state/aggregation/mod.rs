@@ -14,6 +14,8 @@
 
 //! Aggregators with state store support
 
+pub use collect::*;
+pub use distinct::*;
 pub use extreme::*;
 use risingwave_common::array::stream_chunk::Ops;
 use risingwave_common::array::ArrayImpl;
@@ -29,6 +31,8 @@ pub use value::*;
 use super::super::PkDataTypes;
 use crate::executor_v2::aggregation::AggCall;
 
+mod collect;
+mod distinct;
 mod extreme;
 mod extreme_serializer;
 mod string_agg;
@@ -58,6 +62,9 @@ pub enum ManagedStateImpl<S: StateStore> {
 
     /// States as table structure e.g. `MAX`, `STRING_AGG`
     Table(Box<dyn ManagedTableState<S>>),
+
+    /// States of aggregations on distinct values e.g. `COUNT(DISTINCT)`, `SUM(DISTINCT)`
+    Distinct(Box<ManagedDistinctState<S>>),
 }
 
 impl<S: StateStore> ManagedStateImpl<S> {
@@ -71,6 +78,7 @@ impl<S: StateStore> ManagedStateImpl<S> {
         match self {
             Self::Value(state) => state.apply_batch(ops, visibility, data).await,
             Self::Table(state) => state.apply_batch(ops, visibility, data, epoch).await,
+            Self::Distinct(state) => state.apply_batch(ops, visibility, data, epoch).await,
         }
     }
 
@@ -79,6 +87,7 @@ impl<S: StateStore> ManagedStateImpl<S> {
         match self {
             Self::Value(state) => state.get_output().await,
             Self::Table(state) => state.get_output(epoch).await,
+            Self::Distinct(state) => state.get_output().await,
         }
     }
 
@@ -87,6 +96,7 @@ impl<S: StateStore> ManagedStateImpl<S> {
         match self {
             Self::Value(state) => state.is_dirty(),
             Self::Table(state) => state.is_dirty(),
+            Self::Distinct(state) => state.is_dirty(),
         }
     }
 
@@ -95,6 +105,7 @@ impl<S: StateStore> ManagedStateImpl<S> {
         match self {
             Self::Value(state) => state.flush(write_batch),
             Self::Table(state) => state.flush(write_batch),
+            Self::Distinct(state) => state.flush(write_batch),
         }
     }
 
@@ -108,7 +119,9 @@ impl<S: StateStore> ManagedStateImpl<S> {
         key_hash_code: Option<HashCode>,
     ) -> Result<Self> {
         match agg_call.kind {
-            AggKind::Max | AggKind::Min => {
+            // `MAX(DISTINCT)` and `MIN(DISTINCT)` are the same as `MAX` and `MIN`, and so are
+            // `BOOL_AND` and `BOOL_OR`, which are `MIN` and `MAX` of booleans.
+            AggKind::Max | AggKind::Min | AggKind::BoolAnd | AggKind::BoolOr => {
                 assert!(
                     row_count.is_some(),
                     "should set row_count for value states other than AggKind::RowCount"
@@ -126,14 +139,27 @@ impl<S: StateStore> ManagedStateImpl<S> {
                     .await?,
                 ))
             }
-            AggKind::StringAgg => {
-                // TODO, It seems with `order by`, `StringAgg` needs more stuff from `AggCall`
-                Err(ErrorCode::NotImplemented(
-                    "It seems with `order by`, `StringAgg` needs more stuff from `AggCall`"
-                        .to_string(),
-                    None.into(),
-                )
-                .into())
+            // TODO: support `ORDER BY` in `STRING_AGG` and `ARRAY_AGG`, which are now ordered by
+            // the primary key of the input.
+            AggKind::StringAgg | AggKind::ArrayAgg => {
+                assert!(
+                    row_count.is_some(),
+                    "should set row_count for value states other than AggKind::RowCount"
+                );
+                if agg_call.distinct {
+                    return Err(ErrorCode::NotImplemented(
+                        format!("{}(distinct)", agg_call.kind),
+                        None.into(),
+                    )
+                    .into());
+                }
+                Ok(Self::Table(Box::new(ManagedCollectState::new(
+                    agg_call.kind,
+                    agg_call.args.arg_types().to_vec(),
+                    keyspace,
+                    row_count.unwrap(),
+                    pk_data_types.len(),
+                ))))
             }
             // TODO: for append-only lists, we can create `ManagedValueState` instead of
             // `ManagedExtremeState`.
@@ -142,6 +168,21 @@ impl<S: StateStore> ManagedStateImpl<S> {
                     is_row_count || row_count.is_some(),
                     "should set row_count for value states other than AggKind::RowCount"
                 );
+                let distinct = agg_call.distinct;
+                let state = ManagedValueState::new(agg_call, keyspace.clone(), row_count).await?;
+                if distinct {
+                    Ok(Self::Distinct(Box::new(ManagedDistinctState::new(
+                        state, &keyspace,
+                    ))))
+                } else {
+                    Ok(Self::Value(state))
+                }
+            }
+            AggKind::ApproxCountDistinct | AggKind::ApproxCountDistinctMerge => {
+                assert!(
+                    row_count.is_some(),
+                    "should set row_count for value states other than AggKind::RowCount"
+                );
                 Ok(Self::Value(
                     ManagedValueState::new(agg_call, keyspace, row_count).await?,
                 ))
@@ -155,6 +196,13 @@ impl<S: StateStore> ManagedStateImpl<S> {
             AggKind::SingleValue => Ok(Self::Value(
                 ManagedValueState::new(agg_call, keyspace, row_count).await?,
             )),
+            AggKind::StddevPop | AggKind::StddevSamp | AggKind::VarPop | AggKind::VarSamp => {
+                Err(ErrorCode::InternalError(format!(
+                    "{} should be rewritten by the planner",
+                    agg_call.kind
+                ))
+                .into())
+            }
         }
     }
 }
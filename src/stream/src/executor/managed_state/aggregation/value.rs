@@ -55,7 +55,7 @@ impl<S: StateStore> ManagedValueState<S> {
             // Decode the Datum from the value.
             if let Some(raw_data) = raw_data {
                 let mut deserializer = value_encoding::Deserializer::new(raw_data);
-                Some(deserialize_cell(&mut deserializer, &agg_call.return_type)?)
+                Some(deserialize_cell(&mut deserializer, &agg_call.state_type())?)
             } else {
                 None
             }
@@ -108,7 +108,7 @@ impl<S: StateStore> ManagedValueState<S> {
         debug_assert!(self.is_dirty());
 
         let mut local = write_batch.prefixify(&self.keyspace);
-        let v = self.state.get_output()?;
+        let v = self.state.get_state()?;
         // TODO(Yuanxin): Implement value meta
         local.put_single(StorageValue::new_default_put(serialize_cell(&v)?));
         self.is_dirty = false;
@@ -130,6 +130,7 @@ mod tests {
             kind: risingwave_expr::expr::AggKind::Count,
             args: AggArgs::Unary(DataType::Int64, 0),
             return_type: DataType::Int64,
+            distinct: false,
         }
     }
 
@@ -49,6 +49,9 @@ pub struct JoinEntryState<S: StateStore> {
 
     /// The keyspace to operate on.
     keyspace: Keyspace<S>,
+
+    /// The index of rows by time to maintain on flush.
+    time_index: Option<TimeIndex<S>>,
 }
 
 impl<S: StateStore> JoinEntryState<S> {
@@ -56,6 +59,7 @@ impl<S: StateStore> JoinEntryState<S> {
         keyspace: Keyspace<S>,
         data_types: Arc<[DataType]>,
         pk_data_types: Arc<[DataType]>,
+        time_index: Option<TimeIndex<S>>,
     ) -> Self {
         Self {
             cached: None,
@@ -63,6 +67,7 @@ impl<S: StateStore> JoinEntryState<S> {
             data_types,
             pk_data_types,
             keyspace,
+            time_index,
         }
     }
 
@@ -70,6 +75,7 @@ impl<S: StateStore> JoinEntryState<S> {
         keyspace: Keyspace<S>,
         data_types: Arc<[DataType]>,
         pk_data_types: Arc<[DataType]>,
+        time_index: Option<TimeIndex<S>>,
         epoch: u64,
     ) -> Result<Option<Self>> {
         let all_data = keyspace.scan_strip_prefix(None, epoch).await?;
@@ -82,6 +88,7 @@ impl<S: StateStore> JoinEntryState<S> {
                 data_types,
                 pk_data_types,
                 keyspace,
+                time_index,
             }))
         } else {
             Ok(None)
@@ -130,12 +137,16 @@ impl<S: StateStore> JoinEntryState<S> {
     // Flush data to the state store
     pub fn flush(&mut self, write_batch: &mut WriteBatch<S>) -> Result<()> {
         let mut local = write_batch.prefixify(&self.keyspace);
+        let mut index_entries = vec![];
 
         for (pk, v) in std::mem::take(&mut self.flush_buffer) {
             let key_encoded = pk.serialize_not_null()?;
 
             match v.into_option() {
                 Some(v) => {
+                    if let Some(time_index) = &self.time_index {
+                        index_entries.extend(time_index.entry(&self.keyspace, &key_encoded, &v)?);
+                    }
                     let value = v.serialize()?;
                     // TODO(Yuanxin): Implement value meta
                     local.put(key_encoded, StorageValue::new_default_put(value));
@@ -145,6 +156,13 @@ impl<S: StateStore> JoinEntryState<S> {
                 }
             }
         }
+
+        if let Some(time_index) = &self.time_index {
+            let mut local = write_batch.prefixify(&time_index.keyspace);
+            for (index_key, row_key) in index_entries {
+                local.put(index_key, StorageValue::new_default_put(row_key));
+            }
+        }
         Ok(())
     }
 
@@ -177,6 +195,34 @@ impl<S: StateStore> JoinEntryState<S> {
         Ok(())
     }
 
+    /// Removes the rows whose `time_col_idx`-th column is below `threshold`.
+    pub async fn remove_rows_below(
+        &mut self,
+        time_col_idx: usize,
+        threshold: &ScalarImpl,
+        epoch: u64,
+    ) -> Result<()> {
+        if self.cached.is_none() {
+            self.populate_cache(epoch).await?;
+        }
+        let expired_pks = self
+            .cached
+            .as_ref()
+            .unwrap()
+            .iter()
+            .filter(|(_, value)| {
+                value[time_col_idx]
+                    .as_ref()
+                    .map_or(false, |time| time < threshold)
+            })
+            .map(|(pk, _)| pk.clone())
+            .collect_vec();
+        for pk in expired_pks {
+            self.remove(pk);
+        }
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub fn clear_cache(&mut self) {
         assert!(
@@ -227,6 +273,7 @@ mod tests {
             keyspace,
             vec![DataType::Int64, DataType::Int64].into(),
             vec![DataType::Int64].into(),
+            None,
         );
         assert!(!managed_state.is_dirty());
         let columns = vec![
@@ -13,16 +13,19 @@
 // limitations under the License.
 
 mod join_entry_state;
+use std::collections::HashSet;
 use std::ops::{Deref, DerefMut, Index};
 use std::sync::Arc;
 
+use bytes::Bytes;
 use itertools::Itertools;
 pub use join_entry_state::JoinEntryState;
+use risingwave_common::array::data_chunk_iter::RowDeserializer;
 use risingwave_common::array::Row;
 use risingwave_common::collection::evictable::EvictableHashMap;
 use risingwave_common::error::Result as RwResult;
 use risingwave_common::hash::{HashKey, PrecomputedBuildHasher};
-use risingwave_common::types::{DataType, Datum};
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
 use risingwave_common::util::value_encoding::{deserialize_cell, serialize_cell};
 use risingwave_storage::{Keyspace, StateStore};
 use serde::{Deserialize, Serialize};
@@ -112,6 +115,44 @@ impl JoinRowDeserializer {
 
 type PkType = Row;
 
+/// The leading byte of the keys of [`TimeIndex`] in the keyspace of a join side. Join keys are
+/// memcomparable-encoded with a leading `0u8` or `1u8`, so the index never overlaps the rows.
+const TIME_INDEX_TAG: u8 = 0xff;
+
+/// An index of the rows of a join side by their time column, which maps `[time | join key | pk]`
+/// to `[join key | pk]`, so that the rows below a time are found by a range scan.
+///
+/// Entries are only put along with the rows. The interval join that uses the index has
+/// append-only inputs, whose rows are only deleted by [`JoinHashMap::remove_rows_below`], which
+/// deletes the entries by range as well.
+#[derive(Clone)]
+pub struct TimeIndex<S: StateStore> {
+    time_col_idx: usize,
+    /// The keyspace of the index.
+    keyspace: Keyspace<S>,
+    /// The length of the prefix of the join side's keyspace, which is stripped from the keys of
+    /// rows.
+    row_prefix_len: usize,
+}
+
+impl<S: StateStore> TimeIndex<S> {
+    /// Returns the index entry of `row`, which is stored at `pk_encoded` in `state_keyspace`. Rows
+    /// with null time are never expired, so they are not indexed.
+    fn entry(
+        &self,
+        state_keyspace: &Keyspace<S>,
+        pk_encoded: &[u8],
+        row: &JoinRow,
+    ) -> RwResult<Option<(Vec<u8>, Vec<u8>)>> {
+        let Some(time) = &row[self.time_col_idx] else {
+            return Ok(None);
+        };
+        let row_key = [&state_keyspace.key()[self.row_prefix_len..], pk_encoded].concat();
+        let index_key = [Row(vec![Some(time.clone())]).serialize()?, row_key.clone()].concat();
+        Ok(Some((index_key, row_key)))
+    }
+}
+
 pub type StateValueType = JoinRow;
 pub type HashValueType<S> = JoinEntryState<S>;
 
@@ -126,18 +167,22 @@ pub struct JoinHashMap<K: HashKey, S: StateStore> {
     pk_data_types: Arc<[DataType]>,
     /// The keyspace to operate on.
     keyspace: Keyspace<S>,
+    /// The index of rows by time, if rows are removed by time.
+    time_index: Option<TimeIndex<S>>,
     /// Current epoch
     current_epoch: u64,
 }
 
 impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
-    /// Create a [`JoinHashMap`] with the given LRU capacity.
+    /// Create a [`JoinHashMap`] with the given LRU capacity. If `time_col_idx` is given, the rows
+    /// are indexed by the column to be removed by [`JoinHashMap::remove_rows_below`].
     pub fn new(
         target_cap: usize,
         pk_indices: Vec<usize>,
         join_key_indices: Vec<usize>,
         data_types: Vec<DataType>,
         keyspace: Keyspace<S>,
+        time_col_idx: Option<usize>,
     ) -> Self {
         let pk_data_types = pk_indices
             .iter()
@@ -148,12 +193,31 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
             .map(|idx| data_types[*idx].clone())
             .collect_vec();
 
+        // All rows of a join key are scanned by the prefix of the encoded join key, which can be
+        // filtered by prefix bloom filters if the encoding has a fixed length, i.e. 1-byte null
+        // tag plus the value, for the leading fixed-size columns.
+        let bloom_key_len: usize = join_key_data_types
+            .iter()
+            .map_while(|ty| ty.memcmp_fixed_size())
+            .map(|size| size + 1)
+            .sum();
+        if bloom_key_len > 0 {
+            keyspace.register_bloom_key_len(bloom_key_len);
+        }
+
+        let time_index = time_col_idx.map(|time_col_idx| TimeIndex {
+            time_col_idx,
+            keyspace: keyspace.append_u8(TIME_INDEX_TAG),
+            row_prefix_len: keyspace.key().len(),
+        });
+
         Self {
             inner: EvictableHashMap::with_hasher(target_cap, PrecomputedBuildHasher),
             data_types: data_types.into(),
             join_key_data_types: join_key_data_types.into(),
             pk_data_types: pk_data_types.into(),
             keyspace,
+            time_index,
             current_epoch: 0,
         }
     }
@@ -227,6 +291,7 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
                         keyspace,
                         self.data_types.clone(),
                         self.pk_data_types.clone(),
+                        self.time_index.clone(),
                     );
                     self.inner.put(key.clone(), state);
                     Ok(Some(self.inner.get_mut(key).unwrap()))
@@ -262,6 +327,7 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
             keyspace,
             self.data_types.clone(),
             self.pk_data_types.clone(),
+            self.time_index.clone(),
             self.current_epoch,
         )
         .await
@@ -275,6 +341,7 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
             keyspace,
             self.data_types.clone(),
             self.pk_data_types.clone(),
+            self.time_index.clone(),
         );
         self.inner.put(key.clone(), state);
         Ok(())
@@ -292,6 +359,81 @@ impl<K: HashKey, S: StateStore> JoinHashMap<K, S> {
             Ok(self.inner.get_mut(key).unwrap())
         }
     }
+
+    /// Removes the rows whose time column is below `threshold`, from both the memory and the
+    /// remote storage. The expired rows of the states in the memory are deleted on their next
+    /// flush, while those of the evicted states are found by the time index and deleted from the
+    /// storage directly.
+    pub async fn remove_rows_below(&mut self, threshold: &ScalarImpl) -> RwResult<()> {
+        let time_index = self
+            .time_index
+            .as_ref()
+            .expect("rows can only be removed by time with a time index");
+        let epoch = self.current_epoch;
+        let mut cached_keys = HashSet::new();
+        for (key, state) in self.inner.iter_mut() {
+            state
+                .remove_rows_below(time_index.time_col_idx, threshold, epoch)
+                .await?;
+            cached_keys.insert(key.clone().deserialize(self.join_key_data_types.iter())?);
+        }
+
+        // Indexed times are not null, so they are memcomparable-encoded with a leading `1u8`, and
+        // the expired rows are exactly those indexed in `[[1u8], threshold)`.
+        let start_key = [1u8];
+        let end_key = Row(vec![Some(threshold.clone())]).serialize()?;
+        let expired_rows = self
+            .keyspace
+            .state_store()
+            .scan(
+                time_index.keyspace.prefixed_key(start_key)
+                    ..time_index.keyspace.prefixed_key(&end_key),
+                None,
+                epoch,
+            )
+            .await?;
+        let join_key_deserializer = RowDeserializer::new(self.join_key_data_types.to_vec());
+        let mut write_batch = self.keyspace.state_store().start_write_batch();
+        let mut local = write_batch.prefixify(&self.keyspace);
+        for (_, row_key) in expired_rows {
+            if !cached_keys.contains(&join_key_deserializer.deserialize(&row_key)?) {
+                local.delete(row_key);
+            }
+        }
+        write_batch
+            .prefixify(&time_index.keyspace)
+            .delete_range(start_key, end_key);
+        write_batch.ingest(epoch).await?;
+        Ok(())
+    }
+
+    /// Cleans the states of join keys whose first column is below `watermark`, from both the
+    /// memory and the remote storage. The states must have been flushed before `epoch`.
+    pub async fn clean_below(&mut self, watermark: &ScalarImpl, epoch: u64) -> RwResult<()> {
+        let mut expired_keys = vec![];
+        for (key, _) in self.inner.iter() {
+            let key_row = key.clone().deserialize(self.join_key_data_types.iter())?;
+            if key_row[0].as_ref().map_or(false, |value| value < watermark) {
+                expired_keys.push(key.clone());
+            }
+        }
+        for key in expired_keys {
+            self.inner.pop(&key);
+        }
+
+        // Join keys are memcomparable-encoded with a leading `1u8` if not null, so the expired
+        // states are exactly those in `[[1u8], watermark)`.
+        let end_key = Row(vec![Some(watermark.clone())]).serialize()?;
+        self.keyspace
+            .state_store()
+            .delete_range(
+                Bytes::from(self.keyspace.prefixed_key([1u8])),
+                Bytes::from(self.keyspace.prefixed_key(end_key)),
+                epoch,
+            )
+            .await?;
+        Ok(())
+    }
 }
 
 impl<K: HashKey, S: StateStore> Deref for JoinHashMap<K, S> {
@@ -307,3 +449,82 @@ impl<K: HashKey, S: StateStore> DerefMut for JoinHashMap<K, S> {
         &mut self.inner
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::{DataChunk, DataChunkTestExt};
+    use risingwave_common::catalog::TableId;
+    use risingwave_common::hash::Key64;
+    use risingwave_storage::memory::MemoryStateStore;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_remove_rows_below() {
+        let store = MemoryStateStore::new();
+        let keyspace = Keyspace::table_root(store.clone(), &TableId::new(0));
+        // Columns: pk, join key, time.
+        let data_types = vec![DataType::Int64, DataType::Int64, DataType::Int64];
+        let mut ht = JoinHashMap::<Key64, _>::new(
+            1,
+            vec![0],
+            vec![1],
+            data_types.clone(),
+            keyspace.clone(),
+            Some(2),
+        );
+        let flush = |ht: &mut JoinHashMap<Key64, MemoryStateStore>, epoch| {
+            let mut write_batch = store.start_write_batch();
+            for state in ht.values_mut() {
+                state.flush(&mut write_batch).unwrap();
+            }
+            write_batch.ingest(epoch)
+        };
+
+        let chunk = DataChunk::from_pretty(
+            "I I I
+             1 1 5
+             2 1 10
+             3 2 5
+             4 2 10",
+        );
+        let keys = Key64::build(&[1], &chunk).unwrap();
+        ht.update_epoch(1);
+        for (row, key) in chunk.rows().zip_eq(keys) {
+            let row: Row = row.into();
+            let pk = Row(vec![row[0].clone()]);
+            ht.get_or_init_without_cache(&key)
+                .await
+                .unwrap()
+                .insert(pk, JoinRow::new(row, 0));
+        }
+        flush(&mut ht, 1).await.unwrap();
+        // The state of join key 1 is evicted from the cache.
+        ht.evict_to_target_cap();
+        assert_eq!(ht.len(), 1);
+
+        ht.update_epoch(2);
+        ht.remove_rows_below(&ScalarImpl::Int64(8)).await.unwrap();
+        flush(&mut ht, 2).await.unwrap();
+        assert_eq!(ht.len(), 1);
+
+        // The expired rows of both join keys are deleted from the storage, along with their
+        // index entries.
+        let deserializer = JoinRowDeserializer::new(data_types);
+        let (index, rows): (Vec<_>, Vec<_>) = keyspace
+            .scan_strip_prefix(None, 2)
+            .await
+            .unwrap()
+            .into_iter()
+            .partition(|(key, _)| key[0] == TIME_INDEX_TAG);
+        let pks = rows
+            .into_iter()
+            .map(|(_, value)| deserializer.deserialize(&value).unwrap().row[0].clone())
+            .collect_vec();
+        assert_eq!(
+            pks,
+            vec![Some(ScalarImpl::Int64(2)), Some(ScalarImpl::Int64(4))]
+        );
+        assert_eq!(index.len(), 2);
+    }
+}
@@ -15,4 +15,5 @@
 pub mod aggregation;
 pub mod flush_status;
 pub mod join;
+pub mod session_window;
 pub mod top_n;
@@ -0,0 +1,248 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::ops::Bound;
+
+use risingwave_common::array::Row;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::{DataType, NaiveDateTimeWrapper, ScalarImpl};
+use risingwave_common::util::session_window::SessionGap;
+use risingwave_common::util::value_encoding::{deserialize_cell, serialize_cell};
+use risingwave_storage::storage_value::StorageValue;
+use risingwave_storage::write_batch::WriteBatch;
+use risingwave_storage::{Keyspace, StateStore};
+
+use crate::executor::managed_state::flush_status::BtreeMapFlushStatus as FlushStatus;
+
+/// The key of a row in [`SessionWindowState`], i.e. the time and the memcomparable-encoded primary
+/// key of the row.
+pub type SessionRowKey = (NaiveDateTimeWrapper, Vec<u8>);
+
+/// All rows of a partition of the session window, ordered by [`SessionRowKey`]. The rows are stored
+/// under `partition_key / time / pk`, so the rows of a partition are fully cached once loaded.
+pub struct SessionWindowState<S: StateStore> {
+    /// The full copy of the state.
+    cached: BTreeMap<SessionRowKey, Row>,
+
+    /// The actions that will be taken on next flush
+    flush_buffer: BTreeMap<SessionRowKey, FlushStatus<Row>>,
+
+    /// The keyspace of the partition.
+    keyspace: Keyspace<S>,
+}
+
+impl<S: StateStore> SessionWindowState<S> {
+    /// Loads all rows of the partition from the state store.
+    pub async fn new(
+        keyspace: Keyspace<S>,
+        data_types: &[DataType],
+        time_col_idx: usize,
+        epoch: u64,
+    ) -> Result<Self> {
+        let mut cached = BTreeMap::new();
+        for (raw_key, raw_value) in keyspace.scan_strip_prefix(None, epoch).await? {
+            let mut deserializer = value_encoding::Deserializer::new(raw_value);
+            let row = Row(data_types
+                .iter()
+                .map(|ty| deserialize_cell(&mut deserializer, ty))
+                .collect::<Result<_>>()?);
+            let time = Self::time_of(&row, time_col_idx)?;
+            let time_len = Self::serialize_time(time)?.len();
+            cached.insert((time, raw_key[time_len..].to_vec()), row);
+        }
+        Ok(Self {
+            cached,
+            flush_buffer: BTreeMap::new(),
+            keyspace,
+        })
+    }
+
+    /// Returns the time of a row, which must not be null.
+    pub fn time_of(row: &Row, time_col_idx: usize) -> Result<NaiveDateTimeWrapper> {
+        match &row[time_col_idx] {
+            Some(ScalarImpl::NaiveDateTime(time)) => Ok(*time),
+            _ => Err(ErrorCode::InternalError(format!(
+                "invalid time of session window: {:?}",
+                row[time_col_idx]
+            ))
+            .into()),
+        }
+    }
+
+    fn serialize_time(time: NaiveDateTimeWrapper) -> Result<Vec<u8>> {
+        Ok(Row(vec![Some(ScalarImpl::NaiveDateTime(time))]).serialize()?)
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        !self.flush_buffer.is_empty()
+    }
+
+    pub fn insert(&mut self, key: SessionRowKey, row: Row) {
+        self.cached.insert(key.clone(), row.clone());
+        FlushStatus::do_insert(self.flush_buffer.entry(key), row);
+    }
+
+    pub fn remove(&mut self, key: SessionRowKey) {
+        self.cached.remove(&key);
+        FlushStatus::do_delete(self.flush_buffer.entry(key));
+    }
+
+    /// Returns the rows whose time is in `[start, end]`.
+    pub fn rows_between(
+        &self,
+        start: NaiveDateTimeWrapper,
+        end: NaiveDateTimeWrapper,
+    ) -> impl Iterator<Item = (&SessionRowKey, &Row)> {
+        self.cached
+            .range((Bound::Included((start, vec![])), Bound::Unbounded))
+            .take_while(move |((time, _), _)| *time <= end)
+    }
+
+    /// Extends `[start, end]` to the boundaries of the sessions it touches, so that no row outside
+    /// the range is in the same session with a row inside it. Only the rows outside `[start, end]`
+    /// are considered, so the result is the same before and after changing the rows inside.
+    pub fn extend_to_sessions(
+        &self,
+        start: NaiveDateTimeWrapper,
+        end: NaiveDateTimeWrapper,
+        gap: SessionGap,
+    ) -> (NaiveDateTimeWrapper, NaiveDateTimeWrapper) {
+        let mut new_start = start;
+        for ((time, _), _) in self
+            .cached
+            .range((Bound::Unbounded, Bound::Excluded((start, vec![]))))
+            .rev()
+        {
+            if !gap.connects(*time, new_start) {
+                break;
+            }
+            new_start = *time;
+        }
+
+        let mut new_end = end;
+        for ((time, _), _) in self
+            .cached
+            .range((Bound::Excluded((end, vec![])), Bound::Unbounded))
+            .skip_while(|((time, _), _)| *time == end)
+        {
+            if !gap.connects(new_end, *time) {
+                break;
+            }
+            new_end = *time;
+        }
+
+        (new_start, new_end)
+    }
+
+    /// Flush data to the state store
+    pub fn flush(&mut self, write_batch: &mut WriteBatch<S>) -> Result<()> {
+        let mut local = write_batch.prefixify(&self.keyspace);
+
+        for ((time, pk), v) in std::mem::take(&mut self.flush_buffer) {
+            let mut key_encoded = Self::serialize_time(time)?;
+            key_encoded.extend(pk);
+
+            match v.into_option() {
+                Some(row) => {
+                    let mut value = vec![];
+                    for datum in &row.0 {
+                        value.extend(serialize_cell(datum)?);
+                    }
+                    local.put(key_encoded, StorageValue::new_default_put(value));
+                }
+                None => {
+                    local.delete(key_encoded);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::types::IntervalUnit;
+    use risingwave_storage::memory::MemoryStateStore;
+
+    use super::*;
+
+    fn time(minutes: i64) -> NaiveDateTimeWrapper {
+        NaiveDateTimeWrapper::new(
+            chrono::NaiveDate::from_ymd(2022, 1, 1).and_hms(0, 0, 0)
+                + chrono::Duration::minutes(minutes),
+        )
+    }
+
+    fn row(id: i64, minutes: i64) -> (SessionRowKey, Row) {
+        let pk = Row(vec![Some(ScalarImpl::Int64(id))]).serialize().unwrap();
+        let row = Row(vec![
+            Some(ScalarImpl::Int64(id)),
+            Some(ScalarImpl::NaiveDateTime(time(minutes))),
+        ]);
+        ((time(minutes), pk), row)
+    }
+
+    #[tokio::test]
+    async fn test_session_window_state() {
+        let store = MemoryStateStore::new();
+        let keyspace = Keyspace::executor_root(store.clone(), 0x2333);
+        let data_types = [DataType::Int64, DataType::Timestamp];
+        let gap = SessionGap::from_interval(IntervalUnit::from_minutes(10)).unwrap();
+
+        let mut state = SessionWindowState::new(keyspace.clone(), &data_types, 1, 0)
+            .await
+            .unwrap();
+        for (id, minutes) in [(1, 0), (2, 5), (3, 20), (4, 25), (5, 40)] {
+            let (key, row) = row(id, minutes);
+            state.insert(key, row);
+        }
+        assert!(state.is_dirty());
+
+        // `[20, 25]` is a session by itself, while `[5, 20]` connects the first two sessions.
+        assert_eq!(
+            state.extend_to_sessions(time(22), time(22), gap),
+            (time(20), time(25))
+        );
+        assert_eq!(
+            state.extend_to_sessions(time(12), time(12), gap),
+            (time(0), time(25))
+        );
+        assert_eq!(
+            state.extend_to_sessions(time(45), time(60), gap),
+            (time(40), time(60))
+        );
+        assert_eq!(state.rows_between(time(5), time(25)).count(), 3);
+
+        let mut write_batch = store.start_write_batch();
+        state.flush(&mut write_batch).unwrap();
+        write_batch.ingest(1).await.unwrap();
+        assert!(!state.is_dirty());
+
+        // All rows are loaded back in order.
+        let state = SessionWindowState::new(keyspace, &data_types, 1, 1)
+            .await
+            .unwrap();
+        assert_eq!(
+            state
+                .rows_between(time(0), time(60))
+                .map(|(_, row)| row.clone())
+                .collect::<Vec<_>>(),
+            [(1, 0), (2, 5), (3, 20), (4, 25), (5, 40)]
+                .into_iter()
+                .map(|(id, minutes)| row(id, minutes).1)
+                .collect::<Vec<_>>()
+        );
+    }
+}
@@ -34,7 +34,9 @@ pub use top_n_appendonly::*;
 
 use crate::executor_v2::{
     BoxedExecutor, Executor, HashJoinExecutorBuilder, HopWindowExecutorBuilder,
-    LookupExecutorBuilder, LookupUnionExecutorBuilder, SourceExecutorBuilder, UnionExecutorBuilder,
+    LocalHashAggExecutorBuilder, LookupExecutorBuilder, LookupUnionExecutorBuilder,
+    ProjectSetExecutorBuilder, SessionWindowExecutorBuilder, SourceExecutorBuilder,
+    UnionExecutorBuilder,
 };
 use crate::task::{
     ActorId, DispatcherId, ExecutorParams, LocalStreamManagerCore, ENABLE_BARRIER_AGGREGATION,
@@ -69,13 +71,14 @@ use risingwave_common::array::{ArrayImpl, ArrayRef, DataChunk, StreamChunk};
 use risingwave_common::buffer::Bitmap;
 use risingwave_common::catalog::Schema;
 use risingwave_common::error::{ErrorCode, Result, RwError};
-use risingwave_common::types::DataType;
+use risingwave_common::types::{DataType, ScalarImpl};
+use risingwave_common::util::value_encoding::{deserialize_cell, serialize_cell};
 use risingwave_pb::common::ActorInfo;
 use risingwave_pb::data::barrier::Mutation as ProstMutation;
 use risingwave_pb::data::stream_message::StreamMessage;
 use risingwave_pb::data::{
     AddMutation, Barrier as ProstBarrier, DispatcherMutation, Epoch as ProstEpoch, NothingMutation,
-    StopMutation, StreamMessage as ProstStreamMessage, UpdateMutation,
+    StopMutation, StreamMessage as ProstStreamMessage, UpdateMutation, Watermark as ProstWatermark,
 };
 use risingwave_pb::stream_plan;
 use risingwave_pb::stream_plan::stream_node::Node;
@@ -299,10 +302,54 @@ impl Barrier {
     }
 }
 
+/// Event-time watermark of the column `col_idx`: rows whose value of the column is smaller than
+/// `val` will not arrive afterwards, so states of closed windows can be cleaned up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Watermark {
+    pub col_idx: usize,
+    pub data_type: DataType,
+    pub val: ScalarImpl,
+}
+
+impl Watermark {
+    pub fn new(col_idx: usize, data_type: DataType, val: ScalarImpl) -> Self {
+        Self {
+            col_idx,
+            data_type,
+            val,
+        }
+    }
+
+    /// Returns the same watermark on another column.
+    pub fn with_idx(self, col_idx: usize) -> Self {
+        Self { col_idx, ..self }
+    }
+
+    pub fn to_protobuf(&self) -> Result<ProstWatermark> {
+        Ok(ProstWatermark {
+            col_idx: self.col_idx as u32,
+            data_type: Some(self.data_type.to_protobuf()),
+            val: serialize_cell(&Some(self.val.clone()))?,
+        })
+    }
+
+    pub fn from_protobuf(prost: &ProstWatermark) -> Result<Self> {
+        let data_type = DataType::from(prost.get_data_type()?);
+        let mut deserializer = value_encoding::Deserializer::new(prost.val.as_slice());
+        let val = deserialize_cell(&mut deserializer, &data_type)?.ok_or_else(|| {
+            RwError::from(ErrorCode::InternalError(
+                "watermark value should not be null".to_string(),
+            ))
+        })?;
+        Ok(Self::new(prost.col_idx as usize, data_type, val))
+    }
+}
+
 #[derive(Debug, EnumAsInner, PartialEq)]
 pub enum Message {
     Chunk(StreamChunk),
     Barrier(Barrier),
+    Watermark(Watermark),
 }
 
 impl<'a> TryFrom<&'a Message> for &'a Barrier {
@@ -310,7 +357,7 @@ impl<'a> TryFrom<&'a Message> for &'a Barrier {
 
     fn try_from(m: &'a Message) -> std::result::Result<Self, Self::Error> {
         match m {
-            Message::Chunk(_) => Err(()),
+            Message::Chunk(_) | Message::Watermark(_) => Err(()),
             Message::Barrier(b) => Ok(b),
         }
     }
@@ -339,6 +386,7 @@ impl Message {
                 StreamMessage::StreamChunk(prost_stream_chunk)
             }
             Self::Barrier(barrier) => StreamMessage::Barrier(barrier.clone().to_protobuf()),
+            Self::Watermark(watermark) => StreamMessage::Watermark(watermark.to_protobuf()?),
         };
         let prost_stream_msg = ProstStreamMessage {
             stream_message: Some(prost),
@@ -354,6 +402,9 @@ impl Message {
             StreamMessage::Barrier(ref barrier) => {
                 Message::Barrier(Barrier::from_protobuf(barrier)?)
             }
+            StreamMessage::Watermark(ref watermark) => {
+                Message::Watermark(Watermark::from_protobuf(watermark)?)
+            }
         };
         Ok(res)
     }
@@ -524,8 +575,10 @@ pub fn create_executor(
         Node::LocalSimpleAggNode => LocalSimpleAggExecutorBuilder,
         Node::GlobalSimpleAggNode => SimpleAggExecutorBuilder,
         Node::HashAggNode => HashAggExecutorBuilder,
+        Node::LocalHashAggNode => LocalHashAggExecutorBuilder,
         Node::HashJoinNode => HashJoinExecutorBuilder,
         Node::HopWindowNode => HopWindowExecutorBuilder,
+        Node::SessionWindowNode => SessionWindowExecutorBuilder,
         Node::ChainNode => ChainExecutorBuilder,
         Node::BatchPlanNode => BatchQueryExecutorBuilder,
         Node::MergeNode => MergeExecutorBuilder,
@@ -535,6 +588,7 @@ pub fn create_executor(
         Node::LookupNode => LookupExecutorBuilder,
         Node::UnionNode => UnionExecutorBuilder,
         Node::LookupUnionNode => LookupUnionExecutorBuilder,
+        Node::ProjectSetNode => ProjectSetExecutorBuilder,
     }
 }
 
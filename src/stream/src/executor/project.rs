@@ -15,6 +15,7 @@
 use risingwave_common::error::Result;
 use risingwave_common::try_match_expand;
 use risingwave_expr::expr::build_from_prost;
+use risingwave_pb::expr::expr_node::RexNode;
 use risingwave_pb::stream_plan;
 use risingwave_pb::stream_plan::stream_node::Node;
 use risingwave_storage::StateStore;
@@ -38,6 +39,18 @@ impl ExecutorBuilder for ProjectExecutorBuilder {
             .iter()
             .map(build_from_prost)
             .collect::<Result<Vec<_>>>()?;
+        // Watermarks are derived through plain column references.
+        let watermark_derivations = node
+            .get_select_list()
+            .iter()
+            .enumerate()
+            .filter_map(|(output_idx, expr)| match expr.rex_node {
+                Some(RexNode::InputRef(ref input_ref)) => {
+                    Some((input_ref.column_idx as usize, output_idx))
+                }
+                _ => None,
+            })
+            .collect();
 
         Ok(ProjectExecutor::new_from_v1(
             params.input.remove(0),
@@ -46,6 +59,7 @@ impl ExecutorBuilder for ProjectExecutorBuilder {
             params.executor_id,
             params.op_info,
         )
+        .with_watermark_derivations(watermark_derivations)
         .boxed())
     }
 }
@@ -60,4 +60,17 @@ pub struct AggCall {
     pub args: AggArgs,
     /// The return type of aggregation function.
     pub return_type: DataType,
+    /// Whether the aggregation function only applies to the distinct values of the argument.
+    pub distinct: bool,
+}
+
+impl AggCall {
+    /// The type of the value persisted for the aggregation. It's the return type unless the state
+    /// can not be recovered from the output, e.g. the sketch of `approx_count_distinct`.
+    pub fn state_type(&self) -> DataType {
+        match self.kind {
+            AggKind::ApproxCountDistinct | AggKind::ApproxCountDistinctMerge => DataType::Varchar,
+            _ => self.return_type.clone(),
+        }
+    }
 }
@@ -0,0 +1,179 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! This module implements `StreamingApproxCountDistinct`.
+
+use risingwave_common::array::stream_chunk::Ops;
+use risingwave_common::array::*;
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::types::{DataType, Datum, ScalarImpl};
+use risingwave_expr::vector_op::agg::HyperLogLog;
+
+use super::StreamingAggStateImpl;
+
+/// `StreamingApproxCountDistinct` estimates the number of distinct values with a `HyperLogLog`
+/// sketch. As a sketch can not forget a value, only append-only inputs are supported.
+///
+/// For two-phase aggregation, the local phase outputs the encoded sketch as `varchar` and is reset
+/// on every barrier, and the global phase merges the sketches it receives into its own, which is
+/// persisted as `varchar` as well.
+#[derive(Clone, Debug)]
+pub struct StreamingApproxCountDistinct {
+    sketch: HyperLogLog,
+    /// Whether the inputs are sketches to merge instead of values to count.
+    merge: bool,
+    /// `int64` for the estimation, or `varchar` for the encoded sketch.
+    return_type: DataType,
+}
+
+impl StreamingApproxCountDistinct {
+    /// Create the state, recovering the sketch from the persisted encoded one if any.
+    pub fn new(merge: bool, return_type: DataType, state: Datum) -> Result<Self> {
+        let sketch = match state {
+            Some(ScalarImpl::Utf8(encoded)) => HyperLogLog::decode(&encoded)?,
+            Some(other) => {
+                return Err(ErrorCode::InternalError(format!(
+                    "type mismatch in streaming aggregator StreamingApproxCountDistinct init: expected varchar, get {}",
+                    other.get_ident()
+                ))
+                .into())
+            }
+            None => HyperLogLog::default(),
+        };
+        Ok(Self {
+            sketch,
+            merge,
+            return_type,
+        })
+    }
+
+    fn add(&mut self, data: &ArrayImpl, idx: usize) -> Result<()> {
+        let datum = data.datum_at(idx);
+        if self.merge {
+            if let Some(ScalarImpl::Utf8(encoded)) = datum {
+                self.sketch.merge(&HyperLogLog::decode(&encoded)?);
+            }
+        } else {
+            self.sketch.add(&datum)?;
+        }
+        Ok(())
+    }
+}
+
+impl StreamingAggStateImpl for StreamingApproxCountDistinct {
+    fn apply_batch(
+        &mut self,
+        ops: Ops<'_>,
+        visibility: Option<&Bitmap>,
+        data: &[&ArrayImpl],
+    ) -> Result<()> {
+        for (idx, op) in ops.iter().enumerate() {
+            if let Some(visibility) = visibility && !visibility.is_set(idx)? {
+                continue;
+            }
+            match op {
+                Op::Insert | Op::UpdateInsert => self.add(data[0], idx)?,
+                Op::Delete | Op::UpdateDelete => {
+                    return Err(ErrorCode::NotImplemented(
+                        "approx_count_distinct on non-append-only input".to_string(),
+                        None.into(),
+                    )
+                    .into())
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn get_output(&self) -> Result<Datum> {
+        match self.return_type {
+            DataType::Varchar => Ok(Some(ScalarImpl::Utf8(self.sketch.encode()))),
+            _ => Ok(Some(ScalarImpl::Int64(self.sketch.estimate()))),
+        }
+    }
+
+    fn get_state(&self) -> Result<Datum> {
+        Ok(Some(ScalarImpl::Utf8(self.sketch.encode())))
+    }
+
+    fn new_builder(&self) -> ArrayBuilderImpl {
+        self.return_type.create_array_builder(0).unwrap()
+    }
+
+    fn reset(&mut self) {
+        self.sketch = HyperLogLog::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_approx_count_distinct_two_phase() {
+        let mut local_1 =
+            StreamingApproxCountDistinct::new(false, DataType::Varchar, None).unwrap();
+        let mut local_2 =
+            StreamingApproxCountDistinct::new(false, DataType::Varchar, None).unwrap();
+        local_1
+            .apply_batch(
+                &[Op::Insert, Op::Insert, Op::Insert],
+                None,
+                &[&I64Array::from_slice(&[Some(1), Some(2), Some(1)])
+                    .unwrap()
+                    .into()],
+            )
+            .unwrap();
+        local_2
+            .apply_batch(
+                &[Op::Insert, Op::Insert, Op::Insert],
+                Some(&Bitmap::try_from(vec![true, true, false]).unwrap()),
+                &[&I64Array::from_slice(&[Some(2), Some(3), Some(4)])
+                    .unwrap()
+                    .into()],
+            )
+            .unwrap();
+
+        // the global phase merges the sketches output by the local phase
+        let mut global = StreamingApproxCountDistinct::new(true, DataType::Int64, None).unwrap();
+        let mut builder = Utf8ArrayBuilder::new(0).unwrap();
+        for local in [&local_1, &local_2] {
+            let output = local.get_output().unwrap();
+            builder
+                .append(output.as_ref().map(|s| s.as_utf8().as_str()))
+                .unwrap();
+        }
+        let sketches: ArrayImpl = builder.finish().unwrap().into();
+        global
+            .apply_batch(&[Op::Insert, Op::Insert], None, &[&sketches])
+            .unwrap();
+        assert_eq!(global.get_output().unwrap(), Some(ScalarImpl::Int64(3)));
+
+        // the global state is recovered from the persisted sketch
+        let global =
+            StreamingApproxCountDistinct::new(true, DataType::Int64, global.get_state().unwrap())
+                .unwrap();
+        assert_eq!(global.get_output().unwrap(), Some(ScalarImpl::Int64(3)));
+
+        // deletions are not supported
+        assert!(local_1
+            .apply_batch(
+                &[Op::Delete],
+                None,
+                &[&I64Array::from_slice(&[Some(1)]).unwrap().into()],
+            )
+            .is_err());
+    }
+}
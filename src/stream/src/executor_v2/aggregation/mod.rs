@@ -16,6 +16,7 @@ use std::any::Any;
 
 pub use agg_call::*;
 pub use agg_state::*;
+pub use approx_count_distinct::*;
 use dyn_clone::{self, DynClone};
 pub use foldable::*;
 use itertools::Itertools;
@@ -44,6 +45,7 @@ use crate::executor_v2::Executor;
 
 mod agg_call;
 mod agg_state;
+mod approx_count_distinct;
 mod foldable;
 mod row_count;
 mod single_value;
@@ -92,6 +94,12 @@ pub trait StreamingAggStateImpl: Any + std::fmt::Debug + DynClone + Send + Sync
     /// Get the output value
     fn get_output(&self) -> Result<Datum>;
 
+    /// Get the value to be persisted, which is the output unless the output can not recover the
+    /// state
+    fn get_state(&self) -> Result<Datum> {
+        self.get_output()
+    }
+
     /// Get the builder of the state output
     fn new_builder(&self) -> ArrayBuilderImpl;
 
@@ -118,6 +126,15 @@ pub fn create_streaming_agg_state(
     return_type: &DataType,
     datum: Option<Datum>,
 ) -> Result<Box<dyn StreamingAggStateImpl>> {
+    // The sketch works on the hashes of the values, so any input type is accepted.
+    if let AggKind::ApproxCountDistinct | AggKind::ApproxCountDistinctMerge = agg_type {
+        return Ok(Box::new(StreamingApproxCountDistinct::new(
+            *agg_type == AggKind::ApproxCountDistinctMerge,
+            return_type.clone(),
+            datum.flatten(),
+        )?));
+    }
+
     macro_rules! gen_unary_agg_state_match {
         ($agg_type_expr:expr, $input_type_expr:expr, $return_type_expr:expr, $datum: expr, [$(($agg_type:ident, $input_type:ident, $return_type:ident, $state_impl:ty)),*$(,)?]) => {
             match (
@@ -17,12 +17,14 @@ use futures::StreamExt;
 use futures_async_stream::try_stream;
 
 use super::error::StreamExecutorError;
-use super::{Barrier, BoxedMessageStream, Message, StreamChunk};
+use super::{Barrier, BoxedMessageStream, Message, StreamChunk, Watermark};
 
 #[derive(Debug, PartialEq)]
 pub enum AlignedMessage {
     Left(StreamChunk),
     Right(StreamChunk),
+    WatermarkLeft(Watermark),
+    WatermarkRight(Watermark),
     Barrier(Barrier),
 }
 
@@ -36,6 +38,9 @@ pub async fn barrier_align(mut left: BoxedMessageStream, mut right: BoxedMessage
                 while let Some(msg) = right.next().await {
                     match msg? {
                         Message::Chunk(chunk) => yield AlignedMessage::Right(chunk),
+                        Message::Watermark(watermark) => {
+                            yield AlignedMessage::WatermarkRight(watermark)
+                        }
                         Message::Barrier(_) => {
                             panic!("right barrier received while left stream end")
                         }
@@ -48,6 +53,9 @@ pub async fn barrier_align(mut left: BoxedMessageStream, mut right: BoxedMessage
                 while let Some(msg) = left.next().await {
                     match msg? {
                         Message::Chunk(chunk) => yield AlignedMessage::Left(chunk),
+                        Message::Watermark(watermark) => {
+                            yield AlignedMessage::WatermarkLeft(watermark)
+                        }
                         Message::Barrier(_) => {
                             panic!("left barrier received while right stream end")
                         }
@@ -57,10 +65,14 @@ pub async fn barrier_align(mut left: BoxedMessageStream, mut right: BoxedMessage
             }
             Either::Left((Some(msg), _)) => match msg? {
                 Message::Chunk(chunk) => yield AlignedMessage::Left(chunk),
+                Message::Watermark(watermark) => yield AlignedMessage::WatermarkLeft(watermark),
                 Message::Barrier(_) => loop {
                     // received left barrier, waiting for right barrier
                     match right.next().await.unwrap()? {
                         Message::Chunk(chunk) => yield AlignedMessage::Right(chunk),
+                        Message::Watermark(watermark) => {
+                            yield AlignedMessage::WatermarkRight(watermark)
+                        }
                         Message::Barrier(barrier) => {
                             yield AlignedMessage::Barrier(barrier);
                             break;
@@ -70,10 +82,14 @@ pub async fn barrier_align(mut left: BoxedMessageStream, mut right: BoxedMessage
             },
             Either::Right((Some(msg), _)) => match msg? {
                 Message::Chunk(chunk) => yield AlignedMessage::Right(chunk),
+                Message::Watermark(watermark) => yield AlignedMessage::WatermarkRight(watermark),
                 Message::Barrier(_) => loop {
                     // received right barrier, waiting for left barrier
                     match left.next().await.unwrap()? {
                         Message::Chunk(chunk) => yield AlignedMessage::Left(chunk),
+                        Message::Watermark(watermark) => {
+                            yield AlignedMessage::WatermarkLeft(watermark)
+                        }
                         Message::Barrier(barrier) => {
                             yield AlignedMessage::Barrier(barrier);
                             break;
@@ -39,7 +39,7 @@ pub struct ChainExecutor {
     info: ExecutorInfo,
 }
 
-fn mapping(upstream_indices: &[usize], msg: Message) -> Message {
+fn mapping(upstream_indices: &[usize], msg: Message) -> Option<Message> {
     match msg {
         Message::Chunk(chunk) => {
             let (ops, columns, visibility) = chunk.into_inner();
@@ -47,9 +47,18 @@ fn mapping(upstream_indices: &[usize], msg: Message) -> Message {
                 .iter()
                 .map(|&i| columns[i].clone())
                 .collect();
-            Message::Chunk(StreamChunk::new(ops, mapped_columns, visibility))
+            Some(Message::Chunk(StreamChunk::new(
+                ops,
+                mapped_columns,
+                visibility,
+            )))
         }
-        _ => msg,
+        // Watermarks of columns not in the output are dropped.
+        Message::Watermark(watermark) => upstream_indices
+            .iter()
+            .position(|&i| i == watermark.col_idx)
+            .map(|col_idx| Message::Watermark(watermark.with_idx(col_idx))),
+        _ => Some(msg),
     }
 }
 
@@ -109,7 +118,9 @@ impl ChainExecutor {
         // 4. Continuously consume the upstream.
         #[for_await]
         for msg in upstream {
-            yield mapping(&self.upstream_indices, msg?);
+            if let Some(msg) = mapping(&self.upstream_indices, msg?) {
+                yield msg;
+            }
         }
     }
 }
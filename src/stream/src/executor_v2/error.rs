@@ -50,6 +50,9 @@ enum StreamExecutorErrorInner {
     #[error("Hash join error: {0}")]
     HashJoinError(RwError),
 
+    #[error("Session window state error: {0}")]
+    SessionWindowStateError(RwError),
+
     #[error("Source error: {0}")]
     SourceError(RwError),
 
@@ -89,6 +92,10 @@ impl StreamExecutorError {
         StreamExecutorErrorInner::HashJoinError(error.into()).into()
     }
 
+    pub fn session_window_state_error(error: impl Into<RwError>) -> Self {
+        StreamExecutorErrorInner::SessionWindowStateError(error.into()).into()
+    }
+
     pub fn source_error(error: impl Into<RwError>) -> Self {
         StreamExecutorErrorInner::SourceError(error.into()).into()
     }
@@ -258,6 +258,8 @@ impl<S: StateStore> SimpleAggExecutor<S> {
                     )
                     .await?;
                 }
+                // The output has no columns derived from the input columns.
+                Message::Watermark(_) => {}
                 Message::Barrier(barrier) => {
                     let next_epoch = barrier.epoch.curr;
                     if let Some(chunk) =
@@ -326,21 +328,25 @@ mod tests {
                 kind: AggKind::RowCount,
                 args: AggArgs::None,
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Sum,
                 args: AggArgs::Unary(DataType::Int64, 0),
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Sum,
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Min,
                 args: AggArgs::Unary(DataType::Int64, 0),
                 return_type: DataType::Int64,
+                distinct: false,
             },
         ];
 
@@ -376,4 +382,101 @@ mod tests {
             )
         );
     }
+
+    #[tokio::test]
+    async fn test_two_phase_approx_count_distinct_in_memory() {
+        test_two_phase_approx_count_distinct(create_in_memory_keyspace()).await
+    }
+
+    async fn test_two_phase_approx_count_distinct(keyspace: Keyspace<impl StateStore>) {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                // primary key column
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let (mut tx, source) = MockSource::channel(schema, vec![1]); // pk
+        tx.push_barrier(1, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I    I
+            + 1 1001
+            + 2 1002
+            + 2 1003",
+        ));
+        tx.push_barrier(2, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I    I
+            + 2 1004
+            + 3 1005
+            + 4 1006",
+        ));
+        tx.push_barrier(3, false);
+
+        // The local aggregation emits the sketch of each epoch.
+        let local_agg_calls = vec![
+            AggCall {
+                kind: AggKind::RowCount,
+                args: AggArgs::None,
+                return_type: DataType::Int64,
+                distinct: false,
+            },
+            AggCall {
+                kind: AggKind::ApproxCountDistinct,
+                args: AggArgs::Unary(DataType::Int64, 0),
+                return_type: DataType::Varchar,
+                distinct: false,
+            },
+        ];
+        let local_agg = Box::new(
+            LocalSimpleAggExecutor::new(Box::new(source), local_agg_calls, vec![], 1).unwrap(),
+        );
+
+        // The global aggregation merges the sketches into the estimated count.
+        let global_agg_calls = vec![
+            AggCall {
+                kind: AggKind::RowCount,
+                args: AggArgs::None,
+                return_type: DataType::Int64,
+                distinct: false,
+            },
+            AggCall {
+                kind: AggKind::ApproxCountDistinctMerge,
+                args: AggArgs::Unary(DataType::Varchar, 1),
+                return_type: DataType::Int64,
+                distinct: false,
+            },
+        ];
+        let global_agg = Box::new(
+            SimpleAggExecutor::new(local_agg, global_agg_calls, keyspace, vec![], 2, vec![])
+                .unwrap(),
+        );
+        let mut global_agg = global_agg.execute();
+
+        // Consume the init barrier
+        global_agg.next().await.unwrap().unwrap();
+        // Consume stream chunk
+        let msg = global_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            *msg.as_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I
+                + 1 2"
+            )
+        );
+        assert_matches!(
+            global_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        let msg = global_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            *msg.as_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                "  I I
+                U- 1 2
+                U+ 2 4"
+            )
+        );
+    }
 }
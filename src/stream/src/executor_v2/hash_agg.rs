@@ -16,17 +16,20 @@ use std::collections::HashMap;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+use bytes::Bytes;
 use futures::{stream, StreamExt};
 use futures_async_stream::try_stream;
 use iter_chunks::IterChunks;
 use itertools::Itertools;
 use risingwave_common::array::column::Column;
-use risingwave_common::array::StreamChunk;
+use risingwave_common::array::data_chunk_iter::RowDeserializer;
+use risingwave_common::array::{DataChunk, Op, Row, StreamChunk};
 use risingwave_common::buffer::Bitmap;
 use risingwave_common::catalog::Schema;
 use risingwave_common::collection::evictable::EvictableHashMap;
 use risingwave_common::error::{Result, RwError};
 use risingwave_common::hash::{HashCode, HashKey};
+use risingwave_common::types::ScalarImpl;
 use risingwave_common::util::hash_util::CRC32FastBuilder;
 use risingwave_storage::{Keyspace, StateStore};
 
@@ -36,7 +39,10 @@ use crate::executor_v2::aggregation::{
     agg_input_arrays, generate_agg_schema, generate_hash_agg_state, AggCall, AggState,
 };
 use crate::executor_v2::error::StreamExecutorError;
-use crate::executor_v2::{BoxedMessageStream, Message, PkIndices, PROCESSING_WINDOW_SIZE};
+use crate::executor_v2::watermark::hide_late_rows;
+use crate::executor_v2::{
+    BoxedMessageStream, Message, PkIndices, Watermark, PROCESSING_WINDOW_SIZE,
+};
 
 /// [`HashAggExecutor`] could process large amounts of data using a state backend. It works as
 /// follows:
@@ -48,6 +54,13 @@ use crate::executor_v2::{BoxedMessageStream, Message, PkIndices, PROCESSING_WIND
 /// * Upon a barrier is received, the executor will call `.flush` on the storage backend, so that
 ///   all modifications will be flushed to the storage backend. Meanwhile, the executor will go
 ///   through `modified_keys`, and produce a stream chunk based on the state changes.
+/// * Upon a watermark of the first group key is received, the groups below the watermark are
+///   cleaned from both the cache and the storage at the next barrier, after their changes are
+///   produced. Late rows of the cleaned groups are ignored afterwards.
+/// * If `emit_on_window_close` is set, no changes are produced on barriers. Instead, the final
+///   results of the groups below the watermark are produced as inserts right before they are
+///   cleaned, so the output is append-only. The groups to produce are scanned from the storage, so
+///   that the groups not produced before recovery are not lost.
 pub struct HashAggExecutor<K: HashKey, S: StateStore> {
     input: Box<dyn Executor>,
 
@@ -81,6 +94,9 @@ struct HashAggExecutorExtra<S: StateStore> {
     /// Indices of the columns
     /// all of the aggregation functions in this executor should depend on same group of keys
     key_indices: Vec<usize>,
+
+    /// Whether to emit the results of a group only once its first group key passes the watermark.
+    emit_on_window_close: bool,
 }
 
 impl<K: HashKey, S: StateStore> Executor for HashAggExecutor<K, S> {
@@ -109,10 +125,24 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
         pk_indices: PkIndices,
         executor_id: u64,
         key_indices: Vec<usize>,
+        emit_on_window_close: bool,
     ) -> Result<Self> {
         let input_info = input.info();
         let schema = generate_agg_schema(input.as_ref(), &agg_calls, Some(&key_indices));
 
+        // The states of a group are scanned by the prefix of the encoded group key, which can be
+        // filtered by prefix bloom filters for the leading fixed-size columns.
+        let bloom_key_len: usize = key_indices
+            .iter()
+            .map_while(|idx| input_info.schema[*idx].data_type.memcmp_fixed_size())
+            .map(|size| size + 1)
+            .sum();
+        if bloom_key_len > 0 {
+            keyspace
+                .iter()
+                .for_each(|keyspace| keyspace.register_bloom_key_len(bloom_key_len));
+        }
+
         Ok(Self {
             input,
             extra: HashAggExecutorExtra {
@@ -124,6 +154,7 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                 keyspace,
                 agg_calls,
                 key_indices,
+                emit_on_window_close,
             },
             _phantom: PhantomData,
         })
@@ -299,6 +330,7 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
             ref key_indices,
             ref keyspace,
             ref schema,
+            emit_on_window_close,
             ..
         }: &'a HashAggExecutorExtra<S>,
         state_map: &'a mut EvictableHashMap<K, Option<Box<AggState<S>>>>,
@@ -336,6 +368,16 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                 .await
                 .map_err(StreamExecutorError::agg_state_error)?;
 
+            if emit_on_window_close {
+                // The results are produced when the windows are closed, so just clear the dirty
+                // marks here.
+                for states in state_map.values_mut() {
+                    states.as_mut().unwrap().prev_states = None;
+                }
+                state_map.evict_to_target_cap();
+                return Ok(());
+            }
+
             // --- Produce the stream chunk ---
             let mut batches = IterChunks::chunks(state_map.iter_mut(), PROCESSING_WINDOW_SIZE);
             while let Some(batch) = batches.next() {
@@ -386,6 +428,171 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
         }
     }
 
+    /// Produces the final results of the groups whose first group key is below `watermark` as
+    /// inserts. All states must have been flushed.
+    async fn emit_closed_windows(
+        &HashAggExecutorExtra::<S> {
+            ref key_indices,
+            ref agg_calls,
+            ref input_pk_indices,
+            ref input_schema,
+            ref keyspace,
+            ref schema,
+            ..
+        }: &HashAggExecutorExtra<S>,
+        state_map: &mut EvictableHashMap<K, Option<Box<AggState<S>>>>,
+        watermark: &ScalarImpl,
+        epoch: u64,
+    ) -> StreamExecutorResult<Option<StreamChunk>> {
+        let key_data_types = &schema.data_types()[..key_indices.len()];
+        let input_pk_data_types: PkDataTypes = input_pk_indices
+            .iter()
+            .map(|idx| input_schema.fields[*idx].data_type.clone())
+            .collect();
+
+        // The row count of a group is stored right at `[group_key]` of the first keyspace, so the
+        // closed groups are exactly the keys in `[[1u8], watermark)` there, as in `clean_states`.
+        let row_count_keyspace = &keyspace[0];
+        let end_key = Row(vec![Some(watermark.clone())])
+            .serialize()
+            .map_err(StreamExecutorError::eval_error)?;
+        let pairs = row_count_keyspace
+            .state_store()
+            .scan(
+                row_count_keyspace.prefixed_key([1u8])..row_count_keyspace.prefixed_key(&end_key),
+                None,
+                epoch,
+            )
+            .await
+            .map_err(StreamExecutorError::storage)?;
+        if pairs.is_empty() {
+            return Ok(None);
+        }
+        let deserializer = RowDeserializer::new(key_data_types.to_vec());
+        let rows: Vec<Row> = pairs
+            .iter()
+            .map(|(key, _)| deserializer.deserialize(&key[row_count_keyspace.key().len()..]))
+            .try_collect()
+            .map_err(StreamExecutorError::eval_error)?;
+
+        // Build the hash keys in the same way as `apply_chunk`, to look up the cached states.
+        let key_chunk =
+            DataChunk::from_rows(&rows, key_data_types).map_err(StreamExecutorError::eval_error)?;
+        let group_key_indices = (0..key_indices.len()).collect_vec();
+        let hash_codes = key_chunk
+            .get_hash_values(&group_key_indices, CRC32FastBuilder)
+            .map_err(StreamExecutorError::eval_error)?;
+        let keys = K::build_from_hash_code(&group_key_indices, &key_chunk, hash_codes.clone())
+            .map_err(StreamExecutorError::eval_error)?;
+
+        let mut builders = schema
+            .create_array_builders(rows.len())
+            .map_err(StreamExecutorError::eval_error)?;
+        let mut new_ops = Vec::with_capacity(rows.len());
+        for ((key, hash_code), row) in keys.into_iter().zip_eq(hash_codes).zip_eq(rows) {
+            // The closed groups will be cleaned anyway, so take them out of the cache.
+            let mut states = match state_map.pop(&key) {
+                Some(states) => states.unwrap(),
+                None => Box::new(
+                    generate_hash_agg_state(
+                        Some(&row),
+                        agg_calls,
+                        keyspace,
+                        input_pk_data_types.clone(),
+                        epoch,
+                        Some(hash_code),
+                    )
+                    .await?,
+                ),
+            };
+            let row_count = states
+                .row_count(epoch)
+                .await
+                .map_err(StreamExecutorError::agg_state_error)?;
+            if row_count == 0 {
+                continue;
+            }
+
+            new_ops.push(Op::Insert);
+            for (builder, datum) in builders.iter_mut().zip_eq(row.0.iter()) {
+                builder
+                    .append_datum(datum)
+                    .map_err(StreamExecutorError::eval_error)?;
+            }
+            for (builder, state) in builders[key_indices.len()..]
+                .iter_mut()
+                .zip_eq(states.managed_states.iter_mut())
+            {
+                let data = state
+                    .get_output(epoch)
+                    .await
+                    .map_err(StreamExecutorError::agg_state_error)?;
+                builder
+                    .append_datum(&data)
+                    .map_err(StreamExecutorError::eval_error)?;
+            }
+        }
+
+        if new_ops.is_empty() {
+            return Ok(None);
+        }
+        let columns: Vec<Column> = builders
+            .into_iter()
+            .map(|builder| -> Result<_> { Ok(Column::new(Arc::new(builder.finish()?))) })
+            .try_collect()
+            .map_err(StreamExecutorError::eval_error)?;
+
+        Ok(Some(StreamChunk::new(new_ops, columns, None)))
+    }
+
+    /// Cleans the states of groups whose first group key is below `watermark`. All states must have
+    /// been flushed.
+    async fn clean_states(
+        &HashAggExecutorExtra::<S> {
+            ref key_indices,
+            ref keyspace,
+            ref schema,
+            ..
+        }: &HashAggExecutorExtra<S>,
+        state_map: &mut EvictableHashMap<K, Option<Box<AggState<S>>>>,
+        watermark: &ScalarImpl,
+        epoch: u64,
+    ) -> StreamExecutorResult<()> {
+        let key_data_types = &schema.data_types()[..key_indices.len()];
+        let mut expired_keys = vec![];
+        for (key, _) in state_map.iter() {
+            let row = key
+                .clone()
+                .deserialize(key_data_types.iter())
+                .map_err(StreamExecutorError::eval_error)?;
+            if row.0[0].as_ref().map_or(false, |value| value < watermark) {
+                expired_keys.push(key.clone());
+            }
+        }
+        for key in expired_keys {
+            state_map.pop(&key);
+        }
+
+        // Group keys are memcomparable-encoded with a leading `1u8` if not null, so the expired
+        // groups are exactly those in `[[1u8], watermark)`.
+        let end_key = Row(vec![Some(watermark.clone())])
+            .serialize()
+            .map_err(StreamExecutorError::eval_error)?;
+        for keyspace in keyspace {
+            keyspace
+                .state_store()
+                .delete_range(
+                    Bytes::from(keyspace.prefixed_key([1u8])),
+                    Bytes::from(keyspace.prefixed_key(&end_key)),
+                    epoch,
+                )
+                .await
+                .map_err(StreamExecutorError::storage)?;
+        }
+
+        Ok(())
+    }
+
     #[try_stream(ok = Message, error = StreamExecutorError)]
     async fn execute_inner(self) {
         let HashAggExecutor { input, extra, .. } = self;
@@ -401,13 +608,27 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
         let mut epoch = barrier.epoch.curr;
         yield Message::Barrier(barrier);
 
+        // The latest watermark of the first group key, which is applied at the next barrier.
+        let mut pending_watermark: Option<Watermark> = None;
+        // Groups below this value have been cleaned.
+        let mut state_watermark: Option<ScalarImpl> = None;
+
         #[for_await]
         for msg in input {
             let msg = msg?;
             match msg {
-                Message::Chunk(chunk) => {
+                Message::Chunk(mut chunk) => {
+                    if let Some(watermark) = &state_watermark {
+                        chunk = hide_late_rows(chunk, extra.key_indices[0], watermark);
+                    }
                     Self::apply_chunk(&extra, &mut state_map, chunk, epoch).await?;
                 }
+                Message::Watermark(watermark) => {
+                    // Watermarks of other columns are not meaningful to the output.
+                    if extra.key_indices.first() == Some(&watermark.col_idx) {
+                        pending_watermark = Some(watermark);
+                    }
+                }
                 Message::Barrier(barrier) => {
                     let next_epoch = barrier.epoch.curr;
                     assert_eq!(epoch, barrier.epoch.prev);
@@ -417,7 +638,32 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
                         yield Message::Chunk(chunk?);
                     }
 
+                    // Clean states in the next epoch, so that the flushed expired groups are
+                    // covered by the range deletion.
+                    let watermark = pending_watermark.take();
+                    if let Some(watermark) = &watermark {
+                        if extra.emit_on_window_close {
+                            if let Some(chunk) = Self::emit_closed_windows(
+                                &extra,
+                                &mut state_map,
+                                &watermark.val,
+                                epoch,
+                            )
+                            .await?
+                            {
+                                yield Message::Chunk(chunk);
+                            }
+                        }
+                        Self::clean_states(&extra, &mut state_map, &watermark.val, next_epoch)
+                            .await?;
+                        state_watermark = Some(watermark.val.clone());
+                    }
+
                     yield Message::Barrier(barrier);
+                    if let Some(watermark) = watermark {
+                        // Group keys come first in the output.
+                        yield Message::Watermark(watermark.with_idx(0));
+                    }
                     epoch = next_epoch;
                 }
             }
@@ -438,14 +684,14 @@ mod tests {
     use risingwave_common::catalog::{Field, Schema, TableId};
     use risingwave_common::error::Result;
     use risingwave_common::hash::{calc_hash_key_kind, HashKey, HashKeyDispatcher};
-    use risingwave_common::types::DataType;
+    use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_expr::expr::*;
     use risingwave_storage::memory::MemoryStateStore;
     use risingwave_storage::{Keyspace, StateStore};
 
     use crate::executor_v2::aggregation::{AggArgs, AggCall};
     use crate::executor_v2::test_utils::*;
-    use crate::executor_v2::{Executor, HashAggExecutor, Message, PkIndices};
+    use crate::executor_v2::{Executor, HashAggExecutor, Message, PkIndices, Watermark};
 
     struct HashAggExecutorDispatcher<S: StateStore>(PhantomData<S>);
 
@@ -456,6 +702,7 @@ mod tests {
         keyspace: Vec<Keyspace<S>>,
         pk_indices: PkIndices,
         executor_id: u64,
+        emit_on_window_close: bool,
     }
 
     impl<S: StateStore> HashKeyDispatcher for HashAggExecutorDispatcher<S> {
@@ -470,6 +717,7 @@ mod tests {
                 args.pk_indices,
                 args.executor_id,
                 args.key_indices,
+                args.emit_on_window_close,
             )?))
         }
     }
@@ -481,6 +729,7 @@ mod tests {
         keyspace: Vec<Keyspace<impl StateStore>>,
         pk_indices: PkIndices,
         executor_id: u64,
+        emit_on_window_close: bool,
     ) -> Box<dyn Executor> {
         let keys = key_indices
             .iter()
@@ -493,6 +742,7 @@ mod tests {
             keyspace,
             pk_indices,
             executor_id,
+            emit_on_window_close,
         };
         let kind = calc_hash_key_kind(&keys);
         HashAggExecutorDispatcher::dispatch_by_kind(kind, args).unwrap()
@@ -515,6 +765,21 @@ mod tests {
         test_local_hash_aggregation_max(create_in_memory_keyspace_agg(2)).await
     }
 
+    #[tokio::test]
+    async fn test_hash_aggregation_watermark_in_memory() {
+        test_hash_aggregation_watermark(create_in_memory_keyspace_agg(2)).await
+    }
+
+    #[tokio::test]
+    async fn test_hash_aggregation_emit_on_window_close_in_memory() {
+        test_hash_aggregation_emit_on_window_close(create_in_memory_keyspace_agg(2)).await
+    }
+
+    #[tokio::test]
+    async fn test_hash_aggregation_emit_on_window_close_recovery_in_memory() {
+        test_hash_aggregation_emit_on_window_close_recovery(create_in_memory_keyspace_agg(2)).await
+    }
+
     /// Create a vector of memory keyspace with len `num_ks`.
     fn create_in_memory_keyspace_agg(num_ks: usize) -> Vec<Keyspace<MemoryStateStore>> {
         let mut returned_vec = vec![];
@@ -556,21 +821,31 @@ mod tests {
                 kind: AggKind::RowCount,
                 args: AggArgs::None,
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Count,
                 args: AggArgs::Unary(DataType::Int64, 0),
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Count,
                 args: AggArgs::None,
                 return_type: DataType::Int64,
+                distinct: false,
             },
         ];
 
-        let hash_agg =
-            new_boxed_hash_agg_executor(Box::new(source), agg_calls, keys, keyspace, vec![], 1);
+        let hash_agg = new_boxed_hash_agg_executor(
+            Box::new(source),
+            agg_calls,
+            keys,
+            keyspace,
+            vec![],
+            1,
+            false,
+        );
         let mut hash_agg = hash_agg.execute();
 
         // Consume the init barrier
@@ -639,17 +914,20 @@ mod tests {
                 kind: AggKind::RowCount,
                 args: AggArgs::None,
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Sum,
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
+                distinct: false,
             },
             // This is local hash aggregation, so we add another sum state
             AggCall {
                 kind: AggKind::Sum,
                 args: AggArgs::Unary(DataType::Int64, 2),
                 return_type: DataType::Int64,
+                distinct: false,
             },
         ];
 
@@ -660,6 +938,7 @@ mod tests {
             keyspace,
             vec![],
             1,
+            false,
         );
         let mut hash_agg = hash_agg.execute();
 
@@ -731,16 +1010,25 @@ mod tests {
                 kind: AggKind::RowCount,
                 args: AggArgs::None,
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Min,
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
+                distinct: false,
             },
         ];
 
-        let hash_agg =
-            new_boxed_hash_agg_executor(Box::new(source), agg_calls, keys, keyspace, vec![], 1);
+        let hash_agg = new_boxed_hash_agg_executor(
+            Box::new(source),
+            agg_calls,
+            keys,
+            keyspace,
+            vec![],
+            1,
+            false,
+        );
         let mut hash_agg = hash_agg.execute();
 
         // Consume the init barrier
@@ -787,4 +1075,283 @@ mod tests {
                 .collect_vec()
         }
     }
+
+    async fn test_hash_aggregation_watermark(keyspace: Vec<Keyspace<impl StateStore>>) {
+        let schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int64)],
+        };
+        let (mut tx, source) = MockSource::channel(schema, PkIndices::new());
+        tx.push_barrier(1, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I
+            + 1
+            + 2
+            + 5",
+        ));
+        tx.push_watermark(0, DataType::Int64, ScalarImpl::Int64(3));
+        tx.push_barrier(2, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I
+            + 1
+            + 2
+            + 5",
+        ));
+        tx.push_barrier(3, false);
+
+        let agg_calls = vec![
+            AggCall {
+                kind: AggKind::RowCount,
+                args: AggArgs::None,
+                return_type: DataType::Int64,
+                distinct: false,
+            },
+            AggCall {
+                kind: AggKind::Count,
+                args: AggArgs::None,
+                return_type: DataType::Int64,
+                distinct: false,
+            },
+        ];
+
+        let hash_agg = new_boxed_hash_agg_executor(
+            Box::new(source),
+            agg_calls,
+            vec![0],
+            keyspace.clone(),
+            vec![],
+            1,
+            false,
+        );
+        let mut hash_agg = hash_agg.execute();
+
+        // Consume the init barrier
+        hash_agg.next().await.unwrap().unwrap();
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                " I I I
+                + 1 1 1
+                + 2 1 1
+                + 5 1 1"
+            )
+            .sorted_rows(),
+        );
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+        // The watermark is emitted after the groups below it are cleaned.
+        assert_eq!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Watermark(Watermark::new(0, DataType::Int64, ScalarImpl::Int64(3)))
+        );
+
+        // Late rows of cleaned groups are ignored.
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                "  I I I
+                U- 5 1 1
+                U+ 5 2 2"
+            )
+            .sorted_rows(),
+        );
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        // Only the state of group 5 is left in the storage.
+        assert_eq!(keyspace[0].scan(None, 2).await.unwrap().len(), 1);
+    }
+
+    async fn test_hash_aggregation_emit_on_window_close(keyspace: Vec<Keyspace<impl StateStore>>) {
+        let schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int64)],
+        };
+        let (mut tx, source) = MockSource::channel(schema, PkIndices::new());
+        tx.push_barrier(1, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I
+            + 1
+            + 2
+            + 5",
+        ));
+        tx.push_barrier(2, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I
+            + 1
+            + 5",
+        ));
+        tx.push_watermark(0, DataType::Int64, ScalarImpl::Int64(3));
+        tx.push_barrier(3, false);
+        tx.push_watermark(0, DataType::Int64, ScalarImpl::Int64(6));
+        tx.push_barrier(4, false);
+
+        let agg_calls = vec![
+            AggCall {
+                kind: AggKind::RowCount,
+                args: AggArgs::None,
+                return_type: DataType::Int64,
+                distinct: false,
+            },
+            AggCall {
+                kind: AggKind::Count,
+                args: AggArgs::None,
+                return_type: DataType::Int64,
+                distinct: false,
+            },
+        ];
+
+        let hash_agg = new_boxed_hash_agg_executor(
+            Box::new(source),
+            agg_calls,
+            vec![0],
+            keyspace.clone(),
+            vec![],
+            1,
+            true,
+        );
+        let mut hash_agg = hash_agg.execute();
+
+        // Consume the init barrier
+        hash_agg.next().await.unwrap().unwrap();
+        // Nothing is emitted before the watermark.
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        // The groups below the watermark are emitted once as inserts.
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                " I I I
+                + 1 2 2
+                + 2 1 1"
+            )
+            .sorted_rows(),
+        );
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+        assert_eq!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Watermark(Watermark::new(0, DataType::Int64, ScalarImpl::Int64(3)))
+        );
+
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                " I I I
+                + 5 2 2"
+            )
+            .sorted_rows(),
+        );
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+        assert_eq!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Watermark(Watermark::new(0, DataType::Int64, ScalarImpl::Int64(6)))
+        );
+
+        // All groups are cleaned after being emitted.
+        assert_eq!(keyspace[0].scan(None, 4).await.unwrap().len(), 0);
+    }
+
+    async fn test_hash_aggregation_emit_on_window_close_recovery(
+        keyspace: Vec<Keyspace<impl StateStore>>,
+    ) {
+        let schema = Schema {
+            fields: vec![Field::unnamed(DataType::Int64)],
+        };
+        let agg_calls = vec![
+            AggCall {
+                kind: AggKind::RowCount,
+                args: AggArgs::None,
+                return_type: DataType::Int64,
+                distinct: false,
+            },
+            AggCall {
+                kind: AggKind::Count,
+                args: AggArgs::None,
+                return_type: DataType::Int64,
+                distinct: false,
+            },
+        ];
+
+        let (mut tx, source) = MockSource::channel(schema.clone(), PkIndices::new());
+        tx.push_barrier(1, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I
+            + 1
+            + 1
+            + 2
+            + 5",
+        ));
+        tx.push_barrier(2, false);
+        let hash_agg = new_boxed_hash_agg_executor(
+            Box::new(source),
+            agg_calls.clone(),
+            vec![0],
+            keyspace.clone(),
+            vec![],
+            1,
+            true,
+        );
+        let mut hash_agg = hash_agg.execute();
+        hash_agg.next().await.unwrap().unwrap();
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+        drop(hash_agg);
+
+        // The groups are not emitted before the executor is recovered.
+        let (mut tx, source) = MockSource::channel(schema, PkIndices::new());
+        tx.push_barrier(3, false);
+        tx.push_watermark(0, DataType::Int64, ScalarImpl::Int64(3));
+        tx.push_barrier(4, false);
+        let hash_agg = new_boxed_hash_agg_executor(
+            Box::new(source),
+            agg_calls,
+            vec![0],
+            keyspace.clone(),
+            vec![],
+            1,
+            true,
+        );
+        let mut hash_agg = hash_agg.execute();
+        hash_agg.next().await.unwrap().unwrap();
+
+        // The closed groups are found in the storage and emitted.
+        let msg = hash_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                " I I I
+                + 1 2 2
+                + 2 1 1"
+            )
+            .sorted_rows(),
+        );
+        assert_matches!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+        assert_eq!(
+            hash_agg.next().await.unwrap().unwrap(),
+            Message::Watermark(Watermark::new(0, DataType::Int64, ScalarImpl::Int64(3)))
+        );
+
+        // Only the state of group 5 is left in the storage.
+        assert_eq!(keyspace[0].scan(None, 4).await.unwrap().len(), 1);
+    }
 }
@@ -22,8 +22,11 @@ use risingwave_common::catalog::{Schema, TableId};
 use risingwave_common::error::Result;
 use risingwave_common::hash::{calc_hash_key_kind, HashKey, HashKeyDispatcher, HashKeyKind};
 use risingwave_common::try_match_expand;
-use risingwave_common::types::{DataType, ToOwnedDatum};
+use risingwave_common::types::{
+    DataType, IntervalUnit, NaiveDateTimeWrapper, ScalarImpl, ToOwnedDatum,
+};
 use risingwave_expr::expr::{build_from_prost, RowExpression};
+use risingwave_expr::vector_op::arithmetic_op::{timestamp_interval_add, timestamp_interval_sub};
 use risingwave_pb::plan_common::JoinType as JoinTypeProto;
 use risingwave_pb::stream_plan;
 use risingwave_pb::stream_plan::stream_node::Node;
@@ -31,7 +34,10 @@ use risingwave_storage::{Keyspace, StateStore};
 
 use super::barrier_align::*;
 use super::error::StreamExecutorError;
-use super::{BoxedExecutor, BoxedMessageStream, Executor, Message, PkIndices, PkIndicesRef};
+use super::watermark::{hide_late_rows, BufferedWatermarks};
+use super::{
+    BoxedExecutor, BoxedMessageStream, Executor, Message, PkIndices, PkIndicesRef, Watermark,
+};
 use crate::common::StreamChunkBuilder;
 use crate::executor::managed_state::join::*;
 use crate::executor::ExecutorBuilder;
@@ -50,6 +56,10 @@ pub mod JoinType {
     pub const LeftOuter: JoinTypePrimitive = 1;
     pub const RightOuter: JoinTypePrimitive = 2;
     pub const FullOuter: JoinTypePrimitive = 3;
+    pub const LeftSemi: JoinTypePrimitive = 4;
+    pub const LeftAnti: JoinTypePrimitive = 5;
+    pub const RightSemi: JoinTypePrimitive = 6;
+    pub const RightAnti: JoinTypePrimitive = 7;
 }
 
 type SideTypePrimitive = u8;
@@ -72,6 +82,25 @@ const fn outer_side_null(join_type: JoinTypePrimitive, side_type: SideTypePrimit
         || (join_type == JoinType::RightOuter && side_type == SideType::Left)
 }
 
+const fn is_semi(join_type: JoinTypePrimitive) -> bool {
+    join_type == JoinType::LeftSemi || join_type == JoinType::RightSemi
+}
+
+const fn is_semi_or_anti(join_type: JoinTypePrimitive) -> bool {
+    join_type == JoinType::LeftSemi
+        || join_type == JoinType::LeftAnti
+        || join_type == JoinType::RightSemi
+        || join_type == JoinType::RightAnti
+}
+
+/// Whether the rows of the side are the output of a semi or anti join.
+const fn semi_anti_output_side(join_type: JoinTypePrimitive, side_type: SideTypePrimitive) -> bool {
+    ((join_type == JoinType::LeftSemi || join_type == JoinType::LeftAnti)
+        && side_type == SideType::Left)
+        || ((join_type == JoinType::RightSemi || join_type == JoinType::RightAnti)
+            && side_type == SideType::Right)
+}
+
 pub struct JoinParams {
     /// Indices of the join columns
     key_indices: Vec<usize>,
@@ -83,6 +112,16 @@ impl JoinParams {
     }
 }
 
+/// The time bound of an interval join, i.e. `left_time_col - right_time_col` is in
+/// `[lower, upper]`. The time columns are indexed in the schema of their own side.
+#[derive(Clone, Debug)]
+pub struct IntervalJoinParams {
+    pub left_time_col: usize,
+    pub right_time_col: usize,
+    pub lower: IntervalUnit,
+    pub upper: IntervalUnit,
+}
+
 struct JoinSide<K: HashKey, S: StateStore> {
     /// Store all data from a one side stream
     ht: JoinHashMap<K, S>,
@@ -140,6 +179,7 @@ struct HashJoinExecutorDispatcherArgs<S: StateStore> {
     key_indices: Vec<usize>,
     keyspace_l: Keyspace<S>,
     keyspace_r: Keyspace<S>,
+    interval: Option<IntervalJoinParams>,
 }
 
 impl<S: StateStore, const T: JoinTypePrimitive> HashKeyDispatcher
@@ -161,6 +201,7 @@ impl<S: StateStore, const T: JoinTypePrimitive> HashKeyDispatcher
             args.key_indices,
             args.keyspace_l,
             args.keyspace_r,
+            args.interval,
         )))
     }
 }
@@ -203,6 +244,16 @@ impl ExecutorBuilder for HashJoinExecutorBuilder {
             .map(|key| *key as usize)
             .collect::<Vec<_>>();
 
+        let interval = match &node.interval_bound {
+            Some(bound) => Some(IntervalJoinParams {
+                left_time_col: bound.left_time_col as usize,
+                right_time_col: bound.right_time_col as usize,
+                lower: bound.get_lower()?.into(),
+                upper: bound.get_upper()?.into(),
+            }),
+            None => None,
+        };
+
         macro_rules! impl_create_hash_join_executor {
             ([], $( { $join_type_proto:ident, $join_type:ident } ),*) => {
                 fn create_hash_join_executor<S: StateStore>(
@@ -224,7 +275,11 @@ impl ExecutorBuilder for HashJoinExecutorBuilder {
                     { Inner, Inner },
                     { LeftOuter, LeftOuter },
                     { RightOuter, RightOuter },
-                    { FullOuter, FullOuter }
+                    { FullOuter, FullOuter },
+                    { LeftSemi, LeftSemi },
+                    { LeftAnti, LeftAnti },
+                    { RightSemi, RightSemi },
+                    { RightAnti, RightAnti }
                 }
             };
         }
@@ -251,6 +306,7 @@ impl ExecutorBuilder for HashJoinExecutorBuilder {
             key_indices,
             keyspace_l: Keyspace::table_root(store.clone(), &left_table_id),
             keyspace_r: Keyspace::table_root(store, &right_table_id),
+            interval,
         };
 
         for_all_join_types! { impl_create_hash_join_executor };
@@ -260,7 +316,20 @@ impl ExecutorBuilder for HashJoinExecutorBuilder {
 }
 
 /// `HashJoinExecutor` takes two input streams and runs equal hash join on them.
-/// The output columns are the concatenation of left and right columns.
+/// The output columns are the concatenation of left and right columns, except for semi and anti
+/// joins, which only output the columns of the left or right side.
+///
+/// Each row in the states keeps its degree, i.e. the number of the matched rows of the other side.
+/// A semi join outputs the rows whose degree is positive, and an anti join outputs the rows whose
+/// degree is zero, so the output is retracted once the degree of a row changes from or to zero.
+///
+/// Once both sides have watermarks on the first join key, rows below the minimum of them can no
+/// longer be joined. The states below it are cleaned at the next barrier, and late rows are
+/// ignored.
+///
+/// For an interval join of append-only inputs, a watermark on the time column of one side bounds
+/// the time of the rows of the other side that can still be matched, and the rows out of the bound
+/// are evicted from the states at the next barrier.
 pub struct HashJoinExecutor<K: HashKey, S: StateStore, const T: JoinTypePrimitive> {
     /// Left input executor.
     input_l: Option<BoxedExecutor>,
@@ -268,6 +337,9 @@ pub struct HashJoinExecutor<K: HashKey, S: StateStore, const T: JoinTypePrimitiv
     input_r: Option<BoxedExecutor>,
     /// the data types of the formed new columns
     output_data_types: Vec<DataType>,
+    /// The data types of the concatenation of left and right columns, on which the non-equi
+    /// conditions are evaluated
+    full_data_types: Vec<DataType>,
     /// The schema of the hash join executor
     schema: Schema,
     /// The primary key indices of the schema
@@ -283,6 +355,20 @@ pub struct HashJoinExecutor<K: HashKey, S: StateStore, const T: JoinTypePrimitiv
     /// Epoch
     epoch: u64,
 
+    /// Watermarks of the first join key from both sides.
+    buffered_watermarks: BufferedWatermarks,
+    /// The watermark to clean states with at the next barrier.
+    pending_watermark: Option<ScalarImpl>,
+    /// Join keys below this value are cleaned.
+    state_watermark: Option<ScalarImpl>,
+
+    /// The time bound if this is an interval join.
+    interval: Option<IntervalJoinParams>,
+    /// The rows of the left side whose time is below this value are evicted at the next barrier.
+    expire_l: Option<ScalarImpl>,
+    /// The rows of the right side whose time is below this value are evicted at the next barrier.
+    expire_r: Option<ScalarImpl>,
+
     #[allow(dead_code)]
     /// Logical Operator Info
     op_info: String,
@@ -341,22 +427,35 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
         key_indices: Vec<usize>,
         ks_l: Keyspace<S>,
         ks_r: Keyspace<S>,
+        interval: Option<IntervalJoinParams>,
     ) -> Self {
         let new_column_n = input_l.schema().len() + input_r.schema().len();
         let side_l_column_n = input_l.schema().len();
 
-        let schema_fields = [
+        let full_schema_fields = [
             input_l.schema().fields.clone(),
             input_r.schema().fields.clone(),
         ]
         .concat();
 
-        assert_eq!(schema_fields.len(), new_column_n);
+        assert_eq!(full_schema_fields.len(), new_column_n);
+
+        let schema_fields = if semi_anti_output_side(T, SideType::Left) {
+            input_l.schema().fields.clone()
+        } else if semi_anti_output_side(T, SideType::Right) {
+            input_r.schema().fields.clone()
+        } else {
+            full_schema_fields.clone()
+        };
 
         let output_data_types = schema_fields
             .iter()
             .map(|field| field.data_type.clone())
             .collect();
+        let full_data_types = full_schema_fields
+            .iter()
+            .map(|field| field.data_type.clone())
+            .collect();
         let col_l_datatypes = input_l
             .schema()
             .fields
@@ -372,10 +471,14 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
 
         let pk_indices_l = input_l.pk_indices().to_vec();
         let pk_indices_r = input_r.pk_indices().to_vec();
+        // The rows of an interval join are removed by their time columns.
+        let time_col_l = interval.as_ref().map(|interval| interval.left_time_col);
+        let time_col_r = interval.as_ref().map(|interval| interval.right_time_col);
         Self {
             input_l: Some(input_l),
             input_r: Some(input_r),
             output_data_types,
+            full_data_types,
             schema: Schema {
                 fields: schema_fields,
             },
@@ -386,6 +489,7 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
                     params_l.key_indices.clone(),
                     col_l_datatypes.clone(),
                     ks_l.clone(),
+                    time_col_l,
                 ), // TODO: decide the target cap
                 key_indices: params_l.key_indices,
                 col_types: col_l_datatypes,
@@ -400,6 +504,7 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
                     params_r.key_indices.clone(),
                     col_r_datatypes.clone(),
                     ks_r.clone(),
+                    time_col_r,
                 ), // TODO: decide the target cap
                 key_indices: params_r.key_indices,
                 col_types: col_r_datatypes,
@@ -413,6 +518,12 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
             op_info,
             key_indices,
             epoch: 0,
+            buffered_watermarks: BufferedWatermarks::new(2),
+            pending_watermark: None,
+            state_watermark: None,
+            interval,
+            expire_l: None,
+            expire_r: None,
         }
     }
 
@@ -424,23 +535,56 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
         #[for_await]
         for msg in aligned_stream {
             match msg? {
-                AlignedMessage::Left(chunk) => {
+                AlignedMessage::Left(mut chunk) => {
+                    if let Some(watermark) = &self.state_watermark {
+                        chunk = hide_late_rows(chunk, self.side_l.key_indices[0], watermark);
+                    }
                     yield self
                         .eq_join_oneside::<{ SideType::Left }>(chunk)
                         .await
                         .map_err(StreamExecutorError::hash_join_error)?;
                 }
-                AlignedMessage::Right(chunk) => {
+                AlignedMessage::Right(mut chunk) => {
+                    if let Some(watermark) = &self.state_watermark {
+                        chunk = hide_late_rows(chunk, self.side_r.key_indices[0], watermark);
+                    }
                     yield self
                         .eq_join_oneside::<{ SideType::Right }>(chunk)
                         .await
                         .map_err(StreamExecutorError::hash_join_error)?;
                 }
+                AlignedMessage::WatermarkLeft(watermark) => {
+                    self.handle_interval_watermark::<{ SideType::Left }>(&watermark)
+                        .map_err(StreamExecutorError::hash_join_error)?;
+                    for watermark in self.handle_watermark::<{ SideType::Left }>(watermark) {
+                        yield Message::Watermark(watermark);
+                    }
+                }
+                AlignedMessage::WatermarkRight(watermark) => {
+                    self.handle_interval_watermark::<{ SideType::Right }>(&watermark)
+                        .map_err(StreamExecutorError::hash_join_error)?;
+                    for watermark in self.handle_watermark::<{ SideType::Right }>(watermark) {
+                        yield Message::Watermark(watermark);
+                    }
+                }
                 AlignedMessage::Barrier(barrier) => {
+                    self.evict_expired_rows()
+                        .await
+                        .map_err(StreamExecutorError::hash_join_error)?;
                     self.flush_data()
                         .await
                         .map_err(StreamExecutorError::hash_join_error)?;
                     let epoch = barrier.epoch.curr;
+                    // Clean states in the next epoch, so that the flushed expired rows are
+                    // covered by the range deletion.
+                    if let Some(watermark) = self.pending_watermark.take() {
+                        for side in [&mut self.side_l, &mut self.side_r] {
+                            side.ht
+                                .clean_below(&watermark, epoch)
+                                .await
+                                .map_err(StreamExecutorError::hash_join_error)?;
+                        }
+                    }
                     self.side_l.ht.update_epoch(epoch);
                     self.side_r.ht.update_epoch(epoch);
                     self.epoch = epoch;
@@ -450,6 +594,88 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
         }
     }
 
+    /// Handles a watermark from `SIDE`, and returns the output watermarks on the join key columns
+    /// of both sides if the minimum watermark of the first join key advances. Watermarks of other
+    /// columns are ignored.
+    fn handle_watermark<const SIDE: SideTypePrimitive>(
+        &mut self,
+        watermark: Watermark,
+    ) -> Vec<Watermark> {
+        let side = if SIDE == SideType::Left {
+            &self.side_l
+        } else {
+            &self.side_r
+        };
+        let output = if side.key_indices.first() == Some(&watermark.col_idx) {
+            self.buffered_watermarks
+                .handle_watermark(SIDE as usize, watermark.with_idx(0))
+        } else {
+            None
+        };
+        match output {
+            Some(watermark) => {
+                self.pending_watermark = Some(watermark.val.clone());
+                self.state_watermark = Some(watermark.val.clone());
+                if semi_anti_output_side(T, SideType::Left) {
+                    vec![watermark.with_idx(self.side_l.key_indices[0])]
+                } else if semi_anti_output_side(T, SideType::Right) {
+                    vec![watermark.with_idx(self.side_r.key_indices[0])]
+                } else {
+                    vec![
+                        watermark.clone().with_idx(self.side_l.key_indices[0]),
+                        watermark.with_idx(self.side_r.start_pos + self.side_r.key_indices[0]),
+                    ]
+                }
+            }
+            None => vec![],
+        }
+    }
+
+    /// Updates the time below which the rows of the other side are expired, if this is an interval
+    /// join and the watermark is on the time column of `SIDE`. The future rows of `SIDE` are not
+    /// below the watermark, so only the rows of the other side within the bound relative to the
+    /// watermark can be matched.
+    fn handle_interval_watermark<const SIDE: SideTypePrimitive>(
+        &mut self,
+        watermark: &Watermark,
+    ) -> Result<()> {
+        let Some(interval) = &self.interval else {
+            return Ok(());
+        };
+        let ScalarImpl::NaiveDateTime(time) = watermark.val else {
+            return Ok(());
+        };
+        if SIDE == SideType::Left && watermark.col_idx == interval.left_time_col {
+            // `right_time >= left_time - upper`
+            let threshold = timestamp_interval_sub::<
+                NaiveDateTimeWrapper,
+                IntervalUnit,
+                NaiveDateTimeWrapper,
+            >(time, interval.upper)?;
+            self.expire_r = Some(ScalarImpl::NaiveDateTime(threshold));
+        } else if SIDE == SideType::Right && watermark.col_idx == interval.right_time_col {
+            // `left_time >= right_time + lower`
+            let threshold = timestamp_interval_add::<
+                NaiveDateTimeWrapper,
+                IntervalUnit,
+                NaiveDateTimeWrapper,
+            >(time, interval.lower)?;
+            self.expire_l = Some(ScalarImpl::NaiveDateTime(threshold));
+        }
+        Ok(())
+    }
+
+    /// Evicts the rows out of the time bound of an interval join from the states.
+    async fn evict_expired_rows(&mut self) -> Result<()> {
+        if let Some(threshold) = self.expire_l.take() {
+            self.side_l.ht.remove_rows_below(&threshold).await?;
+        }
+        if let Some(threshold) = self.expire_r.take() {
+            self.side_r.ht.remove_rows_below(&threshold).await?;
+        }
+        Ok(())
+    }
+
     async fn flush_data(&mut self) -> Result<()> {
         let epoch = self.epoch;
         for side in [&mut self.side_l, &mut self.side_r] {
@@ -499,18 +725,18 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
 
     fn bool_from_array_ref(array_ref: ArrayRef) -> bool {
         let bool_array = array_ref.as_ref().as_bool();
-        bool_array.value_at(0).unwrap_or_else(|| {
-            panic!(
-                "Some thing wrong with the expression result. Bool array: {:?}",
-                bool_array
-            )
-        })
+        // A null result of the conditions means the rows are not matched.
+        bool_array.value_at(0).unwrap_or(false)
     }
 
     async fn eq_join_oneside<const SIDE: SideTypePrimitive>(
         &mut self,
         chunk: StreamChunk,
     ) -> Result<Message> {
+        if is_semi_or_anti(T) {
+            return self.semi_anti_join_oneside::<SIDE>(chunk).await;
+        }
+
         let epoch = self.epoch;
         let chunk = chunk.compact()?;
         let (ops, columns, visibility) = chunk.into_inner();
@@ -551,52 +777,48 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
                 match *op {
                     Op::Insert | Op::UpdateInsert => {
                         let entry_value = side_update.ht.get_or_init_without_cache(key).await?;
+                        // The number of the matched rows passing the non-equi conditions.
                         let mut degree = 0;
                         for matched_row in matched_rows.values_mut(epoch).await {
                             // TODO(yuhao-su): We should find a better way to eval the
                             // expression without concat
                             // two rows.
-                            let new_row = Self::row_concat(
+                            if !Self::cond_match(
+                                &mut self.cond,
+                                &self.full_data_types,
                                 &row,
                                 side_update.start_pos,
                                 &matched_row.row,
                                 side_match.start_pos,
-                            );
-                            let mut cond_match = true;
-                            // if there are non-equi expressions
-                            if let Some(ref mut cond) = self.cond {
-                                cond_match = Self::bool_from_array_ref(
-                                    cond.eval(&new_row, &self.output_data_types)?,
-                                );
+                            )? {
+                                continue;
                             }
-                            if cond_match {
-                                degree += 1;
-                                if matched_row.is_zero_degree() && outer_side_null(T, SIDE) {
-                                    // if the matched_row does not have any current matches
-                                    stream_chunk_builder
-                                        .append_row_matched(Op::UpdateDelete, &matched_row.row)?;
-                                    stream_chunk_builder.append_row(
-                                        Op::UpdateInsert,
-                                        &row,
-                                        &matched_row.row,
-                                    )?;
-                                } else {
-                                    // concat with the matched_row and append the new row
-                                    // FIXME: we always use `Op::Delete` here to avoid violating
-                                    // the assumption for U+ after U-.
-                                    stream_chunk_builder.append_row(
-                                        Op::Insert,
-                                        &row,
-                                        &matched_row.row,
-                                    )?;
-                                }
-                                matched_row.inc_degree();
+                            degree += 1;
+                            if matched_row.is_zero_degree() && outer_side_null(T, SIDE) {
+                                // if the matched_row does not have any current matches
+                                stream_chunk_builder
+                                    .append_row_matched(Op::UpdateDelete, &matched_row.row)?;
+                                stream_chunk_builder.append_row(
+                                    Op::UpdateInsert,
+                                    &row,
+                                    &matched_row.row,
+                                )?;
                             } else {
-                                // not matched
-                                if outer_side_keep(T, SIDE) {
-                                    stream_chunk_builder.append_row_update(*op, &row)?;
-                                }
+                                // concat with the matched_row and append the new row
+                                // FIXME: we always use `Op::Delete` here to avoid violating
+                                // the assumption for U+ after U-.
+                                stream_chunk_builder.append_row(
+                                    Op::Insert,
+                                    &row,
+                                    &matched_row.row,
+                                )?;
                             }
+                            matched_row.inc_degree();
+                        }
+                        // The row is only padded with nulls if no matched row passes the
+                        // non-equi conditions.
+                        if degree == 0 && outer_side_keep(T, SIDE) {
+                            stream_chunk_builder.append_row_update(*op, &row)?;
                         }
                         entry_value.insert(pk, JoinRow::new(value, degree));
                     }
@@ -605,51 +827,42 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
                             // remove the row by it's primary key
                             v.remove(pk);
 
+                            let mut degree = 0;
                             for matched_row in matched_rows.values_mut(epoch).await {
-                                let new_row = Self::row_concat(
+                                if !Self::cond_match(
+                                    &mut self.cond,
+                                    &self.full_data_types,
                                     &row,
                                     side_update.start_pos,
                                     &matched_row.row,
                                     side_match.start_pos,
-                                );
-
-                                let mut cond_match = true;
-                                // if there are non-equi expressions
-                                if let Some(ref mut cond) = self.cond {
-                                    cond_match = Self::bool_from_array_ref(
-                                        cond.eval(&new_row, &self.output_data_types)?,
-                                    );
+                                )? {
+                                    continue;
                                 }
-                                if cond_match {
-                                    if matched_row.is_zero_degree() && outer_side_null(T, SIDE) {
-                                        // if the matched_row does not have any current matches
-                                        stream_chunk_builder.append_row(
-                                            Op::UpdateDelete,
-                                            &row,
-                                            &matched_row.row,
-                                        )?;
-                                        stream_chunk_builder.append_row_matched(
-                                            Op::UpdateInsert,
-                                            &matched_row.row,
-                                        )?;
-                                    } else {
-                                        // concat with the matched_row and append the new row
-                                        // FIXME: we always use `Op::Delete` here to avoid violating
-                                        // the assumption for U+ after U-.
-                                        stream_chunk_builder.append_row(
-                                            Op::Delete,
-                                            &row,
-                                            &matched_row.row,
-                                        )?;
-                                    }
-                                    matched_row.dec_degree();
+                                degree += 1;
+                                if matched_row.dec_degree() == 0 && outer_side_null(T, SIDE) {
+                                    // if the matched_row loses its last match
+                                    stream_chunk_builder.append_row(
+                                        Op::UpdateDelete,
+                                        &row,
+                                        &matched_row.row,
+                                    )?;
+                                    stream_chunk_builder
+                                        .append_row_matched(Op::UpdateInsert, &matched_row.row)?;
                                 } else {
-                                    // not matched
-                                    if outer_side_keep(T, SIDE) {
-                                        stream_chunk_builder.append_row_update(*op, &row)?;
-                                    }
+                                    // concat with the matched_row and append the new row
+                                    // FIXME: we always use `Op::Delete` here to avoid violating
+                                    // the assumption for U+ after U-.
+                                    stream_chunk_builder.append_row(
+                                        Op::Delete,
+                                        &row,
+                                        &matched_row.row,
+                                    )?;
                                 }
                             }
+                            if degree == 0 && outer_side_keep(T, SIDE) {
+                                stream_chunk_builder.append_row_update(*op, &row)?;
+                            }
                         }
                     }
                 };
@@ -680,6 +893,130 @@ impl<K: HashKey, S: StateStore, const T: JoinTypePrimitive> HashJoinExecutor<K,
 
         Ok(Message::Chunk(new_chunk))
     }
+
+    /// Evaluates the non-equi conditions on the concatenation of the two rows.
+    fn cond_match(
+        cond: &mut Option<RowExpression>,
+        full_data_types: &[DataType],
+        row_update: &RowRef<'_>,
+        update_start_pos: usize,
+        row_matched: &Row,
+        matched_start_pos: usize,
+    ) -> Result<bool> {
+        match cond {
+            Some(cond) => {
+                let new_row =
+                    Self::row_concat(row_update, update_start_pos, row_matched, matched_start_pos);
+                Ok(Self::bool_from_array_ref(
+                    cond.eval(&new_row, full_data_types)?,
+                ))
+            }
+            None => Ok(true),
+        }
+    }
+
+    /// Joins a chunk of `SIDE` for semi and anti joins, whose output is the rows of one side.
+    ///
+    /// A row of the output side is output when it is inserted or deleted with a positive degree
+    /// (semi) or zero degree (anti). A row of the other side changes the degrees of the matched
+    /// rows, which are output or retracted once their degrees change from or to zero.
+    async fn semi_anti_join_oneside<const SIDE: SideTypePrimitive>(
+        &mut self,
+        chunk: StreamChunk,
+    ) -> Result<Message> {
+        let epoch = self.epoch;
+        let chunk = chunk.compact()?;
+        let (ops, columns, visibility) = chunk.into_inner();
+
+        let data_chunk = {
+            let data_chunk_builder = DataChunk::builder().columns(columns);
+            if let Some(visibility) = visibility {
+                data_chunk_builder.visibility(visibility).build()
+            } else {
+                data_chunk_builder.build()
+            }
+        };
+
+        let (side_update, side_match) = if SIDE == SideType::Left {
+            (&mut self.side_l, &mut self.side_r)
+        } else {
+            (&mut self.side_r, &mut self.side_l)
+        };
+        let is_output_side = semi_anti_output_side(T, SIDE);
+
+        // Only the rows of the output side are appended, which fill all the output columns.
+        let mut stream_chunk_builder =
+            StreamChunkBuilder::new(data_chunk.capacity(), &self.output_data_types, 0, 0)?;
+
+        let keys = K::build(&side_update.key_indices, &data_chunk)?;
+        for (idx, (row, op)) in data_chunk.rows().zip_eq(ops.iter()).enumerate() {
+            let key = &keys[idx];
+            let value = row.to_owned_row();
+            let pk = row.row_by_indices(&side_update.pk_indices);
+            let is_insert = matches!(*op, Op::Insert | Op::UpdateInsert);
+
+            if !is_insert {
+                match side_update.ht.get_mut_without_cached(key).await? {
+                    Some(state) => state.remove(pk.clone()),
+                    // The row has never been inserted.
+                    None => continue,
+                }
+            }
+
+            let mut degree = 0;
+            if let Some(matched_rows) = Self::hash_eq_match(key, &mut side_match.ht).await {
+                for matched_row in matched_rows.values_mut(epoch).await {
+                    if !Self::cond_match(
+                        &mut self.cond,
+                        &self.full_data_types,
+                        &row,
+                        side_update.start_pos,
+                        &matched_row.row,
+                        side_match.start_pos,
+                    )? {
+                        continue;
+                    }
+                    degree += 1;
+                    if is_output_side {
+                        continue;
+                    }
+                    let matched_degree = if is_insert {
+                        matched_row.inc_degree()
+                    } else {
+                        matched_row.dec_degree()
+                    };
+                    // The matched row gets its first match, or loses its last match.
+                    if (is_insert && matched_degree == 1) || (!is_insert && matched_degree == 0) {
+                        let output_insert = is_insert == is_semi(T);
+                        stream_chunk_builder.append_row_matched(
+                            if output_insert {
+                                Op::Insert
+                            } else {
+                                Op::Delete
+                            },
+                            &matched_row.row,
+                        )?;
+                    }
+                }
+            }
+
+            if is_output_side && (degree > 0) == is_semi(T) {
+                stream_chunk_builder
+                    .append_row_update(if is_insert { Op::Insert } else { Op::Delete }, &row)?;
+            }
+            if is_insert {
+                side_update
+                    .ht
+                    .get_or_init_without_cache(key)
+                    .await?
+                    .insert(pk, JoinRow::new(value, degree));
+            }
+        }
+
+        let new_chunk = stream_chunk_builder.finish()?;
+
+        Ok(Message::Chunk(new_chunk))
+    }
 }
 
 #[cfg(test)]
@@ -746,6 +1083,42 @@ mod tests {
             vec![],
             ks_l,
             ks_r,
+            None,
+        );
+        (tx_l, tx_r, Box::new(executor).execute())
+    }
+
+    fn create_interval_executor() -> (MessageSender, MessageSender, BoxedMessageStream) {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Timestamp),
+            ],
+        };
+        let (tx_l, source_l) = MockSource::channel(schema.clone(), vec![0, 1]);
+        let (tx_r, source_r) = MockSource::channel(schema, vec![0, 1]);
+        let (ks_l, ks_r) = create_in_memory_keyspace();
+
+        // `l.ts BETWEEN r.ts AND r.ts + INTERVAL '1' HOUR`
+        let interval = IntervalJoinParams {
+            left_time_col: 1,
+            right_time_col: 1,
+            lower: IntervalUnit::default(),
+            upper: IntervalUnit::from_minutes(60),
+        };
+        let executor = HashJoinExecutor::<Key64, MemoryStateStore, { JoinType::Inner }>::new(
+            Box::new(source_l),
+            Box::new(source_r),
+            JoinParams::new(vec![0]),
+            JoinParams::new(vec![0]),
+            vec![0, 1, 2, 3],
+            1,
+            None,
+            "HashJoinExecutor".to_string(),
+            vec![],
+            ks_l,
+            ks_r,
+            Some(interval),
         );
         (tx_l, tx_r, Box::new(executor).execute())
     }
@@ -1242,6 +1615,84 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_streaming_hash_left_join_with_nonequi_condition() {
+        let chunk_l1 = StreamChunk::from_pretty(
+            "  I I
+             + 1 4
+             + 1 9",
+        );
+        let chunk_l2 = StreamChunk::from_pretty(
+            "  I  I
+             + 1 10",
+        );
+        let chunk_r1 = StreamChunk::from_pretty(
+            "  I I
+             + 1 6
+             + 1 5",
+        );
+        let chunk_r2 = StreamChunk::from_pretty(
+            "  I I
+             - 1 6
+             - 1 5",
+        );
+        let (mut tx_l, mut tx_r, mut hash_join) = create_executor::<{ JoinType::LeftOuter }>(true);
+
+        // push the init barrier for left and right
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        hash_join.next().await.unwrap().unwrap();
+
+        // push the 1st left chunk
+        tx_l.push_chunk(chunk_l1);
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I I I
+                + 1 4 . .
+                + 1 9 . ."
+            )
+        );
+
+        // push the 1st right chunk, only the rows passing the condition are matched
+        tx_r.push_chunk(chunk_r1);
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                "  I I I I
+                U- 1 4 . .
+                U+ 1 4 1 6
+                +  1 4 1 5"
+            )
+        );
+
+        // push the 2nd left chunk, padded with nulls once as no matched row passes the condition
+        tx_l.push_chunk(chunk_l2);
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I  I I I
+                + 1 10 . ."
+            )
+        );
+
+        // push the 2nd right chunk
+        tx_r.push_chunk(chunk_r2);
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                "  I I I I
+                -  1 4 1 6
+                U- 1 4 1 5
+                U+ 1 4 . ."
+            )
+        );
+    }
+
     #[tokio::test]
     async fn test_streaming_hash_inner_join_with_nonequi_condition() {
         let chunk_l1 = StreamChunk::from_pretty(
@@ -1308,4 +1759,286 @@ mod tests {
             )
         );
     }
+
+    #[tokio::test]
+    async fn test_streaming_hash_left_semi_join() {
+        let chunk_l1 = StreamChunk::from_pretty(
+            "  I I
+             + 1 4
+             + 2 5
+             + 3 6",
+        );
+        let chunk_l2 = StreamChunk::from_pretty(
+            "  I I
+             + 4 1
+             + 5 2",
+        );
+        let chunk_r1 = StreamChunk::from_pretty(
+            "  I I
+             + 2 7
+             + 4 8
+             + 2 9",
+        );
+        let chunk_r2 = StreamChunk::from_pretty(
+            "  I  I
+             + 3 10
+             - 2 7
+             - 2 9",
+        );
+        let (mut tx_l, mut tx_r, mut hash_join) = create_executor::<{ JoinType::LeftSemi }>(false);
+
+        // push the init barrier for left and right
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        hash_join.next().await.unwrap().unwrap();
+
+        // push the 1st left chunk
+        tx_l.push_chunk(chunk_l1);
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(chunk.into_chunk().unwrap(), StreamChunk::from_pretty("I I"));
+
+        // push the 1st right chunk
+        tx_r.push_chunk(chunk_r1);
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I
+                + 2 5"
+            )
+        );
+
+        // push the 2nd right chunk
+        tx_r.push_chunk(chunk_r2);
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I
+                + 3 6
+                - 2 5"
+            )
+        );
+
+        // push the 2nd left chunk
+        tx_l.push_chunk(chunk_l2);
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I
+                + 4 1"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_streaming_hash_left_anti_join() {
+        let chunk_l1 = StreamChunk::from_pretty(
+            "  I I
+             + 1 4
+             + 2 5
+             + 3 6",
+        );
+        let chunk_l2 = StreamChunk::from_pretty(
+            "  I I
+             + 4 1
+             + 5 2
+             - 1 4",
+        );
+        let chunk_r1 = StreamChunk::from_pretty(
+            "  I I
+             + 2 7
+             + 4 8
+             + 2 9",
+        );
+        let chunk_r2 = StreamChunk::from_pretty(
+            "  I  I
+             + 3 10
+             - 2 7
+             - 2 9",
+        );
+        let (mut tx_l, mut tx_r, mut hash_join) = create_executor::<{ JoinType::LeftAnti }>(false);
+
+        // push the init barrier for left and right
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        hash_join.next().await.unwrap().unwrap();
+
+        // push the 1st left chunk
+        tx_l.push_chunk(chunk_l1);
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I
+                + 1 4
+                + 2 5
+                + 3 6"
+            )
+        );
+
+        // push the 1st right chunk
+        tx_r.push_chunk(chunk_r1);
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I
+                - 2 5"
+            )
+        );
+
+        // push the 2nd right chunk, the deletions retract the anti join result
+        tx_r.push_chunk(chunk_r2);
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I
+                - 3 6
+                + 2 5"
+            )
+        );
+
+        // push the 2nd left chunk
+        tx_l.push_chunk(chunk_l2);
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I
+                + 5 2
+                - 1 4"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_streaming_hash_inner_join_with_watermark() {
+        let (mut tx_l, mut tx_r, mut hash_join) = create_executor::<{ JoinType::Inner }>(false);
+        let watermark = |col_idx, val| {
+            Message::Watermark(Watermark::new(
+                col_idx,
+                DataType::Int64,
+                ScalarImpl::Int64(val),
+            ))
+        };
+
+        // push the init barrier for left and right
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        hash_join.next().await.unwrap().unwrap();
+
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 1 4
+             + 5 5",
+        ));
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty("I I I I")
+        );
+
+        // The output watermark is the minimum of both sides, on the join keys of both sides.
+        tx_l.push_watermark(0, DataType::Int64, ScalarImpl::Int64(3));
+        tx_r.push_watermark(0, DataType::Int64, ScalarImpl::Int64(4));
+        assert_eq!(hash_join.next().await.unwrap().unwrap(), watermark(0, 3));
+        assert_eq!(hash_join.next().await.unwrap().unwrap(), watermark(2, 3));
+
+        tx_l.push_barrier(2, false);
+        tx_r.push_barrier(2, false);
+        hash_join
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_barrier()
+            .unwrap();
+
+        // Late rows are ignored.
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I I
+             + 1 7
+             + 5 8",
+        ));
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I I I
+                + 5 5 5 8"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_streaming_interval_join() {
+        let (mut tx_l, mut tx_r, mut hash_join) = create_interval_executor();
+        let time = |s: &str| {
+            ScalarImpl::NaiveDateTime(NaiveDateTimeWrapper::new(
+                chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S").unwrap(),
+            ))
+        };
+
+        tx_l.push_barrier(1, false);
+        tx_r.push_barrier(1, false);
+        hash_join.next().await.unwrap().unwrap();
+
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I TS
+             + 1 2022-01-01T10:00:00",
+        ));
+        hash_join.next().await.unwrap().unwrap();
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I TS
+             + 1 2022-01-01T10:30:00",
+        ));
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I TS                  I TS
+                + 1 2022-01-01T10:30:00 1 2022-01-01T10:00:00"
+            )
+        );
+
+        // The right rows before 10:30 can no longer be matched by the left side after 11:30, and
+        // the left rows before 10:40 can no longer be matched by the right side after 10:40.
+        tx_l.push_watermark(1, DataType::Timestamp, time("2022-01-01T11:30:00"));
+        tx_r.push_watermark(1, DataType::Timestamp, time("2022-01-01T10:40:00"));
+        tx_l.push_barrier(2, false);
+        tx_r.push_barrier(2, false);
+        hash_join
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_barrier()
+            .unwrap();
+
+        // Rows behind the watermarks show that the expired rows are evicted from the states.
+        tx_l.push_chunk(StreamChunk::from_pretty(
+            "  I TS
+             + 1 2022-01-01T10:45:00",
+        ));
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty("I TS I TS")
+        );
+        tx_r.push_chunk(StreamChunk::from_pretty(
+            "  I TS
+             + 1 2022-01-01T10:20:00",
+        ));
+        let chunk = hash_join.next().await.unwrap().unwrap();
+        assert_eq!(
+            chunk.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I TS                  I TS
+                + 1 2022-01-01T10:45:00 1 2022-01-01T10:20:00"
+            )
+        );
+    }
 }
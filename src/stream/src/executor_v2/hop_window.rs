@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::num::NonZeroUsize;
+use std::sync::Arc;
 
 use futures::StreamExt;
 use futures_async_stream::try_stream;
@@ -28,7 +29,7 @@ use risingwave_pb::stream_plan::{self, stream_node};
 use risingwave_storage::StateStore;
 
 use super::error::StreamExecutorError;
-use super::{BoxedExecutor, Executor, ExecutorInfo, Message};
+use super::{BoxedExecutor, Executor, ExecutorInfo, Message, Watermark};
 use crate::executor::ExecutorBuilder;
 use crate::task::{ExecutorParams, LocalStreamManagerCore};
 
@@ -144,10 +145,9 @@ impl HopWindowExecutor {
         let schema = self.info.schema;
         let time_col_data_type = schema.fields()[time_col_idx].data_type();
         let time_col_ref = InputRefExpression::new(time_col_data_type, self.time_col_idx).boxed();
-
-        let window_slide_expr =
-            LiteralExpression::new(DataType::Interval, Some(ScalarImpl::Interval(window_slide)))
-                .boxed();
+        // `window_start` and `window_end` are appended after the input columns.
+        let window_start_col_idx = input.schema().len();
+        let window_end_col_idx = window_start_col_idx + 1;
 
         // The first window_start of hop window should be:
         // tumble_start(`time_col` - (`window_size` - `window_slide`), `window_slide`).
@@ -158,31 +158,76 @@ impl HopWindowExecutor {
                 window_size, window_slide
             ))
         })?;
-        let window_size_sub_slide_expr = LiteralExpression::new(
-            DataType::Interval,
-            Some(ScalarImpl::Interval(window_size_sub_slide)),
-        )
-        .boxed();
-
-        let hop_start = new_binary_expr(
-            expr_node::Type::TumbleStart,
-            risingwave_common::types::DataType::Timestamp,
+        let interval_expr = |interval| {
+            LiteralExpression::new(DataType::Interval, Some(ScalarImpl::Interval(interval))).boxed()
+        };
+        let hop_start_expr = |time_col_ref| {
             new_binary_expr(
-                expr_node::Type::Subtract,
-                DataType::Timestamp,
-                time_col_ref,
-                window_size_sub_slide_expr,
-            ),
-            window_slide_expr,
+                expr_node::Type::TumbleStart,
+                risingwave_common::types::DataType::Timestamp,
+                new_binary_expr(
+                    expr_node::Type::Subtract,
+                    DataType::Timestamp,
+                    time_col_ref,
+                    interval_expr(window_size_sub_slide),
+                ),
+                interval_expr(window_slide),
+            )
+        };
+
+        let hop_start = hop_start_expr(time_col_ref);
+
+        // Rows after a watermark `W` of the time column are in windows starting from at least
+        // `hop_start(W)` and ending at least `hop_start(W) + window_size`.
+        let watermark_window_start =
+            hop_start_expr(InputRefExpression::new(DataType::Timestamp, 0).boxed());
+        let watermark_window_end = new_binary_expr(
+            expr_node::Type::Add,
+            DataType::Timestamp,
+            hop_start_expr(InputRefExpression::new(DataType::Timestamp, 0).boxed()),
+            interval_expr(window_size),
         );
 
         #[for_await]
         for msg in input.execute() {
             let msg = msg?;
-            let Message::Chunk(chunk) = msg else {
-                // TODO: syn has not supported `let_else`, we desugar here manually.
-                yield std::task::Poll::Ready(msg);
-                continue;
+            let chunk = match msg {
+                Message::Chunk(chunk) => chunk,
+                Message::Watermark(watermark) if watermark.col_idx == time_col_idx => {
+                    let watermark_chunk = {
+                        let mut builder = watermark
+                            .data_type
+                            .create_array_builder(1)
+                            .map_err(StreamExecutorError::eval_error)?;
+                        builder
+                            .append_datum(&Some(watermark.val.clone()))
+                            .map_err(StreamExecutorError::eval_error)?;
+                        let array = builder.finish().map_err(StreamExecutorError::eval_error)?;
+                        DataChunk::new(vec![Column::new(Arc::new(array))], None)
+                    };
+                    yield Message::Watermark(watermark);
+                    for (expr, col_idx) in [
+                        (&watermark_window_start, window_start_col_idx),
+                        (&watermark_window_end, window_end_col_idx),
+                    ] {
+                        let val = expr
+                            .eval(&watermark_chunk)
+                            .map_err(StreamExecutorError::eval_error)?
+                            .datum_at(0);
+                        if let Some(val) = val {
+                            yield Message::Watermark(Watermark::new(
+                                col_idx,
+                                DataType::Timestamp,
+                                val,
+                            ));
+                        }
+                    }
+                    continue;
+                }
+                msg => {
+                    yield msg;
+                    continue;
+                }
             };
             // TODO: compact may be not necessary here.
             let chunk = chunk.compact().map_err(StreamExecutorError::executor_v1)?;
@@ -253,10 +298,10 @@ mod tests {
     use futures::StreamExt;
     use risingwave_common::array::stream_chunk::StreamChunkTestExt;
     use risingwave_common::catalog::{Field, Schema};
-    use risingwave_common::types::{DataType, IntervalUnit};
+    use risingwave_common::types::{DataType, IntervalUnit, NaiveDateTimeWrapper, ScalarImpl};
 
     use crate::executor_v2::test_utils::MockSource;
-    use crate::executor_v2::{Executor, ExecutorInfo, StreamChunk};
+    use crate::executor_v2::{Executor, ExecutorInfo, Message, StreamChunk, Watermark};
 
     #[tokio::test]
     async fn test_execute() {
@@ -336,4 +381,49 @@ mod tests {
             )
         );
     }
+
+    #[tokio::test]
+    async fn test_watermark() {
+        let schema = Schema::new(vec![
+            Field::unnamed(DataType::Int64),
+            Field::with_name(DataType::Timestamp, "created_at"),
+        ]);
+        let timestamp = |s: &str| {
+            ScalarImpl::NaiveDateTime(NaiveDateTimeWrapper(
+                format!("2022-2-2T{}", s).parse().unwrap(),
+            ))
+        };
+        let (mut tx, input) = MockSource::channel(schema.clone(), vec![0]);
+        // Watermarks of other columns are forwarded as they are.
+        tx.push_watermark(0, DataType::Int64, ScalarImpl::Int64(1));
+        tx.push_watermark(1, DataType::Timestamp, timestamp("10:22:00"));
+
+        let executor = super::HopWindowExecutor::new(
+            input.boxed(),
+            ExecutorInfo {
+                schema,
+                pk_indices: vec![0],
+                identity: "test".to_string(),
+            },
+            1,
+            IntervalUnit::from_minutes(15),
+            IntervalUnit::from_minutes(30),
+        )
+        .boxed();
+        let mut stream = executor.execute();
+
+        let expected = [
+            Watermark::new(0, DataType::Int64, ScalarImpl::Int64(1)),
+            Watermark::new(1, DataType::Timestamp, timestamp("10:22:00")),
+            // The earliest window of rows after 10:22 is [10:00, 10:30).
+            Watermark::new(2, DataType::Timestamp, timestamp("10:00:00")),
+            Watermark::new(3, DataType::Timestamp, timestamp("10:30:00")),
+        ];
+        for watermark in expected {
+            assert_eq!(
+                stream.next().await.unwrap().unwrap(),
+                Message::Watermark(watermark)
+            );
+        }
+    }
 }
@@ -0,0 +1,494 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::column::Column;
+use risingwave_common::array::{Op, Row, StreamChunk};
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::catalog::Schema;
+use risingwave_common::error::{ErrorCode, Result};
+use risingwave_common::try_match_expand;
+use risingwave_pb::stream_plan;
+use risingwave_pb::stream_plan::stream_node::Node;
+use risingwave_storage::StateStore;
+
+use super::{
+    BoxedExecutor, BoxedMessageStream, Executor, ExecutorInfo, Message, PkIndices, PkIndicesRef,
+    StreamExecutorResult, Watermark,
+};
+use crate::executor::ExecutorBuilder;
+use crate::executor_v2::aggregation::{
+    agg_input_array_refs, create_streaming_agg_state, generate_agg_schema, AggCall,
+    StreamingAggStateImpl,
+};
+use crate::executor_v2::error::StreamExecutorError;
+use crate::task::{build_agg_call_from_prost, ExecutorParams, LocalStreamManagerCore};
+
+pub struct LocalHashAggExecutorBuilder {}
+
+impl ExecutorBuilder for LocalHashAggExecutorBuilder {
+    fn new_boxed_executor(
+        mut params: ExecutorParams,
+        node: &stream_plan::StreamNode,
+        _store: impl StateStore,
+        _stream: &mut LocalStreamManagerCore,
+    ) -> Result<BoxedExecutor> {
+        let node = try_match_expand!(node.get_node().unwrap(), Node::LocalHashAggNode)?;
+        let key_indices = node
+            .get_distribution_keys()
+            .iter()
+            .map(|key| *key as usize)
+            .collect();
+        let agg_calls: Vec<AggCall> = node
+            .get_agg_calls()
+            .iter()
+            .map(build_agg_call_from_prost)
+            .try_collect()?;
+
+        Ok(LocalHashAggExecutor::new(
+            params.input.remove(0),
+            agg_calls,
+            key_indices,
+            params.pk_indices,
+            params.executor_id,
+        )?
+        .boxed())
+    }
+}
+
+/// [`LocalHashAggExecutor`] pre-aggregates the rows of each group within an epoch, and emits the
+/// partial results as inserts on barriers. It keeps no state across epochs, so that the partial
+/// results, which are deltas of the aggregations, are merged by the global
+/// [`crate::executor_v2::HashAggExecutor`] after the shuffle by group keys.
+///
+/// Only aggregations whose deltas can be merged are supported, e.g. the partial results of
+/// `count` are summed up by the global phase. Note that a group is emitted even if its row count
+/// does not change, as an update may still change the other aggregations.
+pub struct LocalHashAggExecutor {
+    input: BoxedExecutor,
+    info: ExecutorInfo,
+    agg_calls: Vec<AggCall>,
+    key_indices: Vec<usize>,
+}
+
+/// The groups seen in the current epoch, in the order of their first appearances.
+#[derive(Default)]
+struct LocalGroups {
+    index: HashMap<Row, usize>,
+    groups: Vec<(Row, Vec<Box<dyn StreamingAggStateImpl>>)>,
+}
+
+impl Executor for LocalHashAggExecutor {
+    fn execute(self: Box<Self>) -> BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+impl LocalHashAggExecutor {
+    pub fn new(
+        input: BoxedExecutor,
+        agg_calls: Vec<AggCall>,
+        key_indices: Vec<usize>,
+        pk_indices: PkIndices,
+        executor_id: u64,
+    ) -> Result<Self> {
+        // The partial results of distinct aggregations can not be merged by the global phase.
+        if agg_calls.iter().any(|agg_call| agg_call.distinct) {
+            return Err(ErrorCode::NotImplemented(
+                "distinct aggregation in local hash agg".to_string(),
+                None.into(),
+            )
+            .into());
+        }
+        let schema = generate_agg_schema(input.as_ref(), &agg_calls, Some(&key_indices));
+        let info = ExecutorInfo {
+            schema,
+            pk_indices,
+            identity: format!("LocalHashAggExecutor-{:X}", executor_id),
+        };
+
+        Ok(Self {
+            input,
+            info,
+            agg_calls,
+            key_indices,
+        })
+    }
+
+    fn create_states(agg_calls: &[AggCall]) -> Result<Vec<Box<dyn StreamingAggStateImpl>>> {
+        agg_calls
+            .iter()
+            .map(|agg_call| {
+                create_streaming_agg_state(
+                    agg_call.args.arg_types(),
+                    &agg_call.kind,
+                    &agg_call.return_type,
+                    None,
+                )
+            })
+            .try_collect()
+    }
+
+    fn apply_chunk(
+        agg_calls: &[AggCall],
+        key_indices: &[usize],
+        groups: &mut LocalGroups,
+        chunk: StreamChunk,
+    ) -> Result<()> {
+        let (data_chunk, ops) = chunk.into_parts();
+        let capacity = data_chunk.capacity();
+
+        // The visibility map of each group touched by the chunk, which also shadows the rows
+        // shadowed in the input.
+        let mut vis_maps: Vec<(usize, Vec<bool>)> = vec![];
+        let mut group_to_vis_map = HashMap::new();
+        for row_idx in 0..capacity {
+            if let Some(visibility) = data_chunk.visibility() && !visibility.is_set(row_idx)? {
+                continue;
+            }
+            let key = data_chunk
+                .row_at_unchecked_vis(row_idx)
+                .row_by_indices(key_indices);
+            let group_idx = match groups.index.entry(key) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    groups
+                        .groups
+                        .push((entry.key().clone(), Self::create_states(agg_calls)?));
+                    *entry.insert(groups.groups.len() - 1)
+                }
+            };
+            let vis_map_idx = *group_to_vis_map.entry(group_idx).or_insert_with(|| {
+                vis_maps.push((group_idx, vec![false; capacity]));
+                vis_maps.len() - 1
+            });
+            vis_maps[vis_map_idx].1[row_idx] = true;
+        }
+
+        let (columns, _) = data_chunk.into_parts();
+        let agg_inputs = agg_input_array_refs(agg_calls, &columns);
+        for (group_idx, vis_map) in vis_maps {
+            let vis_map = Bitmap::try_from(vis_map)?;
+            let (_, states) = &mut groups.groups[group_idx];
+            for (state, inputs) in states.iter_mut().zip_eq(agg_inputs.iter()) {
+                state.apply_batch(&ops, Some(&vis_map), inputs)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Emit the partial results of the groups in the current epoch, and clear the groups.
+    fn flush_groups(schema: &Schema, groups: &mut LocalGroups) -> Result<Option<StreamChunk>> {
+        let LocalGroups { groups, .. } = std::mem::take(groups);
+        if groups.is_empty() {
+            return Ok(None);
+        }
+
+        let cardinality = groups.len();
+        let mut builders = schema.create_array_builders(cardinality)?;
+        for (key, states) in groups {
+            let outputs: Vec<_> = states
+                .iter()
+                .map(|state| state.get_output())
+                .try_collect()?;
+            for (builder, datum) in builders
+                .iter_mut()
+                .zip_eq(key.0.iter().chain(outputs.iter()))
+            {
+                builder.append_datum(datum)?;
+            }
+        }
+
+        let columns: Vec<Column> = builders
+            .into_iter()
+            .map(|builder| -> Result<_> { Ok(Column::new(Arc::new(builder.finish()?))) })
+            .try_collect()?;
+        Ok(Some(StreamChunk::new(
+            vec![Op::Insert; cardinality],
+            columns,
+            None,
+        )))
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn execute_inner(self) {
+        let LocalHashAggExecutor {
+            input,
+            info,
+            agg_calls,
+            key_indices,
+        } = self;
+
+        let mut groups = LocalGroups::default();
+        // The latest watermarks of the group keys, which are delayed until the partial results
+        // before them are emitted. Indexed by the positions in the output.
+        let mut pending_watermarks: HashMap<usize, Watermark> = HashMap::new();
+
+        #[for_await]
+        for msg in input.execute() {
+            let msg = msg?;
+            match msg {
+                Message::Chunk(chunk) => {
+                    Self::apply_chunk(&agg_calls, &key_indices, &mut groups, chunk)
+                        .map_err(StreamExecutorError::agg_state_error)?;
+                }
+                Message::Watermark(watermark) => {
+                    // Watermarks of other columns are not meaningful to the output.
+                    if let Some(idx) = key_indices.iter().position(|&k| k == watermark.col_idx) {
+                        pending_watermarks.insert(idx, watermark.with_idx(idx));
+                    }
+                }
+                Message::Barrier(barrier) => {
+                    if let Some(chunk) = Self::flush_groups(&info.schema, &mut groups)
+                        .map_err(StreamExecutorError::agg_state_error)?
+                    {
+                        yield Message::Chunk(chunk);
+                    }
+                    yield Message::Barrier(barrier);
+                    for (_, watermark) in pending_watermarks.drain().sorted_by_key(|(k, _)| *k) {
+                        yield Message::Watermark(watermark);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use futures::StreamExt;
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::array::StreamChunk;
+    use risingwave_common::catalog::{Field, Schema, TableId};
+    use risingwave_common::hash::Key64;
+    use risingwave_common::types::{DataType, ScalarImpl};
+    use risingwave_expr::expr::AggKind;
+    use risingwave_storage::memory::MemoryStateStore;
+    use risingwave_storage::Keyspace;
+
+    use super::*;
+    use crate::executor_v2::aggregation::AggArgs;
+    use crate::executor_v2::test_utils::MockSource;
+    use crate::executor_v2::HashAggExecutor;
+
+    fn schema() -> Schema {
+        Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        }
+    }
+
+    fn local_agg_calls() -> Vec<AggCall> {
+        vec![
+            AggCall {
+                kind: AggKind::RowCount,
+                args: AggArgs::None,
+                return_type: DataType::Int64,
+                distinct: false,
+            },
+            AggCall {
+                kind: AggKind::Count,
+                args: AggArgs::Unary(DataType::Int64, 1),
+                return_type: DataType::Int64,
+                distinct: false,
+            },
+            AggCall {
+                kind: AggKind::Sum,
+                args: AggArgs::Unary(DataType::Int64, 1),
+                return_type: DataType::Int64,
+                distinct: false,
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_local_hash_agg() {
+        let (mut tx, source) = MockSource::channel(schema(), vec![2]);
+        tx.push_barrier(1, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I I
+            + 1 1 1
+            + 2 . 2
+            + 2 3 3",
+        ));
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I I
+            + 1 4 4 D
+            + 3 5 5",
+        ));
+        tx.push_barrier(2, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I I
+            - 2 3 3
+            + 2 6 6
+            - 3 5 5
+            + 3 7 7
+            - 1 1 1",
+        ));
+        tx.push_watermark(0, DataType::Int64, ScalarImpl::Int64(2));
+        tx.push_watermark(1, DataType::Int64, ScalarImpl::Int64(2));
+        tx.push_barrier(3, false);
+
+        let local_agg =
+            LocalHashAggExecutor::new(Box::new(source), local_agg_calls(), vec![0], vec![], 1)
+                .unwrap();
+        let mut local_agg = Box::new(local_agg).execute();
+
+        assert_matches!(
+            local_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        let msg = local_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I I I
+                + 1 1 1 1
+                + 2 2 1 3
+                + 3 1 1 5"
+            )
+        );
+        assert_matches!(
+            local_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        // The deltas are emitted as inserts, including those of the groups whose row counts do not
+        // change.
+        let msg = local_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                "  I  I  I  I
+                +  2  0  0  3
+                +  3  0  0  2
+                +  1 -1 -1 -1"
+            )
+        );
+        assert_matches!(
+            local_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        // Only the watermark of the group key is propagated after the barrier.
+        assert_matches!(
+            local_agg.next().await.unwrap().unwrap(),
+            Message::Watermark(Watermark { col_idx: 0, .. })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_two_phase_hash_agg() {
+        let (mut tx, source) = MockSource::channel(schema(), vec![2]);
+        tx.push_barrier(1, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I I
+            + 1 1 1
+            + 2 . 2
+            + 2 3 3",
+        ));
+        tx.push_barrier(2, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I I
+            - 2 3 3
+            + 2 6 6
+            - 1 1 1",
+        ));
+        tx.push_barrier(3, false);
+
+        let local_agg =
+            LocalHashAggExecutor::new(Box::new(source), local_agg_calls(), vec![0], vec![], 1)
+                .unwrap();
+
+        // The global phase sums up the partial results.
+        let global_agg_calls = (1..4)
+            .map(|idx| AggCall {
+                kind: AggKind::Sum,
+                args: AggArgs::Unary(DataType::Int64, idx),
+                return_type: DataType::Int64,
+                distinct: false,
+            })
+            .collect();
+        let store = MemoryStateStore::new();
+        let keyspace = (0..3)
+            .map(|idx| Keyspace::table_root(store.clone(), &TableId::new(idx)))
+            .collect();
+        let global_agg = HashAggExecutor::<Key64, _>::new(
+            Box::new(local_agg),
+            global_agg_calls,
+            keyspace,
+            vec![0],
+            2,
+            vec![0],
+            false,
+        )
+        .unwrap();
+        let mut global_agg = Box::new(global_agg).execute();
+
+        assert_matches!(
+            global_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+        let msg = global_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                " I I I I
+                + 1 1 1 1
+                + 2 2 1 3"
+            )
+            .sorted_rows(),
+        );
+        assert_matches!(
+            global_agg.next().await.unwrap().unwrap(),
+            Message::Barrier { .. }
+        );
+
+        // The retractions are applied as if the aggregation were not split.
+        let msg = global_agg.next().await.unwrap().unwrap();
+        assert_eq!(
+            msg.into_chunk().unwrap().sorted_rows(),
+            StreamChunk::from_pretty(
+                "  I I I I
+                -  1 1 1 1
+                U- 2 2 1 3
+                U+ 2 2 1 6"
+            )
+            .sorted_rows(),
+        );
+    }
+}
@@ -20,7 +20,7 @@ use itertools::Itertools;
 use risingwave_common::array::column::Column;
 use risingwave_common::array::{Op, StreamChunk};
 use risingwave_common::catalog::Schema;
-use risingwave_common::error::Result;
+use risingwave_common::error::{ErrorCode, Result};
 
 use super::{BoxedMessageStream, Executor, ExecutorInfo, Message, StreamExecutorResult};
 use crate::executor::PkIndicesRef;
@@ -107,6 +107,8 @@ impl LocalSimpleAggExecutor {
                     Self::apply_chunk(&agg_calls, &mut states, chunk)?;
                     is_dirty = true;
                 }
+                // The output has no columns derived from the input columns.
+                Message::Watermark(_) => {}
                 m @ Message::Barrier(_) => {
                     if is_dirty {
                         is_dirty = false;
@@ -152,6 +154,14 @@ impl LocalSimpleAggExecutor {
         pk_indices: PkIndices,
         executor_id: u64,
     ) -> Result<Self> {
+        // The partial results of distinct aggregations can not be merged by the global phase.
+        if agg_calls.iter().any(|agg_call| agg_call.distinct) {
+            return Err(ErrorCode::NotImplemented(
+                "distinct aggregation in local simple agg".to_string(),
+                None.into(),
+            )
+            .into());
+        }
         let schema = generate_agg_schema(input.as_ref(), &agg_calls, None);
         let info = ExecutorInfo {
             schema,
@@ -195,6 +205,7 @@ mod tests {
             kind: AggKind::RowCount,
             args: AggArgs::None,
             return_type: DataType::Int64,
+            distinct: false,
         }];
 
         let simple_agg = Box::new(LocalSimpleAggExecutor::new(
@@ -248,16 +259,19 @@ mod tests {
                 kind: AggKind::RowCount,
                 args: AggArgs::None,
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Sum,
                 args: AggArgs::Unary(DataType::Int64, 0),
                 return_type: DataType::Int64,
+                distinct: false,
             },
             AggCall {
                 kind: AggKind::Sum,
                 args: AggArgs::Unary(DataType::Int64, 1),
                 return_type: DataType::Int64,
+                distinct: false,
             },
         ];
 
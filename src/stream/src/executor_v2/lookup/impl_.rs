@@ -164,6 +164,17 @@ impl<S: StateStore> LookupExecutor<S> {
             );
         }
 
+        // The arrangement is scanned by the prefix of the encoded join keys, which can be filtered
+        // by prefix bloom filters for the leading fixed-size columns, same as the hash join state.
+        let bloom_key_len: usize = arrangement_order_rules[0..arrange_join_key_indices.len()]
+            .iter()
+            .map_while(|x| arrangement_datatypes[x.column_idx].memcmp_fixed_size())
+            .map(|size| size + 1)
+            .sum();
+        if bloom_key_len > 0 {
+            arrangement_keyspace.register_bloom_key_len(bloom_key_len);
+        }
+
         // compute the arrange keys used for the lookup
         let arrangement_order_types = arrangement_order_rules
             .iter()
@@ -108,6 +108,8 @@ pub async fn poll_until_barrier(stream: impl MessageStream, expected_barrier: Ba
     for item in stream {
         match item? {
             c @ Message::Chunk(_) => yield c,
+            // Lookup joins don't make use of watermarks.
+            Message::Watermark(_) => {}
             Message::Barrier(b) => {
                 if b.epoch != expected_barrier.epoch {
                     return Err(StreamExecutorError::align_barrier(expected_barrier, b));
@@ -125,7 +127,7 @@ fn prefer_right(_: &mut ()) -> PollNext {
 }
 
 /// A biased barrier aligner which prefers message from the right side. Barrier message will be
-/// available for both left and right side, instead of being combined.
+/// available for both left and right side, instead of being combined. Watermarks are dropped.
 #[try_stream(ok = BarrierAlignedMessage, error = StreamExecutorError)]
 pub async fn align_barrier(left: impl MessageStream, right: impl MessageStream) {
     let mut left = Box::pin(left);
@@ -159,6 +161,9 @@ pub async fn align_barrier(left: impl MessageStream, right: impl MessageStream)
                     yield Either::Right(Message::Barrier(b.clone()));
                     break 'inner (SideStatus::RightBarrier, b);
                 }
+                // Lookup joins don't make use of watermarks.
+                Some(Either::Left(Ok(Message::Watermark(_))))
+                | Some(Either::Right(Ok(Message::Watermark(_)))) => {}
                 Some(Either::Left(Err(e))) | Some(Either::Right(Err(e))) => return Err(e),
                 None => {
                     break 'outer;
@@ -220,6 +225,9 @@ pub async fn stream_lookup_arrange_prev_epoch(
                     yield ArrangeMessage::Barrier(barrier);
                     stream_side_end = true;
                 }
+                Either::Left(Message::Watermark(_)) | Either::Right(Message::Watermark(_)) => {
+                    unreachable!("watermarks are dropped by `align_barrier`")
+                }
                 Either::Right(Message::Barrier(_)) => {
                     if stream_side_end {
                         yield ArrangeMessage::ArrangeReady(std::mem::take(&mut arrange_buf));
@@ -242,7 +250,7 @@ pub async fn stream_lookup_arrange_prev_epoch(
                     yield ArrangeMessage::Barrier(b);
                     break;
                 }
-                Either::Right(_) => unreachable!(),
+                Either::Left(Message::Watermark(_)) | Either::Right(_) => unreachable!(),
             }
         }
 
@@ -293,6 +301,9 @@ pub async fn stream_lookup_arrange_this_epoch(
                 Either::Left(Message::Barrier(barrier)) => {
                     break 'inner Status::StreamReady(barrier);
                 }
+                Either::Left(Message::Watermark(_)) | Either::Right(Message::Watermark(_)) => {
+                    unreachable!("watermarks are dropped by `align_barrier`")
+                }
                 Either::Right(Message::Barrier(_)) => {
                     yield ArrangeMessage::ArrangeReady(std::mem::take(&mut arrange_buf));
                     for msg in std::mem::take(&mut stream_buf) {
@@ -316,7 +327,7 @@ pub async fn stream_lookup_arrange_this_epoch(
                         yield ArrangeMessage::Barrier(b);
                         break;
                     }
-                    Either::Right(_) => unreachable!(),
+                    Either::Left(Message::Watermark(_)) | Either::Right(_) => unreachable!(),
                 }
             },
             // Stream is done in this epoch, but arrangement is not ready -- we wait for the
@@ -327,7 +338,7 @@ pub async fn stream_lookup_arrange_this_epoch(
                     .await
                     .expect("unexpected close of barrier aligner")?
                 {
-                    Either::Left(_) => unreachable!(),
+                    Either::Left(_) | Either::Right(Message::Watermark(_)) => unreachable!(),
                     Either::Right(Message::Chunk(chunk)) => {
                         arrange_buf.push(chunk);
                     }
@@ -25,6 +25,7 @@ use risingwave_pb::stream_plan::stream_node::Node;
 use risingwave_storage::StateStore;
 
 use super::error::StreamExecutorError;
+use super::watermark::BufferedWatermarks;
 use super::{Barrier, BoxedExecutor, Executor, Message, PkIndicesRef};
 use crate::executor::{ExecutorBuilder, PkIndices};
 use crate::executor_v2::{BoxedMessageStream, ExecutorInfo};
@@ -105,11 +106,12 @@ impl LookupUnionExecutor {
         }
         // This future is used to drive all inputs.
         let mut drive_inputs = join_all(futures).fuse();
+        let mut watermarks = BufferedWatermarks::new(rxs.len());
         let mut end = false;
         while !end {
             end = true; // no message on this turn?
             let mut this_barrier: Option<Barrier> = None;
-            for rx in &mut rxs {
+            for (rx_idx, rx) in rxs.iter_mut().enumerate() {
                 loop {
                     let msg = match select(rx.next(), &mut drive_inputs).await {
                         Either::Left((Some(msg), _)) => msg?,
@@ -119,6 +121,12 @@ impl LookupUnionExecutor {
                     end = false;
                     match msg {
                         msg @ Message::Chunk(_) => yield msg,
+                        Message::Watermark(watermark) => {
+                            if let Some(watermark) = watermarks.handle_watermark(rx_idx, watermark)
+                            {
+                                yield Message::Watermark(watermark);
+                            }
+                        }
                         Message::Barrier(barrier) => {
                             if let Some(this_barrier) = &this_barrier {
                                 if this_barrier != &barrier {
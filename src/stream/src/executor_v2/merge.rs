@@ -15,7 +15,7 @@
 use async_trait::async_trait;
 use futures::channel::mpsc::{Receiver, Sender};
 use futures::future::select_all;
-use futures::{SinkExt, StreamExt};
+use futures::{Future, FutureExt, SinkExt, StreamExt};
 use futures_async_stream::{for_await, try_stream};
 use itertools::Itertools;
 use risingwave_common::catalog::Schema;
@@ -26,6 +26,7 @@ use tonic::Streaming;
 use tracing_futures::Instrument;
 
 use super::error::StreamExecutorError;
+use super::watermark::BufferedWatermarks;
 use super::{Executor, Message, PkIndicesRef};
 use crate::executor::PkIndices;
 use crate::executor_v2::{BoxedMessageStream, ExecutorInfo};
@@ -128,16 +129,27 @@ impl Executor for MergeExecutor {
     }
 }
 
+/// Polls the next message of the upstream `upstream_idx`.
+fn next_message(
+    upstream_idx: usize,
+    upstream: Receiver<Message>,
+) -> impl Future<Output = (usize, Option<Message>, Receiver<Message>)> + Unpin {
+    upstream
+        .into_future()
+        .map(move |(message, upstream)| (upstream_idx, message, upstream))
+}
+
 impl MergeExecutor {
     #[try_stream(ok = Message, error = StreamExecutorError)]
     async fn execute_inner(self) {
-        let mut upstreams = self.upstreams;
+        let mut watermarks = BufferedWatermarks::new(self.upstreams.len());
+        let mut upstreams = self.upstreams.into_iter().enumerate().collect_vec();
 
         loop {
             // Futures of all active upstreams.
             let mut active = upstreams
                 .into_iter()
-                .map(|ch| ch.into_future())
+                .map(|(idx, ch)| next_message(idx, ch))
                 .collect_vec();
             // Channels that're blocked by the barrier to align.
             let mut blocked = Vec::with_capacity(active.len());
@@ -147,7 +159,7 @@ impl MergeExecutor {
             // 1. Align the barriers.
             while !active.is_empty() {
                 // Poll upstreams and get a message from the ready one.
-                let ((message, from), _id, remainings) = select_all(active)
+                let ((idx, message, from), _id, remainings) = select_all(active)
                     .instrument(tracing::trace_span!("idle"))
                     .await;
 
@@ -161,9 +173,16 @@ impl MergeExecutor {
                 match message {
                     Message::Chunk(_) => {
                         // We may still receive message from this channel.
-                        active.push(from.into_future());
+                        active.push(next_message(idx, from));
                         yield message;
                     }
+                    Message::Watermark(watermark) => {
+                        active.push(next_message(idx, from));
+                        // Rows of other upstreams may still be behind the watermark.
+                        if let Some(watermark) = watermarks.handle_watermark(idx, watermark) {
+                            yield Message::Watermark(watermark);
+                        }
+                    }
                     Message::Barrier(barrier) => {
                         // Align the barrier.
                         if let Some(current_barrier) = current_barrier.as_ref() {
@@ -178,7 +197,7 @@ impl MergeExecutor {
                             current_barrier = Some(barrier);
                         }
                         // We'll not receive message from this channel during this epoch.
-                        blocked.push(from);
+                        blocked.push((idx, from));
                     }
                 }
             }
@@ -211,6 +230,7 @@ mod tests {
     use futures::SinkExt;
     use itertools::Itertools;
     use risingwave_common::array::{Op, StreamChunk};
+    use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_pb::data::StreamMessage;
     use risingwave_pb::task_service::exchange_service_server::{
         ExchangeService, ExchangeServiceServer,
@@ -223,7 +243,7 @@ mod tests {
     use tonic::{Request, Response, Status};
 
     use super::*;
-    use crate::executor::{Barrier, ExecutorV1, Mutation};
+    use crate::executor::{Barrier, ExecutorV1, Mutation, Watermark};
     use crate::executor_v2::merge::RemoteInput;
     use crate::executor_v2::Executor;
 
@@ -296,6 +316,36 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_merger_watermark() {
+        let (mut tx1, rx1) = channel(16);
+        let (mut tx2, rx2) = channel(16);
+        let merger = MergeExecutor::new(Schema::default(), vec![], 0, vec![rx1, rx2]);
+        let mut merger = merger.boxed().v1();
+        let watermark =
+            |val| Message::Watermark(Watermark::new(0, DataType::Int64, ScalarImpl::Int64(val)));
+
+        // The output watermark is the minimum of the upstreams.
+        tx1.send(watermark(5)).await.unwrap();
+        tx2.send(watermark(3)).await.unwrap();
+        assert_eq!(merger.next().await.unwrap(), watermark(3));
+        tx2.send(watermark(9)).await.unwrap();
+        assert_eq!(merger.next().await.unwrap(), watermark(5));
+        tx1.send(watermark(7)).await.unwrap();
+        assert_eq!(merger.next().await.unwrap(), watermark(7));
+
+        // Watermarks not advancing the minimum are not forwarded.
+        tx1.send(watermark(6)).await.unwrap();
+        for tx in [&mut tx1, &mut tx2] {
+            tx.send(Message::Barrier(
+                Barrier::new_test_barrier(1).with_mutation(Mutation::Stop(HashSet::default())),
+            ))
+            .await
+            .unwrap();
+        }
+        assert!(merger.next().await.unwrap().is_stop());
+    }
+
     struct FakeExchangeService {
         rpc_called: Arc<AtomicBool>,
     }
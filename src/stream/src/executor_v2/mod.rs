@@ -19,7 +19,9 @@ use futures::stream::BoxStream;
 pub use risingwave_common::array::StreamChunk;
 use risingwave_common::catalog::Schema;
 
-pub use super::executor::{Barrier, ExecutorV1, Message, Mutation, PkIndices, PkIndicesRef};
+pub use super::executor::{
+    Barrier, ExecutorV1, Message, Mutation, PkIndices, PkIndicesRef, Watermark,
+};
 
 pub mod aggregation;
 mod barrier_align;
@@ -31,14 +33,17 @@ mod global_simple_agg;
 mod hash_agg;
 mod hash_join;
 mod hop_window;
+mod local_hash_agg;
 mod local_simple_agg;
 mod lookup;
 mod lookup_union;
 pub mod merge;
 pub(crate) mod mview;
 mod project;
+mod project_set;
 mod rearranged_chain;
 pub mod receiver;
+mod session_window;
 mod simple;
 mod source;
 #[cfg(test)]
@@ -48,6 +53,7 @@ mod top_n_appendonly;
 mod top_n_executor;
 mod union;
 mod v1_compat;
+mod watermark;
 
 pub use batch_query::BatchQueryExecutor;
 pub use chain::ChainExecutor;
@@ -57,13 +63,16 @@ pub use global_simple_agg::SimpleAggExecutor;
 pub use hash_agg::HashAggExecutor;
 pub use hash_join::*;
 pub use hop_window::{HopWindowExecutor, HopWindowExecutorBuilder};
+pub use local_hash_agg::{LocalHashAggExecutor, LocalHashAggExecutorBuilder};
 pub use local_simple_agg::LocalSimpleAggExecutor;
 pub use lookup::*;
 pub use lookup_union::{LookupUnionExecutor, LookupUnionExecutorBuilder};
 pub use merge::MergeExecutor;
 pub use mview::*;
 pub use project::ProjectExecutor;
+pub use project_set::{ProjectSetExecutor, ProjectSetExecutorBuilder};
 pub use rearranged_chain::RearrangedChainExecutor;
+pub use session_window::{SessionWindowExecutor, SessionWindowExecutorBuilder};
 pub(crate) use simple::{SimpleExecutor, SimpleExecutorWrapper};
 pub use source::*;
 pub use top_n::TopNExecutor;
@@ -118,6 +118,7 @@ impl<S: StateStore> MaterializeExecutor<S> {
 
                     Message::Chunk(chunk)
                 }
+                Message::Watermark(w) => Message::Watermark(w),
                 Message::Barrier(b) => {
                     // FIXME(ZBW): use a better error type
                     self.state_table
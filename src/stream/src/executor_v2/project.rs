@@ -20,7 +20,9 @@ use risingwave_common::array::{DataChunk, StreamChunk};
 use risingwave_common::catalog::{Field, Schema};
 use risingwave_expr::expr::BoxedExpression;
 
-use super::{Executor, ExecutorInfo, SimpleExecutor, SimpleExecutorWrapper, StreamExecutorResult};
+use super::{
+    Executor, ExecutorInfo, SimpleExecutor, SimpleExecutorWrapper, StreamExecutorResult, Watermark,
+};
 use crate::executor::PkIndicesRef;
 use crate::executor_v2::error::StreamExecutorError;
 
@@ -35,6 +37,17 @@ impl ProjectExecutor {
             inner: SimpleProjectExecutor::new(info, exprs, execuotr_id),
         }
     }
+
+    /// Sets the `(input, output)` column index pairs where the output column is a plain reference
+    /// to the input column, so that watermarks of the input column are forwarded to the output
+    /// one.
+    pub fn with_watermark_derivations(
+        mut self,
+        watermark_derivations: Vec<(usize, usize)>,
+    ) -> Self {
+        self.inner.watermark_derivations = watermark_derivations;
+        self
+    }
 }
 
 /// `ProjectExecutor` project data with the `expr`. The `expr` takes a chunk of data,
@@ -45,6 +58,9 @@ pub struct SimpleProjectExecutor {
 
     /// Expressions of the current projection.
     exprs: Vec<BoxedExpression>,
+
+    /// `(input, output)` column index pairs to derive output watermarks from input ones.
+    watermark_derivations: Vec<(usize, usize)>,
 }
 
 impl SimpleProjectExecutor {
@@ -62,6 +78,7 @@ impl SimpleProjectExecutor {
                 identity: format!("ProjectExecutor {:X}", executor_id),
             },
             exprs,
+            watermark_derivations: vec![],
         }
     }
 }
@@ -105,6 +122,14 @@ impl SimpleExecutor for SimpleProjectExecutor {
         Ok(Some(new_chunk))
     }
 
+    fn handle_watermark(&mut self, watermark: Watermark) -> Vec<Watermark> {
+        self.watermark_derivations
+            .iter()
+            .filter(|(input_idx, _)| *input_idx == watermark.col_idx)
+            .map(|(_, output_idx)| watermark.clone().with_idx(*output_idx))
+            .collect()
+    }
+
     fn schema(&self) -> &Schema {
         &self.info.schema
     }
@@ -124,7 +149,7 @@ mod tests {
     use risingwave_common::array::stream_chunk::StreamChunkTestExt;
     use risingwave_common::array::StreamChunk;
     use risingwave_common::catalog::{Field, Schema};
-    use risingwave_common::types::DataType;
+    use risingwave_common::types::{DataType, ScalarImpl};
     use risingwave_expr::expr::expr_binary_nonnull::new_binary_expr;
     use risingwave_expr::expr::InputRefExpression;
     use risingwave_pb::expr::expr_node::Type;
@@ -189,4 +214,46 @@ mod tests {
 
         assert!(project.next().await.unwrap().unwrap().is_stop());
     }
+
+    #[tokio::test]
+    async fn test_projection_watermark() {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let (mut tx, source) = MockSource::channel(schema, PkIndices::new());
+        tx.push_watermark(0, DataType::Int64, ScalarImpl::Int64(5));
+        tx.push_watermark(1, DataType::Int64, ScalarImpl::Int64(7));
+        tx.push_barrier(1, true);
+
+        // Output columns: [$1, $0 + $1, $1]
+        let exprs: Vec<BoxedExpression> = vec![
+            Box::new(InputRefExpression::new(DataType::Int64, 1)),
+            new_binary_expr(
+                Type::Add,
+                DataType::Int64,
+                Box::new(InputRefExpression::new(DataType::Int64, 0)),
+                Box::new(InputRefExpression::new(DataType::Int64, 1)),
+            ),
+            Box::new(InputRefExpression::new(DataType::Int64, 1)),
+        ];
+        let project = ProjectExecutor::new(Box::new(source), exprs, 1)
+            .with_watermark_derivations(vec![(1, 0), (1, 2)]);
+        let mut project = Box::new(project).execute();
+
+        // The watermark on $0 is dropped, and the one on $1 is forwarded to both references.
+        for col_idx in [0, 2] {
+            assert_eq!(
+                project.next().await.unwrap().unwrap(),
+                Message::Watermark(Watermark::new(
+                    col_idx,
+                    DataType::Int64,
+                    ScalarImpl::Int64(7)
+                ))
+            );
+        }
+        assert!(project.next().await.unwrap().unwrap().is_stop());
+    }
 }
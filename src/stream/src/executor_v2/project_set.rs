@@ -0,0 +1,231 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::{Debug, Formatter};
+
+use itertools::Itertools;
+use risingwave_common::array::{DataChunk, Op, StreamChunk};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::types::DataType;
+use risingwave_expr::table_function::{eval_project_set, ProjectSetSelectItem};
+use risingwave_pb::stream_plan::{self, stream_node};
+use risingwave_storage::StateStore;
+
+use super::error::StreamExecutorError;
+use super::{
+    BoxedExecutor, Executor, ExecutorInfo, SimpleExecutor, SimpleExecutorWrapper,
+    StreamExecutorResult, Watermark,
+};
+use crate::executor::{ExecutorBuilder, PkIndicesRef};
+use crate::task::{ExecutorParams, LocalStreamManagerCore};
+
+pub struct ProjectSetExecutorBuilder {}
+
+impl ExecutorBuilder for ProjectSetExecutorBuilder {
+    fn new_boxed_executor(
+        params: ExecutorParams,
+        node: &stream_plan::StreamNode,
+        _store: impl StateStore,
+        _stream: &mut LocalStreamManagerCore,
+    ) -> risingwave_common::error::Result<BoxedExecutor> {
+        let ExecutorParams {
+            input,
+            pk_indices,
+            executor_id,
+            ..
+        } = params;
+
+        let input = input.into_iter().next().unwrap();
+        let Some(stream_node::Node::ProjectSetNode(node)) = &node.node else {
+            unreachable!();
+        };
+        let select_list = node
+            .get_select_list()
+            .iter()
+            .map(ProjectSetSelectItem::from_prost)
+            .try_collect()?;
+        Ok(ProjectSetExecutor::new(input, pk_indices, select_list, executor_id).boxed())
+    }
+}
+
+pub type ProjectSetExecutor = SimpleExecutorWrapper<SimpleProjectSetExecutor>;
+
+impl ProjectSetExecutor {
+    pub fn new(
+        input: BoxedExecutor,
+        pk_indices: Vec<usize>,
+        select_list: Vec<ProjectSetSelectItem>,
+        executor_id: u64,
+    ) -> Self {
+        SimpleExecutorWrapper {
+            input,
+            inner: SimpleProjectSetExecutor::new(pk_indices, select_list, executor_id),
+        }
+    }
+}
+
+/// `ProjectSetExecutor` evaluates a select list containing table functions, so that each input
+/// row may be expanded into multiple output rows. The first output column is the
+/// `projected_row_id`, which, together with the primary key of the input, identifies an output
+/// row.
+pub struct SimpleProjectSetExecutor {
+    info: ExecutorInfo,
+
+    select_list: Vec<ProjectSetSelectItem>,
+}
+
+impl SimpleProjectSetExecutor {
+    pub fn new(
+        pk_indices: Vec<usize>,
+        select_list: Vec<ProjectSetSelectItem>,
+        executor_id: u64,
+    ) -> Self {
+        let fields = std::iter::once(Field::with_name(DataType::Int64, "projected_row_id"))
+            .chain(
+                select_list
+                    .iter()
+                    .map(|item| Field::unnamed(item.return_type())),
+            )
+            .collect();
+        Self {
+            info: ExecutorInfo {
+                schema: Schema { fields },
+                pk_indices,
+                identity: format!("ProjectSetExecutor {:X}", executor_id),
+            },
+            select_list,
+        }
+    }
+}
+
+impl Debug for SimpleProjectSetExecutor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProjectSetExecutor")
+            .field("select_list", &self.select_list)
+            .finish()
+    }
+}
+
+impl SimpleExecutor for SimpleProjectSetExecutor {
+    fn map_filter_chunk(
+        &mut self,
+        chunk: StreamChunk,
+    ) -> StreamExecutorResult<Option<StreamChunk>> {
+        let chunk = chunk.compact().map_err(StreamExecutorError::eval_error)?;
+        let (ops, columns, _) = chunk.into_inner();
+        let data_chunk = DataChunk::builder().columns(columns).build();
+
+        let (columns, input_row_indices) = eval_project_set(&self.select_list, &data_chunk)
+            .map_err(StreamExecutorError::eval_error)?;
+        if input_row_indices.is_empty() {
+            return Ok(None);
+        }
+
+        // An update pair may be expanded into different numbers of rows, so it's emitted as a
+        // deletion and an insertion.
+        let ops = input_row_indices
+            .into_iter()
+            .map(|idx| match ops[idx] {
+                Op::Insert | Op::UpdateInsert => Op::Insert,
+                Op::Delete | Op::UpdateDelete => Op::Delete,
+            })
+            .collect();
+        Ok(Some(StreamChunk::new(ops, columns, None)))
+    }
+
+    fn handle_watermark(&mut self, _watermark: Watermark) -> Vec<Watermark> {
+        // The output rows of a table function are not ordered by any input column.
+        vec![]
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::types::ScalarImpl;
+    use risingwave_expr::expr::{Expression, InputRefExpression, LiteralExpression};
+    use risingwave_expr::table_function::new_generate_series;
+
+    use super::super::test_utils::MockSource;
+    use super::super::*;
+    use super::*;
+
+    #[tokio::test]
+    async fn test_project_set() {
+        let chunk = StreamChunk::from_pretty(
+            "  I I
+            +  1 2
+            -  2 0
+            U- 3 1
+            U+ 3 3",
+        );
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+            ],
+        };
+        let source = MockSource::with_chunks(schema, vec![0], vec![chunk]);
+
+        let one = || LiteralExpression::new(DataType::Int64, Some(ScalarImpl::Int64(1))).boxed();
+        let series = new_generate_series(
+            vec![
+                one(),
+                InputRefExpression::new(DataType::Int64, 1).boxed(),
+                one(),
+            ],
+            DataType::Int64,
+        )
+        .unwrap();
+        let select_list = vec![
+            ProjectSetSelectItem::Expr(InputRefExpression::new(DataType::Int64, 0).boxed()),
+            ProjectSetSelectItem::TableFunction(series),
+        ];
+
+        let project_set = Box::new(ProjectSetExecutor::new(
+            Box::new(source),
+            vec![1, 0],
+            select_list,
+            1,
+        ));
+        let mut project_set = project_set.execute();
+
+        let msg = project_set.next().await.unwrap().unwrap();
+        assert_eq!(
+            *msg.as_chunk().unwrap(),
+            StreamChunk::from_pretty(
+                " I I I
+                + 0 1 1
+                + 1 1 2
+                - 0 3 1
+                + 0 3 1
+                + 1 3 2
+                + 2 3 3"
+            )
+        );
+    }
+}
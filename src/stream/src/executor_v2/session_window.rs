@@ -0,0 +1,410 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::{BTreeMap, HashMap};
+
+use futures::StreamExt;
+use futures_async_stream::try_stream;
+use itertools::Itertools;
+use risingwave_common::array::{Op, Row, StreamChunk};
+use risingwave_common::catalog::{Field, Schema};
+use risingwave_common::collection::evictable::EvictableHashMap;
+use risingwave_common::error::ErrorCode;
+use risingwave_common::types::{DataType, NaiveDateTimeWrapper, ScalarImpl};
+use risingwave_common::util::session_window::SessionGap;
+use risingwave_pb::stream_plan::{self, stream_node};
+use risingwave_storage::{Keyspace, StateStore};
+
+use super::error::StreamExecutorError;
+use super::{BoxedExecutor, Executor, ExecutorInfo, Message, PkIndices, PkIndicesRef};
+use crate::executor::managed_state::session_window::{SessionRowKey, SessionWindowState};
+use crate::executor::ExecutorBuilder;
+use crate::task::{ExecutorParams, LocalStreamManagerCore};
+
+pub struct SessionWindowExecutorBuilder {}
+
+impl ExecutorBuilder for SessionWindowExecutorBuilder {
+    fn new_boxed_executor(
+        params: ExecutorParams,
+        node: &stream_plan::StreamNode,
+        store: impl StateStore,
+        _stream: &mut LocalStreamManagerCore,
+    ) -> risingwave_common::error::Result<BoxedExecutor> {
+        let ExecutorParams {
+            input,
+            pk_indices,
+            executor_id,
+            ..
+        } = params;
+
+        let input = input.into_iter().next().unwrap();
+        let Some(stream_node::Node::SessionWindowNode(node)) = &node.node else {
+            unreachable!();
+        };
+        let time_col_idx = node.get_time_col()?.column_idx as usize;
+        let gap = node.get_gap()?.into();
+        let gap = SessionGap::from_interval(gap).ok_or_else(|| {
+            ErrorCode::InvalidInputSyntax(format!("invalid gap of session window: {}", gap))
+        })?;
+        let partition_key_indices = node
+            .get_partition_keys()
+            .iter()
+            .map(|key| key.column_idx as usize)
+            .collect();
+        let keyspace = Keyspace::executor_root(store, executor_id);
+        Ok(SessionWindowExecutor::new(
+            input,
+            keyspace,
+            pk_indices,
+            executor_id,
+            time_col_idx,
+            gap,
+            partition_key_indices,
+        )
+        .boxed())
+    }
+}
+
+/// [`SessionWindowExecutor`] appends the `window_start` and `window_end` of the session to each
+/// row. It works as follows:
+///
+/// * All rows are kept in the state of their partitions, ordered by time.
+/// * For each partition changed by a chunk, the time range of the changes is extended to the
+///   boundaries of the sessions it touches. The rows in the range are the only ones whose sessions
+///   may be merged or split by the changes.
+/// * The rows in the range are assigned to sessions before and after applying the changes. Rows
+///   whose windows change are retracted and inserted again with the new windows.
+///
+/// Rows whose time is null do not belong to any session and are ignored. Since the windows of
+/// earlier rows may change at any time, watermarks are not propagated.
+pub struct SessionWindowExecutor<S: StateStore> {
+    input: BoxedExecutor,
+    info: ExecutorInfo,
+
+    /// The executor operates on this keyspace.
+    keyspace: Keyspace<S>,
+
+    time_col_idx: usize,
+    gap: SessionGap,
+    partition_key_indices: Vec<usize>,
+}
+
+impl<S: StateStore> SessionWindowExecutor<S> {
+    pub fn new(
+        input: BoxedExecutor,
+        keyspace: Keyspace<S>,
+        pk_indices: PkIndices,
+        executor_id: u64,
+        time_col_idx: usize,
+        gap: SessionGap,
+        partition_key_indices: Vec<usize>,
+    ) -> Self {
+        let schema = input
+            .schema()
+            .clone()
+            .into_fields()
+            .into_iter()
+            .chain([
+                Field::with_name(DataType::Timestamp, "window_start"),
+                Field::with_name(DataType::Timestamp, "window_end"),
+            ])
+            .collect();
+        Self {
+            input,
+            info: ExecutorInfo {
+                schema,
+                pk_indices,
+                identity: format!("SessionWindowExecutor {:X}", executor_id),
+            },
+            keyspace,
+            time_col_idx,
+            gap,
+            partition_key_indices,
+        }
+    }
+}
+
+impl<S: StateStore> Executor for SessionWindowExecutor<S> {
+    fn execute(self: Box<Self>) -> super::BoxedMessageStream {
+        self.execute_inner().boxed()
+    }
+
+    fn schema(&self) -> &Schema {
+        &self.info.schema
+    }
+
+    fn pk_indices(&self) -> PkIndicesRef {
+        &self.info.pk_indices
+    }
+
+    fn identity(&self) -> &str {
+        &self.info.identity
+    }
+}
+
+impl<S: StateStore> SessionWindowExecutor<S> {
+    /// Applies the changes of a partition, and appends the resulting changes of the output rows to
+    /// `output`.
+    fn apply_partition(
+        &self,
+        state: &mut SessionWindowState<S>,
+        changes: Vec<(Op, SessionRowKey, Row)>,
+        output: &mut Vec<(Op, Row)>,
+    ) {
+        let start = changes.iter().map(|(_, (time, _), _)| *time).min().unwrap();
+        let end = changes.iter().map(|(_, (time, _), _)| *time).max().unwrap();
+        let (start, end) = state.extend_to_sessions(start, end, self.gap);
+
+        let windows_between = |state: &SessionWindowState<S>| {
+            let (keys, rows): (Vec<_>, Vec<_>) = state
+                .rows_between(start, end)
+                .map(|(key, row)| (key.clone(), row.clone()))
+                .unzip();
+            let times = keys.iter().map(|(time, _)| *time).collect_vec();
+            keys.into_iter()
+                .zip_eq(rows.into_iter().zip_eq(self.gap.assign(&times)))
+                .collect::<BTreeMap<_, _>>()
+        };
+
+        let mut old_windows = windows_between(state);
+        for (op, key, row) in changes {
+            match op {
+                Op::Insert | Op::UpdateInsert => state.insert(key, row),
+                Op::Delete | Op::UpdateDelete => state.remove(key),
+            }
+        }
+        let new_windows = windows_between(state);
+
+        let with_window = |mut row: Row, (window_start, window_end): (NaiveDateTimeWrapper, _)| {
+            row.0.push(Some(ScalarImpl::NaiveDateTime(window_start)));
+            row.0.push(Some(ScalarImpl::NaiveDateTime(window_end)));
+            row
+        };
+        let mut inserts = vec![];
+        for (key, new) in new_windows {
+            match old_windows.remove(&key) {
+                Some(old) if old == new => {}
+                Some((old_row, old_window)) => {
+                    output.push((Op::Delete, with_window(old_row, old_window)));
+                    inserts.push((Op::Insert, with_window(new.0, new.1)));
+                }
+                None => inserts.push((Op::Insert, with_window(new.0, new.1))),
+            }
+        }
+        for (old_row, old_window) in old_windows.into_values() {
+            output.push((Op::Delete, with_window(old_row, old_window)));
+        }
+        output.extend(inserts);
+    }
+
+    #[try_stream(ok = Message, error = StreamExecutorError)]
+    async fn execute_inner(self: Box<Self>) {
+        let input = self.input.execute();
+        let data_types = self.input_data_types();
+        let output_data_types = self.info.schema.data_types();
+        let pk_indices = self.input_pk_indices();
+
+        // The states of partitions. `partition_key -> rows`.
+        let mut state_map: EvictableHashMap<Row, SessionWindowState<S>> =
+            EvictableHashMap::new(1 << 16);
+        let mut epoch = 0;
+
+        #[for_await]
+        for msg in input {
+            let msg = msg?;
+            match msg {
+                Message::Chunk(chunk) => {
+                    let mut changes: HashMap<Row, Vec<(Op, SessionRowKey, Row)>> = HashMap::new();
+                    for (op, row) in chunk.rows() {
+                        let row = row.to_owned_row();
+                        if row[self.time_col_idx].is_none() {
+                            continue;
+                        }
+                        let time = SessionWindowState::<S>::time_of(&row, self.time_col_idx)
+                            .map_err(StreamExecutorError::session_window_state_error)?;
+                        let pk = Row(pk_indices.iter().map(|idx| row[*idx].clone()).collect())
+                            .serialize()
+                            .map_err(StreamExecutorError::eval_error)?;
+                        let partition_key = Row(self
+                            .partition_key_indices
+                            .iter()
+                            .map(|idx| row[*idx].clone())
+                            .collect());
+                        changes
+                            .entry(partition_key)
+                            .or_default()
+                            .push((op, (time, pk), row));
+                    }
+
+                    let mut output = vec![];
+                    for (partition_key, changes) in changes {
+                        if !state_map.contains(&partition_key) {
+                            let keyspace = self.keyspace.append(
+                                partition_key
+                                    .serialize()
+                                    .map_err(StreamExecutorError::eval_error)?,
+                            );
+                            let state = SessionWindowState::new(
+                                keyspace,
+                                &data_types,
+                                self.time_col_idx,
+                                epoch,
+                            )
+                            .await
+                            .map_err(StreamExecutorError::session_window_state_error)?;
+                            state_map.put(partition_key.clone(), state);
+                        }
+                        let state = state_map.get_mut(&partition_key).unwrap();
+                        self.apply_partition(state, changes, &mut output);
+                    }
+
+                    if !output.is_empty() {
+                        let chunk = StreamChunk::from_rows(&output, &output_data_types)
+                            .map_err(StreamExecutorError::eval_error)?;
+                        yield Message::Chunk(chunk);
+                    }
+                }
+                Message::Barrier(barrier) => {
+                    let mut write_batch = self.keyspace.state_store().start_write_batch();
+                    for state in state_map.values_mut() {
+                        if state.is_dirty() {
+                            state
+                                .flush(&mut write_batch)
+                                .map_err(StreamExecutorError::session_window_state_error)?;
+                        }
+                    }
+                    if !write_batch.is_empty() {
+                        write_batch
+                            .ingest(barrier.epoch.prev)
+                            .await
+                            .map_err(StreamExecutorError::session_window_state_error)?;
+                    }
+                    state_map.evict_to_target_cap();
+
+                    epoch = barrier.epoch.curr;
+                    yield Message::Barrier(barrier);
+                }
+                // The windows of the rows before a watermark may still be changed by later rows, so
+                // the watermark is not meaningful to the output.
+                Message::Watermark(_) => {}
+            }
+        }
+    }
+
+    fn input_data_types(&self) -> Vec<DataType> {
+        self.input.schema().data_types()
+    }
+
+    fn input_pk_indices(&self) -> Vec<usize> {
+        self.input.pk_indices().to_vec()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::catalog::{Field, Schema};
+    use risingwave_common::types::{DataType, IntervalUnit};
+    use risingwave_storage::memory::MemoryStateStore;
+
+    use super::*;
+    use crate::executor_v2::test_utils::MockSource;
+    use crate::executor_v2::BoxedMessageStream;
+
+    async fn next_chunk(executor: &mut BoxedMessageStream) -> StreamChunk {
+        loop {
+            if let Message::Chunk(chunk) = executor.next().await.unwrap().unwrap() {
+                return chunk;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_session_window_executor() {
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Timestamp),
+            ],
+        };
+        let (mut tx, source) = MockSource::channel(schema, vec![0]);
+        tx.push_barrier(1, false);
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I TS
+            + 1 1 2022-01-01T10:00:00
+            + 2 1 2022-01-01T10:20:00
+            + 3 2 2022-01-01T10:05:00",
+        ));
+        tx.push_barrier(2, false);
+        // Row 4 merges the two sessions of partition 1.
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I TS
+            + 4 1 2022-01-01T10:10:00",
+        ));
+        tx.push_barrier(3, false);
+        // Deleting row 4 splits the session again.
+        tx.push_chunk(StreamChunk::from_pretty(
+            " I I TS
+            - 4 1 2022-01-01T10:10:00",
+        ));
+
+        let executor = Box::new(SessionWindowExecutor::new(
+            Box::new(source),
+            Keyspace::executor_root(MemoryStateStore::new(), 0x2333),
+            vec![0],
+            1,
+            2,
+            SessionGap::from_interval(IntervalUnit::from_minutes(15)).unwrap(),
+            vec![1],
+        ));
+        let mut executor = executor.execute();
+
+        assert_eq!(
+            next_chunk(&mut executor).await.sorted_rows(),
+            StreamChunk::from_pretty(
+                " I I TS                  TS                  TS
+                + 1 1 2022-01-01T10:00:00 2022-01-01T10:00:00 2022-01-01T10:15:00
+                + 2 1 2022-01-01T10:20:00 2022-01-01T10:20:00 2022-01-01T10:35:00
+                + 3 2 2022-01-01T10:05:00 2022-01-01T10:05:00 2022-01-01T10:20:00",
+            )
+            .sorted_rows(),
+        );
+        assert_eq!(
+            next_chunk(&mut executor).await.sorted_rows(),
+            StreamChunk::from_pretty(
+                " I I TS                  TS                  TS
+                - 1 1 2022-01-01T10:00:00 2022-01-01T10:00:00 2022-01-01T10:15:00
+                - 2 1 2022-01-01T10:20:00 2022-01-01T10:20:00 2022-01-01T10:35:00
+                + 1 1 2022-01-01T10:00:00 2022-01-01T10:00:00 2022-01-01T10:35:00
+                + 4 1 2022-01-01T10:10:00 2022-01-01T10:00:00 2022-01-01T10:35:00
+                + 2 1 2022-01-01T10:20:00 2022-01-01T10:00:00 2022-01-01T10:35:00",
+            )
+            .sorted_rows(),
+        );
+        assert_eq!(
+            next_chunk(&mut executor).await.sorted_rows(),
+            StreamChunk::from_pretty(
+                " I I TS                  TS                  TS
+                - 1 1 2022-01-01T10:00:00 2022-01-01T10:00:00 2022-01-01T10:35:00
+                - 4 1 2022-01-01T10:10:00 2022-01-01T10:00:00 2022-01-01T10:35:00
+                - 2 1 2022-01-01T10:20:00 2022-01-01T10:00:00 2022-01-01T10:35:00
+                + 1 1 2022-01-01T10:00:00 2022-01-01T10:00:00 2022-01-01T10:15:00
+                + 2 1 2022-01-01T10:20:00 2022-01-01T10:20:00 2022-01-01T10:35:00",
+            )
+            .sorted_rows(),
+        );
+    }
+}
@@ -18,12 +18,14 @@ use std::sync::Arc;
 use futures::stream::{select_with_strategy, PollNext};
 use futures::{Stream, StreamExt};
 use futures_async_stream::try_stream;
+use itertools::Itertools;
 use risingwave_common::array::column::Column;
-use risingwave_common::array::{ArrayBuilder, ArrayImpl, I64ArrayBuilder, StreamChunk};
+use risingwave_common::array::{Array, ArrayBuilder, ArrayImpl, I64ArrayBuilder, StreamChunk};
 use risingwave_common::catalog::{ColumnId, Field, Schema, TableId};
 use risingwave_common::error::ErrorCode::InternalError;
 use risingwave_common::error::{Result, RwError, ToRwResult};
 use risingwave_common::try_match_expand;
+use risingwave_common::types::{DataType, NaiveDateTimeWrapper, ScalarImpl};
 use risingwave_connector::SplitImpl;
 use risingwave_pb::stream_plan;
 use risingwave_pb::stream_plan::stream_node::Node;
@@ -34,7 +36,7 @@ use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
 use super::error::StreamExecutorError;
 use super::{BoxedExecutor, BoxedMessageStream, Executor};
 use crate::executor::monitor::StreamingMetrics;
-use crate::executor::{Barrier, ExecutorBuilder, Message, PkIndices, PkIndicesRef};
+use crate::executor::{Barrier, ExecutorBuilder, Message, PkIndices, PkIndicesRef, Watermark};
 use crate::task::{ExecutorParams, LocalStreamManagerCore};
 
 /// [`SourceExecutor`] is a streaming source, from risingwave's batch table, or external systems
@@ -59,6 +61,12 @@ pub struct SourceExecutor {
     /// Split info for stream source
     stream_source_splits: Vec<SplitImpl>,
 
+    /// Index of the watermark column in the output, and the delay of the watermark in ms.
+    watermark_desc: Option<(usize, u64)>,
+
+    /// The latest watermark emitted.
+    current_watermark: Option<NaiveDateTimeWrapper>,
+
     source_identify: String,
 }
 
@@ -141,6 +149,12 @@ impl SourceExecutor {
         streaming_metrics: Arc<StreamingMetrics>,
         stream_source_splits: Vec<SplitImpl>,
     ) -> Result<Self> {
+        let watermark_desc = source_desc.watermark.as_ref().and_then(|watermark| {
+            column_ids
+                .iter()
+                .position(|column_id| *column_id == watermark.column_id)
+                .map(|idx| (idx, watermark.delay_ms))
+        });
         Ok(Self {
             source_id,
             source_desc,
@@ -152,9 +166,44 @@ impl SourceExecutor {
             metrics: streaming_metrics,
             stream_source_splits,
             source_identify: "Table_".to_string() + &source_id.table_id().to_string(),
+            watermark_desc,
+            current_watermark: None,
         })
     }
 
+    /// Advances the watermark with the max event time seen in `chunk`, and returns the new
+    /// watermark if it advances.
+    fn advance_watermark(&mut self, chunk: &StreamChunk) -> Option<Watermark> {
+        let (col_idx, delay_ms) = self.watermark_desc?;
+        let ArrayImpl::NaiveDateTime(array) = chunk.column_at(col_idx).array_ref() else {
+            return None;
+        };
+        let max_event_time = match chunk.visibility() {
+            Some(visibility) => array
+                .iter()
+                .zip_eq(visibility.iter())
+                .filter_map(|(v, vis)| if vis { v } else { None })
+                .max(),
+            None => array.iter().flatten().max(),
+        }?;
+
+        let watermark = NaiveDateTimeWrapper::new(
+            max_event_time.0 - chrono::Duration::milliseconds(delay_ms as i64),
+        );
+        if self
+            .current_watermark
+            .map_or(false, |current| current >= watermark)
+        {
+            return None;
+        }
+        self.current_watermark = Some(watermark);
+        Some(Watermark::new(
+            col_idx,
+            DataType::Timestamp,
+            ScalarImpl::NaiveDateTime(watermark),
+        ))
+    }
+
     /// Generate a row ID column.
     fn gen_row_id_column(&mut self, len: usize) -> Column {
         let mut builder = I64ArrayBuilder::new(len).unwrap();
@@ -272,8 +321,13 @@ impl SourceExecutor {
                         .source_output_row_count
                         .with_label_values(&[self.source_identify.as_str()])
                         .inc_by(chunk.cardinality() as u64);
+                    let watermark = self.advance_watermark(&chunk);
                     yield Message::Chunk(chunk);
+                    if let Some(watermark) = watermark {
+                        yield Message::Watermark(watermark);
+                    }
                 }
+                Message::Watermark(_) => unreachable!(),
             }
         }
         unreachable!();
@@ -431,6 +485,7 @@ mod tests {
                 Message::Barrier(barrier) => {
                     assert_eq!(barrier.epoch, Epoch::new_test_epoch(1))
                 }
+                Message::Watermark(_) => unreachable!(),
             }
         }
 
@@ -545,4 +600,134 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_source_watermark() -> Result<()> {
+        let table_id = TableId::default();
+
+        let table_columns = vec![
+            ColumnDesc {
+                column_id: ColumnId::from(0),
+                data_type: DataType::Int64,
+                name: String::new(),
+                field_descs: vec![],
+                type_name: "".to_string(),
+            },
+            ColumnDesc {
+                column_id: ColumnId::from(1),
+                data_type: DataType::Timestamp,
+                name: String::new(),
+                field_descs: vec![],
+                type_name: "".to_string(),
+            },
+        ];
+        let source_manager = MemSourceManager::default();
+        source_manager.create_table_source(&table_id, table_columns)?;
+        let mut source_desc = source_manager.get_source(&table_id)?;
+        source_desc.watermark = Some(SourceWatermarkDesc {
+            column_id: ColumnId::from(1),
+            delay_ms: 5000,
+        });
+        let source = source_desc.clone().source;
+
+        let schema = Schema {
+            fields: vec![
+                Field::unnamed(DataType::Int64),
+                Field::unnamed(DataType::Timestamp),
+            ],
+        };
+
+        let (barrier_sender, barrier_receiver) = unbounded_channel();
+        let keyspace = Keyspace::executor_root(MemoryStateStore::new(), 0x2333);
+        let executor = SourceExecutor::new(
+            table_id,
+            source_desc,
+            keyspace,
+            vec![0.into(), 1.into()],
+            schema,
+            vec![0],
+            barrier_receiver,
+            1,
+            1,
+            "SourceExecutor".to_string(),
+            Arc::new(StreamingMetrics::unused()),
+            vec![],
+        )
+        .unwrap();
+        let mut executor = Box::new(executor).execute();
+
+        let write_chunk = |chunk: StreamChunk| {
+            let source = source.clone();
+            tokio::spawn(async move {
+                let table_source = source.as_table_v2().unwrap();
+                table_source.blocking_write_chunk(chunk).await.unwrap();
+            });
+        };
+        let watermark = |s: &str| {
+            Message::Watermark(Watermark::new(
+                1,
+                DataType::Timestamp,
+                ScalarImpl::NaiveDateTime(NaiveDateTimeWrapper(
+                    format!("2022-2-2T{}", s).parse().unwrap(),
+                )),
+            ))
+        };
+
+        barrier_sender.send(Barrier::new_test_barrier(1)).unwrap();
+        executor
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+            .as_barrier()
+            .unwrap();
+
+        // The watermark lags behind the max event time by 5 seconds.
+        write_chunk(StreamChunk::from_pretty(
+            " I TS
+            + 0 2022-2-2T10:00:10
+            + 0 2022-2-2T10:00:00",
+        ));
+        executor
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_chunk()
+            .unwrap();
+        assert_eq!(
+            executor.next().await.unwrap().unwrap(),
+            watermark("10:00:05")
+        );
+
+        // Late events do not move the watermark backwards.
+        write_chunk(StreamChunk::from_pretty(
+            " I TS
+            + 0 2022-2-2T10:00:08",
+        ));
+        executor
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_chunk()
+            .unwrap();
+        write_chunk(StreamChunk::from_pretty(
+            " I TS
+            + 0 2022-2-2T10:00:20",
+        ));
+        executor
+            .next()
+            .await
+            .unwrap()
+            .unwrap()
+            .into_chunk()
+            .unwrap();
+        assert_eq!(
+            executor.next().await.unwrap().unwrap(),
+            watermark("10:00:15")
+        );
+
+        Ok(())
+    }
 }
@@ -15,12 +15,13 @@
 use futures::StreamExt;
 use futures_async_stream::try_stream;
 use risingwave_common::catalog::Schema;
+use risingwave_common::types::{DataType, ScalarImpl};
 use risingwave_storage::memory::MemoryStateStore;
 use risingwave_storage::Keyspace;
 use tokio::sync::mpsc;
 
 use super::error::StreamExecutorError;
-use super::{Barrier, Executor, Message, PkIndices, StreamChunk};
+use super::{Barrier, Executor, Message, PkIndices, StreamChunk, Watermark};
 
 pub struct MockSource {
     schema: Schema,
@@ -48,6 +49,13 @@ impl MessageSender {
         }
         self.0.send(Message::Barrier(barrier)).unwrap();
     }
+
+    #[allow(dead_code)]
+    pub fn push_watermark(&mut self, col_idx: usize, data_type: DataType, val: ScalarImpl) {
+        self.0
+            .send(Message::Watermark(Watermark::new(col_idx, data_type, val)))
+            .unwrap();
+    }
 }
 
 impl std::fmt::Debug for MockSource {
@@ -99,6 +99,8 @@ where
                 Message::Chunk(chunk) => {
                     yield Message::Chunk(self.inner.apply_chunk(chunk, epoch).await?)
                 }
+                // Rows out of the top N may enter it later, no matter how late they are.
+                Message::Watermark(_) => {}
                 Message::Barrier(barrier) => {
                     self.inner.flush_data(epoch).await?;
                     epoch = barrier.epoch.curr;
@@ -17,12 +17,14 @@ use std::sync::Arc;
 use futures::stream::select_all;
 use futures::StreamExt;
 use futures_async_stream::try_stream;
+use parking_lot::Mutex;
 use risingwave_common::catalog::Schema;
 use risingwave_common::try_match_expand;
 use risingwave_pb::stream_plan;
 use risingwave_pb::stream_plan::stream_node::Node;
 use risingwave_storage::StateStore;
 
+use super::watermark::BufferedWatermarks;
 use super::{BoxedExecutor, Executor, Message, PkIndicesRef};
 use crate::executor::{ExecutorBuilder, PkIndices};
 use crate::executor_v2::{BoxedMessageStream, ExecutorInfo};
@@ -75,18 +77,26 @@ impl Executor for UnionExecutor {
     }
 }
 
-/// Merges input streams and aligns with barriers.
+/// Merges input streams and aligns with barriers. Watermarks are the minimum over inputs.
 pub fn merge(inputs: Vec<BoxedMessageStream>) -> BoxedMessageStream {
     let barrier = Arc::new(tokio::sync::Barrier::new(inputs.len()));
+    let watermarks = Arc::new(Mutex::new(BufferedWatermarks::new(inputs.len())));
     let mut streams = vec![];
-    for input in inputs {
+    for (idx, input) in inputs.into_iter().enumerate() {
         let barrier = barrier.clone();
+        let watermarks = watermarks.clone();
         let stream = #[try_stream]
         async move {
             #[for_await]
             for item in input {
                 match item? {
                     msg @ Message::Chunk(_) => yield msg,
+                    Message::Watermark(watermark) => {
+                        let watermark = watermarks.lock().handle_watermark(idx, watermark);
+                        if let Some(watermark) = watermark {
+                            yield Message::Watermark(watermark);
+                        }
+                    }
                     msg @ Message::Barrier(_) => {
                         if barrier.wait().await.is_leader() {
                             // one leader is responsible for sending barrier
@@ -308,6 +308,7 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
         pk_indices: PkIndices,
         executor_id: u64,
         _op_info: String,
+        emit_on_window_close: bool,
     ) -> Result<Self> {
         Self::new(
             input,
@@ -316,6 +317,7 @@ impl<K: HashKey, S: StateStore> HashAggExecutor<K, S> {
             pk_indices,
             executor_id,
             key_indices,
+            emit_on_window_close,
         )
     }
 }
@@ -0,0 +1,193 @@
+// Copyright 2022 Singularity Data
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use risingwave_common::array::{Op, StreamChunk};
+use risingwave_common::buffer::Bitmap;
+use risingwave_common::types::ScalarImpl;
+
+use super::Watermark;
+
+/// Tracks the watermarks of multiple inputs with the same schema, e.g., upstreams of a merge. As
+/// rows of any input may still arrive, the output watermark of a column is the minimum of the
+/// input ones, and is only emitted when it advances.
+pub struct BufferedWatermarks {
+    num_inputs: usize,
+
+    /// Column index -> the latest watermark value of each input.
+    input_watermarks: HashMap<usize, Vec<Option<ScalarImpl>>>,
+
+    /// Column index -> the latest output watermark value.
+    output_watermarks: HashMap<usize, ScalarImpl>,
+}
+
+impl BufferedWatermarks {
+    pub fn new(num_inputs: usize) -> Self {
+        Self {
+            num_inputs,
+            input_watermarks: HashMap::new(),
+            output_watermarks: HashMap::new(),
+        }
+    }
+
+    /// Handles a watermark from the input `input_idx`, and returns the output watermark if the
+    /// minimum over all inputs advances.
+    pub fn handle_watermark(
+        &mut self,
+        input_idx: usize,
+        watermark: Watermark,
+    ) -> Option<Watermark> {
+        let values = self
+            .input_watermarks
+            .entry(watermark.col_idx)
+            .or_insert_with(|| vec![None; self.num_inputs]);
+        let value = &mut values[input_idx];
+        if value.as_ref().map_or(true, |value| *value < watermark.val) {
+            *value = Some(watermark.val.clone());
+        }
+
+        // `None` is smaller than any `Some`, so there's no output until all inputs have watermarks.
+        let min_value = values.iter().min().cloned().flatten()?;
+        match self.output_watermarks.get(&watermark.col_idx) {
+            Some(output_value) if *output_value >= min_value => None,
+            _ => {
+                self.output_watermarks
+                    .insert(watermark.col_idx, min_value.clone());
+                Some(Watermark {
+                    val: min_value,
+                    ..watermark
+                })
+            }
+        }
+    }
+}
+
+/// Hides the inserted rows of `chunk` whose value on `col_idx` is below `watermark`. These rows
+/// arrive after their states are cleaned by the watermark, so they are dropped as late events.
+/// Retractions are kept, as they may retract rows accepted before the watermark. If the new row
+/// of an update is hidden, the update becomes a delete of the old row. Rows with null values are
+/// kept.
+pub fn hide_late_rows(chunk: StreamChunk, col_idx: usize, watermark: &ScalarImpl) -> StreamChunk {
+    let (mut ops, columns, visibility) = chunk.into_inner();
+    let array = columns[col_idx].array_ref();
+    let mut new_visibility = Vec::with_capacity(ops.len());
+    for idx in 0..ops.len() {
+        let visible = visibility
+            .as_ref()
+            .map_or(true, |visibility| visibility.is_set(idx).unwrap());
+        let late = matches!(ops[idx], Op::Insert | Op::UpdateInsert)
+            && array
+                .value_at(idx)
+                .map_or(false, |value| value.into_scalar_impl() < *watermark);
+        if visible && late && ops[idx] == Op::UpdateInsert {
+            assert_eq!(ops[idx - 1], Op::UpdateDelete);
+            ops[idx - 1] = Op::Delete;
+        }
+        new_visibility.push(visible && !late);
+    }
+    StreamChunk::new(
+        ops,
+        columns,
+        Some(Bitmap::try_from(new_visibility).unwrap()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use risingwave_common::array::stream_chunk::StreamChunkTestExt;
+    use risingwave_common::types::DataType;
+
+    use super::*;
+
+    fn watermark(col_idx: usize, val: i64) -> Watermark {
+        Watermark::new(col_idx, DataType::Int64, ScalarImpl::Int64(val))
+    }
+
+    #[test]
+    fn test_buffered_watermarks() {
+        let mut watermarks = BufferedWatermarks::new(2);
+
+        // No output until both inputs have watermarks on the column.
+        assert_eq!(watermarks.handle_watermark(0, watermark(0, 5)), None);
+        assert_eq!(
+            watermarks.handle_watermark(1, watermark(0, 3)),
+            Some(watermark(0, 3))
+        );
+        // The minimum doesn't change.
+        assert_eq!(watermarks.handle_watermark(0, watermark(0, 8)), None);
+        assert_eq!(
+            watermarks.handle_watermark(1, watermark(0, 10)),
+            Some(watermark(0, 8))
+        );
+        // Watermarks never go back.
+        assert_eq!(watermarks.handle_watermark(1, watermark(0, 1)), None);
+        // Columns are tracked separately.
+        assert_eq!(watermarks.handle_watermark(0, watermark(1, 2)), None);
+        assert_eq!(
+            watermarks.handle_watermark(1, watermark(1, 2)),
+            Some(watermark(1, 2))
+        );
+    }
+
+    #[test]
+    fn test_hide_late_rows() {
+        let chunk = StreamChunk::from_pretty(
+            " I I
+            + 1 3
+            + 2 5 D
+            - 3 .
+            + 4 7",
+        );
+        let chunk = hide_late_rows(chunk, 1, &ScalarImpl::Int64(5));
+        assert_eq!(
+            chunk.visibility().as_ref().unwrap().iter().collect_vec(),
+            vec![false, false, true, true]
+        );
+    }
+
+    #[test]
+    fn test_hide_late_rows_with_retractions() {
+        let chunk = StreamChunk::from_pretty(
+            "  I I
+            -  1 3
+            U- 2 6
+            U+ 2 4
+            U- 3 2
+            U+ 3 8
+            U- 4 1
+            U+ 4 2
+            +  5 .",
+        );
+        let chunk = hide_late_rows(chunk, 1, &ScalarImpl::Int64(5));
+        assert_eq!(
+            chunk.ops(),
+            &[
+                Op::Delete,
+                Op::Delete,
+                Op::UpdateInsert,
+                Op::UpdateDelete,
+                Op::UpdateInsert,
+                Op::Delete,
+                Op::UpdateInsert,
+                Op::Insert,
+            ]
+        );
+        assert_eq!(
+            chunk.visibility().as_ref().unwrap().iter().collect_vec(),
+            vec![true, true, false, true, true, true, false, true]
+        );
+    }
+}
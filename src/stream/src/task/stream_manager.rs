@@ -314,6 +314,16 @@ pub fn build_agg_call_from_prost(agg_call_proto: &expr::AggCall) -> Result<AggCa
                 DataType::from(arg.get_type()?),
                 arg.get_input()?.column_idx as usize,
             ),
+            [arg1, arg2] => AggArgs::Binary(
+                [
+                    DataType::from(arg1.get_type()?),
+                    DataType::from(arg2.get_type()?),
+                ],
+                [
+                    arg1.get_input()?.column_idx as usize,
+                    arg2.get_input()?.column_idx as usize,
+                ],
+            ),
             _ => {
                 return Err(RwError::from(ErrorCode::NotImplemented(
                     "multiple aggregation args".to_string(),
@@ -326,6 +336,7 @@ pub fn build_agg_call_from_prost(agg_call_proto: &expr::AggCall) -> Result<AggCa
         kind: AggKind::try_from(agg_call_proto.get_type()?)?,
         args,
         return_type: DataType::from(agg_call_proto.get_return_type()?),
+        distinct: agg_call_proto.distinct,
     })
 }
 
@@ -102,7 +102,8 @@ impl<B: Buf> MaybeFlip<B> {
 }
 
 impl<B: Buf> Deserializer<B> {
-    fn read_bytes(&mut self) -> Result<Vec<u8>> {
+    /// Read a byte string which was serialized by `serialize_bytes`.
+    pub fn read_bytes(&mut self) -> Result<Vec<u8>> {
         match self.input.get_u8() {
             0 => return Ok(vec![]), // empty slice
             1 => {}                 // non-empty slice
@@ -822,6 +823,18 @@ mod tests {
         deserializer.deserialize_naivedate().unwrap()
     }
 
+    #[test]
+    fn test_bytes() {
+        for bytes in [&b""[..], b"\x00\xff\x00", b"0123456789abcdef\x01"] {
+            let mut serializer = crate::Serializer::new(vec![]);
+            serde::Serializer::serialize_bytes(&mut serializer, bytes).unwrap();
+            let encoded = serializer.into_inner();
+            let mut deserializer = Deserializer::new(&encoded[..]);
+            assert_eq!(deserializer.read_bytes().unwrap(), bytes);
+            assert!(deserializer.input.is_empty());
+        }
+    }
+
     #[test]
     fn test_naivetime() {
         let (secs, nano) = (23 * 3600 + 59 * 60 + 59, 1234_5678);
@@ -757,6 +757,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_bytes_order() {
+        fn to_vec_bytes(b: &[u8]) -> Vec<u8> {
+            let mut ser = Serializer::new(vec![]);
+            serde::Serializer::serialize_bytes(&mut ser, b).unwrap();
+            ser.into_inner()
+        }
+
+        let mut rng = rand::thread_rng();
+        for _ in 0..1000 {
+            let a: Vec<u8> = (0..rng.gen_range(0..16)).map(|_| rng.gen()).collect();
+            let b: Vec<u8> = (0..rng.gen_range(0..16)).map(|_| rng.gen()).collect();
+            assert_eq!(a.cmp(&b), to_vec_bytes(&a).cmp(&to_vec_bytes(&b)));
+        }
+    }
+
     fn rand_string(len_range: std::ops::Range<usize>) -> String {
         let mut rng = rand::thread_rng();
         let len = rng.gen_range(len_range);
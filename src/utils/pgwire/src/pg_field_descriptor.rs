@@ -44,7 +44,11 @@ impl PgFieldDescriptor {
             | TypeOid::Time
             | TypeOid::Timestampz => 8,
             TypeOid::SmallInt => 2,
-            TypeOid::CharArray | TypeOid::Varchar | TypeOid::Decimal => -1,
+            TypeOid::CharArray
+            | TypeOid::Varchar
+            | TypeOid::Decimal
+            | TypeOid::Jsonb
+            | TypeOid::Bytea => -1,
         };
 
         Self {
@@ -102,6 +106,8 @@ pub enum TypeOid {
     Timestamp,
     Timestampz,
     Decimal,
+    Jsonb,
+    Bytea,
 }
 
 impl TypeOid {
@@ -120,6 +126,8 @@ impl TypeOid {
             TypeOid::Timestamp => 1114,
             TypeOid::Timestampz => 1184,
             TypeOid::Decimal => 1231,
+            TypeOid::Jsonb => 3802,
+            TypeOid::Bytea => 17,
         }
     }
 }
@@ -32,10 +32,12 @@ pub enum StatementType {
     CREATE_TABLE,
     CREATE_MATERIALIZED_VIEW,
     CREATE_SOURCE,
+    CREATE_FUNCTION,
     DESCRIBE_TABLE,
     DROP_TABLE,
     DROP_MATERIALIZED_VIEW,
     DROP_SOURCE,
+    DROP_FUNCTION,
     // Introduce ORDER_BY statement type cuz Calcite unvalidated AST has SqlKind.ORDER_BY. Note
     // that Statement Type is not designed to be one to one mapping with SqlKind.
     ORDER_BY,